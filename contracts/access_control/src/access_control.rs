@@ -1,12 +1,20 @@
 // Allow deprecated events API until migration to #[contractevent] macro
 #![allow(deprecated)]
 
-use soroban_sdk::{contracttype, symbol_short, Address, Env, IntoVal, Symbol, Vec};
+use soroban_sdk::{
+    contracttype, symbol_short, xdr::FromXdr, Address, BytesN, Env, IntoVal, InvokeError, Map,
+    String, Symbol, TryFromVal, Val, Vec,
+};
 
+use crate::audit::AuditLog;
 use crate::errors::{AccessControlError, AccessControlResult};
 use crate::types::{
-    AccessControlConfig, MembershipInfo, MultiSigConfig, PendingAdminTransfer, PendingProposal,
-    ProposalAction, ProposalStats, SubscriptionTierLevel, UserRole, UserSubscriptionStatus,
+    AccessControlConfig, AuditAction, AuditTarget, AuditValue, ContractCall, EmergencyState,
+    LegacyAccessControlConfig, LegacyMultiSigConfig, LegacyPendingProposal, LegacyProposalStats,
+    MembershipInfo, MembershipRecord, MembershipStatus, MultiSigConfig, PendingAdminTransfer,
+    PendingProposal, ProposalAction, ProposalBatchResult, ProposalExecution, ProposalMetadata,
+    ProposalProgress, ProposalStats, ProposalVeto, SessionGrant, SessionPermission,
+    SubscriptionTierLevel, UserRole, UserSubscriptionStatus,
 };
 
 /// Storage keys for the access control module
@@ -31,9 +39,29 @@ pub enum DataKey {
     ProposalStats,
     PendingProposalsList,
     TimeLockExpiry(u64),
-    EmergencyMode,
+    EmergencyState,
+    // Cross-contract execution results
+    ProposalExecution(u64),
+    // Delegated session keys, by grantee
+    SessionGrant(Address),
+    // Membership registry synced by the subscription contract
+    Membership(Address),
+    // Address allowed to veto pending proposals
+    SecurityCouncil,
+    ProposalVeto(u64),
+    ProposalMetadata(u64),
 }
 
+/// Maximum number of proposals in one batch approval or rejection
+const MAX_PROPOSAL_BATCH: u32 = 20;
+
+/// Maximum length of a proposal title, in bytes
+const MAX_PROPOSAL_TITLE_LEN: u32 = 64;
+
+/// Emergency mode duration used when multisig is not enabled, or its config
+/// predates emergency mode
+const DEFAULT_EMERGENCY_DURATION: u64 = 86400;
+
 pub struct AccessControlModule;
 
 impl AccessControlModule {
@@ -99,10 +127,12 @@ impl AccessControlModule {
 
         let multisig_config = MultiSigConfig {
             admins: admins.clone(),
+            admin_weights: Map::new(env),
             required_signatures,
             critical_threshold,
             emergency_threshold,
             time_lock_duration: 86400, // 24 hours default
+            emergency_duration: 86400, // 24 hours default
             max_pending_proposals: 50,
             proposal_expiry_duration: 604800, // 7 days default
         };
@@ -138,6 +168,7 @@ impl AccessControlModule {
             total_executed: 0,
             total_rejected: 0,
             total_expired: 0,
+            total_vetoed: 0,
             pending_count: 0,
         };
         env.storage()
@@ -168,7 +199,7 @@ impl AccessControlModule {
         Self::require_initialized(env)?;
         Self::require_not_paused(env)?;
         Self::require_not_blacklisted(env, &user)?;
-        Self::require_admin(env, &caller)?;
+        Self::require_admin_or_session(env, &caller, SessionPermission::SetRole)?;
 
         Self::validate_role_assignment(env, &user, &role)?;
 
@@ -177,6 +208,15 @@ impl AccessControlModule {
             .persistent()
             .set(&DataKey::UserRole(user.clone()), &role);
 
+        AuditLog::record(
+            env,
+            AuditAction::RoleSet,
+            &caller,
+            AuditTarget::Account(user.clone()),
+            AuditValue::Role(old_role.clone()),
+            AuditValue::Role(role.clone()),
+        );
+
         env.events().publish(
             (symbol_short!("role_set"), user.clone(), role.clone()),
             (caller.clone(), old_role),
@@ -300,10 +340,29 @@ impl AccessControlModule {
     pub fn get_config(env: &Env) -> AccessControlConfig {
         env.storage()
             .persistent()
-            .get(&DataKey::Config)
+            .get::<DataKey, Map<Symbol, Val>>(&DataKey::Config)
+            .map(|fields| Self::decode_config(env, fields))
             .unwrap_or_default()
     }
 
+    /// Decode a stored config, including the layout saved before the
+    /// membership registry existed, which never required an active membership
+    fn decode_config(env: &Env, fields: Map<Symbol, Val>) -> AccessControlConfig {
+        if fields.contains_key(Symbol::new(env, "require_active_membership")) {
+            return AccessControlConfig::try_from_val(env, &fields.to_val()).unwrap();
+        }
+
+        let legacy = LegacyAccessControlConfig::try_from_val(env, &fields.to_val()).unwrap();
+        AccessControlConfig {
+            membership_token_contract: legacy.membership_token_contract,
+            require_membership_for_roles: legacy.require_membership_for_roles,
+            min_token_balance: legacy.min_token_balance,
+            subscription_contract: legacy.subscription_contract,
+            enforce_tier_restrictions: legacy.enforce_tier_restrictions,
+            require_active_membership: false,
+        }
+    }
+
     /// Update configuration (admin only)
     pub fn update_config(
         env: &Env,
@@ -319,6 +378,15 @@ impl AccessControlModule {
         let old_config = Self::get_config(env);
         env.storage().persistent().set(&DataKey::Config, &config);
 
+        AuditLog::record(
+            env,
+            AuditAction::ConfigUpdated,
+            &caller,
+            AuditTarget::Config,
+            AuditValue::Config(old_config.clone()),
+            AuditValue::Config(config.clone()),
+        );
+
         env.events().publish(
             (symbol_short!("cfg_upd"), config.clone()),
             (caller.clone(), old_config),
@@ -403,9 +471,87 @@ impl AccessControlModule {
             }
         }
 
+        if config.require_active_membership
+            && matches!(required_role, UserRole::Member | UserRole::Admin)
+            && !Self::has_active_membership(env, user)
+        {
+            return Err(AccessControlError::InsufficientMembership);
+        }
+
         Ok(())
     }
 
+    // ============================================================================
+    // Membership Registry
+    // ============================================================================
+
+    /// Record a user's membership status. Only the configured subscription
+    /// contract may sync, and it must authorize the call.
+    pub fn sync_membership(
+        env: &Env,
+        user: Address,
+        status: MembershipStatus,
+        expires_at: u64,
+    ) -> AccessControlResult<()> {
+        Self::require_initialized(env)?;
+        let subscription_contract = Self::get_config(env)
+            .subscription_contract
+            .ok_or(AccessControlError::ConfigurationError)?;
+        subscription_contract.require_auth();
+
+        let record = MembershipRecord {
+            user: user.clone(),
+            status: status.clone(),
+            expires_at,
+            updated_at: env.ledger().timestamp(),
+        };
+        env.storage()
+            .persistent()
+            .set(&DataKey::Membership(user.clone()), &record);
+
+        env.events()
+            .publish((symbol_short!("mem_sync"), user), (status, expires_at));
+
+        Ok(())
+    }
+
+    pub fn get_membership(env: &Env, user: Address) -> Option<MembershipRecord> {
+        env.storage().persistent().get(&DataKey::Membership(user))
+    }
+
+    /// Check if the registry holds an active, unexpired membership for `user`
+    pub fn has_active_membership(env: &Env, user: &Address) -> bool {
+        Self::get_membership(env, user.clone()).is_some_and(|record| {
+            record.status == MembershipStatus::Active
+                && env.ledger().timestamp() < record.expires_at
+        })
+    }
+
+    /// Remove registry entries whose expiry has passed. Returns the number removed.
+    pub fn cleanup_stale_memberships(env: &Env, users: Vec<Address>) -> u32 {
+        let current_time = env.ledger().timestamp();
+        let mut removed = 0u32;
+
+        for user in users.iter() {
+            let key = DataKey::Membership(user);
+            let stale = env
+                .storage()
+                .persistent()
+                .get::<DataKey, MembershipRecord>(&key)
+                .is_some_and(|record| record.expires_at <= current_time);
+            if stale {
+                env.storage().persistent().remove(&key);
+                removed += 1;
+            }
+        }
+
+        if removed > 0 {
+            env.events().publish((symbol_short!("mem_clean"),), removed);
+        }
+
+        removed
+    }
+
     fn check_membership_token(
         env: &Env,
         membership_contract: &Address,
@@ -545,7 +691,8 @@ impl AccessControlModule {
     }
 
     pub fn remove_role(env: &Env, caller: Address, user: Address) -> AccessControlResult<()> {
-        Self::require_admin(env, &caller)?;
+        let acting_admin =
+            Self::require_admin_or_session(env, &caller, SessionPermission::RemoveRole)?;
 
         if let Some(admin) = Self::get_admin(env) {
             if user == admin {
@@ -553,7 +700,7 @@ impl AccessControlModule {
             }
         }
 
-        if caller == user && Self::get_role(env, user.clone()) == UserRole::Admin {
+        if acting_admin == user && Self::get_role(env, user.clone()) == UserRole::Admin {
             return Err(AccessControlError::RoleHierarchyViolation);
         }
 
@@ -562,6 +709,15 @@ impl AccessControlModule {
             .persistent()
             .set(&DataKey::UserRole(user.clone()), &UserRole::Guest);
 
+        AuditLog::record(
+            env,
+            AuditAction::RoleRemoved,
+            &caller,
+            AuditTarget::Account(user.clone()),
+            AuditValue::Role(old_role.clone()),
+            AuditValue::Role(UserRole::Guest),
+        );
+
         env.events().publish(
             (symbol_short!("role_rm"), user.clone()),
             (caller.clone(), old_role),
@@ -570,13 +726,111 @@ impl AccessControlModule {
         Ok(())
     }
 
+    // ============================================================================
+    // Session Keys
+    // ============================================================================
+
+    /// Issue a time-boxed session grant letting `grantee` perform the listed
+    /// actions on behalf of `issuer`. Replaces any existing grant for `grantee`.
+    pub fn issue_session_grant(
+        env: &Env,
+        issuer: Address,
+        grantee: Address,
+        permissions: Vec<SessionPermission>,
+        expires_at: u64,
+    ) -> AccessControlResult<()> {
+        Self::require_initialized(env)?;
+        issuer.require_auth();
+        Self::require_admin(env, &issuer)?;
+
+        if permissions.is_empty() || expires_at <= env.ledger().timestamp() {
+            return Err(AccessControlError::InvalidSessionGrant);
+        }
+
+        let grant = SessionGrant {
+            grantee: grantee.clone(),
+            issuer: issuer.clone(),
+            permissions,
+            created_at: env.ledger().timestamp(),
+            expires_at,
+        };
+        env.storage()
+            .persistent()
+            .set(&DataKey::SessionGrant(grantee.clone()), &grant);
+
+        env.events()
+            .publish((symbol_short!("sess_iss"), grantee), (issuer, expires_at));
+
+        Ok(())
+    }
+
+    /// Revoke a session grant. Any admin may revoke, not only the issuer.
+    pub fn revoke_session_grant(
+        env: &Env,
+        caller: Address,
+        grantee: Address,
+    ) -> AccessControlResult<()> {
+        caller.require_auth();
+        Self::require_admin(env, &caller)?;
+
+        let key = DataKey::SessionGrant(grantee.clone());
+        if !env.storage().persistent().has(&key) {
+            return Err(AccessControlError::SessionGrantNotFound);
+        }
+        env.storage().persistent().remove(&key);
+
+        env.events()
+            .publish((symbol_short!("sess_rev"), grantee), caller);
+
+        Ok(())
+    }
+
+    /// Get the unexpired session grant held by `grantee`, if any
+    pub fn get_session_grant(env: &Env, grantee: Address) -> Option<SessionGrant> {
+        env.storage()
+            .persistent()
+            .get::<DataKey, SessionGrant>(&DataKey::SessionGrant(grantee))
+            .filter(|grant| env.ledger().timestamp() < grant.expires_at)
+    }
+
+    /// Require that `caller` is an admin, or holds an unexpired session grant
+    /// with `permission` from an address that is still an admin. Returns the
+    /// admin the action is attributed to.
+    fn require_admin_or_session(
+        env: &Env,
+        caller: &Address,
+        permission: SessionPermission,
+    ) -> AccessControlResult<Address> {
+        if Self::require_admin(env, caller).is_ok() {
+            return Ok(caller.clone());
+        }
+
+        let grant = Self::get_session_grant(env, caller.clone())
+            .filter(|grant| grant.permissions.contains(&permission))
+            .ok_or(AccessControlError::AdminRequired)?;
+        Self::require_admin(env, &grant.issuer)?;
+        caller.require_auth();
+
+        Ok(grant.issuer)
+    }
+
     pub fn blacklist_user(env: &Env, caller: Address, user: Address) -> AccessControlResult<()> {
         Self::require_admin(env, &caller)?;
 
+        let was_blacklisted = Self::is_blacklisted(env, &user);
         env.storage()
             .persistent()
             .set(&DataKey::Blacklisted(user.clone()), &true);
 
+        AuditLog::record(
+            env,
+            AuditAction::Blacklisted,
+            &caller,
+            AuditTarget::Account(user.clone()),
+            AuditValue::Blacklisted(was_blacklisted),
+            AuditValue::Blacklisted(true),
+        );
+
         env.events()
             .publish((symbol_short!("usr_black"), user.clone()), caller.clone());
 
@@ -586,10 +840,20 @@ impl AccessControlModule {
     pub fn unblacklist_user(env: &Env, caller: Address, user: Address) -> AccessControlResult<()> {
         Self::require_admin(env, &caller)?;
 
+        let was_blacklisted = Self::is_blacklisted(env, &user);
         env.storage()
             .persistent()
             .remove(&DataKey::Blacklisted(user.clone()));
 
+        AuditLog::record(
+            env,
+            AuditAction::Unblacklisted,
+            &caller,
+            AuditTarget::Account(user.clone()),
+            AuditValue::Blacklisted(was_blacklisted),
+            AuditValue::Blacklisted(false),
+        );
+
         env.events()
             .publish((symbol_short!("usr_white"), user.clone()), caller.clone());
 
@@ -633,16 +897,43 @@ impl AccessControlModule {
     }
 
     pub fn is_multisig_enabled(env: &Env) -> bool {
-        env.storage()
-            .persistent()
-            .get::<DataKey, MultiSigConfig>(&DataKey::MultiSigConfig)
-            .is_some()
+        env.storage().persistent().has(&DataKey::MultiSigConfig)
     }
 
     pub fn get_multisig_config(env: &Env) -> Option<MultiSigConfig> {
         env.storage()
             .persistent()
-            .get::<DataKey, MultiSigConfig>(&DataKey::MultiSigConfig)
+            .get::<DataKey, Map<Symbol, Val>>(&DataKey::MultiSigConfig)
+            .map(|fields| Self::decode_multisig_config(env, fields))
+    }
+
+    /// Decode a stored multisig config, including layouts saved before
+    /// weighted approvals or emergency mode existed
+    fn decode_multisig_config(env: &Env, mut fields: Map<Symbol, Val>) -> MultiSigConfig {
+        if fields.contains_key(Symbol::new(env, "emergency_duration")) {
+            return MultiSigConfig::try_from_val(env, &fields.to_val()).unwrap();
+        }
+
+        // Configs saved before emergency mode use the default duration, and
+        // those saved before weighted approvals weigh every admin 1
+        let weights_key = Symbol::new(env, "admin_weights");
+        let admin_weights = fields
+            .get(weights_key.clone())
+            .map(|weights| Map::try_from_val(env, &weights).unwrap())
+            .unwrap_or_else(|| Map::new(env));
+        fields.remove(weights_key);
+        let legacy = LegacyMultiSigConfig::try_from_val(env, &fields.to_val()).unwrap();
+        MultiSigConfig {
+            admins: legacy.admins,
+            admin_weights,
+            required_signatures: legacy.required_signatures,
+            critical_threshold: legacy.critical_threshold,
+            emergency_threshold: legacy.emergency_threshold,
+            time_lock_duration: legacy.time_lock_duration,
+            emergency_duration: DEFAULT_EMERGENCY_DURATION,
+            max_pending_proposals: legacy.max_pending_proposals,
+            proposal_expiry_duration: legacy.proposal_expiry_duration,
+        }
     }
 
     pub fn create_proposal(
@@ -656,17 +947,7 @@ impl AccessControlModule {
             Self::get_multisig_config(env).ok_or(AccessControlError::MultisigNotEnabled)?;
 
         // Check max pending proposals limit
-        let mut stats: ProposalStats = env
-            .storage()
-            .persistent()
-            .get(&DataKey::ProposalStats)
-            .unwrap_or(ProposalStats {
-                total_created: 0,
-                total_executed: 0,
-                total_rejected: 0,
-                total_expired: 0,
-                pending_count: 0,
-            });
+        let mut stats = Self::get_proposal_stats(env);
 
         if stats.pending_count >= multisig_config.max_pending_proposals {
             return Err(AccessControlError::MaxProposalsReached);
@@ -711,6 +992,7 @@ impl AccessControlModule {
             expiry,
             time_lock_until,
             required_signatures,
+            vetoed: false,
         };
 
         env.storage()
@@ -739,6 +1021,15 @@ impl AccessControlModule {
             .persistent()
             .set(&DataKey::ProposalStats, &stats);
 
+        AuditLog::record(
+            env,
+            AuditAction::ProposalCreated,
+            &proposer,
+            AuditTarget::Proposal(proposal_id),
+            AuditValue::None,
+            AuditValue::Approvals(1),
+        );
+
         env.events().publish(
             (
                 symbol_short!("proposal"),
@@ -748,8 +1039,11 @@ impl AccessControlModule {
             proposer.clone(),
         );
 
-        // Check if proposal can be executed immediately (only for non-time-locked proposals)
-        if time_lock_until.is_none() && new_proposal.approvals.len() >= required_signatures {
+        // Check if proposal can be executed immediately (only for non-time-locked
+        // proposals, or pause-type proposals during emergency mode)
+        if Self::time_lock_passed(env, &new_proposal)
+            && multisig_config.approved_weight(&new_proposal.approvals) >= required_signatures
+        {
             Self::execute_proposal(env, proposal_id)?;
         }
 
@@ -761,18 +1055,20 @@ impl AccessControlModule {
         approver: Address,
         proposal_id: u64,
     ) -> AccessControlResult<()> {
-        Self::require_admin(env, &approver)?;
+        let approver =
+            Self::require_admin_or_session(env, &approver, SessionPermission::ApproveProposal)?;
 
-        let mut proposal: PendingProposal = env
-            .storage()
-            .persistent()
-            .get(&DataKey::Proposal(proposal_id))
-            .ok_or(AccessControlError::ProposalNotFound)?;
+        let mut proposal =
+            Self::get_proposal(env, proposal_id).ok_or(AccessControlError::ProposalNotFound)?;
 
         if proposal.executed {
             return Err(AccessControlError::ProposalAlreadyExecuted);
         }
 
+        if proposal.vetoed {
+            return Err(AccessControlError::ProposalVetoed);
+        }
+
         if env.ledger().timestamp() > proposal.expiry {
             // Clean up expired proposal
             Self::cleanup_expired_proposal(env, proposal_id)?;
@@ -793,20 +1089,22 @@ impl AccessControlModule {
             .persistent()
             .set(&DataKey::Proposal(proposal_id), &proposal);
 
+        AuditLog::record(
+            env,
+            AuditAction::ProposalApproved,
+            &approver,
+            AuditTarget::Proposal(proposal_id),
+            AuditValue::Approvals(proposal.approvals.len() - 1),
+            AuditValue::Approvals(proposal.approvals.len()),
+        );
+
         env.events()
             .publish((symbol_short!("approve"), proposal_id), approver.clone());
 
-        // Check if we have enough approvals to execute
-        let can_execute = proposal.approvals.len() >= proposal.required_signatures;
-
-        // Check time-lock
-        let time_lock_passed = if let Some(time_lock_until) = proposal.time_lock_until {
-            env.ledger().timestamp() >= time_lock_until
-        } else {
-            true
-        };
+        // Check if we have enough approval weight to execute
+        let can_execute = Self::approved_weight(env, &proposal) >= proposal.required_signatures;
 
-        if can_execute && time_lock_passed {
+        if can_execute && Self::time_lock_passed(env, &proposal) {
             Self::execute_proposal(env, proposal_id)?;
         }
 
@@ -814,16 +1112,17 @@ impl AccessControlModule {
     }
 
     pub fn execute_proposal(env: &Env, proposal_id: u64) -> AccessControlResult<()> {
-        let mut proposal: PendingProposal = env
-            .storage()
-            .persistent()
-            .get(&DataKey::Proposal(proposal_id))
-            .ok_or(AccessControlError::ProposalNotFound)?;
+        let mut proposal =
+            Self::get_proposal(env, proposal_id).ok_or(AccessControlError::ProposalNotFound)?;
 
         if proposal.executed {
             return Err(AccessControlError::ProposalAlreadyExecuted);
         }
 
+        if proposal.vetoed {
+            return Err(AccessControlError::ProposalVetoed);
+        }
+
         // Check if expired
         if env.ledger().timestamp() > proposal.expiry {
             Self::cleanup_expired_proposal(env, proposal_id)?;
@@ -831,14 +1130,21 @@ impl AccessControlModule {
         }
 
         // Check if time-lock has passed
-        if let Some(time_lock_until) = proposal.time_lock_until {
-            if env.ledger().timestamp() < time_lock_until {
-                return Err(AccessControlError::TimeLockActive);
-            }
+        if !Self::time_lock_passed(env, &proposal) {
+            return Err(AccessControlError::TimeLockActive);
+        }
+        if proposal
+            .time_lock_until
+            .is_some_and(|until| env.ledger().timestamp() < until)
+        {
+            env.events().publish(
+                (symbol_short!("emrg_byp"), proposal_id),
+                proposal.action.clone(),
+            );
         }
 
-        // Validate signatures
-        if proposal.approvals.len() < proposal.required_signatures {
+        // Validate approval weight
+        if Self::approved_weight(env, &proposal) < proposal.required_signatures {
             return Err(AccessControlError::InsufficientApprovals);
         }
 
@@ -855,14 +1161,33 @@ impl AccessControlModule {
                     .persistent()
                     .set(&DataKey::UserRole(user.clone()), &role);
 
+                AuditLog::record(
+                    env,
+                    AuditAction::RoleSet,
+                    &proposal.proposer,
+                    AuditTarget::Account(user.clone()),
+                    AuditValue::Role(old_role.clone()),
+                    AuditValue::Role(role.clone()),
+                );
+
                 env.events().publish(
                     (symbol_short!("role_set"), user.clone(), role.clone()),
                     (proposal.proposer.clone(), old_role),
                 );
             }
             ProposalAction::UpdateConfig(config) => {
+                let old_config = Self::get_config(env);
                 env.storage().persistent().set(&DataKey::Config, &config);
 
+                AuditLog::record(
+                    env,
+                    AuditAction::ConfigUpdated,
+                    &proposal.proposer,
+                    AuditTarget::Config,
+                    AuditValue::Config(old_config),
+                    AuditValue::Config(config.clone()),
+                );
+
                 env.events().publish(
                     (symbol_short!("cfg_upd"), config.clone()),
                     proposal.proposer.clone(),
@@ -897,20 +1222,34 @@ impl AccessControlModule {
             }
             ProposalAction::EmergencyPause(reason) => {
                 env.storage().persistent().set(&DataKey::Paused, &true);
-                env.storage()
-                    .persistent()
-                    .set(&DataKey::EmergencyMode, &true);
+                Self::start_emergency_mode(env, reason.clone(), &proposal.proposer);
 
                 env.events().publish(
                     (symbol_short!("emrg_pse"), reason),
                     proposal.proposer.clone(),
                 );
             }
+            ProposalAction::ActivateEmergencyMode(reason) => {
+                Self::start_emergency_mode(env, reason, &proposal.proposer);
+            }
+            ProposalAction::DeactivateEmergencyMode => {
+                Self::end_emergency_mode(env, &proposal.proposer);
+            }
             ProposalAction::BatchBlacklist(users) => {
                 for user in users.iter() {
+                    let was_blacklisted = Self::is_blacklisted(env, &user);
                     env.storage()
                         .persistent()
                         .set(&DataKey::Blacklisted(user.clone()), &true);
+
+                    AuditLog::record(
+                        env,
+                        AuditAction::Blacklisted,
+                        &proposal.proposer,
+                        AuditTarget::Account(user.clone()),
+                        AuditValue::Blacklisted(was_blacklisted),
+                        AuditValue::Blacklisted(true),
+                    );
                 }
 
                 env.events().publish(
@@ -939,7 +1278,9 @@ impl AccessControlModule {
             }
             ProposalAction::RemoveAdmin(admin_to_remove) => {
                 if let Some(mut multisig_config) = Self::get_multisig_config(env) {
-                    if multisig_config.admins.len() <= multisig_config.emergency_threshold {
+                    let remaining_weight = multisig_config.total_weight()
+                        - multisig_config.weight_of(&admin_to_remove);
+                    if remaining_weight < multisig_config.emergency_threshold {
                         return Err(AccessControlError::CannotRemoveLastAdmin);
                     }
 
@@ -950,6 +1291,9 @@ impl AccessControlModule {
                         }
                     }
                     multisig_config.admins = new_admins;
+                    multisig_config
+                        .admin_weights
+                        .remove(admin_to_remove.clone());
                     env.storage()
                         .persistent()
                         .set(&DataKey::MultiSigConfig, &multisig_config);
@@ -964,28 +1308,63 @@ impl AccessControlModule {
                     );
                 }
             }
+            ProposalAction::InvokeContract(call) => {
+                Self::invoke_contract_call(env, proposal_id, &call);
+            }
+            ProposalAction::SetSecurityCouncil(council) => {
+                match &council {
+                    Some(council) => env
+                        .storage()
+                        .persistent()
+                        .set(&DataKey::SecurityCouncil, council),
+                    None => env.storage().persistent().remove(&DataKey::SecurityCouncil),
+                }
+
+                env.events().publish(
+                    (symbol_short!("council"),),
+                    (council, proposal.proposer.clone()),
+                );
+            }
+            ProposalAction::SetAdminWeight(admin, weight) => {
+                let mut multisig_config =
+                    Self::get_multisig_config(env).ok_or(AccessControlError::MultisigNotEnabled)?;
+                if !multisig_config.admins.contains(&admin) {
+                    return Err(AccessControlError::NotMultisigAdmin);
+                }
+                multisig_config.admin_weights.set(admin.clone(), weight);
+                if !multisig_config.validate() {
+                    return Err(AccessControlError::InvalidMultisigConfig);
+                }
+                env.storage()
+                    .persistent()
+                    .set(&DataKey::MultiSigConfig, &multisig_config);
+
+                env.events().publish(
+                    (symbol_short!("adm_wgt"), admin),
+                    (weight, proposal.proposer.clone()),
+                );
+            }
             _ => return Err(AccessControlError::InvalidProposalType),
         }
 
         // Remove from pending list and update stats
         Self::remove_from_pending_list(env, proposal_id);
-        let mut stats: ProposalStats = env
-            .storage()
-            .persistent()
-            .get(&DataKey::ProposalStats)
-            .unwrap_or(ProposalStats {
-                total_created: 0,
-                total_executed: 0,
-                total_rejected: 0,
-                total_expired: 0,
-                pending_count: 0,
-            });
+        let mut stats = Self::get_proposal_stats(env);
         stats.total_executed += 1;
         stats.pending_count = stats.pending_count.saturating_sub(1);
         env.storage()
             .persistent()
             .set(&DataKey::ProposalStats, &stats);
 
+        AuditLog::record(
+            env,
+            AuditAction::ProposalExecuted,
+            &proposal.proposer,
+            AuditTarget::Proposal(proposal_id),
+            AuditValue::None,
+            AuditValue::Approvals(proposal.approvals.len()),
+        );
+
         env.events().publish(
             (symbol_short!("executed"), proposal_id),
             proposal.proposer.clone(),
@@ -994,28 +1373,179 @@ impl AccessControlModule {
         Ok(())
     }
 
+    /// Invoke the target of an `InvokeContract` proposal and record the outcome.
+    /// A failing call is recorded rather than propagated, so the proposal is
+    /// still consumed and the failure can be inspected afterwards.
+    fn invoke_contract_call(env: &Env, proposal_id: u64, call: &ContractCall) {
+        let result = match Vec::<Val>::from_xdr(env, &call.args) {
+            Ok(args) => env
+                .try_invoke_contract::<Val, soroban_sdk::Error>(
+                    &call.contract,
+                    &call.function,
+                    args,
+                )
+                .map(|_| ()),
+            Err(_) => Err(Err(InvokeError::Abort)),
+        };
+
+        let error_code = match &result {
+            Ok(()) => None,
+            Err(Ok(error)) => Some(error.get_code()),
+            Err(Err(InvokeError::Contract(code))) => Some(*code),
+            Err(Err(InvokeError::Abort)) => None,
+        };
+
+        let execution = ProposalExecution {
+            proposal_id,
+            success: result.is_ok(),
+            error_code,
+            executed_at: env.ledger().timestamp(),
+        };
+        env.storage()
+            .persistent()
+            .set(&DataKey::ProposalExecution(proposal_id), &execution);
+
+        env.events().publish(
+            (symbol_short!("invoked"), proposal_id, call.contract.clone()),
+            (call.function.clone(), execution.success),
+        );
+    }
+
+    /// Combined weight of a proposal's approvers under the current admin weights
+    fn approved_weight(env: &Env, proposal: &PendingProposal) -> u32 {
+        Self::get_multisig_config(env)
+            .map(|config| config.approved_weight(&proposal.approvals))
+            .unwrap_or(0)
+    }
+
+    /// Get the accumulated approval weight of a proposal against its requirement
+    pub fn get_proposal_progress(
+        env: &Env,
+        proposal_id: u64,
+    ) -> AccessControlResult<ProposalProgress> {
+        let proposal =
+            Self::get_proposal(env, proposal_id).ok_or(AccessControlError::ProposalNotFound)?;
+
+        let approved_weight = Self::approved_weight(env, &proposal);
+        let time_lock_passed = Self::time_lock_passed(env, &proposal);
+
+        Ok(ProposalProgress {
+            proposal_id,
+            approvals: proposal.approvals.len(),
+            approved_weight,
+            required_weight: proposal.required_signatures,
+            time_lock_until: proposal.time_lock_until,
+            executable: !proposal.executed
+                && !proposal.vetoed
+                && env.ledger().timestamp() <= proposal.expiry
+                && approved_weight >= proposal.required_signatures
+                && time_lock_passed,
+        })
+    }
+
+    /// Get the recorded outcome of an executed `InvokeContract` proposal
+    pub fn get_proposal_execution(env: &Env, proposal_id: u64) -> Option<ProposalExecution> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::ProposalExecution(proposal_id))
+    }
+
     // ============================================================================
     // Enhanced Multisig Helper Functions
     // ============================================================================
 
     /// Reject a proposal (vote against it)
+    /// Approve several proposals at once. Proposals that cannot take the
+    /// vote (missing, executed, vetoed, expired, already voted on) are
+    /// skipped with their error code; any other error aborts the batch.
+    pub fn approve_proposals(
+        env: &Env,
+        approver: Address,
+        proposal_ids: Vec<u64>,
+    ) -> AccessControlResult<Vec<ProposalBatchResult>> {
+        let results =
+            Self::vote_on_proposals(env, &approver, &proposal_ids, Self::approve_proposal)?;
+        Self::publish_batch_vote(env, symbol_short!("batch_apv"), approver, &results);
+        Ok(results)
+    }
+
+    /// Reject several proposals at once, skipping those that cannot take the
+    /// vote the same way as `approve_proposals`.
+    pub fn reject_proposals(
+        env: &Env,
+        rejecter: Address,
+        proposal_ids: Vec<u64>,
+    ) -> AccessControlResult<Vec<ProposalBatchResult>> {
+        let results =
+            Self::vote_on_proposals(env, &rejecter, &proposal_ids, Self::reject_proposal)?;
+        Self::publish_batch_vote(env, symbol_short!("batch_rej"), rejecter, &results);
+        Ok(results)
+    }
+
+    fn vote_on_proposals(
+        env: &Env,
+        voter: &Address,
+        proposal_ids: &Vec<u64>,
+        vote: fn(&Env, Address, u64) -> AccessControlResult<()>,
+    ) -> AccessControlResult<Vec<ProposalBatchResult>> {
+        if proposal_ids.len() > MAX_PROPOSAL_BATCH {
+            return Err(AccessControlError::BatchTooLarge);
+        }
+
+        let mut results = Vec::new(env);
+        for proposal_id in proposal_ids.iter() {
+            let error_code = match vote(env, voter.clone(), proposal_id) {
+                Ok(()) => None,
+                Err(
+                    err @ (AccessControlError::ProposalNotFound
+                    | AccessControlError::ProposalAlreadyExecuted
+                    | AccessControlError::ProposalVetoed
+                    | AccessControlError::ProposalExpired
+                    | AccessControlError::AlreadyApproved
+                    | AccessControlError::AlreadyRejected),
+                ) => Some(err as u32),
+                Err(err) => return Err(err),
+            };
+            results.push_back(ProposalBatchResult {
+                proposal_id,
+                applied: error_code.is_none(),
+                error_code,
+            });
+        }
+
+        Ok(results)
+    }
+
+    fn publish_batch_vote(
+        env: &Env,
+        topic: Symbol,
+        voter: Address,
+        results: &Vec<ProposalBatchResult>,
+    ) {
+        let applied = results.iter().filter(|result| result.applied).count() as u32;
+        env.events()
+            .publish((topic, voter), (applied, results.len() - applied));
+    }
+
     pub fn reject_proposal(
         env: &Env,
         rejecter: Address,
         proposal_id: u64,
     ) -> AccessControlResult<()> {
-        Self::require_admin(env, &rejecter)?;
+        let rejecter =
+            Self::require_admin_or_session(env, &rejecter, SessionPermission::RejectProposal)?;
 
-        let mut proposal: PendingProposal = env
-            .storage()
-            .persistent()
-            .get(&DataKey::Proposal(proposal_id))
-            .ok_or(AccessControlError::ProposalNotFound)?;
+        let mut proposal =
+            Self::get_proposal(env, proposal_id).ok_or(AccessControlError::ProposalNotFound)?;
 
         if proposal.executed {
             return Err(AccessControlError::ProposalAlreadyExecuted);
         }
 
+        if proposal.vetoed {
+            return Err(AccessControlError::ProposalVetoed);
+        }
+
         if env.ledger().timestamp() > proposal.expiry {
             Self::cleanup_expired_proposal(env, proposal_id)?;
             return Err(AccessControlError::ProposalExpired);
@@ -1042,24 +1572,26 @@ impl AccessControlModule {
             env.storage()
                 .persistent()
                 .remove(&DataKey::Proposal(proposal_id));
-
-            let mut stats: ProposalStats = env
-                .storage()
+            env.storage()
                 .persistent()
-                .get(&DataKey::ProposalStats)
-                .unwrap_or(ProposalStats {
-                    total_created: 0,
-                    total_executed: 0,
-                    total_rejected: 0,
-                    total_expired: 0,
-                    pending_count: 0,
-                });
+                .remove(&DataKey::ProposalMetadata(proposal_id));
+
+            let mut stats = Self::get_proposal_stats(env);
             stats.total_rejected += 1;
             stats.pending_count = stats.pending_count.saturating_sub(1);
             env.storage()
                 .persistent()
                 .set(&DataKey::ProposalStats, &stats);
 
+            AuditLog::record(
+                env,
+                AuditAction::ProposalRejected,
+                &rejecter,
+                AuditTarget::Proposal(proposal_id),
+                AuditValue::Approvals(proposal.approvals.len()),
+                AuditValue::None,
+            );
+
             env.events()
                 .publish((symbol_short!("rejected"), proposal_id), rejecter.clone());
 
@@ -1070,6 +1602,15 @@ impl AccessControlModule {
             .persistent()
             .set(&DataKey::Proposal(proposal_id), &proposal);
 
+        AuditLog::record(
+            env,
+            AuditAction::ProposalRejected,
+            &rejecter,
+            AuditTarget::Proposal(proposal_id),
+            AuditValue::Approvals(proposal.approvals.len()),
+            AuditValue::Approvals(proposal.approvals.len()),
+        );
+
         env.events()
             .publish((symbol_short!("reject"), proposal_id), rejecter.clone());
 
@@ -1082,11 +1623,8 @@ impl AccessControlModule {
         proposer: Address,
         proposal_id: u64,
     ) -> AccessControlResult<()> {
-        let proposal: PendingProposal = env
-            .storage()
-            .persistent()
-            .get(&DataKey::Proposal(proposal_id))
-            .ok_or(AccessControlError::ProposalNotFound)?;
+        let proposal =
+            Self::get_proposal(env, proposal_id).ok_or(AccessControlError::ProposalNotFound)?;
 
         if proposal.proposer != proposer {
             return Err(AccessControlError::Unauthorized);
@@ -1096,38 +1634,238 @@ impl AccessControlModule {
             return Err(AccessControlError::ProposalAlreadyExecuted);
         }
 
+        if proposal.vetoed {
+            return Err(AccessControlError::ProposalVetoed);
+        }
+
         Self::remove_from_pending_list(env, proposal_id);
         env.storage()
             .persistent()
             .remove(&DataKey::Proposal(proposal_id));
-
-        let mut stats: ProposalStats = env
-            .storage()
+        env.storage()
             .persistent()
-            .get(&DataKey::ProposalStats)
-            .unwrap_or(ProposalStats {
-                total_created: 0,
-                total_executed: 0,
-                total_rejected: 0,
-                total_expired: 0,
-                pending_count: 0,
-            });
+            .remove(&DataKey::ProposalMetadata(proposal_id));
+
+        let mut stats = Self::get_proposal_stats(env);
         stats.pending_count = stats.pending_count.saturating_sub(1);
         env.storage()
             .persistent()
             .set(&DataKey::ProposalStats, &stats);
 
+        AuditLog::record(
+            env,
+            AuditAction::ProposalCancelled,
+            &proposer,
+            AuditTarget::Proposal(proposal_id),
+            AuditValue::Approvals(proposal.approvals.len()),
+            AuditValue::None,
+        );
+
         env.events()
             .publish((symbol_short!("cancelled"), proposal_id), proposer.clone());
 
         Ok(())
     }
 
+    /// Veto a pending proposal so it can never execute. Allowed for the
+    /// security council and for any admin whose weight alone meets the
+    /// emergency threshold.
+    pub fn veto_proposal(
+        env: &Env,
+        vetoer: Address,
+        proposal_id: u64,
+        reason: String,
+    ) -> AccessControlResult<()> {
+        let multisig_config =
+            Self::get_multisig_config(env).ok_or(AccessControlError::MultisigNotEnabled)?;
+
+        let is_council = Self::get_security_council(env).as_ref() == Some(&vetoer);
+        if !is_council && multisig_config.weight_of(&vetoer) < multisig_config.emergency_threshold {
+            return Err(AccessControlError::Unauthorized);
+        }
+
+        let mut proposal =
+            Self::get_proposal(env, proposal_id).ok_or(AccessControlError::ProposalNotFound)?;
+
+        if proposal.executed {
+            return Err(AccessControlError::ProposalAlreadyExecuted);
+        }
+
+        if proposal.vetoed {
+            return Err(AccessControlError::ProposalVetoed);
+        }
+
+        vetoer.require_auth();
+        proposal.vetoed = true;
+        env.storage()
+            .persistent()
+            .set(&DataKey::Proposal(proposal_id), &proposal);
+
+        let veto = ProposalVeto {
+            vetoed_by: vetoer.clone(),
+            reason: reason.clone(),
+            vetoed_at: env.ledger().timestamp(),
+        };
+        env.storage()
+            .persistent()
+            .set(&DataKey::ProposalVeto(proposal_id), &veto);
+
+        Self::remove_from_pending_list(env, proposal_id);
+        let mut stats = Self::get_proposal_stats(env);
+        stats.total_vetoed += 1;
+        stats.pending_count = stats.pending_count.saturating_sub(1);
+        env.storage()
+            .persistent()
+            .set(&DataKey::ProposalStats, &stats);
+
+        AuditLog::record(
+            env,
+            AuditAction::ProposalVetoed,
+            &vetoer,
+            AuditTarget::Proposal(proposal_id),
+            AuditValue::Approvals(proposal.approvals.len()),
+            AuditValue::None,
+        );
+
+        env.events()
+            .publish((symbol_short!("vetoed"), proposal_id), (vetoer, reason));
+
+        Ok(())
+    }
+
+    /// Attach or replace a proposal's metadata. Only the proposer may do so,
+    /// and only until another admin has approved the proposal.
+    pub fn set_proposal_metadata(
+        env: &Env,
+        proposer: Address,
+        proposal_id: u64,
+        title: String,
+        reference_hash: Option<BytesN<32>>,
+        category: Symbol,
+    ) -> AccessControlResult<()> {
+        let proposal =
+            Self::get_proposal(env, proposal_id).ok_or(AccessControlError::ProposalNotFound)?;
+
+        if proposal.proposer != proposer {
+            return Err(AccessControlError::Unauthorized);
+        }
+
+        if proposal.executed {
+            return Err(AccessControlError::ProposalAlreadyExecuted);
+        }
+
+        if proposal.vetoed {
+            return Err(AccessControlError::ProposalVetoed);
+        }
+
+        // The proposer's own approval is recorded at creation
+        if proposal.approvals.len() > 1 {
+            return Err(AccessControlError::ProposalMetadataLocked);
+        }
+
+        if title.is_empty() || title.len() > MAX_PROPOSAL_TITLE_LEN {
+            return Err(AccessControlError::InvalidProposalMetadata);
+        }
+
+        proposer.require_auth();
+        let metadata = ProposalMetadata {
+            title,
+            reference_hash,
+            category,
+            updated_at: env.ledger().timestamp(),
+        };
+        env.storage()
+            .persistent()
+            .set(&DataKey::ProposalMetadata(proposal_id), &metadata);
+
+        env.events().publish(
+            (symbol_short!("prop_meta"), proposal_id),
+            (metadata.category, metadata.reference_hash),
+        );
+
+        Ok(())
+    }
+
+    /// Get the metadata attached to a proposal, if any
+    pub fn get_proposal_metadata(env: &Env, proposal_id: u64) -> Option<ProposalMetadata> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::ProposalMetadata(proposal_id))
+    }
+
+    /// Get the veto recorded against a proposal, if any
+    pub fn get_proposal_veto(env: &Env, proposal_id: u64) -> Option<ProposalVeto> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::ProposalVeto(proposal_id))
+    }
+
+    pub fn get_security_council(env: &Env) -> Option<Address> {
+        env.storage().persistent().get(&DataKey::SecurityCouncil)
+    }
+
     /// Get proposal details
     pub fn get_proposal(env: &Env, proposal_id: u64) -> Option<PendingProposal> {
-        env.storage()
+        let fields = env
+            .storage()
             .persistent()
-            .get(&DataKey::Proposal(proposal_id))
+            .get::<DataKey, Map<Symbol, Val>>(&DataKey::Proposal(proposal_id))?;
+        let fields = Self::upgrade_proposal_action(env, fields);
+        if fields.contains_key(Symbol::new(env, "vetoed")) {
+            return Some(PendingProposal::try_from_val(env, &fields.to_val()).unwrap());
+        }
+
+        // Proposals created before vetoes existed were never vetoed
+        let legacy = LegacyPendingProposal::try_from_val(env, &fields.to_val()).unwrap();
+        Some(PendingProposal {
+            id: legacy.id,
+            proposer: legacy.proposer,
+            action: legacy.action,
+            proposal_type: legacy.proposal_type,
+            approvals: legacy.approvals,
+            rejections: legacy.rejections,
+            executed: legacy.executed,
+            created_at: legacy.created_at,
+            expiry: legacy.expiry,
+            time_lock_until: legacy.time_lock_until,
+            required_signatures: legacy.required_signatures,
+            vetoed: false,
+        })
+    }
+
+    /// `UpdateConfig` and `UpdateMultisigConfig` proposals created by older
+    /// builds carry old config layouts; rewrite them to the current ones
+    fn upgrade_proposal_action(env: &Env, mut fields: Map<Symbol, Val>) -> Map<Symbol, Val> {
+        let action_key = Symbol::new(env, "action");
+        let Some(mut action) = fields
+            .get(action_key.clone())
+            .and_then(|action| Vec::<Val>::try_from_val(env, &action).ok())
+        else {
+            return fields;
+        };
+        let Some(variant) = action
+            .get(0)
+            .and_then(|variant| Symbol::try_from_val(env, &variant).ok())
+        else {
+            return fields;
+        };
+        let Some(config) = action
+            .get(1)
+            .and_then(|config| Map::<Symbol, Val>::try_from_val(env, &config).ok())
+        else {
+            return fields;
+        };
+
+        let upgraded: Val = if variant == Symbol::new(env, "UpdateConfig") {
+            Self::decode_config(env, config).into_val(env)
+        } else if variant == Symbol::new(env, "UpdateMultisigConfig") {
+            Self::decode_multisig_config(env, config).into_val(env)
+        } else {
+            return fields;
+        };
+        action.set(1, upgraded);
+        fields.set(action_key, action.to_val());
+        fields
     }
 
     /// Get all pending proposal IDs
@@ -1140,16 +1878,34 @@ impl AccessControlModule {
 
     /// Get proposal statistics
     pub fn get_proposal_stats(env: &Env) -> ProposalStats {
-        env.storage()
+        let Some(fields) = env
+            .storage()
             .persistent()
-            .get(&DataKey::ProposalStats)
-            .unwrap_or(ProposalStats {
+            .get::<DataKey, Map<Symbol, Val>>(&DataKey::ProposalStats)
+        else {
+            return ProposalStats {
                 total_created: 0,
                 total_executed: 0,
                 total_rejected: 0,
                 total_expired: 0,
+                total_vetoed: 0,
                 pending_count: 0,
-            })
+            };
+        };
+        if fields.contains_key(Symbol::new(env, "total_vetoed")) {
+            return ProposalStats::try_from_val(env, &fields.to_val()).unwrap();
+        }
+
+        // Statistics saved before vetoes existed have none to count
+        let legacy = LegacyProposalStats::try_from_val(env, &fields.to_val()).unwrap();
+        ProposalStats {
+            total_created: legacy.total_created,
+            total_executed: legacy.total_executed,
+            total_rejected: legacy.total_rejected,
+            total_expired: legacy.total_expired,
+            total_vetoed: 0,
+            pending_count: legacy.pending_count,
+        }
     }
 
     /// Clean up expired proposals (can be called by anyone)
@@ -1164,11 +1920,7 @@ impl AccessControlModule {
         let mut cleaned_count = 0u32;
 
         for proposal_id in pending_list.iter() {
-            if let Some(proposal) = env
-                .storage()
-                .persistent()
-                .get::<DataKey, PendingProposal>(&DataKey::Proposal(proposal_id))
-            {
+            if let Some(proposal) = Self::get_proposal(env, proposal_id) {
                 if current_time > proposal.expiry && !proposal.executed {
                     Self::cleanup_expired_proposal(env, proposal_id)?;
                     cleaned_count += 1;
@@ -1184,24 +1936,28 @@ impl AccessControlModule {
         env.storage()
             .persistent()
             .remove(&DataKey::Proposal(proposal_id));
-
-        let mut stats: ProposalStats = env
-            .storage()
+        env.storage()
             .persistent()
-            .get(&DataKey::ProposalStats)
-            .unwrap_or(ProposalStats {
-                total_created: 0,
-                total_executed: 0,
-                total_rejected: 0,
-                total_expired: 0,
-                pending_count: 0,
-            });
+            .remove(&DataKey::ProposalMetadata(proposal_id));
+
+        let mut stats = Self::get_proposal_stats(env);
         stats.total_expired += 1;
         stats.pending_count = stats.pending_count.saturating_sub(1);
         env.storage()
             .persistent()
             .set(&DataKey::ProposalStats, &stats);
 
+        // Expiry is triggered by whoever touches the proposal, so the
+        // contract itself is recorded as the actor
+        AuditLog::record(
+            env,
+            AuditAction::ProposalExpired,
+            &env.current_contract_address(),
+            AuditTarget::Proposal(proposal_id),
+            AuditValue::None,
+            AuditValue::None,
+        );
+
         env.events()
             .publish((symbol_short!("expired"), proposal_id), ());
 
@@ -1247,15 +2003,36 @@ impl AccessControlModule {
         Err(AccessControlError::AdminRequired)
     }
 
-    /// Check if emergency mode is active
+    /// Check if emergency mode is active (activated and not yet expired)
     pub fn is_emergency_mode(env: &Env) -> bool {
+        Self::get_emergency_state(env).is_some()
+    }
+
+    /// Get the active emergency mode window, if any
+    pub fn get_emergency_state(env: &Env) -> Option<EmergencyState> {
         env.storage()
             .persistent()
-            .get(&DataKey::EmergencyMode)
-            .unwrap_or(false)
+            .get::<_, EmergencyState>(&DataKey::EmergencyState)
+            .filter(|state| env.ledger().timestamp() < state.expires_at)
+    }
+
+    /// Activate emergency mode (requires proposal when multisig is enabled)
+    pub fn activate_emergency_mode(
+        env: &Env,
+        caller: Address,
+        reason: String,
+    ) -> AccessControlResult<()> {
+        Self::require_admin(env, &caller)?;
+
+        if Self::is_multisig_enabled(env) {
+            return Err(AccessControlError::AdminRequired);
+        }
+
+        Self::start_emergency_mode(env, reason, &caller);
+        Ok(())
     }
 
-    /// Deactivate emergency mode (requires proposal)
+    /// Deactivate emergency mode (requires proposal when multisig is enabled)
     pub fn deactivate_emergency_mode(env: &Env, caller: Address) -> AccessControlResult<()> {
         Self::require_admin(env, &caller)?;
 
@@ -1263,14 +2040,64 @@ impl AccessControlModule {
             return Err(AccessControlError::AdminRequired);
         }
 
+        Self::end_emergency_mode(env, &caller);
+        Ok(())
+    }
+
+    /// Clear an emergency mode window that has run out. Callable by anyone;
+    /// returns whether an expired window was cleared.
+    pub fn expire_emergency_mode(env: &Env) -> bool {
+        let state: Option<EmergencyState> =
+            env.storage().persistent().get(&DataKey::EmergencyState);
+
+        match state {
+            Some(state) if env.ledger().timestamp() >= state.expires_at => {
+                env.storage().persistent().remove(&DataKey::EmergencyState);
+                env.events()
+                    .publish((symbol_short!("emrg_exp"), state.reason), state.expires_at);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Open (or restart) the emergency mode window for the configured duration
+    fn start_emergency_mode(env: &Env, reason: String, activated_by: &Address) {
+        let duration = Self::get_multisig_config(env)
+            .map(|config| config.emergency_duration)
+            .unwrap_or(DEFAULT_EMERGENCY_DURATION);
+        let now = env.ledger().timestamp();
+        let state = EmergencyState {
+            reason: reason.clone(),
+            activated_by: activated_by.clone(),
+            activated_at: now,
+            expires_at: now.saturating_add(duration),
+        };
+
         env.storage()
             .persistent()
-            .set(&DataKey::EmergencyMode, &false);
+            .set(&DataKey::EmergencyState, &state);
+
+        env.events().publish(
+            (symbol_short!("emrg_on"), reason),
+            (activated_by.clone(), state.expires_at),
+        );
+    }
+
+    fn end_emergency_mode(env: &Env, deactivated_by: &Address) {
+        env.storage().persistent().remove(&DataKey::EmergencyState);
 
         env.events()
-            .publish((symbol_short!("emrg_off"), false), caller.clone());
+            .publish((symbol_short!("emrg_off"), false), deactivated_by.clone());
+    }
 
-        Ok(())
+    /// Whether a proposal's time-lock no longer blocks execution. Pause-type
+    /// proposals bypass it while emergency mode is active.
+    fn time_lock_passed(env: &Env, proposal: &PendingProposal) -> bool {
+        proposal
+            .time_lock_until
+            .is_none_or(|until| env.ledger().timestamp() >= until)
+            || (proposal.action.is_pause_type() && Self::is_emergency_mode(env))
     }
 
     // ============================================================================
@@ -1,9 +1,13 @@
 use crate::access_control::AccessControlModule;
 use crate::errors::AccessControlError;
-use crate::types::{AccessControlConfig, ProposalAction, ProposalType, UserRole};
+use crate::types::{
+    AccessControlConfig, AuditAction, AuditTarget, AuditValue, ContractCall, MembershipStatus,
+    ProposalAction, ProposalType, SessionPermission, UserRole,
+};
 use soroban_sdk::{
-    testutils::{Address as _, Events, Ledger, LedgerInfo},
-    Address, Env, Vec,
+    testutils::{Address as _, Events, Ledger, LedgerInfo, MockAuth, MockAuthInvoke},
+    xdr::ToXdr,
+    Address, Env, IntoVal, Symbol, Val, Vec,
 };
 
 fn setup_test_env() -> (Env, Address, Address, Address, Address) {
@@ -261,6 +265,7 @@ fn test_config_management() {
             min_token_balance: 100,
             subscription_contract: None,
             enforce_tier_restrictions: false,
+            require_active_membership: false,
         };
 
         AccessControlModule::update_config(&env, admin.clone(), new_config.clone()).unwrap();
@@ -306,6 +311,7 @@ fn test_membership_token_integration() {
             min_token_balance: 500,
             subscription_contract: None,
             enforce_tier_restrictions: false,
+            require_active_membership: false,
         };
 
         AccessControlModule::update_config(&env, admin.clone(), config).unwrap();
@@ -337,6 +343,7 @@ fn test_membership_token_insufficient_balance() {
             min_token_balance: 2000, // Mock only returns 1000
             subscription_contract: None,
             enforce_tier_restrictions: false,
+            require_active_membership: false,
         };
 
         AccessControlModule::update_config(&env, admin.clone(), config).unwrap();
@@ -366,6 +373,7 @@ fn test_membership_not_required_for_guest_role() {
             min_token_balance: 2000, // More than mock provides
             subscription_contract: None,
             enforce_tier_restrictions: false,
+            require_active_membership: false,
         };
 
         AccessControlModule::update_config(&env, admin.clone(), config).unwrap();
@@ -1150,10 +1158,12 @@ fn test_max_pending_proposals_limit() {
         // Create config with low max pending proposals for testing
         let ms_config = crate::types::MultiSigConfig {
             admins: admins.clone(),
+            admin_weights: soroban_sdk::Map::new(&env),
             required_signatures: 2,
             critical_threshold: 2,
             emergency_threshold: 2,
             time_lock_duration: 86400,
+            emergency_duration: 86400,
             max_pending_proposals: 3,
             proposal_expiry_duration: 604800,
         };
@@ -1360,3 +1370,1425 @@ fn test_get_pending_proposals_list() {
         assert!(pending.contains(id2));
     });
 }
+
+// Mock target contract for cross-contract proposal execution
+mod mock_target {
+    use soroban_sdk::{contract, contracterror, contractimpl, symbol_short, Env};
+
+    #[contracterror]
+    #[derive(Copy, Clone, Debug, Eq, PartialEq)]
+    pub enum MockError {
+        Rejected = 7,
+    }
+
+    #[contract]
+    pub struct MockTarget;
+
+    #[contractimpl]
+    impl MockTarget {
+        pub fn set_value(env: Env, value: u32) {
+            env.storage()
+                .instance()
+                .set(&symbol_short!("value"), &value);
+        }
+
+        pub fn get_value(env: Env) -> u32 {
+            env.storage()
+                .instance()
+                .get(&symbol_short!("value"))
+                .unwrap_or(0)
+        }
+
+        pub fn fail(_env: Env) -> Result<(), MockError> {
+            Err(MockError::Rejected)
+        }
+    }
+}
+
+fn advance_past_time_lock(env: &Env) {
+    env.ledger().set(LedgerInfo {
+        timestamp: env.ledger().timestamp() + 86401,
+        protocol_version: 23,
+        sequence_number: 10,
+        network_id: [0; 32],
+        base_reserve: 10,
+        min_temp_entry_ttl: 10,
+        min_persistent_entry_ttl: 10,
+        max_entry_ttl: 6312000,
+    });
+}
+
+#[test]
+fn test_invoke_contract_proposal_executes_after_time_lock() {
+    let env = Env::default();
+    let contract_id = env.register(crate::AccessControl, ());
+    let target_id = env.register(mock_target::MockTarget, ());
+    let target = mock_target::MockTargetClient::new(&env, &target_id);
+    let admin1 = Address::generate(&env);
+    let admin2 = Address::generate(&env);
+    let admin3 = Address::generate(&env);
+
+    env.as_contract(&contract_id, || {
+        let admins = Vec::from_array(&env, [admin1.clone(), admin2.clone(), admin3.clone()]);
+        AccessControlModule::initialize_multisig(&env, admins, 2, None).unwrap();
+
+        let args: Vec<Val> = Vec::from_array(&env, [42u32.into_val(&env)]);
+        let action = ProposalAction::InvokeContract(ContractCall {
+            contract: target_id.clone(),
+            function: Symbol::new(&env, "set_value"),
+            args: args.to_xdr(&env),
+        });
+        let proposal_id =
+            AccessControlModule::create_proposal(&env, admin1.clone(), action).unwrap();
+
+        // Threshold reached, but the time-lock holds execution back
+        AccessControlModule::approve_proposal(&env, admin2.clone(), proposal_id).unwrap();
+        AccessControlModule::approve_proposal(&env, admin3.clone(), proposal_id).unwrap();
+        assert!(AccessControlModule::get_proposal_execution(&env, proposal_id).is_none());
+        assert_eq!(
+            AccessControlModule::execute_proposal(&env, proposal_id).unwrap_err(),
+            AccessControlError::TimeLockActive
+        );
+
+        advance_past_time_lock(&env);
+        AccessControlModule::execute_proposal(&env, proposal_id).unwrap();
+
+        let execution = AccessControlModule::get_proposal_execution(&env, proposal_id).unwrap();
+        assert!(execution.success);
+        assert_eq!(execution.error_code, None);
+        assert!(
+            AccessControlModule::get_proposal(&env, proposal_id)
+                .unwrap()
+                .executed
+        );
+    });
+
+    assert_eq!(target.get_value(), 42);
+}
+
+#[test]
+fn test_invoke_contract_proposal_records_failure() {
+    let env = Env::default();
+    let contract_id = env.register(crate::AccessControl, ());
+    let target_id = env.register(mock_target::MockTarget, ());
+    let admin1 = Address::generate(&env);
+    let admin2 = Address::generate(&env);
+    let admin3 = Address::generate(&env);
+
+    env.as_contract(&contract_id, || {
+        let admins = Vec::from_array(&env, [admin1.clone(), admin2.clone(), admin3.clone()]);
+        AccessControlModule::initialize_multisig(&env, admins, 2, None).unwrap();
+
+        let args: Vec<Val> = Vec::new(&env);
+        let action = ProposalAction::InvokeContract(ContractCall {
+            contract: target_id.clone(),
+            function: Symbol::new(&env, "fail"),
+            args: args.to_xdr(&env),
+        });
+        let proposal_id =
+            AccessControlModule::create_proposal(&env, admin1.clone(), action).unwrap();
+
+        advance_past_time_lock(&env);
+        AccessControlModule::approve_proposal(&env, admin2.clone(), proposal_id).unwrap();
+        AccessControlModule::approve_proposal(&env, admin3.clone(), proposal_id).unwrap();
+
+        // The failed call is recorded and the proposal is consumed
+        let execution = AccessControlModule::get_proposal_execution(&env, proposal_id).unwrap();
+        assert!(!execution.success);
+        assert_eq!(execution.error_code, Some(7));
+        assert_eq!(
+            AccessControlModule::execute_proposal(&env, proposal_id).unwrap_err(),
+            AccessControlError::ProposalAlreadyExecuted
+        );
+        assert_eq!(
+            AccessControlModule::get_proposal_stats(&env).total_executed,
+            1
+        );
+    });
+}
+
+#[test]
+fn test_admin_weight_set_via_proposal() {
+    let env = Env::default();
+    let contract_id = env.register(crate::AccessControl, ());
+    let admin1 = Address::generate(&env);
+    let admin2 = Address::generate(&env);
+    let admin3 = Address::generate(&env);
+
+    env.as_contract(&contract_id, || {
+        let admins = Vec::from_array(&env, [admin1.clone(), admin2.clone(), admin3.clone()]);
+        AccessControlModule::initialize_multisig(&env, admins, 2, None).unwrap();
+
+        let config = AccessControlModule::get_multisig_config(&env).unwrap();
+        assert_eq!(config.weight_of(&admin1), 1);
+        assert_eq!(config.total_weight(), 3);
+
+        let action = ProposalAction::SetAdminWeight(admin1.clone(), 2);
+        let proposal_id =
+            AccessControlModule::create_proposal(&env, admin1.clone(), action).unwrap();
+
+        advance_past_time_lock(&env);
+        AccessControlModule::approve_proposal(&env, admin2.clone(), proposal_id).unwrap();
+        AccessControlModule::approve_proposal(&env, admin3.clone(), proposal_id).unwrap();
+
+        let config = AccessControlModule::get_multisig_config(&env).unwrap();
+        assert_eq!(config.weight_of(&admin1), 2);
+        assert_eq!(config.total_weight(), 4);
+
+        // admin1 alone now carries the standard threshold
+        let user = Address::generate(&env);
+        let action = ProposalAction::SetRole(user.clone(), UserRole::Member);
+        AccessControlModule::create_proposal(&env, admin1.clone(), action).unwrap();
+        assert_eq!(AccessControlModule::get_role(&env, user), UserRole::Member);
+    });
+}
+
+#[test]
+fn test_proposal_progress_reports_weight() {
+    let env = Env::default();
+    let contract_id = env.register(crate::AccessControl, ());
+    let admin1 = Address::generate(&env);
+    let admin2 = Address::generate(&env);
+    let admin3 = Address::generate(&env);
+
+    env.as_contract(&contract_id, || {
+        let admins = Vec::from_array(&env, [admin1.clone(), admin2.clone(), admin3.clone()]);
+        AccessControlModule::initialize_multisig(&env, admins, 2, None).unwrap();
+
+        let user = Address::generate(&env);
+        let action = ProposalAction::BatchBlacklist(Vec::from_array(&env, [user]));
+        let proposal_id =
+            AccessControlModule::create_proposal(&env, admin1.clone(), action).unwrap();
+        AccessControlModule::approve_proposal(&env, admin2.clone(), proposal_id).unwrap();
+
+        let progress = AccessControlModule::get_proposal_progress(&env, proposal_id).unwrap();
+        assert_eq!(progress.approvals, 2);
+        assert_eq!(progress.approved_weight, 2);
+        assert_eq!(progress.required_weight, 3);
+        assert!(progress.time_lock_until.is_some());
+        assert!(!progress.executable);
+
+        assert_eq!(
+            AccessControlModule::get_proposal_progress(&env, 99).unwrap_err(),
+            AccessControlError::ProposalNotFound
+        );
+    });
+}
+
+#[test]
+fn test_invalid_admin_weight_rejected() {
+    let env = Env::default();
+    let contract_id = env.register(crate::AccessControl, ());
+    let admin1 = Address::generate(&env);
+    let admin2 = Address::generate(&env);
+    let outsider = Address::generate(&env);
+
+    env.as_contract(&contract_id, || {
+        let admins = Vec::from_array(&env, [admin1.clone(), admin2.clone()]);
+        AccessControlModule::initialize_multisig(&env, admins, 2, None).unwrap();
+
+        let zero = AccessControlModule::create_proposal(
+            &env,
+            admin1.clone(),
+            ProposalAction::SetAdminWeight(admin2.clone(), 0),
+        )
+        .unwrap();
+        let non_admin = AccessControlModule::create_proposal(
+            &env,
+            admin1.clone(),
+            ProposalAction::SetAdminWeight(outsider, 3),
+        )
+        .unwrap();
+
+        advance_past_time_lock(&env);
+        assert_eq!(
+            AccessControlModule::approve_proposal(&env, admin2.clone(), zero).unwrap_err(),
+            AccessControlError::InvalidMultisigConfig
+        );
+        assert_eq!(
+            AccessControlModule::approve_proposal(&env, admin2.clone(), non_admin).unwrap_err(),
+            AccessControlError::NotMultisigAdmin
+        );
+    });
+}
+
+#[test]
+fn test_multisig_config_saved_before_weights_still_loads() {
+    let env = Env::default();
+    let contract_id = env.register(crate::AccessControl, ());
+    let admin1 = Address::generate(&env);
+    let admin2 = Address::generate(&env);
+
+    env.as_contract(&contract_id, || {
+        let legacy = crate::types::LegacyMultiSigConfig {
+            admins: Vec::from_array(&env, [admin1.clone(), admin2.clone()]),
+            required_signatures: 2,
+            critical_threshold: 2,
+            emergency_threshold: 2,
+            time_lock_duration: 86400,
+            max_pending_proposals: 50,
+            proposal_expiry_duration: 604800,
+        };
+        env.storage()
+            .persistent()
+            .set(&crate::access_control::DataKey::MultiSigConfig, &legacy);
+
+        // Every admin weighs 1 and emergency mode lasts the default 24 hours
+        let config = AccessControlModule::get_multisig_config(&env).unwrap();
+        assert!(config.admin_weights.is_empty());
+        assert_eq!(config.weight_of(&admin2), 1);
+        assert_eq!(config.emergency_duration, 86400);
+        assert_eq!(config.required_signatures, 2);
+
+        // Configs with weights but no emergency duration keep their weights
+        let mut fields: soroban_sdk::Map<Symbol, Val> = env
+            .storage()
+            .persistent()
+            .get(&crate::access_control::DataKey::MultiSigConfig)
+            .unwrap();
+        fields.set(
+            Symbol::new(&env, "admin_weights"),
+            soroban_sdk::Map::<Address, u32>::from_array(&env, [(admin2.clone(), 3)])
+                .into_val(&env),
+        );
+        env.storage()
+            .persistent()
+            .set(&crate::access_control::DataKey::MultiSigConfig, &fields);
+        let config = AccessControlModule::get_multisig_config(&env).unwrap();
+        assert_eq!(config.weight_of(&admin2), 3);
+        assert_eq!(config.emergency_duration, 86400);
+
+        // Governance keeps working on the old layout
+        let proposal_id = AccessControlModule::create_proposal(
+            &env,
+            admin1.clone(),
+            ProposalAction::SetRole(Address::generate(&env), UserRole::Member),
+        )
+        .unwrap();
+        assert!(AccessControlModule::get_proposal(&env, proposal_id).is_some());
+    });
+}
+
+#[test]
+fn test_multisig_proposals_saved_before_weights_still_load() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(crate::AccessControl, ());
+    let admin1 = Address::generate(&env);
+    let admin2 = Address::generate(&env);
+
+    env.as_contract(&contract_id, || {
+        let admins = Vec::from_array(&env, [admin1.clone(), admin2.clone()]);
+        AccessControlModule::initialize_multisig(&env, admins.clone(), 2, None).unwrap();
+        let update = AccessControlModule::get_multisig_config(&env).unwrap();
+        let proposal_id = AccessControlModule::create_proposal(
+            &env,
+            admin1.clone(),
+            ProposalAction::UpdateMultisigConfig(update),
+        )
+        .unwrap();
+
+        // Rewrite the pending payload to the layout used before admin weights
+        let legacy = crate::types::LegacyMultiSigConfig {
+            admins,
+            required_signatures: 2,
+            critical_threshold: 2,
+            emergency_threshold: 2,
+            time_lock_duration: 86400,
+            max_pending_proposals: 50,
+            proposal_expiry_duration: 604800,
+        };
+        let key = crate::access_control::DataKey::Proposal(proposal_id);
+        let mut fields: soroban_sdk::Map<Symbol, Val> =
+            env.storage().persistent().get(&key).unwrap();
+        let action = Vec::<Val>::from_array(
+            &env,
+            [
+                Symbol::new(&env, "UpdateMultisigConfig").into_val(&env),
+                legacy.into_val(&env),
+            ],
+        );
+        fields.set(Symbol::new(&env, "action"), action.into_val(&env));
+        env.storage().persistent().set(&key, &fields);
+
+        let proposal = AccessControlModule::get_proposal(&env, proposal_id).unwrap();
+        match proposal.action {
+            ProposalAction::UpdateMultisigConfig(config) => {
+                assert!(config.admin_weights.is_empty());
+                assert_eq!(config.emergency_duration, 86400);
+                assert_eq!(config.max_pending_proposals, 50);
+            }
+            _ => panic!("expected an UpdateMultisigConfig proposal"),
+        }
+        assert_eq!(AccessControlModule::cleanup_expired_proposals(&env), Ok(0));
+    });
+}
+
+#[test]
+fn test_session_grant_allows_listed_actions() {
+    let (env, contract_id, admin, operator, user) = setup_initialized_env();
+    env.mock_all_auths();
+
+    env.as_contract(&contract_id, || {
+        let expires_at = env.ledger().timestamp() + 3600;
+        let permissions = Vec::from_array(&env, [SessionPermission::SetRole]);
+        AccessControlModule::issue_session_grant(
+            &env,
+            admin.clone(),
+            operator.clone(),
+            permissions,
+            expires_at,
+        )
+        .unwrap();
+
+        let grant = AccessControlModule::get_session_grant(&env, operator.clone()).unwrap();
+        assert_eq!(grant.issuer, admin);
+        assert_eq!(grant.expires_at, expires_at);
+
+        AccessControlModule::set_role(&env, operator.clone(), user.clone(), UserRole::Member)
+            .unwrap();
+        assert_eq!(
+            AccessControlModule::get_role(&env, user.clone()),
+            UserRole::Member
+        );
+
+        // Not granted: remove_role
+        assert_eq!(
+            AccessControlModule::remove_role(&env, operator.clone(), user.clone()).unwrap_err(),
+            AccessControlError::AdminRequired
+        );
+        // The session key itself holds no admin role
+        assert!(!AccessControlModule::is_admin(&env, operator.clone()));
+    });
+}
+
+#[test]
+fn test_session_grant_expiry_and_revocation() {
+    let (env, contract_id, admin, operator, user) = setup_initialized_env();
+    env.mock_all_auths();
+    let permissions = Vec::from_array(&env, [SessionPermission::SetRole]);
+    // Each signed call runs in its own frame, as it would on chain
+    let issue = |issuer: &Address, expires_at: u64| {
+        env.as_contract(&contract_id, || {
+            AccessControlModule::issue_session_grant(
+                &env,
+                issuer.clone(),
+                operator.clone(),
+                permissions.clone(),
+                expires_at,
+            )
+        })
+    };
+    let revoke = || {
+        env.as_contract(&contract_id, || {
+            AccessControlModule::revoke_session_grant(&env, admin.clone(), operator.clone())
+        })
+    };
+    let set_role_as_operator = || {
+        env.as_contract(&contract_id, || {
+            AccessControlModule::set_role(&env, operator.clone(), user.clone(), UserRole::Member)
+        })
+    };
+
+    let now = env.ledger().timestamp();
+    assert_eq!(
+        issue(&admin, now).unwrap_err(),
+        AccessControlError::InvalidSessionGrant
+    );
+    assert_eq!(
+        issue(&user, now + 3600).unwrap_err(),
+        AccessControlError::AdminRequired
+    );
+
+    issue(&admin, now + 3600).unwrap();
+    revoke().unwrap();
+    env.as_contract(&contract_id, || {
+        assert!(AccessControlModule::get_session_grant(&env, operator.clone()).is_none());
+    });
+    assert_eq!(
+        set_role_as_operator().unwrap_err(),
+        AccessControlError::AdminRequired
+    );
+    assert_eq!(
+        revoke().unwrap_err(),
+        AccessControlError::SessionGrantNotFound
+    );
+
+    issue(&admin, now + 3600).unwrap();
+    advance_past_time_lock(&env);
+    env.as_contract(&contract_id, || {
+        assert!(AccessControlModule::get_session_grant(&env, operator.clone()).is_none());
+    });
+    assert_eq!(
+        set_role_as_operator().unwrap_err(),
+        AccessControlError::AdminRequired
+    );
+}
+
+#[test]
+fn test_session_grant_approves_on_behalf_of_issuer() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(crate::AccessControl, ());
+    let admin1 = Address::generate(&env);
+    let admin2 = Address::generate(&env);
+    let operator = Address::generate(&env);
+
+    env.as_contract(&contract_id, || {
+        let admins = Vec::from_array(&env, [admin1.clone(), admin2.clone()]);
+        AccessControlModule::initialize_multisig(&env, admins, 2, None).unwrap();
+
+        AccessControlModule::issue_session_grant(
+            &env,
+            admin2.clone(),
+            operator.clone(),
+            Vec::from_array(&env, [SessionPermission::ApproveProposal]),
+            env.ledger().timestamp() + 3600,
+        )
+        .unwrap();
+
+        let user = Address::generate(&env);
+        let action = ProposalAction::SetRole(user.clone(), UserRole::Member);
+        let proposal_id =
+            AccessControlModule::create_proposal(&env, admin1.clone(), action).unwrap();
+
+        // The approval is attributed to admin2
+        AccessControlModule::approve_proposal(&env, operator.clone(), proposal_id).unwrap();
+        let proposal = AccessControlModule::get_proposal(&env, proposal_id).unwrap();
+        assert!(proposal.executed);
+        assert!(proposal.approvals.contains(&admin2));
+        assert!(!proposal.approvals.contains(&operator));
+        assert_eq!(AccessControlModule::get_role(&env, user), UserRole::Member);
+    });
+}
+
+/// Multisig of `admin1` and `admin2` with one pending proposal by `admin1`,
+/// set up without mocking any auths.
+fn setup_unsigned_proposal(env: &Env, contract_id: &Address) -> (Address, Address, u64) {
+    let admin1 = Address::generate(env);
+    let admin2 = Address::generate(env);
+    let proposal_id = env.as_contract(contract_id, || {
+        let admins = Vec::from_array(env, [admin1.clone(), admin2.clone()]);
+        AccessControlModule::initialize_multisig(env, admins, 2, None).unwrap();
+        AccessControlModule::create_proposal(
+            env,
+            admin1.clone(),
+            ProposalAction::AddAdmin(Address::generate(env)),
+        )
+        .unwrap()
+    });
+    (admin1, admin2, proposal_id)
+}
+
+/// Issues an `ApproveProposal` grant to `grantee`, signed by `issuer` only.
+fn issue_signed_session_grant(
+    env: &Env,
+    contract_id: &Address,
+    issuer: &Address,
+    grantee: &Address,
+) {
+    let client = crate::AccessControlClient::new(env, contract_id);
+    let permissions = Vec::from_array(env, [SessionPermission::ApproveProposal]);
+    let expires_at = env.ledger().timestamp() + 2 * 86_400;
+    client
+        .mock_auths(&[MockAuth {
+            address: issuer,
+            invoke: &MockAuthInvoke {
+                contract: contract_id,
+                fn_name: "issue_session_grant",
+                args: (issuer, grantee, permissions.clone(), expires_at).into_val(env),
+                sub_invokes: &[],
+            },
+        }])
+        .issue_session_grant(issuer, grantee, &permissions, &expires_at);
+}
+
+#[test]
+fn test_session_grants_require_admin_signature() {
+    let env = Env::default();
+    let contract_id = env.register(crate::AccessControl, ());
+    let client = crate::AccessControlClient::new(&env, &contract_id);
+    let (admin1, admin2, _proposal_id) = setup_unsigned_proposal(&env, &contract_id);
+    let operator = Address::generate(&env);
+
+    // Naming an admin as issuer is not enough without their signature
+    let permissions = Vec::from_array(&env, [SessionPermission::ApproveProposal]);
+    let expires_at = env.ledger().timestamp() + 2 * 86_400;
+    assert!(client
+        .try_issue_session_grant(&admin2, &operator, &permissions, &expires_at)
+        .is_err());
+    assert!(client.get_session_grant(&operator).is_none());
+
+    issue_signed_session_grant(&env, &contract_id, &admin2, &operator);
+    assert!(client.get_session_grant(&operator).is_some());
+
+    // Likewise for revoking
+    assert!(client.try_revoke_session_grant(&admin1, &operator).is_err());
+    assert!(client.get_session_grant(&operator).is_some());
+    client
+        .mock_auths(&[MockAuth {
+            address: &admin1,
+            invoke: &MockAuthInvoke {
+                contract: &contract_id,
+                fn_name: "revoke_session_grant",
+                args: (&admin1, &operator).into_val(&env),
+                sub_invokes: &[],
+            },
+        }])
+        .revoke_session_grant(&admin1, &operator);
+    assert!(client.get_session_grant(&operator).is_none());
+}
+
+#[test]
+fn test_session_grantee_must_sign() {
+    let env = Env::default();
+    let contract_id = env.register(crate::AccessControl, ());
+    let client = crate::AccessControlClient::new(&env, &contract_id);
+    let (_admin1, admin2, proposal_id) = setup_unsigned_proposal(&env, &contract_id);
+
+    let operator = Address::generate(&env);
+    issue_signed_session_grant(&env, &contract_id, &admin2, &operator);
+
+    // Naming a grantee is not enough without the grantee's signature
+    advance_past_time_lock(&env);
+    assert!(client
+        .try_approve_proposal(&operator, &proposal_id)
+        .is_err());
+    assert_eq!(
+        client.get_proposal(&proposal_id).unwrap().approvals.len(),
+        1
+    );
+}
+
+fn configure_membership_registry(env: &Env, admin: &Address) -> Address {
+    let subscription_contract = Address::generate(env);
+    let config = AccessControlConfig {
+        membership_token_contract: None,
+        require_membership_for_roles: false,
+        min_token_balance: 0,
+        subscription_contract: Some(subscription_contract.clone()),
+        enforce_tier_restrictions: false,
+        require_active_membership: true,
+    };
+    AccessControlModule::update_config(env, admin.clone(), config).unwrap();
+    subscription_contract
+}
+
+#[test]
+fn test_membership_registry_gates_access() {
+    let (env, contract_id, admin, user1, _) = setup_initialized_env();
+    env.mock_all_auths();
+
+    env.as_contract(&contract_id, || {
+        configure_membership_registry(&env, &admin);
+        AccessControlModule::set_role(&env, admin.clone(), user1.clone(), UserRole::Member)
+            .unwrap();
+
+        // Role alone is not enough without an active membership
+        assert!(!AccessControlModule::check_access(&env, user1.clone(), UserRole::Member).unwrap());
+        assert!(AccessControlModule::check_access(&env, user1.clone(), UserRole::Guest).unwrap());
+
+        let expires_at = env.ledger().timestamp() + 1000;
+        AccessControlModule::sync_membership(
+            &env,
+            user1.clone(),
+            MembershipStatus::Active,
+            expires_at,
+        )
+        .unwrap();
+        assert!(AccessControlModule::check_access(&env, user1.clone(), UserRole::Member).unwrap());
+
+        AccessControlModule::sync_membership(
+            &env,
+            user1.clone(),
+            MembershipStatus::GracePeriod,
+            expires_at,
+        )
+        .unwrap();
+        assert!(!AccessControlModule::check_access(&env, user1.clone(), UserRole::Member).unwrap());
+
+        let record = AccessControlModule::get_membership(&env, user1.clone()).unwrap();
+        assert_eq!(record.status, MembershipStatus::GracePeriod);
+        assert_eq!(record.expires_at, expires_at);
+    });
+}
+
+#[test]
+fn test_config_saved_before_membership_registry_still_loads() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(crate::AccessControl, ());
+    let admin1 = Address::generate(&env);
+    let admin2 = Address::generate(&env);
+    let subscription_contract = Address::generate(&env);
+    let legacy = crate::types::LegacyAccessControlConfig {
+        membership_token_contract: None,
+        require_membership_for_roles: false,
+        min_token_balance: 0,
+        subscription_contract: Some(subscription_contract.clone()),
+        enforce_tier_restrictions: true,
+    };
+
+    env.as_contract(&contract_id, || {
+        let admins = Vec::from_array(&env, [admin1.clone(), admin2.clone()]);
+        AccessControlModule::initialize_multisig(&env, admins, 2, None).unwrap();
+        env.storage()
+            .persistent()
+            .set(&crate::access_control::DataKey::Config, &legacy);
+
+        let config = AccessControlModule::get_config(&env);
+        assert_eq!(config.subscription_contract, Some(subscription_contract));
+        assert!(config.enforce_tier_restrictions);
+        assert!(!config.require_active_membership);
+
+        // A pending UpdateConfig proposal in the old layout still decodes
+        let mut update = config.clone();
+        update.min_token_balance = 5;
+        let proposal_id = AccessControlModule::create_proposal(
+            &env,
+            admin1.clone(),
+            ProposalAction::UpdateConfig(update),
+        )
+        .unwrap();
+        let key = crate::access_control::DataKey::Proposal(proposal_id);
+        let mut fields: soroban_sdk::Map<Symbol, Val> =
+            env.storage().persistent().get(&key).unwrap();
+        let mut legacy_update = legacy.clone();
+        legacy_update.min_token_balance = 5;
+        let action = Vec::<Val>::from_array(
+            &env,
+            [
+                Symbol::new(&env, "UpdateConfig").into_val(&env),
+                legacy_update.into_val(&env),
+            ],
+        );
+        fields.set(Symbol::new(&env, "action"), action.into_val(&env));
+        env.storage().persistent().set(&key, &fields);
+
+        let proposal = AccessControlModule::get_proposal(&env, proposal_id).unwrap();
+        match proposal.action {
+            ProposalAction::UpdateConfig(config) => {
+                assert_eq!(config.min_token_balance, 5);
+                assert!(!config.require_active_membership);
+            }
+            _ => panic!("expected an UpdateConfig proposal"),
+        }
+        assert_eq!(AccessControlModule::cleanup_expired_proposals(&env), Ok(0));
+    });
+}
+
+#[test]
+fn test_membership_sync_requires_subscription_contract() {
+    let (env, contract_id, _admin, user1, _) = setup_initialized_env();
+    env.mock_all_auths();
+
+    env.as_contract(&contract_id, || {
+        let result =
+            AccessControlModule::sync_membership(&env, user1.clone(), MembershipStatus::Active, 1);
+        assert_eq!(result.unwrap_err(), AccessControlError::ConfigurationError);
+    });
+}
+
+#[test]
+fn test_cleanup_stale_memberships() {
+    let (env, contract_id, admin, user1, user2) = setup_initialized_env();
+    env.mock_all_auths();
+
+    env.as_contract(&contract_id, || {
+        configure_membership_registry(&env, &admin);
+        let now = env.ledger().timestamp();
+        AccessControlModule::sync_membership(
+            &env,
+            user1.clone(),
+            MembershipStatus::Active,
+            now + 10,
+        )
+        .unwrap();
+        AccessControlModule::sync_membership(
+            &env,
+            user2.clone(),
+            MembershipStatus::Active,
+            now + 100_000,
+        )
+        .unwrap();
+
+        advance_past_time_lock(&env);
+        assert!(!AccessControlModule::has_active_membership(&env, &user1));
+
+        let users = Vec::from_array(&env, [user1.clone(), user2.clone()]);
+        assert_eq!(
+            AccessControlModule::cleanup_stale_memberships(&env, users),
+            1
+        );
+        assert!(AccessControlModule::get_membership(&env, user1).is_none());
+        assert!(AccessControlModule::has_active_membership(&env, &user2));
+    });
+}
+
+#[test]
+fn test_endpoints_return_typed_errors() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(crate::AccessControl, ());
+    let client = crate::AccessControlClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let user1 = Address::generate(&env);
+
+    assert_eq!(
+        client.try_set_role(&admin, &user1, &UserRole::Member),
+        Err(Ok(AccessControlError::NotInitialized))
+    );
+
+    client.initialize(&admin);
+    assert_eq!(
+        client.try_initialize(&admin),
+        Err(Ok(AccessControlError::ConfigurationError))
+    );
+    assert_eq!(
+        client.try_set_role(&user1, &admin, &UserRole::Guest),
+        Err(Ok(AccessControlError::AdminRequired))
+    );
+    assert_eq!(
+        client.try_require_access(&user1, &UserRole::Admin),
+        Err(Ok(AccessControlError::InsufficientRole))
+    );
+    assert_eq!(
+        client.try_accept_admin_transfer(&user1),
+        Err(Ok(AccessControlError::InvalidAddress))
+    );
+    assert_eq!(
+        client.try_revoke_session_grant(&admin, &user1),
+        Err(Ok(AccessControlError::SessionGrantNotFound))
+    );
+}
+
+#[test]
+fn test_proposal_endpoints_return_typed_errors() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(crate::AccessControl, ());
+    let client = crate::AccessControlClient::new(&env, &contract_id);
+    let admin1 = Address::generate(&env);
+    let admin2 = Address::generate(&env);
+    let outsider = Address::generate(&env);
+
+    client.initialize_multisig(&Vec::from_array(&env, [admin1.clone(), admin2.clone()]), &2);
+    let proposal_id = client.create_proposal(&admin1, &ProposalAction::Pause);
+
+    assert_eq!(
+        client.try_approve_proposal(&outsider, &proposal_id),
+        Err(Ok(AccessControlError::AdminRequired))
+    );
+    assert_eq!(
+        client.try_approve_proposal(&admin1, &proposal_id),
+        Err(Ok(AccessControlError::AlreadyApproved))
+    );
+    assert_eq!(
+        client.try_execute_proposal(&proposal_id),
+        Err(Ok(AccessControlError::TimeLockActive))
+    );
+    assert_eq!(
+        client.try_get_proposal_progress(&99),
+        Err(Ok(AccessControlError::ProposalNotFound))
+    );
+    assert_eq!(
+        client.try_cancel_proposal(&admin2, &proposal_id),
+        Err(Ok(AccessControlError::Unauthorized))
+    );
+}
+
+#[test]
+#[should_panic]
+fn test_or_panic_wrapper_panics_on_error() {
+    let (env, contract_id, _admin, user1, user2) = setup_initialized_env();
+
+    env.as_contract(&contract_id, || {
+        crate::AccessControl::set_role_or_panic(env.clone(), user1, user2, UserRole::Member);
+    });
+}
+
+#[test]
+fn test_security_council_vetoes_proposal() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(crate::AccessControl, ());
+    let admin1 = Address::generate(&env);
+    let admin2 = Address::generate(&env);
+    let admin3 = Address::generate(&env);
+    let council = Address::generate(&env);
+
+    env.as_contract(&contract_id, || {
+        let admins = Vec::from_array(&env, [admin1.clone(), admin2.clone(), admin3.clone()]);
+        AccessControlModule::initialize_multisig(&env, admins, 2, None).unwrap();
+
+        let action = ProposalAction::SetSecurityCouncil(Some(council.clone()));
+        let council_proposal =
+            AccessControlModule::create_proposal(&env, admin1.clone(), action).unwrap();
+        advance_past_time_lock(&env);
+        AccessControlModule::approve_proposal(&env, admin2.clone(), council_proposal).unwrap();
+        AccessControlModule::approve_proposal(&env, admin3.clone(), council_proposal).unwrap();
+        assert_eq!(
+            AccessControlModule::get_security_council(&env),
+            Some(council.clone())
+        );
+
+        let user = Address::generate(&env);
+        let action = ProposalAction::BatchBlacklist(Vec::from_array(&env, [user.clone()]));
+        let proposal_id =
+            AccessControlModule::create_proposal(&env, admin1.clone(), action).unwrap();
+
+        // A regular admin's weight does not meet the emergency threshold
+        let reason = soroban_sdk::String::from_str(&env, "suspicious batch");
+        assert_eq!(
+            AccessControlModule::veto_proposal(&env, admin2.clone(), proposal_id, reason.clone())
+                .unwrap_err(),
+            AccessControlError::Unauthorized
+        );
+
+        AccessControlModule::veto_proposal(&env, council.clone(), proposal_id, reason.clone())
+            .unwrap();
+        assert!(
+            AccessControlModule::get_proposal(&env, proposal_id)
+                .unwrap()
+                .vetoed
+        );
+        let veto = AccessControlModule::get_proposal_veto(&env, proposal_id).unwrap();
+        assert_eq!(veto.vetoed_by, council);
+        assert_eq!(veto.reason, reason);
+        assert!(!AccessControlModule::get_pending_proposals(&env).contains(proposal_id));
+
+        let stats = AccessControlModule::get_proposal_stats(&env);
+        assert_eq!(stats.total_vetoed, 1);
+        assert_eq!(stats.pending_count, 0);
+
+        // A vetoed proposal can no longer move forward
+        advance_past_time_lock(&env);
+        assert_eq!(
+            AccessControlModule::approve_proposal(&env, admin2.clone(), proposal_id).unwrap_err(),
+            AccessControlError::ProposalVetoed
+        );
+        assert_eq!(
+            AccessControlModule::execute_proposal(&env, proposal_id).unwrap_err(),
+            AccessControlError::ProposalVetoed
+        );
+        assert!(!AccessControlModule::is_blacklisted(&env, &user));
+    });
+}
+
+#[test]
+fn test_heavy_admin_can_veto() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(crate::AccessControl, ());
+    let admin1 = Address::generate(&env);
+    let admin2 = Address::generate(&env);
+    let admin3 = Address::generate(&env);
+
+    env.as_contract(&contract_id, || {
+        let admins = Vec::from_array(&env, [admin1.clone(), admin2.clone(), admin3.clone()]);
+        AccessControlModule::initialize_multisig(&env, admins, 2, None).unwrap();
+
+        // Raise admin3 to the emergency threshold (3)
+        let action = ProposalAction::SetAdminWeight(admin3.clone(), 3);
+        let weight_proposal =
+            AccessControlModule::create_proposal(&env, admin1.clone(), action).unwrap();
+        advance_past_time_lock(&env);
+        AccessControlModule::approve_proposal(&env, admin2.clone(), weight_proposal).unwrap();
+        AccessControlModule::approve_proposal(&env, admin3.clone(), weight_proposal).unwrap();
+
+        let action = ProposalAction::RemoveAdmin(admin2.clone());
+        let proposal_id =
+            AccessControlModule::create_proposal(&env, admin1.clone(), action).unwrap();
+        let reason = soroban_sdk::String::from_str(&env, "not agreed");
+        AccessControlModule::veto_proposal(&env, admin3.clone(), proposal_id, reason.clone())
+            .unwrap();
+        assert_eq!(
+            AccessControlModule::veto_proposal(&env, admin3.clone(), proposal_id, reason)
+                .unwrap_err(),
+            AccessControlError::ProposalVetoed
+        );
+        assert!(
+            !AccessControlModule::get_proposal_progress(&env, proposal_id)
+                .unwrap()
+                .executable
+        );
+    });
+}
+
+#[test]
+fn test_proposals_saved_before_vetoes_still_load() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(crate::AccessControl, ());
+    let admin1 = Address::generate(&env);
+    let admin2 = Address::generate(&env);
+    let admin3 = Address::generate(&env);
+
+    env.as_contract(&contract_id, || {
+        let admins = Vec::from_array(&env, [admin1.clone(), admin2.clone(), admin3.clone()]);
+        AccessControlModule::initialize_multisig(&env, admins, 2, None).unwrap();
+        let proposal_id = AccessControlModule::create_proposal(
+            &env,
+            admin1.clone(),
+            ProposalAction::SetRole(Address::generate(&env), UserRole::Member),
+        )
+        .unwrap();
+
+        // Rewrite both entries in the layout used before vetoes
+        let proposal = AccessControlModule::get_proposal(&env, proposal_id).unwrap();
+        env.storage().persistent().set(
+            &crate::access_control::DataKey::Proposal(proposal_id),
+            &crate::types::LegacyPendingProposal {
+                id: proposal.id,
+                proposer: proposal.proposer,
+                action: proposal.action,
+                proposal_type: proposal.proposal_type,
+                approvals: proposal.approvals,
+                rejections: proposal.rejections,
+                executed: proposal.executed,
+                created_at: proposal.created_at,
+                expiry: proposal.expiry,
+                time_lock_until: proposal.time_lock_until,
+                required_signatures: proposal.required_signatures,
+            },
+        );
+        env.storage().persistent().set(
+            &crate::access_control::DataKey::ProposalStats,
+            &crate::types::LegacyProposalStats {
+                total_created: 1,
+                total_executed: 0,
+                total_rejected: 0,
+                total_expired: 0,
+                pending_count: 1,
+            },
+        );
+
+        let proposal = AccessControlModule::get_proposal(&env, proposal_id).unwrap();
+        assert!(!proposal.vetoed);
+        let stats = AccessControlModule::get_proposal_stats(&env);
+        assert_eq!(stats.total_created, 1);
+        assert_eq!(stats.total_vetoed, 0);
+
+        // Old proposals can still be approved and executed
+        AccessControlModule::approve_proposal(&env, admin2.clone(), proposal_id).unwrap();
+        assert!(
+            AccessControlModule::get_proposal(&env, proposal_id)
+                .unwrap()
+                .executed
+        );
+        assert_eq!(
+            AccessControlModule::get_proposal_stats(&env).total_executed,
+            1
+        );
+    });
+}
+
+#[test]
+fn test_veto_requires_vetoer_signature() {
+    let env = Env::default();
+    let contract_id = env.register(crate::AccessControl, ());
+    let client = crate::AccessControlClient::new(&env, &contract_id);
+    let (_admin1, _admin2, proposal_id) = setup_unsigned_proposal(&env, &contract_id);
+
+    let council = Address::generate(&env);
+    env.as_contract(&contract_id, || {
+        env.storage()
+            .persistent()
+            .set(&crate::access_control::DataKey::SecurityCouncil, &council);
+    });
+
+    let reason = soroban_sdk::String::from_str(&env, "forged");
+    assert!(client
+        .try_veto_proposal(&council, &proposal_id, &reason)
+        .is_err());
+    assert!(!client.get_proposal(&proposal_id).unwrap().vetoed);
+}
+
+#[test]
+fn test_audit_log_records_role_and_blacklist_changes() {
+    let (env, contract_id, admin, user1, user2) = setup_initialized_env();
+
+    env.as_contract(&contract_id, || {
+        AccessControlModule::set_role(&env, admin.clone(), user1.clone(), UserRole::Member)
+            .unwrap();
+        AccessControlModule::blacklist_user(&env, admin.clone(), user2.clone()).unwrap();
+        AccessControlModule::remove_role(&env, admin.clone(), user1.clone()).unwrap();
+
+        let log = crate::AuditLog::get_audit_log(&env, 0, 10);
+        assert_eq!(log.len(), 3);
+
+        let entry = log.get(0).unwrap();
+        assert_eq!(entry.id, 0);
+        assert_eq!(entry.action, AuditAction::RoleSet);
+        assert_eq!(entry.actor, admin);
+        assert_eq!(entry.target, AuditTarget::Account(user1.clone()));
+        assert_eq!(entry.before, AuditValue::Role(UserRole::Guest));
+        assert_eq!(entry.after, AuditValue::Role(UserRole::Member));
+
+        let entry = log.get(1).unwrap();
+        assert_eq!(entry.action, AuditAction::Blacklisted);
+        assert_eq!(entry.before, AuditValue::Blacklisted(false));
+        assert_eq!(entry.after, AuditValue::Blacklisted(true));
+
+        let entry = log.get(2).unwrap();
+        assert_eq!(entry.action, AuditAction::RoleRemoved);
+        assert_eq!(entry.before, AuditValue::Role(UserRole::Member));
+        assert_eq!(entry.after, AuditValue::Role(UserRole::Guest));
+
+        // Pagination
+        let page = crate::AuditLog::get_audit_log(&env, 1, 1);
+        assert_eq!(page.len(), 1);
+        assert_eq!(page.get(0).unwrap().id, 1);
+        assert!(crate::AuditLog::get_audit_log(&env, 3, 10).is_empty());
+    });
+}
+
+#[test]
+fn test_audit_log_proposal_lifecycle_filtered_by_actor() {
+    let env = Env::default();
+    let contract_id = env.register(crate::AccessControl, ());
+    let admin1 = Address::generate(&env);
+    let admin2 = Address::generate(&env);
+
+    env.as_contract(&contract_id, || {
+        let admins = Vec::from_array(&env, [admin1.clone(), admin2.clone()]);
+        AccessControlModule::initialize_multisig(&env, admins, 2, None).unwrap();
+
+        let user = Address::generate(&env);
+        let action = ProposalAction::SetRole(user.clone(), UserRole::Member);
+        let executed =
+            AccessControlModule::create_proposal(&env, admin1.clone(), action.clone()).unwrap();
+        AccessControlModule::approve_proposal(&env, admin2.clone(), executed).unwrap();
+        let cancelled = AccessControlModule::create_proposal(&env, admin1.clone(), action).unwrap();
+        AccessControlModule::cancel_proposal(&env, admin1.clone(), cancelled).unwrap();
+
+        let actions: Vec<AuditAction> = Vec::from_iter(
+            &env,
+            crate::AuditLog::get_audit_log(&env, 0, 10)
+                .iter()
+                .map(|entry| entry.action),
+        );
+        assert_eq!(
+            actions,
+            Vec::from_array(
+                &env,
+                [
+                    AuditAction::ProposalCreated,
+                    AuditAction::ProposalApproved,
+                    AuditAction::RoleSet,
+                    AuditAction::ProposalExecuted,
+                    AuditAction::ProposalCreated,
+                    AuditAction::ProposalCancelled,
+                ]
+            )
+        );
+
+        let by_admin2 = crate::AuditLog::get_audit_log_by_actor(&env, admin2.clone(), 0, 10);
+        assert_eq!(by_admin2.len(), 1);
+        let approval = by_admin2.get(0).unwrap();
+        assert_eq!(approval.action, AuditAction::ProposalApproved);
+        assert_eq!(approval.target, AuditTarget::Proposal(executed));
+        assert_eq!(approval.before, AuditValue::Approvals(1));
+        assert_eq!(approval.after, AuditValue::Approvals(2));
+
+        let by_admin1 = crate::AuditLog::get_audit_log_by_actor(&env, admin1.clone(), 1, 10);
+        assert_eq!(by_admin1.len(), 4);
+        assert_eq!(by_admin1.get(0).unwrap().action, AuditAction::RoleSet);
+    });
+}
+
+#[test]
+fn test_emergency_mode_bypasses_pause_time_lock_until_expiry() {
+    let env = Env::default();
+    let contract_id = env.register(crate::AccessControl, ());
+    let admin1 = Address::generate(&env);
+    let admin2 = Address::generate(&env);
+    let admin3 = Address::generate(&env);
+
+    env.as_contract(&contract_id, || {
+        let admins = Vec::from_array(&env, [admin1.clone(), admin2.clone(), admin3.clone()]);
+        AccessControlModule::initialize_multisig(&env, admins, 2, None).unwrap();
+
+        let reason = soroban_sdk::String::from_str(&env, "Oracle compromised");
+        let activation = AccessControlModule::create_proposal(
+            &env,
+            admin1.clone(),
+            ProposalAction::ActivateEmergencyMode(reason.clone()),
+        )
+        .unwrap();
+        assert_eq!(
+            AccessControlModule::get_proposal(&env, activation)
+                .unwrap()
+                .proposal_type,
+            ProposalType::Emergency
+        );
+        AccessControlModule::approve_proposal(&env, admin2.clone(), activation).unwrap();
+        assert!(!AccessControlModule::is_emergency_mode(&env));
+        AccessControlModule::approve_proposal(&env, admin3.clone(), activation).unwrap();
+
+        let state = AccessControlModule::get_emergency_state(&env).unwrap();
+        assert_eq!(state.reason, reason);
+        assert_eq!(state.activated_by, admin1);
+        assert_eq!(state.expires_at, state.activated_at + 86400);
+
+        // Pause proposals skip the time-lock while emergency mode is active
+        let pause =
+            AccessControlModule::create_proposal(&env, admin1.clone(), ProposalAction::Pause)
+                .unwrap();
+        AccessControlModule::approve_proposal(&env, admin2.clone(), pause).unwrap();
+        AccessControlModule::approve_proposal(&env, admin3.clone(), pause).unwrap();
+        assert!(AccessControlModule::is_paused(&env));
+
+        // Other critical proposals stay time-locked
+        let add_admin = AccessControlModule::create_proposal(
+            &env,
+            admin1.clone(),
+            ProposalAction::AddAdmin(Address::generate(&env)),
+        )
+        .unwrap();
+        AccessControlModule::approve_proposal(&env, admin2.clone(), add_admin).unwrap();
+        AccessControlModule::approve_proposal(&env, admin3.clone(), add_admin).unwrap();
+        assert!(
+            !AccessControlModule::get_proposal(&env, add_admin)
+                .unwrap()
+                .executed
+        );
+        assert_eq!(
+            AccessControlModule::execute_proposal(&env, add_admin),
+            Err(AccessControlError::TimeLockActive)
+        );
+
+        // The window closes on its own after the configured duration
+        env.ledger().set_timestamp(state.expires_at);
+        assert!(!AccessControlModule::is_emergency_mode(&env));
+        assert!(AccessControlModule::get_emergency_state(&env).is_none());
+
+        let pause_after =
+            AccessControlModule::create_proposal(&env, admin1.clone(), ProposalAction::Pause)
+                .unwrap();
+        AccessControlModule::approve_proposal(&env, admin2.clone(), pause_after).unwrap();
+        AccessControlModule::approve_proposal(&env, admin3.clone(), pause_after).unwrap();
+        assert_eq!(
+            AccessControlModule::execute_proposal(&env, pause_after),
+            Err(AccessControlError::TimeLockActive)
+        );
+
+        assert!(AccessControlModule::expire_emergency_mode(&env));
+        assert!(!AccessControlModule::expire_emergency_mode(&env));
+    });
+}
+
+#[test]
+fn test_emergency_mode_deactivation() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(crate::AccessControl, ());
+    let admin = Address::generate(&env);
+    let other = Address::generate(&env);
+
+    env.as_contract(&contract_id, || {
+        AccessControlModule::initialize(&env, admin.clone(), None).unwrap();
+        let reason = soroban_sdk::String::from_str(&env, "Incident");
+
+        assert_eq!(
+            AccessControlModule::activate_emergency_mode(&env, other.clone(), reason.clone()),
+            Err(AccessControlError::AdminRequired)
+        );
+        AccessControlModule::activate_emergency_mode(&env, admin.clone(), reason).unwrap();
+        assert!(AccessControlModule::is_emergency_mode(&env));
+
+        AccessControlModule::deactivate_emergency_mode(&env, admin.clone()).unwrap();
+        assert!(!AccessControlModule::is_emergency_mode(&env));
+        assert!(!AccessControlModule::expire_emergency_mode(&env));
+    });
+}
+
+#[test]
+fn test_proposal_metadata_locked_after_first_approval() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(crate::AccessControl, ());
+    let admin1 = Address::generate(&env);
+    let admin2 = Address::generate(&env);
+    let admin3 = Address::generate(&env);
+
+    env.as_contract(&contract_id, || {
+        let admins = Vec::from_array(&env, [admin1.clone(), admin2.clone(), admin3.clone()]);
+        AccessControlModule::initialize_multisig(&env, admins, 2, None).unwrap();
+
+        let proposal_id = AccessControlModule::create_proposal(
+            &env,
+            admin1.clone(),
+            ProposalAction::AddAdmin(Address::generate(&env)),
+        )
+        .unwrap();
+        assert!(AccessControlModule::get_proposal_metadata(&env, proposal_id).is_none());
+
+        let title = soroban_sdk::String::from_str(&env, "Onboard front desk staff");
+        let hash = soroban_sdk::BytesN::from_array(&env, &[7; 32]);
+        let category = Symbol::new(&env, "staffing");
+
+        assert_eq!(
+            AccessControlModule::set_proposal_metadata(
+                &env,
+                admin2.clone(),
+                proposal_id,
+                title.clone(),
+                None,
+                category.clone(),
+            ),
+            Err(AccessControlError::Unauthorized)
+        );
+        assert_eq!(
+            AccessControlModule::set_proposal_metadata(
+                &env,
+                admin1.clone(),
+                proposal_id,
+                soroban_sdk::String::from_str(&env, ""),
+                None,
+                category.clone(),
+            ),
+            Err(AccessControlError::InvalidProposalMetadata)
+        );
+
+        AccessControlModule::set_proposal_metadata(
+            &env,
+            admin1.clone(),
+            proposal_id,
+            title.clone(),
+            Some(hash.clone()),
+            category.clone(),
+        )
+        .unwrap();
+        let metadata = AccessControlModule::get_proposal_metadata(&env, proposal_id).unwrap();
+        assert_eq!(metadata.title, title);
+        assert_eq!(metadata.reference_hash, Some(hash));
+        assert_eq!(metadata.category, category);
+
+        // Once another admin approves, the metadata is frozen
+        AccessControlModule::approve_proposal(&env, admin2.clone(), proposal_id).unwrap();
+        assert_eq!(
+            AccessControlModule::set_proposal_metadata(
+                &env,
+                admin1.clone(),
+                proposal_id,
+                title,
+                None,
+                category,
+            ),
+            Err(AccessControlError::ProposalMetadataLocked)
+        );
+    });
+}
+
+#[test]
+fn test_proposal_metadata_requires_proposer_signature() {
+    let env = Env::default();
+    let contract_id = env.register(crate::AccessControl, ());
+    let client = crate::AccessControlClient::new(&env, &contract_id);
+    let (admin1, _admin2, proposal_id) = setup_unsigned_proposal(&env, &contract_id);
+
+    assert!(client
+        .try_set_proposal_metadata(
+            &admin1,
+            &proposal_id,
+            &soroban_sdk::String::from_str(&env, "forged"),
+            &None,
+            &Symbol::new(&env, "staffing"),
+        )
+        .is_err());
+    assert!(client.get_proposal_metadata(&proposal_id).is_none());
+}
+
+#[test]
+fn test_batch_approve_and_reject_proposals() {
+    let env = Env::default();
+    let contract_id = env.register(crate::AccessControl, ());
+    let admins_list = [
+        Address::generate(&env),
+        Address::generate(&env),
+        Address::generate(&env),
+        Address::generate(&env),
+    ];
+    let [admin1, admin2, admin3, _admin4] = admins_list.clone();
+
+    env.as_contract(&contract_id, || {
+        let admins = Vec::from_array(&env, admins_list);
+        AccessControlModule::initialize_multisig(&env, admins, 3, None).unwrap();
+
+        let first = AccessControlModule::create_proposal(
+            &env,
+            admin1.clone(),
+            ProposalAction::SetRole(Address::generate(&env), UserRole::Member),
+        )
+        .unwrap();
+        let second = AccessControlModule::create_proposal(
+            &env,
+            admin1.clone(),
+            ProposalAction::SetRole(Address::generate(&env), UserRole::Member),
+        )
+        .unwrap();
+        AccessControlModule::approve_proposal(&env, admin2.clone(), second).unwrap();
+
+        let results = AccessControlModule::approve_proposals(
+            &env,
+            admin2.clone(),
+            Vec::from_array(&env, [first, second, 99]),
+        )
+        .unwrap();
+        assert_eq!(results.len(), 3);
+        assert!(results.get(0).unwrap().applied);
+        assert_eq!(
+            results.get(1).unwrap().error_code,
+            Some(AccessControlError::AlreadyApproved as u32)
+        );
+        assert_eq!(
+            results.get(2).unwrap().error_code,
+            Some(AccessControlError::ProposalNotFound as u32)
+        );
+
+        // A third approval executes the proposal; the batch reports it as applied
+        let results = AccessControlModule::approve_proposals(
+            &env,
+            admin3.clone(),
+            Vec::from_array(&env, [first]),
+        )
+        .unwrap();
+        assert!(results.get(0).unwrap().applied);
+        assert!(
+            AccessControlModule::get_proposal(&env, first)
+                .unwrap()
+                .executed
+        );
+
+        let results = AccessControlModule::reject_proposals(
+            &env,
+            admin3.clone(),
+            Vec::from_array(&env, [first, second]),
+        )
+        .unwrap();
+        assert_eq!(
+            results.get(0).unwrap().error_code,
+            Some(AccessControlError::ProposalAlreadyExecuted as u32)
+        );
+        assert!(results.get(1).unwrap().applied);
+        assert!(AccessControlModule::get_proposal(&env, second)
+            .unwrap()
+            .rejections
+            .contains(&admin3));
+
+        let too_many = Vec::from_array(&env, [0u64; 21]);
+        assert_eq!(
+            AccessControlModule::approve_proposals(&env, admin3.clone(), too_many),
+            Err(AccessControlError::BatchTooLarge)
+        );
+        assert_eq!(
+            AccessControlModule::approve_proposals(
+                &env,
+                Address::generate(&env),
+                Vec::from_array(&env, [second]),
+            ),
+            Err(AccessControlError::AdminRequired)
+        );
+    });
+}
@@ -0,0 +1,119 @@
+use soroban_sdk::{contracttype, Address, Env, Vec};
+
+use crate::types::{AuditAction, AuditEntry, AuditTarget, AuditValue};
+
+/// Maximum number of entries returned by a single audit log query
+pub const MAX_AUDIT_PAGE: u32 = 100;
+
+/// Storage keys for the audit log
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum AuditDataKey {
+    /// Number of entries recorded so far
+    Count,
+    /// Entry by its position in the log
+    Entry(u64),
+    /// Number of entries recorded for an actor
+    ActorCount(Address),
+    /// Log position of an actor's n-th entry
+    ActorEntry(Address, u64),
+}
+
+/// Append-only audit trail of role, blacklist, config and proposal changes
+pub struct AuditLog;
+
+impl AuditLog {
+    /// Append an entry to the log and to the actor's index
+    pub fn record(
+        env: &Env,
+        action: AuditAction,
+        actor: &Address,
+        target: AuditTarget,
+        before: AuditValue,
+        after: AuditValue,
+    ) {
+        let id: u64 = env
+            .storage()
+            .persistent()
+            .get(&AuditDataKey::Count)
+            .unwrap_or(0);
+
+        let entry = AuditEntry {
+            id,
+            action,
+            actor: actor.clone(),
+            target,
+            before,
+            after,
+            timestamp: env.ledger().timestamp(),
+        };
+        env.storage()
+            .persistent()
+            .set(&AuditDataKey::Entry(id), &entry);
+        env.storage()
+            .persistent()
+            .set(&AuditDataKey::Count, &(id + 1));
+
+        let actor_count = Self::get_actor_count(env, actor);
+        env.storage()
+            .persistent()
+            .set(&AuditDataKey::ActorEntry(actor.clone(), actor_count), &id);
+        env.storage()
+            .persistent()
+            .set(&AuditDataKey::ActorCount(actor.clone()), &(actor_count + 1));
+    }
+
+    /// Get the total number of audit entries
+    pub fn get_count(env: &Env) -> u64 {
+        env.storage()
+            .persistent()
+            .get(&AuditDataKey::Count)
+            .unwrap_or(0)
+    }
+
+    /// Get up to `limit` entries starting at `offset`, oldest first
+    pub fn get_audit_log(env: &Env, offset: u64, limit: u32) -> Vec<AuditEntry> {
+        let end = Self::get_count(env).min(offset.saturating_add(limit.min(MAX_AUDIT_PAGE) as u64));
+
+        let mut entries = Vec::new(env);
+        for id in offset..end {
+            if let Some(entry) = env.storage().persistent().get(&AuditDataKey::Entry(id)) {
+                entries.push_back(entry);
+            }
+        }
+        entries
+    }
+
+    /// Get up to `limit` of `actor`'s entries starting at the actor's
+    /// `offset`-th entry, oldest first
+    pub fn get_audit_log_by_actor(
+        env: &Env,
+        actor: Address,
+        offset: u64,
+        limit: u32,
+    ) -> Vec<AuditEntry> {
+        let end = Self::get_actor_count(env, &actor)
+            .min(offset.saturating_add(limit.min(MAX_AUDIT_PAGE) as u64));
+
+        let mut entries = Vec::new(env);
+        for index in offset..end {
+            let id: Option<u64> = env
+                .storage()
+                .persistent()
+                .get(&AuditDataKey::ActorEntry(actor.clone(), index));
+            if let Some(entry) =
+                id.and_then(|id| env.storage().persistent().get(&AuditDataKey::Entry(id)))
+            {
+                entries.push_back(entry);
+            }
+        }
+        entries
+    }
+
+    fn get_actor_count(env: &Env, actor: &Address) -> u64 {
+        env.storage()
+            .persistent()
+            .get(&AuditDataKey::ActorCount(actor.clone()))
+            .unwrap_or(0)
+    }
+}
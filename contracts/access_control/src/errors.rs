@@ -73,6 +73,18 @@ pub enum AccessControlError {
     NotMultisigAdmin = 132,
     /// Proposal rejection threshold reached
     ProposalRejected = 133,
+    /// Session grant has no permissions or an expiry in the past
+    InvalidSessionGrant = 134,
+    /// No session grant exists for this address
+    SessionGrantNotFound = 135,
+    /// Proposal has been vetoed
+    ProposalVetoed = 136,
+    /// Proposal metadata is malformed
+    InvalidProposalMetadata = 137,
+    /// Proposal metadata can no longer be changed
+    ProposalMetadataLocked = 138,
+    /// Too many proposals in a single batch
+    BatchTooLarge = 139,
 }
 
 impl AccessControlError {
@@ -119,6 +131,14 @@ impl AccessControlError {
             AccessControlError::DuplicateAdmin => "Duplicate admin address",
             AccessControlError::NotMultisigAdmin => "Not authorized as multisig admin",
             AccessControlError::ProposalRejected => "Proposal rejection threshold reached",
+            AccessControlError::InvalidSessionGrant => "Invalid session grant",
+            AccessControlError::SessionGrantNotFound => "Session grant not found",
+            AccessControlError::ProposalVetoed => "Proposal has been vetoed",
+            AccessControlError::InvalidProposalMetadata => "Invalid proposal metadata",
+            AccessControlError::ProposalMetadataLocked => {
+                "Proposal metadata cannot change after another admin has approved"
+            }
+            AccessControlError::BatchTooLarge => "Too many proposals in a single batch",
         }
     }
 
@@ -1,8 +1,9 @@
 #![no_std]
 
-use soroban_sdk::{contract, contractimpl, Address, Env, String, Vec};
+use soroban_sdk::{contract, contractimpl, Address, BytesN, Env, String, Symbol, Vec};
 
 pub mod access_control;
+pub mod audit;
 pub mod errors;
 pub mod types;
 
@@ -10,10 +11,14 @@ pub mod types;
 mod access_control_tests;
 
 pub use access_control::AccessControlModule;
+pub use audit::AuditLog;
 pub use errors::{AccessControlError, AccessControlResult};
 pub use types::{
-    AccessControlConfig, MembershipInfo, MultiSigConfig, PendingProposal, ProposalAction,
-    ProposalStats, ProposalType, UserRole,
+    AccessControlConfig, AuditAction, AuditEntry, AuditTarget, AuditValue, ContractCall,
+    EmergencyState, MembershipInfo, MembershipRecord, MembershipStatus, MultiSigConfig,
+    PendingProposal, ProposalAction, ProposalBatchResult, ProposalExecution, ProposalMetadata,
+    ProposalProgress, ProposalStats, ProposalType, ProposalVeto, SessionGrant, SessionPermission,
+    UserRole,
 };
 
 #[contract]
@@ -21,12 +26,17 @@ pub struct AccessControl;
 
 #[contractimpl]
 impl AccessControl {
-    pub fn initialize(env: Env, admin: Address) {
-        AccessControlModule::initialize(&env, admin, None).unwrap()
+    pub fn initialize(env: Env, admin: Address) -> Result<(), AccessControlError> {
+        AccessControlModule::initialize(&env, admin, None)
     }
 
-    pub fn set_role(env: Env, admin: Address, user: Address, role: UserRole) {
-        AccessControlModule::set_role(&env, admin, user, role).unwrap()
+    pub fn set_role(
+        env: Env,
+        admin: Address,
+        user: Address,
+        role: UserRole,
+    ) -> Result<(), AccessControlError> {
+        AccessControlModule::set_role(&env, admin, user, role)
     }
 
     pub fn get_role(env: Env, user: Address) -> UserRole {
@@ -37,68 +47,111 @@ impl AccessControl {
         AccessControlModule::check_access(&env, user, required_role).unwrap_or(false)
     }
 
-    pub fn require_access(env: Env, user: Address, required_role: UserRole) {
-        AccessControlModule::require_access(&env, user, required_role).unwrap()
+    pub fn require_access(
+        env: Env,
+        user: Address,
+        required_role: UserRole,
+    ) -> Result<(), AccessControlError> {
+        AccessControlModule::require_access(&env, user, required_role)
     }
 
     pub fn is_admin(env: Env, user: Address) -> bool {
         AccessControlModule::is_admin(&env, user)
     }
 
-    pub fn remove_role(env: Env, admin: Address, user: Address) {
-        AccessControlModule::remove_role(&env, admin, user).unwrap()
+    pub fn remove_role(env: Env, admin: Address, user: Address) -> Result<(), AccessControlError> {
+        AccessControlModule::remove_role(&env, admin, user)
     }
 
-    pub fn update_config(env: Env, admin: Address, config: AccessControlConfig) {
-        AccessControlModule::update_config(&env, admin, config).unwrap()
+    pub fn update_config(
+        env: Env,
+        admin: Address,
+        config: AccessControlConfig,
+    ) -> Result<(), AccessControlError> {
+        AccessControlModule::update_config(&env, admin, config)
     }
 
     pub fn get_config(env: Env) -> AccessControlConfig {
         AccessControlModule::get_config(&env)
     }
 
-    pub fn pause(env: Env, admin: Address) {
-        AccessControlModule::pause(&env, admin).unwrap()
+    pub fn pause(env: Env, admin: Address) -> Result<(), AccessControlError> {
+        AccessControlModule::pause(&env, admin)
     }
 
-    pub fn unpause(env: Env, admin: Address) {
-        AccessControlModule::unpause(&env, admin).unwrap()
+    pub fn unpause(env: Env, admin: Address) -> Result<(), AccessControlError> {
+        AccessControlModule::unpause(&env, admin)
     }
 
-    pub fn blacklist_user(env: Env, admin: Address, user: Address) {
-        AccessControlModule::blacklist_user(&env, admin, user).unwrap()
+    pub fn blacklist_user(
+        env: Env,
+        admin: Address,
+        user: Address,
+    ) -> Result<(), AccessControlError> {
+        AccessControlModule::blacklist_user(&env, admin, user)
     }
 
-    pub fn unblacklist_user(env: Env, admin: Address, user: Address) {
-        AccessControlModule::unblacklist_user(&env, admin, user).unwrap()
+    pub fn unblacklist_user(
+        env: Env,
+        admin: Address,
+        user: Address,
+    ) -> Result<(), AccessControlError> {
+        AccessControlModule::unblacklist_user(&env, admin, user)
     }
 
     pub fn is_blacklisted(env: Env, user: Address) -> bool {
         AccessControlModule::is_blacklisted(&env, &user)
     }
 
-    pub fn propose_admin_transfer(env: Env, current_admin: Address, new_admin: Address) {
-        AccessControlModule::propose_admin_transfer(&env, current_admin, new_admin).unwrap()
+    pub fn propose_admin_transfer(
+        env: Env,
+        current_admin: Address,
+        new_admin: Address,
+    ) -> Result<(), AccessControlError> {
+        AccessControlModule::propose_admin_transfer(&env, current_admin, new_admin)
     }
 
-    pub fn accept_admin_transfer(env: Env, new_admin: Address) {
-        AccessControlModule::accept_admin_transfer(&env, new_admin).unwrap()
+    pub fn accept_admin_transfer(env: Env, new_admin: Address) -> Result<(), AccessControlError> {
+        AccessControlModule::accept_admin_transfer(&env, new_admin)
     }
 
-    pub fn cancel_admin_transfer(env: Env, current_admin: Address) {
-        AccessControlModule::cancel_admin_transfer(&env, current_admin).unwrap()
+    pub fn cancel_admin_transfer(
+        env: Env,
+        current_admin: Address,
+    ) -> Result<(), AccessControlError> {
+        AccessControlModule::cancel_admin_transfer(&env, current_admin)
     }
 
-    pub fn initialize_multisig(env: Env, admins: Vec<Address>, required_signatures: u32) {
-        AccessControlModule::initialize_multisig(&env, admins, required_signatures, None).unwrap()
+    pub fn initialize_multisig(
+        env: Env,
+        admins: Vec<Address>,
+        required_signatures: u32,
+    ) -> Result<(), AccessControlError> {
+        AccessControlModule::initialize_multisig(&env, admins, required_signatures, None)
     }
 
-    pub fn create_proposal(env: Env, proposer: Address, action: ProposalAction) -> u64 {
-        AccessControlModule::create_proposal(&env, proposer, action).unwrap()
+    pub fn create_proposal(
+        env: Env,
+        proposer: Address,
+        action: ProposalAction,
+    ) -> Result<u64, AccessControlError> {
+        AccessControlModule::create_proposal(&env, proposer, action)
     }
 
-    pub fn approve_proposal(env: Env, approver: Address, proposal_id: u64) {
-        AccessControlModule::approve_proposal(&env, approver, proposal_id).unwrap()
+    pub fn approve_proposal(
+        env: Env,
+        approver: Address,
+        proposal_id: u64,
+    ) -> Result<(), AccessControlError> {
+        AccessControlModule::approve_proposal(&env, approver, proposal_id)
+    }
+
+    pub fn approve_proposals(
+        env: Env,
+        approver: Address,
+        proposal_ids: Vec<u64>,
+    ) -> Result<Vec<ProposalBatchResult>, AccessControlError> {
+        AccessControlModule::approve_proposals(&env, approver, proposal_ids)
     }
 
     pub fn is_multisig_enabled(env: Env) -> bool {
@@ -135,12 +188,67 @@ impl AccessControl {
     // Enhanced Multisig Endpoints
     // ============================================================================
 
-    pub fn reject_proposal(env: Env, rejecter: Address, proposal_id: u64) {
-        AccessControlModule::reject_proposal(&env, rejecter, proposal_id).unwrap()
+    pub fn reject_proposal(
+        env: Env,
+        rejecter: Address,
+        proposal_id: u64,
+    ) -> Result<(), AccessControlError> {
+        AccessControlModule::reject_proposal(&env, rejecter, proposal_id)
+    }
+
+    pub fn reject_proposals(
+        env: Env,
+        rejecter: Address,
+        proposal_ids: Vec<u64>,
+    ) -> Result<Vec<ProposalBatchResult>, AccessControlError> {
+        AccessControlModule::reject_proposals(&env, rejecter, proposal_ids)
+    }
+
+    pub fn cancel_proposal(
+        env: Env,
+        proposer: Address,
+        proposal_id: u64,
+    ) -> Result<(), AccessControlError> {
+        AccessControlModule::cancel_proposal(&env, proposer, proposal_id)
+    }
+
+    pub fn veto_proposal(
+        env: Env,
+        vetoer: Address,
+        proposal_id: u64,
+        reason: String,
+    ) -> Result<(), AccessControlError> {
+        AccessControlModule::veto_proposal(&env, vetoer, proposal_id, reason)
+    }
+
+    pub fn get_proposal_veto(env: Env, proposal_id: u64) -> Option<ProposalVeto> {
+        AccessControlModule::get_proposal_veto(&env, proposal_id)
+    }
+
+    pub fn set_proposal_metadata(
+        env: Env,
+        proposer: Address,
+        proposal_id: u64,
+        title: String,
+        reference_hash: Option<BytesN<32>>,
+        category: Symbol,
+    ) -> Result<(), AccessControlError> {
+        AccessControlModule::set_proposal_metadata(
+            &env,
+            proposer,
+            proposal_id,
+            title,
+            reference_hash,
+            category,
+        )
+    }
+
+    pub fn get_proposal_metadata(env: Env, proposal_id: u64) -> Option<ProposalMetadata> {
+        AccessControlModule::get_proposal_metadata(&env, proposal_id)
     }
 
-    pub fn cancel_proposal(env: Env, proposer: Address, proposal_id: u64) {
-        AccessControlModule::cancel_proposal(&env, proposer, proposal_id).unwrap()
+    pub fn get_security_council(env: Env) -> Option<Address> {
+        AccessControlModule::get_security_council(&env)
     }
 
     pub fn get_proposal(env: Env, proposal_id: u64) -> Option<PendingProposal> {
@@ -155,6 +263,29 @@ impl AccessControl {
         AccessControlModule::get_proposal_stats(&env)
     }
 
+    /// Execute an approved proposal once its time-lock has passed. Anyone may
+    /// trigger execution; the approvals recorded on the proposal authorize it.
+    pub fn execute_proposal(env: Env, proposal_id: u64) -> Result<(), AccessControlError> {
+        AccessControlModule::execute_proposal(&env, proposal_id)
+    }
+
+    pub fn get_proposal_progress(
+        env: Env,
+        proposal_id: u64,
+    ) -> Result<ProposalProgress, AccessControlError> {
+        AccessControlModule::get_proposal_progress(&env, proposal_id)
+    }
+
+    pub fn get_admin_weight(env: Env, admin: Address) -> u32 {
+        AccessControlModule::get_multisig_config(&env)
+            .map(|config| config.weight_of(&admin))
+            .unwrap_or(0)
+    }
+
+    pub fn get_proposal_execution(env: Env, proposal_id: u64) -> Option<ProposalExecution> {
+        AccessControlModule::get_proposal_execution(&env, proposal_id)
+    }
+
     pub fn cleanup_expired_proposals(env: Env) -> u32 {
         AccessControlModule::cleanup_expired_proposals(&env).unwrap_or(0)
     }
@@ -163,7 +294,212 @@ impl AccessControl {
         AccessControlModule::is_emergency_mode(&env)
     }
 
-    pub fn deactivate_emergency_mode(env: Env, caller: Address) {
-        AccessControlModule::deactivate_emergency_mode(&env, caller).unwrap()
+    pub fn get_emergency_state(env: Env) -> Option<EmergencyState> {
+        AccessControlModule::get_emergency_state(&env)
+    }
+
+    pub fn activate_emergency_mode(
+        env: Env,
+        caller: Address,
+        reason: String,
+    ) -> Result<(), AccessControlError> {
+        AccessControlModule::activate_emergency_mode(&env, caller, reason)
+    }
+
+    pub fn deactivate_emergency_mode(env: Env, caller: Address) -> Result<(), AccessControlError> {
+        AccessControlModule::deactivate_emergency_mode(&env, caller)
+    }
+
+    pub fn expire_emergency_mode(env: Env) -> bool {
+        AccessControlModule::expire_emergency_mode(&env)
+    }
+
+    // ============================================================================
+    // Session Key Endpoints
+    // ============================================================================
+
+    pub fn issue_session_grant(
+        env: Env,
+        issuer: Address,
+        grantee: Address,
+        permissions: Vec<SessionPermission>,
+        expires_at: u64,
+    ) -> Result<(), AccessControlError> {
+        AccessControlModule::issue_session_grant(&env, issuer, grantee, permissions, expires_at)
+    }
+
+    pub fn revoke_session_grant(
+        env: Env,
+        caller: Address,
+        grantee: Address,
+    ) -> Result<(), AccessControlError> {
+        AccessControlModule::revoke_session_grant(&env, caller, grantee)
+    }
+
+    pub fn get_session_grant(env: Env, grantee: Address) -> Option<SessionGrant> {
+        AccessControlModule::get_session_grant(&env, grantee)
+    }
+
+    // ============================================================================
+    // Membership Registry Endpoints
+    // ============================================================================
+
+    pub fn sync_membership(
+        env: Env,
+        user: Address,
+        status: MembershipStatus,
+        expires_at: u64,
+    ) -> Result<(), AccessControlError> {
+        AccessControlModule::sync_membership(&env, user, status, expires_at)
+    }
+
+    pub fn get_membership(env: Env, user: Address) -> Option<MembershipRecord> {
+        AccessControlModule::get_membership(&env, user)
+    }
+
+    pub fn has_active_membership(env: Env, user: Address) -> bool {
+        AccessControlModule::has_active_membership(&env, &user)
+    }
+
+    pub fn cleanup_stale_memberships(env: Env, users: Vec<Address>) -> u32 {
+        AccessControlModule::cleanup_stale_memberships(&env, users)
+    }
+
+    // ============================================================================
+    // Audit Log Endpoints
+    // ============================================================================
+
+    /// Get up to `limit` audit entries starting at position `offset`, oldest first
+    pub fn get_audit_log(env: Env, offset: u64, limit: u32) -> Vec<AuditEntry> {
+        AuditLog::get_audit_log(&env, offset, limit)
+    }
+
+    /// Get up to `limit` of `actor`'s audit entries, skipping the first `offset`
+    pub fn get_audit_log_by_actor(
+        env: Env,
+        actor: Address,
+        offset: u64,
+        limit: u32,
+    ) -> Vec<AuditEntry> {
+        AuditLog::get_audit_log_by_actor(&env, actor, offset, limit)
+    }
+
+    pub fn get_audit_log_count(env: Env) -> u64 {
+        AuditLog::get_count(&env)
+    }
+}
+
+/// Panicking variants of the endpoints that return `AccessControlResult`,
+/// kept for Rust callers written against the earlier `()`-returning API.
+impl AccessControl {
+    pub fn initialize_or_panic(env: Env, admin: Address) {
+        Self::initialize(env, admin).unwrap()
+    }
+
+    pub fn set_role_or_panic(env: Env, admin: Address, user: Address, role: UserRole) {
+        Self::set_role(env, admin, user, role).unwrap()
+    }
+
+    pub fn require_access_or_panic(env: Env, user: Address, required_role: UserRole) {
+        Self::require_access(env, user, required_role).unwrap()
+    }
+
+    pub fn remove_role_or_panic(env: Env, admin: Address, user: Address) {
+        Self::remove_role(env, admin, user).unwrap()
+    }
+
+    pub fn update_config_or_panic(env: Env, admin: Address, config: AccessControlConfig) {
+        Self::update_config(env, admin, config).unwrap()
+    }
+
+    pub fn pause_or_panic(env: Env, admin: Address) {
+        Self::pause(env, admin).unwrap()
+    }
+
+    pub fn unpause_or_panic(env: Env, admin: Address) {
+        Self::unpause(env, admin).unwrap()
+    }
+
+    pub fn blacklist_user_or_panic(env: Env, admin: Address, user: Address) {
+        Self::blacklist_user(env, admin, user).unwrap()
+    }
+
+    pub fn unblacklist_user_or_panic(env: Env, admin: Address, user: Address) {
+        Self::unblacklist_user(env, admin, user).unwrap()
+    }
+
+    pub fn propose_admin_transfer_or_panic(env: Env, current_admin: Address, new_admin: Address) {
+        Self::propose_admin_transfer(env, current_admin, new_admin).unwrap()
+    }
+
+    pub fn accept_admin_transfer_or_panic(env: Env, new_admin: Address) {
+        Self::accept_admin_transfer(env, new_admin).unwrap()
+    }
+
+    pub fn cancel_admin_transfer_or_panic(env: Env, current_admin: Address) {
+        Self::cancel_admin_transfer(env, current_admin).unwrap()
+    }
+
+    pub fn initialize_multisig_or_panic(env: Env, admins: Vec<Address>, required_signatures: u32) {
+        Self::initialize_multisig(env, admins, required_signatures).unwrap()
+    }
+
+    pub fn create_proposal_or_panic(env: Env, proposer: Address, action: ProposalAction) -> u64 {
+        Self::create_proposal(env, proposer, action).unwrap()
+    }
+
+    pub fn approve_proposal_or_panic(env: Env, approver: Address, proposal_id: u64) {
+        Self::approve_proposal(env, approver, proposal_id).unwrap()
+    }
+
+    pub fn reject_proposal_or_panic(env: Env, rejecter: Address, proposal_id: u64) {
+        Self::reject_proposal(env, rejecter, proposal_id).unwrap()
+    }
+
+    pub fn veto_proposal_or_panic(env: Env, vetoer: Address, proposal_id: u64, reason: String) {
+        Self::veto_proposal(env, vetoer, proposal_id, reason).unwrap()
+    }
+
+    pub fn cancel_proposal_or_panic(env: Env, proposer: Address, proposal_id: u64) {
+        Self::cancel_proposal(env, proposer, proposal_id).unwrap()
+    }
+
+    pub fn execute_proposal_or_panic(env: Env, proposal_id: u64) {
+        Self::execute_proposal(env, proposal_id).unwrap()
+    }
+
+    pub fn get_proposal_progress_or_panic(env: Env, proposal_id: u64) -> ProposalProgress {
+        Self::get_proposal_progress(env, proposal_id).unwrap()
+    }
+
+    pub fn activate_emergency_mode_or_panic(env: Env, caller: Address, reason: String) {
+        Self::activate_emergency_mode(env, caller, reason).unwrap()
+    }
+
+    pub fn deactivate_emergency_mode_or_panic(env: Env, caller: Address) {
+        Self::deactivate_emergency_mode(env, caller).unwrap()
+    }
+
+    pub fn issue_session_grant_or_panic(
+        env: Env,
+        issuer: Address,
+        grantee: Address,
+        permissions: Vec<SessionPermission>,
+        expires_at: u64,
+    ) {
+        Self::issue_session_grant(env, issuer, grantee, permissions, expires_at).unwrap()
+    }
+
+    pub fn revoke_session_grant_or_panic(env: Env, caller: Address, grantee: Address) {
+        Self::revoke_session_grant(env, caller, grantee).unwrap()
+    }
+
+    pub fn sync_membership_or_panic(
+        env: Env,
+        user: Address,
+        status: MembershipStatus,
+        expires_at: u64,
+    ) {
+        Self::sync_membership(env, user, status, expires_at).unwrap()
     }
 }
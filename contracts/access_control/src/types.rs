@@ -1,4 +1,6 @@
-use soroban_sdk::{contracttype, Address, String, Vec};
+/// Membership status synced from the subscription contract.
+pub use common_types::MembershipStatus;
+use soroban_sdk::{contracttype, Address, Bytes, BytesN, Map, String, Symbol, Vec};
 
 /// User roles in the access control system
 /// Implements a hierarchical role system where Admin > Member > Guest
@@ -66,6 +68,31 @@ pub struct AccessControlConfig {
     pub subscription_contract: Option<Address>,
     /// Whether to enforce tier-based feature restrictions
     pub enforce_tier_restrictions: bool,
+    /// Whether Member and Admin access also requires an active membership
+    /// in the registry synced by the subscription contract
+    pub require_active_membership: bool,
+}
+
+/// Config layout written before the membership registry existed. Only
+/// decoded when an old config is read.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LegacyAccessControlConfig {
+    pub membership_token_contract: Option<Address>,
+    pub require_membership_for_roles: bool,
+    pub min_token_balance: i128,
+    pub subscription_contract: Option<Address>,
+    pub enforce_tier_restrictions: bool,
+}
+
+/// Registry entry for a user's membership, as last synced
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MembershipRecord {
+    pub user: Address,
+    pub status: MembershipStatus,
+    pub expires_at: u64,
+    pub updated_at: u64,
 }
 
 /// Subscription tier level for access control integration.
@@ -116,19 +143,38 @@ pub struct UserSubscriptionStatus {
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct MultiSigConfig {
     pub admins: Vec<Address>,
+    /// Voting weight per admin; admins without an entry weigh 1
+    pub admin_weights: Map<Address, u32>,
+    /// Approval weight required for standard operations
     pub required_signatures: u32,
-    /// Higher threshold for critical operations
+    /// Higher weight threshold for critical operations
     pub critical_threshold: u32,
-    /// Even higher threshold for emergency operations
+    /// Even higher weight threshold for emergency operations
     pub emergency_threshold: u32,
     /// Default time-lock duration in seconds (e.g., 24 hours)
     pub time_lock_duration: u64,
+    /// How long emergency mode stays active once activated, in seconds
+    pub emergency_duration: u64,
     /// Maximum number of pending proposals
     pub max_pending_proposals: u32,
     /// Proposal expiration duration in seconds
     pub proposal_expiry_duration: u64,
 }
 
+/// Multisig config layout written before weighted approvals and emergency
+/// mode existed. Only decoded when an old config is read.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LegacyMultiSigConfig {
+    pub admins: Vec<Address>,
+    pub required_signatures: u32,
+    pub critical_threshold: u32,
+    pub emergency_threshold: u32,
+    pub time_lock_duration: u64,
+    pub max_pending_proposals: u32,
+    pub proposal_expiry_duration: u64,
+}
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct PendingProposal {
@@ -143,10 +189,44 @@ pub struct PendingProposal {
     pub expiry: u64,
     /// For time-locked proposals: earliest execution time
     pub time_lock_until: Option<u64>,
-    /// Number of signatures required (can override default based on type)
+    /// Approval weight required (can override default based on type)
+    pub required_signatures: u32,
+    /// Set once the proposal has been vetoed; a vetoed proposal can no
+    /// longer be approved, rejected, cancelled or executed
+    pub vetoed: bool,
+}
+
+/// Proposal layout written before vetoes existed. Only decoded when an old
+/// proposal is read.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LegacyPendingProposal {
+    pub id: u64,
+    pub proposer: Address,
+    pub action: ProposalAction,
+    pub proposal_type: ProposalType,
+    pub approvals: Vec<Address>,
+    pub rejections: Vec<Address>,
+    pub executed: bool,
+    pub created_at: u64,
+    pub expiry: u64,
+    pub time_lock_until: Option<u64>,
     pub required_signatures: u32,
 }
 
+/// Approval progress of a proposal, weighted by the current admin weights
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProposalProgress {
+    pub proposal_id: u64,
+    pub approvals: u32,
+    pub approved_weight: u32,
+    pub required_weight: u32,
+    pub time_lock_until: Option<u64>,
+    /// Whether the proposal has enough weight and its time-lock has passed
+    pub executable: bool,
+}
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum ProposalType {
@@ -180,6 +260,40 @@ pub enum ProposalAction {
     ScheduleUpgrade(Address, u64),
     /// Emergency operation: Force admin transfer
     EmergencyAdminTransfer(Address),
+    /// Critical operation: Invoke a function on another contract
+    InvokeContract(ContractCall),
+    /// Critical operation: Set the voting weight of a multisig admin
+    SetAdminWeight(Address, u32),
+    /// Critical operation: Designate (or clear) the security council, which
+    /// may veto pending proposals
+    SetSecurityCouncil(Option<Address>),
+    /// Emergency operation: Enter emergency mode with reason
+    ActivateEmergencyMode(String),
+    /// Standard operation: Leave emergency mode before it expires
+    DeactivateEmergencyMode,
+}
+
+/// Cross-contract call carried by an `InvokeContract` proposal
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ContractCall {
+    /// Contract to invoke
+    pub contract: Address,
+    /// Function to call on the target contract
+    pub function: Symbol,
+    /// XDR-encoded `Vec<Val>` of call arguments
+    pub args: Bytes,
+}
+
+/// Outcome of executing an `InvokeContract` proposal
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProposalExecution {
+    pub proposal_id: u64,
+    pub success: bool,
+    /// Error code reported by the target, if the call failed with one
+    pub error_code: Option<u32>,
+    pub executed_at: u64,
 }
 
 #[contracttype]
@@ -190,10 +304,97 @@ pub struct PendingAdminTransfer {
     pub expiry: u64,
 }
 
+/// Action a session key may perform on behalf of the admin that issued it
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum SessionPermission {
+    ApproveProposal,
+    RejectProposal,
+    SetRole,
+    RemoveRole,
+}
+
+/// Time-boxed delegation of selected admin actions to another address
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SessionGrant {
+    pub grantee: Address,
+    /// Admin on whose behalf the grantee acts
+    pub issuer: Address,
+    pub permissions: Vec<SessionPermission>,
+    pub created_at: u64,
+    pub expires_at: u64,
+}
+
+/// Kind of change recorded in the audit log
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum AuditAction {
+    RoleSet,
+    RoleRemoved,
+    Blacklisted,
+    Unblacklisted,
+    ConfigUpdated,
+    ProposalCreated,
+    ProposalApproved,
+    ProposalRejected,
+    ProposalExecuted,
+    ProposalCancelled,
+    ProposalExpired,
+    ProposalVetoed,
+}
+
+/// What an audit entry refers to
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum AuditTarget {
+    Account(Address),
+    Proposal(u64),
+    Config,
+}
+
+/// Value of the audited state before or after a change
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum AuditValue {
+    None,
+    Role(UserRole),
+    Blacklisted(bool),
+    Config(AccessControlConfig),
+    /// Number of approvals on a proposal
+    Approvals(u32),
+}
+
+/// Single entry in the audit log
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AuditEntry {
+    pub id: u64,
+    pub action: AuditAction,
+    pub actor: Address,
+    pub target: AuditTarget,
+    pub before: AuditValue,
+    pub after: AuditValue,
+    pub timestamp: u64,
+}
+
 /// Proposal statistics for tracking and analytics
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct ProposalStats {
+    pub total_created: u64,
+    pub total_executed: u64,
+    pub total_rejected: u64,
+    pub total_expired: u64,
+    pub total_vetoed: u64,
+    pub pending_count: u32,
+}
+
+/// Proposal statistics layout written before vetoes existed. Only decoded
+/// when old statistics are read.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LegacyProposalStats {
     pub total_created: u64,
     pub total_executed: u64,
     pub total_rejected: u64,
@@ -201,6 +402,50 @@ pub struct ProposalStats {
     pub pending_count: u32,
 }
 
+/// Record of a veto that stopped a proposal before execution
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProposalVeto {
+    pub vetoed_by: Address,
+    pub reason: String,
+    pub vetoed_at: u64,
+}
+
+/// Outcome for one proposal in a batch approval or rejection
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProposalBatchResult {
+    pub proposal_id: u64,
+    /// Whether the vote was recorded
+    pub applied: bool,
+    /// `AccessControlError` code explaining why the proposal was skipped
+    pub error_code: Option<u32>,
+}
+
+/// Off-chain discussion details attached to a proposal, so signers can check
+/// what they are approving against the referenced document
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProposalMetadata {
+    pub title: String,
+    /// Hash of the external proposal document (e.g. an IPFS CID digest)
+    pub reference_hash: Option<BytesN<32>>,
+    pub category: Symbol,
+    pub updated_at: u64,
+}
+
+/// Active emergency mode window. While it lasts, pause-type proposals skip
+/// their time-lock; nothing else is affected.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EmergencyState {
+    pub reason: String,
+    /// Admin that activated it, or the proposer of the activating proposal
+    pub activated_by: Address,
+    pub activated_at: u64,
+    pub expires_at: u64,
+}
+
 impl ProposalType {
     /// Determine if this proposal type requires time-lock
     pub fn requires_time_lock(&self) -> bool {
@@ -234,9 +479,22 @@ impl ProposalAction {
             ProposalAction::BatchBlacklist(_) => ProposalType::Critical,
             ProposalAction::ScheduleUpgrade(_, _) => ProposalType::TimeLocked,
             ProposalAction::EmergencyAdminTransfer(_) => ProposalType::Emergency,
+            ProposalAction::InvokeContract(_) => ProposalType::Critical,
+            ProposalAction::SetAdminWeight(_, _) => ProposalType::Critical,
+            ProposalAction::SetSecurityCouncil(_) => ProposalType::Critical,
+            ProposalAction::ActivateEmergencyMode(_) => ProposalType::Emergency,
+            ProposalAction::DeactivateEmergencyMode => ProposalType::Standard,
         }
     }
 
+    /// Check if this action pauses the contract
+    pub fn is_pause_type(&self) -> bool {
+        matches!(
+            self,
+            ProposalAction::Pause | ProposalAction::EmergencyPause(_)
+        )
+    }
+
     /// Check if this action is reversible
     pub fn is_reversible(&self) -> bool {
         matches!(
@@ -254,10 +512,12 @@ impl MultiSigConfig {
     pub fn default_config() -> Self {
         MultiSigConfig {
             admins: Vec::new(&soroban_sdk::Env::default()),
+            admin_weights: Map::new(&soroban_sdk::Env::default()),
             required_signatures: 2,
             critical_threshold: 3,
             emergency_threshold: 4,
             time_lock_duration: 86400, // 24 hours
+            emergency_duration: 86400, // 24 hours
             max_pending_proposals: 50,
             proposal_expiry_duration: 604800, // 7 days
         }
@@ -265,18 +525,47 @@ impl MultiSigConfig {
 
     /// Validate configuration parameters
     pub fn validate(&self) -> bool {
+        let weights_valid = self
+            .admin_weights
+            .iter()
+            .all(|(admin, weight)| weight > 0 && self.admins.contains(&admin));
+
         !self.admins.is_empty()
+            && weights_valid
             && self.required_signatures > 0
-            && self.required_signatures <= self.admins.len()
+            && self.required_signatures <= self.total_weight()
             && self.critical_threshold >= self.required_signatures
             && self.emergency_threshold >= self.critical_threshold
-            && self.emergency_threshold <= self.admins.len()
+            && self.emergency_threshold <= self.total_weight()
             && self.time_lock_duration > 0
+            && self.emergency_duration > 0
             && self.max_pending_proposals > 0
             && self.proposal_expiry_duration > 0
     }
 
-    /// Get required signatures for a specific proposal type
+    /// Get the voting weight of an admin (0 for non-admins)
+    pub fn weight_of(&self, admin: &Address) -> u32 {
+        if !self.admins.contains(admin) {
+            return 0;
+        }
+        self.admin_weights.get(admin.clone()).unwrap_or(1)
+    }
+
+    /// Get the combined weight of all admins
+    pub fn total_weight(&self) -> u32 {
+        self.admins.iter().fold(0u32, |total, admin| {
+            total.saturating_add(self.weight_of(&admin))
+        })
+    }
+
+    /// Get the combined weight of the given approvers
+    pub fn approved_weight(&self, approvals: &Vec<Address>) -> u32 {
+        approvals.iter().fold(0u32, |total, admin| {
+            total.saturating_add(self.weight_of(&admin))
+        })
+    }
+
+    /// Get required approval weight for a specific proposal type
     pub fn get_required_signatures(&self, proposal_type: &ProposalType) -> u32 {
         match proposal_type {
             ProposalType::Standard => self.required_signatures,
@@ -0,0 +1,132 @@
+//! Checked token amounts and basis points.
+//!
+//! Contracts pass amounts around as raw `i128`, where a stray negative value
+//! or an unchecked multiplication goes unnoticed. `Amount` only holds
+//! non-negative values and every operation on it is checked; `Bps` only holds
+//! rates between 0 and 100 %. Both are plain helpers for arithmetic and are
+//! converted back to `i128` / `u32` at storage and interface boundaries.
+
+/// Denominator of a basis-point rate (100 %).
+pub const BPS_DENOMINATOR: u32 = 10_000;
+
+/// Non-negative amount in an asset's smallest unit.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Ord, PartialOrd)]
+pub struct Amount(i128);
+
+impl Amount {
+    pub const ZERO: Amount = Amount(0);
+
+    /// Wraps a raw amount; `None` if it is negative.
+    pub fn new(value: i128) -> Option<Self> {
+        (value >= 0).then_some(Amount(value))
+    }
+
+    pub fn value(self) -> i128 {
+        self.0
+    }
+
+    pub fn is_zero(self) -> bool {
+        self.0 == 0
+    }
+
+    pub fn checked_add(self, other: Amount) -> Option<Self> {
+        self.0.checked_add(other.0).map(Amount)
+    }
+
+    /// `None` on underflow below zero.
+    pub fn checked_sub(self, other: Amount) -> Option<Self> {
+        Self::new(self.0.checked_sub(other.0)?)
+    }
+
+    /// `bps` of this amount, rounded down.
+    pub fn mul_bps(self, bps: Bps) -> Option<Self> {
+        self.mul_div(bps.0 as i128, BPS_DENOMINATOR as i128)
+    }
+
+    /// This amount less `bps` of it, i.e. the price after a `bps` discount.
+    pub fn discount(self, bps: Bps) -> Option<Self> {
+        self.checked_sub(self.mul_bps(bps)?)
+    }
+
+    /// `self * numerator / denominator`, rounded down. `None` on overflow,
+    /// a negative numerator or a non-positive denominator.
+    pub fn mul_div(self, numerator: i128, denominator: i128) -> Option<Self> {
+        if numerator < 0 || denominator <= 0 {
+            return None;
+        }
+        Self::new(self.0.checked_mul(numerator)? / denominator)
+    }
+
+    /// Like `mul_div`, but rounded up.
+    pub fn mul_div_ceil(self, numerator: i128, denominator: i128) -> Option<Self> {
+        if numerator < 0 || denominator <= 0 {
+            return None;
+        }
+        let product = self.0.checked_mul(numerator)?;
+        Self::new(product.checked_add(denominator - 1)? / denominator)
+    }
+}
+
+/// Rate in basis points, from 0 to 10_000 (100 %).
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Ord, PartialOrd)]
+pub struct Bps(u32);
+
+impl Bps {
+    pub const ZERO: Bps = Bps(0);
+    pub const MAX: Bps = Bps(BPS_DENOMINATOR);
+
+    /// `None` if `bps` exceeds 100 %.
+    pub fn new(bps: u32) -> Option<Self> {
+        (bps <= BPS_DENOMINATOR).then_some(Bps(bps))
+    }
+
+    /// `None` if `percent` exceeds 100.
+    pub fn from_percent(percent: u32) -> Option<Self> {
+        Self::new(percent.checked_mul(100)?)
+    }
+
+    pub fn value(self) -> u32 {
+        self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn amount(value: i128) -> Amount {
+        Amount::new(value).unwrap()
+    }
+
+    #[test]
+    fn test_amount_rejects_negative_values() {
+        assert_eq!(Amount::new(-1), None);
+        assert_eq!(amount(5).checked_sub(amount(6)), None);
+        assert_eq!(amount(5).checked_sub(amount(5)), Some(Amount::ZERO));
+        assert_eq!(amount(i128::MAX).checked_add(amount(1)), None);
+    }
+
+    #[test]
+    fn test_bps_bounds() {
+        assert_eq!(Bps::new(10_001), None);
+        assert_eq!(Bps::from_percent(101), None);
+        assert_eq!(Bps::from_percent(25), Bps::new(2_500));
+        assert_eq!(Bps::from_percent(u32::MAX), None);
+    }
+
+    #[test]
+    fn test_bps_and_ratio_math() {
+        let price = amount(999);
+        assert_eq!(price.mul_bps(Bps::new(1_000).unwrap()), Some(amount(99)));
+        assert_eq!(
+            price.discount(Bps::from_percent(10).unwrap()),
+            Some(amount(900))
+        );
+        assert_eq!(price.discount(Bps::MAX), Some(Amount::ZERO));
+        assert_eq!(price.mul_div(1, 3), Some(amount(333)));
+        assert_eq!(price.mul_div_ceil(1, 2), Some(amount(500)));
+        assert_eq!(price.mul_div(1, 0), None);
+        assert_eq!(price.mul_div(-1, 2), None);
+        assert_eq!(amount(i128::MAX).mul_bps(Bps::MAX), None);
+    }
+}
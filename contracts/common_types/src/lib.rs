@@ -5,16 +5,20 @@
 //! This crate provides shared enums and structs to ensure consistency
 //! across all ManageHub smart contracts.
 
+mod amount;
 mod types;
+mod versioning;
 
 // Re-export all types
+pub use amount::{Amount, Bps, BPS_DENOMINATOR};
 pub use types::{
     validate_attribute, validate_metadata, AttendanceAction, AttendanceFrequency, DateRange,
     DayPattern, MembershipStatus, MetadataUpdate, MetadataValue, PeakHourData, SubscriptionPlan,
     SubscriptionTier, TierChangeRequest, TierChangeStatus, TierChangeType, TierFeature, TierLevel,
     TierPromotion, TimePeriod, TokenMetadata, UserAttendanceStats, UserRole, MAX_ATTRIBUTES_COUNT,
-    MAX_ATTRIBUTE_KEY_LENGTH, MAX_DESCRIPTION_LENGTH, MAX_TEXT_VALUE_LENGTH,
+    MAX_ATTRIBUTE_KEY_LENGTH, MAX_DESCRIPTION_LENGTH, MAX_TEXT_VALUE_LENGTH, MAX_TOKEN_URI_LENGTH,
 };
+pub use versioning::{TokenMetadataV1, TokenMetadataV2, VersionedTokenMetadata};
 
 #[cfg(test)]
 mod test_contract;
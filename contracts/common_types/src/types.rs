@@ -4,7 +4,7 @@
 //! across all ManageHub smart contracts, including subscription management,
 //! attendance tracking, and user role definitions.
 
-use soroban_sdk::{contracttype, Address, Map, String, Vec};
+use soroban_sdk::{contracttype, Address, BytesN, Map, String, Vec};
 
 // ============================================================================
 // Metadata Types for Token Metadata System
@@ -22,6 +22,9 @@ pub const MAX_ATTRIBUTE_KEY_LENGTH: u32 = 50;
 /// Maximum length for text attribute values
 pub const MAX_TEXT_VALUE_LENGTH: u32 = 200;
 
+/// Maximum length for a token-bound content URI
+pub const MAX_TOKEN_URI_LENGTH: u32 = 256;
+
 /// Represents different types of metadata values that can be stored.
 ///
 /// This enum provides flexibility in storing various data types as metadata
@@ -56,6 +59,8 @@ pub enum MetadataValue {
 /// * `version` - Current version number (increments on updates)
 /// * `last_updated` - Timestamp of last metadata update
 /// * `updated_by` - Address of user who last updated metadata
+/// * `token_uri` - Off-chain content URI (empty when unset)
+/// * `content_hash` - SHA-256 hash of the content behind `token_uri`
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct TokenMetadata {
@@ -69,6 +74,10 @@ pub struct TokenMetadata {
     pub last_updated: u64,
     /// Address of last updater
     pub updated_by: Address,
+    /// Off-chain content URI
+    pub token_uri: String,
+    /// Hash of the off-chain content, if a URI has been bound
+    pub content_hash: Option<BytesN<32>>,
 }
 
 /// Metadata update history entry for versioning and audit trail.
@@ -559,12 +568,18 @@ pub enum TierChangeStatus {
 /// - Attributes count must not exceed MAX_ATTRIBUTES_COUNT
 /// - Each attribute key must not exceed MAX_ATTRIBUTE_KEY_LENGTH
 /// - Text values must not exceed MAX_TEXT_VALUE_LENGTH
+/// - Token URI length must not exceed MAX_TOKEN_URI_LENGTH
 pub fn validate_metadata(metadata: &TokenMetadata) -> Result<(), &'static str> {
     // Validate description length
     if metadata.description.len() > MAX_DESCRIPTION_LENGTH {
         return Err("Description exceeds maximum length");
     }
 
+    // Validate token URI length
+    if metadata.token_uri.len() > MAX_TOKEN_URI_LENGTH {
+        return Err("Token URI exceeds maximum length");
+    }
+
     // Validate attributes count
     if metadata.attributes.len() > MAX_ATTRIBUTES_COUNT {
         return Err("Too many attributes");
@@ -0,0 +1,208 @@
+//! Versioned layouts of persisted shared structs.
+//!
+//! Soroban decodes a stored struct by its exact field set, so an entry written
+//! before a field was added no longer deserializes into the current type. Each
+//! past layout is kept here as a frozen struct, wrapped in a versioned enum
+//! that can recognise a raw stored value and upgrade it step by step to the
+//! current layout.
+
+use soroban_sdk::{contracttype, Address, Env, Map, String, Symbol, TryFromVal, Val};
+
+use crate::types::{MetadataValue, TokenMetadata};
+
+/// `TokenMetadata` as first released, before updater tracking.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TokenMetadataV1 {
+    pub description: String,
+    pub attributes: Map<String, MetadataValue>,
+    pub version: u32,
+    pub last_updated: u64,
+}
+
+/// `TokenMetadata` with the last updater, before content URI binding.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TokenMetadataV2 {
+    pub description: String,
+    pub attributes: Map<String, MetadataValue>,
+    pub version: u32,
+    pub last_updated: u64,
+    pub updated_by: Address,
+}
+
+/// Token metadata in any layout it has been persisted with.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum VersionedTokenMetadata {
+    V1(TokenMetadataV1),
+    V2(TokenMetadataV2),
+    V3(TokenMetadata),
+}
+
+impl VersionedTokenMetadata {
+    /// Layout version of `TokenMetadata` in this build.
+    pub const CURRENT_VERSION: u32 = 3;
+
+    /// Layout version of the wrapped value.
+    pub fn version(&self) -> u32 {
+        match self {
+            VersionedTokenMetadata::V1(_) => 1,
+            VersionedTokenMetadata::V2(_) => 2,
+            VersionedTokenMetadata::V3(_) => 3,
+        }
+    }
+
+    /// Whether the wrapped value already has the current layout.
+    pub fn is_current(&self) -> bool {
+        self.version() == Self::CURRENT_VERSION
+    }
+
+    /// Recognises a raw stored value by the fields its layout introduced.
+    /// Returns `None` if the value is not a metadata struct at all.
+    pub fn from_stored(env: &Env, value: &Val) -> Option<Self> {
+        let fields = Map::<Symbol, Val>::try_from_val(env, value).ok()?;
+        if fields.contains_key(Symbol::new(env, "content_hash")) {
+            TokenMetadata::try_from_val(env, value)
+                .ok()
+                .map(VersionedTokenMetadata::V3)
+        } else if fields.contains_key(Symbol::new(env, "updated_by")) {
+            TokenMetadataV2::try_from_val(env, value)
+                .ok()
+                .map(VersionedTokenMetadata::V2)
+        } else {
+            TokenMetadataV1::try_from_val(env, value)
+                .ok()
+                .map(VersionedTokenMetadata::V1)
+        }
+    }
+
+    /// Upgrades the wrapped value to the current layout.
+    ///
+    /// `fallback_updater` stands in for the updater of V1 entries, which did
+    /// not record one (usually the token owner). Entries from before content
+    /// URI binding come out with no URI and no content hash.
+    pub fn upgrade(self, env: &Env, fallback_updater: &Address) -> TokenMetadata {
+        match self {
+            VersionedTokenMetadata::V1(v1) => VersionedTokenMetadata::V2(TokenMetadataV2 {
+                description: v1.description,
+                attributes: v1.attributes,
+                version: v1.version,
+                last_updated: v1.last_updated,
+                updated_by: fallback_updater.clone(),
+            })
+            .upgrade(env, fallback_updater),
+            VersionedTokenMetadata::V2(v2) => TokenMetadata {
+                description: v2.description,
+                attributes: v2.attributes,
+                version: v2.version,
+                last_updated: v2.last_updated,
+                updated_by: v2.updated_by,
+                token_uri: String::from_str(env, ""),
+                content_hash: None,
+            },
+            VersionedTokenMetadata::V3(current) => current,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_contract::TestTypesContract;
+    use soroban_sdk::{testutils::Address as _, BytesN, IntoVal};
+
+    fn attributes(env: &Env) -> Map<String, MetadataValue> {
+        let mut attributes = Map::new(env);
+        attributes.set(
+            String::from_str(env, "level"),
+            MetadataValue::Text(String::from_str(env, "gold")),
+        );
+        attributes
+    }
+
+    fn v1_fixture(env: &Env) -> TokenMetadataV1 {
+        TokenMetadataV1 {
+            description: String::from_str(env, "Founding member"),
+            attributes: attributes(env),
+            version: 4,
+            last_updated: 1_000,
+        }
+    }
+
+    fn v2_fixture(env: &Env, updater: &Address) -> TokenMetadataV2 {
+        TokenMetadataV2 {
+            description: String::from_str(env, "Founding member"),
+            attributes: attributes(env),
+            version: 5,
+            last_updated: 2_000,
+            updated_by: updater.clone(),
+        }
+    }
+
+    fn v3_fixture(env: &Env, updater: &Address) -> TokenMetadata {
+        TokenMetadata {
+            description: String::from_str(env, "Founding member"),
+            attributes: attributes(env),
+            version: 6,
+            last_updated: 3_000,
+            updated_by: updater.clone(),
+            token_uri: String::from_str(env, "ipfs://token"),
+            content_hash: Some(BytesN::from_array(env, &[1; 32])),
+        }
+    }
+
+    /// Writes a fixture the way an older build would have, then reads it back
+    /// untyped, as a current build finds it in storage.
+    fn store_and_load(env: &Env, fixture: Val) -> Val {
+        let contract_id = env.register(TestTypesContract, ());
+        env.as_contract(&contract_id, || {
+            let key = Symbol::new(env, "metadata");
+            env.storage().persistent().set(&key, &fixture);
+            env.storage().persistent().get::<_, Val>(&key).unwrap()
+        })
+    }
+
+    #[test]
+    fn test_recognises_each_layout() {
+        let env = Env::default();
+        let updater = Address::generate(&env);
+
+        let fixtures: [Val; 3] = [
+            v1_fixture(&env).into_val(&env),
+            v2_fixture(&env, &updater).into_val(&env),
+            v3_fixture(&env, &updater).into_val(&env),
+        ];
+        for (index, fixture) in fixtures.into_iter().enumerate() {
+            let stored = store_and_load(&env, fixture);
+            let versioned = VersionedTokenMetadata::from_stored(&env, &stored).unwrap();
+            assert_eq!(versioned.version(), index as u32 + 1);
+        }
+
+        let unrelated = store_and_load(&env, 7u32.into_val(&env));
+        assert!(VersionedTokenMetadata::from_stored(&env, &unrelated).is_none());
+    }
+
+    #[test]
+    fn test_upgrade_to_current_layout() {
+        let env = Env::default();
+        let owner = Address::generate(&env);
+        let updater = Address::generate(&env);
+
+        let from_v1 = VersionedTokenMetadata::V1(v1_fixture(&env)).upgrade(&env, &owner);
+        assert_eq!(from_v1.version, 4);
+        assert_eq!(from_v1.attributes, attributes(&env));
+        assert_eq!(from_v1.updated_by, owner);
+        assert_eq!(from_v1.token_uri, String::from_str(&env, ""));
+        assert_eq!(from_v1.content_hash, None);
+
+        let from_v2 = VersionedTokenMetadata::V2(v2_fixture(&env, &updater)).upgrade(&env, &owner);
+        assert_eq!(from_v2.updated_by, updater);
+        assert_eq!(from_v2.last_updated, 2_000);
+
+        let current = v3_fixture(&env, &updater);
+        let versioned = VersionedTokenMetadata::V3(current.clone());
+        assert!(versioned.is_current());
+        assert_eq!(versioned.upgrade(&env, &owner), current);
+    }
+}
@@ -0,0 +1,17 @@
+//! Achievement-related error types for the ManageHub contract.
+//!
+//! `AchievementError` owns the code range 400–449 (see [`crate::errors`]).
+
+use soroban_sdk::contracterror;
+
+/// Achievement-specific errors.
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum AchievementError {
+    /// No badge is defined with the given ID, or it is inactive.
+    BadgeNotFound = 400,
+    /// The member already holds the badge.
+    BadgeAlreadyAwarded = 401,
+    /// The member does not meet the badge's criterion.
+    CriterionNotMet = 402,
+}
@@ -0,0 +1,266 @@
+use crate::achievement_errors::AchievementError;
+use crate::errors::{Error, ModuleError};
+use crate::events::{BadgeAwarded, BadgeDefined};
+use crate::loyalty::LoyaltyModule;
+use crate::membership_token::{
+    DataKey as MembershipDataKey, MembershipToken, MembershipTokenContract,
+};
+use crate::staking::StakingModule;
+use crate::types::{Badge, BadgeCriterion, BadgeDefinition};
+use common_types::MetadataValue;
+use soroban_sdk::{contracttype, Address, BytesN, Env, String, Vec};
+
+// ---------------------------------------------------------------------------
+// Storage keys
+// ---------------------------------------------------------------------------
+
+#[contracttype]
+pub enum AchievementDataKey {
+    /// Badge definition by ID (persistent storage).
+    Definition(String),
+    /// IDs of all badge definitions (instance storage).
+    DefinitionList,
+    /// Badge held by a member, keyed by (user, badge_id) (persistent storage).
+    Badge(Address, String),
+    /// IDs of the badges a member holds, in award order (persistent storage).
+    UserBadges(Address),
+}
+
+// ---------------------------------------------------------------------------
+// Constants
+// ---------------------------------------------------------------------------
+
+const SECONDS_PER_DAY: u64 = 24 * 60 * 60;
+
+// ---------------------------------------------------------------------------
+// Module
+// ---------------------------------------------------------------------------
+
+/// Milestone badges.
+///
+/// Badges are soulbound records keyed by `(user, badge_id)`: there is no
+/// transfer path, and once earned they are only ever read. Members claim a
+/// badge once they meet its admin-defined criterion; `Manual` badges are
+/// granted by the admin. When a membership token is supplied, the badge is
+/// also written to the token's metadata as a `Timestamp` attribute keyed by
+/// the badge ID.
+pub struct AchievementModule;
+
+impl AchievementModule {
+    // -----------------------------------------------------------------------
+    // Definitions
+    // -----------------------------------------------------------------------
+
+    /// Adds or replaces a badge definition. Admin only.
+    pub fn define_badge(env: Env, admin: Address, badge: BadgeDefinition) -> Result<(), Error> {
+        MembershipTokenContract::require_admin(&env, &admin)?;
+
+        let mut list = Self::definition_ids(&env);
+        if !list.contains(&badge.id) {
+            list.push_back(badge.id.clone());
+            env.storage()
+                .instance()
+                .set(&AchievementDataKey::DefinitionList, &list);
+        }
+        env.storage()
+            .persistent()
+            .set(&AchievementDataKey::Definition(badge.id.clone()), &badge);
+
+        BadgeDefined {
+            badge_id: badge.id.clone(),
+            badge,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    pub fn get_badge_definitions(env: &Env) -> Vec<BadgeDefinition> {
+        let mut definitions = Vec::new(env);
+        for id in Self::definition_ids(env).iter() {
+            if let Some(badge) = env
+                .storage()
+                .persistent()
+                .get(&AchievementDataKey::Definition(id))
+            {
+                definitions.push_back(badge);
+            }
+        }
+        definitions
+    }
+
+    fn definition_ids(env: &Env) -> Vec<String> {
+        env.storage()
+            .instance()
+            .get(&AchievementDataKey::DefinitionList)
+            .unwrap_or(Vec::new(env))
+    }
+
+    fn active_definition(env: &Env, badge_id: &String) -> Result<BadgeDefinition, ModuleError> {
+        env.storage()
+            .persistent()
+            .get(&AchievementDataKey::Definition(badge_id.clone()))
+            .filter(|badge: &BadgeDefinition| badge.is_active)
+            .ok_or(AchievementError::BadgeNotFound.into())
+    }
+
+    // -----------------------------------------------------------------------
+    // Awarding
+    // -----------------------------------------------------------------------
+
+    /// Awards a badge whose criterion the member meets.
+    ///
+    /// `token_id`, if given, must be the member's token; it is used for
+    /// `MembershipDays` criteria and receives the badge as a metadata attribute.
+    pub fn claim_badge(
+        env: Env,
+        user: Address,
+        badge_id: String,
+        token_id: Option<BytesN<32>>,
+    ) -> Result<Badge, ModuleError> {
+        user.require_auth();
+
+        let definition = Self::active_definition(&env, &badge_id)?;
+        let token = match token_id {
+            Some(id) => Some(Self::owned_token(&env, &user, id)?),
+            None => None,
+        };
+        if !Self::meets_criterion(&env, &user, &definition.criterion, token.as_ref()) {
+            return Err(AchievementError::CriterionNotMet.into());
+        }
+
+        Self::award(&env, &user, &badge_id, token.as_ref())
+    }
+
+    /// Grants a badge regardless of its criterion. Admin only.
+    pub fn grant_badge(
+        env: Env,
+        admin: Address,
+        user: Address,
+        badge_id: String,
+        token_id: Option<BytesN<32>>,
+    ) -> Result<Badge, ModuleError> {
+        MembershipTokenContract::require_admin(&env, &admin)?;
+
+        Self::active_definition(&env, &badge_id)?;
+        let token = match token_id {
+            Some(id) => Some(Self::owned_token(&env, &user, id)?),
+            None => None,
+        };
+
+        Self::award(&env, &user, &badge_id, token.as_ref())
+    }
+
+    fn owned_token(
+        env: &Env,
+        user: &Address,
+        token_id: BytesN<32>,
+    ) -> Result<MembershipToken, Error> {
+        let token: MembershipToken = env
+            .storage()
+            .persistent()
+            .get(&MembershipDataKey::Token(token_id))
+            .ok_or(Error::TokenNotFound)?;
+        if token.user != *user {
+            return Err(Error::Unauthorized);
+        }
+        Ok(token)
+    }
+
+    fn meets_criterion(
+        env: &Env,
+        user: &Address,
+        criterion: &BadgeCriterion,
+        token: Option<&MembershipToken>,
+    ) -> bool {
+        match criterion {
+            BadgeCriterion::VerifiedSessions(count) => {
+                LoyaltyModule::get_account(env, user).verified_sessions >= *count
+            }
+            BadgeCriterion::AttendanceStreak(days) => {
+                LoyaltyModule::get_streak(env, user).longest >= *days
+            }
+            BadgeCriterion::MembershipDays(days) => token.is_some_and(|token| {
+                env.ledger().timestamp().saturating_sub(token.issue_date)
+                    >= days.saturating_mul(SECONDS_PER_DAY)
+            }),
+            BadgeCriterion::StakingTier(tier_id) => {
+                StakingModule::get_stake_info(env.clone(), user.clone()).is_some_and(|stake| {
+                    stake.tier_id == *tier_id && stake.amount > 0 && !stake.emergency_unstaked
+                })
+            }
+            BadgeCriterion::Manual => false,
+        }
+    }
+
+    fn award(
+        env: &Env,
+        user: &Address,
+        badge_id: &String,
+        token: Option<&MembershipToken>,
+    ) -> Result<Badge, ModuleError> {
+        let key = AchievementDataKey::Badge(user.clone(), badge_id.clone());
+        if env.storage().persistent().has(&key) {
+            return Err(AchievementError::BadgeAlreadyAwarded.into());
+        }
+
+        let badge = Badge {
+            badge_id: badge_id.clone(),
+            awarded_at: env.ledger().timestamp(),
+        };
+        env.storage().persistent().set(&key, &badge);
+
+        let mut held = Self::badge_ids(env, user);
+        held.push_back(badge_id.clone());
+        env.storage()
+            .persistent()
+            .set(&AchievementDataKey::UserBadges(user.clone()), &held);
+
+        if let Some(token) = token {
+            MembershipTokenContract::set_metadata_attribute(
+                env,
+                &token.id,
+                badge_id.clone(),
+                MetadataValue::Timestamp(badge.awarded_at),
+            )?;
+        }
+
+        BadgeAwarded {
+            user: user.clone(),
+            badge_id: badge_id.clone(),
+            awarded_at: badge.awarded_at,
+        }
+        .publish(env);
+        Ok(badge)
+    }
+
+    // -----------------------------------------------------------------------
+    // Queries
+    // -----------------------------------------------------------------------
+
+    fn badge_ids(env: &Env, user: &Address) -> Vec<String> {
+        env.storage()
+            .persistent()
+            .get(&AchievementDataKey::UserBadges(user.clone()))
+            .unwrap_or(Vec::new(env))
+    }
+
+    pub fn get_badges(env: &Env, user: &Address) -> Vec<Badge> {
+        let mut badges = Vec::new(env);
+        for id in Self::badge_ids(env, user).iter() {
+            if let Some(badge) = env
+                .storage()
+                .persistent()
+                .get(&AchievementDataKey::Badge(user.clone(), id))
+            {
+                badges.push_back(badge);
+            }
+        }
+        badges
+    }
+
+    pub fn has_badge(env: &Env, user: &Address, badge_id: &String) -> bool {
+        env.storage()
+            .persistent()
+            .has(&AchievementDataKey::Badge(user.clone(), badge_id.clone()))
+    }
+}
@@ -0,0 +1,19 @@
+//! Admin-transfer error types for the ManageHub contract.
+//!
+//! `AdminTransferError` owns the code range 450–499 (see [`crate::errors`]).
+
+use soroban_sdk::contracterror;
+
+/// Admin-transfer-specific errors.
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum AdminTransferError {
+    /// An admin is already set; use the propose/accept flow to change it.
+    AdminAlreadySet = 450,
+    /// The proposed admin is the current admin.
+    SameAdmin = 451,
+    /// No admin transfer is pending.
+    NoPendingTransfer = 452,
+    /// The pending transfer was not accepted before its expiry.
+    TransferExpired = 453,
+}
@@ -2,11 +2,16 @@
 
 use crate::errors::Error;
 use crate::types::TokenAllowance;
-use soroban_sdk::{contracttype, Address, BytesN, Env, String};
+use soroban_sdk::{contracttype, Address, BytesN, Env, String, Vec};
 
 #[contracttype]
 pub enum AllowanceDataKey {
     Allowance(BytesN<32>, Address, Address),
+    /// Spenders holding an allowance on a token from its current owner
+    /// (persistent storage). Cleared whenever the token changes hands.
+    Spenders(BytesN<32>),
+    /// Operator approved to manage every token of an owner (persistent storage).
+    OperatorApproval(Address, Address),
 }
 
 pub struct AllowanceModule;
@@ -45,6 +50,11 @@ impl AllowanceModule {
             &AllowanceDataKey::Allowance(token_id.clone(), owner.clone(), spender.clone()),
             &allowance,
         );
+        let mut spenders = Self::get_spenders(env, token_id);
+        if !spenders.contains(spender) {
+            spenders.push_back(spender.clone());
+            Self::set_spenders(env, token_id, &spenders);
+        }
 
         env.events().publish(
             (
@@ -60,13 +70,7 @@ impl AllowanceModule {
     }
 
     pub fn revoke_allowance(env: &Env, token_id: &BytesN<32>, owner: &Address, spender: &Address) {
-        env.storage()
-            .persistent()
-            .remove(&AllowanceDataKey::Allowance(
-                token_id.clone(),
-                owner.clone(),
-                spender.clone(),
-            ));
+        Self::remove_allowance(env, token_id, owner, spender);
 
         env.events().publish(
             (
@@ -90,7 +94,7 @@ impl AllowanceModule {
 
         if let Some(current) = allowance {
             if Self::is_expired(env, &current) {
-                env.storage().persistent().remove(&key);
+                Self::remove_allowance(env, token_id, owner, spender);
                 return None;
             }
             return Some(current);
@@ -118,7 +122,7 @@ impl AllowanceModule {
             .ok_or(Error::Unauthorized)?;
 
         if Self::is_expired(env, &allowance) {
-            env.storage().persistent().remove(&key);
+            Self::remove_allowance(env, token_id, owner, spender);
             return Err(Error::Unauthorized);
         }
 
@@ -133,7 +137,7 @@ impl AllowanceModule {
         allowance.updated_at = env.ledger().timestamp();
 
         if allowance.amount == 0 {
-            env.storage().persistent().remove(&key);
+            Self::remove_allowance(env, token_id, owner, spender);
         } else {
             env.storage().persistent().set(&key, &allowance);
         }
@@ -151,6 +155,127 @@ impl AllowanceModule {
         Ok(())
     }
 
+    /// Every unexpired allowance granted by `owner` on the token.
+    pub fn get_all_allowances(
+        env: &Env,
+        token_id: &BytesN<32>,
+        owner: &Address,
+    ) -> Vec<TokenAllowance> {
+        let mut allowances = Vec::new(env);
+        for spender in Self::get_spenders(env, token_id).iter() {
+            let allowance: Option<TokenAllowance> = env.storage().persistent().get(
+                &AllowanceDataKey::Allowance(token_id.clone(), owner.clone(), spender),
+            );
+            if let Some(allowance) = allowance {
+                if !Self::is_expired(env, &allowance) {
+                    allowances.push_back(allowance);
+                }
+            }
+        }
+        allowances
+    }
+
+    /// Removes every allowance `owner` granted on the token. Returns how many
+    /// spenders were cleared.
+    pub fn revoke_all_allowances(env: &Env, token_id: &BytesN<32>, owner: &Address) -> u32 {
+        let spenders = Self::get_spenders(env, token_id);
+        for spender in spenders.iter() {
+            env.storage()
+                .persistent()
+                .remove(&AllowanceDataKey::Allowance(
+                    token_id.clone(),
+                    owner.clone(),
+                    spender,
+                ));
+        }
+        env.storage()
+            .persistent()
+            .remove(&AllowanceDataKey::Spenders(token_id.clone()));
+
+        if !spenders.is_empty() {
+            env.events().publish(
+                (
+                    String::from_str(env, "AllowancesCleared"),
+                    token_id.clone(),
+                    owner.clone(),
+                ),
+                (spenders.len(), env.ledger().timestamp()),
+            );
+        }
+        spenders.len()
+    }
+
+    fn remove_allowance(env: &Env, token_id: &BytesN<32>, owner: &Address, spender: &Address) {
+        env.storage()
+            .persistent()
+            .remove(&AllowanceDataKey::Allowance(
+                token_id.clone(),
+                owner.clone(),
+                spender.clone(),
+            ));
+        let mut spenders = Self::get_spenders(env, token_id);
+        if let Some(index) = spenders.first_index_of(spender) {
+            spenders.remove(index);
+            Self::set_spenders(env, token_id, &spenders);
+        }
+    }
+
+    fn get_spenders(env: &Env, token_id: &BytesN<32>) -> Vec<Address> {
+        env.storage()
+            .persistent()
+            .get(&AllowanceDataKey::Spenders(token_id.clone()))
+            .unwrap_or(Vec::new(env))
+    }
+
+    fn set_spenders(env: &Env, token_id: &BytesN<32>, spenders: &Vec<Address>) {
+        let key = AllowanceDataKey::Spenders(token_id.clone());
+        if spenders.is_empty() {
+            env.storage().persistent().remove(&key);
+        } else {
+            env.storage().persistent().set(&key, spenders);
+        }
+    }
+
+    /// Approves or revokes `operator` for every token `owner` holds.
+    pub fn set_approval_for_all(
+        env: &Env,
+        owner: &Address,
+        operator: &Address,
+        approved: bool,
+    ) -> Result<(), Error> {
+        if owner == operator {
+            return Err(Error::Unauthorized);
+        }
+
+        let key = AllowanceDataKey::OperatorApproval(owner.clone(), operator.clone());
+        let event_name = if approved {
+            env.storage().persistent().set(&key, &true);
+            "ApprovalForAll"
+        } else {
+            env.storage().persistent().remove(&key);
+            "OperatorRevoked"
+        };
+
+        env.events().publish(
+            (
+                String::from_str(env, event_name),
+                owner.clone(),
+                operator.clone(),
+            ),
+            env.ledger().timestamp(),
+        );
+        Ok(())
+    }
+
+    pub fn is_approved_for_all(env: &Env, owner: &Address, operator: &Address) -> bool {
+        env.storage()
+            .persistent()
+            .has(&AllowanceDataKey::OperatorApproval(
+                owner.clone(),
+                operator.clone(),
+            ))
+    }
+
     fn is_expired(env: &Env, allowance: &TokenAllowance) -> bool {
         if let Some(expiry) = allowance.expires_at {
             return env.ledger().timestamp() >= expiry;
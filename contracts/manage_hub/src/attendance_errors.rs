@@ -0,0 +1,29 @@
+//! Attendance-related error types for the ManageHub contract.
+//!
+//! `AttendanceError` owns the code range 500–549 (see [`crate::errors`]).
+
+use soroban_sdk::contracterror;
+
+/// Attendance-specific errors.
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum AttendanceError {
+    /// The user exceeded the hourly log limit or the daily details budget.
+    RateLimited = 500,
+    /// The maximum number of attendance hooks is already registered.
+    HookLimitReached = 501,
+    /// No attendance log exists with the given id.
+    LogNotFound = 502,
+    /// The details map has more entries than the configured maximum.
+    TooManyDetails = 503,
+    /// A detail key is longer than the configured maximum.
+    DetailKeyTooLong = 504,
+    /// A detail value is longer than the configured maximum.
+    DetailValueTooLong = 505,
+    /// A detail key is not on the configured allowlist.
+    DetailKeyNotAllowed = 506,
+    /// A key required for the logged action is missing.
+    MissingRequiredDetail = 507,
+    /// The detail rules are out of bounds.
+    InvalidDetailRules = 508,
+}
@@ -0,0 +1,107 @@
+use crate::attendance_errors::AttendanceError;
+use crate::errors::{Error, ModuleError};
+use crate::events::{AttendanceHookFailed, AttendanceHookRegistered, AttendanceHookUnregistered};
+use crate::membership_token::MembershipTokenContract;
+use crate::types::AttendanceAction;
+use soroban_sdk::{contracttype, Address, Env, IntoVal, Symbol, Val, Vec};
+
+// ---------------------------------------------------------------------------
+// Storage keys
+// ---------------------------------------------------------------------------
+
+#[contracttype]
+pub enum AttendanceHookDataKey {
+    /// Registered hook contract addresses, in registration order (instance storage).
+    Hooks,
+}
+
+// ---------------------------------------------------------------------------
+// Constants
+// ---------------------------------------------------------------------------
+
+/// Upper bound on registered hooks, which caps the cross-contract calls (and
+/// so the budget) a single `log_attendance` can spend on notifications.
+pub const MAX_ATTENDANCE_HOOKS: u32 = 5;
+
+// ---------------------------------------------------------------------------
+// Module
+// ---------------------------------------------------------------------------
+
+/// Registry of contracts notified when attendance is logged.
+///
+/// Hooks must expose `on_attendance(user: Address, action: AttendanceAction,
+/// timestamp: u64)`. Only these three compact values are passed (never the
+/// details map), and each call is made with `try_invoke_contract` so a failing
+/// or missing hook cannot revert the attendance log.
+pub struct AttendanceHookModule;
+
+impl AttendanceHookModule {
+    pub fn register_hook(env: Env, admin: Address, hook: Address) -> Result<(), ModuleError> {
+        MembershipTokenContract::require_admin(&env, &admin)?;
+
+        let mut hooks = Self::get_hooks(&env);
+        if hooks.contains(&hook) {
+            return Ok(());
+        }
+        if hooks.len() >= MAX_ATTENDANCE_HOOKS {
+            return Err(AttendanceError::HookLimitReached.into());
+        }
+        hooks.push_back(hook.clone());
+        env.storage()
+            .instance()
+            .set(&AttendanceHookDataKey::Hooks, &hooks);
+
+        AttendanceHookRegistered { hook, admin }.publish(&env);
+        Ok(())
+    }
+
+    pub fn unregister_hook(env: Env, admin: Address, hook: Address) -> Result<(), Error> {
+        MembershipTokenContract::require_admin(&env, &admin)?;
+
+        let mut hooks = Self::get_hooks(&env);
+        if let Some(index) = hooks.first_index_of(&hook) {
+            hooks.remove(index);
+            env.storage()
+                .instance()
+                .set(&AttendanceHookDataKey::Hooks, &hooks);
+            AttendanceHookUnregistered { hook, admin }.publish(&env);
+        }
+        Ok(())
+    }
+
+    pub fn get_hooks(env: &Env) -> Vec<Address> {
+        env.storage()
+            .instance()
+            .get(&AttendanceHookDataKey::Hooks)
+            .unwrap_or(Vec::new(env))
+    }
+
+    /// Calls every registered hook. Failures are reported through an
+    /// `attendance_hook_failed` event and otherwise ignored.
+    pub(crate) fn notify(env: &Env, user: &Address, action: &AttendanceAction, timestamp: u64) {
+        let hooks = Self::get_hooks(env);
+        if hooks.is_empty() {
+            return;
+        }
+
+        let function = Symbol::new(env, "on_attendance");
+        for hook in hooks.iter() {
+            let args: Vec<Val> = Vec::from_array(
+                env,
+                [
+                    user.into_val(env),
+                    action.into_val(env),
+                    timestamp.into_val(env),
+                ],
+            );
+            let result = env.try_invoke_contract::<(), soroban_sdk::Error>(&hook, &function, args);
+            if !matches!(result, Ok(Ok(()))) {
+                AttendanceHookFailed {
+                    hook,
+                    user: user.clone(),
+                }
+                .publish(env);
+            }
+        }
+    }
+}
@@ -1,20 +1,81 @@
 // Allow deprecated events API until migration to #[contractevent] macro
 #![allow(deprecated)]
 
-use crate::errors::Error;
-use crate::types::{AttendanceAction, AttendanceSummary, SessionPair};
+use crate::attendance_errors::AttendanceError;
+use crate::attendance_hooks::AttendanceHookModule;
+use crate::bookings::BookingModule;
+use crate::errors::{Error, ModuleError};
+use crate::household::HouseholdModule;
+use crate::loyalty::LoyaltyModule;
+use crate::membership_token::MembershipTokenContract;
+use crate::types::{
+    ArchivedAttendanceSummary, AttendanceAction, AttendanceDetailRules, AttendanceRateLimit,
+    AttendanceSummary, SessionPair,
+};
 use common_types::{
     AttendanceFrequency, DateRange, DayPattern, PeakHourData, TimePeriod, UserAttendanceStats,
 };
+use soroban_sdk::xdr::ToXdr;
 use soroban_sdk::{contracttype, symbol_short, Address, BytesN, Env, Map, String, Vec};
 
 #[contracttype]
 #[derive(Clone, Debug, PartialEq)]
 pub enum DataKey {
     AttendanceLog(BytesN<32>),
+    /// Legacy unpaged log vector; moved into indexed entries on the user's
+    /// next access.
     AttendanceLogsByUser(Address),
+    /// Legacy fixed-size page of a user's logs; moved into indexed entries on
+    /// the user's next access.
+    LogPage(Address, u32),
+    /// Number of logs appended to a user's legacy pages; removed once they
+    /// are migrated.
+    LogCount(Address),
+    /// A user's log by its append index (persistent storage).
+    UserLog(Address, u32),
+    /// Number of logs ever appended for a user, archived ones included
+    /// (persistent storage).
+    UserLogCount(Address),
+    /// Index of a user's oldest unarchived log (persistent storage).
+    LogStart(Address),
+    /// Archived summary for a user and `YYYYMM` month (persistent storage).
+    MonthlySummary(Address, u32),
+    /// Months with an archived summary for a user (persistent storage).
+    ArchivedMonths(Address),
+    /// Rate limit applied to attendance logging (instance storage).
+    RateLimitConfig,
+    /// Users exempt from the rate limit (persistent storage).
+    RateLimitExempt(Address),
+    /// Logs recorded for a user in an hour bucket (temporary storage).
+    HourlyLogCount(Address, u64),
+    /// Detail bytes recorded for a user in a day bucket (temporary storage).
+    DailyDetailBytes(Address, u64),
+    /// Position of a log in its user's index (persistent storage).
+    LogIndex(BytesN<32>),
+    /// sha256 of a log's details, kept once the details are redacted
+    /// (persistent storage).
+    DetailsHash(BytesN<32>),
+    /// Whether member-supplied details are stored only as a hash (instance
+    /// storage).
+    HashDetailsOnWrite,
+    /// Rules applied to member-supplied details (instance storage).
+    DetailRules,
 }
 
+/// Logs per page in the legacy paged layout.
+pub const LOG_PAGE_SIZE: u32 = 50;
+/// Largest `limit` accepted by `get_logs_for_user_paged`.
+pub const MAX_LOGS_PER_QUERY: u32 = 100;
+/// Logs processed by a single `archive_logs_before` call.
+pub const MAX_ARCHIVE_PER_CALL: u32 = MAX_LOGS_PER_QUERY;
+/// Largest number of entries in a log's details map.
+pub const MAX_DETAIL_ENTRIES: u32 = 50;
+
+const SECONDS_PER_HOUR: u64 = 3_600;
+const SECONDS_PER_DAY: u64 = 86_400;
+/// Counters only need to outlive their window; ~1 day at 5s ledgers.
+const RATE_COUNTER_TTL_LEDGERS: u32 = 17_280;
+
 #[contracttype]
 #[derive(Clone, Debug, PartialEq)]
 pub struct AttendanceLog {
@@ -34,11 +95,127 @@ impl AttendanceLogModule {
         user_id: Address,
         action: AttendanceAction,
         details: Map<String, String>,
-    ) -> Result<(), Error> {
+    ) -> Result<(), ModuleError> {
         // Enforce initiator authentication
         user_id.require_auth();
 
-        Self::log_attendance_internal(env, id, user_id, action, details)
+        Self::record_attendance(&env, id, user_id, action, details, true)
+    }
+
+    /// Records a log the caller has already authorized, through the same
+    /// steps as a member's own log: rate limit, detail and booking checks,
+    /// storage, then loyalty, household attribution and attendance hooks.
+    /// Loyalty is skipped when `earns_loyalty` is false.
+    pub(crate) fn record_attendance(
+        env: &Env,
+        id: BytesN<32>,
+        user_id: Address,
+        action: AttendanceAction,
+        details: Map<String, String>,
+        earns_loyalty: bool,
+    ) -> Result<(), ModuleError> {
+        Self::enforce_rate_limit(env, &user_id, &details)?;
+        BookingModule::check_session_log(env, &user_id, &action, &details)?;
+        let details = Self::prepare_details(env, &id, &action, details)?;
+        Self::log_attendance_internal(env.clone(), id, user_id.clone(), action.clone(), details)?;
+        let now = env.ledger().timestamp();
+        if earns_loyalty {
+            LoyaltyModule::on_attendance(env, &user_id, &action, now);
+        }
+        HouseholdModule::on_attendance(env, &user_id, &action, now);
+        AttendanceHookModule::notify(env, &user_id, &action, now);
+        Ok(())
+    }
+
+    // ============================================================================
+    // Rate Limiting
+    // ============================================================================
+
+    /// Sets the attendance rate limit. Admin only.
+    pub fn set_rate_limit(
+        env: Env,
+        admin: Address,
+        config: AttendanceRateLimit,
+    ) -> Result<(), Error> {
+        MembershipTokenContract::require_admin(&env, &admin)?;
+        env.storage()
+            .instance()
+            .set(&DataKey::RateLimitConfig, &config);
+        env.events()
+            .publish((symbol_short!("att_limit"), admin), config);
+        Ok(())
+    }
+
+    pub fn get_rate_limit(env: &Env) -> Option<AttendanceRateLimit> {
+        env.storage().instance().get(&DataKey::RateLimitConfig)
+    }
+
+    /// Adds or removes a user from the rate-limit exemption list. Admin only.
+    pub fn set_rate_limit_exempt(
+        env: Env,
+        admin: Address,
+        user: Address,
+        exempt: bool,
+    ) -> Result<(), Error> {
+        MembershipTokenContract::require_admin(&env, &admin)?;
+        let key = DataKey::RateLimitExempt(user);
+        if exempt {
+            env.storage().persistent().set(&key, &true);
+        } else {
+            env.storage().persistent().remove(&key);
+        }
+        Ok(())
+    }
+
+    pub fn is_rate_limit_exempt(env: &Env, user: &Address) -> bool {
+        env.storage()
+            .persistent()
+            .has(&DataKey::RateLimitExempt(user.clone()))
+    }
+
+    /// Checks `user` against the configured limits and records the log.
+    /// A limit of 0 disables that check; no config disables both.
+    fn enforce_rate_limit(
+        env: &Env,
+        user: &Address,
+        details: &Map<String, String>,
+    ) -> Result<(), ModuleError> {
+        let config = match Self::get_rate_limit(env) {
+            Some(config) => config,
+            None => return Ok(()),
+        };
+        if Self::is_rate_limit_exempt(env, user) {
+            return Ok(());
+        }
+
+        let now = env.ledger().timestamp();
+        let storage = env.storage().temporary();
+
+        let hour_key = DataKey::HourlyLogCount(user.clone(), now / SECONDS_PER_HOUR);
+        let logs: u32 = storage.get(&hour_key).unwrap_or(0);
+        if config.max_logs_per_hour > 0 && logs >= config.max_logs_per_hour {
+            return Err(AttendanceError::RateLimited.into());
+        }
+
+        let day_key = DataKey::DailyDetailBytes(user.clone(), now / SECONDS_PER_DAY);
+        let used: u32 = storage.get(&day_key).unwrap_or(0);
+        let size = details
+            .iter()
+            .fold(0u32, |acc, (k, v)| acc.saturating_add(k.len() + v.len()));
+        let total = used.saturating_add(size);
+        if config.max_detail_bytes_per_day > 0 && total > config.max_detail_bytes_per_day {
+            return Err(AttendanceError::RateLimited.into());
+        }
+
+        storage.set(&hour_key, &(logs + 1));
+        storage.extend_ttl(
+            &hour_key,
+            RATE_COUNTER_TTL_LEDGERS,
+            RATE_COUNTER_TTL_LEDGERS,
+        );
+        storage.set(&day_key, &total);
+        storage.extend_ttl(&day_key, RATE_COUNTER_TTL_LEDGERS, RATE_COUNTER_TTL_LEDGERS);
+        Ok(())
     }
 
     /// Internal version without auth check for cross-contract calls
@@ -49,10 +226,7 @@ impl AttendanceLogModule {
         action: AttendanceAction,
         details: Map<String, String>,
     ) -> Result<(), Error> {
-        // Validate details size
-        if details.len() > 50 {
-            return Err(Error::InvalidEventDetails);
-        }
+        Self::validate_details(&details)?;
 
         let timestamp = env.ledger().timestamp();
 
@@ -69,16 +243,8 @@ impl AttendanceLogModule {
             .persistent()
             .set(&DataKey::AttendanceLog(id.clone()), &log);
 
-        // Append to user's attendance logs
-        let mut user_logs: Vec<AttendanceLog> = env
-            .storage()
-            .persistent()
-            .get(&DataKey::AttendanceLogsByUser(user_id.clone()))
-            .unwrap_or(Vec::new(&env));
-        user_logs.push_back(log.clone());
-        env.storage()
-            .persistent()
-            .set(&DataKey::AttendanceLogsByUser(user_id.clone()), &user_logs);
+        // Append to user's indexed attendance logs
+        Self::append_user_log(&env, &user_id, log);
 
         // Emit event for off-chain indexing
         env.events()
@@ -88,10 +254,436 @@ impl AttendanceLogModule {
     }
 
     pub fn get_logs_for_user(env: Env, user_id: Address) -> Vec<AttendanceLog> {
+        let (start, count) = Self::log_bounds(&env, &user_id);
+        Self::load_range(&env, &user_id, start, count)
+    }
+
+    /// Returns up to `limit` of the user's logs starting at `offset`, oldest
+    /// first. Only the entries inside the requested window are read.
+    pub fn get_logs_for_user_paged(
+        env: Env,
+        user_id: Address,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<AttendanceLog> {
+        let limit = limit.min(MAX_LOGS_PER_QUERY);
+        let (start, count) = Self::log_bounds(&env, &user_id);
+
+        // Offsets are relative to the oldest unarchived log
+        let from = start.saturating_add(offset).min(count);
+        let to = from.saturating_add(limit).min(count);
+        Self::load_range(&env, &user_id, from, to)
+    }
+
+    /// Number of (unarchived) attendance logs stored for a user.
+    pub fn get_log_count(env: &Env, user_id: &Address) -> u32 {
+        let (start, count) = Self::log_bounds(env, user_id);
+        count - start
+    }
+
+    /// `(first unarchived index, total logs ever stored)` for a user. Logs
+    /// still in a legacy layout are moved to indexed entries first.
+    fn log_bounds(env: &Env, user_id: &Address) -> (u32, u32) {
+        Self::migrate_legacy_logs(env, user_id);
+
+        let storage = env.storage().persistent();
+        let count: u32 = storage
+            .get(&DataKey::UserLogCount(user_id.clone()))
+            .unwrap_or(0);
+        let start: u32 = storage
+            .get(&DataKey::LogStart(user_id.clone()))
+            .unwrap_or(0);
+        (start, count)
+    }
+
+    /// The user's logs with indices in `from..to`, oldest first.
+    fn load_range(env: &Env, user_id: &Address, from: u32, to: u32) -> Vec<AttendanceLog> {
+        let storage = env.storage().persistent();
+        let mut logs = Vec::new(env);
+        for index in from..to {
+            if let Some(log) = storage.get(&DataKey::UserLog(user_id.clone(), index)) {
+                logs.push_back(log);
+            }
+        }
+        logs
+    }
+
+    /// One-time move of a pre-pagination log vector, or of log pages, into
+    /// indexed entries. Indices are preserved, so `LogStart` stays valid.
+    fn migrate_legacy_logs(env: &Env, user_id: &Address) {
+        let storage = env.storage().persistent();
+        let legacy_key = DataKey::AttendanceLogsByUser(user_id.clone());
+        if let Some(legacy) = storage.get::<_, Vec<AttendanceLog>>(&legacy_key) {
+            for (index, log) in legacy.iter().enumerate() {
+                storage.set(&DataKey::UserLog(user_id.clone(), index as u32), &log);
+            }
+            storage.set(&DataKey::UserLogCount(user_id.clone()), &legacy.len());
+            storage.remove(&legacy_key);
+        }
+
+        let count_key = DataKey::LogCount(user_id.clone());
+        let count: u32 = match storage.get(&count_key) {
+            Some(count) => count,
+            None => return,
+        };
+        let start: u32 = storage
+            .get(&DataKey::LogStart(user_id.clone()))
+            .unwrap_or(0);
+        for page in start / LOG_PAGE_SIZE..count.div_ceil(LOG_PAGE_SIZE) {
+            let page_key = DataKey::LogPage(user_id.clone(), page);
+            let logs: Vec<AttendanceLog> = storage.get(&page_key).unwrap_or(Vec::new(env));
+            // Archival trims the first page down to the unarchived logs
+            let first = (page * LOG_PAGE_SIZE).max(start);
+            for (i, log) in logs.iter().enumerate() {
+                storage.set(&DataKey::UserLog(user_id.clone(), first + i as u32), &log);
+            }
+            storage.remove(&page_key);
+        }
+        storage.set(&DataKey::UserLogCount(user_id.clone()), &count);
+        storage.remove(&count_key);
+    }
+
+    // ============================================================================
+    // Detail Rules
+    // ============================================================================
+
+    /// Sets the rules member-supplied details must satisfy. Admin only.
+    pub fn set_detail_rules(
+        env: Env,
+        admin: Address,
+        rules: AttendanceDetailRules,
+    ) -> Result<(), ModuleError> {
+        MembershipTokenContract::require_admin(&env, &admin)?;
+        let too_many_required = rules
+            .required_keys
+            .values()
+            .iter()
+            .any(|keys| keys.len() > MAX_DETAIL_ENTRIES);
+        if rules.max_entries > MAX_DETAIL_ENTRIES
+            || rules.allowed_keys.len() > MAX_DETAIL_ENTRIES
+            || too_many_required
+        {
+            return Err(AttendanceError::InvalidDetailRules.into());
+        }
+        env.storage().instance().set(&DataKey::DetailRules, &rules);
+        env.events()
+            .publish((symbol_short!("att_rules"), admin), rules);
+        Ok(())
+    }
+
+    pub fn get_detail_rules(env: &Env) -> Option<AttendanceDetailRules> {
+        env.storage().instance().get(&DataKey::DetailRules)
+    }
+
+    /// Checks member-supplied details against the configured rules.
+    fn check_detail_rules(
+        env: &Env,
+        action: &AttendanceAction,
+        details: &Map<String, String>,
+    ) -> Result<(), ModuleError> {
+        let Some(rules) = Self::get_detail_rules(env) else {
+            return Ok(());
+        };
+        if rules.max_entries > 0 && details.len() > rules.max_entries {
+            return Err(AttendanceError::TooManyDetails.into());
+        }
+        for (key, value) in details.iter() {
+            if rules.max_key_len > 0 && key.len() > rules.max_key_len {
+                return Err(AttendanceError::DetailKeyTooLong.into());
+            }
+            if rules.max_value_len > 0 && value.len() > rules.max_value_len {
+                return Err(AttendanceError::DetailValueTooLong.into());
+            }
+            if !rules.allowed_keys.is_empty() && !rules.allowed_keys.contains(&key) {
+                return Err(AttendanceError::DetailKeyNotAllowed.into());
+            }
+        }
+        if let Some(required) = rules.required_keys.get(action.clone()) {
+            if required.iter().any(|key| !details.contains_key(key)) {
+                return Err(AttendanceError::MissingRequiredDetail.into());
+            }
+        }
+        Ok(())
+    }
+
+    fn validate_details(details: &Map<String, String>) -> Result<(), Error> {
+        if details.len() > MAX_DETAIL_ENTRIES {
+            return Err(Error::InvalidEventDetails);
+        }
+        Ok(())
+    }
+
+    fn append_user_log(env: &Env, user_id: &Address, log: AttendanceLog) {
+        let (_, count) = Self::log_bounds(env, user_id);
+
+        let storage = env.storage().persistent();
+        storage.set(&DataKey::LogIndex(log.id.clone()), &count);
+        storage.set(&DataKey::UserLog(user_id.clone(), count), &log);
+        storage.set(&DataKey::UserLogCount(user_id.clone()), &(count + 1));
+    }
+
+    // ============================================================================
+    // Detail Redaction
+    // ============================================================================
+
+    /// Turns hash-only storage of member-supplied details on or off. Admin
+    /// only. Logs written by the contract itself (subscription, booking and
+    /// guest events) keep their details, which other modules read back.
+    pub fn set_hash_details_on_write(env: Env, admin: Address, enabled: bool) -> Result<(), Error> {
+        MembershipTokenContract::require_admin(&env, &admin)?;
+        env.storage()
+            .instance()
+            .set(&DataKey::HashDetailsOnWrite, &enabled);
+        env.events()
+            .publish((symbol_short!("att_hash"), admin), enabled);
+        Ok(())
+    }
+
+    pub fn is_hash_details_on_write(env: &Env) -> bool {
+        env.storage()
+            .instance()
+            .get(&DataKey::HashDetailsOnWrite)
+            .unwrap_or(false)
+    }
+
+    /// Checks member-supplied details and returns what to store for log
+    /// `id`: the details unchanged, or empty with their hash recorded when
+    /// hash-only storage is on.
+    fn prepare_details(
+        env: &Env,
+        id: &BytesN<32>,
+        action: &AttendanceAction,
+        details: Map<String, String>,
+    ) -> Result<Map<String, String>, ModuleError> {
+        Self::validate_details(&details)?;
+        Self::check_detail_rules(env, action, &details)?;
+        if details.is_empty() || !Self::is_hash_details_on_write(env) {
+            return Ok(details);
+        }
+        env.storage().persistent().set(
+            &DataKey::DetailsHash(id.clone()),
+            &Self::hash_details(env, &details),
+        );
+        Ok(Map::new(env))
+    }
+
+    /// Replaces a log's details with their sha256 hash. Callable by the
+    /// log's user or the admin; redacting an already redacted log returns
+    /// the stored hash.
+    pub fn redact_log_details(
+        env: Env,
+        caller: Address,
+        log_id: BytesN<32>,
+    ) -> Result<BytesN<32>, ModuleError> {
+        let log = Self::get_attendance_log(env.clone(), log_id.clone())
+            .ok_or(AttendanceError::LogNotFound)?;
+        Self::require_owner_or_admin(&env, &caller, &log.user_id)?;
+
+        if let Some(hash) = Self::get_details_hash(&env, &log_id) {
+            return Ok(hash);
+        }
+        let index = Self::find_log_index(&env, &log.user_id, &log_id)
+            .ok_or(AttendanceError::LogNotFound)?;
+        Ok(Self::redact(&env, index, log))
+    }
+
+    /// Redacts up to `limit` of a user's logs starting at `offset` (as in
+    /// `get_logs_for_user_paged`). Callable by the user or the admin.
+    /// Returns the number of logs newly redacted.
+    pub fn redact_user_log_details(
+        env: Env,
+        caller: Address,
+        user_id: Address,
+        offset: u32,
+        limit: u32,
+    ) -> Result<u32, Error> {
+        Self::require_owner_or_admin(&env, &caller, &user_id)?;
+
+        let (start, _) = Self::log_bounds(&env, &user_id);
+        let logs = Self::get_logs_for_user_paged(env.clone(), user_id, offset, limit);
+        let mut redacted = 0u32;
+        for (i, log) in logs.iter().enumerate() {
+            if Self::get_details_hash(&env, &log.id).is_none() {
+                Self::redact(&env, start + offset + i as u32, log);
+                redacted += 1;
+            }
+        }
+        Ok(redacted)
+    }
+
+    /// Hash of a log's original details, if they were redacted or stored
+    /// hash-only.
+    pub fn get_details_hash(env: &Env, log_id: &BytesN<32>) -> Option<BytesN<32>> {
         env.storage()
             .persistent()
-            .get(&DataKey::AttendanceLogsByUser(user_id))
-            .unwrap_or(Vec::new(&env))
+            .get(&DataKey::DetailsHash(log_id.clone()))
+    }
+
+    fn require_owner_or_admin(env: &Env, caller: &Address, owner: &Address) -> Result<(), Error> {
+        if caller == owner {
+            caller.require_auth();
+            return Ok(());
+        }
+        MembershipTokenContract::require_admin(env, caller)
+    }
+
+    /// Position of `log_id` in the user's index. Logs written before
+    /// positions were recorded are found by scanning.
+    fn find_log_index(env: &Env, user_id: &Address, log_id: &BytesN<32>) -> Option<u32> {
+        let storage = env.storage().persistent();
+        if let Some(index) = storage.get(&DataKey::LogIndex(log_id.clone())) {
+            return Some(index);
+        }
+        let (start, count) = Self::log_bounds(env, user_id);
+        (start..count).find(|index| {
+            storage
+                .get::<_, AttendanceLog>(&DataKey::UserLog(user_id.clone(), *index))
+                .is_some_and(|log| log.id == *log_id)
+        })
+    }
+
+    /// Clears the details of the log at `index`, records their hash and
+    /// returns it.
+    fn redact(env: &Env, index: u32, mut log: AttendanceLog) -> BytesN<32> {
+        let hash = Self::hash_details(env, &log.details);
+        log.details = Map::new(env);
+
+        let storage = env.storage().persistent();
+        storage.set(&DataKey::DetailsHash(log.id.clone()), &hash);
+        storage.set(&DataKey::AttendanceLog(log.id.clone()), &log);
+        storage.set(&DataKey::UserLog(log.user_id.clone(), index), &log);
+
+        env.events().publish(
+            (symbol_short!("att_redac"), log.id, log.user_id),
+            hash.clone(),
+        );
+        hash
+    }
+
+    fn hash_details(env: &Env, details: &Map<String, String>) -> BytesN<32> {
+        env.crypto().sha256(&details.clone().to_xdr(env)).into()
+    }
+
+    // ============================================================================
+    // Archival
+    // ============================================================================
+
+    /// Rolls a user's logs older than `before` into per-month summaries and
+    /// deletes the raw entries. Admin only.
+    ///
+    /// At most `MAX_ARCHIVE_PER_CALL` logs are processed per call, and a
+    /// trailing clock-in is kept until its clock-out can be archived with it,
+    /// so repeated calls are needed for large backlogs. Returns the number of
+    /// logs archived.
+    pub fn archive_logs_before(
+        env: Env,
+        admin: Address,
+        user_id: Address,
+        before: u64,
+    ) -> Result<u32, Error> {
+        MembershipTokenContract::require_admin(&env, &admin)?;
+        let (start, _) = Self::log_bounds(&env, &user_id);
+
+        // Collect the archivable prefix, stopping at an unmatched clock-in
+        let candidates =
+            Self::get_logs_for_user_paged(env.clone(), user_id.clone(), 0, MAX_ARCHIVE_PER_CALL);
+        let mut archived = 0u32;
+        let mut open_clock_in: Option<u32> = None;
+        for (i, log) in candidates.iter().enumerate() {
+            if log.timestamp >= before {
+                break;
+            }
+            match log.action {
+                AttendanceAction::ClockIn => open_clock_in = Some(i as u32),
+                AttendanceAction::ClockOut => open_clock_in = None,
+            }
+            archived = i as u32 + 1;
+        }
+        if let Some(pending) = open_clock_in {
+            archived = pending;
+        }
+        if archived == 0 {
+            return Ok(0);
+        }
+
+        // Fold the archived logs into monthly summaries
+        let storage = env.storage().persistent();
+        let months_key = DataKey::ArchivedMonths(user_id.clone());
+        let mut months: Vec<u32> = storage.get(&months_key).unwrap_or(Vec::new(&env));
+        let mut clock_in_at: Option<u64> = None;
+        for log in candidates.slice(0..archived).iter() {
+            let month = Self::year_month(log.timestamp);
+            let key = DataKey::MonthlySummary(user_id.clone(), month);
+            let mut summary: ArchivedAttendanceSummary =
+                storage.get(&key).unwrap_or(ArchivedAttendanceSummary {
+                    year_month: month,
+                    log_count: 0,
+                    visit_count: 0,
+                    total_seconds: 0,
+                });
+            summary.log_count += 1;
+            match log.action {
+                AttendanceAction::ClockIn => {
+                    summary.visit_count += 1;
+                    clock_in_at = Some(log.timestamp);
+                }
+                AttendanceAction::ClockOut => {
+                    if let Some(clock_in) = clock_in_at.take() {
+                        summary.total_seconds += log.timestamp.saturating_sub(clock_in);
+                    }
+                }
+            }
+            storage.set(&key, &summary);
+            if !months.contains(month) {
+                months.push_back(month);
+            }
+            storage.remove(&DataKey::LogIndex(log.id.clone()));
+            storage.remove(&DataKey::DetailsHash(log.id.clone()));
+            storage.remove(&DataKey::AttendanceLog(log.id));
+        }
+        storage.set(&months_key, &months);
+
+        // Drop the archived entries from the user's index
+        let new_start = start + archived;
+        for index in start..new_start {
+            storage.remove(&DataKey::UserLog(user_id.clone(), index));
+        }
+        storage.set(&DataKey::LogStart(user_id.clone()), &new_start);
+
+        env.events()
+            .publish((symbol_short!("att_arch"), user_id), (archived, before));
+
+        Ok(archived)
+    }
+
+    /// Monthly summaries of a user's archived logs, in archival order.
+    pub fn get_archived_summaries(env: Env, user_id: Address) -> Vec<ArchivedAttendanceSummary> {
+        let storage = env.storage().persistent();
+        let months: Vec<u32> = storage
+            .get(&DataKey::ArchivedMonths(user_id.clone()))
+            .unwrap_or(Vec::new(&env));
+        let mut summaries = Vec::new(&env);
+        for month in months.iter() {
+            if let Some(summary) = storage.get(&DataKey::MonthlySummary(user_id.clone(), month)) {
+                summaries.push_back(summary);
+            }
+        }
+        summaries
+    }
+
+    /// Converts a Unix timestamp to a `YYYYMM` month key (UTC).
+    fn year_month(timestamp: u64) -> u32 {
+        // Civil-from-days conversion (Howard Hinnant's algorithm)
+        let days = (timestamp / SECONDS_PER_DAY) as i64 + 719_468;
+        let era = days / 146_097;
+        let day_of_era = days - era * 146_097;
+        let year_of_era =
+            (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+        let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+        let mp = (5 * day_of_year + 2) / 153;
+        let month = if mp < 10 { mp + 3 } else { mp - 9 };
+        let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+        (year * 100 + month) as u32
     }
 
     pub fn get_attendance_log(env: Env, id: BytesN<32>) -> Option<AttendanceLog> {
@@ -122,14 +714,7 @@ impl AttendanceLogModule {
             return Err(Error::InvalidDateRange);
         }
 
-        let logs = Self::get_logs_for_user(env.clone(), user_id.clone());
-
-        if logs.is_empty() {
-            return Err(Error::NoAttendanceRecords);
-        }
-
-        // Filter logs by date range
-        let filtered_logs = Self::filter_logs_by_date_range(&logs, &date_range);
+        let filtered_logs = Self::logs_in_range(&env, &user_id, &date_range);
 
         if filtered_logs.is_empty() {
             return Err(Error::NoAttendanceRecords);
@@ -211,8 +796,7 @@ impl AttendanceLogModule {
             return Err(Error::InvalidDateRange);
         }
 
-        let logs = Self::get_logs_for_user(env.clone(), user_id);
-        let filtered_logs = Self::filter_logs_by_date_range(&logs, &date_range);
+        let filtered_logs = Self::logs_in_range(&env, &user_id, &date_range);
 
         if filtered_logs.is_empty() {
             return Err(Error::NoAttendanceRecords);
@@ -242,8 +826,7 @@ impl AttendanceLogModule {
             return Err(Error::InvalidDateRange);
         }
 
-        let logs = Self::get_logs_for_user(env.clone(), user_id);
-        let filtered_logs = Self::filter_logs_by_date_range(&logs, &date_range);
+        let filtered_logs = Self::logs_in_range(&env, &user_id, &date_range);
 
         if filtered_logs.is_empty() {
             return Err(Error::NoAttendanceRecords);
@@ -281,20 +864,14 @@ impl AttendanceLogModule {
         user_id: Address,
         date_range: Option<DateRange>,
     ) -> Result<UserAttendanceStats, Error> {
-        let logs = Self::get_logs_for_user(env.clone(), user_id.clone());
-
-        if logs.is_empty() {
-            return Err(Error::NoAttendanceRecords);
-        }
-
         let filtered_logs = match date_range {
             Some(range) => {
                 if range.start_time > range.end_time {
                     return Err(Error::InvalidDateRange);
                 }
-                Self::filter_logs_by_date_range(&logs, &range)
+                Self::logs_in_range(&env, &user_id, &range)
             }
-            None => logs,
+            None => Self::get_logs_for_user(env.clone(), user_id.clone()),
         };
 
         if filtered_logs.is_empty() {
@@ -370,8 +947,7 @@ impl AttendanceLogModule {
             return Err(Error::InvalidDateRange);
         }
 
-        let logs = Self::get_logs_for_user(env.clone(), user_id);
-        let filtered_logs = Self::filter_logs_by_date_range(&logs, &date_range);
+        let filtered_logs = Self::logs_in_range(&env, &user_id, &date_range);
 
         if filtered_logs.is_empty() {
             return Err(Error::NoAttendanceRecords);
@@ -424,8 +1000,7 @@ impl AttendanceLogModule {
             return Err(Error::InvalidDateRange);
         }
 
-        let logs = Self::get_logs_for_user(env.clone(), user_id);
-        let filtered_logs = Self::filter_logs_by_date_range(&logs, &date_range);
+        let filtered_logs = Self::logs_in_range(&env, &user_id, &date_range);
 
         if filtered_logs.is_empty() {
             return Err(Error::NoAttendanceRecords);
@@ -467,18 +1042,23 @@ impl AttendanceLogModule {
     // Helper Functions
     // ============================================================================
 
-    /// Filter logs by date range
-    fn filter_logs_by_date_range(
-        logs: &Vec<AttendanceLog>,
+    /// A user's unarchived logs inside `date_range`, read entry by entry
+    /// from the user's index.
+    pub(crate) fn logs_in_range(
+        env: &Env,
+        user_id: &Address,
         date_range: &DateRange,
     ) -> Vec<AttendanceLog> {
-        let env = logs.env();
+        let (start, count) = Self::log_bounds(env, user_id);
+        let storage = env.storage().persistent();
         let mut filtered: Vec<AttendanceLog> = Vec::new(env);
 
-        for i in 0..logs.len() {
-            let log = logs.get(i).unwrap();
-            if log.timestamp >= date_range.start_time && log.timestamp <= date_range.end_time {
-                filtered.push_back(log);
+        for index in start..count {
+            let log: Option<AttendanceLog> = storage.get(&DataKey::UserLog(user_id.clone(), index));
+            if let Some(log) = log {
+                if log.timestamp >= date_range.start_time && log.timestamp <= date_range.end_time {
+                    filtered.push_back(log);
+                }
             }
         }
 
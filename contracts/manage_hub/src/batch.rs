@@ -1,7 +1,7 @@
 // Allow deprecated events API until migration to #[contractevent] macro
 #![allow(deprecated)]
 
-use crate::errors::Error;
+use crate::errors::{Error, ModuleError};
 use crate::membership_token::MembershipTokenContract;
 use crate::types::{BatchMintParams, BatchTransferParams, BatchUpdateParams};
 use crate::validation::BatchValidator;
@@ -16,7 +16,7 @@ impl BatchModule {
     /// # Arguments
     /// * `env` - The contract environment
     /// * `params_vec` - Vector of minting parameters for each token
-    pub fn batch_mint(env: Env, params_vec: Vec<BatchMintParams>) -> Result<(), Error> {
+    pub fn batch_mint(env: Env, params_vec: Vec<BatchMintParams>) -> Result<(), ModuleError> {
         BatchValidator::validate_batch_size(params_vec.len())?;
 
         MembershipTokenContract::batch_issue_tokens(env.clone(), params_vec.clone())?;
@@ -36,7 +36,10 @@ impl BatchModule {
     /// # Arguments
     /// * `env` - The contract environment
     /// * `params_vec` - Vector of transfer parameters for each token
-    pub fn batch_transfer(env: Env, params_vec: Vec<BatchTransferParams>) -> Result<(), Error> {
+    pub fn batch_transfer(
+        env: Env,
+        params_vec: Vec<BatchTransferParams>,
+    ) -> Result<(), ModuleError> {
         BatchValidator::validate_batch_size(params_vec.len())?;
 
         MembershipTokenContract::batch_transfer_tokens(env.clone(), params_vec.clone())?;
@@ -0,0 +1,296 @@
+use crate::billing_errors::BillingError;
+use crate::errors::ModuleError;
+use crate::events::{InvoiceIssued, InvoiceRefunded};
+use crate::membership_token::MembershipTokenContract;
+use crate::ttl::{TtlEntityClass, TtlModule};
+use crate::types::{
+    BillingKind, BillingRecord, FeeBreakdown, Invoice, InvoiceLineItem, InvoiceStatus,
+};
+use soroban_sdk::{contracttype, vec, Address, Env, String, Vec};
+
+// ---------------------------------------------------------------------------
+// Storage keys
+// ---------------------------------------------------------------------------
+
+#[contracttype]
+pub enum BillingDataKey {
+    /// Number of billing records of a subscription (persistent storage).
+    RecordCount(String),
+    /// Billing record by subscription ID and index, oldest first
+    /// (persistent storage).
+    Record(String, u32),
+    /// Number the next invoice will receive (instance storage).
+    NextInvoiceId,
+    /// Invoice by invoice number (persistent storage).
+    Invoice(u64),
+    /// Number of invoices issued to a payer (persistent storage).
+    UserInvoiceCount(Address),
+    /// Invoice number by payer and index, oldest first (persistent storage).
+    UserInvoice(Address, u32),
+    /// Tax rate included in every charge, in basis points (instance storage).
+    TaxRate,
+}
+
+// ---------------------------------------------------------------------------
+// Constants
+// ---------------------------------------------------------------------------
+
+/// Most records `get_billing_history` returns per call.
+pub const MAX_BILLING_PAGE: u32 = 50;
+
+/// Highest configurable tax rate (50 %).
+pub const MAX_TAX_RATE_BPS: u32 = 5_000;
+
+// ---------------------------------------------------------------------------
+// Module
+// ---------------------------------------------------------------------------
+
+/// Ledger of what each subscription was actually charged. Every payment
+/// path appends one record per charge, and each record gets an invoice.
+pub struct BillingModule;
+
+impl BillingModule {
+    /// Appends a billing record for `charge`, paid in `payment_token`,
+    /// against `subscription_id` and issues its invoice.
+    pub(crate) fn record(
+        env: &Env,
+        subscription_id: &String,
+        payer: &Address,
+        kind: BillingKind,
+        payment_token: &Address,
+        charge: &FeeBreakdown,
+        promo_code: Option<String>,
+    ) {
+        let invoice_id =
+            Self::issue_invoice(env, subscription_id, payer, &kind, payment_token, charge);
+        let record = BillingRecord {
+            subscription_id: subscription_id.clone(),
+            payer: payer.clone(),
+            kind,
+            amount: charge.total,
+            payment_token: payment_token.clone(),
+            promo_code,
+            timestamp: env.ledger().timestamp(),
+            invoice_id,
+        };
+
+        let count_key = BillingDataKey::RecordCount(subscription_id.clone());
+        let index: u32 = env.storage().persistent().get(&count_key).unwrap_or(0);
+        let key = BillingDataKey::Record(subscription_id.clone(), index);
+        env.storage().persistent().set(&key, &record);
+        env.storage().persistent().set(&count_key, &(index + 1));
+        TtlModule::bump(env, TtlEntityClass::History, &key);
+        TtlModule::bump(env, TtlEntityClass::History, &count_key);
+    }
+
+    /// Returns up to `limit` billing records of a subscription starting at
+    /// `offset`, oldest first. `limit` is capped at `MAX_BILLING_PAGE`.
+    pub fn get_billing_history(
+        env: Env,
+        subscription_id: String,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<BillingRecord> {
+        let count: u32 = env
+            .storage()
+            .persistent()
+            .get(&BillingDataKey::RecordCount(subscription_id.clone()))
+            .unwrap_or(0);
+        let end = offset
+            .saturating_add(limit.min(MAX_BILLING_PAGE))
+            .min(count);
+
+        let mut page = Vec::new(&env);
+        for index in offset..end {
+            if let Some(record) = env
+                .storage()
+                .persistent()
+                .get(&BillingDataKey::Record(subscription_id.clone(), index))
+            {
+                page.push_back(record);
+            }
+        }
+        page
+    }
+
+    // -----------------------------------------------------------------------
+    // Invoices
+    // -----------------------------------------------------------------------
+
+    /// Stores the invoice for one charge and returns its number. The price
+    /// and each fee get their own line. A charge without regional tax is
+    /// treated as tax-inclusive, and its tax is carved out of the total at
+    /// the invoice tax rate.
+    fn issue_invoice(
+        env: &Env,
+        subscription_id: &String,
+        payer: &Address,
+        kind: &BillingKind,
+        payment_token: &Address,
+        charge: &FeeBreakdown,
+    ) -> u64 {
+        let invoice_id: u64 = env
+            .storage()
+            .instance()
+            .get(&BillingDataKey::NextInvoiceId)
+            .unwrap_or(1);
+        env.storage()
+            .instance()
+            .set(&BillingDataKey::NextInvoiceId, &(invoice_id + 1));
+
+        let amount = charge.total;
+        let tax = if charge.tax > 0 {
+            charge.tax
+        } else {
+            let rate = Self::get_tax_rate(env) as i128;
+            amount * rate / (10_000 + rate)
+        };
+        let description = match kind {
+            BillingKind::Create => "Subscription",
+            BillingKind::Renew => "Subscription renewal",
+            BillingKind::TierChange => "Tier upgrade",
+            BillingKind::Installment => "Installment payment",
+            BillingKind::Reactivate => "Subscription reactivation",
+        };
+        let mut line_items = vec![
+            env,
+            InvoiceLineItem {
+                description: String::from_str(env, description),
+                amount: charge.base,
+            },
+        ];
+        for (description, fee) in [
+            ("Platform fee", charge.platform_fee),
+            ("Processing fee", charge.processing_fee),
+        ] {
+            if fee > 0 {
+                line_items.push_back(InvoiceLineItem {
+                    description: String::from_str(env, description),
+                    amount: fee,
+                });
+            }
+        }
+        let invoice = Invoice {
+            invoice_id,
+            subscription_id: subscription_id.clone(),
+            payer: payer.clone(),
+            payment_token: payment_token.clone(),
+            line_items,
+            subtotal: amount - tax,
+            tax,
+            total: amount,
+            status: InvoiceStatus::Paid,
+            issued_at: env.ledger().timestamp(),
+            refunded_at: None,
+        };
+        Self::save_invoice(env, &invoice);
+
+        let count_key = BillingDataKey::UserInvoiceCount(payer.clone());
+        let index: u32 = env.storage().persistent().get(&count_key).unwrap_or(0);
+        let key = BillingDataKey::UserInvoice(payer.clone(), index);
+        env.storage().persistent().set(&key, &invoice_id);
+        env.storage().persistent().set(&count_key, &(index + 1));
+        TtlModule::bump(env, TtlEntityClass::History, &key);
+        TtlModule::bump(env, TtlEntityClass::History, &count_key);
+
+        InvoiceIssued {
+            invoice_id,
+            payer: payer.clone(),
+            subscription_id: subscription_id.clone(),
+            total: amount,
+        }
+        .publish(env);
+        invoice_id
+    }
+
+    fn save_invoice(env: &Env, invoice: &Invoice) {
+        let key = BillingDataKey::Invoice(invoice.invoice_id);
+        env.storage().persistent().set(&key, invoice);
+        TtlModule::bump(env, TtlEntityClass::History, &key);
+    }
+
+    /// Returns an invoice by number.
+    pub fn get_invoice(env: Env, invoice_id: u64) -> Result<Invoice, ModuleError> {
+        env.storage()
+            .persistent()
+            .get(&BillingDataKey::Invoice(invoice_id))
+            .ok_or_else(|| BillingError::InvoiceNotFound.into())
+    }
+
+    /// Returns up to `limit` invoices paid by `user` starting at `offset`,
+    /// oldest first. `limit` is capped at `MAX_BILLING_PAGE`.
+    pub fn get_invoices_for_user(env: Env, user: Address, offset: u32, limit: u32) -> Vec<Invoice> {
+        let count: u32 = env
+            .storage()
+            .persistent()
+            .get(&BillingDataKey::UserInvoiceCount(user.clone()))
+            .unwrap_or(0);
+        let end = offset
+            .saturating_add(limit.min(MAX_BILLING_PAGE))
+            .min(count);
+
+        let mut page = Vec::new(&env);
+        for index in offset..end {
+            let invoice = env
+                .storage()
+                .persistent()
+                .get::<_, u64>(&BillingDataKey::UserInvoice(user.clone(), index))
+                .and_then(|invoice_id| {
+                    env.storage()
+                        .persistent()
+                        .get(&BillingDataKey::Invoice(invoice_id))
+                });
+            if let Some(invoice) = invoice {
+                page.push_back(invoice);
+            }
+        }
+        page
+    }
+
+    /// Marks a paid invoice as refunded. Admin only. The refund itself is
+    /// settled outside the contract.
+    pub fn mark_invoice_refunded(
+        env: Env,
+        admin: Address,
+        invoice_id: u64,
+    ) -> Result<(), ModuleError> {
+        MembershipTokenContract::require_admin(&env, &admin)?;
+
+        let mut invoice = Self::get_invoice(env.clone(), invoice_id)?;
+        if invoice.status == InvoiceStatus::Refunded {
+            return Err(BillingError::InvoiceAlreadyRefunded.into());
+        }
+        invoice.status = InvoiceStatus::Refunded;
+        invoice.refunded_at = Some(env.ledger().timestamp());
+        Self::save_invoice(&env, &invoice);
+
+        InvoiceRefunded {
+            invoice_id,
+            payer: invoice.payer,
+            total: invoice.total,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    /// Sets the tax rate included in every charge, in basis points. Admin
+    /// only. Applies to invoices issued afterwards.
+    pub fn set_tax_rate(env: Env, admin: Address, rate_bps: u32) -> Result<(), ModuleError> {
+        MembershipTokenContract::require_admin(&env, &admin)?;
+        if rate_bps > MAX_TAX_RATE_BPS {
+            return Err(BillingError::InvalidTaxRate.into());
+        }
+        env.storage()
+            .instance()
+            .set(&BillingDataKey::TaxRate, &rate_bps);
+        Ok(())
+    }
+
+    /// Returns the tax rate in basis points (0 until the admin sets one).
+    pub fn get_tax_rate(env: &Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&BillingDataKey::TaxRate)
+            .unwrap_or(0)
+    }
+}
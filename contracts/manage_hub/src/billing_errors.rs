@@ -0,0 +1,19 @@
+//! Billing-related error types for the ManageHub contract.
+//!
+//! `BillingError` owns the code range 550–599 (see [`crate::errors`]).
+
+use soroban_sdk::contracterror;
+
+/// Billing-specific errors.
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum BillingError {
+    /// No invoice exists with the given ID.
+    InvoiceNotFound = 550,
+    /// The invoice has already been marked as refunded.
+    InvoiceAlreadyRefunded = 551,
+    /// The tax rate exceeds `MAX_TAX_RATE_BPS`.
+    InvalidTaxRate = 552,
+    /// A fee rate exceeds `MAX_FEE_BPS`, or the processing fee is negative.
+    InvalidFeeConfig = 553,
+}
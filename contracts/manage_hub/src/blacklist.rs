@@ -0,0 +1,84 @@
+use crate::blacklist_errors::BlacklistError;
+use crate::errors::{Error, ModuleError};
+use crate::events::BlacklistEnforcementUpdated;
+use crate::initialization::InitializationModule;
+use crate::membership_token::MembershipTokenContract;
+use crate::types::BlacklistOperation;
+use soroban_sdk::{contracttype, Address, Env, IntoVal, Symbol, Val, Vec};
+
+// ---------------------------------------------------------------------------
+// Storage keys
+// ---------------------------------------------------------------------------
+
+#[contracttype]
+pub enum BlacklistDataKey {
+    /// Whether the access_control blacklist is enforced for an operation
+    /// type (instance storage).
+    Enforced(BlacklistOperation),
+}
+
+// ---------------------------------------------------------------------------
+// Module
+// ---------------------------------------------------------------------------
+
+/// Applies the access_control contract's blacklist to manage_hub operations.
+///
+/// Enforcement is opt-in per operation type. Once enabled, the check fails
+/// closed: if the access_control contract cannot be queried the operation is
+/// refused rather than allowed through.
+pub struct BlacklistModule;
+
+impl BlacklistModule {
+    pub fn set_enforcement(
+        env: Env,
+        admin: Address,
+        operation: BlacklistOperation,
+        enabled: bool,
+    ) -> Result<(), Error> {
+        MembershipTokenContract::require_admin(&env, &admin)?;
+
+        env.storage()
+            .instance()
+            .set(&BlacklistDataKey::Enforced(operation.clone()), &enabled);
+
+        BlacklistEnforcementUpdated {
+            operation,
+            enabled,
+            updated_by: admin,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    pub fn is_enforced(env: &Env, operation: BlacklistOperation) -> bool {
+        env.storage()
+            .instance()
+            .get(&BlacklistDataKey::Enforced(operation))
+            .unwrap_or(false)
+    }
+
+    /// Errors with `UserBlacklisted` if enforcement is on for `operation`
+    /// and `user` is blacklisted.
+    pub(crate) fn require_not_blacklisted(
+        env: &Env,
+        operation: BlacklistOperation,
+        user: &Address,
+    ) -> Result<(), ModuleError> {
+        if !Self::is_enforced(env, operation) {
+            return Ok(());
+        }
+
+        let access_control = InitializationModule::get_access_control(env)
+            .ok_or(BlacklistError::BlacklistUnavailable)?;
+        let args: Vec<Val> = Vec::from_array(env, [user.into_val(env)]);
+        match env.try_invoke_contract::<bool, soroban_sdk::Error>(
+            &access_control,
+            &Symbol::new(env, "is_blacklisted"),
+            args,
+        ) {
+            Ok(Ok(false)) => Ok(()),
+            Ok(Ok(true)) => Err(BlacklistError::UserBlacklisted.into()),
+            _ => Err(BlacklistError::BlacklistUnavailable.into()),
+        }
+    }
+}
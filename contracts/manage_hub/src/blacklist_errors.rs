@@ -0,0 +1,15 @@
+//! Blacklist enforcement error types for the ManageHub contract.
+//!
+//! `BlacklistError` owns the code range 600–649 (see [`crate::errors`]).
+
+use soroban_sdk::contracterror;
+
+/// Blacklist enforcement errors.
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum BlacklistError {
+    /// The address is blacklisted in the access_control contract.
+    UserBlacklisted = 600,
+    /// Enforcement is on but the access_control contract could not be queried.
+    BlacklistUnavailable = 601,
+}
@@ -0,0 +1,33 @@
+//! Session booking error types for the ManageHub contract.
+//!
+//! `BookingError` owns the code range 650–699 (see [`crate::errors`]).
+
+use soroban_sdk::contracterror;
+
+/// Booking-specific errors.
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum BookingError {
+    /// No session exists with the given id.
+    SessionNotFound = 650,
+    /// A session with the given id already exists.
+    SessionAlreadyExists = 651,
+    /// The session's times or capacity are invalid.
+    InvalidSession = 652,
+    /// Every seat in the session is taken.
+    SessionFull = 653,
+    /// The subscription has used its bookings for the current period.
+    QuotaExceeded = 654,
+    /// The subscription already holds a live booking for the session.
+    AlreadyBooked = 655,
+    /// The subscription holds no booking for the session.
+    BookingNotFound = 656,
+    /// The booking was already cancelled, attended or marked as a no-show.
+    BookingNotActive = 657,
+    /// The session has started, or the cancellation cutoff has passed.
+    BookingClosed = 658,
+    /// Check-in is only open around the session's scheduled time.
+    CheckInClosed = 659,
+    /// No-shows can only be recorded once the session has ended.
+    SessionNotEnded = 660,
+}
@@ -0,0 +1,517 @@
+use crate::attendance_log::AttendanceLogModule;
+use crate::booking_errors::BookingError;
+use crate::errors::{Error, ModuleError};
+use crate::events::{
+    BookingCancelled, BookingQuotaSet, NoShowsRecorded, SessionBooked, SessionCreated,
+};
+use crate::membership_token::MembershipTokenContract;
+use crate::subscription::SubscriptionContract;
+use crate::types::{
+    AttendanceAction, Booking, BookingStatus, BookingUsage, CreateSessionParams, MembershipStatus,
+    Session, Subscription,
+};
+use soroban_sdk::{contracttype, Address, BytesN, Env, Map, String, Vec};
+
+// ---------------------------------------------------------------------------
+// Storage keys
+// ---------------------------------------------------------------------------
+
+#[contracttype]
+pub enum BookingDataKey {
+    /// Scheduled session (persistent storage).
+    Session(String),
+    /// Subscriptions that have booked a session, in booking order
+    /// (persistent storage).
+    SessionBookings(String),
+    /// A subscription's booking for a session (persistent storage).
+    SessionBooking(String, String),
+    /// Session bookings per quota period for a tier (persistent storage).
+    SessionQuota(String),
+    /// Session bookings made in the current period of a subscription
+    /// (persistent storage).
+    SessionUsage(String),
+    /// Seconds before a session starts after which bookings can no longer
+    /// be cancelled (instance storage).
+    CancelCutoff,
+    /// Sessions a member booked but did not attend (persistent storage).
+    NoShowCount(Address),
+    /// Subscription a member last booked a session with (persistent storage).
+    MemberBooking(String, Address),
+}
+
+// ---------------------------------------------------------------------------
+// Constants
+// ---------------------------------------------------------------------------
+
+/// Length of a booking quota period. Periods are anchored at the
+/// subscription's creation, like guest quota periods.
+pub const BOOKING_QUOTA_PERIOD: u64 = 30 * 24 * 60 * 60;
+
+/// Cancellation cutoff used until the admin configures one.
+pub const DEFAULT_CANCEL_CUTOFF: u64 = 2 * 60 * 60;
+
+/// Check-in opens this long before a session starts.
+pub const SESSION_CHECK_IN_WINDOW: u64 = 15 * 60;
+
+/// Upper bound on session capacity, keeping the booking list bounded.
+pub const MAX_SESSION_CAPACITY: u32 = 200;
+
+/// Log detail naming the session a log belongs to.
+const SESSION_DETAIL: &str = "session_id";
+
+/// Log detail naming the subscription that booked the session.
+const SUBSCRIPTION_DETAIL: &str = "subscription_id";
+
+// ---------------------------------------------------------------------------
+// Module
+// ---------------------------------------------------------------------------
+
+/// Class and session bookings.
+///
+/// The admin schedules sessions with a capacity and, optionally, features a
+/// subscription needs to attend. Each booking spends one of the tier's
+/// bookings for the current 30-day period; cancelling before the cutoff
+/// returns it. Members check in against their booking, and bookings left
+/// unattended once the session ends are recorded as no-shows.
+pub struct BookingModule;
+
+impl BookingModule {
+    pub fn create_session(
+        env: Env,
+        admin: Address,
+        params: CreateSessionParams,
+    ) -> Result<Session, ModuleError> {
+        MembershipTokenContract::require_admin(&env, &admin)?;
+
+        let now = env.ledger().timestamp();
+        if params.start_time <= now
+            || params.end_time <= params.start_time
+            || params.capacity == 0
+            || params.capacity > MAX_SESSION_CAPACITY
+        {
+            return Err(BookingError::InvalidSession.into());
+        }
+
+        let key = BookingDataKey::Session(params.id.clone());
+        if env.storage().persistent().has(&key) {
+            return Err(BookingError::SessionAlreadyExists.into());
+        }
+
+        let session = Session {
+            id: params.id.clone(),
+            title: params.title,
+            start_time: params.start_time,
+            end_time: params.end_time,
+            capacity: params.capacity,
+            booked: 0,
+            required_features: params.required_features,
+            created_at: now,
+            no_shows_recorded: false,
+        };
+        env.storage().persistent().set(&key, &session);
+
+        SessionCreated {
+            session_id: params.id,
+            start_time: session.start_time,
+            end_time: session.end_time,
+            capacity: session.capacity,
+        }
+        .publish(&env);
+        Ok(session)
+    }
+
+    pub fn get_session(env: &Env, session_id: &String) -> Result<Session, ModuleError> {
+        env.storage()
+            .persistent()
+            .get(&BookingDataKey::Session(session_id.clone()))
+            .ok_or(BookingError::SessionNotFound.into())
+    }
+
+    pub fn set_tier_quota(
+        env: Env,
+        admin: Address,
+        tier_id: String,
+        monthly_quota: u32,
+    ) -> Result<(), Error> {
+        MembershipTokenContract::require_admin(&env, &admin)?;
+        SubscriptionContract::get_tier(env.clone(), tier_id.clone())?;
+        env.storage().persistent().set(
+            &BookingDataKey::SessionQuota(tier_id.clone()),
+            &monthly_quota,
+        );
+
+        BookingQuotaSet {
+            tier_id,
+            monthly_quota,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    pub fn get_tier_quota(env: &Env, tier_id: &String) -> u32 {
+        env.storage()
+            .persistent()
+            .get(&BookingDataKey::SessionQuota(tier_id.clone()))
+            .unwrap_or(0)
+    }
+
+    pub fn set_cancel_cutoff(env: Env, admin: Address, cutoff_secs: u64) -> Result<(), Error> {
+        MembershipTokenContract::require_admin(&env, &admin)?;
+        env.storage()
+            .instance()
+            .set(&BookingDataKey::CancelCutoff, &cutoff_secs);
+        Ok(())
+    }
+
+    pub fn get_cancel_cutoff(env: &Env) -> u64 {
+        env.storage()
+            .instance()
+            .get(&BookingDataKey::CancelCutoff)
+            .unwrap_or(DEFAULT_CANCEL_CUTOFF)
+    }
+
+    /// Books a seat in `session_id` for the subscription, spending one of
+    /// its bookings for the current period. Requires the subscriber's auth,
+    /// an active subscription and every feature the session requires.
+    pub fn book_session(
+        env: Env,
+        subscription_id: String,
+        session_id: String,
+    ) -> Result<Booking, ModuleError> {
+        let subscription = Self::get_active_subscription(&env, &subscription_id)?;
+        subscription.user.require_auth();
+
+        let mut session = Self::get_session(&env, &session_id)?;
+        let now = env.ledger().timestamp();
+        if now >= session.start_time {
+            return Err(BookingError::BookingClosed.into());
+        }
+        for feature in session.required_features.iter() {
+            SubscriptionContract::require_feature_access(
+                env.clone(),
+                subscription_id.clone(),
+                feature,
+            )?;
+        }
+
+        let booking_key =
+            BookingDataKey::SessionBooking(session_id.clone(), subscription_id.clone());
+        let previous = env.storage().persistent().get::<_, Booking>(&booking_key);
+        if previous
+            .as_ref()
+            .is_some_and(|booking| booking.status == BookingStatus::Booked)
+        {
+            return Err(BookingError::AlreadyBooked.into());
+        }
+        if session.booked >= session.capacity {
+            return Err(BookingError::SessionFull.into());
+        }
+
+        let mut usage = Self::current_usage(&env, &subscription);
+        if usage.used >= usage.quota {
+            return Err(BookingError::QuotaExceeded.into());
+        }
+        usage.used += 1;
+        env.storage().persistent().set(
+            &BookingDataKey::SessionUsage(subscription_id.clone()),
+            &usage,
+        );
+
+        session.booked += 1;
+        env.storage()
+            .persistent()
+            .set(&BookingDataKey::Session(session_id.clone()), &session);
+
+        // A cancelled booking is already on the session's list
+        if previous.is_none() {
+            let list_key = BookingDataKey::SessionBookings(session_id.clone());
+            let mut bookings: Vec<String> = env
+                .storage()
+                .persistent()
+                .get(&list_key)
+                .unwrap_or_else(|| Vec::new(&env));
+            bookings.push_back(subscription_id.clone());
+            env.storage().persistent().set(&list_key, &bookings);
+        }
+
+        let booking = Booking {
+            session_id: session_id.clone(),
+            subscription_id: subscription_id.clone(),
+            user: subscription.user.clone(),
+            status: BookingStatus::Booked,
+            booked_at: now,
+            checked_in_at: 0,
+        };
+        env.storage().persistent().set(&booking_key, &booking);
+        env.storage().persistent().set(
+            &BookingDataKey::MemberBooking(session_id.clone(), subscription.user.clone()),
+            &subscription_id,
+        );
+
+        SessionBooked {
+            session_id,
+            subscription_id,
+            user: subscription.user,
+        }
+        .publish(&env);
+        Ok(booking)
+    }
+
+    /// Cancels a booking, releasing its seat. Allowed until the cutoff
+    /// before the session starts; the booking is returned to the quota if
+    /// it was made in the current period.
+    pub fn cancel_booking(
+        env: Env,
+        subscription_id: String,
+        session_id: String,
+    ) -> Result<(), ModuleError> {
+        let booking_key =
+            BookingDataKey::SessionBooking(session_id.clone(), subscription_id.clone());
+        let mut booking: Booking = env
+            .storage()
+            .persistent()
+            .get(&booking_key)
+            .ok_or(BookingError::BookingNotFound)?;
+        booking.user.require_auth();
+        if booking.status != BookingStatus::Booked {
+            return Err(BookingError::BookingNotActive.into());
+        }
+
+        let mut session = Self::get_session(&env, &session_id)?;
+        let now = env.ledger().timestamp();
+        if now.saturating_add(Self::get_cancel_cutoff(&env)) > session.start_time {
+            return Err(BookingError::BookingClosed.into());
+        }
+
+        booking.status = BookingStatus::Cancelled;
+        env.storage().persistent().set(&booking_key, &booking);
+        session.booked -= 1;
+        env.storage()
+            .persistent()
+            .set(&BookingDataKey::Session(session_id.clone()), &session);
+
+        let subscription =
+            SubscriptionContract::get_subscription(env.clone(), subscription_id.clone())?;
+        let mut usage = Self::current_usage(&env, &subscription);
+        if booking.booked_at >= usage.period_start && usage.used > 0 {
+            usage.used -= 1;
+            env.storage().persistent().set(
+                &BookingDataKey::SessionUsage(subscription_id.clone()),
+                &usage,
+            );
+        }
+
+        BookingCancelled {
+            session_id,
+            subscription_id,
+            user: booking.user,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    /// Checks a member in to a booked session, logging a clock-in with the
+    /// session id in its details. Open from `SESSION_CHECK_IN_WINDOW` before
+    /// the start until the session ends.
+    pub fn check_in_session(
+        env: Env,
+        id: BytesN<32>,
+        subscription_id: String,
+        session_id: String,
+    ) -> Result<(), ModuleError> {
+        let booking = Self::get_booking(&env, &session_id, &subscription_id)
+            .ok_or(BookingError::BookingNotFound)?;
+        booking.user.require_auth();
+
+        let mut details = Map::new(&env);
+        details.set(String::from_str(&env, SESSION_DETAIL), session_id);
+        details.set(String::from_str(&env, SUBSCRIPTION_DETAIL), subscription_id);
+        AttendanceLogModule::record_attendance(
+            &env,
+            id,
+            booking.user,
+            AttendanceAction::ClockIn,
+            details,
+            true,
+        )
+    }
+
+    /// Validates a log that names a session in its details against the
+    /// member's booking. A clock-in checks the booking in and must fall in
+    /// the check-in window; a clock-out needs a booking already checked in.
+    /// Logs that name no session are left alone.
+    pub(crate) fn check_session_log(
+        env: &Env,
+        user: &Address,
+        action: &AttendanceAction,
+        details: &Map<String, String>,
+    ) -> Result<(), ModuleError> {
+        let Some(session_id) = details.get(String::from_str(env, SESSION_DETAIL)) else {
+            return Ok(());
+        };
+        let subscription_id = match details.get(String::from_str(env, SUBSCRIPTION_DETAIL)) {
+            Some(subscription_id) => subscription_id,
+            None => env
+                .storage()
+                .persistent()
+                .get(&BookingDataKey::MemberBooking(
+                    session_id.clone(),
+                    user.clone(),
+                ))
+                .ok_or(BookingError::BookingNotFound)?,
+        };
+        let booking_key =
+            BookingDataKey::SessionBooking(session_id.clone(), subscription_id.clone());
+        let mut booking: Booking = env
+            .storage()
+            .persistent()
+            .get(&booking_key)
+            .filter(|booking: &Booking| &booking.user == user)
+            .ok_or(BookingError::BookingNotFound)?;
+
+        if *action == AttendanceAction::ClockOut {
+            if booking.status != BookingStatus::Attended {
+                return Err(BookingError::BookingNotActive.into());
+            }
+            return Ok(());
+        }
+        if booking.status != BookingStatus::Booked {
+            return Err(BookingError::BookingNotActive.into());
+        }
+
+        let session = Self::get_session(env, &session_id)?;
+        let now = env.ledger().timestamp();
+        if now.saturating_add(SESSION_CHECK_IN_WINDOW) < session.start_time
+            || now > session.end_time
+        {
+            return Err(BookingError::CheckInClosed.into());
+        }
+        Self::get_active_subscription(env, &subscription_id)?;
+
+        booking.status = BookingStatus::Attended;
+        booking.checked_in_at = now;
+        env.storage().persistent().set(&booking_key, &booking);
+        Ok(())
+    }
+
+    /// Marks every booking still open once the session has ended as a
+    /// no-show. Admin only; returns the number of no-shows recorded.
+    pub fn record_no_shows(
+        env: Env,
+        admin: Address,
+        session_id: String,
+    ) -> Result<u32, ModuleError> {
+        MembershipTokenContract::require_admin(&env, &admin)?;
+
+        let mut session = Self::get_session(&env, &session_id)?;
+        if env.ledger().timestamp() <= session.end_time {
+            return Err(BookingError::SessionNotEnded.into());
+        }
+        if session.no_shows_recorded {
+            return Ok(0);
+        }
+
+        let mut no_shows = 0;
+        for subscription_id in Self::get_session_bookings(&env, &session_id).iter() {
+            let booking_key = BookingDataKey::SessionBooking(session_id.clone(), subscription_id);
+            let Some(mut booking) = env.storage().persistent().get::<_, Booking>(&booking_key)
+            else {
+                continue;
+            };
+            if booking.status != BookingStatus::Booked {
+                continue;
+            }
+            booking.status = BookingStatus::NoShow;
+            env.storage().persistent().set(&booking_key, &booking);
+
+            let count_key = BookingDataKey::NoShowCount(booking.user.clone());
+            let count: u32 = env.storage().persistent().get(&count_key).unwrap_or(0);
+            env.storage().persistent().set(&count_key, &(count + 1));
+            no_shows += 1;
+        }
+
+        session.no_shows_recorded = true;
+        env.storage()
+            .persistent()
+            .set(&BookingDataKey::Session(session_id.clone()), &session);
+
+        NoShowsRecorded {
+            session_id,
+            no_shows,
+        }
+        .publish(&env);
+        Ok(no_shows)
+    }
+
+    pub fn get_booking(
+        env: &Env,
+        session_id: &String,
+        subscription_id: &String,
+    ) -> Option<Booking> {
+        env.storage()
+            .persistent()
+            .get(&BookingDataKey::SessionBooking(
+                session_id.clone(),
+                subscription_id.clone(),
+            ))
+    }
+
+    /// Subscriptions that have booked the session, including cancelled
+    /// bookings.
+    pub fn get_session_bookings(env: &Env, session_id: &String) -> Vec<String> {
+        env.storage()
+            .persistent()
+            .get(&BookingDataKey::SessionBookings(session_id.clone()))
+            .unwrap_or_else(|| Vec::new(env))
+    }
+
+    pub fn get_no_show_count(env: &Env, user: &Address) -> u32 {
+        env.storage()
+            .persistent()
+            .get(&BookingDataKey::NoShowCount(user.clone()))
+            .unwrap_or(0)
+    }
+
+    /// Session bookings used and remaining in the subscription's current
+    /// period.
+    pub fn get_usage(env: Env, subscription_id: String) -> Result<BookingUsage, Error> {
+        let subscription = SubscriptionContract::get_subscription(env.clone(), subscription_id)?;
+        Ok(Self::current_usage(&env, &subscription))
+    }
+
+    /// Usage for the period containing now, starting a fresh period once the
+    /// stored one has ended.
+    fn current_usage(env: &Env, subscription: &Subscription) -> BookingUsage {
+        let now = env.ledger().timestamp();
+        let elapsed_periods = now.saturating_sub(subscription.created_at) / BOOKING_QUOTA_PERIOD;
+        let period_start = subscription.created_at + elapsed_periods * BOOKING_QUOTA_PERIOD;
+        let quota = Self::get_tier_quota(env, &subscription.tier_id);
+
+        let used = env
+            .storage()
+            .persistent()
+            .get::<_, BookingUsage>(&BookingDataKey::SessionUsage(subscription.id.clone()))
+            .filter(|usage| usage.period_start == period_start)
+            .map(|usage| usage.used)
+            .unwrap_or(0);
+
+        BookingUsage {
+            subscription_id: subscription.id.clone(),
+            period_start,
+            period_end: period_start + BOOKING_QUOTA_PERIOD,
+            used,
+            quota,
+        }
+    }
+
+    fn get_active_subscription(env: &Env, subscription_id: &String) -> Result<Subscription, Error> {
+        let subscription =
+            SubscriptionContract::get_subscription(env.clone(), subscription_id.clone())?;
+        if subscription.status != MembershipStatus::Active
+            || subscription.expires_at < env.ledger().timestamp()
+        {
+            return Err(Error::SubscriptionNotActive);
+        }
+        Ok(subscription)
+    }
+}
@@ -0,0 +1,244 @@
+use crate::billing::BillingModule;
+use crate::blacklist::BlacklistModule;
+use crate::errors::{Error, ModuleError};
+use crate::events::{BundleCreated, BundleDeactivated, BundleSubscribed};
+use crate::membership_token::MembershipTokenContract;
+use crate::subscription::SubscriptionContract;
+use crate::types::{
+    BillingCycle, BillingKind, BlacklistOperation, BundleEntitlements, CreateBundleParams,
+    MembershipStatus, SubscriptionBundle,
+};
+use common_types::TierFeature;
+use soroban_sdk::{contracttype, Address, Env, String, Vec};
+
+// ---------------------------------------------------------------------------
+// Storage keys
+// ---------------------------------------------------------------------------
+
+#[contracttype]
+pub enum BundleDataKey {
+    /// Bundle definition by ID (persistent storage).
+    Bundle(String),
+    /// IDs of all bundles (instance storage).
+    BundleList,
+    /// Entitlements from a member's most recent bundle purchase (persistent storage).
+    Entitlements(Address),
+}
+
+// ---------------------------------------------------------------------------
+// Module
+// ---------------------------------------------------------------------------
+
+/// Subscription plan bundles.
+///
+/// A bundle packages a subscription tier with a staking reward bonus and extra
+/// feature flags at a combined price. Subscribing provisions a regular tier
+/// subscription charged at the bundle price and records the extra
+/// entitlements, which stay in effect while that subscription is active.
+pub struct BundleModule;
+
+impl BundleModule {
+    // -----------------------------------------------------------------------
+    // Admin
+    // -----------------------------------------------------------------------
+
+    pub fn create_bundle(
+        env: Env,
+        admin: Address,
+        params: CreateBundleParams,
+    ) -> Result<(), Error> {
+        MembershipTokenContract::require_admin(&env, &admin)?;
+
+        if params.price <= 0 || params.annual_price <= 0 {
+            return Err(Error::InvalidTierPrice);
+        }
+        let key = BundleDataKey::Bundle(params.id.clone());
+        if env.storage().persistent().has(&key) {
+            return Err(Error::TierAlreadyExists);
+        }
+        // The underlying tier must exist
+        SubscriptionContract::get_tier(env.clone(), params.tier_id.clone())?;
+
+        let bundle = SubscriptionBundle {
+            id: params.id.clone(),
+            name: params.name,
+            tier_id: params.tier_id,
+            price: params.price,
+            annual_price: params.annual_price,
+            staking_bonus_bps: params.staking_bonus_bps,
+            extra_features: params.extra_features,
+            is_active: true,
+            created_at: env.ledger().timestamp(),
+        };
+        env.storage().persistent().set(&key, &bundle);
+
+        let mut list = Self::bundle_ids(&env);
+        list.push_back(params.id.clone());
+        env.storage()
+            .instance()
+            .set(&BundleDataKey::BundleList, &list);
+
+        BundleCreated {
+            bundle_id: params.id,
+            tier_id: bundle.tier_id,
+            price: bundle.price,
+            annual_price: bundle.annual_price,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    /// Stops new subscriptions to a bundle. Existing entitlements are kept.
+    pub fn deactivate_bundle(env: Env, admin: Address, bundle_id: String) -> Result<(), Error> {
+        MembershipTokenContract::require_admin(&env, &admin)?;
+
+        let mut bundle = Self::get_bundle(&env, &bundle_id)?;
+        bundle.is_active = false;
+        env.storage()
+            .persistent()
+            .set(&BundleDataKey::Bundle(bundle_id.clone()), &bundle);
+
+        BundleDeactivated { bundle_id, admin }.publish(&env);
+        Ok(())
+    }
+
+    pub fn get_bundle(env: &Env, bundle_id: &String) -> Result<SubscriptionBundle, Error> {
+        env.storage()
+            .persistent()
+            .get(&BundleDataKey::Bundle(bundle_id.clone()))
+            .ok_or(Error::TierNotFound)
+    }
+
+    pub fn get_bundles(env: &Env) -> Vec<SubscriptionBundle> {
+        let mut bundles = Vec::new(env);
+        for id in Self::bundle_ids(env).iter() {
+            if let Ok(bundle) = Self::get_bundle(env, &id) {
+                bundles.push_back(bundle);
+            }
+        }
+        bundles
+    }
+
+    fn bundle_ids(env: &Env) -> Vec<String> {
+        env.storage()
+            .instance()
+            .get(&BundleDataKey::BundleList)
+            .unwrap_or(Vec::new(env))
+    }
+
+    // -----------------------------------------------------------------------
+    // Subscribing
+    // -----------------------------------------------------------------------
+
+    /// Provisions subscription `id` on the bundle's tier, charged in USDC at
+    /// the bundle price, and records the bundle entitlements for `user`.
+    pub fn subscribe_to_bundle(
+        env: Env,
+        id: String,
+        user: Address,
+        bundle_id: String,
+        billing_cycle: BillingCycle,
+    ) -> Result<BundleEntitlements, ModuleError> {
+        user.require_auth();
+        BlacklistModule::require_not_blacklisted(&env, BlacklistOperation::Subscription, &user)?;
+
+        let bundle = Self::get_bundle(&env, &bundle_id)?;
+        if !bundle.is_active {
+            return Err(Error::TierNotActive.into());
+        }
+        let tier = SubscriptionContract::get_tier(env.clone(), bundle.tier_id.clone())?;
+        if !tier.is_active {
+            return Err(Error::TierNotActive.into());
+        }
+
+        let price = match billing_cycle {
+            BillingCycle::Monthly => bundle.price,
+            BillingCycle::Annual => bundle.annual_price,
+        };
+        let usdc = SubscriptionContract::get_usdc_contract_address(&env)?;
+        let (subscription, charge) = SubscriptionContract::provision_tier_subscription(
+            &env,
+            id.clone(),
+            user.clone(),
+            usdc,
+            &tier,
+            billing_cycle,
+            price,
+        )?;
+        BillingModule::record(
+            &env,
+            &subscription.id,
+            &user,
+            BillingKind::Create,
+            &subscription.payment_token,
+            &charge,
+            None,
+        );
+
+        let entitlements = BundleEntitlements {
+            bundle_id: bundle_id.clone(),
+            subscription_id: id,
+            staking_bonus_bps: bundle.staking_bonus_bps,
+            extra_features: bundle.extra_features,
+            granted_at: subscription.created_at,
+        };
+        env.storage()
+            .persistent()
+            .set(&BundleDataKey::Entitlements(user.clone()), &entitlements);
+
+        BundleSubscribed {
+            user,
+            bundle_id,
+            subscription_id: entitlements.subscription_id.clone(),
+            price,
+        }
+        .publish(&env);
+        Ok(entitlements)
+    }
+
+    // -----------------------------------------------------------------------
+    // Entitlements
+    // -----------------------------------------------------------------------
+
+    pub fn get_entitlements(env: &Env, user: &Address) -> Option<BundleEntitlements> {
+        env.storage()
+            .persistent()
+            .get(&BundleDataKey::Entitlements(user.clone()))
+    }
+
+    /// Entitlements whose bundle subscription is currently active.
+    fn active_entitlements(env: &Env, user: &Address) -> Option<BundleEntitlements> {
+        let entitlements = Self::get_entitlements(env, user)?;
+        let subscription = SubscriptionContract::get_subscription(
+            env.clone(),
+            entitlements.subscription_id.clone(),
+        )
+        .ok()?;
+        if subscription.status != MembershipStatus::Active
+            || subscription.expires_at < env.ledger().timestamp()
+        {
+            return None;
+        }
+        Some(entitlements)
+    }
+
+    /// Extra staking reward multiplier, in basis points, from an active bundle.
+    pub(crate) fn staking_bonus_bps(env: &Env, user: &Address) -> u32 {
+        Self::active_entitlements(env, user)
+            .map(|entitlements| entitlements.staking_bonus_bps)
+            .unwrap_or(0)
+    }
+
+    /// Whether the bundle bought with `subscription_id` adds `feature`.
+    pub(crate) fn grants_feature(
+        env: &Env,
+        user: &Address,
+        subscription_id: &String,
+        feature: &TierFeature,
+    ) -> bool {
+        Self::get_entitlements(env, user).is_some_and(|entitlements| {
+            entitlements.subscription_id == *subscription_id
+                && entitlements.extra_features.contains(feature)
+        })
+    }
+}
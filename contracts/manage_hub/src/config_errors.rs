@@ -0,0 +1,17 @@
+//! Errors for governed configuration changes in the ManageHub contract.
+//!
+//! `ConfigChangeError` owns the code range 700–749 (see [`crate::errors`]).
+
+use soroban_sdk::contracterror;
+
+/// Errors raised by propose/confirm configuration changes.
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum ConfigChangeError {
+    /// The value is already set; changing it requires a proposal.
+    ChangeRequiresProposal = 700,
+    /// There is no pending proposal to confirm or cancel.
+    NoPendingChange = 701,
+    /// The proposal's time delay has not elapsed yet.
+    TimelockActive = 702,
+}
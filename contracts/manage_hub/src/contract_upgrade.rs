@@ -0,0 +1,159 @@
+//! WASM upgrade of the ManageHub contract itself.
+//!
+//! Upgrades go through a schedule → time lock → execute flow. When an
+//! access_control contract is registered, only it may schedule upgrades, so a
+//! new WASM hash has to pass its multisig proposal flow first; deployments
+//! without one fall back to the admin. Either authority may cancel a
+//! scheduled upgrade or execute it once `CONTRACT_UPGRADE_DELAY` has passed.
+
+use crate::config_errors::ConfigChangeError;
+use crate::errors::{Error, ModuleError};
+use crate::events::{ContractUpgradeCancelled, ContractUpgradeScheduled, ContractUpgraded};
+use crate::initialization::InitializationModule;
+use crate::membership_token::MembershipTokenContract;
+use crate::types::ScheduledContractUpgrade;
+use soroban_sdk::{contracttype, Address, BytesN, Env};
+
+// ---------------------------------------------------------------------------
+// Storage keys
+// ---------------------------------------------------------------------------
+
+#[contracttype]
+pub enum ContractUpgradeDataKey {
+    /// Upgrade waiting for its time lock (instance storage).
+    Scheduled,
+    /// Number of WASM upgrades applied, starting at 1 (instance storage).
+    Version,
+}
+
+// ---------------------------------------------------------------------------
+// Constants
+// ---------------------------------------------------------------------------
+
+/// Delay between scheduling and executing a contract upgrade (48 hours).
+pub const CONTRACT_UPGRADE_DELAY: u64 = 48 * 60 * 60;
+
+// ---------------------------------------------------------------------------
+// Module
+// ---------------------------------------------------------------------------
+
+pub struct ContractUpgradeModule;
+
+impl ContractUpgradeModule {
+    /// Scheduling authority: the access_control contract if registered,
+    /// otherwise the admin.
+    fn require_scheduler(env: &Env, caller: &Address) -> Result<(), Error> {
+        match InitializationModule::get_access_control(env) {
+            Some(access_control) if access_control == *caller => {
+                caller.require_auth();
+                Ok(())
+            }
+            Some(_) => Err(Error::Unauthorized),
+            None => MembershipTokenContract::require_admin(env, caller),
+        }
+    }
+
+    /// Allows the admin or the registered access_control contract.
+    fn require_manager(env: &Env, caller: &Address) -> Result<(), Error> {
+        if InitializationModule::get_access_control(env).as_ref() == Some(caller) {
+            caller.require_auth();
+            return Ok(());
+        }
+        MembershipTokenContract::require_admin(env, caller)
+    }
+
+    /// Schedules an upgrade to `new_wasm_hash`, replacing any upgrade already
+    /// scheduled and restarting the time lock.
+    pub fn schedule_upgrade(
+        env: Env,
+        caller: Address,
+        new_wasm_hash: BytesN<32>,
+    ) -> Result<ScheduledContractUpgrade, Error> {
+        Self::require_scheduler(&env, &caller)?;
+
+        let now = env.ledger().timestamp();
+        let scheduled = ScheduledContractUpgrade {
+            new_wasm_hash: new_wasm_hash.clone(),
+            scheduled_by: caller.clone(),
+            scheduled_at: now,
+            executable_at: now
+                .checked_add(CONTRACT_UPGRADE_DELAY)
+                .ok_or(Error::TimestampOverflow)?,
+        };
+        env.storage()
+            .instance()
+            .set(&ContractUpgradeDataKey::Scheduled, &scheduled);
+
+        ContractUpgradeScheduled {
+            scheduled_by: caller,
+            new_wasm_hash,
+            executable_at: scheduled.executable_at,
+        }
+        .publish(&env);
+
+        Ok(scheduled)
+    }
+
+    /// Discards the scheduled upgrade.
+    pub fn cancel_upgrade(env: Env, caller: Address) -> Result<(), ModuleError> {
+        Self::require_manager(&env, &caller)?;
+        let scheduled =
+            Self::get_scheduled_upgrade(&env).ok_or(ConfigChangeError::NoPendingChange)?;
+
+        env.storage()
+            .instance()
+            .remove(&ContractUpgradeDataKey::Scheduled);
+
+        ContractUpgradeCancelled {
+            cancelled_by: caller,
+            new_wasm_hash: scheduled.new_wasm_hash,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    /// Replaces the contract's WASM with the scheduled hash once the time
+    /// lock has elapsed. The new code takes effect after this invocation.
+    pub fn execute_upgrade(env: Env, caller: Address) -> Result<u32, ModuleError> {
+        Self::require_manager(&env, &caller)?;
+        let scheduled =
+            Self::get_scheduled_upgrade(&env).ok_or(ConfigChangeError::NoPendingChange)?;
+
+        if env.ledger().timestamp() < scheduled.executable_at {
+            return Err(ConfigChangeError::TimelockActive.into());
+        }
+
+        let version = Self::get_version(&env)
+            .checked_add(1)
+            .ok_or(Error::TimestampOverflow)?;
+        let storage = env.storage().instance();
+        storage.remove(&ContractUpgradeDataKey::Scheduled);
+        storage.set(&ContractUpgradeDataKey::Version, &version);
+
+        ContractUpgraded {
+            executed_by: caller,
+            new_wasm_hash: scheduled.new_wasm_hash.clone(),
+            version,
+        }
+        .publish(&env);
+
+        env.deployer()
+            .update_current_contract_wasm(scheduled.new_wasm_hash);
+        Ok(version)
+    }
+
+    pub fn get_scheduled_upgrade(env: &Env) -> Option<ScheduledContractUpgrade> {
+        env.storage()
+            .instance()
+            .get(&ContractUpgradeDataKey::Scheduled)
+    }
+
+    /// Contract code version: 1 for the originally deployed WASM, incremented
+    /// by every executed upgrade.
+    pub fn get_version(env: &Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&ContractUpgradeDataKey::Version)
+            .unwrap_or(1)
+    }
+}
@@ -0,0 +1,21 @@
+//! Dispute error types for the ManageHub contract.
+//!
+//! `DisputeError` owns the code range 750–799 (see [`crate::errors`]).
+
+use soroban_sdk::contracterror;
+
+/// Dispute errors.
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum DisputeError {
+    /// No dispute exists with the given ID.
+    DisputeNotFound = 750,
+    /// The dispute has already been resolved.
+    AlreadyResolved = 751,
+    /// The disputed attendance log does not exist.
+    SubjectNotFound = 752,
+    /// The disputed token, subscription or log belongs to someone else.
+    NotSubjectOwner = 753,
+    /// A compensation outcome awarded no credit.
+    InvalidCompensation = 754,
+}
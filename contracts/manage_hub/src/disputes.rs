@@ -0,0 +1,228 @@
+use crate::attendance_log::AttendanceLogModule;
+use crate::dispute_errors::DisputeError;
+use crate::errors::{Error, ModuleError};
+use crate::events::{DisputeFiled, DisputeResolved};
+use crate::guards::PauseGuard;
+use crate::initialization::InitializationModule;
+use crate::loyalty::LoyaltyModule;
+use crate::membership_token::{
+    DataKey as MembershipDataKey, MembershipToken, MembershipTokenContract,
+};
+use crate::subscription::SubscriptionContract;
+use crate::types::{Dispute, DisputeOutcome, DisputeStatus, DisputeSubject};
+use soroban_sdk::{contracttype, symbol_short, Address, BytesN, Env, String, Vec};
+
+// ---------------------------------------------------------------------------
+// Storage keys
+// ---------------------------------------------------------------------------
+
+#[contracttype]
+pub enum DisputeDataKey {
+    /// ID the next filed dispute receives (instance storage).
+    NextDisputeId,
+    /// Dispute by ID (persistent storage).
+    Dispute(u64),
+    /// IDs of the disputes filed by a member (persistent storage).
+    UserDisputes(Address),
+    /// IDs of disputes awaiting resolution (persistent storage).
+    OpenDisputes,
+}
+
+// ---------------------------------------------------------------------------
+// Module
+// ---------------------------------------------------------------------------
+
+/// Member disputes against pauses, attendance records and other actions.
+///
+/// A member files a dispute about a token, subscription or attendance log
+/// they own, with the hash of off-chain evidence. The dispute is linked to
+/// the affected token or subscription and stays open until the admin or the
+/// access_control contract (acting on an approved multisig proposal)
+/// resolves it. Reversing a token dispute lifts any pause on the token once
+/// its time lock has elapsed; compensation is paid as loyalty points.
+pub struct DisputeModule;
+
+impl DisputeModule {
+    /// Allows the admin or the registered access_control contract.
+    fn require_resolver(env: &Env, caller: &Address) -> Result<(), Error> {
+        if InitializationModule::get_access_control(env).as_ref() == Some(caller) {
+            caller.require_auth();
+            return Ok(());
+        }
+        MembershipTokenContract::require_admin(env, caller)
+    }
+
+    pub fn file_dispute(
+        env: Env,
+        user: Address,
+        subject: DisputeSubject,
+        evidence_hash: BytesN<32>,
+    ) -> Result<u64, ModuleError> {
+        user.require_auth();
+        let (linked_token, linked_subscription) = Self::link_subject(&env, &user, &subject)?;
+
+        let id: u64 = env
+            .storage()
+            .instance()
+            .get(&DisputeDataKey::NextDisputeId)
+            .unwrap_or(0);
+        env.storage()
+            .instance()
+            .set(&DisputeDataKey::NextDisputeId, &(id + 1));
+
+        let dispute = Dispute {
+            id,
+            user: user.clone(),
+            subject: subject.clone(),
+            evidence_hash,
+            linked_token,
+            linked_subscription,
+            status: DisputeStatus::Open,
+            filed_at: env.ledger().timestamp(),
+            resolved_at: None,
+            resolved_by: None,
+            credited_points: 0,
+        };
+        env.storage()
+            .persistent()
+            .set(&DisputeDataKey::Dispute(id), &dispute);
+
+        let mut user_disputes = Self::get_user_disputes(&env, &user);
+        user_disputes.push_back(id);
+        env.storage()
+            .persistent()
+            .set(&DisputeDataKey::UserDisputes(user.clone()), &user_disputes);
+
+        let mut open = Self::get_open_disputes(&env);
+        open.push_back(id);
+        env.storage()
+            .persistent()
+            .set(&DisputeDataKey::OpenDisputes, &open);
+
+        DisputeFiled {
+            dispute_id: id,
+            user,
+            subject,
+        }
+        .publish(&env);
+        Ok(id)
+    }
+
+    /// Checks `user` owns the subject and returns the token and subscription
+    /// it concerns. Attendance disputes are linked to the member's first
+    /// token, if they hold one.
+    fn link_subject(
+        env: &Env,
+        user: &Address,
+        subject: &DisputeSubject,
+    ) -> Result<(Option<BytesN<32>>, Option<String>), ModuleError> {
+        match subject {
+            DisputeSubject::Token(token_id) => {
+                let token: MembershipToken = env
+                    .storage()
+                    .persistent()
+                    .get(&MembershipDataKey::Token(token_id.clone()))
+                    .ok_or(Error::TokenNotFound)?;
+                if &token.user != user {
+                    return Err(DisputeError::NotSubjectOwner.into());
+                }
+                Ok((Some(token_id.clone()), None))
+            }
+            DisputeSubject::Subscription(subscription_id) => {
+                let subscription =
+                    SubscriptionContract::get_subscription(env.clone(), subscription_id.clone())?;
+                if &subscription.user != user {
+                    return Err(DisputeError::NotSubjectOwner.into());
+                }
+                Ok((None, Some(subscription_id.clone())))
+            }
+            DisputeSubject::Attendance(log_id) => {
+                let log = AttendanceLogModule::get_attendance_log(env.clone(), log_id.clone())
+                    .ok_or(DisputeError::SubjectNotFound)?;
+                if &log.user_id != user {
+                    return Err(DisputeError::NotSubjectOwner.into());
+                }
+                let token = MembershipTokenContract::get_owner_tokens(env, user).first();
+                Ok((token, None))
+            }
+        }
+    }
+
+    pub fn resolve_dispute(
+        env: Env,
+        caller: Address,
+        dispute_id: u64,
+        outcome: DisputeOutcome,
+    ) -> Result<(), ModuleError> {
+        Self::require_resolver(&env, &caller)?;
+
+        let mut dispute = Self::get_dispute(&env, dispute_id)?;
+        if dispute.status != DisputeStatus::Open {
+            return Err(DisputeError::AlreadyResolved.into());
+        }
+
+        dispute.status = match outcome {
+            DisputeOutcome::Uphold => DisputeStatus::Upheld,
+            DisputeOutcome::Reverse => {
+                if let DisputeSubject::Token(token_id) = &dispute.subject {
+                    if PauseGuard::is_token_paused(&env, token_id) {
+                        PauseGuard::require_token_timelock_expired(&env, token_id)?;
+                        MembershipTokenContract::lift_token_pause(&env, &caller, token_id);
+                    }
+                }
+                DisputeStatus::Reversed
+            }
+            DisputeOutcome::Compensate(points) => {
+                if points == 0 {
+                    return Err(DisputeError::InvalidCompensation.into());
+                }
+                LoyaltyModule::award_points(&env, &dispute.user, points, symbol_short!("dispute"));
+                dispute.credited_points = points;
+                DisputeStatus::Compensated
+            }
+        };
+        dispute.resolved_at = Some(env.ledger().timestamp());
+        dispute.resolved_by = Some(caller.clone());
+        env.storage()
+            .persistent()
+            .set(&DisputeDataKey::Dispute(dispute_id), &dispute);
+
+        let mut open = Self::get_open_disputes(&env);
+        if let Some(index) = open.first_index_of(dispute_id) {
+            open.remove(index);
+        }
+        env.storage()
+            .persistent()
+            .set(&DisputeDataKey::OpenDisputes, &open);
+
+        DisputeResolved {
+            dispute_id,
+            user: dispute.user,
+            status: dispute.status,
+            resolved_by: caller,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    pub fn get_dispute(env: &Env, dispute_id: u64) -> Result<Dispute, ModuleError> {
+        env.storage()
+            .persistent()
+            .get(&DisputeDataKey::Dispute(dispute_id))
+            .ok_or(DisputeError::DisputeNotFound.into())
+    }
+
+    pub fn get_user_disputes(env: &Env, user: &Address) -> Vec<u64> {
+        env.storage()
+            .persistent()
+            .get(&DisputeDataKey::UserDisputes(user.clone()))
+            .unwrap_or(Vec::new(env))
+    }
+
+    pub fn get_open_disputes(env: &Env) -> Vec<u64> {
+        env.storage()
+            .persistent()
+            .get(&DisputeDataKey::OpenDisputes)
+            .unwrap_or(Vec::new(env))
+    }
+}
@@ -0,0 +1,323 @@
+use crate::entry_errors::EntryError;
+use crate::errors::{Error, ModuleError};
+use crate::events::{EntryPassIssued, EntryPassRedeemed, EntryPolicySet};
+use crate::federation::FederationModule;
+use crate::guards::PauseGuard;
+use crate::membership_token::{
+    DataKey as MembershipDataKey, MembershipToken, MembershipTokenContract,
+};
+use crate::operators::OperatorModule;
+use crate::staking::StakingModule;
+use crate::subscription::SubscriptionContract;
+use crate::types::{
+    AttendanceAction, EntryDecision, EntryPass, EntryPolicy, EntryStatus, MembershipStatus,
+};
+use soroban_sdk::xdr::ToXdr;
+use soroban_sdk::{contracttype, Address, BytesN, Env, Map, String};
+
+// ---------------------------------------------------------------------------
+// Storage keys
+// ---------------------------------------------------------------------------
+
+#[contracttype]
+pub enum EntryDataKey {
+    /// Policy applied by `verify_entry` (instance storage).
+    Policy,
+    /// One-time entry pass by ID (temporary storage).
+    Pass(BytesN<32>),
+    /// Counter mixed into pass IDs so each is unique (instance storage).
+    PassNonce,
+}
+
+// ---------------------------------------------------------------------------
+// Constants
+// ---------------------------------------------------------------------------
+
+/// Longest validity window for an entry pass (7 days).
+pub const MAX_ENTRY_PASS_VALIDITY: u64 = 7 * 24 * 60 * 60;
+
+/// Keep pass records for ~8 days (Stellar produces ~1 ledger / 5 s) so a
+/// redeemed or expired pass can still be looked up after its window.
+const ENTRY_PASS_TTL_LEDGERS: u32 = 138_240;
+
+// ---------------------------------------------------------------------------
+// Module
+// ---------------------------------------------------------------------------
+
+/// Door access decisions.
+///
+/// `verify_entry` folds token validity, subscription status and the optional
+/// staking requirement into one read-only check so access hardware needs a
+/// single call. Until the admin sets a policy, holding a valid token is
+/// enough. Members of federated partner hubs are let in on their home
+/// hub's word, within their visit quota.
+///
+/// Members can also issue one-time entry passes (rendered as QR codes) so a
+/// guest can be checked in by a kiosk without the member's key.
+pub struct EntryModule;
+
+impl EntryModule {
+    pub fn set_policy(env: Env, admin: Address, policy: EntryPolicy) -> Result<(), Error> {
+        MembershipTokenContract::require_admin(&env, &admin)?;
+        if policy.min_stake < 0 {
+            return Err(Error::InvalidPaymentAmount);
+        }
+        env.storage().instance().set(&EntryDataKey::Policy, &policy);
+
+        EntryPolicySet { admin, policy }.publish(&env);
+        Ok(())
+    }
+
+    pub fn get_policy(env: &Env) -> EntryPolicy {
+        env.storage()
+            .instance()
+            .get(&EntryDataKey::Policy)
+            .unwrap_or(EntryPolicy {
+                require_subscription: false,
+                grace_entry_secs: 0,
+                min_stake: 0,
+            })
+    }
+
+    /// Decides whether `user` may enter, falling back to partner hubs when
+    /// the user holds no membership that grants entry here.
+    pub fn verify_entry(env: Env, user: Address) -> EntryDecision {
+        let decision = Self::verify_home_entry(env.clone(), user.clone());
+        if decision.allowed {
+            return decision;
+        }
+        FederationModule::verify_partner_entry(&env, &user).unwrap_or(decision)
+    }
+
+    /// Decides whether `user` may enter on this hub's own tokens only. The
+    /// first token that passes the token checks is reported; otherwise the
+    /// last token's failure is. Partner hubs call this to vouch for their
+    /// members, so it must never consult partners itself.
+    pub fn verify_home_entry(env: Env, user: Address) -> EntryDecision {
+        let policy = Self::get_policy(&env);
+        let now = env.ledger().timestamp();
+
+        let mut status = EntryStatus::NoMembership;
+        let mut token_id = None;
+        for id in MembershipTokenContract::get_owner_tokens(&env, &user).iter() {
+            let Some(token) = env
+                .storage()
+                .persistent()
+                .get::<_, MembershipToken>(&MembershipDataKey::Token(id.clone()))
+            else {
+                continue;
+            };
+            status = Self::check_token(&env, &token, &policy, now);
+            token_id = Some(id);
+            if status == EntryStatus::Granted {
+                break;
+            }
+        }
+
+        if status == EntryStatus::Granted {
+            status = Self::check_member(&env, &user, &policy, now);
+        }
+
+        EntryDecision {
+            allowed: status == EntryStatus::Granted,
+            status,
+            token_id,
+            partner: None,
+            checked_at: now,
+        }
+    }
+
+    /// Issues a one-time pass for `token_id`, valid for `valid_for_secs`.
+    /// The token owner must authorize, and the membership must currently
+    /// satisfy the entry policy.
+    pub fn create_pass(
+        env: Env,
+        token_id: BytesN<32>,
+        valid_for_secs: u64,
+    ) -> Result<BytesN<32>, ModuleError> {
+        if valid_for_secs == 0 || valid_for_secs > MAX_ENTRY_PASS_VALIDITY {
+            return Err(EntryError::InvalidPassValidity.into());
+        }
+
+        let token: MembershipToken = env
+            .storage()
+            .persistent()
+            .get(&MembershipDataKey::Token(token_id.clone()))
+            .ok_or(Error::TokenNotFound)?;
+        token.user.require_auth();
+
+        let now = env.ledger().timestamp();
+        Self::require_entry(&env, &token, now)?;
+
+        let nonce: u64 = env
+            .storage()
+            .instance()
+            .get(&EntryDataKey::PassNonce)
+            .unwrap_or(0);
+        env.storage()
+            .instance()
+            .set(&EntryDataKey::PassNonce, &(nonce + 1));
+        let pass_id: BytesN<32> = env
+            .crypto()
+            .sha256(&(token_id.clone(), nonce, now).to_xdr(&env))
+            .into();
+
+        let pass = EntryPass {
+            pass_id: pass_id.clone(),
+            token_id: token_id.clone(),
+            issued_by: token.user.clone(),
+            created_at: now,
+            expires_at: now.saturating_add(valid_for_secs),
+            redeemed_at: None,
+            redeemed_by: None,
+        };
+        let key = EntryDataKey::Pass(pass_id.clone());
+        env.storage().temporary().set(&key, &pass);
+        env.storage()
+            .temporary()
+            .extend_ttl(&key, ENTRY_PASS_TTL_LEDGERS, ENTRY_PASS_TTL_LEDGERS);
+
+        EntryPassIssued {
+            token_id,
+            user: token.user,
+            pass_id: pass_id.clone(),
+            expires_at: pass.expires_at,
+        }
+        .publish(&env);
+
+        Ok(pass_id)
+    }
+
+    /// Redeems a pass from a kiosk: re-validates the membership, marks the
+    /// pass consumed and logs a clock-in for the member under the pass ID.
+    pub fn redeem_pass(
+        env: Env,
+        operator: Address,
+        pass_id: BytesN<32>,
+    ) -> Result<(), ModuleError> {
+        let key = EntryDataKey::Pass(pass_id.clone());
+        let mut pass: EntryPass = env
+            .storage()
+            .temporary()
+            .get(&key)
+            .ok_or(EntryError::PassNotFound)?;
+        if pass.redeemed_at.is_some() {
+            return Err(EntryError::PassAlreadyRedeemed.into());
+        }
+        let now = env.ledger().timestamp();
+        if now > pass.expires_at {
+            return Err(EntryError::PassExpired.into());
+        }
+
+        let token: MembershipToken = env
+            .storage()
+            .persistent()
+            .get(&MembershipDataKey::Token(pass.token_id.clone()))
+            .ok_or(Error::TokenNotFound)?;
+        Self::require_entry(&env, &token, now)?;
+
+        pass.redeemed_at = Some(now);
+        pass.redeemed_by = Some(operator.clone());
+        env.storage().temporary().set(&key, &pass);
+
+        let mut details = Map::new(&env);
+        details.set(
+            String::from_str(&env, "source"),
+            String::from_str(&env, "entry_pass"),
+        );
+        OperatorModule::log_attendance(
+            env.clone(),
+            operator.clone(),
+            pass_id.clone(),
+            token.user,
+            AttendanceAction::ClockIn,
+            details,
+        )?;
+
+        EntryPassRedeemed {
+            token_id: pass.token_id,
+            operator,
+            pass_id,
+            redeemed_at: now,
+        }
+        .publish(&env);
+
+        Ok(())
+    }
+
+    pub fn get_pass(env: &Env, pass_id: &BytesN<32>) -> Option<EntryPass> {
+        env.storage()
+            .temporary()
+            .get(&EntryDataKey::Pass(pass_id.clone()))
+    }
+
+    /// Fails unless `token` and its owner satisfy the entry policy.
+    fn require_entry(env: &Env, token: &MembershipToken, now: u64) -> Result<(), ModuleError> {
+        let policy = Self::get_policy(env);
+        let mut status = Self::check_token(env, token, &policy, now);
+        if status == EntryStatus::Granted {
+            status = Self::check_member(env, &token.user, &policy, now);
+        }
+        if status != EntryStatus::Granted {
+            return Err(EntryError::EntryDenied.into());
+        }
+        Ok(())
+    }
+
+    /// Applies the subscription and staking requirements to `user`.
+    fn check_member(env: &Env, user: &Address, policy: &EntryPolicy, now: u64) -> EntryStatus {
+        if policy.require_subscription && !Self::has_active_subscription(env, user, now) {
+            return EntryStatus::NoActiveSubscription;
+        }
+
+        if policy.min_stake > 0 {
+            let staked = StakingModule::get_stake_info(env.clone(), user.clone())
+                .filter(|_| StakingModule::get_unstake_request(env.clone(), user.clone()).is_none())
+                .map(|stake| stake.amount)
+                .unwrap_or(0);
+            if staked < policy.min_stake {
+                return EntryStatus::InsufficientStake;
+            }
+        }
+
+        EntryStatus::Granted
+    }
+
+    fn check_token(
+        env: &Env,
+        token: &MembershipToken,
+        policy: &EntryPolicy,
+        now: u64,
+    ) -> EntryStatus {
+        if token.status == MembershipStatus::Paused || PauseGuard::is_token_paused(env, &token.id) {
+            return EntryStatus::MembershipPaused;
+        }
+        match token.status {
+            MembershipStatus::Active if token.expiry_date < now => EntryStatus::MembershipExpired,
+            MembershipStatus::Active => EntryStatus::Granted,
+            MembershipStatus::GracePeriod => {
+                let entered_at = token.grace_period_entered_at.unwrap_or(token.expiry_date);
+                if policy.grace_entry_secs > 0
+                    && now <= entered_at.saturating_add(policy.grace_entry_secs)
+                {
+                    EntryStatus::Granted
+                } else {
+                    EntryStatus::GracePeriodExceeded
+                }
+            }
+            MembershipStatus::Expired => EntryStatus::MembershipExpired,
+            _ => EntryStatus::MembershipInactive,
+        }
+    }
+
+    fn has_active_subscription(env: &Env, user: &Address, now: u64) -> bool {
+        SubscriptionContract::get_user_subscription_ids(env, user)
+            .iter()
+            .any(|id| {
+                SubscriptionContract::get_subscription(env.clone(), id).is_ok_and(|subscription| {
+                    subscription.status == MembershipStatus::Active
+                        && subscription.expires_at >= now
+                })
+            })
+    }
+}
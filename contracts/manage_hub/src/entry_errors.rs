@@ -0,0 +1,21 @@
+//! Entry-access error types for the ManageHub contract.
+//!
+//! `EntryError` owns the code range 800–849 (see [`crate::errors`]).
+
+use soroban_sdk::contracterror;
+
+/// Entry-specific errors.
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum EntryError {
+    /// No entry pass exists with this ID.
+    PassNotFound = 800,
+    /// The entry pass has already been redeemed.
+    PassAlreadyRedeemed = 801,
+    /// The entry pass is past its validity window.
+    PassExpired = 802,
+    /// The requested pass validity is zero or above the maximum.
+    InvalidPassValidity = 803,
+    /// The membership behind the pass does not satisfy the entry policy.
+    EntryDenied = 804,
+}
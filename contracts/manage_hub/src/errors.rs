@@ -1,5 +1,53 @@
+//! Contract error codes.
+//!
+//! The contract spec caps a `#[contracterror]` enum at 50 variants, so
+//! [`Error`] only holds the shared codes. Every module has its own enum in a
+//! `*_errors.rs` file with a code range of its own, so a code identifies the
+//! module that raised it. Endpoints that can fail with a module code return
+//! [`ModuleError`].
+//!
+//! | Codes     | Enum                                                 |
+//! |-----------|------------------------------------------------------|
+//! | 1–50      | [`Error`]                                            |
+//! | 100–149   | [`crate::staking_errors::StakingError`]              |
+//! | 200–249   | [`crate::pause_errors::PauseError`]                  |
+//! | 300–349   | [`crate::upgrade_errors::UpgradeError`]              |
+//! | 400–449   | [`crate::achievement_errors::AchievementError`]      |
+//! | 450–499   | [`crate::admin_errors::AdminTransferError`]          |
+//! | 500–549   | [`crate::attendance_errors::AttendanceError`]        |
+//! | 550–599   | [`crate::billing_errors::BillingError`]              |
+//! | 600–649   | [`crate::blacklist_errors::BlacklistError`]          |
+//! | 650–699   | [`crate::booking_errors::BookingError`]              |
+//! | 700–749   | [`crate::config_errors::ConfigChangeError`]          |
+//! | 750–799   | [`crate::dispute_errors::DisputeError`]              |
+//! | 800–849   | [`crate::entry_errors::EntryError`]                  |
+//! | 850–899   | [`crate::factory_errors::FactoryError`]              |
+//! | 900–949   | [`crate::federation_errors::FederationError`]        |
+//! | 950–999   | [`crate::fraction_errors::FractionError`]            |
+//! | 1000–1049 | [`crate::guest_errors::GuestError`]                  |
+//! | 1050–1099 | [`crate::household_errors::HouseholdError`]          |
+//! | 1100–1149 | [`crate::init_errors::InitError`]                    |
+//! | 1150–1199 | [`crate::installment_errors::InstallmentError`]      |
+//! | 1200–1249 | [`crate::loyalty_errors::LoyaltyError`]              |
+//! | 1250–1299 | [`crate::oracle_errors::OracleError`]                |
+//! | 1300–1349 | [`crate::pause_schedule_errors::PauseScheduleError`] |
+//! | 1350–1399 | [`crate::resource_errors::ResourceError`]            |
+//! | 1400–1449 | [`crate::retention_errors::RetentionError`]          |
+//! | 1450–1499 | [`crate::sale_errors::SaleError`]                    |
+//! | 1500–1549 | [`crate::staff_errors::StaffError`]                  |
+//! | 1550–1599 | [`crate::tier_change_errors::TierChangeError`]       |
+//! | 1600–1649 | [`crate::treasury_errors::TreasuryError`]            |
+//! | 1650–1699 | [`crate::verification_errors::VerificationError`]    |
+//!
+//! Subscription lifecycle errors have no range; they are reported with the
+//! [`Error`] code for the subscription's state.
+
 use soroban_sdk::contracterror;
 
+/// Error type of endpoints that can fail with a code from a module range as
+/// well as an [`Error`] code. Both convert into it with `?`.
+pub type ModuleError = soroban_sdk::Error;
+
 #[contracterror]
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
 pub enum Error {
@@ -0,0 +1,1646 @@
+//! Typed contract events, grouped by the module that emits them.
+//!
+//! Every event carries two fixed topics: a stable snake_case event name
+//! followed by the schema version (`"v1"`). Fields marked `#[topic]` are
+//! appended to the topic list for indexing; the remaining fields form the
+//! event data map, keyed by field name. A breaking change to any payload must
+//! ship as a new struct with a bumped version topic rather than editing the
+//! existing one, so indexers can keep decoding older events.
+
+use crate::types::{
+    BadgeDefinition, BillingCycle, BlacklistOperation, DisputeStatus, DisputeSubject, EntryPolicy,
+    FractionTransferPolicy, GracePolicy, LoyaltyEarnRate, LoyaltyReward, MembershipStatus,
+    NotificationKind, PauseHistoryEntry, RenewalConfig, RenewalConsent, ReservationStatus,
+    ResourceKind, TierChangeType, TierFeature, TierLevel, TtlPolicy, VerificationPolicy,
+};
+use soroban_sdk::{contractevent, Address, BytesN, String, Symbol, Vec};
+
+// ============================================================================
+// Membership Token Events
+// ============================================================================
+
+/// A membership token was issued.
+#[contractevent(topics = ["token_issued", "v1"])]
+#[derive(Clone, Debug, PartialEq)]
+pub struct TokenIssued {
+    #[topic]
+    pub token_id: BytesN<32>,
+    #[topic]
+    pub user: Address,
+    pub issued_by: Address,
+    pub issued_at: u64,
+    pub expiry_date: u64,
+    pub status: MembershipStatus,
+}
+
+/// Ownership of a membership token moved to a new holder.
+#[contractevent(topics = ["token_transferred", "v1"])]
+#[derive(Clone, Debug, PartialEq)]
+pub struct TokenTransferred {
+    #[topic]
+    pub token_id: BytesN<32>,
+    #[topic]
+    pub to: Address,
+    pub from: Address,
+    pub timestamp: u64,
+}
+
+/// A membership token was sold with royalties applied.
+#[contractevent(topics = ["token_sold", "v1"])]
+#[derive(Clone, Debug, PartialEq)]
+pub struct TokenSold {
+    #[topic]
+    pub token_id: BytesN<32>,
+    #[topic]
+    pub buyer: Address,
+    pub payment_token: Address,
+    pub sale_price: i128,
+    pub timestamp: u64,
+}
+
+/// The hub transfer fee was charged on a `transfer_from` resale.
+#[contractevent(topics = ["transfer_fee_charged", "v1"])]
+#[derive(Clone, Debug, PartialEq)]
+pub struct TransferFeeCharged {
+    #[topic]
+    pub token_id: BytesN<32>,
+    #[topic]
+    pub payer: Address,
+    pub recipient: Address,
+    pub payment_token: Address,
+    pub sale_amount: i128,
+    pub fee: i128,
+    pub timestamp: u64,
+}
+
+/// A membership token was listed for peer-to-peer sale.
+#[contractevent(topics = ["token_listed_for_sale", "v1"])]
+#[derive(Clone, Debug, PartialEq)]
+pub struct TokenListedForSale {
+    #[topic]
+    pub token_id: BytesN<32>,
+    #[topic]
+    pub seller: Address,
+    pub price: i128,
+    pub timestamp: u64,
+}
+
+/// A sale listing was withdrawn.
+#[contractevent(topics = ["token_sale_cancelled", "v1"])]
+#[derive(Clone, Debug, PartialEq)]
+pub struct TokenSaleCancelled {
+    #[topic]
+    pub token_id: BytesN<32>,
+    pub seller: Address,
+    pub timestamp: u64,
+}
+
+/// A spender transferred a token using an allowance.
+#[contractevent(topics = ["token_delegated_transfer", "v1"])]
+#[derive(Clone, Debug, PartialEq)]
+pub struct TokenDelegatedTransfer {
+    #[topic]
+    pub token_id: BytesN<32>,
+    #[topic]
+    pub spender: Address,
+    pub from: Address,
+    pub to: Address,
+    pub allowance_used: i128,
+    pub timestamp: u64,
+}
+
+/// The bootstrap admin was set.
+#[contractevent(topics = ["admin_set", "v1"])]
+#[derive(Clone, Debug, PartialEq)]
+pub struct AdminSet {
+    #[topic]
+    pub admin: Address,
+    pub timestamp: u64,
+}
+
+/// An admin transfer was proposed.
+#[contractevent(topics = ["admin_transfer_proposed", "v1"])]
+#[derive(Clone, Debug, PartialEq)]
+pub struct AdminTransferProposed {
+    #[topic]
+    pub proposed_admin: Address,
+    pub current_admin: Address,
+    pub expiry: u64,
+}
+
+/// A pending admin transfer was accepted.
+#[contractevent(topics = ["admin_transferred", "v1"])]
+#[derive(Clone, Debug, PartialEq)]
+pub struct AdminTransferred {
+    #[topic]
+    pub new_admin: Address,
+    pub old_admin: Address,
+    pub timestamp: u64,
+}
+
+/// A pending admin transfer was withdrawn.
+#[contractevent(topics = ["admin_transfer_cancelled", "v1"])]
+#[derive(Clone, Debug, PartialEq)]
+pub struct AdminTransferCancelled {
+    #[topic]
+    pub proposed_admin: Address,
+    pub current_admin: Address,
+}
+
+/// Token metadata was created or replaced.
+#[contractevent(topics = ["metadata_set", "v1"])]
+#[derive(Clone, Debug, PartialEq)]
+pub struct MetadataSet {
+    #[topic]
+    pub token_id: BytesN<32>,
+    pub version: u32,
+    pub updated_by: Address,
+    pub timestamp: u64,
+}
+
+/// An off-chain content URI and hash were bound to a token.
+#[contractevent(topics = ["token_uri_set", "v1"])]
+#[derive(Clone, Debug, PartialEq)]
+pub struct TokenUriSet {
+    #[topic]
+    pub token_id: BytesN<32>,
+    pub uri: String,
+    pub content_hash: BytesN<32>,
+    pub updated_by: Address,
+    pub timestamp: u64,
+}
+
+/// Token metadata attributes were updated.
+#[contractevent(topics = ["metadata_updated", "v1"])]
+#[derive(Clone, Debug, PartialEq)]
+pub struct MetadataUpdated {
+    #[topic]
+    pub token_id: BytesN<32>,
+    pub old_version: u32,
+    pub new_version: u32,
+    pub updated_by: Address,
+    pub timestamp: u64,
+}
+
+/// Token metadata attributes were removed.
+#[contractevent(topics = ["metadata_removed", "v1"])]
+#[derive(Clone, Debug, PartialEq)]
+pub struct MetadataRemoved {
+    #[topic]
+    pub token_id: BytesN<32>,
+    pub removed_keys: Vec<String>,
+    pub new_version: u32,
+    pub updated_by: Address,
+    pub timestamp: u64,
+}
+
+/// Old entries were pruned from a token's metadata history.
+#[contractevent(topics = ["metadata_history_pruned", "v1"])]
+#[derive(Clone, Debug, PartialEq)]
+pub struct MetadataHistoryPruned {
+    #[topic]
+    pub token_id: BytesN<32>,
+    pub pruned: u32,
+    pub kept: u32,
+    pub checksum: BytesN<32>,
+    pub timestamp: u64,
+}
+
+/// A new token metadata schema version was set and activated.
+#[contractevent(topics = ["metadata_schema_set", "v1"])]
+#[derive(Clone, Debug, PartialEq)]
+pub struct MetadataSchemaSet {
+    #[topic]
+    pub version: u32,
+    pub fields: u32,
+    pub set_by: Address,
+    pub timestamp: u64,
+}
+
+/// The token renewal configuration changed.
+#[contractevent(topics = ["renewal_config_updated", "v1"])]
+#[derive(Clone, Debug, PartialEq)]
+pub struct RenewalConfigUpdated {
+    #[topic]
+    pub admin: Address,
+    pub old_config: RenewalConfig,
+    pub new_config: RenewalConfig,
+}
+
+/// A tier's grace-period policy was set, overriding the renewal config.
+#[contractevent(topics = ["tier_grace_policy_set", "v1"])]
+#[derive(Clone, Debug, PartialEq)]
+pub struct TierGracePolicySet {
+    #[topic]
+    pub tier_id: String,
+    pub admin: Address,
+    pub policy: GracePolicy,
+}
+
+/// A tier's grace-period policy was removed; the renewal config applies
+/// again.
+#[contractevent(topics = ["tier_grace_policy_removed", "v1"])]
+#[derive(Clone, Debug, PartialEq)]
+pub struct TierGracePolicyRemoved {
+    #[topic]
+    pub tier_id: String,
+    pub admin: Address,
+}
+
+/// A membership token was renewed, manually or by auto-renewal.
+#[contractevent(topics = ["token_renewed", "v1"])]
+#[derive(Clone, Debug, PartialEq)]
+pub struct TokenRenewed {
+    #[topic]
+    pub token_id: BytesN<32>,
+    #[topic]
+    pub user: Address,
+    pub payment_token: Address,
+    pub amount: i128,
+    pub old_expiry: u64,
+    pub new_expiry: u64,
+    pub automatic: bool,
+}
+
+/// A token entered its grace period (`reason` is `expired` or
+/// `auto_renewal_failed`).
+#[contractevent(topics = ["grace_period_entered", "v1"])]
+#[derive(Clone, Debug, PartialEq)]
+pub struct GracePeriodEntered {
+    #[topic]
+    pub token_id: BytesN<32>,
+    #[topic]
+    pub user: Address,
+    pub entered_at: u64,
+    pub expires_at: u64,
+    pub reason: Symbol,
+}
+
+/// Auto-renewal settings for a token changed.
+#[contractevent(topics = ["auto_renewal_updated", "v1"])]
+#[derive(Clone, Debug, PartialEq)]
+pub struct AutoRenewalUpdated {
+    #[topic]
+    pub token_id: BytesN<32>,
+    #[topic]
+    pub user: Address,
+    pub enabled: bool,
+    pub payment_token: Address,
+}
+
+/// A member consented to auto-renewal terms by changing their settings.
+#[contractevent(topics = ["auto_renewal_consent", "v1"])]
+#[derive(Clone, Debug, PartialEq)]
+pub struct AutoRenewalConsentRecorded {
+    #[topic]
+    pub token_id: BytesN<32>,
+    #[topic]
+    pub user: Address,
+    pub consent: RenewalConsent,
+}
+
+/// An auto-renewal was held back because its price exceeds the member's
+/// cap; it waits for their consent instead of entering the grace period.
+#[contractevent(topics = ["auto_renewal_consent_required", "v1"])]
+#[derive(Clone, Debug, PartialEq)]
+pub struct AutoRenewalConsentRequired {
+    #[topic]
+    pub token_id: BytesN<32>,
+    #[topic]
+    pub user: Address,
+    pub required_amount: i128,
+    pub max_amount: i128,
+}
+
+/// The contract was emergency-paused. `admin` is whoever initiated the
+/// pause: the admin or a guardian.
+#[contractevent(topics = ["emergency_paused", "v1"])]
+#[derive(Clone, Debug, PartialEq)]
+pub struct EmergencyPaused {
+    #[topic]
+    pub admin: Address,
+    pub paused_at: u64,
+    pub reason: Option<String>,
+    pub auto_unpause_at: Option<u64>,
+    pub time_lock_until: Option<u64>,
+    pub pause_count: u32,
+}
+
+/// A guardian pushed back the auto-unpause deadline of an active emergency
+/// pause. The pause keeps its original initiator and reason.
+#[contractevent(topics = ["emergency_pause_extended", "v1"])]
+#[derive(Clone, Debug, PartialEq)]
+pub struct EmergencyPauseExtended {
+    #[topic]
+    pub guardian: Address,
+    pub auto_unpause_at: Option<u64>,
+}
+
+/// The contract emergency pause was lifted.
+#[contractevent(topics = ["emergency_unpaused", "v1"])]
+#[derive(Clone, Debug, PartialEq)]
+pub struct EmergencyUnpaused {
+    #[topic]
+    pub admin: Address,
+    pub timestamp: u64,
+}
+
+/// Operations on a single token were paused by the admin or a guardian.
+#[contractevent(topics = ["token_paused", "v1"])]
+#[derive(Clone, Debug, PartialEq)]
+pub struct TokenPaused {
+    #[topic]
+    pub token_id: BytesN<32>,
+    #[topic]
+    pub admin: Address,
+    pub paused_at: u64,
+    pub reason: Option<String>,
+    pub auto_unpause_at: Option<u64>,
+    pub time_lock_until: Option<u64>,
+}
+
+/// A guardian pushed back the auto-unpause deadline of an active token
+/// pause. The pause keeps its original initiator and reason.
+#[contractevent(topics = ["token_pause_extended", "v1"])]
+#[derive(Clone, Debug, PartialEq)]
+pub struct TokenPauseExtended {
+    #[topic]
+    pub token_id: BytesN<32>,
+    #[topic]
+    pub guardian: Address,
+    pub auto_unpause_at: Option<u64>,
+}
+
+/// Operations on a single token were resumed.
+#[contractevent(topics = ["token_unpaused", "v1"])]
+#[derive(Clone, Debug, PartialEq)]
+pub struct TokenUnpaused {
+    #[topic]
+    pub token_id: BytesN<32>,
+    #[topic]
+    pub admin: Address,
+    pub timestamp: u64,
+}
+
+// ============================================================================
+// Subscription Events
+// ============================================================================
+
+/// A subscription was created. `tier_id` is empty for untiered subscriptions.
+#[contractevent(topics = ["subscription_created", "v1"])]
+#[derive(Clone, Debug, PartialEq)]
+pub struct SubscriptionCreated {
+    #[topic]
+    pub subscription_id: String,
+    #[topic]
+    pub user: Address,
+    pub payment_token: Address,
+    pub amount: i128,
+    pub tier_id: String,
+    pub billing_cycle: BillingCycle,
+    pub created_at: u64,
+    pub expires_at: u64,
+}
+
+/// A subscription was paused.
+#[contractevent(topics = ["subscription_paused", "v1"])]
+#[derive(Clone, Debug, PartialEq)]
+pub struct SubscriptionPaused {
+    #[topic]
+    pub subscription_id: String,
+    #[topic]
+    pub user: Address,
+    pub entry: PauseHistoryEntry,
+}
+
+/// A paused subscription was resumed.
+#[contractevent(topics = ["subscription_resumed", "v1"])]
+#[derive(Clone, Debug, PartialEq)]
+pub struct SubscriptionResumed {
+    #[topic]
+    pub subscription_id: String,
+    #[topic]
+    pub user: Address,
+    pub entry: PauseHistoryEntry,
+    pub new_expires_at: u64,
+}
+
+/// A member booked a pause in advance.
+#[contractevent(topics = ["subscription_pause_scheduled", "v1"])]
+#[derive(Clone, Debug, PartialEq)]
+pub struct SubscriptionPauseScheduled {
+    #[topic]
+    pub subscription_id: String,
+    #[topic]
+    pub user: Address,
+    pub start_at: u64,
+    pub duration: u64,
+}
+
+/// A scheduled pause was cancelled before it started.
+#[contractevent(topics = ["scheduled_pause_cancelled", "v1"])]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ScheduledPauseCancelled {
+    #[topic]
+    pub subscription_id: String,
+    #[topic]
+    pub user: Address,
+    pub start_at: u64,
+}
+
+/// A subscription was cancelled.
+#[contractevent(topics = ["subscription_cancelled", "v1"])]
+#[derive(Clone, Debug, PartialEq)]
+pub struct SubscriptionCancelled {
+    #[topic]
+    pub subscription_id: String,
+    #[topic]
+    pub user: Address,
+    pub old_status: MembershipStatus,
+    pub new_status: MembershipStatus,
+    pub timestamp: u64,
+}
+
+/// A subscription was marked expired after passing its expiry date.
+#[contractevent(topics = ["subscription_expired", "v1"])]
+#[derive(Clone, Debug, PartialEq)]
+pub struct SubscriptionExpired {
+    #[topic]
+    pub subscription_id: String,
+    #[topic]
+    pub user: Address,
+    pub expired_at: u64,
+    pub timestamp: u64,
+}
+
+/// A subscription was renewed.
+#[contractevent(topics = ["subscription_renewed", "v1"])]
+#[derive(Clone, Debug, PartialEq)]
+pub struct SubscriptionRenewed {
+    #[topic]
+    pub subscription_id: String,
+    #[topic]
+    pub user: Address,
+    pub payment_token: Address,
+    pub amount: i128,
+    pub old_expiry: u64,
+    pub new_expiry: u64,
+}
+
+/// A cancelled or expired subscription was brought back under its original
+/// ID.
+#[contractevent(topics = ["subscription_reactivated", "v1"])]
+#[derive(Clone, Debug, PartialEq)]
+pub struct SubscriptionReactivated {
+    #[topic]
+    pub subscription_id: String,
+    #[topic]
+    pub user: Address,
+    pub payment_token: Address,
+    pub amount: i128,
+    pub tier_id: String,
+    pub billing_cycle: BillingCycle,
+    pub expires_at: u64,
+}
+
+/// A subscription was moved off its locked price; the new price applies from
+/// `effective_at`.
+#[contractevent(topics = ["subscription_price_migrated", "v1"])]
+#[derive(Clone, Debug, PartialEq)]
+pub struct SubscriptionPriceMigrated {
+    #[topic]
+    pub subscription_id: String,
+    #[topic]
+    pub user: Address,
+    pub old_price: i128,
+    pub new_price: i128,
+    pub effective_at: u64,
+}
+
+/// A change of the USDC contract was proposed.
+#[contractevent(topics = ["usdc_change_proposed", "v1"])]
+#[derive(Clone, Debug, PartialEq)]
+pub struct UsdcChangeProposed {
+    #[topic]
+    pub admin: Address,
+    pub current: Address,
+    pub proposed: Address,
+    pub executable_at: u64,
+}
+
+/// A pending USDC contract change was withdrawn.
+#[contractevent(topics = ["usdc_change_cancelled", "v1"])]
+#[derive(Clone, Debug, PartialEq)]
+pub struct UsdcChangeCancelled {
+    #[topic]
+    pub admin: Address,
+    pub current: Address,
+    pub proposed: Address,
+}
+
+/// The USDC contract address was set or changed.
+#[contractevent(topics = ["usdc_contract_set", "v1"])]
+#[derive(Clone, Debug, PartialEq)]
+pub struct UsdcContractSet {
+    #[topic]
+    pub admin: Address,
+    pub previous: Option<Address>,
+    pub new: Address,
+    pub timestamp: u64,
+}
+
+/// A subscription tier was created.
+#[contractevent(topics = ["tier_created", "v1"])]
+#[derive(Clone, Debug, PartialEq)]
+pub struct TierCreated {
+    #[topic]
+    pub tier_id: String,
+    #[topic]
+    pub admin: Address,
+    pub name: String,
+    pub level: TierLevel,
+    pub price: i128,
+    pub annual_price: i128,
+    pub created_at: u64,
+}
+
+/// A subscription tier was updated.
+#[contractevent(topics = ["tier_updated", "v1"])]
+#[derive(Clone, Debug, PartialEq)]
+pub struct TierUpdated {
+    #[topic]
+    pub tier_id: String,
+    #[topic]
+    pub admin: Address,
+    pub old_price: i128,
+    pub new_price: i128,
+    pub old_annual_price: i128,
+    pub new_annual_price: i128,
+    pub is_active: bool,
+    pub updated_at: u64,
+}
+
+/// A subscription tier was deactivated.
+#[contractevent(topics = ["tier_deactivated", "v1"])]
+#[derive(Clone, Debug, PartialEq)]
+pub struct TierDeactivated {
+    #[topic]
+    pub tier_id: String,
+    #[topic]
+    pub admin: Address,
+    pub updated_at: u64,
+}
+
+/// A tier was deprecated; its subscribers will be moved to
+/// `replacement_tier_id` from `migrate_at`.
+#[contractevent(topics = ["tier_deprecated", "v1"])]
+#[derive(Clone, Debug, PartialEq)]
+pub struct TierDeprecated {
+    #[topic]
+    pub tier_id: String,
+    #[topic]
+    pub admin: Address,
+    pub replacement_tier_id: String,
+    pub migrate_at: u64,
+}
+
+/// A batch of a deprecated tier's subscribers was moved to its replacement.
+#[contractevent(topics = ["tier_subscribers_migrated", "v1"])]
+#[derive(Clone, Debug, PartialEq)]
+pub struct TierSubscribersMigrated {
+    #[topic]
+    pub tier_id: String,
+    pub replacement_tier_id: String,
+    pub migrated: u32,
+    pub remaining: u32,
+}
+
+/// A tier change was requested.
+#[contractevent(topics = ["tier_change_requested", "v1"])]
+#[derive(Clone, Debug, PartialEq)]
+pub struct TierChangeRequested {
+    #[topic]
+    pub change_id: String,
+    #[topic]
+    pub user: Address,
+    pub from_tier: String,
+    pub to_tier: String,
+    pub change_type: TierChangeType,
+    pub prorated_amount: i128,
+}
+
+/// A tier change was applied to a subscription.
+#[contractevent(topics = ["tier_change_completed", "v1"])]
+#[derive(Clone, Debug, PartialEq)]
+pub struct TierChangeCompleted {
+    #[topic]
+    pub change_id: String,
+    #[topic]
+    pub user: Address,
+    pub subscription_id: String,
+    pub from_tier: String,
+    pub to_tier: String,
+    pub prorated_amount: i128,
+}
+
+/// A pending tier change was cancelled.
+#[contractevent(topics = ["tier_change_cancelled", "v1"])]
+#[derive(Clone, Debug, PartialEq)]
+pub struct TierChangeCancelled {
+    #[topic]
+    pub change_id: String,
+    #[topic]
+    pub user: Address,
+    pub timestamp: u64,
+}
+
+/// A downgrade credited its unused difference to the user's credit balance.
+#[contractevent(topics = ["tier_change_credited", "v1"])]
+#[derive(Clone, Debug, PartialEq)]
+pub struct TierChangeCredited {
+    #[topic]
+    pub change_id: String,
+    #[topic]
+    pub user: Address,
+    pub amount: i128,
+    pub balance: i128,
+}
+
+/// Tier-change credit was spent on a renewal or upgrade.
+#[contractevent(topics = ["credit_applied", "v1"])]
+#[derive(Clone, Debug, PartialEq)]
+pub struct CreditApplied {
+    #[topic]
+    pub user: Address,
+    pub amount: i128,
+    pub remaining: i128,
+}
+
+/// A feature was granted to a single subscription outside its tier.
+#[contractevent(topics = ["feature_override_granted", "v1"])]
+#[derive(Clone, Debug, PartialEq)]
+pub struct FeatureOverrideGranted {
+    #[topic]
+    pub subscription_id: String,
+    pub feature: TierFeature,
+    pub granted_by: Address,
+    pub expires_at: u64,
+}
+
+/// A subscription's feature override was revoked.
+#[contractevent(topics = ["feature_override_revoked", "v1"])]
+#[derive(Clone, Debug, PartialEq)]
+pub struct FeatureOverrideRevoked {
+    #[topic]
+    pub subscription_id: String,
+    pub feature: TierFeature,
+    pub revoked_by: Address,
+}
+
+/// A downgrade was scheduled to take effect at the end of the billing period.
+#[contractevent(topics = ["tier_downgrade_scheduled", "v1"])]
+#[derive(Clone, Debug, PartialEq)]
+pub struct TierDowngradeScheduled {
+    #[topic]
+    pub change_id: String,
+    #[topic]
+    pub subscription_id: String,
+    pub user: Address,
+    pub from_tier: String,
+    pub to_tier: String,
+    pub effective_date: u64,
+}
+
+/// A tier promotion was created.
+#[contractevent(topics = ["promotion_created", "v1"])]
+#[derive(Clone, Debug, PartialEq)]
+pub struct PromotionCreated {
+    #[topic]
+    pub promo_id: String,
+    #[topic]
+    pub admin: Address,
+    pub tier_id: String,
+    pub discount_percent: u32,
+    pub start_date: u64,
+    pub end_date: u64,
+}
+
+// ============================================================================
+// Staking Events
+// ============================================================================
+
+/// A staking tier was created.
+#[contractevent(topics = ["staking_tier_created", "v1"])]
+#[derive(Clone, Debug, PartialEq)]
+pub struct StakingTierCreated {
+    #[topic]
+    pub tier_id: String,
+    pub min_stake_amount: i128,
+    pub lock_duration: u64,
+    pub timestamp: u64,
+}
+
+/// Tokens were staked (a new stake or a top-up of an existing one).
+#[contractevent(topics = ["staked", "v1"])]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Staked {
+    #[topic]
+    pub staker: Address,
+    #[topic]
+    pub tier_id: String,
+    pub amount: i128,
+    pub total_staked: i128,
+    pub unlock_at: u64,
+}
+
+/// A stake was withdrawn after its lock period.
+#[contractevent(topics = ["unstaked", "v1"])]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Unstaked {
+    #[topic]
+    pub staker: Address,
+    pub amount: i128,
+    pub rewards: i128,
+}
+
+/// An unstake cooldown was started.
+#[contractevent(topics = ["unstake_requested", "v1"])]
+#[derive(Clone, Debug, PartialEq)]
+pub struct UnstakeRequested {
+    #[topic]
+    pub staker: Address,
+    pub amount: i128,
+    pub available_at: u64,
+}
+
+/// A pending unstake was cancelled and the stake resumed accruing.
+#[contractevent(topics = ["unstake_cancelled", "v1"])]
+#[derive(Clone, Debug, PartialEq)]
+pub struct UnstakeCancelled {
+    #[topic]
+    pub staker: Address,
+    pub timestamp: u64,
+}
+
+/// A stake was withdrawn early with a penalty.
+#[contractevent(topics = ["emergency_unstaked", "v1"])]
+#[derive(Clone, Debug, PartialEq)]
+pub struct EmergencyUnstaked {
+    #[topic]
+    pub staker: Address,
+    pub amount_returned: i128,
+    pub penalty: i128,
+}
+
+/// Part of a stake was slashed into the treasury.
+#[contractevent(topics = ["stake_slashed", "v1"])]
+#[derive(Clone, Debug, PartialEq)]
+pub struct StakeSlashed {
+    #[topic]
+    pub staker: Address,
+    pub bps: u32,
+    pub amount: i128,
+    pub remaining: i128,
+    pub reason: String,
+    pub slashed_by: Address,
+}
+
+// ============================================================================
+// Fractionalization Events
+// ============================================================================
+
+/// A token was split into fractional shares.
+#[contractevent(topics = ["token_fractionalized", "v1"])]
+#[derive(Clone, Debug, PartialEq)]
+pub struct TokenFractionalized {
+    #[topic]
+    pub token_id: BytesN<32>,
+    #[topic]
+    pub owner: Address,
+    pub total_shares: i128,
+    pub min_fraction_size: i128,
+    pub timestamp: u64,
+}
+
+/// Fractional shares moved between holders.
+#[contractevent(topics = ["fraction_transferred", "v1"])]
+#[derive(Clone, Debug, PartialEq)]
+pub struct FractionTransferred {
+    #[topic]
+    pub token_id: BytesN<32>,
+    #[topic]
+    pub from: Address,
+    pub to: Address,
+    pub share_amount: i128,
+    pub timestamp: u64,
+}
+
+/// The transfer policy of a fractionalized token was changed.
+#[contractevent(topics = ["fraction_transfer_policy_set", "v1"])]
+#[derive(Clone, Debug, PartialEq)]
+pub struct FractionTransferPolicySet {
+    #[topic]
+    pub token_id: BytesN<32>,
+    pub policy: FractionTransferPolicy,
+    pub set_by: Address,
+}
+
+/// An address was added to or removed from a token's holder whitelist.
+#[contractevent(topics = ["fraction_whitelist_updated", "v1"])]
+#[derive(Clone, Debug, PartialEq)]
+pub struct FractionWhitelistUpdated {
+    #[topic]
+    pub token_id: BytesN<32>,
+    #[topic]
+    pub holder: Address,
+    pub allowed: bool,
+}
+
+/// A token's owner approved a share transfer between two holders.
+#[contractevent(topics = ["fraction_transfer_approved", "v1"])]
+#[derive(Clone, Debug, PartialEq)]
+pub struct FractionTransferApproved {
+    #[topic]
+    pub token_id: BytesN<32>,
+    #[topic]
+    pub from: Address,
+    pub to: Address,
+    pub share_amount: i128,
+}
+
+/// A share allowance was set through the fraction token interface.
+#[contractevent(topics = ["fraction_approved", "v1"])]
+#[derive(Clone, Debug, PartialEq)]
+pub struct FractionApproved {
+    #[topic]
+    pub token_id: BytesN<32>,
+    #[topic]
+    pub from: Address,
+    pub spender: Address,
+    pub amount: i128,
+    pub expiration_ledger: u32,
+}
+
+/// A share holder paid toward renewing a fractionalized token.
+#[contractevent(topics = ["renewal_contributed", "v1"])]
+#[derive(Clone, Debug, PartialEq)]
+pub struct RenewalContributed {
+    #[topic]
+    pub token_id: BytesN<32>,
+    #[topic]
+    pub holder: Address,
+    pub amount: i128,
+    pub funded: i128,
+    pub price: i128,
+}
+
+/// A contributor took their payments back out of a renewal pool.
+#[contractevent(topics = ["renewal_contribution_withdrawn", "v1"])]
+#[derive(Clone, Debug, PartialEq)]
+pub struct RenewalContributionWithdrawn {
+    #[topic]
+    pub token_id: BytesN<32>,
+    #[topic]
+    pub holder: Address,
+    pub amount: i128,
+}
+
+/// A fractionalized token was renewed from its holders' pooled funds.
+#[contractevent(topics = ["fractionalized_token_renewed", "v1"])]
+#[derive(Clone, Debug, PartialEq)]
+pub struct FractionalizedTokenRenewed {
+    #[topic]
+    pub token_id: BytesN<32>,
+    pub price: i128,
+    pub refunded: i128,
+    pub new_expiry: u64,
+    pub renewed_by: Address,
+}
+
+/// All shares were recombined into a whole token.
+#[contractevent(topics = ["token_recombined", "v1"])]
+#[derive(Clone, Debug, PartialEq)]
+pub struct TokenRecombined {
+    #[topic]
+    pub token_id: BytesN<32>,
+    #[topic]
+    pub holder: Address,
+    pub timestamp: u64,
+}
+
+/// A dividend was distributed to a token's share holders.
+#[contractevent(topics = ["dividend_distributed", "v1"])]
+#[derive(Clone, Debug, PartialEq)]
+pub struct DividendDistributed {
+    #[topic]
+    pub token_id: BytesN<32>,
+    #[topic]
+    pub admin: Address,
+    pub total_amount: i128,
+    pub recipients: u32,
+    pub distributed_at: u64,
+}
+
+// ============================================================================
+// Contract Upgrade Events
+// ============================================================================
+
+/// A WASM upgrade of this contract was scheduled behind the time lock.
+#[contractevent(topics = ["contract_upgrade_scheduled", "v1"])]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ContractUpgradeScheduled {
+    #[topic]
+    pub scheduled_by: Address,
+    pub new_wasm_hash: BytesN<32>,
+    pub executable_at: u64,
+}
+
+/// A scheduled WASM upgrade was withdrawn before execution.
+#[contractevent(topics = ["contract_upgrade_cancelled", "v1"])]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ContractUpgradeCancelled {
+    #[topic]
+    pub cancelled_by: Address,
+    pub new_wasm_hash: BytesN<32>,
+}
+
+/// The contract's WASM was replaced.
+#[contractevent(topics = ["contract_upgraded", "v1"])]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ContractUpgraded {
+    #[topic]
+    pub executed_by: Address,
+    pub new_wasm_hash: BytesN<32>,
+    pub version: u32,
+}
+
+// ============================================================================
+// Blacklist Enforcement Events
+// ============================================================================
+
+/// Blacklist enforcement was switched on or off for an operation type.
+#[contractevent(topics = ["blacklist_enforcement_updated", "v1"])]
+#[derive(Clone, Debug, PartialEq)]
+pub struct BlacklistEnforcementUpdated {
+    pub operation: BlacklistOperation,
+    pub enabled: bool,
+    #[topic]
+    pub updated_by: Address,
+}
+
+// ============================================================================
+// Verification Events
+// ============================================================================
+
+/// A verifier was designated or removed.
+#[contractevent(topics = ["verifier_updated", "v1"])]
+#[derive(Clone, Debug, PartialEq)]
+pub struct VerifierUpdated {
+    #[topic]
+    pub verifier: Address,
+    pub enabled: bool,
+    pub updated_by: Address,
+}
+
+/// An address was marked as verified.
+#[contractevent(topics = ["user_verified", "v1"])]
+#[derive(Clone, Debug, PartialEq)]
+pub struct UserVerified {
+    #[topic]
+    pub user: Address,
+    pub verified_by: Address,
+    pub expires_at: u64,
+}
+
+/// An address's verification was revoked.
+#[contractevent(topics = ["user_verification_revoked", "v1"])]
+#[derive(Clone, Debug, PartialEq)]
+pub struct UserVerificationRevoked {
+    #[topic]
+    pub user: Address,
+    pub revoked_by: Address,
+}
+
+/// The verification policy was replaced.
+#[contractevent(topics = ["verification_policy_updated", "v1"])]
+#[derive(Clone, Debug, PartialEq)]
+pub struct VerificationPolicyUpdated {
+    pub policy: VerificationPolicy,
+    #[topic]
+    pub updated_by: Address,
+}
+
+// ============================================================================
+// Dispute Events
+// ============================================================================
+
+/// A member filed a dispute.
+#[contractevent(topics = ["dispute_filed", "v1"])]
+#[derive(Clone, Debug, PartialEq)]
+pub struct DisputeFiled {
+    pub dispute_id: u64,
+    #[topic]
+    pub user: Address,
+    pub subject: DisputeSubject,
+}
+
+/// A dispute was resolved.
+#[contractevent(topics = ["dispute_resolved", "v1"])]
+#[derive(Clone, Debug, PartialEq)]
+pub struct DisputeResolved {
+    pub dispute_id: u64,
+    #[topic]
+    pub user: Address,
+    pub status: DisputeStatus,
+    pub resolved_by: Address,
+}
+
+// ============================================================================
+// TTL Policy Events
+// ============================================================================
+
+/// The TTL policy was replaced.
+#[contractevent(topics = ["ttl_policy_updated", "v1"])]
+#[derive(Clone, Debug, PartialEq)]
+pub struct TtlPolicyUpdated {
+    pub policy: TtlPolicy,
+    #[topic]
+    pub updated_by: Address,
+}
+
+// ============================================================================
+// Booking Events
+// ============================================================================
+
+/// A bookable class session was created.
+#[contractevent(topics = ["session_created", "v1"])]
+#[derive(Clone, Debug, PartialEq)]
+pub struct SessionCreated {
+    #[topic]
+    pub session_id: String,
+    pub start_time: u64,
+    pub end_time: u64,
+    pub capacity: u32,
+}
+
+/// A tier's monthly session booking quota was set.
+#[contractevent(topics = ["booking_quota_set", "v1"])]
+#[derive(Clone, Debug, PartialEq)]
+pub struct BookingQuotaSet {
+    #[topic]
+    pub tier_id: String,
+    pub monthly_quota: u32,
+}
+
+/// A member booked a seat in a session.
+#[contractevent(topics = ["session_booked", "v1"])]
+#[derive(Clone, Debug, PartialEq)]
+pub struct SessionBooked {
+    #[topic]
+    pub session_id: String,
+    #[topic]
+    pub subscription_id: String,
+    pub user: Address,
+}
+
+/// A session booking was cancelled and its seat released.
+#[contractevent(topics = ["booking_cancelled", "v1"])]
+#[derive(Clone, Debug, PartialEq)]
+pub struct BookingCancelled {
+    #[topic]
+    pub session_id: String,
+    #[topic]
+    pub subscription_id: String,
+    pub user: Address,
+}
+
+/// Unattended bookings of an ended session were marked as no-shows.
+#[contractevent(topics = ["no_shows_recorded", "v1"])]
+#[derive(Clone, Debug, PartialEq)]
+pub struct NoShowsRecorded {
+    #[topic]
+    pub session_id: String,
+    pub no_shows: u32,
+}
+
+// ============================================================================
+// Resource Reservation Events
+// ============================================================================
+
+/// A reservable resource was registered.
+#[contractevent(topics = ["resource_registered", "v1"])]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ResourceRegistered {
+    #[topic]
+    pub resource_id: String,
+    pub kind: ResourceKind,
+    pub deposit: i128,
+}
+
+/// A resource was activated or deactivated.
+#[contractevent(topics = ["resource_active_set", "v1"])]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ResourceActiveSet {
+    #[topic]
+    pub resource_id: String,
+    pub active: bool,
+}
+
+/// A tier's limit on open reservations was set.
+#[contractevent(topics = ["reservation_limit_set", "v1"])]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ReservationLimitSet {
+    #[topic]
+    pub tier_id: String,
+    pub max_open: u32,
+}
+
+/// A member reserved a resource and paid its deposit.
+#[contractevent(topics = ["resource_reserved", "v1"])]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ResourceReserved {
+    #[topic]
+    pub resource_id: String,
+    #[topic]
+    pub subscription_id: String,
+    pub reservation_id: u64,
+    pub start_time: u64,
+    pub end_time: u64,
+    pub deposit: i128,
+}
+
+/// A reservation was cancelled and its deposit refunded.
+#[contractevent(topics = ["reservation_cancelled", "v1"])]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ReservationCancelled {
+    #[topic]
+    pub resource_id: String,
+    pub reservation_id: u64,
+    pub deposit: i128,
+}
+
+/// A member checked in to their reservation.
+#[contractevent(topics = ["reservation_checked_in", "v1"])]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ReservationCheckedIn {
+    #[topic]
+    pub resource_id: String,
+    pub reservation_id: u64,
+    pub user: Address,
+}
+
+/// An ended reservation was settled and its deposit released.
+#[contractevent(topics = ["reservation_settled", "v1"])]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ReservationSettled {
+    #[topic]
+    pub resource_id: String,
+    pub reservation_id: u64,
+    pub status: ReservationStatus,
+    pub deposit: i128,
+}
+
+// ============================================================================
+// Staff Events
+// ============================================================================
+
+/// A staff member was registered with an hourly rate.
+#[contractevent(topics = ["staff_registered", "v1"])]
+#[derive(Clone, Debug, PartialEq)]
+pub struct StaffRegistered {
+    #[topic]
+    pub staff: Address,
+    pub hourly_rate: i128,
+}
+
+/// A staff member's hourly rate changed.
+#[contractevent(topics = ["staff_rate_updated", "v1"])]
+#[derive(Clone, Debug, PartialEq)]
+pub struct StaffRateUpdated {
+    #[topic]
+    pub staff: Address,
+    pub hourly_rate: i128,
+}
+
+/// A staff member was activated or deactivated.
+#[contractevent(topics = ["staff_active_set", "v1"])]
+#[derive(Clone, Debug, PartialEq)]
+pub struct StaffActiveSet {
+    #[topic]
+    pub staff: Address,
+    pub active: bool,
+}
+
+/// A completed shift was recorded from a clock-in/clock-out pair.
+#[contractevent(topics = ["shift_recorded", "v1"])]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ShiftRecorded {
+    #[topic]
+    pub staff: Address,
+    pub start_time: u64,
+    pub end_time: u64,
+    pub amount: i128,
+}
+
+/// Unpaid shifts in a period were paid out to a staff member.
+#[contractevent(topics = ["payroll_paid", "v1"])]
+#[derive(Clone, Debug, PartialEq)]
+pub struct PayrollPaid {
+    #[topic]
+    pub staff: Address,
+    pub payment_id: u64,
+    pub amount: i128,
+    pub shifts: u32,
+}
+
+// ============================================================================
+// Federation Events
+// ============================================================================
+
+/// A partner hub was registered for reciprocal access.
+#[contractevent(topics = ["partner_hub_added", "v1"])]
+#[derive(Clone, Debug, PartialEq)]
+pub struct PartnerHubAdded {
+    #[topic]
+    pub partner: Address,
+    pub visit_quota: u32,
+}
+
+/// A partner hub's visit quota or active flag changed.
+#[contractevent(topics = ["partner_hub_updated", "v1"])]
+#[derive(Clone, Debug, PartialEq)]
+pub struct PartnerHubUpdated {
+    #[topic]
+    pub partner: Address,
+    pub visit_quota: u32,
+    pub active: bool,
+}
+
+/// A partner hub's member visited this hub.
+#[contractevent(topics = ["partner_visit_logged", "v1"])]
+#[derive(Clone, Debug, PartialEq)]
+pub struct PartnerVisitLogged {
+    #[topic]
+    pub partner: Address,
+    #[topic]
+    pub user: Address,
+    pub visits_used: u32,
+    /// Whether the visit was reported back to the partner
+    pub reported: bool,
+}
+
+/// A partner hub reported a visit by one of this hub's members.
+#[contractevent(topics = ["partner_visit_reported", "v1"])]
+#[derive(Clone, Debug, PartialEq)]
+pub struct PartnerVisitReported {
+    #[topic]
+    pub hub: Address,
+    #[topic]
+    pub user: Address,
+    pub outbound_visits: u64,
+}
+
+// ============================================================================
+// Hub Factory Events
+// ============================================================================
+
+/// The WASM used for new hub deployments was set.
+#[contractevent(topics = ["hub_wasm_set", "v1"])]
+#[derive(Clone, Debug, PartialEq)]
+pub struct HubWasmSet {
+    #[topic]
+    pub admin: Address,
+    pub wasm_hash: BytesN<32>,
+}
+
+/// A new hub contract was deployed and initialized by the factory.
+#[contractevent(topics = ["hub_deployed", "v1"])]
+#[derive(Clone, Debug, PartialEq)]
+pub struct HubDeployed {
+    #[topic]
+    pub hub: Address,
+    pub admin: Address,
+    pub name: String,
+}
+
+// ============================================================================
+// Guardian Events
+// ============================================================================
+
+/// An address was granted the guardian role.
+#[contractevent(topics = ["guardian_added", "v1"])]
+#[derive(Clone, Debug, PartialEq)]
+pub struct GuardianAdded {
+    #[topic]
+    pub guardian: Address,
+    pub added_by: Address,
+}
+
+/// An address lost the guardian role.
+#[contractevent(topics = ["guardian_removed", "v1"])]
+#[derive(Clone, Debug, PartialEq)]
+pub struct GuardianRemoved {
+    #[topic]
+    pub guardian: Address,
+    pub removed_by: Address,
+}
+
+// ============================================================================
+// Attendance Hook Events
+// ============================================================================
+
+/// A contract was registered to be notified of attendance.
+#[contractevent(topics = ["attendance_hook_registered", "v1"])]
+#[derive(Clone, Debug, PartialEq)]
+pub struct AttendanceHookRegistered {
+    #[topic]
+    pub hook: Address,
+    pub admin: Address,
+}
+
+/// An attendance hook was unregistered.
+#[contractevent(topics = ["attendance_hook_unregistered", "v1"])]
+#[derive(Clone, Debug, PartialEq)]
+pub struct AttendanceHookUnregistered {
+    #[topic]
+    pub hook: Address,
+    pub admin: Address,
+}
+
+/// An attendance hook failed; attendance was still logged.
+#[contractevent(topics = ["attendance_hook_failed", "v1"])]
+#[derive(Clone, Debug, PartialEq)]
+pub struct AttendanceHookFailed {
+    #[topic]
+    pub hook: Address,
+    #[topic]
+    pub user: Address,
+}
+
+// ============================================================================
+// Loyalty Events
+// ============================================================================
+
+/// A tier's loyalty earn rate was set; an empty tier ID sets the default.
+#[contractevent(topics = ["loyalty_earn_rate_set", "v1"])]
+#[derive(Clone, Debug, PartialEq)]
+pub struct LoyaltyEarnRateSet {
+    #[topic]
+    pub tier_id: String,
+    pub rate: LoyaltyEarnRate,
+}
+
+/// A rewards catalog entry was added or replaced.
+#[contractevent(topics = ["loyalty_reward_set", "v1"])]
+#[derive(Clone, Debug, PartialEq)]
+pub struct LoyaltyRewardSet {
+    #[topic]
+    pub reward_id: String,
+    pub reward: LoyaltyReward,
+}
+
+/// A member earned loyalty points.
+#[contractevent(topics = ["loyalty_points_earned", "v1"])]
+#[derive(Clone, Debug, PartialEq)]
+pub struct LoyaltyPointsEarned {
+    #[topic]
+    pub user: Address,
+    pub points: u32,
+    pub reason: Symbol,
+    /// Spendable points after the award
+    pub balance: u64,
+}
+
+/// A member spent points on a catalog reward.
+#[contractevent(topics = ["loyalty_points_redeemed", "v1"])]
+#[derive(Clone, Debug, PartialEq)]
+pub struct LoyaltyPointsRedeemed {
+    #[topic]
+    pub user: Address,
+    #[topic]
+    pub reward_id: String,
+    pub points_cost: u64,
+    pub credit_days: u32,
+}
+
+/// A check-in streak reached a bonus milestone.
+#[contractevent(topics = ["loyalty_streak_bonus", "v1"])]
+#[derive(Clone, Debug, PartialEq)]
+pub struct LoyaltyStreakBonus {
+    #[topic]
+    pub user: Address,
+    pub streak: u32,
+    pub points: u32,
+    pub credit_days: u32,
+}
+
+// ============================================================================
+// Achievement Events
+// ============================================================================
+
+/// A badge definition was added or replaced.
+#[contractevent(topics = ["badge_defined", "v1"])]
+#[derive(Clone, Debug, PartialEq)]
+pub struct BadgeDefined {
+    #[topic]
+    pub badge_id: String,
+    pub badge: BadgeDefinition,
+}
+
+/// A member was awarded a badge.
+#[contractevent(topics = ["badge_awarded", "v1"])]
+#[derive(Clone, Debug, PartialEq)]
+pub struct BadgeAwarded {
+    #[topic]
+    pub user: Address,
+    #[topic]
+    pub badge_id: String,
+    pub awarded_at: u64,
+}
+
+// ============================================================================
+// Subscription Bundle Events
+// ============================================================================
+
+/// A subscription bundle was created.
+#[contractevent(topics = ["bundle_created", "v1"])]
+#[derive(Clone, Debug, PartialEq)]
+pub struct BundleCreated {
+    #[topic]
+    pub bundle_id: String,
+    pub tier_id: String,
+    pub price: i128,
+    pub annual_price: i128,
+}
+
+/// A bundle stopped accepting new subscriptions.
+#[contractevent(topics = ["bundle_deactivated", "v1"])]
+#[derive(Clone, Debug, PartialEq)]
+pub struct BundleDeactivated {
+    #[topic]
+    pub bundle_id: String,
+    pub admin: Address,
+}
+
+/// A member subscribed through a bundle.
+#[contractevent(topics = ["bundle_subscribed", "v1"])]
+#[derive(Clone, Debug, PartialEq)]
+pub struct BundleSubscribed {
+    #[topic]
+    pub user: Address,
+    #[topic]
+    pub bundle_id: String,
+    pub subscription_id: String,
+    /// Bundle price for the chosen billing cycle, in USDC
+    pub price: i128,
+}
+
+// ============================================================================
+// Household Events
+// ============================================================================
+
+/// A member was linked to a household subscription.
+#[contractevent(topics = ["household_member_added", "v1"])]
+#[derive(Clone, Debug, PartialEq)]
+pub struct HouseholdMemberAdded {
+    #[topic]
+    pub subscription_id: String,
+    #[topic]
+    pub member: Address,
+}
+
+/// A member was unlinked from a household subscription.
+#[contractevent(topics = ["household_member_removed", "v1"])]
+#[derive(Clone, Debug, PartialEq)]
+pub struct HouseholdMemberRemoved {
+    #[topic]
+    pub subscription_id: String,
+    #[topic]
+    pub member: Address,
+}
+
+// ============================================================================
+// Entry Events
+// ============================================================================
+
+/// The kiosk entry policy was replaced.
+#[contractevent(topics = ["entry_policy_set", "v1"])]
+#[derive(Clone, Debug, PartialEq)]
+pub struct EntryPolicySet {
+    #[topic]
+    pub admin: Address,
+    pub policy: EntryPolicy,
+}
+
+/// A single-use entry pass was issued for a token.
+#[contractevent(topics = ["entry_pass_issued", "v1"])]
+#[derive(Clone, Debug, PartialEq)]
+pub struct EntryPassIssued {
+    #[topic]
+    pub token_id: BytesN<32>,
+    #[topic]
+    pub user: Address,
+    pub pass_id: BytesN<32>,
+    pub expires_at: u64,
+}
+
+/// An entry pass was redeemed at a kiosk.
+#[contractevent(topics = ["entry_pass_redeemed", "v1"])]
+#[derive(Clone, Debug, PartialEq)]
+pub struct EntryPassRedeemed {
+    #[topic]
+    pub token_id: BytesN<32>,
+    #[topic]
+    pub operator: Address,
+    pub pass_id: BytesN<32>,
+    pub redeemed_at: u64,
+}
+
+// ============================================================================
+// Guest Events
+// ============================================================================
+
+/// A tier's monthly guest invite quota was set.
+#[contractevent(topics = ["guest_quota_set", "v1"])]
+#[derive(Clone, Debug, PartialEq)]
+pub struct GuestQuotaSet {
+    #[topic]
+    pub tier_id: String,
+    pub monthly_quota: u32,
+}
+
+/// A subscriber invited a guest.
+#[contractevent(topics = ["guest_invited", "v1"])]
+#[derive(Clone, Debug, PartialEq)]
+pub struct GuestInvited {
+    #[topic]
+    pub subscription_id: String,
+    #[topic]
+    pub guest: Address,
+    pub host: Address,
+    pub valid_until: u64,
+}
+
+// ============================================================================
+// Membership Sync Events
+// ============================================================================
+
+/// A membership change could not be pushed to the access_control registry.
+#[contractevent(topics = ["membership_sync_failed", "v1"])]
+#[derive(Clone, Debug, PartialEq)]
+pub struct MembershipSyncFailed {
+    #[topic]
+    pub user: Address,
+    pub status: MembershipStatus,
+    pub expires_at: u64,
+}
+
+// ============================================================================
+// Billing Events
+// ============================================================================
+
+/// An invoice was issued for a charge.
+#[contractevent(topics = ["invoice_issued", "v1"])]
+#[derive(Clone, Debug, PartialEq)]
+pub struct InvoiceIssued {
+    #[topic]
+    pub invoice_id: u64,
+    #[topic]
+    pub payer: Address,
+    pub subscription_id: String,
+    pub total: i128,
+}
+
+/// An invoice was marked refunded.
+#[contractevent(topics = ["invoice_refunded", "v1"])]
+#[derive(Clone, Debug, PartialEq)]
+pub struct InvoiceRefunded {
+    #[topic]
+    pub invoice_id: u64,
+    #[topic]
+    pub payer: Address,
+    pub total: i128,
+}
+
+// ============================================================================
+// Notification Events
+// ============================================================================
+
+/// A notification was queued for a member.
+#[contractevent(topics = ["notification_queued", "v1"])]
+#[derive(Clone, Debug, PartialEq)]
+pub struct NotificationQueued {
+    #[topic]
+    pub user: Address,
+    pub seq: u64,
+    pub kind: NotificationKind,
+}
+
+/// A member acknowledged their notifications up to a sequence number.
+#[contractevent(topics = ["notifications_acknowledged", "v1"])]
+#[derive(Clone, Debug, PartialEq)]
+pub struct NotificationsAcknowledged {
+    #[topic]
+    pub user: Address,
+    pub up_to_seq: u64,
+}
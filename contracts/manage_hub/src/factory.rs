@@ -0,0 +1,138 @@
+use crate::errors::{Error, ModuleError};
+use crate::events::{HubDeployed, HubWasmSet};
+use crate::factory_errors::FactoryError;
+use crate::initialization::InitializationModule;
+use crate::membership_token::MembershipTokenContract;
+use crate::types::{DeployHubParams, HubRecord};
+use soroban_sdk::{contracttype, Address, BytesN, Env, IntoVal, Symbol, Val, Vec};
+
+// ---------------------------------------------------------------------------
+// Storage keys
+// ---------------------------------------------------------------------------
+
+#[contracttype]
+pub enum FactoryDataKey {
+    /// Uploaded manage_hub WASM new hubs are deployed from (instance storage).
+    HubWasmHash,
+    /// Hub deployed by the factory, by address (persistent storage).
+    Hub(Address),
+    /// Addresses of every hub deployed by the factory (persistent storage).
+    Hubs,
+}
+
+// ---------------------------------------------------------------------------
+// Constants
+// ---------------------------------------------------------------------------
+
+/// Upper bound on hubs tracked by the registry.
+pub const MAX_HUBS: u32 = 200;
+
+// ---------------------------------------------------------------------------
+// Module
+// ---------------------------------------------------------------------------
+
+/// Factory for franchise hubs.
+///
+/// The admin uploads the manage_hub WASM once and records its hash here.
+/// Each `deploy_hub` call then deploys a fresh instance at an address
+/// derived from this contract and a salt, initializes it with the
+/// operator's admin, USDC contract and module configuration, links it to
+/// this hub's access_control contract so every hub shares one multisig, and
+/// records it in the hub registry. Deployment and initialization happen in
+/// one call, so a hub is never left uninitialized.
+pub struct FactoryModule;
+
+impl FactoryModule {
+    pub fn set_hub_wasm_hash(env: Env, admin: Address, wasm_hash: BytesN<32>) -> Result<(), Error> {
+        MembershipTokenContract::require_admin(&env, &admin)?;
+        env.storage()
+            .instance()
+            .set(&FactoryDataKey::HubWasmHash, &wasm_hash);
+
+        HubWasmSet { admin, wasm_hash }.publish(&env);
+        Ok(())
+    }
+
+    pub fn get_hub_wasm_hash(env: &Env) -> Option<BytesN<32>> {
+        env.storage().instance().get(&FactoryDataKey::HubWasmHash)
+    }
+
+    /// Deploys and initializes a new hub. Admin only; the new hub's admin
+    /// must also authorize, as its `initialize` requires.
+    pub fn deploy_hub(
+        env: Env,
+        admin: Address,
+        params: DeployHubParams,
+    ) -> Result<Address, ModuleError> {
+        MembershipTokenContract::require_admin(&env, &admin)?;
+        let wasm_hash = Self::get_hub_wasm_hash(&env).ok_or(FactoryError::HubCodeNotConfigured)?;
+        let access_control = InitializationModule::get_access_control(&env)
+            .ok_or(FactoryError::MultisigNotConfigured)?;
+
+        let mut hubs = Self::get_hubs(&env);
+        if hubs.len() >= MAX_HUBS {
+            return Err(FactoryError::TooManyHubs.into());
+        }
+        let deployer = env.deployer().with_current_contract(params.salt.clone());
+        if env
+            .storage()
+            .persistent()
+            .has(&FactoryDataKey::Hub(deployer.deployed_address()))
+        {
+            return Err(FactoryError::HubAlreadyDeployed.into());
+        }
+
+        let hub = deployer.deploy_v2(wasm_hash.clone(), ());
+        let args: Vec<Val> = Vec::from_array(
+            &env,
+            [
+                params.admin.into_val(&env),
+                params.usdc.into_val(&env),
+                access_control.into_val(&env),
+                params.config.into_val(&env),
+            ],
+        );
+        match env.try_invoke_contract::<(), Error>(&hub, &Symbol::new(&env, "initialize"), args) {
+            Ok(Ok(())) => {}
+            Err(Ok(error)) => return Err(error.into()),
+            _ => return Err(FactoryError::HubInitFailed.into()),
+        }
+
+        let record = HubRecord {
+            address: hub.clone(),
+            name: params.name,
+            admin: params.admin,
+            access_control,
+            wasm_hash,
+            deployed_by: admin,
+            deployed_at: env.ledger().timestamp(),
+        };
+        env.storage()
+            .persistent()
+            .set(&FactoryDataKey::Hub(hub.clone()), &record);
+        hubs.push_back(hub.clone());
+        env.storage().persistent().set(&FactoryDataKey::Hubs, &hubs);
+
+        HubDeployed {
+            hub: hub.clone(),
+            admin: record.admin,
+            name: record.name,
+        }
+        .publish(&env);
+        Ok(hub)
+    }
+
+    pub fn get_hub(env: &Env, hub: &Address) -> Result<HubRecord, ModuleError> {
+        env.storage()
+            .persistent()
+            .get(&FactoryDataKey::Hub(hub.clone()))
+            .ok_or(FactoryError::HubNotFound.into())
+    }
+
+    pub fn get_hubs(env: &Env) -> Vec<Address> {
+        env.storage()
+            .persistent()
+            .get(&FactoryDataKey::Hubs)
+            .unwrap_or_else(|| Vec::new(env))
+    }
+}
@@ -0,0 +1,23 @@
+//! Hub factory error types for the ManageHub contract.
+//!
+//! `FactoryError` owns the code range 850–899 (see [`crate::errors`]).
+
+use soroban_sdk::contracterror;
+
+/// Factory-specific errors.
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum FactoryError {
+    /// No hub WASM hash has been uploaded to the factory.
+    HubCodeNotConfigured = 850,
+    /// The factory has no access_control contract to share with new hubs.
+    MultisigNotConfigured = 851,
+    /// A hub was already deployed with this salt.
+    HubAlreadyDeployed = 852,
+    /// The hub registry is full.
+    TooManyHubs = 853,
+    /// The new hub could not be initialized.
+    HubInitFailed = 854,
+    /// No hub is registered at the given address.
+    HubNotFound = 855,
+}
@@ -0,0 +1,301 @@
+use crate::attendance_log::AttendanceLogModule;
+use crate::errors::ModuleError;
+use crate::events::{PartnerHubAdded, PartnerHubUpdated, PartnerVisitLogged, PartnerVisitReported};
+use crate::federation_errors::FederationError;
+use crate::membership_token::MembershipTokenContract;
+use crate::types::{
+    AttendanceAction, EntryDecision, EntryStatus, PartnerHub, PartnerUsage, PartnerVisits,
+};
+use soroban_sdk::{contracttype, Address, BytesN, Env, IntoVal, Map, String, Symbol, Val, Vec};
+
+// ---------------------------------------------------------------------------
+// Storage keys
+// ---------------------------------------------------------------------------
+
+#[contracttype]
+pub enum FederationDataKey {
+    /// Partner hub by contract address (persistent storage).
+    PartnerHub(Address),
+    /// Contract addresses of every registered partner hub (persistent
+    /// storage).
+    FederatedHubs,
+    /// Visits exchanged with a partner hub (persistent storage).
+    PartnerUsage(Address),
+    /// A partner member's visits in the current period, keyed by partner
+    /// and member (persistent storage).
+    PartnerVisits(Address, Address),
+}
+
+// ---------------------------------------------------------------------------
+// Constants
+// ---------------------------------------------------------------------------
+
+/// Upper bound on partner hubs, keeping entry fallback checks bounded.
+pub const MAX_PARTNER_HUBS: u32 = 20;
+
+/// Length of a partner visit quota period.
+pub const PARTNER_QUOTA_PERIOD: u64 = 30 * 24 * 60 * 60;
+
+// ---------------------------------------------------------------------------
+// Module
+// ---------------------------------------------------------------------------
+
+/// Federation with partner hubs run as separate contract instances.
+///
+/// The admin registers trusted partner manage_hub contracts. A member of a
+/// partner hub is recognized here by asking their home hub, through
+/// `verify_home_entry`, whether it would let them in; home verification
+/// never consults partners, so two hubs can't recurse into each other. Each
+/// partner's members get a number of visits per 30-day period, counted when
+/// they clock in, and every such visit is reported back to the home hub so
+/// both sides can reconcile usage.
+pub struct FederationModule;
+
+impl FederationModule {
+    pub fn add_partner(
+        env: Env,
+        admin: Address,
+        partner: Address,
+        name: String,
+        visit_quota: u32,
+    ) -> Result<PartnerHub, ModuleError> {
+        MembershipTokenContract::require_admin(&env, &admin)?;
+        if partner == env.current_contract_address() || visit_quota == 0 {
+            return Err(FederationError::InvalidPartner.into());
+        }
+
+        let key = FederationDataKey::PartnerHub(partner.clone());
+        if env.storage().persistent().has(&key) {
+            return Err(FederationError::PartnerAlreadyRegistered.into());
+        }
+        let mut partners = Self::get_partners(&env);
+        if partners.len() >= MAX_PARTNER_HUBS {
+            return Err(FederationError::TooManyPartners.into());
+        }
+        partners.push_back(partner.clone());
+        env.storage()
+            .persistent()
+            .set(&FederationDataKey::FederatedHubs, &partners);
+
+        let hub = PartnerHub {
+            contract: partner.clone(),
+            name,
+            visit_quota,
+            active: true,
+            added_at: env.ledger().timestamp(),
+        };
+        env.storage().persistent().set(&key, &hub);
+
+        PartnerHubAdded {
+            partner,
+            visit_quota,
+        }
+        .publish(&env);
+        Ok(hub)
+    }
+
+    /// Changes a partner's visit quota or suspends recognition of its
+    /// tokens.
+    pub fn update_partner(
+        env: Env,
+        admin: Address,
+        partner: Address,
+        visit_quota: u32,
+        active: bool,
+    ) -> Result<(), ModuleError> {
+        MembershipTokenContract::require_admin(&env, &admin)?;
+        if visit_quota == 0 {
+            return Err(FederationError::InvalidPartner.into());
+        }
+        let mut hub = Self::get_partner(&env, &partner)?;
+        hub.visit_quota = visit_quota;
+        hub.active = active;
+        env.storage()
+            .persistent()
+            .set(&FederationDataKey::PartnerHub(partner.clone()), &hub);
+
+        PartnerHubUpdated {
+            partner,
+            visit_quota,
+            active,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    pub fn get_partner(env: &Env, partner: &Address) -> Result<PartnerHub, ModuleError> {
+        env.storage()
+            .persistent()
+            .get(&FederationDataKey::PartnerHub(partner.clone()))
+            .ok_or(FederationError::PartnerNotFound.into())
+    }
+
+    pub fn get_partners(env: &Env) -> Vec<Address> {
+        env.storage()
+            .persistent()
+            .get(&FederationDataKey::FederatedHubs)
+            .unwrap_or_else(|| Vec::new(env))
+    }
+
+    /// Asks each active partner whether `user` holds a membership there.
+    /// Returns a granting decision for the first partner that vouches for
+    /// the user with visits left, a `PartnerQuotaExceeded` decision if every
+    /// vouching partner's quota is used up, or `None` if no partner vouches.
+    pub(crate) fn verify_partner_entry(env: &Env, user: &Address) -> Option<EntryDecision> {
+        let mut exhausted = None;
+        for partner in Self::get_partners(env).iter() {
+            let Ok(hub) = Self::get_partner(env, &partner) else {
+                continue;
+            };
+            if !hub.active {
+                continue;
+            }
+            let Some(mut decision) = Self::home_decision(env, &partner, user) else {
+                continue;
+            };
+            decision.partner = Some(partner.clone());
+            decision.checked_at = env.ledger().timestamp();
+            if Self::current_visits(env, &partner, user).used < hub.visit_quota {
+                return Some(decision);
+            }
+            decision.allowed = false;
+            decision.status = EntryStatus::PartnerQuotaExceeded;
+            exhausted.get_or_insert(decision);
+        }
+        exhausted
+    }
+
+    /// Logs attendance for a member of `partner`, after the partner confirms
+    /// the membership. A clock-in spends one of the member's visits for the
+    /// period and is reported back to the partner.
+    pub fn log_partner_attendance(
+        env: Env,
+        id: BytesN<32>,
+        user: Address,
+        partner: Address,
+        action: AttendanceAction,
+        details: Map<String, String>,
+    ) -> Result<(), ModuleError> {
+        let hub = Self::get_partner(&env, &partner)?;
+        if !hub.active {
+            return Err(FederationError::PartnerInactive.into());
+        }
+        if Self::home_decision(&env, &partner, &user).is_none() {
+            return Err(FederationError::PartnerEntryDenied.into());
+        }
+
+        let counts_as_visit = action == AttendanceAction::ClockIn;
+        let mut visits = Self::current_visits(&env, &partner, &user);
+        if counts_as_visit && visits.used >= hub.visit_quota {
+            return Err(FederationError::VisitQuotaExceeded.into());
+        }
+
+        AttendanceLogModule::log_attendance(env.clone(), id, user.clone(), action, details)?;
+        if !counts_as_visit {
+            return Ok(());
+        }
+
+        visits.used += 1;
+        env.storage().persistent().set(
+            &FederationDataKey::PartnerVisits(partner.clone(), user.clone()),
+            &visits,
+        );
+        let now = env.ledger().timestamp();
+        let mut usage = Self::get_usage(&env, &partner);
+        usage.inbound_visits += 1;
+        usage.last_inbound_at = now;
+        env.storage()
+            .persistent()
+            .set(&FederationDataKey::PartnerUsage(partner.clone()), &usage);
+
+        // Reporting is best-effort: an unreachable partner must not turn
+        // its members away at the door.
+        let args: Vec<Val> = Vec::from_array(
+            &env,
+            [
+                env.current_contract_address().into_val(&env),
+                user.into_val(&env),
+            ],
+        );
+        let reported = env
+            .try_invoke_contract::<(), soroban_sdk::Error>(
+                &partner,
+                &Symbol::new(&env, "report_partner_visit"),
+                args,
+            )
+            .is_ok_and(|result| result.is_ok());
+
+        PartnerVisitLogged {
+            partner,
+            user,
+            visits_used: visits.used,
+            reported,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    /// Records that one of this hub's members visited `hub`. Called by the
+    /// partner contract itself, which must be registered here.
+    pub fn report_partner_visit(env: Env, hub: Address, user: Address) -> Result<(), ModuleError> {
+        hub.require_auth();
+        Self::get_partner(&env, &hub)?;
+
+        let mut usage = Self::get_usage(&env, &hub);
+        usage.outbound_visits += 1;
+        usage.last_outbound_at = env.ledger().timestamp();
+        env.storage()
+            .persistent()
+            .set(&FederationDataKey::PartnerUsage(hub.clone()), &usage);
+
+        PartnerVisitReported {
+            hub,
+            user,
+            outbound_visits: usage.outbound_visits,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    pub fn get_usage(env: &Env, partner: &Address) -> PartnerUsage {
+        env.storage()
+            .persistent()
+            .get(&FederationDataKey::PartnerUsage(partner.clone()))
+            .unwrap_or_default()
+    }
+
+    /// Visits a partner member made in the current period.
+    pub fn get_visits_used(env: &Env, partner: &Address, user: &Address) -> u32 {
+        Self::current_visits(env, partner, user).used
+    }
+
+    /// The partner's home decision for `user`, if it grants entry.
+    fn home_decision(env: &Env, partner: &Address, user: &Address) -> Option<EntryDecision> {
+        let args: Vec<Val> = Vec::from_array(env, [user.into_val(env)]);
+        match env.try_invoke_contract::<EntryDecision, soroban_sdk::Error>(
+            partner,
+            &Symbol::new(env, "verify_home_entry"),
+            args,
+        ) {
+            Ok(Ok(decision)) if decision.allowed => Some(decision),
+            _ => None,
+        }
+    }
+
+    /// Visits for the period containing now, starting afresh once the
+    /// stored period has ended.
+    fn current_visits(env: &Env, partner: &Address, user: &Address) -> PartnerVisits {
+        let period_start = env.ledger().timestamp() / PARTNER_QUOTA_PERIOD * PARTNER_QUOTA_PERIOD;
+        env.storage()
+            .persistent()
+            .get::<_, PartnerVisits>(&FederationDataKey::PartnerVisits(
+                partner.clone(),
+                user.clone(),
+            ))
+            .filter(|visits| visits.period_start == period_start)
+            .unwrap_or(PartnerVisits {
+                period_start,
+                used: 0,
+            })
+    }
+}
@@ -0,0 +1,25 @@
+//! Federation error types for the ManageHub contract.
+//!
+//! `FederationError` owns the code range 900–949 (see [`crate::errors`]).
+
+use soroban_sdk::contracterror;
+
+/// Federation-specific errors.
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum FederationError {
+    /// The address is not a registered partner hub.
+    PartnerNotFound = 900,
+    /// The partner hub is already registered.
+    PartnerAlreadyRegistered = 901,
+    /// A hub cannot partner with itself, and visit quotas must be positive.
+    InvalidPartner = 902,
+    /// The partner registry is full.
+    TooManyPartners = 903,
+    /// The partner hub has been deactivated.
+    PartnerInactive = 904,
+    /// The partner hub did not grant the user entry, or could not be reached.
+    PartnerEntryDenied = 905,
+    /// The user has used this period's visits at the hub for the partner.
+    VisitQuotaExceeded = 906,
+}
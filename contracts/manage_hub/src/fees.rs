@@ -0,0 +1,236 @@
+use crate::billing_errors::BillingError;
+use crate::errors::{Error, ModuleError};
+use crate::membership_token::MembershipTokenContract;
+use crate::oracle::OracleModule;
+use crate::subscription::SubscriptionContract;
+use crate::types::{BillingCycle, FeeBreakdown, FeeConfig};
+use common_types::{Amount, Bps};
+use soroban_sdk::{contracttype, Address, Env, String};
+
+// ---------------------------------------------------------------------------
+// Storage keys
+// ---------------------------------------------------------------------------
+
+#[contracttype]
+pub enum FeeDataKey {
+    /// Platform and processing fee settings (instance storage).
+    FeeConfig,
+    /// Tax rate of a region code, in basis points (persistent storage).
+    RegionTax(String),
+    /// Region code a user is billed in (persistent storage).
+    UserRegion(Address),
+}
+
+// ---------------------------------------------------------------------------
+// Constants
+// ---------------------------------------------------------------------------
+
+/// Highest configurable platform fee or regional tax rate (50 %).
+pub const MAX_FEE_BPS: u32 = 5_000;
+
+// ---------------------------------------------------------------------------
+// Module
+// ---------------------------------------------------------------------------
+
+/// Fee engine for tier-priced charges. A charge is the price plus a
+/// platform fee, a fixed processing fee and the tax of the payer's region,
+/// which applies to the price and both fees.
+pub struct FeeModule;
+
+impl FeeModule {
+    /// Sets the platform and processing fees. Admin only.
+    pub fn set_fee_config(env: Env, admin: Address, config: FeeConfig) -> Result<(), ModuleError> {
+        MembershipTokenContract::require_admin(&env, &admin)?;
+        if config.platform_fee_bps > MAX_FEE_BPS || config.processing_fee < 0 {
+            return Err(BillingError::InvalidFeeConfig.into());
+        }
+        env.storage()
+            .instance()
+            .set(&FeeDataKey::FeeConfig, &config);
+        Ok(())
+    }
+
+    /// Returns the fee settings; no fees until the admin configures them.
+    pub fn get_fee_config(env: &Env) -> FeeConfig {
+        env.storage()
+            .instance()
+            .get(&FeeDataKey::FeeConfig)
+            .unwrap_or(FeeConfig {
+                platform_fee_bps: 0,
+                processing_fee: 0,
+            })
+    }
+
+    /// Sets the tax rate of a region code. Admin only. A rate of zero
+    /// removes the region.
+    pub fn set_region_tax_rate(
+        env: Env,
+        admin: Address,
+        region: String,
+        rate_bps: u32,
+    ) -> Result<(), ModuleError> {
+        MembershipTokenContract::require_admin(&env, &admin)?;
+        if rate_bps > MAX_FEE_BPS {
+            return Err(BillingError::InvalidFeeConfig.into());
+        }
+        let key = FeeDataKey::RegionTax(region);
+        if rate_bps == 0 {
+            env.storage().persistent().remove(&key);
+        } else {
+            env.storage().persistent().set(&key, &rate_bps);
+        }
+        Ok(())
+    }
+
+    /// Returns the tax rate of a region code, 0 if it has none.
+    pub fn get_region_tax_rate(env: &Env, region: &String) -> u32 {
+        env.storage()
+            .persistent()
+            .get(&FeeDataKey::RegionTax(region.clone()))
+            .unwrap_or(0)
+    }
+
+    /// Records the region code `user` is billed in. `None` clears it.
+    pub fn set_billing_region(env: Env, user: Address, region: Option<String>) {
+        user.require_auth();
+        let key = FeeDataKey::UserRegion(user);
+        match region {
+            Some(region) => env.storage().persistent().set(&key, &region),
+            None => env.storage().persistent().remove(&key),
+        }
+    }
+
+    pub fn get_billing_region(env: &Env, user: &Address) -> Option<String> {
+        env.storage()
+            .persistent()
+            .get(&FeeDataKey::UserRegion(user.clone()))
+    }
+
+    /// Quotes the total for a new subscription to `tier_id` at list price,
+    /// USDC-denominated, billed in `region`.
+    pub fn quote_total(
+        env: Env,
+        tier_id: String,
+        billing_cycle: BillingCycle,
+        region: Option<String>,
+    ) -> Result<FeeBreakdown, Error> {
+        let tier = SubscriptionContract::get_tier(env.clone(), tier_id)?;
+        let price = match billing_cycle {
+            BillingCycle::Monthly => tier.price,
+            BillingCycle::Annual => tier.annual_price,
+        };
+        Self::breakdown(&env, price, region.as_ref())
+    }
+
+    /// Fee breakdown of a USDC-denominated `price` for `payer`, using the
+    /// payer's billing region.
+    pub(crate) fn breakdown_for(
+        env: &Env,
+        payer: &Address,
+        price: i128,
+    ) -> Result<FeeBreakdown, Error> {
+        let region = Self::get_billing_region(env, payer);
+        Self::breakdown(env, price, region.as_ref())
+    }
+
+    /// A charge that carries no fees.
+    pub(crate) fn without_fees(amount: i128) -> FeeBreakdown {
+        FeeBreakdown {
+            base: amount,
+            platform_fee: 0,
+            processing_fee: 0,
+            tax: 0,
+            total: amount,
+        }
+    }
+
+    /// Part of `charge` settled by a payment that raised the amount paid
+    /// towards it from `paid_before` to `paid_after`, such as one
+    /// installment. Fees are settled in proportion to the amount paid, so
+    /// a fully paid charge settles each fee exactly once.
+    pub(crate) fn settled_between(
+        charge: &FeeBreakdown,
+        paid_before: i128,
+        paid_after: i128,
+    ) -> Result<FeeBreakdown, Error> {
+        let share = |fee: i128| -> Result<i128, Error> {
+            if charge.total <= 0 {
+                return Ok(0);
+            }
+            let settled = |paid: i128| {
+                fee.checked_mul(paid)
+                    .map(|v| v / charge.total)
+                    .ok_or(Error::InvalidPaymentAmount)
+            };
+            Ok(settled(paid_after)? - settled(paid_before)?)
+        };
+        let total = paid_after - paid_before;
+        let platform_fee = share(charge.platform_fee)?;
+        let processing_fee = share(charge.processing_fee)?;
+        let tax = share(charge.tax)?;
+        Ok(FeeBreakdown {
+            base: total - platform_fee - processing_fee - tax,
+            platform_fee,
+            processing_fee,
+            tax,
+            total,
+        })
+    }
+
+    /// Converts a USDC-denominated breakdown of a `tier_id` charge into
+    /// `payment_token` units. Each fee is converted on its own and the base
+    /// absorbs the rounding, so the parts still add up to the converted total.
+    pub(crate) fn convert(
+        env: &Env,
+        payment_token: &Address,
+        tier_id: &String,
+        usdc: &FeeBreakdown,
+    ) -> Result<FeeBreakdown, ModuleError> {
+        let convert =
+            |amount: i128| OracleModule::convert_tier_price(env, payment_token, tier_id, amount);
+        let total = convert(usdc.total)?;
+        let platform_fee = convert(usdc.platform_fee)?;
+        let processing_fee = convert(usdc.processing_fee)?;
+        let tax = convert(usdc.tax)?;
+        Ok(FeeBreakdown {
+            base: total - platform_fee - processing_fee - tax,
+            platform_fee,
+            processing_fee,
+            tax,
+            total,
+        })
+    }
+
+    fn breakdown(env: &Env, price: i128, region: Option<&String>) -> Result<FeeBreakdown, Error> {
+        // Nothing is charged, so no per-charge fees either
+        if price <= 0 {
+            return Ok(Self::without_fees(price));
+        }
+        let config = Self::get_fee_config(env);
+        let tax_bps = region.map_or(0, |region| Self::get_region_tax_rate(env, region));
+
+        let platform_fee = Self::bps_of(price, config.platform_fee_bps)?;
+        let taxable = price
+            .checked_add(platform_fee)
+            .and_then(|v| v.checked_add(config.processing_fee))
+            .ok_or(Error::InvalidPaymentAmount)?;
+        let tax = Self::bps_of(taxable, tax_bps)?;
+        Ok(FeeBreakdown {
+            base: price,
+            platform_fee,
+            processing_fee: config.processing_fee,
+            tax,
+            total: taxable
+                .checked_add(tax)
+                .ok_or(Error::InvalidPaymentAmount)?,
+        })
+    }
+
+    fn bps_of(amount: i128, bps: u32) -> Result<i128, Error> {
+        Amount::new(amount)
+            .zip(Bps::new(bps))
+            .and_then(|(amount, bps)| amount.mul_bps(bps))
+            .map(Amount::value)
+            .ok_or(Error::InvalidPaymentAmount)
+    }
+}
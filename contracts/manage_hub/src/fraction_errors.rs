@@ -0,0 +1,29 @@
+//! Fraction error types for the ManageHub contract.
+//!
+//! `FractionError` owns the code range 950–999 (see [`crate::errors`]).
+
+use soroban_sdk::contracterror;
+
+/// Fraction errors.
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum FractionError {
+    /// The token only lets whitelisted addresses receive shares.
+    RecipientNotWhitelisted = 950,
+    /// The token's owner has not approved this transfer.
+    TransferNotApproved = 951,
+    /// The token's holder whitelist is full.
+    WhitelistFull = 952,
+    /// The sender's unlocked shares do not cover the transfer.
+    SharesLocked = 953,
+    /// The lockup period is zero or longer than the maximum.
+    InvalidLockupPeriod = 954,
+    /// The token has no tier to renew at.
+    NoRenewalTier = 955,
+    /// The renewal pool holds less than the renewal price.
+    RenewalUnderfunded = 956,
+    /// The renewal pool has reached its contribution limit.
+    TooManyContributions = 957,
+    /// The holder has nothing in the renewal pool.
+    NoContribution = 958,
+}
@@ -1,17 +1,77 @@
-#![allow(deprecated)]
-
-use crate::errors::Error;
-use crate::membership_token::{DataKey as MembershipDataKey, MembershipToken};
-use crate::types::{DividendDistribution, FractionHolder, FractionalTokenInfo};
-use soroban_sdk::{contracttype, Address, BytesN, Env, Map, String, Vec};
+use crate::allowance::AllowanceModule;
+use crate::errors::{Error, ModuleError};
+use crate::events::{
+    DividendDistributed, FractionApproved, FractionTransferApproved, FractionTransferPolicySet,
+    FractionTransferred, FractionWhitelistUpdated, FractionalizedTokenRenewed, RenewalContributed,
+    RenewalContributionWithdrawn, TokenFractionalized, TokenRecombined,
+};
+use crate::fraction_errors::FractionError;
+use crate::membership_token::{
+    DataKey as MembershipDataKey, MembershipToken, MembershipTokenContract,
+};
+use crate::outbox::OutboxModule;
+use crate::sales::SaleModule;
+use crate::stats::StatsModule;
+use crate::subscription::SubscriptionContract;
+use crate::ttl::{TtlEntityClass, TtlModule};
+use crate::types::{
+    DividendDistribution, FractionAllowance, FractionHolder, FractionTransferPolicy,
+    FractionalTokenInfo, FundedRenewal, OutboxSubject, RenewalContribution, RenewalPool,
+    RenewalRefund, ShareLot,
+};
+use crate::verification::VerificationModule;
+use common_types::Amount;
+use soroban_sdk::{contracttype, symbol_short, token, Address, BytesN, Env, Map, String, Vec};
 
 #[contracttype]
 pub enum FractionDataKey {
     FractionInfo(BytesN<32>),
     FractionShares(BytesN<32>),
     PendingRewards(BytesN<32>),
+    /// Share allowances keyed by (owner, spender) (persistent)
+    Allowances(BytesN<32>),
+    /// Fractionalized tokens an address holds shares in (persistent)
+    HolderTokens(Address),
+    /// Who may receive a token's shares, when not open (persistent)
+    TransferPolicy(BytesN<32>),
+    /// Addresses allowed to receive a token's shares under the whitelist
+    /// policy (persistent)
+    Whitelist(BytesN<32>),
+    /// Shares the token's owner approved moving, keyed by (from, to)
+    /// (persistent)
+    TransferApprovals(BytesN<32>),
+    /// Seconds received shares stay locked, when the token has a lockup
+    /// (persistent)
+    Lockup(BytesN<32>),
+    /// Locked lots of shares a holder received, oldest first (persistent)
+    ShareLots(BytesN<32>, Address),
+    /// USDC holders have pooled toward the token's next renewal
+    /// (persistent)
+    RenewalPool(BytesN<32>),
+    /// Renewals the token's holders paid for, oldest first (persistent)
+    FundedRenewals(BytesN<32>),
 }
 
+/// Upper bound on a token's holder whitelist.
+pub const MAX_FRACTION_WHITELIST: u32 = 100;
+
+/// Longest lockup a token can be fractionalized with (one year).
+pub const MAX_FRACTION_LOCKUP: u64 = 365 * 24 * 60 * 60;
+
+/// Locked lots kept per holder; further receipts merge into the newest.
+pub const MAX_SHARE_LOTS: u32 = 20;
+
+/// Upper bound on contributions to one renewal pool.
+pub const MAX_RENEWAL_CONTRIBUTIONS: u32 = 50;
+
+/// Holder-funded renewals kept per token; the oldest is dropped first.
+pub const MAX_FUNDED_RENEWALS: u32 = 20;
+
+/// Fraction shares are whole units.
+const FRACTION_DECIMALS: u32 = 0;
+const FRACTION_NAME: &str = "ManageHub Membership Fraction";
+const FRACTION_SYMBOL: &str = "MHFRAC";
+
 pub struct FractionalizationModule;
 
 impl FractionalizationModule {
@@ -20,18 +80,49 @@ impl FractionalizationModule {
         token_id: BytesN<32>,
         total_shares: i128,
         min_fraction_size: i128,
-    ) -> Result<(), Error> {
+    ) -> Result<(), ModuleError> {
+        Self::fractionalize(env, token_id, total_shares, min_fraction_size, 0)
+    }
+
+    /// Fractionalizes a token whose shares, once transferred, stay locked
+    /// with their new holder for `lockup_period` seconds.
+    pub fn fractionalize_token_with_lockup(
+        env: Env,
+        token_id: BytesN<32>,
+        total_shares: i128,
+        min_fraction_size: i128,
+        lockup_period: u64,
+    ) -> Result<(), ModuleError> {
+        if lockup_period == 0 || lockup_period > MAX_FRACTION_LOCKUP {
+            return Err(FractionError::InvalidLockupPeriod.into());
+        }
+        Self::fractionalize(
+            env,
+            token_id,
+            total_shares,
+            min_fraction_size,
+            lockup_period,
+        )
+    }
+
+    fn fractionalize(
+        env: Env,
+        token_id: BytesN<32>,
+        total_shares: i128,
+        min_fraction_size: i128,
+        lockup_period: u64,
+    ) -> Result<(), ModuleError> {
         if total_shares <= 1 {
-            return Err(Error::InvalidPaymentAmount);
+            return Err(Error::InvalidPaymentAmount.into());
         }
         if min_fraction_size <= 0 || min_fraction_size > total_shares {
-            return Err(Error::InvalidPaymentAmount);
+            return Err(Error::InvalidPaymentAmount.into());
         }
         if total_shares % min_fraction_size != 0 {
-            return Err(Error::InvalidPaymentAmount);
+            return Err(Error::InvalidPaymentAmount.into());
         }
         if Self::is_fractionalized(&env, &token_id) {
-            return Err(Error::TokenFractionalized);
+            return Err(Error::TokenFractionalized.into());
         }
 
         let token: MembershipToken = env
@@ -40,9 +131,10 @@ impl FractionalizationModule {
             .get(&MembershipDataKey::Token(token_id.clone()))
             .ok_or(Error::TokenNotFound)?;
         if token.status != crate::types::MembershipStatus::Active {
-            return Err(Error::TokenExpired);
+            return Err(Error::TokenExpired.into());
         }
         token.user.require_auth();
+        VerificationModule::check_fractionalization(&env, &token.user)?;
 
         let info = FractionalTokenInfo {
             token_id: token_id.clone(),
@@ -61,16 +153,28 @@ impl FractionalizationModule {
         env.storage()
             .persistent()
             .set(&FractionDataKey::FractionShares(token_id.clone()), &shares);
-
-        env.events().publish(
-            (
-                String::from_str(&env, "Fractionalized"),
-                token_id,
-                token.user.clone(),
-            ),
-            (total_shares, min_fraction_size, env.ledger().timestamp()),
+        if lockup_period > 0 {
+            env.storage()
+                .persistent()
+                .set(&FractionDataKey::Lockup(token_id.clone()), &lockup_period);
+        }
+        Self::index_holder(&env, &token.user, &token_id, true);
+        StatsModule::record_fractionalized(&env, 1);
+        OutboxModule::append(
+            &env,
+            symbol_short!("fraction"),
+            OutboxSubject::Token(token_id.clone()),
         );
 
+        TokenFractionalized {
+            token_id,
+            owner: token.user.clone(),
+            total_shares,
+            min_fraction_size,
+            timestamp: env.ledger().timestamp(),
+        }
+        .publish(&env);
+
         Ok(())
     }
 
@@ -80,8 +184,16 @@ impl FractionalizationModule {
         from: Address,
         to: Address,
         share_amount: i128,
-    ) -> Result<(), Error> {
+    ) -> Result<(), ModuleError> {
         let info = Self::get_fraction_info(&env, &token_id)?;
+        Self::validate_share_amount(&info, share_amount)?;
+
+        from.require_auth();
+
+        Self::move_shares(&env, &info, token_id, from, to, share_amount)
+    }
+
+    fn validate_share_amount(info: &FractionalTokenInfo, share_amount: i128) -> Result<(), Error> {
         if share_amount <= 0 {
             return Err(Error::InvalidPaymentAmount);
         }
@@ -91,24 +203,38 @@ impl FractionalizationModule {
         if share_amount % info.min_fraction_size != 0 {
             return Err(Error::InvalidPaymentAmount);
         }
+        Ok(())
+    }
 
-        from.require_auth();
+    fn move_shares(
+        env: &Env,
+        info: &FractionalTokenInfo,
+        token_id: BytesN<32>,
+        from: Address,
+        to: Address,
+        share_amount: i128,
+    ) -> Result<(), ModuleError> {
+        Self::check_transfer_policy(env, &token_id, &from, &to, share_amount)?;
 
-        let mut shares = Self::get_fraction_shares(&env, &token_id)?;
+        let mut shares = Self::get_fraction_shares(env, &token_id)?;
         let sender_shares = shares.get(from.clone()).ok_or(Error::Unauthorized)?;
         if sender_shares < share_amount {
-            return Err(Error::InsufficientBalance);
+            return Err(Error::InsufficientBalance.into());
         }
 
         let remaining = sender_shares
             .checked_sub(share_amount)
             .ok_or(Error::TimestampOverflow)?;
         if remaining > 0 && remaining < info.min_fraction_size {
-            return Err(Error::InvalidPaymentAmount);
+            return Err(Error::InvalidPaymentAmount.into());
+        }
+        if remaining < Self::locked_shares(env, &token_id, &from) {
+            return Err(FractionError::SharesLocked.into());
         }
 
         if remaining == 0 {
             shares.remove(from.clone());
+            Self::index_holder(env, &from, &token_id, false);
         } else {
             shares.set(from.clone(), remaining);
         }
@@ -118,23 +244,700 @@ impl FractionalizationModule {
             .checked_add(share_amount)
             .ok_or(Error::TimestampOverflow)?;
         shares.set(to.clone(), new_receiver_shares);
+        Self::index_holder(env, &to, &token_id, true);
+        Self::lock_received(env, &token_id, &to, share_amount);
 
         env.storage()
             .persistent()
             .set(&FractionDataKey::FractionShares(token_id.clone()), &shares);
 
-        env.events().publish(
-            (
-                String::from_str(&env, "FractionTransferred"),
-                token_id,
-                from,
-            ),
-            (to, share_amount, env.ledger().timestamp()),
+        FractionTransferred {
+            token_id,
+            from,
+            to,
+            share_amount,
+            timestamp: env.ledger().timestamp(),
+        }
+        .publish(env);
+
+        Ok(())
+    }
+
+    // ------------------------------------------------------------------
+    // Transfer restrictions
+    // ------------------------------------------------------------------
+
+    pub fn set_transfer_policy(
+        env: Env,
+        admin: Address,
+        token_id: BytesN<32>,
+        policy: FractionTransferPolicy,
+    ) -> Result<(), Error> {
+        MembershipTokenContract::require_admin(&env, &admin)?;
+        Self::get_fraction_info(&env, &token_id)?;
+
+        let key = FractionDataKey::TransferPolicy(token_id.clone());
+        if policy == FractionTransferPolicy::Open {
+            env.storage().persistent().remove(&key);
+        } else {
+            env.storage().persistent().set(&key, &policy);
+        }
+
+        FractionTransferPolicySet {
+            token_id,
+            policy,
+            set_by: admin,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    pub fn get_transfer_policy(env: &Env, token_id: &BytesN<32>) -> FractionTransferPolicy {
+        env.storage()
+            .persistent()
+            .get(&FractionDataKey::TransferPolicy(token_id.clone()))
+            .unwrap_or(FractionTransferPolicy::Open)
+    }
+
+    pub fn set_whitelisted(
+        env: Env,
+        admin: Address,
+        token_id: BytesN<32>,
+        holder: Address,
+        allowed: bool,
+    ) -> Result<(), ModuleError> {
+        MembershipTokenContract::require_admin(&env, &admin)?;
+        Self::get_fraction_info(&env, &token_id)?;
+
+        let mut whitelist = Self::get_whitelist(&env, &token_id);
+        match (whitelist.first_index_of(&holder), allowed) {
+            (None, true) => {
+                if whitelist.len() >= MAX_FRACTION_WHITELIST {
+                    return Err(FractionError::WhitelistFull.into());
+                }
+                whitelist.push_back(holder.clone());
+            }
+            (Some(index), false) => {
+                whitelist.remove(index);
+            }
+            _ => return Ok(()),
+        }
+        env.storage()
+            .persistent()
+            .set(&FractionDataKey::Whitelist(token_id.clone()), &whitelist);
+
+        FractionWhitelistUpdated {
+            token_id,
+            holder,
+            allowed,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    pub fn get_whitelist(env: &Env, token_id: &BytesN<32>) -> Vec<Address> {
+        env.storage()
+            .persistent()
+            .get(&FractionDataKey::Whitelist(token_id.clone()))
+            .unwrap_or_else(|| Vec::new(env))
+    }
+
+    pub fn approve_transfer(
+        env: Env,
+        token_id: BytesN<32>,
+        from: Address,
+        to: Address,
+        share_amount: i128,
+    ) -> Result<(), Error> {
+        Self::get_fraction_info(&env, &token_id)?;
+        if share_amount < 0 {
+            return Err(Error::InvalidPaymentAmount);
+        }
+        let token: MembershipToken = env
+            .storage()
+            .persistent()
+            .get(&MembershipDataKey::Token(token_id.clone()))
+            .ok_or(Error::TokenNotFound)?;
+        token.user.require_auth();
+
+        let mut approvals = Self::get_transfer_approvals(&env, &token_id);
+        let key = (from.clone(), to.clone());
+        if share_amount == 0 {
+            approvals.remove(key);
+        } else {
+            approvals.set(key, share_amount);
+        }
+        Self::set_transfer_approvals(&env, &token_id, &approvals);
+
+        FractionTransferApproved {
+            token_id,
+            from,
+            to,
+            share_amount,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    /// Shares the token's owner has approved moving from `from` to `to`.
+    pub fn get_transfer_approval(
+        env: &Env,
+        token_id: &BytesN<32>,
+        from: &Address,
+        to: &Address,
+    ) -> i128 {
+        Self::get_transfer_approvals(env, token_id)
+            .get((from.clone(), to.clone()))
+            .unwrap_or(0)
+    }
+
+    fn get_transfer_approvals(env: &Env, token_id: &BytesN<32>) -> Map<(Address, Address), i128> {
+        env.storage()
+            .persistent()
+            .get(&FractionDataKey::TransferApprovals(token_id.clone()))
+            .unwrap_or_else(|| Map::new(env))
+    }
+
+    fn set_transfer_approvals(
+        env: &Env,
+        token_id: &BytesN<32>,
+        approvals: &Map<(Address, Address), i128>,
+    ) {
+        let key = FractionDataKey::TransferApprovals(token_id.clone());
+        if approvals.is_empty() {
+            env.storage().persistent().remove(&key);
+        } else {
+            env.storage().persistent().set(&key, approvals);
+        }
+    }
+
+    /// Rejects a share transfer the token's policy does not allow, and
+    /// spends the owner's approval under `OwnerApproved`.
+    fn check_transfer_policy(
+        env: &Env,
+        token_id: &BytesN<32>,
+        from: &Address,
+        to: &Address,
+        share_amount: i128,
+    ) -> Result<(), ModuleError> {
+        match Self::get_transfer_policy(env, token_id) {
+            FractionTransferPolicy::Open => Ok(()),
+            FractionTransferPolicy::Whitelist => {
+                if Self::get_whitelist(env, token_id).contains(to) {
+                    Ok(())
+                } else {
+                    Err(FractionError::RecipientNotWhitelisted.into())
+                }
+            }
+            FractionTransferPolicy::OwnerApproved => {
+                let owner = env
+                    .storage()
+                    .persistent()
+                    .get::<_, MembershipToken>(&MembershipDataKey::Token(token_id.clone()))
+                    .ok_or(Error::TokenNotFound)?
+                    .user;
+                if from == &owner {
+                    return Ok(());
+                }
+                let approved = Self::get_transfer_approval(env, token_id, from, to);
+                if approved < share_amount {
+                    return Err(FractionError::TransferNotApproved.into());
+                }
+                let mut approvals = Self::get_transfer_approvals(env, token_id);
+                let key = (from.clone(), to.clone());
+                if approved == share_amount {
+                    approvals.remove(key);
+                } else {
+                    approvals.set(key, approved - share_amount);
+                }
+                Self::set_transfer_approvals(env, token_id, &approvals);
+                Ok(())
+            }
+        }
+    }
+
+    // ------------------------------------------------------------------
+    // Lockup
+    // ------------------------------------------------------------------
+
+    /// Seconds received shares of a token stay locked; 0 without a lockup.
+    pub fn get_lockup_period(env: &Env, token_id: &BytesN<32>) -> u64 {
+        env.storage()
+            .persistent()
+            .get(&FractionDataKey::Lockup(token_id.clone()))
+            .unwrap_or(0)
+    }
+
+    /// Shares `holder` received within the lockup period and cannot move
+    /// yet.
+    pub fn get_locked_shares(env: Env, token_id: BytesN<32>, holder: Address) -> i128 {
+        Self::locked_shares(&env, &token_id, &holder)
+    }
+
+    fn locked_shares(env: &Env, token_id: &BytesN<32>, holder: &Address) -> i128 {
+        let now = env.ledger().timestamp();
+        Self::get_share_lots(env, token_id, holder)
+            .iter()
+            .filter(|lot| lot.unlocks_at > now)
+            .map(|lot| lot.amount)
+            .sum()
+    }
+
+    fn get_share_lots(env: &Env, token_id: &BytesN<32>, holder: &Address) -> Vec<ShareLot> {
+        env.storage()
+            .persistent()
+            .get(&FractionDataKey::ShareLots(
+                token_id.clone(),
+                holder.clone(),
+            ))
+            .unwrap_or_else(|| Vec::new(env))
+    }
+
+    /// Records shares `holder` just received as a locked lot, dropping lots
+    /// that have unlocked. Once the holder has `MAX_SHARE_LOTS` lots, the
+    /// receipt merges into the newest lot, which then unlocks with it.
+    fn lock_received(env: &Env, token_id: &BytesN<32>, holder: &Address, amount: i128) {
+        let lockup = Self::get_lockup_period(env, token_id);
+        if lockup == 0 {
+            return;
+        }
+        let now = env.ledger().timestamp();
+        let unlocks_at = now.saturating_add(lockup);
+        let mut lots = Vec::new(env);
+        for lot in Self::get_share_lots(env, token_id, holder).iter() {
+            if lot.unlocks_at > now {
+                lots.push_back(lot);
+            }
+        }
+        match lots.last() {
+            Some(mut newest) if lots.len() >= MAX_SHARE_LOTS => {
+                newest.amount = newest.amount.saturating_add(amount);
+                newest.unlocks_at = unlocks_at;
+                lots.set(lots.len() - 1, newest);
+            }
+            _ => lots.push_back(ShareLot { amount, unlocks_at }),
+        }
+        env.storage().persistent().set(
+            &FractionDataKey::ShareLots(token_id.clone(), holder.clone()),
+            &lots,
+        );
+    }
+
+    // ------------------------------------------------------------------
+    // Holder-funded renewal
+    // ------------------------------------------------------------------
+
+    /// Pays `amount` from `holder` toward the token's next monthly renewal,
+    /// in the USDC configured when the pool was opened. Only current share
+    /// holders may contribute; the pool may end up above the price, and the
+    /// excess is refunded on renewal.
+    pub fn contribute_renewal(
+        env: Env,
+        token_id: BytesN<32>,
+        holder: Address,
+        amount: i128,
+    ) -> Result<RenewalPool, ModuleError> {
+        Self::get_fraction_info(&env, &token_id)?;
+        holder.require_auth();
+        if amount <= 0 {
+            return Err(Error::InvalidPaymentAmount.into());
+        }
+        let shares = Self::get_fraction_shares(&env, &token_id)?;
+        if shares.get(holder.clone()).unwrap_or(0) <= 0 {
+            return Err(Error::Unauthorized.into());
+        }
+
+        // Later contributions stay in the token the pool was opened in
+        let existing = Self::get_renewal_pool(&env, &token_id);
+        let payment_token = match &existing {
+            Some(pool) => pool.payment_token.clone(),
+            None => SubscriptionContract::get_usdc_contract_address(&env)?,
+        };
+        let (tier_id, price) = Self::renewal_price(&env, &token_id, &payment_token)?;
+        let mut pool = existing.unwrap_or(RenewalPool {
+            token_id: token_id.clone(),
+            tier_id: tier_id.clone(),
+            payment_token,
+            price,
+            funded: 0,
+            contributions: Vec::new(&env),
+        });
+        if pool.contributions.len() >= MAX_RENEWAL_CONTRIBUTIONS {
+            return Err(FractionError::TooManyContributions.into());
+        }
+
+        token::Client::new(&env, &pool.payment_token).transfer(
+            &holder,
+            env.current_contract_address(),
+            &amount,
+        );
+
+        pool.tier_id = tier_id;
+        pool.price = price;
+        pool.funded = pool.funded.saturating_add(amount);
+        pool.contributions.push_back(RenewalContribution {
+            holder: holder.clone(),
+            amount,
+            contributed_at: env.ledger().timestamp(),
+        });
+        env.storage()
+            .persistent()
+            .set(&FractionDataKey::RenewalPool(token_id.clone()), &pool);
+
+        RenewalContributed {
+            token_id,
+            holder,
+            amount,
+            funded: pool.funded,
+            price,
+        }
+        .publish(&env);
+
+        Ok(pool)
+    }
+
+    /// Returns everything `holder` has paid into the renewal pool.
+    pub fn withdraw_renewal_contribution(
+        env: Env,
+        token_id: BytesN<32>,
+        holder: Address,
+    ) -> Result<i128, ModuleError> {
+        holder.require_auth();
+        let mut pool =
+            Self::get_renewal_pool(&env, &token_id).ok_or(FractionError::NoContribution)?;
+
+        let mut withdrawn: i128 = 0;
+        let mut remaining = Vec::new(&env);
+        for contribution in pool.contributions.iter() {
+            if contribution.holder == holder {
+                withdrawn = withdrawn.saturating_add(contribution.amount);
+            } else {
+                remaining.push_back(contribution);
+            }
+        }
+        if withdrawn == 0 {
+            return Err(FractionError::NoContribution.into());
+        }
+
+        let key = FractionDataKey::RenewalPool(token_id.clone());
+        if remaining.is_empty() {
+            env.storage().persistent().remove(&key);
+        } else {
+            pool.funded = pool.funded.saturating_sub(withdrawn);
+            pool.contributions = remaining;
+            env.storage().persistent().set(&key, &pool);
+        }
+
+        token::Client::new(&env, &pool.payment_token).transfer(
+            &env.current_contract_address(),
+            &holder,
+            &withdrawn,
         );
 
+        RenewalContributionWithdrawn {
+            token_id,
+            holder,
+            amount: withdrawn,
+        }
+        .publish(&env);
+
+        Ok(withdrawn)
+    }
+
+    /// Renews the token for a month from its holders' pooled USDC, once
+    /// the pool covers the price. Anyone may trigger it. The excess is
+    /// refunded in proportion to what each holder paid, with the rounding
+    /// remainder going to the last holder refunded.
+    pub fn renew_fractionalized_token(
+        env: Env,
+        token_id: BytesN<32>,
+        caller: Address,
+    ) -> Result<FundedRenewal, ModuleError> {
+        caller.require_auth();
+        Self::get_fraction_info(&env, &token_id)?;
+        let pool =
+            Self::get_renewal_pool(&env, &token_id).ok_or(FractionError::RenewalUnderfunded)?;
+        let (tier_id, price) = Self::renewal_price(&env, &token_id, &pool.payment_token)?;
+        if pool.funded < price {
+            return Err(FractionError::RenewalUnderfunded.into());
+        }
+
+        let new_expiry = MembershipTokenContract::renew_funded_token(
+            &env,
+            token_id.clone(),
+            pool.payment_token.clone(),
+            tier_id.clone(),
+        )?;
+
+        let payment_client = token::Client::new(&env, &pool.payment_token);
+        let excess = pool.funded - price;
+        let mut refunds = Vec::new(&env);
+        let mut refunded: i128 = 0;
+        if excess > 0 {
+            let totals = Self::contribution_totals(&env, &pool);
+            let last = totals.len() - 1;
+            for (index, (holder, paid)) in totals.iter().enumerate() {
+                let amount = if index as u32 == last {
+                    excess - refunded
+                } else {
+                    excess.saturating_mul(paid) / pool.funded
+                };
+                if amount <= 0 {
+                    continue;
+                }
+                payment_client.transfer(&env.current_contract_address(), &holder, &amount);
+                refunded += amount;
+                refunds.push_back(RenewalRefund { holder, amount });
+            }
+        }
+
+        let renewal = FundedRenewal {
+            token_id: token_id.clone(),
+            tier_id,
+            price,
+            funded: pool.funded,
+            contributions: pool.contributions,
+            refunds,
+            new_expiry,
+            renewed_at: env.ledger().timestamp(),
+            renewed_by: caller.clone(),
+        };
+        env.storage()
+            .persistent()
+            .remove(&FractionDataKey::RenewalPool(token_id.clone()));
+        let history_key = FractionDataKey::FundedRenewals(token_id.clone());
+        let mut history = Self::get_funded_renewals(&env, &token_id);
+        if history.len() >= MAX_FUNDED_RENEWALS {
+            history.pop_front();
+        }
+        history.push_back(renewal.clone());
+        env.storage().persistent().set(&history_key, &history);
+
+        FractionalizedTokenRenewed {
+            token_id,
+            price,
+            refunded,
+            new_expiry,
+            renewed_by: caller,
+        }
+        .publish(&env);
+
+        Ok(renewal)
+    }
+
+    /// The open renewal pool of a token, if anyone has contributed.
+    pub fn get_renewal_pool(env: &Env, token_id: &BytesN<32>) -> Option<RenewalPool> {
+        env.storage()
+            .persistent()
+            .get(&FractionDataKey::RenewalPool(token_id.clone()))
+    }
+
+    /// Renewals of a token its holders paid for, oldest first.
+    pub fn get_funded_renewals(env: &Env, token_id: &BytesN<32>) -> Vec<FundedRenewal> {
+        env.storage()
+            .persistent()
+            .get(&FractionDataKey::FundedRenewals(token_id.clone()))
+            .unwrap_or_else(|| Vec::new(env))
+    }
+
+    /// The token's tier and its monthly price in `payment_token`.
+    fn renewal_price(
+        env: &Env,
+        token_id: &BytesN<32>,
+        payment_token: &Address,
+    ) -> Result<(String, i128), ModuleError> {
+        let token: MembershipToken = env
+            .storage()
+            .persistent()
+            .get(&MembershipDataKey::Token(token_id.clone()))
+            .ok_or(Error::TokenNotFound)?;
+        let tier_id = token.tier_id.ok_or(FractionError::NoRenewalTier)?;
+        let tier = SubscriptionContract::get_tier(env.clone(), tier_id.clone())?;
+        let charge = MembershipTokenContract::renewal_charge(
+            env,
+            &token.user,
+            payment_token,
+            &tier_id,
+            tier.price,
+        )?;
+        Ok((tier_id, charge.total))
+    }
+
+    /// What each contributor paid into the pool in total.
+    fn contribution_totals(env: &Env, pool: &RenewalPool) -> Map<Address, i128> {
+        let mut totals = Map::new(env);
+        for contribution in pool.contributions.iter() {
+            let paid = totals.get(contribution.holder.clone()).unwrap_or(0);
+            totals.set(contribution.holder, paid + contribution.amount);
+        }
+        totals
+    }
+
+    /// Returns every open contribution, e.g. when the token is recombined
+    /// before the pool was used.
+    fn refund_renewal_pool(env: &Env, token_id: &BytesN<32>) -> Result<(), Error> {
+        let Some(pool) = Self::get_renewal_pool(env, token_id) else {
+            return Ok(());
+        };
+        let payment_client = token::Client::new(env, &pool.payment_token);
+        for (holder, paid) in Self::contribution_totals(env, &pool).iter() {
+            payment_client.transfer(&env.current_contract_address(), &holder, &paid);
+        }
+        env.storage()
+            .persistent()
+            .remove(&FractionDataKey::RenewalPool(token_id.clone()));
         Ok(())
     }
 
+    // ------------------------------------------------------------------
+    // SEP-41 style share interface
+    // ------------------------------------------------------------------
+
+    /// Share balance of `id`, or 0 when it holds none.
+    pub fn fraction_balance(env: Env, token_id: BytesN<32>, id: Address) -> Result<i128, Error> {
+        let shares = Self::get_fraction_shares(&env, &token_id)?;
+        Ok(shares.get(id).unwrap_or(0))
+    }
+
+    /// Remaining shares `spender` may move on behalf of `from`. Expired
+    /// allowances read as 0.
+    pub fn fraction_allowance(
+        env: Env,
+        token_id: BytesN<32>,
+        from: Address,
+        spender: Address,
+    ) -> Result<i128, Error> {
+        Self::get_fraction_info(&env, &token_id)?;
+        Ok(Self::live_allowance(&env, &token_id, &from, &spender))
+    }
+
+    /// Sets the shares `spender` may move on behalf of `from` until
+    /// `expiration_ledger`. An amount of 0 revokes the allowance.
+    pub fn fraction_approve(
+        env: Env,
+        token_id: BytesN<32>,
+        from: Address,
+        spender: Address,
+        amount: i128,
+        expiration_ledger: u32,
+    ) -> Result<(), Error> {
+        Self::get_fraction_info(&env, &token_id)?;
+        if amount < 0 {
+            return Err(Error::InvalidPaymentAmount);
+        }
+        if amount > 0 && expiration_ledger < env.ledger().sequence() {
+            return Err(Error::InvalidExpiryDate);
+        }
+
+        from.require_auth();
+
+        let mut allowances = Self::get_allowances(&env, &token_id);
+        let key = (from.clone(), spender.clone());
+        if amount == 0 {
+            allowances.remove(key);
+        } else {
+            allowances.set(
+                key,
+                FractionAllowance {
+                    amount,
+                    expiration_ledger,
+                },
+            );
+        }
+        Self::set_allowances(&env, &token_id, &allowances);
+
+        FractionApproved {
+            token_id,
+            from,
+            spender,
+            amount,
+            expiration_ledger,
+        }
+        .publish(&env);
+
+        Ok(())
+    }
+
+    /// Moves shares from `from` to `to` using `spender`'s allowance.
+    pub fn fraction_transfer_from(
+        env: Env,
+        token_id: BytesN<32>,
+        spender: Address,
+        from: Address,
+        to: Address,
+        amount: i128,
+    ) -> Result<(), ModuleError> {
+        let info = Self::get_fraction_info(&env, &token_id)?;
+        Self::validate_share_amount(&info, amount)?;
+
+        spender.require_auth();
+
+        let allowed = Self::live_allowance(&env, &token_id, &from, &spender);
+        if allowed < amount {
+            return Err(Error::InsufficientBalance.into());
+        }
+
+        let mut allowances = Self::get_allowances(&env, &token_id);
+        let key = (from.clone(), spender.clone());
+        let remaining = allowed - amount;
+        if remaining == 0 {
+            allowances.remove(key);
+        } else if let Some(mut allowance) = allowances.get(key.clone()) {
+            allowance.amount = remaining;
+            allowances.set(key, allowance);
+        }
+        Self::set_allowances(&env, &token_id, &allowances);
+
+        Self::move_shares(&env, &info, token_id, from, to, amount)
+    }
+
+    pub fn fraction_decimals(env: Env, token_id: BytesN<32>) -> Result<u32, Error> {
+        Self::get_fraction_info(&env, &token_id)?;
+        Ok(FRACTION_DECIMALS)
+    }
+
+    pub fn fraction_name(env: Env, token_id: BytesN<32>) -> Result<String, Error> {
+        Self::get_fraction_info(&env, &token_id)?;
+        Ok(String::from_str(&env, FRACTION_NAME))
+    }
+
+    pub fn fraction_symbol(env: Env, token_id: BytesN<32>) -> Result<String, Error> {
+        Self::get_fraction_info(&env, &token_id)?;
+        Ok(String::from_str(&env, FRACTION_SYMBOL))
+    }
+
+    fn live_allowance(env: &Env, token_id: &BytesN<32>, from: &Address, spender: &Address) -> i128 {
+        match Self::get_allowances(env, token_id).get((from.clone(), spender.clone())) {
+            Some(allowance) if allowance.expiration_ledger >= env.ledger().sequence() => {
+                allowance.amount
+            }
+            _ => 0,
+        }
+    }
+
+    fn get_allowances(
+        env: &Env,
+        token_id: &BytesN<32>,
+    ) -> Map<(Address, Address), FractionAllowance> {
+        env.storage()
+            .persistent()
+            .get(&FractionDataKey::Allowances(token_id.clone()))
+            .unwrap_or(Map::new(env))
+    }
+
+    fn set_allowances(
+        env: &Env,
+        token_id: &BytesN<32>,
+        allowances: &Map<(Address, Address), FractionAllowance>,
+    ) {
+        let key = FractionDataKey::Allowances(token_id.clone());
+        if allowances.is_empty() {
+            env.storage().persistent().remove(&key);
+        } else {
+            env.storage().persistent().set(&key, allowances);
+        }
+    }
+
     pub fn recombine_fractions(
         env: Env,
         token_id: BytesN<32>,
@@ -154,11 +957,23 @@ impl FractionalizationModule {
             .persistent()
             .get(&MembershipDataKey::Token(token_id.clone()))
             .ok_or(Error::TokenNotFound)?;
+        if token.user != holder {
+            AllowanceModule::revoke_all_allowances(&env, &token_id, &token.user);
+            SaleModule::clear_listing(&env, &token_id);
+            MembershipTokenContract::move_owner_token(&env, &token_id, &token.user, &holder);
+        }
         token.user = holder.clone();
+        Self::refund_renewal_pool(&env, &token_id)?;
 
         env.storage()
             .persistent()
             .set(&MembershipDataKey::Token(token_id.clone()), &token);
+
+        TtlModule::bump(
+            &env,
+            TtlEntityClass::Token,
+            &MembershipDataKey::Token(token_id.clone()),
+        );
         env.storage()
             .persistent()
             .remove(&FractionDataKey::FractionInfo(token_id.clone()));
@@ -168,19 +983,71 @@ impl FractionalizationModule {
         env.storage()
             .persistent()
             .remove(&FractionDataKey::PendingRewards(token_id.clone()));
-
-        env.events().publish(
-            (
-                String::from_str(&env, "Recombined"),
-                token_id,
+        env.storage()
+            .persistent()
+            .remove(&FractionDataKey::Allowances(token_id.clone()));
+        env.storage()
+            .persistent()
+            .remove(&FractionDataKey::TransferPolicy(token_id.clone()));
+        env.storage()
+            .persistent()
+            .remove(&FractionDataKey::Whitelist(token_id.clone()));
+        env.storage()
+            .persistent()
+            .remove(&FractionDataKey::TransferApprovals(token_id.clone()));
+        env.storage()
+            .persistent()
+            .remove(&FractionDataKey::Lockup(token_id.clone()));
+        env.storage()
+            .persistent()
+            .remove(&FractionDataKey::ShareLots(
+                token_id.clone(),
                 holder.clone(),
-            ),
-            env.ledger().timestamp(),
+            ));
+        Self::index_holder(&env, &holder, &token_id, false);
+        StatsModule::record_fractionalized(&env, -1);
+        OutboxModule::append(
+            &env,
+            symbol_short!("recombine"),
+            OutboxSubject::Token(token_id.clone()),
         );
 
+        TokenRecombined {
+            token_id,
+            holder: holder.clone(),
+            timestamp: env.ledger().timestamp(),
+        }
+        .publish(&env);
+
         Ok(())
     }
 
+    /// Fractionalized tokens `holder` owns shares in. Only holdings that
+    /// changed after the index was introduced are listed.
+    pub(crate) fn get_holder_tokens(env: &Env, holder: &Address) -> Vec<BytesN<32>> {
+        env.storage()
+            .persistent()
+            .get(&FractionDataKey::HolderTokens(holder.clone()))
+            .unwrap_or_else(|| Vec::new(env))
+    }
+
+    fn index_holder(env: &Env, holder: &Address, token_id: &BytesN<32>, holds: bool) {
+        let key = FractionDataKey::HolderTokens(holder.clone());
+        let mut tokens = Self::get_holder_tokens(env, holder);
+        match (tokens.first_index_of(token_id), holds) {
+            (None, true) => tokens.push_back(token_id.clone()),
+            (Some(index), false) => {
+                tokens.remove(index);
+            }
+            _ => return,
+        }
+        if tokens.is_empty() {
+            env.storage().persistent().remove(&key);
+        } else {
+            env.storage().persistent().set(&key, &tokens);
+        }
+    }
+
     pub fn get_fraction_holders(
         env: Env,
         token_id: BytesN<32>,
@@ -216,9 +1083,9 @@ impl FractionalizationModule {
         token_id: BytesN<32>,
         total_amount: i128,
     ) -> Result<DividendDistribution, Error> {
-        if total_amount <= 0 {
-            return Err(Error::InvalidPaymentAmount);
-        }
+        let total = Amount::new(total_amount)
+            .filter(|total| !total.is_zero())
+            .ok_or(Error::InvalidPaymentAmount)?;
 
         let admin: Address = env
             .storage()
@@ -236,16 +1103,14 @@ impl FractionalizationModule {
         }
 
         let mut rewards = Self::get_pending_rewards(&env, &token_id);
-        let mut distributed = 0i128;
+        let mut distributed = Amount::ZERO;
         for holder in holder_keys.iter() {
             let holder_address: Address = holder;
             let share_count = shares
                 .get(holder_address.clone())
                 .ok_or(Error::Unauthorized)?;
-            let holder_amount = total_amount
-                .checked_mul(share_count)
-                .ok_or(Error::TimestampOverflow)?
-                .checked_div(info.total_shares)
+            let holder_amount = total
+                .mul_div(share_count, info.total_shares)
                 .ok_or(Error::TimestampOverflow)?;
 
             distributed = distributed
@@ -256,21 +1121,21 @@ impl FractionalizationModule {
             rewards.set(
                 holder_address,
                 current
-                    .checked_add(holder_amount)
+                    .checked_add(holder_amount.value())
                     .ok_or(Error::TimestampOverflow)?,
             );
         }
 
-        let remainder = total_amount
+        let remainder = total
             .checked_sub(distributed)
             .ok_or(Error::TimestampOverflow)?;
-        if remainder > 0 {
+        if !remainder.is_zero() {
             let first_holder = holder_keys.get(0).ok_or(Error::Unauthorized)?;
             let current = rewards.get(first_holder.clone()).unwrap_or(0);
             rewards.set(
                 first_holder,
                 current
-                    .checked_add(remainder)
+                    .checked_add(remainder.value())
                     .ok_or(Error::TimestampOverflow)?,
             );
         }
@@ -286,14 +1151,14 @@ impl FractionalizationModule {
             distributed_at: env.ledger().timestamp(),
         };
 
-        env.events().publish(
-            (
-                String::from_str(&env, "DividendDistributed"),
-                token_id,
-                admin,
-            ),
-            (total_amount, recipients, distribution.distributed_at),
-        );
+        DividendDistributed {
+            token_id,
+            admin,
+            total_amount,
+            recipients,
+            distributed_at: distribution.distributed_at,
+        }
+        .publish(&env);
 
         Ok(distribution)
     }
@@ -314,7 +1179,10 @@ impl FractionalizationModule {
             .has(&FractionDataKey::FractionInfo(token_id.clone()))
     }
 
-    fn get_fraction_info(env: &Env, token_id: &BytesN<32>) -> Result<FractionalTokenInfo, Error> {
+    pub(crate) fn get_fraction_info(
+        env: &Env,
+        token_id: &BytesN<32>,
+    ) -> Result<FractionalTokenInfo, Error> {
         env.storage()
             .persistent()
             .get(&FractionDataKey::FractionInfo(token_id.clone()))
@@ -0,0 +1,98 @@
+use crate::errors::Error;
+use crate::events::{GuardianAdded, GuardianRemoved};
+use crate::initialization::InitializationModule;
+use crate::membership_token::MembershipTokenContract;
+use soroban_sdk::{contracttype, Address, Env, Vec};
+
+// ---------------------------------------------------------------------------
+// Storage keys
+// ---------------------------------------------------------------------------
+
+#[contracttype]
+pub enum GuardianDataKey {
+    /// Addresses holding the guardian role (instance storage).
+    Guardians,
+}
+
+// ---------------------------------------------------------------------------
+// Module
+// ---------------------------------------------------------------------------
+
+/// Incident-response guardians.
+///
+/// Guardians can trigger the global emergency pause and per-token pauses, but
+/// cannot unpause, set a pause time lock or change any configuration. They are
+/// managed by the admin or the access_control contract registered at
+/// initialization.
+pub struct GuardianModule;
+
+impl GuardianModule {
+    /// Allows the admin or the registered access_control contract.
+    fn require_manager(env: &Env, caller: &Address) -> Result<(), Error> {
+        if InitializationModule::get_access_control(env).as_ref() == Some(caller) {
+            caller.require_auth();
+            return Ok(());
+        }
+        MembershipTokenContract::require_admin(env, caller)
+    }
+
+    pub fn add_guardian(env: Env, caller: Address, guardian: Address) -> Result<(), Error> {
+        Self::require_manager(&env, &caller)?;
+
+        let mut guardians = Self::get_guardians(&env);
+        if !guardians.contains(&guardian) {
+            guardians.push_back(guardian.clone());
+            env.storage()
+                .instance()
+                .set(&GuardianDataKey::Guardians, &guardians);
+        }
+
+        GuardianAdded {
+            guardian,
+            added_by: caller,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    pub fn remove_guardian(env: Env, caller: Address, guardian: Address) -> Result<(), Error> {
+        Self::require_manager(&env, &caller)?;
+
+        let mut guardians = Self::get_guardians(&env);
+        if let Some(index) = guardians.first_index_of(&guardian) {
+            guardians.remove(index);
+            env.storage()
+                .instance()
+                .set(&GuardianDataKey::Guardians, &guardians);
+        }
+
+        GuardianRemoved {
+            guardian,
+            removed_by: caller,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    pub fn get_guardians(env: &Env) -> Vec<Address> {
+        env.storage()
+            .instance()
+            .get(&GuardianDataKey::Guardians)
+            .unwrap_or(Vec::new(env))
+    }
+
+    pub fn is_guardian(env: &Env, address: &Address) -> bool {
+        Self::get_guardians(env).contains(address)
+    }
+
+    /// Authorizes a pause initiated by the admin or a guardian. Returns `true`
+    /// when the caller acted as a guardian.
+    pub(crate) fn require_pauser(env: &Env, caller: &Address) -> Result<bool, Error> {
+        if Self::is_guardian(env, caller) {
+            caller.require_auth();
+            return Ok(true);
+        }
+        MembershipTokenContract::require_admin(env, caller)?;
+        Ok(false)
+    }
+}
@@ -10,9 +10,8 @@
 //! | `require_token_not_paused`  | `PauseError::TokenOpsPaused`    |
 //! | `require_timelock_expired`  | `PauseError::TimeLockActive`    |
 //!
-//! [`crate::pause_errors`] provides a [`From`] impl that bridges `PauseError`
-//! into `Error` so that `?` propagation works in functions returning
-//! `Result<_, Error>`.
+//! Callers return [`ModuleError`](crate::errors::ModuleError) so the pause
+//! codes propagate with `?` unchanged.
 //!
 //! ## Usage
 //!
@@ -41,8 +40,8 @@ impl PauseGuard {
     /// current ledger timestamp is at or past that value, the guard treats
     /// the contract as unpaused without requiring a storage write.
     ///
-    /// In functions returning `Result<(), Error>` the `?` operator
-    /// auto-converts via [`From<PauseError> for Error`].
+    /// In functions returning `Result<_, ModuleError>` the `?` operator
+    /// passes the code through unchanged.
     pub fn require_not_paused(env: &Env) -> Result<(), PauseError> {
         let state: Option<EmergencyPauseState> =
             env.storage().instance().get(&DataKey::EmergencyPauseState);
@@ -75,6 +74,12 @@ impl PauseGuard {
 
         if let Some(state) = state {
             if state.is_paused {
+                // Check whether the auto-unpause deadline has passed.
+                if let Some(auto_unpause_at) = state.auto_unpause_at {
+                    if env.ledger().timestamp() >= auto_unpause_at {
+                        return Ok(()); // Automatic unpause has taken effect.
+                    }
+                }
                 return Err(PauseError::TokenOpsPaused);
             }
         }
@@ -82,6 +87,26 @@ impl PauseGuard {
         Ok(())
     }
 
+    /// Returns `Err(PauseError::TimeLockActive)` if the token's pause time
+    /// lock is still active.
+    pub fn require_token_timelock_expired(
+        env: &Env,
+        token_id: &BytesN<32>,
+    ) -> Result<(), PauseError> {
+        let state: Option<TokenPauseState> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::TokenPaused(token_id.clone()));
+
+        if let Some(time_lock_until) = state.and_then(|state| state.time_lock_until) {
+            if env.ledger().timestamp() < time_lock_until {
+                return Err(PauseError::TimeLockActive);
+            }
+        }
+
+        Ok(())
+    }
+
     /// Returns `Err(PauseError::TimeLockActive)` if the emergency time lock is still active.
     ///
     /// Should be called before any admin-initiated unpause to ensure the minimum
@@ -0,0 +1,21 @@
+//! Guest-pass error types for the ManageHub contract.
+//!
+//! `GuestError` owns the code range 1000–1049 (see [`crate::errors`]).
+
+use soroban_sdk::contracterror;
+
+/// Guest-specific errors.
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum GuestError {
+    /// The subscription has used its guest visits for the current period.
+    QuotaExceeded = 1000,
+    /// The guest already holds an unexpired invite from this subscription.
+    AlreadyInvited = 1001,
+    /// The guest holds no invite from this subscription.
+    InviteNotFound = 1002,
+    /// The invite is past its `valid_until` timestamp.
+    InviteExpired = 1003,
+    /// `valid_until` is in the past or after the subscription expires.
+    InvalidInviteWindow = 1004,
+}
@@ -0,0 +1,219 @@
+use crate::attendance_log::AttendanceLogModule;
+use crate::errors::{Error, ModuleError};
+use crate::events::{GuestInvited, GuestQuotaSet};
+use crate::guest_errors::GuestError;
+use crate::membership_token::MembershipTokenContract;
+use crate::subscription::SubscriptionContract;
+use crate::types::{AttendanceAction, GuestInvite, GuestUsage, MembershipStatus, Subscription};
+use soroban_sdk::{contracttype, Address, BytesN, Env, Map, String};
+
+// ---------------------------------------------------------------------------
+// Storage keys
+// ---------------------------------------------------------------------------
+
+#[contracttype]
+pub enum GuestDataKey {
+    /// Guest visits per quota period for a tier (persistent storage).
+    TierQuota(String),
+    /// Guest visits used in the current period of a subscription
+    /// (persistent storage).
+    Usage(String),
+    /// Invite from a subscription to a guest (persistent storage).
+    Invite(String, Address),
+}
+
+// ---------------------------------------------------------------------------
+// Constants
+// ---------------------------------------------------------------------------
+
+/// Length of a guest quota period. Periods are anchored at the
+/// subscription's creation, so they line up with monthly billing cycles.
+pub const GUEST_QUOTA_PERIOD: u64 = 30 * 24 * 60 * 60;
+
+// ---------------------------------------------------------------------------
+// Module
+// ---------------------------------------------------------------------------
+
+/// Guest passes.
+///
+/// Each tier grants a number of guest visits per 30-day period. A subscriber
+/// spends one visit per invite; the invited guest can then log attendance
+/// until the invite expires, and those logs are attributed to the host.
+pub struct GuestModule;
+
+impl GuestModule {
+    pub fn set_tier_quota(
+        env: Env,
+        admin: Address,
+        tier_id: String,
+        monthly_quota: u32,
+    ) -> Result<(), Error> {
+        MembershipTokenContract::require_admin(&env, &admin)?;
+        SubscriptionContract::get_tier(env.clone(), tier_id.clone())?;
+        env.storage()
+            .persistent()
+            .set(&GuestDataKey::TierQuota(tier_id.clone()), &monthly_quota);
+
+        GuestQuotaSet {
+            tier_id,
+            monthly_quota,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    pub fn get_tier_quota(env: &Env, tier_id: &String) -> u32 {
+        env.storage()
+            .persistent()
+            .get(&GuestDataKey::TierQuota(tier_id.clone()))
+            .unwrap_or(0)
+    }
+
+    /// Invites `guest` until `valid_until`, spending one of the
+    /// subscription's guest visits for the current period. Requires the
+    /// subscriber's auth and an active subscription.
+    pub fn invite_guest(
+        env: Env,
+        subscription_id: String,
+        guest: Address,
+        valid_until: u64,
+    ) -> Result<GuestInvite, ModuleError> {
+        let subscription = Self::get_active_subscription(&env, &subscription_id)?;
+        subscription.user.require_auth();
+
+        let now = env.ledger().timestamp();
+        if valid_until <= now || valid_until > subscription.expires_at {
+            return Err(GuestError::InvalidInviteWindow.into());
+        }
+
+        let invite_key = GuestDataKey::Invite(subscription_id.clone(), guest.clone());
+        if let Some(existing) = env
+            .storage()
+            .persistent()
+            .get::<_, GuestInvite>(&invite_key)
+        {
+            if existing.valid_until >= now {
+                return Err(GuestError::AlreadyInvited.into());
+            }
+        }
+
+        let mut usage = Self::current_usage(&env, &subscription);
+        if usage.used >= usage.quota {
+            return Err(GuestError::QuotaExceeded.into());
+        }
+        usage.used += 1;
+        env.storage()
+            .persistent()
+            .set(&GuestDataKey::Usage(subscription_id.clone()), &usage);
+
+        let invite = GuestInvite {
+            subscription_id: subscription_id.clone(),
+            host: subscription.user.clone(),
+            guest: guest.clone(),
+            invited_at: now,
+            valid_until,
+            visits: 0,
+        };
+        env.storage().persistent().set(&invite_key, &invite);
+
+        GuestInvited {
+            subscription_id,
+            guest,
+            host: subscription.user,
+            valid_until,
+        }
+        .publish(&env);
+
+        Ok(invite)
+    }
+
+    /// Logs attendance for an invited guest, attributed to the host. The
+    /// guest's address is recorded in the log details.
+    pub fn log_guest_attendance(
+        env: Env,
+        id: BytesN<32>,
+        subscription_id: String,
+        guest: Address,
+        action: AttendanceAction,
+    ) -> Result<(), ModuleError> {
+        guest.require_auth();
+
+        let invite_key = GuestDataKey::Invite(subscription_id.clone(), guest.clone());
+        let mut invite: GuestInvite = env
+            .storage()
+            .persistent()
+            .get(&invite_key)
+            .ok_or(GuestError::InviteNotFound)?;
+        if env.ledger().timestamp() > invite.valid_until {
+            return Err(GuestError::InviteExpired.into());
+        }
+        Self::get_active_subscription(&env, &subscription_id)?;
+
+        let mut details = Map::new(&env);
+        details.set(String::from_str(&env, "guest"), guest.to_string());
+        // Guest visits don't earn the host loyalty points or streak days
+        AttendanceLogModule::record_attendance(
+            &env,
+            id,
+            invite.host.clone(),
+            action.clone(),
+            details,
+            false,
+        )?;
+
+        if action == AttendanceAction::ClockIn {
+            invite.visits += 1;
+            env.storage().persistent().set(&invite_key, &invite);
+        }
+        Ok(())
+    }
+
+    pub fn get_invite(env: &Env, subscription_id: &String, guest: &Address) -> Option<GuestInvite> {
+        env.storage().persistent().get(&GuestDataKey::Invite(
+            subscription_id.clone(),
+            guest.clone(),
+        ))
+    }
+
+    /// Guest visits used and remaining in the subscription's current period.
+    pub fn get_usage(env: Env, subscription_id: String) -> Result<GuestUsage, Error> {
+        let subscription = SubscriptionContract::get_subscription(env.clone(), subscription_id)?;
+        Ok(Self::current_usage(&env, &subscription))
+    }
+
+    /// Usage for the period containing now, starting a fresh period once the
+    /// stored one has ended.
+    fn current_usage(env: &Env, subscription: &Subscription) -> GuestUsage {
+        let now = env.ledger().timestamp();
+        let elapsed_periods = now.saturating_sub(subscription.created_at) / GUEST_QUOTA_PERIOD;
+        let period_start = subscription.created_at + elapsed_periods * GUEST_QUOTA_PERIOD;
+        let quota = Self::get_tier_quota(env, &subscription.tier_id);
+
+        let used = env
+            .storage()
+            .persistent()
+            .get::<_, GuestUsage>(&GuestDataKey::Usage(subscription.id.clone()))
+            .filter(|usage| usage.period_start == period_start)
+            .map(|usage| usage.used)
+            .unwrap_or(0);
+
+        GuestUsage {
+            subscription_id: subscription.id.clone(),
+            period_start,
+            period_end: period_start + GUEST_QUOTA_PERIOD,
+            used,
+            quota,
+        }
+    }
+
+    fn get_active_subscription(env: &Env, subscription_id: &String) -> Result<Subscription, Error> {
+        let subscription =
+            SubscriptionContract::get_subscription(env.clone(), subscription_id.clone())?;
+        if subscription.status != MembershipStatus::Active
+            || subscription.expires_at < env.ledger().timestamp()
+        {
+            return Err(Error::SubscriptionNotActive);
+        }
+        Ok(subscription)
+    }
+}
@@ -0,0 +1,204 @@
+use crate::errors::{Error, ModuleError};
+use crate::events::{HouseholdMemberAdded, HouseholdMemberRemoved};
+use crate::household_errors::HouseholdError;
+use crate::membership_token::MembershipTokenContract;
+use crate::subscription::SubscriptionContract;
+use crate::types::{AttendanceAction, HouseholdAttendance, MembershipStatus};
+use crate::verification::VerificationModule;
+use common_types::TierFeature;
+use soroban_sdk::{contracttype, Address, Env, String, Vec};
+
+// ---------------------------------------------------------------------------
+// Storage keys
+// ---------------------------------------------------------------------------
+
+#[contracttype]
+pub enum HouseholdDataKey {
+    /// Maximum dependents per household (instance storage).
+    MaxMembers,
+    /// Dependents linked to a primary subscription (persistent storage).
+    Members(String),
+    /// Primary subscription a dependent is linked to (persistent storage).
+    Household(Address),
+    /// Attendance attributed to a household (persistent storage).
+    Attendance(String),
+}
+
+// ---------------------------------------------------------------------------
+// Constants
+// ---------------------------------------------------------------------------
+
+/// Dependents allowed per household until the admin configures a limit.
+pub const DEFAULT_MAX_HOUSEHOLD_MEMBERS: u32 = 4;
+/// Hard cap on the configurable limit, which bounds the member list size.
+pub const MAX_HOUSEHOLD_MEMBERS_LIMIT: u32 = 10;
+
+// ---------------------------------------------------------------------------
+// Module
+// ---------------------------------------------------------------------------
+
+/// Family / household plans.
+///
+/// The owner of a primary subscription can link dependent addresses to it.
+/// Dependents get feature access through the primary's tier for as long as
+/// the primary subscription is active, and their attendance is counted for
+/// the household as well as in their own logs. An address can belong to one
+/// household at a time.
+pub struct HouseholdModule;
+
+impl HouseholdModule {
+    pub fn set_max_members(env: Env, admin: Address, max_members: u32) -> Result<(), ModuleError> {
+        MembershipTokenContract::require_admin(&env, &admin)?;
+        if max_members == 0 || max_members > MAX_HOUSEHOLD_MEMBERS_LIMIT {
+            return Err(HouseholdError::InvalidHouseholdLimit.into());
+        }
+        env.storage()
+            .instance()
+            .set(&HouseholdDataKey::MaxMembers, &max_members);
+        Ok(())
+    }
+
+    pub fn get_max_members(env: &Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&HouseholdDataKey::MaxMembers)
+            .unwrap_or(DEFAULT_MAX_HOUSEHOLD_MEMBERS)
+    }
+
+    /// Links `member` to the primary subscription. Requires the primary
+    /// subscriber's auth and an active subscription.
+    pub fn add_member(
+        env: Env,
+        subscription_id: String,
+        member: Address,
+    ) -> Result<(), ModuleError> {
+        let subscription =
+            SubscriptionContract::get_subscription(env.clone(), subscription_id.clone())?;
+        subscription.user.require_auth();
+        if subscription.status != MembershipStatus::Active {
+            return Err(Error::SubscriptionNotActive.into());
+        }
+        VerificationModule::check_household(&env, &subscription.user)?;
+        if member == subscription.user || Self::get_household(&env, &member).is_some() {
+            return Err(HouseholdError::AlreadyLinked.into());
+        }
+
+        let mut members = Self::get_members(&env, &subscription_id);
+        if members.len() >= Self::get_max_members(&env) {
+            return Err(HouseholdError::HouseholdFull.into());
+        }
+        members.push_back(member.clone());
+
+        let storage = env.storage().persistent();
+        storage.set(
+            &HouseholdDataKey::Members(subscription_id.clone()),
+            &members,
+        );
+        storage.set(
+            &HouseholdDataKey::Household(member.clone()),
+            &subscription_id,
+        );
+
+        HouseholdMemberAdded {
+            subscription_id,
+            member,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    /// Unlinks `member` from the primary subscription. Requires the primary
+    /// subscriber's auth.
+    pub fn remove_member(
+        env: Env,
+        subscription_id: String,
+        member: Address,
+    ) -> Result<(), ModuleError> {
+        let subscription =
+            SubscriptionContract::get_subscription(env.clone(), subscription_id.clone())?;
+        subscription.user.require_auth();
+
+        let mut members = Self::get_members(&env, &subscription_id);
+        let index = members
+            .first_index_of(&member)
+            .ok_or(HouseholdError::NotHouseholdMember)?;
+        members.remove(index);
+
+        let storage = env.storage().persistent();
+        storage.set(
+            &HouseholdDataKey::Members(subscription_id.clone()),
+            &members,
+        );
+        storage.remove(&HouseholdDataKey::Household(member.clone()));
+
+        HouseholdMemberRemoved {
+            subscription_id,
+            member,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    pub fn get_members(env: &Env, subscription_id: &String) -> Vec<Address> {
+        env.storage()
+            .persistent()
+            .get(&HouseholdDataKey::Members(subscription_id.clone()))
+            .unwrap_or(Vec::new(env))
+    }
+
+    /// Primary subscription `member` is a dependent of, if any.
+    pub fn get_household(env: &Env, member: &Address) -> Option<String> {
+        env.storage()
+            .persistent()
+            .get(&HouseholdDataKey::Household(member.clone()))
+    }
+
+    /// Whether a dependent has `feature` through their household's primary
+    /// subscription. Non-dependents never do.
+    pub fn check_member_feature_access(
+        env: Env,
+        member: Address,
+        feature: TierFeature,
+    ) -> Result<bool, Error> {
+        match Self::get_household(&env, &member) {
+            Some(subscription_id) => {
+                SubscriptionContract::check_feature_access(env, subscription_id, feature)
+            }
+            None => Ok(false),
+        }
+    }
+
+    // -----------------------------------------------------------------------
+    // Attendance attribution
+    // -----------------------------------------------------------------------
+
+    pub fn get_attendance(env: &Env, subscription_id: &String) -> HouseholdAttendance {
+        env.storage()
+            .persistent()
+            .get(&HouseholdDataKey::Attendance(subscription_id.clone()))
+            .unwrap_or(HouseholdAttendance {
+                total_logs: 0,
+                clock_ins: 0,
+                last_member: None,
+                last_logged_at: 0,
+            })
+    }
+
+    /// Attendance hook: counts a dependent's log towards their household.
+    pub(crate) fn on_attendance(env: &Env, user: &Address, action: &AttendanceAction, now: u64) {
+        let Some(subscription_id) = Self::get_household(env, user) else {
+            return;
+        };
+
+        let mut attendance = Self::get_attendance(env, &subscription_id);
+        attendance.total_logs = attendance.total_logs.saturating_add(1);
+        if *action == AttendanceAction::ClockIn {
+            attendance.clock_ins = attendance.clock_ins.saturating_add(1);
+        }
+        attendance.last_member = Some(user.clone());
+        attendance.last_logged_at = now;
+        env.storage()
+            .persistent()
+            .set(&HouseholdDataKey::Attendance(subscription_id), &attendance);
+    }
+}
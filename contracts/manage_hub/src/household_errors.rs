@@ -0,0 +1,19 @@
+//! Household-plan error types for the ManageHub contract.
+//!
+//! `HouseholdError` owns the code range 1050–1099 (see [`crate::errors`]).
+
+use soroban_sdk::contracterror;
+
+/// Household-specific errors.
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum HouseholdError {
+    /// The household already has the maximum number of dependents.
+    HouseholdFull = 1050,
+    /// The address is the primary or already linked to a household.
+    AlreadyLinked = 1051,
+    /// The address is not a dependent of this household.
+    NotHouseholdMember = 1052,
+    /// The household size limit must be between 1 and the hard cap.
+    InvalidHouseholdLimit = 1053,
+}
@@ -0,0 +1,13 @@
+//! Initialization-related error types for the ManageHub contract.
+//!
+//! `InitError` owns the code range 1100–1149 (see [`crate::errors`]).
+
+use soroban_sdk::contracterror;
+
+/// Initialization-specific errors.
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum InitError {
+    /// `initialize` has already run, or an admin was set through the legacy path.
+    AlreadyInitialized = 1100,
+}
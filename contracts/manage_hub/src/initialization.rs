@@ -0,0 +1,85 @@
+// Allow deprecated events API until migration to #[contractevent] macro
+#![allow(deprecated)]
+
+use crate::errors::ModuleError;
+use crate::init_errors::InitError;
+use crate::membership_token::DataKey as MembershipDataKey;
+use crate::migration::MigrationModule;
+use crate::staking::{StakingDataKey, StakingModule};
+use crate::subscription::{SubscriptionContract, SubscriptionDataKey};
+use crate::types::InitConfig;
+use soroban_sdk::{contracttype, symbol_short, Address, Env};
+
+// ---------------------------------------------------------------------------
+// Storage keys
+// ---------------------------------------------------------------------------
+
+#[contracttype]
+pub enum InitDataKey {
+    /// Set once `initialize` has completed (instance storage).
+    Initialized,
+    /// Address of the access_control contract used for multisig (instance storage).
+    AccessControl,
+}
+
+// ---------------------------------------------------------------------------
+// Module
+// ---------------------------------------------------------------------------
+
+pub struct InitializationModule;
+
+impl InitializationModule {
+    /// One-shot contract setup.
+    ///
+    /// Stores the admin, USDC contract and access_control contract, and seeds
+    /// every module configuration in `config`. All validation runs
+    /// before anything is written, so a failing call leaves storage untouched.
+    pub fn initialize(
+        env: Env,
+        admin: Address,
+        usdc: Address,
+        access_control: Address,
+        config: InitConfig,
+    ) -> Result<(), ModuleError> {
+        if Self::is_initialized(&env) || env.storage().instance().has(&MembershipDataKey::Admin) {
+            return Err(InitError::AlreadyInitialized.into());
+        }
+        admin.require_auth();
+
+        SubscriptionContract::validate_pause_config(&config.pause_config)?;
+        StakingModule::validate_config(&config.staking_config)?;
+
+        let storage = env.storage().instance();
+        storage.set(&MembershipDataKey::Admin, &admin);
+        storage.set(&SubscriptionDataKey::UsdcContract, &usdc);
+        storage.set(&InitDataKey::AccessControl, &access_control);
+
+        storage.set(&SubscriptionDataKey::PauseConfig, &config.pause_config);
+        storage.set(&MembershipDataKey::RenewalConfig, &config.renewal_config);
+        storage.set(&StakingDataKey::Config, &config.staking_config);
+        storage.set(&MembershipDataKey::UpgradeConfig, &config.upgrade_config);
+
+        storage.set(&InitDataKey::Initialized, &true);
+        MigrationModule::set_storage_version(&env);
+
+        env.events().publish(
+            (symbol_short!("init"), admin),
+            (usdc, access_control, env.ledger().timestamp()),
+        );
+
+        Ok(())
+    }
+
+    /// Returns `true` once `initialize` has completed.
+    pub fn is_initialized(env: &Env) -> bool {
+        env.storage()
+            .instance()
+            .get(&InitDataKey::Initialized)
+            .unwrap_or(false)
+    }
+
+    /// Returns the access_control contract address recorded at initialization.
+    pub fn get_access_control(env: &Env) -> Option<Address> {
+        env.storage().instance().get(&InitDataKey::AccessControl)
+    }
+}
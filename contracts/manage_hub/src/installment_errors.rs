@@ -0,0 +1,21 @@
+//! Installment-plan error types for the ManageHub contract.
+//!
+//! `InstallmentError` owns the code range 1150–1199 (see [`crate::errors`]).
+
+use soroban_sdk::contracterror;
+
+/// Installment-specific errors.
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum InstallmentError {
+    /// The number of installments is outside the supported range.
+    InvalidInstallmentCount = 1150,
+    /// No installment plan exists for this subscription.
+    PlanNotFound = 1151,
+    /// Every installment has already been paid.
+    PlanPaidOff = 1152,
+    /// The deadline for the current installment has passed.
+    InstallmentOverdue = 1153,
+    /// The plan was suspended after a missed installment.
+    PlanSuspended = 1154,
+}
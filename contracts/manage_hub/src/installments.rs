@@ -0,0 +1,327 @@
+// Allow deprecated events API until migration to #[contractevent] macro
+#![allow(deprecated)]
+
+use crate::billing::BillingModule;
+use crate::blacklist::BlacklistModule;
+use crate::errors::{Error, ModuleError};
+use crate::fees::FeeModule;
+use crate::installment_errors::InstallmentError;
+use crate::loyalty::LoyaltyModule;
+use crate::migration::MigrationModule;
+use crate::outbox::OutboxModule;
+use crate::payment_tokens::PaymentTokenModule;
+use crate::stats::StatsModule;
+use crate::subscription::{SubscriptionContract, SubscriptionDataKey};
+use crate::subscription_lifecycle::{SubscriptionEvent, SubscriptionLifecycle};
+use crate::treasury::TreasuryModule;
+use crate::ttl::{TtlEntityClass, TtlModule};
+use crate::types::{
+    BillingCycle, BillingKind, BlacklistOperation, InstallmentPlan, MembershipStatus,
+    OutboxSubject, RevenueSource, Subscription,
+};
+use soroban_sdk::{contracttype, symbol_short, Address, Env, String};
+
+// ---------------------------------------------------------------------------
+// Storage keys
+// ---------------------------------------------------------------------------
+
+#[contracttype]
+pub enum InstallmentDataKey {
+    /// Installment plan keyed by subscription ID (persistent storage).
+    Plan(String),
+    /// Fee breakdown of a plan's full annual charge, keyed by subscription
+    /// ID (persistent storage).
+    Charge(String),
+}
+
+// ---------------------------------------------------------------------------
+// Constants
+// ---------------------------------------------------------------------------
+
+const ANNUAL_DURATION: u64 = 365 * 24 * 60 * 60;
+const MIN_INSTALLMENTS: u32 = 2;
+const MAX_INSTALLMENTS: u32 = 12;
+
+// ---------------------------------------------------------------------------
+// Module
+// ---------------------------------------------------------------------------
+
+pub struct InstallmentModule;
+
+impl InstallmentModule {
+    /// Create an annual subscription whose price is split into
+    /// `installment_count` equal payments. The first installment is charged
+    /// immediately; each following one is due one period after the previous
+    /// deadline. The subscription only runs for the periods paid so far.
+    pub fn create_subscription_installments(
+        env: Env,
+        id: String,
+        user: Address,
+        payment_token: Address,
+        tier_id: String,
+        installment_count: u32,
+    ) -> Result<InstallmentPlan, ModuleError> {
+        user.require_auth();
+        BlacklistModule::require_not_blacklisted(&env, BlacklistOperation::Subscription, &user)?;
+
+        if !(MIN_INSTALLMENTS..=MAX_INSTALLMENTS).contains(&installment_count) {
+            return Err(InstallmentError::InvalidInstallmentCount.into());
+        }
+
+        let key = SubscriptionDataKey::Subscription(id.clone());
+        if env.storage().persistent().has(&key) {
+            return Err(Error::SubscriptionAlreadyExists.into());
+        }
+
+        let tier = SubscriptionContract::get_tier(env.clone(), tier_id.clone())?;
+        if !tier.is_active {
+            return Err(Error::TierNotActive.into());
+        }
+
+        // Fees are charged on the whole annual price; each installment
+        // settles its share of them
+        let usdc = FeeModule::breakdown_for(&env, &user, tier.annual_price)?;
+        let charge = FeeModule::convert(&env, &payment_token, &tier_id, &usdc)?;
+        let total_amount = charge.total;
+        let installment_amount = total_amount / installment_count as i128;
+        SubscriptionContract::validate_payment(&env, &payment_token, installment_amount, &user)?;
+
+        let now = env.ledger().timestamp();
+        let period = ANNUAL_DURATION / installment_count as u64;
+        let expires_at = now.checked_add(period).ok_or(Error::TimestampOverflow)?;
+
+        let subscription = Subscription {
+            id: id.clone(),
+            user: user.clone(),
+            payment_token: payment_token.clone(),
+            amount: total_amount,
+            status: MembershipStatus::Active,
+            created_at: now,
+            expires_at,
+            tier_id: tier_id.clone(),
+            billing_cycle: BillingCycle::Annual,
+            paused_at: None,
+            last_resumed_at: now,
+            pause_count: 0,
+            total_paused_duration: 0,
+            locked_price: tier.annual_price,
+        };
+        env.storage().persistent().set(&key, &subscription);
+        TtlModule::bump(&env, TtlEntityClass::Subscription, &key);
+        MigrationModule::mark_subscription_current(&env, &id);
+        SubscriptionContract::index_user_subscription(&env, &user, &id);
+        SubscriptionContract::index_tier_subscription(&env, &tier_id, &id);
+        StatsModule::record_subscription_status(&env, None, &MembershipStatus::Active);
+        OutboxModule::append(
+            &env,
+            symbol_short!("sub_new"),
+            OutboxSubject::Subscription(id.clone()),
+        );
+
+        let plan = InstallmentPlan {
+            subscription_id: id.clone(),
+            user: user.clone(),
+            payment_token: payment_token.clone(),
+            total_amount,
+            installment_amount,
+            installment_count,
+            paid_count: 1,
+            amount_paid: installment_amount,
+            period,
+            next_due_at: expires_at,
+            suspended: false,
+            created_at: now,
+        };
+        Self::save_plan(&env, &plan);
+        let charge_key = InstallmentDataKey::Charge(id.clone());
+        env.storage().persistent().set(&charge_key, &charge);
+        TtlModule::bump(&env, TtlEntityClass::Subscription, &charge_key);
+
+        SubscriptionContract::update_tier_analytics_on_subscribe(
+            &env,
+            &tier_id,
+            tier.annual_price,
+        )?;
+        Self::record_installment_payment(&env, &plan, installment_amount)?;
+        LoyaltyModule::set_member_tier(&env, &user, &tier_id);
+
+        env.events().publish(
+            (symbol_short!("inst_new"), id, user),
+            (tier_id, total_amount, installment_count, plan.next_due_at),
+        );
+
+        Ok(plan)
+    }
+
+    /// Pay the next installment. Must be called by the subscriber before the
+    /// current deadline, while the subscription is still active. Each
+    /// payment extends the subscription by one period; the final one runs it
+    /// to the end of the year.
+    pub fn pay_installment(
+        env: Env,
+        subscription_id: String,
+    ) -> Result<InstallmentPlan, ModuleError> {
+        let mut plan = Self::get_installment_plan(env.clone(), subscription_id.clone())?;
+        plan.user.require_auth();
+
+        if plan.suspended {
+            return Err(InstallmentError::PlanSuspended.into());
+        }
+        if plan.paid_count >= plan.installment_count {
+            return Err(InstallmentError::PlanPaidOff.into());
+        }
+        let now = env.ledger().timestamp();
+        if now > plan.next_due_at {
+            return Err(InstallmentError::InstallmentOverdue.into());
+        }
+
+        let key = SubscriptionDataKey::Subscription(subscription_id.clone());
+        let mut subscription =
+            SubscriptionContract::get_subscription(env.clone(), subscription_id.clone())?;
+        if subscription.status != MembershipStatus::Active || subscription.expires_at < now {
+            return Err(Error::SubscriptionNotActive.into());
+        }
+
+        // The final installment absorbs any rounding remainder.
+        let amount = if plan.paid_count + 1 == plan.installment_count {
+            plan.total_amount - plan.amount_paid
+        } else {
+            plan.installment_amount
+        };
+        SubscriptionContract::validate_payment(&env, &plan.payment_token, amount, &plan.user)?;
+
+        // The final period absorbs the seconds lost to integer division.
+        let extension = if plan.paid_count + 1 == plan.installment_count {
+            ANNUAL_DURATION - plan.period * (plan.installment_count - 1) as u64
+        } else {
+            plan.period
+        };
+        subscription.expires_at = subscription
+            .expires_at
+            .checked_add(extension)
+            .ok_or(Error::TimestampOverflow)?;
+        env.storage().persistent().set(&key, &subscription);
+        TtlModule::bump(&env, TtlEntityClass::Subscription, &key);
+
+        plan.paid_count += 1;
+        plan.amount_paid += amount;
+        plan.next_due_at = if plan.paid_count == plan.installment_count {
+            0
+        } else {
+            plan.next_due_at
+                .checked_add(plan.period)
+                .ok_or(Error::TimestampOverflow)?
+        };
+        Self::save_plan(&env, &plan);
+        Self::record_installment_payment(&env, &plan, amount)?;
+
+        env.events().publish(
+            (
+                symbol_short!("inst_paid"),
+                subscription_id,
+                plan.user.clone(),
+            ),
+            (amount, plan.paid_count, plan.next_due_at),
+        );
+
+        Ok(plan)
+    }
+
+    /// Suspend the subscription if its current installment deadline has
+    /// passed. Callable by anyone (e.g. a keeper). Returns `true` if the plan
+    /// was suspended by this call.
+    pub fn enforce_installment_deadline(
+        env: Env,
+        subscription_id: String,
+    ) -> Result<bool, ModuleError> {
+        let mut plan = Self::get_installment_plan(env.clone(), subscription_id.clone())?;
+
+        if plan.suspended
+            || plan.paid_count >= plan.installment_count
+            || env.ledger().timestamp() <= plan.next_due_at
+        {
+            return Ok(false);
+        }
+
+        plan.suspended = true;
+        Self::save_plan(&env, &plan);
+
+        let key = SubscriptionDataKey::Subscription(subscription_id.clone());
+        let mut subscription =
+            SubscriptionContract::get_subscription(env.clone(), subscription_id.clone())?;
+        let old_status =
+            SubscriptionLifecycle::transition(&mut subscription, SubscriptionEvent::Lapse)?;
+        let was_active = matches!(
+            old_status,
+            MembershipStatus::Active | MembershipStatus::Paused
+        );
+        env.storage().persistent().set(&key, &subscription);
+        StatsModule::record_subscription_status(&env, Some(&old_status), &subscription.status);
+        OutboxModule::append(
+            &env,
+            symbol_short!("sub_lapse"),
+            OutboxSubject::Subscription(subscription_id.clone()),
+        );
+        if was_active {
+            SubscriptionContract::record_tier_churn(&env, &subscription);
+        }
+        SubscriptionContract::unindex_tier_subscription(
+            &env,
+            &subscription.tier_id,
+            &subscription_id,
+        );
+
+        env.events().publish(
+            (symbol_short!("inst_miss"), subscription_id, plan.user),
+            (plan.paid_count, plan.next_due_at),
+        );
+
+        Ok(true)
+    }
+
+    pub fn get_installment_plan(
+        env: Env,
+        subscription_id: String,
+    ) -> Result<InstallmentPlan, ModuleError> {
+        env.storage()
+            .persistent()
+            .get(&InstallmentDataKey::Plan(subscription_id))
+            .ok_or_else(|| InstallmentError::PlanNotFound.into())
+    }
+
+    fn save_plan(env: &Env, plan: &InstallmentPlan) {
+        let key = InstallmentDataKey::Plan(plan.subscription_id.clone());
+        env.storage().persistent().set(&key, plan);
+        TtlModule::bump(env, TtlEntityClass::Subscription, &key);
+    }
+
+    /// Books an installment of `amount`, which `plan.amount_paid` already
+    /// includes. Plans created before fees applied to installments have no
+    /// stored charge and settle no fees.
+    fn record_installment_payment(
+        env: &Env,
+        plan: &InstallmentPlan,
+        amount: i128,
+    ) -> Result<(), ModuleError> {
+        let charge = env
+            .storage()
+            .persistent()
+            .get(&InstallmentDataKey::Charge(plan.subscription_id.clone()))
+            .unwrap_or_else(|| FeeModule::without_fees(plan.total_amount));
+        let settled =
+            FeeModule::settled_between(&charge, plan.amount_paid - amount, plan.amount_paid)?;
+        let base_amount =
+            PaymentTokenModule::convert_to_base(env, &plan.payment_token, settled.base)?;
+        TreasuryModule::record_revenue(env, RevenueSource::Subscription, base_amount)?;
+        BillingModule::record(
+            env,
+            &plan.subscription_id,
+            &plan.user,
+            BillingKind::Installment,
+            &plan.payment_token,
+            &settled,
+            None,
+        );
+        Ok(())
+    }
+}
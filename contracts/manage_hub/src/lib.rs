@@ -59,47 +59,171 @@
 //! // and will be executable only after time_lock_duration
 //! ```
 //!
+//! WASM upgrades of this contract are scheduled with `schedule_contract_upgrade`.
+//! Once an access_control contract is registered, only it may schedule them, so
+//! the hash must clear its multisig flow first; the upgrade then waits out its
+//! own time lock before `execute_contract_upgrade` swaps the code.
+//!
 use soroban_sdk::{contract, contractimpl, vec, Address, BytesN, Env, Map, String, Vec};
 
+mod achievement_errors;
+mod achievements;
+mod admin_errors;
 mod allowance;
+mod attendance_errors;
+mod attendance_hooks;
 mod attendance_log;
 mod batch;
+mod billing;
+mod billing_errors;
+mod blacklist;
+mod blacklist_errors;
+mod booking_errors;
+mod bookings;
+mod bundles;
+mod config_errors;
+mod contract_upgrade;
+mod dispute_errors;
+mod disputes;
+mod entry;
+mod entry_errors;
 mod errors;
+mod events;
+mod factory;
+mod factory_errors;
+mod federation;
+mod federation_errors;
+mod fees;
+mod fraction_errors;
 mod fractionalization;
+mod guardians;
 mod guards;
+mod guest_errors;
+mod guests;
+mod household;
+mod household_errors;
+mod init_errors;
+mod initialization;
+mod installment_errors;
+mod installments;
+mod lifecycle_errors;
+mod loyalty;
+mod loyalty_errors;
+mod membership_sync;
 mod membership_token;
+mod metadata_schema;
 mod migration;
+mod multiread;
+mod notifications;
+mod operators;
+mod oracle;
+mod oracle_errors;
+mod outbox;
 mod pause_errors;
+mod pause_schedule_errors;
+mod payment_tokens;
+mod resource_errors;
+mod resources;
+mod retention;
+mod retention_errors;
 mod rewards;
 pub mod royalty;
+mod sale_errors;
+mod sales;
+mod snapshot;
+mod staff;
+mod staff_errors;
 mod staking;
 mod staking_errors;
+mod stats;
 mod subscription;
+mod subscription_lifecycle;
+mod tier_advisor;
+mod tier_change_errors;
+mod treasury;
+mod treasury_errors;
+mod ttl;
 mod types;
 mod upgrade;
 mod upgrade_errors;
 mod validation;
+mod verification;
+mod verification_errors;
 
+use achievements::AchievementModule;
+use attendance_hooks::AttendanceHookModule;
 use attendance_log::{AttendanceLog, AttendanceLogModule};
 use batch::BatchModule;
+use billing::BillingModule;
+use blacklist::BlacklistModule;
+use bookings::BookingModule;
+use bundles::BundleModule;
 use common_types::{
     AttendanceFrequency, DateRange, DayPattern, MetadataUpdate, MetadataValue, PeakHourData,
     TimePeriod, TokenMetadata, UserAttendanceStats,
 };
-use errors::Error;
+use contract_upgrade::ContractUpgradeModule;
+use disputes::DisputeModule;
+use entry::EntryModule;
+use errors::{Error, ModuleError};
+use factory::FactoryModule;
+use federation::FederationModule;
+use fees::FeeModule;
 use fractionalization::FractionalizationModule;
+use guardians::GuardianModule;
+use guests::GuestModule;
+use household::HouseholdModule;
+use initialization::InitializationModule;
+use installments::InstallmentModule;
+use loyalty::LoyaltyModule;
 use membership_token::{MembershipToken, MembershipTokenContract};
+use metadata_schema::MetadataSchemaModule;
+use migration::MigrationModule;
+use multiread::{MultireadModule, ReadRequest, ReadResponse};
+use notifications::NotificationModule;
+use operators::OperatorModule;
+use oracle::OracleModule;
+use outbox::OutboxModule;
+use payment_tokens::PaymentTokenModule;
+use resources::ResourceModule;
+use retention::RetentionModule;
+use sales::SaleModule;
+use snapshot::{MemberSnapshot, SnapshotModule};
+use staff::StaffModule;
 use staking::StakingModule;
+use stats::StatsModule;
 use subscription::SubscriptionContract;
+use tier_advisor::TierAdvisorModule;
+use treasury::TreasuryModule;
+use ttl::TtlModule;
 use types::{
-    AttendanceAction, AttendanceSummary, BatchMintParams, BatchTransferParams, BatchUpdateParams,
-    BatchUpgradeResult, BillingCycle, CreatePromotionParams, CreateTierParams,
-    DividendDistribution, EmergencyPauseState, FractionHolder, MembershipStatus, PauseConfig,
-    PauseHistoryEntry, PauseStats, StakeInfo, StakingConfig, StakingTier, Subscription,
-    SubscriptionTier, TierAnalytics, TierFeature, TierPromotion, TokenAllowance, UpdateTierParams,
-    UpgradeConfig, UpgradeRecord, UserSubscriptionInfo,
+    AcceptedPaymentToken, ArchivedAttendanceSummary, AttendanceAction, AttendanceDetailRules,
+    AttendanceRateLimit, AttendanceSummary, Badge, BadgeDefinition, BatchMintParams,
+    BatchPauseResult, BatchTransferParams, BatchUpdateParams, BatchUpgradeResult, BillingCycle,
+    BillingRecord, BlacklistOperation, Booking, BookingUsage, BundleEntitlements,
+    CancellationReason, CancellationRecord, ContractStats, CreateBundleParams,
+    CreatePromotionParams, CreateSessionParams, CreateTierParams, DeployHubParams, Dispute,
+    DisputeOutcome, DisputeSubject, DividendDistribution, EmergencyPauseState, EntryDecision,
+    EntryPass, EntryPolicy, FeatureOverride, FeeBreakdown, FeeConfig, FractionHolder,
+    FractionTransferPolicy, FundedRenewal, GlobalPauseRecord, GracePolicy, GuestInvite, GuestUsage,
+    HouseholdAttendance, HubRecord, InitConfig, InstallmentPlan, Invoice, LoyaltyAccount,
+    LoyaltyEarnRate, LoyaltyReward, MatchMode, MembershipStatus, MetadataFieldRule, MetadataSchema,
+    MigrationEntity, MigrationProgress, Notification, OperationalMetrics, OperatorActivity,
+    OracleConfig, OutboxRecord, PartnerHub, PartnerUsage, PauseConfig, PauseHistoryEntry,
+    PauseStats, PayrollPayment, PendingAdminTransfer, PendingRenewalConsent, PendingUsdcChange,
+    PromotionAnalytics, PrunedMetadataHistory, RegisterResourceParams, RenewalConsent, RenewalPool,
+    RenewalWindow, Reservation, Resource, RevenueBreakdown, ScheduledContractUpgrade,
+    ScheduledPause, ScheduledTokenUpgrade, Session, SlashRecord, SnapshotRetentionConfig,
+    StaffMember, StaffShift, StakeInfo, StakingConfig, StakingTier, StreakBonus, Subscription,
+    SubscriptionBundle, SubscriptionTier, TierAnalytics, TierChangeQuote, TierChangeRequest,
+    TierCohort, TierComparison, TierDeprecation, TierFeature, TierPromotion, TierRecommendation,
+    TierRevenuePoint, TokenAllowance, TokenPauseRecord, TokenUpgradeChanges, TokenVersionSnapshot,
+    TtlEntityKey, TtlPolicy, UnstakeRequest, UpdateTierParams, UpgradeConfig, UpgradeRecord,
+    UpgradeTarget, UserSubscriptionInfo, VerificationPolicy, VerificationRecord, WinbackConfig,
+    WinbackOffer,
 };
 use upgrade::UpgradeModule;
+use verification::VerificationModule;
 
 #[contract]
 pub struct Contract;
@@ -110,13 +234,51 @@ impl Contract {
         vec![&env, String::from_str(&env, "Hello"), to]
     }
 
+    // ============================================================================
+    // Contract Initialization Endpoints
+    // ============================================================================
+
+    /// One-time contract setup. Stores the admin, USDC and access_control
+    /// addresses and seeds every module configuration atomically.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `admin` - Initial admin address (must be authorized)
+    /// * `usdc` - USDC token contract used for payments
+    /// * `access_control` - access_control contract used for multisig operations
+    /// * `default_configs` - Pause, renewal, staking and upgrade configs
+    ///
+    /// # Errors
+    /// * `InitError::AlreadyInitialized` - The contract was already initialized or an admin is already set
+    /// * `InvalidPauseConfig` - The supplied pause configuration is invalid
+    /// * `StakingError::InvalidConfig` - The staking penalty exceeds 100 %
+    pub fn initialize(
+        env: Env,
+        admin: Address,
+        usdc: Address,
+        access_control: Address,
+        default_configs: InitConfig,
+    ) -> Result<(), ModuleError> {
+        InitializationModule::initialize(env, admin, usdc, access_control, default_configs)
+    }
+
+    /// Returns `true` once `initialize` has completed.
+    pub fn is_initialized(env: Env) -> bool {
+        InitializationModule::is_initialized(&env)
+    }
+
+    /// Returns the access_control contract recorded at initialization, if any.
+    pub fn get_access_control_contract(env: Env) -> Option<Address> {
+        InitializationModule::get_access_control(&env)
+    }
+
     /// Mints multiple tokens in a single transaction.
-    pub fn batch_mint(env: Env, params: Vec<BatchMintParams>) -> Result<(), Error> {
+    pub fn batch_mint(env: Env, params: Vec<BatchMintParams>) -> Result<(), ModuleError> {
         BatchModule::batch_mint(env, params)
     }
 
     /// Transfers multiple tokens in a single transaction.
-    pub fn batch_transfer(env: Env, params: Vec<BatchTransferParams>) -> Result<(), Error> {
+    pub fn batch_transfer(env: Env, params: Vec<BatchTransferParams>) -> Result<(), ModuleError> {
         BatchModule::batch_transfer(env, params)
     }
 
@@ -130,12 +292,12 @@ impl Contract {
         id: BytesN<32>,
         user: Address,
         expiry_date: u64,
-    ) -> Result<(), Error> {
+    ) -> Result<(), ModuleError> {
         MembershipTokenContract::issue_token(env, id, user, expiry_date)?;
         Ok(())
     }
 
-    pub fn transfer_token(env: Env, id: BytesN<32>, new_user: Address) -> Result<(), Error> {
+    pub fn transfer_token(env: Env, id: BytesN<32>, new_user: Address) -> Result<(), ModuleError> {
         MembershipTokenContract::transfer_token(env, id, new_user)?;
         Ok(())
     }
@@ -146,7 +308,7 @@ impl Contract {
         new_user: Address,
         payment_token: Address,
         sale_price: i128,
-    ) -> Result<(), Error> {
+    ) -> Result<(), ModuleError> {
         MembershipTokenContract::transfer_token_with_royalty(
             env,
             id,
@@ -170,13 +332,108 @@ impl Contract {
         crate::royalty::RoyaltyModule::get_royalty_info(env, token_id)
     }
 
+    /// Sets the hub fee charged on `transfer_from` resales, in basis points of
+    /// the allowance amount used and never less than `min_fee`. The spender
+    /// pays it in USDC to `recipient`.
+    ///
+    /// # Errors
+    /// * `AdminNotSet` - No admin configured
+    /// * `Unauthorized` - Caller is not the admin
+    /// * `InvalidPaymentAmount` - `fee_bps` is zero or above 10,000, or
+    ///   `min_fee` is negative
+    pub fn set_transfer_fee(
+        env: Env,
+        admin: Address,
+        fee_bps: u32,
+        min_fee: i128,
+        recipient: Address,
+    ) -> Result<(), Error> {
+        crate::royalty::RoyaltyModule::set_transfer_fee(env, admin, fee_bps, min_fee, recipient)
+    }
+
+    /// Removes the `transfer_from` fee.
+    pub fn clear_transfer_fee(env: Env, admin: Address) -> Result<(), Error> {
+        crate::royalty::RoyaltyModule::clear_transfer_fee(env, admin)
+    }
+
+    pub fn get_transfer_fee(env: Env) -> Option<types::TransferFeeConfig> {
+        crate::royalty::RoyaltyModule::get_transfer_fee(&env)
+    }
+
+    /// Exempts an address (as owner or spender) from the transfer fee, or
+    /// lifts the exemption. The admin is always exempt.
+    pub fn set_transfer_fee_exempt(
+        env: Env,
+        admin: Address,
+        account: Address,
+        exempt: bool,
+    ) -> Result<(), Error> {
+        crate::royalty::RoyaltyModule::set_transfer_fee_exempt(env, admin, account, exempt)
+    }
+
+    pub fn is_transfer_fee_exempt(env: Env, account: Address) -> bool {
+        crate::royalty::RoyaltyModule::is_transfer_fee_exempt(&env, &account)
+    }
+
+    /// Lists a token for sale at `price` USDC. Requires the owner's auth.
+    ///
+    /// # Errors
+    /// * `InvalidPaymentAmount` - Non-positive price
+    /// * `TokenNotFound` - Token doesn't exist
+    /// * `TokenExpired` / `TransferNotAllowedInGracePeriod` - Token not active
+    /// * `SaleError::AlreadyListed` - Token already listed by its owner
+    /// * `SaleError::TooManyListings` - The seller has too many active listings
+    /// * `SaleError::ListingCapReached` - The contract has too many active listings
+    /// * `PauseError::ContractPaused` / `PauseError::TokenOpsPaused` - Token operations paused
+    pub fn list_token_for_sale(
+        env: Env,
+        token_id: BytesN<32>,
+        price: i128,
+    ) -> Result<(), ModuleError> {
+        SaleModule::list_token(env, token_id, price)
+    }
+
+    /// Withdraws a sale listing.
+    ///
+    /// # Errors
+    /// * `SaleError::SaleNotFound` - Token isn't listed
+    pub fn cancel_sale(env: Env, token_id: BytesN<32>) -> Result<(), ModuleError> {
+        SaleModule::cancel_sale(env, token_id)
+    }
+
+    /// Buys a listed token. The buyer pays the asking price in USDC: the
+    /// token's royalties go to its royalty recipients and the rest to the
+    /// seller, and ownership moves to the buyer in the same call.
+    ///
+    /// # Errors
+    /// * `SaleError::SaleNotFound` - Token isn't listed
+    /// * `SaleError::ListingStale` - Seller no longer owns the token
+    /// * `Unauthorized` - Buyer is the seller
+    /// * `UsdcContractNotSet` - USDC contract not configured
+    /// * `PauseError::ContractPaused` / `PauseError::TokenOpsPaused` - Token operations paused
+    pub fn buy_token(env: Env, token_id: BytesN<32>, buyer: Address) -> Result<(), ModuleError> {
+        SaleModule::buy_token(env, token_id, buyer)
+    }
+
+    /// Returns a page of listings whose seller still owns the token, in
+    /// listing order. Stale listings are skipped, so a page can hold fewer
+    /// than `limit` sales; advance `offset` by `limit` to read the next one.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `offset` - Index of the first listing to read
+    /// * `limit` - Maximum listings to read (capped at 50)
+    pub fn get_active_sales(env: Env, offset: u32, limit: u32) -> Vec<types::TokenSale> {
+        SaleModule::get_active_sales(&env, offset, limit)
+    }
+
     pub fn approve(
         env: Env,
         token_id: BytesN<32>,
         spender: Address,
         amount: i128,
         expires_at: Option<u64>,
-    ) -> Result<(), Error> {
+    ) -> Result<(), ModuleError> {
         MembershipTokenContract::approve(env, token_id, spender, amount, expires_at)?;
         Ok(())
     }
@@ -188,15 +445,62 @@ impl Contract {
         to: Address,
         spender: Address,
         allowance_amount: i128,
-    ) -> Result<(), Error> {
+    ) -> Result<(), ModuleError> {
         MembershipTokenContract::transfer_from(env, token_id, owner, to, spender, allowance_amount)
     }
 
-    pub fn revoke_allowance(env: Env, token_id: BytesN<32>, spender: Address) -> Result<(), Error> {
+    pub fn revoke_allowance(
+        env: Env,
+        token_id: BytesN<32>,
+        spender: Address,
+    ) -> Result<(), ModuleError> {
         MembershipTokenContract::revoke_allowance(env, token_id, spender)?;
         Ok(())
     }
 
+    /// Revokes every allowance on a token. Requires the owner's auth.
+    /// Allowances are also cleared automatically when the token changes hands.
+    pub fn revoke_all_allowances(env: Env, token_id: BytesN<32>) -> Result<u32, ModuleError> {
+        MembershipTokenContract::revoke_all_allowances(env, token_id)
+    }
+
+    /// Returns every active allowance granted by the token's current owner.
+    pub fn get_all_allowances(
+        env: Env,
+        token_id: BytesN<32>,
+    ) -> Result<Vec<TokenAllowance>, Error> {
+        MembershipTokenContract::get_all_allowances(env, token_id)
+    }
+
+    /// Approves or revokes an operator for every token the owner holds. An
+    /// approved operator can transfer, set metadata on and renew them.
+    pub fn set_approval_for_all(
+        env: Env,
+        owner: Address,
+        operator: Address,
+        approved: bool,
+    ) -> Result<(), ModuleError> {
+        MembershipTokenContract::set_approval_for_all(env, owner, operator, approved)
+    }
+
+    pub fn is_approved_for_all(env: Env, owner: Address, operator: Address) -> bool {
+        MembershipTokenContract::is_approved_for_all(env, owner, operator)
+    }
+
+    /// Transfers a token on the owner's behalf.
+    ///
+    /// # Errors
+    /// * `Unauthorized` - `operator` is neither the owner nor an approved operator
+    /// * `PauseError::ContractPaused` / `PauseError::TokenOpsPaused` - Token operations paused
+    pub fn operator_transfer_token(
+        env: Env,
+        operator: Address,
+        id: BytesN<32>,
+        new_user: Address,
+    ) -> Result<(), ModuleError> {
+        MembershipTokenContract::operator_transfer_token(env, operator, id, new_user)
+    }
+
     pub fn get_allowance(
         env: Env,
         token_id: BytesN<32>,
@@ -211,20 +515,105 @@ impl Contract {
         token_id: BytesN<32>,
         total_shares: i128,
         min_fraction_size: i128,
-    ) -> Result<(), Error> {
+    ) -> Result<(), ModuleError> {
         FractionalizationModule::fractionalize_token(env, token_id, total_shares, min_fraction_size)
     }
 
+    /// Fractionalizes a token with a minimum holding period: shares a
+    /// holder receives cannot be transferred on for `lockup_period` seconds
+    /// (at most one year). Each receipt is locked separately.
+    pub fn fractionalize_token_with_lockup(
+        env: Env,
+        token_id: BytesN<32>,
+        total_shares: i128,
+        min_fraction_size: i128,
+        lockup_period: u64,
+    ) -> Result<(), ModuleError> {
+        FractionalizationModule::fractionalize_token_with_lockup(
+            env,
+            token_id,
+            total_shares,
+            min_fraction_size,
+            lockup_period,
+        )
+    }
+
+    /// Gets how long received shares of a token stay locked; 0 without a
+    /// lockup.
+    pub fn get_fraction_lockup(env: Env, token_id: BytesN<32>) -> u64 {
+        FractionalizationModule::get_lockup_period(&env, &token_id)
+    }
+
+    /// Gets the shares `holder` received too recently to transfer.
+    pub fn get_locked_shares(env: Env, token_id: BytesN<32>, holder: Address) -> i128 {
+        FractionalizationModule::get_locked_shares(env, token_id, holder)
+    }
+
     pub fn transfer_fraction(
         env: Env,
         token_id: BytesN<32>,
         from: Address,
         to: Address,
         share_amount: i128,
-    ) -> Result<(), Error> {
+    ) -> Result<(), ModuleError> {
         FractionalizationModule::transfer_fraction(env, token_id, from, to, share_amount)
     }
 
+    /// Sets who may receive shares of a fractionalized token: anyone
+    /// (`Open`, the default), addresses on the token's holder whitelist, or
+    /// recipients the token's owner approves transfer by transfer. Admin
+    /// only.
+    pub fn set_fraction_transfer_policy(
+        env: Env,
+        admin: Address,
+        token_id: BytesN<32>,
+        policy: FractionTransferPolicy,
+    ) -> Result<(), Error> {
+        FractionalizationModule::set_transfer_policy(env, admin, token_id, policy)
+    }
+
+    pub fn get_fraction_transfer_policy(env: Env, token_id: BytesN<32>) -> FractionTransferPolicy {
+        FractionalizationModule::get_transfer_policy(&env, &token_id)
+    }
+
+    /// Adds `holder` to, or removes it from, a token's holder whitelist.
+    /// Admin only.
+    pub fn set_fraction_whitelisted(
+        env: Env,
+        admin: Address,
+        token_id: BytesN<32>,
+        holder: Address,
+        allowed: bool,
+    ) -> Result<(), ModuleError> {
+        FractionalizationModule::set_whitelisted(env, admin, token_id, holder, allowed)
+    }
+
+    pub fn get_fraction_whitelist(env: Env, token_id: BytesN<32>) -> Vec<Address> {
+        FractionalizationModule::get_whitelist(&env, &token_id)
+    }
+
+    /// Approves moving up to `share_amount` shares from `from` to `to`
+    /// under the `OwnerApproved` policy. The token's owner must authorize;
+    /// 0 revokes the approval.
+    pub fn approve_fraction_transfer(
+        env: Env,
+        token_id: BytesN<32>,
+        from: Address,
+        to: Address,
+        share_amount: i128,
+    ) -> Result<(), Error> {
+        FractionalizationModule::approve_transfer(env, token_id, from, to, share_amount)
+    }
+
+    pub fn get_fraction_transfer_approval(
+        env: Env,
+        token_id: BytesN<32>,
+        from: Address,
+        to: Address,
+    ) -> i128 {
+        FractionalizationModule::get_transfer_approval(&env, &token_id, &from, &to)
+    }
+
     pub fn recombine_fractions(
         env: Env,
         token_id: BytesN<32>,
@@ -256,1078 +645,4587 @@ impl Contract {
         FractionalizationModule::get_pending_fraction_reward(env, token_id, holder)
     }
 
-    pub fn get_token(env: Env, id: BytesN<32>) -> Result<MembershipToken, Error> {
-        MembershipTokenContract::get_token(env, id)
+    /// Pays `amount` USDC from a share holder toward the next monthly
+    /// renewal of a fractionalized token.
+    ///
+    /// # Errors
+    /// * `TokenNotFound` - Token is not fractionalized
+    /// * `Unauthorized` - `holder` holds no shares
+    /// * `FractionError::NoRenewalTier` - Token has no tier to renew at
+    /// * `FractionError::TooManyContributions` - Pool is at its contribution limit
+    pub fn contribute_renewal(
+        env: Env,
+        token_id: BytesN<32>,
+        holder: Address,
+        amount: i128,
+    ) -> Result<RenewalPool, ModuleError> {
+        FractionalizationModule::contribute_renewal(env, token_id, holder, amount)
     }
 
-    pub fn set_admin(env: Env, admin: Address) -> Result<(), Error> {
-        MembershipTokenContract::set_admin(env, admin)?;
-        Ok(())
+    /// Refunds everything `holder` paid into a token's renewal pool.
+    pub fn withdraw_renewal_contribution(
+        env: Env,
+        token_id: BytesN<32>,
+        holder: Address,
+    ) -> Result<i128, ModuleError> {
+        FractionalizationModule::withdraw_renewal_contribution(env, token_id, holder)
     }
 
-    pub fn log_attendance(
+    /// Renews a fractionalized token from its holders' pooled USDC, which
+    /// must cover the tier price plus the owner's fees, and refunds any excess. Anyone may call it once the pool is funded.
+    ///
+    /// # Errors
+    /// * `FractionError::RenewalUnderfunded` - Pool is below the renewal price
+    /// * `RenewalNotAllowed` - Renewals are disabled
+    pub fn renew_fractionalized_token(
         env: Env,
-        id: BytesN<32>,
-        user_id: Address,
-        action: AttendanceAction,
-        details: soroban_sdk::Map<String, String>,
-    ) -> Result<(), Error> {
-        AttendanceLogModule::log_attendance(env, id, user_id, action, details)
+        token_id: BytesN<32>,
+        caller: Address,
+    ) -> Result<FundedRenewal, ModuleError> {
+        FractionalizationModule::renew_fractionalized_token(env, token_id, caller)
     }
 
-    pub fn get_logs_for_user(env: Env, user_id: Address) -> Vec<AttendanceLog> {
-        AttendanceLogModule::get_logs_for_user(env, user_id)
+    pub fn get_renewal_pool(env: Env, token_id: BytesN<32>) -> Option<RenewalPool> {
+        FractionalizationModule::get_renewal_pool(&env, &token_id)
     }
 
-    pub fn get_attendance_log(env: Env, id: BytesN<32>) -> Option<AttendanceLog> {
-        AttendanceLogModule::get_attendance_log(env, id)
+    pub fn get_funded_renewals(env: Env, token_id: BytesN<32>) -> Vec<FundedRenewal> {
+        FractionalizationModule::get_funded_renewals(&env, &token_id)
     }
 
-    pub fn create_subscription(
-        env: Env,
-        id: String,
-        user: Address,
-        payment_token: Address,
-        amount: i128,
-        duration: u64,
-    ) -> Result<(), Error> {
-        SubscriptionContract::create_subscription(env, id, user, payment_token, amount, duration)
+    // ============================================================================
+    // Fraction Token Interface (SEP-41 style)
+    // ============================================================================
+    //
+    // Mirrors the SEP-41 token interface for each fractionalized token so
+    // wallets and DEX tooling can treat shares as a standard asset. Every
+    // call is scoped by the membership `token_id`.
+
+    /// Returns the share balance of `id`.
+    ///
+    /// # Errors
+    /// * `TokenNotFound` - Token is not fractionalized
+    pub fn fraction_balance(env: Env, token_id: BytesN<32>, id: Address) -> Result<i128, Error> {
+        FractionalizationModule::fraction_balance(env, token_id, id)
     }
 
-    pub fn renew_subscription(
+    /// Transfers shares from `from` to `to`. Equivalent to `transfer_fraction`.
+    ///
+    /// # Errors
+    /// * `TokenNotFound` - Token is not fractionalized
+    /// * `InvalidPaymentAmount` - Amount breaks the minimum fraction size
+    /// * `InsufficientBalance` - `from` holds too few shares
+    pub fn fraction_transfer(
         env: Env,
-        id: String,
-        payment_token: Address,
+        token_id: BytesN<32>,
+        from: Address,
+        to: Address,
         amount: i128,
-        duration: u64,
-    ) -> Result<(), Error> {
-        SubscriptionContract::renew_subscription(env, id, payment_token, amount, duration)
-    }
-
-    pub fn get_subscription(env: Env, id: String) -> Result<Subscription, Error> {
-        SubscriptionContract::get_subscription(env, id)
-    }
-
-    pub fn cancel_subscription(env: Env, id: String) -> Result<(), Error> {
-        SubscriptionContract::cancel_subscription(env, id)
+    ) -> Result<(), ModuleError> {
+        FractionalizationModule::transfer_fraction(env, token_id, from, to, amount)
     }
 
-    pub fn pause_subscription(env: Env, id: String, reason: Option<String>) -> Result<(), Error> {
-        SubscriptionContract::pause_subscription(env, id, reason)
+    /// Transfers shares from `from` to `to` against `spender`'s allowance.
+    ///
+    /// # Errors
+    /// * `TokenNotFound` - Token is not fractionalized
+    /// * `InvalidPaymentAmount` - Amount breaks the minimum fraction size
+    /// * `InsufficientBalance` - Allowance or balance is too low
+    pub fn fraction_transfer_from(
+        env: Env,
+        token_id: BytesN<32>,
+        spender: Address,
+        from: Address,
+        to: Address,
+        amount: i128,
+    ) -> Result<(), ModuleError> {
+        FractionalizationModule::fraction_transfer_from(env, token_id, spender, from, to, amount)
     }
 
-    pub fn resume_subscription(env: Env, id: String) -> Result<(), Error> {
-        SubscriptionContract::resume_subscription(env, id)
+    /// Returns the shares `spender` may still move on behalf of `from`.
+    ///
+    /// # Errors
+    /// * `TokenNotFound` - Token is not fractionalized
+    pub fn fraction_allowance(
+        env: Env,
+        token_id: BytesN<32>,
+        from: Address,
+        spender: Address,
+    ) -> Result<i128, Error> {
+        FractionalizationModule::fraction_allowance(env, token_id, from, spender)
     }
 
-    pub fn pause_subscription_admin(
+    /// Allows `spender` to move up to `amount` of `from`'s shares until
+    /// `expiration_ledger`. An amount of 0 revokes the allowance.
+    ///
+    /// # Errors
+    /// * `TokenNotFound` - Token is not fractionalized
+    /// * `InvalidPaymentAmount` - Amount is negative
+    /// * `InvalidExpiryDate` - Expiration ledger is already in the past
+    pub fn fraction_approve(
         env: Env,
-        id: String,
-        admin: Address,
-        reason: Option<String>,
+        token_id: BytesN<32>,
+        from: Address,
+        spender: Address,
+        amount: i128,
+        expiration_ledger: u32,
     ) -> Result<(), Error> {
-        SubscriptionContract::pause_subscription_admin(env, id, admin, reason)
-    }
-
-    pub fn resume_subscription_admin(env: Env, id: String, admin: Address) -> Result<(), Error> {
-        SubscriptionContract::resume_subscription_admin(env, id, admin)
+        FractionalizationModule::fraction_approve(
+            env,
+            token_id,
+            from,
+            spender,
+            amount,
+            expiration_ledger,
+        )
     }
 
-    pub fn set_pause_config(env: Env, admin: Address, config: PauseConfig) -> Result<(), Error> {
-        SubscriptionContract::set_pause_config(env, admin, config)
+    /// Returns the number of decimals used for shares.
+    pub fn fraction_decimals(env: Env, token_id: BytesN<32>) -> Result<u32, Error> {
+        FractionalizationModule::fraction_decimals(env, token_id)
     }
 
-    pub fn get_pause_config(env: Env) -> PauseConfig {
-        SubscriptionContract::get_pause_config(env)
+    /// Returns the display name for shares.
+    pub fn fraction_name(env: Env, token_id: BytesN<32>) -> Result<String, Error> {
+        FractionalizationModule::fraction_name(env, token_id)
     }
 
-    pub fn get_pause_history(env: Env, id: String) -> Result<Vec<PauseHistoryEntry>, Error> {
-        SubscriptionContract::get_pause_history(env, id)
+    /// Returns the ticker symbol for shares.
+    pub fn fraction_symbol(env: Env, token_id: BytesN<32>) -> Result<String, Error> {
+        FractionalizationModule::fraction_symbol(env, token_id)
     }
 
-    pub fn get_pause_stats(env: Env, id: String) -> Result<PauseStats, Error> {
-        SubscriptionContract::get_pause_stats(env, id)
+    pub fn get_token(env: Env, id: BytesN<32>) -> Result<MembershipToken, Error> {
+        MembershipTokenContract::get_token(env, id)
     }
 
-    pub fn set_usdc_contract(env: Env, admin: Address, usdc_address: Address) -> Result<(), Error> {
-        SubscriptionContract::set_usdc_contract(env, admin, usdc_address)
+    /// Sets the first admin. Fails once an admin exists; use
+    /// `propose_admin_transfer` to hand over admin rights afterwards.
+    ///
+    /// # Errors
+    /// * `AdminTransferError::AdminAlreadySet` - An admin is already set
+    pub fn set_admin(env: Env, admin: Address) -> Result<(), ModuleError> {
+        MembershipTokenContract::set_admin(env, admin)?;
+        Ok(())
     }
 
-    // ============================================================================
-    // Tier Management Endpoints
-    // ============================================================================
-
-    /// Creates a new subscription tier. Admin only.
+    /// Proposes transferring admin rights to `new_admin`. The proposal must be
+    /// accepted by `new_admin` within 24 hours.
     ///
     /// # Arguments
     /// * `env` - The contract environment
-    /// * `admin` - Admin address (must be authorized)
-    /// * `params` - Tier creation parameters (id, name, level, prices, features, limits)
-    pub fn create_tier(env: Env, admin: Address, params: CreateTierParams) -> Result<(), Error> {
-        SubscriptionContract::create_tier(env, admin, params)
+    /// * `current_admin` - Current admin (must be authorized)
+    /// * `new_admin` - Address that will become admin on acceptance
+    ///
+    /// # Errors
+    /// * `AdminNotSet` - No admin has been configured
+    /// * `Unauthorized` - Caller is not the admin, or `new_admin` is the current admin
+    pub fn propose_admin_transfer(
+        env: Env,
+        current_admin: Address,
+        new_admin: Address,
+    ) -> Result<(), ModuleError> {
+        MembershipTokenContract::propose_admin_transfer(env, current_admin, new_admin)
     }
 
-    /// Updates an existing subscription tier. Admin only.
+    /// Accepts a pending admin transfer. Must be called by the proposed admin.
     ///
-    /// # Arguments
-    /// * `env` - The contract environment
-    /// * `admin` - Admin address (must be authorized)
-    /// * `params` - Update parameters (id required, other fields optional)
-    pub fn update_tier(env: Env, admin: Address, params: UpdateTierParams) -> Result<(), Error> {
-        SubscriptionContract::update_tier(env, admin, params)
+    /// # Errors
+    /// * `AdminTransferError::NoPendingTransfer` - No transfer is pending
+    /// * `Unauthorized` - Caller is not the proposed admin
+    /// * `AdminTransferError::TransferExpired` - The proposal has expired
+    pub fn accept_admin_transfer(env: Env, new_admin: Address) -> Result<(), ModuleError> {
+        MembershipTokenContract::accept_admin_transfer(env, new_admin)
     }
 
-    /// Gets a subscription tier by ID.
-    pub fn get_tier(env: Env, id: String) -> Result<SubscriptionTier, Error> {
-        SubscriptionContract::get_tier(env, id)
+    /// Cancels a pending admin transfer.
+    ///
+    /// # Errors
+    /// * `AdminNotSet` - No admin configured
+    /// * `AdminTransferError::NoPendingTransfer` - No transfer is pending
+    /// * `Unauthorized` - Caller is not the admin
+    pub fn cancel_admin_transfer(env: Env, current_admin: Address) -> Result<(), ModuleError> {
+        MembershipTokenContract::cancel_admin_transfer(env, current_admin)
     }
 
-    /// Gets all subscription tiers.
-    pub fn get_all_tiers(env: Env) -> Vec<SubscriptionTier> {
-        SubscriptionContract::get_all_tiers(env)
+    /// Returns the pending admin transfer, if any.
+    pub fn get_pending_admin_transfer(env: Env) -> Option<PendingAdminTransfer> {
+        MembershipTokenContract::get_pending_admin_transfer(env)
     }
 
-    /// Gets only active tiers available for purchase.
-    pub fn get_active_tiers(env: Env) -> Vec<SubscriptionTier> {
-        SubscriptionContract::get_active_tiers(env)
+    pub fn log_attendance(
+        env: Env,
+        id: BytesN<32>,
+        user_id: Address,
+        action: AttendanceAction,
+        details: soroban_sdk::Map<String, String>,
+    ) -> Result<(), ModuleError> {
+        AttendanceLogModule::log_attendance(env, id, user_id, action, details)
     }
 
-    /// Deactivates a tier (soft delete). Admin only.
-    pub fn deactivate_tier(env: Env, admin: Address, id: String) -> Result<(), Error> {
-        SubscriptionContract::deactivate_tier(env, admin, id)
+    pub fn get_logs_for_user(env: Env, user_id: Address) -> Vec<AttendanceLog> {
+        AttendanceLogModule::get_logs_for_user(env, user_id)
     }
 
-    // ============================================================================
-    // Subscription with Tier Support Endpoints
-    // ============================================================================
-
-    /// Creates a subscription with tier support.
+    /// Gets a window of a user's attendance logs, oldest first.
     ///
     /// # Arguments
     /// * `env` - The contract environment
-    /// * `id` - Unique subscription identifier
-    /// * `user` - User address
-    /// * `payment_token` - Token used for payment
-    /// * `tier_id` - ID of the tier to subscribe to
-    /// * `billing_cycle` - Monthly or Annual billing
-    /// * `promo_code` - Optional promotion code for discounts
-    pub fn create_subscription_with_tier(
+    /// * `user_id` - User whose logs to read
+    /// * `offset` - Index of the first log to return
+    /// * `limit` - Maximum number of logs to return (capped at 100)
+    pub fn get_logs_for_user_paged(
         env: Env,
-        id: String,
-        user: Address,
-        payment_token: Address,
-        tier_id: String,
-        billing_cycle: BillingCycle,
-        promo_code: Option<String>,
-    ) -> Result<(), Error> {
-        SubscriptionContract::create_subscription_with_tier(
-            env,
-            id,
-            user,
-            payment_token,
-            tier_id,
-            billing_cycle,
-            promo_code,
-        )
+        user_id: Address,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<AttendanceLog> {
+        AttendanceLogModule::get_logs_for_user_paged(env, user_id, offset, limit)
     }
 
-    /// Gets detailed subscription info including tier details.
-    pub fn get_user_subscription_info(
-        env: Env,
-        subscription_id: String,
-    ) -> Result<UserSubscriptionInfo, Error> {
-        SubscriptionContract::get_user_subscription_info(env, subscription_id)
+    /// Gets the number of attendance logs stored for a user.
+    pub fn get_log_count_for_user(env: Env, user_id: Address) -> u32 {
+        AttendanceLogModule::get_log_count(&env, &user_id)
     }
 
-    // ============================================================================
-    // Tier Change (Upgrade/Downgrade) Endpoints
-    // ============================================================================
-
-    /// Initiates a tier change request (upgrade or downgrade).
+    /// Archives a user's attendance logs older than `before`. Admin only.
     ///
-    /// # Returns
-    /// * `Ok(String)` - The change request ID
-    pub fn request_tier_change(
+    /// Archived entries are rolled into per-month summaries (visits and
+    /// session time) and the raw logs are deleted. Processes at most 100 logs
+    /// per call; returns how many were archived so callers can repeat until 0.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `admin` - Admin address (must be authorized)
+    /// * `user_id` - User whose logs to archive
+    /// * `before` - Logs with a timestamp strictly before this are archived
+    ///
+    /// # Errors
+    /// * `AdminNotSet` - No admin configured
+    /// * `Unauthorized` - Caller is not the admin
+    pub fn archive_logs_before(
         env: Env,
-        user: Address,
-        subscription_id: String,
-        new_tier_id: String,
-    ) -> Result<String, Error> {
-        SubscriptionContract::request_tier_change(env, user, subscription_id, new_tier_id)
+        admin: Address,
+        user_id: Address,
+        before: u64,
+    ) -> Result<u32, Error> {
+        AttendanceLogModule::archive_logs_before(env, admin, user_id, before)
     }
 
-    /// Processes a tier change request.
-    pub fn process_tier_change(
-        env: Env,
-        caller: Address,
-        change_request_id: String,
-        subscription_id: String,
-        payment_token: Address,
-    ) -> Result<(), Error> {
-        SubscriptionContract::process_tier_change(
-            env,
-            caller,
-            change_request_id,
-            subscription_id,
-            payment_token,
-        )
+    /// Gets the monthly summaries of a user's archived attendance logs.
+    pub fn get_archived_attendance(env: Env, user_id: Address) -> Vec<ArchivedAttendanceSummary> {
+        AttendanceLogModule::get_archived_summaries(env, user_id)
     }
 
-    /// Cancels a pending tier change request.
-    pub fn cancel_tier_change(
-        env: Env,
-        user: Address,
-        change_request_id: String,
-    ) -> Result<(), Error> {
-        SubscriptionContract::cancel_tier_change(env, user, change_request_id)
+    pub fn get_attendance_log(env: Env, id: BytesN<32>) -> Option<AttendanceLog> {
+        AttendanceLogModule::get_attendance_log(env, id)
     }
 
-    // ============================================================================
-    // Promotion Management Endpoints
-    // ============================================================================
-
-    /// Creates a promotional pricing for a tier. Admin only.
+    /// Sets the attendance logging rate limit. Admin only.
     ///
     /// # Arguments
     /// * `env` - The contract environment
     /// * `admin` - Admin address (must be authorized)
-    /// * `params` - Promotion parameters (promo_id, tier_id, discount, dates, code, limits)
-    pub fn create_promotion(
+    /// * `config` - Per-user limits; a limit of 0 disables that check
+    ///
+    /// # Errors
+    /// * `AdminNotSet` - No admin configured
+    /// * `Unauthorized` - Caller is not the admin
+    pub fn set_attendance_rate_limit(
         env: Env,
         admin: Address,
-        params: CreatePromotionParams,
+        config: AttendanceRateLimit,
     ) -> Result<(), Error> {
-        SubscriptionContract::create_promotion(env, admin, params)
+        AttendanceLogModule::set_rate_limit(env, admin, config)
     }
 
-    /// Gets a promotion by ID.
-    pub fn get_promotion(env: Env, promo_id: String) -> Result<TierPromotion, Error> {
-        SubscriptionContract::get_promotion(env, promo_id)
+    /// Registers a contract to be notified via `on_attendance(user, action,
+    /// timestamp)` whenever attendance is logged. Admin only.
+    ///
+    /// Hook failures never revert the attendance log; at most 5 hooks may be
+    /// registered. Registering an existing hook is a no-op.
+    ///
+    /// # Errors
+    /// * `AdminNotSet` - No admin configured
+    /// * `Unauthorized` - Caller is not the admin
+    /// * `AttendanceError::HookLimitReached` - The hook limit has been reached
+    pub fn register_attendance_hook(
+        env: Env,
+        admin: Address,
+        hook: Address,
+    ) -> Result<(), ModuleError> {
+        AttendanceHookModule::register_hook(env, admin, hook)
     }
 
-    // ============================================================================
-    // Feature Access Control Endpoints
-    // ============================================================================
-
-    /// Checks if a subscription has access to a specific feature.
-    pub fn check_feature_access(
+    /// Removes a registered attendance hook. Admin only.
+    ///
+    /// # Errors
+    /// * `AdminNotSet` - No admin configured
+    /// * `Unauthorized` - Caller is not the admin
+    pub fn unregister_attendance_hook(
         env: Env,
-        subscription_id: String,
-        feature: TierFeature,
-    ) -> Result<bool, Error> {
-        SubscriptionContract::check_feature_access(env, subscription_id, feature)
+        admin: Address,
+        hook: Address,
+    ) -> Result<(), Error> {
+        AttendanceHookModule::unregister_hook(env, admin, hook)
     }
 
-    /// Enforces feature access, returns error if not available.
-    pub fn require_feature_access(
+    /// Gets the registered attendance hook contracts.
+    pub fn get_attendance_hooks(env: Env) -> Vec<Address> {
+        AttendanceHookModule::get_hooks(&env)
+    }
+
+    /// Gets the attendance rate limit, if one is configured.
+    pub fn get_attendance_rate_limit(env: Env) -> Option<AttendanceRateLimit> {
+        AttendanceLogModule::get_rate_limit(&env)
+    }
+
+    /// Adds or removes a user from the attendance rate-limit exemption list. Admin only.
+    ///
+    /// # Errors
+    /// * `AdminNotSet` - No admin configured
+    /// * `Unauthorized` - Caller is not the admin
+    pub fn set_attendance_rate_limit_exempt(
         env: Env,
-        subscription_id: String,
-        feature: TierFeature,
+        admin: Address,
+        user: Address,
+        exempt: bool,
     ) -> Result<(), Error> {
-        SubscriptionContract::require_feature_access(env, subscription_id, feature)
+        AttendanceLogModule::set_rate_limit_exempt(env, admin, user, exempt)
     }
 
-    // ============================================================================
-    // Tier Analytics Endpoints
-    // ============================================================================
+    /// Returns `true` if the user is exempt from the attendance rate limit.
+    pub fn is_attendance_rate_limit_exempt(env: Env, user: Address) -> bool {
+        AttendanceLogModule::is_rate_limit_exempt(&env, &user)
+    }
 
-    /// Gets analytics for a specific tier.
-    pub fn get_tier_analytics(env: Env, tier_id: String) -> Result<TierAnalytics, Error> {
-        SubscriptionContract::get_tier_analytics(env, tier_id)
+    /// Sets the rules member-supplied attendance details must satisfy: entry
+    /// count, key and value length, a key allowlist and keys required per
+    /// action. Admin only.
+    ///
+    /// # Errors
+    /// * `AdminNotSet` - No admin configured
+    /// * `Unauthorized` - Caller is not the admin
+    /// * `AttendanceError::InvalidDetailRules` - A limit or key list exceeds 50 entries
+    pub fn set_attendance_detail_rules(
+        env: Env,
+        admin: Address,
+        rules: AttendanceDetailRules,
+    ) -> Result<(), ModuleError> {
+        AttendanceLogModule::set_detail_rules(env, admin, rules)
     }
 
-    // ============================================================================
-    // Token Metadata Endpoints
-    // ============================================================================
+    /// Gets the attendance detail rules, if configured.
+    pub fn get_attendance_detail_rules(env: Env) -> Option<AttendanceDetailRules> {
+        AttendanceLogModule::get_detail_rules(&env)
+    }
 
-    /// Sets metadata for a membership token.
+    /// Stores member-supplied attendance details only as a sha256 hash from
+    /// now on, or stops doing so. Admin only.
     ///
-    /// # Arguments
-    /// * `env` - The contract environment
-    /// * `token_id` - The token ID to set metadata for
-    /// * `description` - Token description (max 500 chars)
-    /// * `attributes` - Custom attributes map (max 20 attributes)
+    /// # Errors
+    /// * `AdminNotSet` - No admin configured
+    /// * `Unauthorized` - Caller is not the admin
+    pub fn set_hash_details_on_write(env: Env, admin: Address, enabled: bool) -> Result<(), Error> {
+        AttendanceLogModule::set_hash_details_on_write(env, admin, enabled)
+    }
+
+    /// Returns `true` if member-supplied details are stored hash-only.
+    pub fn is_hash_details_on_write(env: Env) -> bool {
+        AttendanceLogModule::is_hash_details_on_write(&env)
+    }
+
+    /// Replaces an attendance log's details with a hash of the original
+    /// content. Callable by the log's user or the admin.
     ///
     /// # Errors
-    /// * `TokenNotFound` - Token doesn't exist
-    /// * `Unauthorized` - Caller is not admin or token owner
-    /// * `MetadataValidationFailed` - Metadata validation failed
-    pub fn set_token_metadata(
+    /// * `AttendanceError::LogNotFound` - No log with this id
+    /// * `Unauthorized` - Caller is neither the log's user nor the admin
+    pub fn redact_log_details(
         env: Env,
-        token_id: BytesN<32>,
-        description: String,
-        attributes: Map<String, MetadataValue>,
-    ) -> Result<(), Error> {
-        MembershipTokenContract::set_token_metadata(env, token_id, description, attributes)
+        caller: Address,
+        log_id: BytesN<32>,
+    ) -> Result<BytesN<32>, ModuleError> {
+        AttendanceLogModule::redact_log_details(env, caller, log_id)
     }
 
-    /// Gets metadata for a membership token.
+    /// Redacts a page of a user's attendance logs, returning how many were
+    /// newly redacted. Callable by the user or the admin; at most 100 logs
+    /// per call.
     ///
-    /// # Arguments
-    /// * `env` - The contract environment
-    /// * `token_id` - The token ID to get metadata for
+    /// # Errors
+    /// * `Unauthorized` - Caller is neither the user nor the admin
+    pub fn redact_user_log_details(
+        env: Env,
+        caller: Address,
+        user_id: Address,
+        offset: u32,
+        limit: u32,
+    ) -> Result<u32, Error> {
+        AttendanceLogModule::redact_user_log_details(env, caller, user_id, offset, limit)
+    }
+
+    /// Gets the hash of a log's original details, if they were redacted or
+    /// stored hash-only.
+    pub fn get_log_details_hash(env: Env, log_id: BytesN<32>) -> Option<BytesN<32>> {
+        AttendanceLogModule::get_details_hash(&env, &log_id)
+    }
+
+    pub fn create_subscription(
+        env: Env,
+        id: String,
+        user: Address,
+        payment_token: Address,
+        amount: i128,
+        duration: u64,
+    ) -> Result<(), ModuleError> {
+        SubscriptionContract::create_subscription(env, id, user, payment_token, amount, duration)
+    }
+
+    pub fn renew_subscription(
+        env: Env,
+        id: String,
+        payment_token: Address,
+        amount: i128,
+        duration: u64,
+    ) -> Result<(), ModuleError> {
+        SubscriptionContract::renew_subscription(env, id, payment_token, amount, duration)
+    }
+
+    /// Returns a subscription with its effective status: one past its expiry
+    /// date reads as Expired even before `expire_subscription` persists it.
     ///
-    /// # Returns
-    /// * `Ok(TokenMetadata)` - The token metadata
-    /// * `Err(Error)` - If token or metadata not found
-    pub fn get_token_metadata(env: Env, token_id: BytesN<32>) -> Result<TokenMetadata, Error> {
-        MembershipTokenContract::get_token_metadata(env, token_id)
+    /// # Errors
+    /// * `SubscriptionNotFound` - Subscription doesn't exist
+    pub fn get_subscription(env: Env, id: String) -> Result<Subscription, Error> {
+        SubscriptionContract::get_subscription_view(env, id)
     }
 
-    /// Updates specific attributes in token metadata.
+    /// Cancels a subscription. Requires the owner's authorization.
     ///
     /// # Arguments
     /// * `env` - The contract environment
-    /// * `token_id` - The token ID to update metadata for
-    /// * `updates` - Map of attributes to add or update
+    /// * `id` - Subscription to cancel
+    /// * `reason` - Optional cancellation survey answer, stored for churn
+    ///   analytics
+    ///
+    /// If a win-back offer is configured, the owner receives one and it is
+    /// applied to their next tier subscription made without a promo code.
     ///
     /// # Errors
-    /// * `TokenNotFound` - Token doesn't exist
-    /// * `MetadataNotFound` - Metadata doesn't exist
-    /// * `Unauthorized` - Caller is not admin or token owner
-    pub fn update_token_metadata(
+    /// * `SubscriptionNotFound` - Subscription doesn't exist
+    /// * `SubscriptionNotActive` - Subscription is already cancelled
+    pub fn cancel_subscription(
         env: Env,
-        token_id: BytesN<32>,
-        updates: Map<String, MetadataValue>,
+        id: String,
+        reason: Option<CancellationReason>,
     ) -> Result<(), Error> {
-        MembershipTokenContract::update_token_metadata(env, token_id, updates)
+        SubscriptionContract::cancel_subscription(env, id, reason)
     }
 
-    /// Gets the metadata update history for a token.
+    /// Reactivates a cancelled or expired tiered subscription under its
+    /// original ID. Requires the original owner's authorization.
     ///
     /// # Arguments
     /// * `env` - The contract environment
-    /// * `token_id` - The token ID to get history for
+    /// * `id` - Subscription to reactivate
+    /// * `payment_token` - Accepted asset to pay in
+    /// * `billing_cycle` - Cycle to bill the new period at
+    ///
+    /// The tier's current price is charged (less any win-back offer and
+    /// credit, plus fees), not a locked price. Pause counters are reset;
+    /// the creation date and billing history are kept. Emits a `sub_react`
+    /// outbox record.
+    ///
+    /// # Errors
+    /// * `SubscriptionNotFound` - Subscription doesn't exist
+    /// * `SubscriptionAlreadyExists` - Subscription is still live; renew it
+    /// * `SubscriptionPaused` - Subscription is paused; resume it
+    /// * `SubscriptionNotActive` - Subscription was revoked
+    /// * `TierNotFound` - Legacy subscription without a tier
+    /// * `TierNotActive` - The tier has been deactivated
+    /// * `InvalidPaymentToken` - Asset is not accepted
+    pub fn reactivate_subscription(
+        env: Env,
+        id: String,
+        payment_token: Address,
+        billing_cycle: BillingCycle,
+    ) -> Result<(), ModuleError> {
+        SubscriptionContract::reactivate_subscription(env, id, payment_token, billing_cycle)
+    }
+
+    /// Returns why and when a subscription was cancelled, if it was.
+    pub fn get_cancellation(env: Env, subscription_id: String) -> Option<CancellationRecord> {
+        RetentionModule::get_cancellation(&env, &subscription_id)
+    }
+
+    /// Returns how many cancellations gave `reason` in the survey.
+    pub fn get_cancellation_reason_count(env: Env, reason: CancellationReason) -> u32 {
+        RetentionModule::get_cancellation_reason_count(&env, reason)
+    }
+
+    /// Sets the win-back offer attached to users when they cancel, or
+    /// disables offers with `None`. Admin only.
+    ///
+    /// # Errors
+    /// * `AdminNotSet` - No admin has been configured
+    /// * `Unauthorized` - Caller is not the admin
+    /// * `RetentionError::InvalidWinbackConfig` - Discount outside 1–100 % or zero valid days
+    pub fn set_winback_config(
+        env: Env,
+        admin: Address,
+        config: Option<WinbackConfig>,
+    ) -> Result<(), ModuleError> {
+        RetentionModule::set_winback_config(env, admin, config)
+    }
+
+    /// Returns the win-back offer settings, `None` while offers are disabled.
+    pub fn get_winback_config(env: Env) -> Option<WinbackConfig> {
+        RetentionModule::get_winback_config(&env)
+    }
+
+    /// Returns `user`'s win-back offer while it can still be redeemed.
+    pub fn get_winback_offer(env: Env, user: Address) -> Option<WinbackOffer> {
+        RetentionModule::get_winback_offer(&env, &user)
+    }
+
+    pub fn pause_subscription(env: Env, id: String, reason: Option<String>) -> Result<(), Error> {
+        SubscriptionContract::pause_subscription(env, id, reason)
+    }
+
+    pub fn resume_subscription(env: Env, id: String) -> Result<(), Error> {
+        SubscriptionContract::resume_subscription(env, id)
+    }
+
+    pub fn pause_subscription_admin(
+        env: Env,
+        id: String,
+        admin: Address,
+        reason: Option<String>,
+    ) -> Result<(), Error> {
+        SubscriptionContract::pause_subscription_admin(env, id, admin, reason)
+    }
+
+    pub fn resume_subscription_admin(env: Env, id: String, admin: Address) -> Result<(), Error> {
+        SubscriptionContract::resume_subscription_admin(env, id, admin)
+    }
+
+    pub fn set_pause_config(env: Env, admin: Address, config: PauseConfig) -> Result<(), Error> {
+        SubscriptionContract::set_pause_config(env, admin, config)
+    }
+
+    pub fn get_pause_config(env: Env) -> PauseConfig {
+        SubscriptionContract::get_pause_config(env)
+    }
+
+    pub fn get_pause_history(env: Env, id: String) -> Result<Vec<PauseHistoryEntry>, Error> {
+        SubscriptionContract::get_pause_history(env, id)
+    }
+
+    pub fn get_pause_stats(env: Env, id: String) -> Result<PauseStats, Error> {
+        SubscriptionContract::get_pause_stats(env, id)
+    }
+
+    /// Books a pause of `duration` seconds starting at `start_at`. The
+    /// member must authorize; the pause is checked against the pause config
+    /// now and again when it starts.
+    pub fn schedule_pause(
+        env: Env,
+        id: String,
+        start_at: u64,
+        duration: u64,
+    ) -> Result<(), ModuleError> {
+        SubscriptionContract::schedule_pause(env, id, start_at, duration)
+    }
+
+    /// Cancels a scheduled pause that has not started yet.
+    pub fn cancel_scheduled_pause(env: Env, id: String) -> Result<(), ModuleError> {
+        SubscriptionContract::cancel_scheduled_pause(env, id)
+    }
+
+    pub fn get_scheduled_pause(env: Env, id: String) -> Option<ScheduledPause> {
+        SubscriptionContract::get_scheduled_pause(env, id)
+    }
+
+    /// Starts scheduled pauses whose start time has arrived and resumes
+    /// those whose duration has run out. Callable by anyone (e.g. a
+    /// keeper); handles at most `limit` subscriptions (capped at 25).
+    /// Returns how many were paused or resumed.
+    pub fn apply_scheduled_pauses(env: Env, limit: u32) -> u32 {
+        SubscriptionContract::apply_scheduled_pauses(env, limit)
+    }
+
+    /// Returns a page of a subscription's billing history, oldest first.
+    ///
+    /// Every charge (creation, renewal, tier upgrade, installment) appends a
+    /// record with the amount, payment asset and any promo code applied.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `subscription_id` - Subscription to query
+    /// * `offset` - Index of the first record to return
+    /// * `limit` - Maximum records to return (capped at 50)
+    pub fn get_billing_history(
+        env: Env,
+        subscription_id: String,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<BillingRecord> {
+        BillingModule::get_billing_history(env, subscription_id, offset, limit)
+    }
+
+    /// Returns an invoice by number.
+    ///
+    /// Every billing history record has an invoice; invoice numbers start at
+    /// 1 and increase by one per invoice.
+    ///
+    /// # Errors
+    /// * `BillingError::InvoiceNotFound` - No invoice with that number
+    pub fn get_invoice(env: Env, invoice_id: u64) -> Result<Invoice, ModuleError> {
+        BillingModule::get_invoice(env, invoice_id)
+    }
+
+    /// Returns a page of the invoices paid by `user`, oldest first.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `user` - Payer to query
+    /// * `offset` - Index of the first invoice to return
+    /// * `limit` - Maximum invoices to return (capped at 50)
+    pub fn get_invoices_for_user(env: Env, user: Address, offset: u32, limit: u32) -> Vec<Invoice> {
+        BillingModule::get_invoices_for_user(env, user, offset, limit)
+    }
+
+    /// Marks a paid invoice as refunded. Admin only. The refund payment
+    /// itself is settled outside the contract.
+    ///
+    /// # Errors
+    /// * `AdminNotSet` - No admin has been configured
+    /// * `Unauthorized` - Caller is not the admin
+    /// * `BillingError::InvoiceNotFound` - No invoice with that number
+    /// * `BillingError::InvoiceAlreadyRefunded` - Invoice is already refunded
+    pub fn mark_invoice_refunded(
+        env: Env,
+        admin: Address,
+        invoice_id: u64,
+    ) -> Result<(), ModuleError> {
+        BillingModule::mark_invoice_refunded(env, admin, invoice_id)
+    }
+
+    /// Sets the tax rate included in charges that carry no regional tax, in
+    /// basis points, used to split invoice totals into subtotal and tax.
+    /// Admin only.
+    ///
+    /// # Errors
+    /// * `AdminNotSet` - No admin has been configured
+    /// * `Unauthorized` - Caller is not the admin
+    /// * `BillingError::InvalidTaxRate` - Rate above 5_000 (50 %)
+    pub fn set_invoice_tax_rate(
+        env: Env,
+        admin: Address,
+        rate_bps: u32,
+    ) -> Result<(), ModuleError> {
+        BillingModule::set_tax_rate(env, admin, rate_bps)
+    }
+
+    /// Returns the invoice tax rate in basis points.
+    pub fn get_invoice_tax_rate(env: Env) -> u32 {
+        BillingModule::get_tax_rate(&env)
+    }
+
+    /// Sets the platform fee (basis points of the price) and the fixed
+    /// processing fee (USDC units) added to tier-priced charges. Admin only.
+    ///
+    /// # Errors
+    /// * `AdminNotSet` - No admin has been configured
+    /// * `Unauthorized` - Caller is not the admin
+    /// * `BillingError::InvalidFeeConfig` - Platform fee above 5_000 (50 %) or a
+    ///   negative processing fee
+    pub fn set_fee_config(env: Env, admin: Address, config: FeeConfig) -> Result<(), ModuleError> {
+        FeeModule::set_fee_config(env, admin, config)
+    }
+
+    /// Returns the fee settings; both fees are zero until configured.
+    pub fn get_fee_config(env: Env) -> FeeConfig {
+        FeeModule::get_fee_config(&env)
+    }
+
+    /// Sets the tax rate of a region code in basis points. Admin only. The
+    /// tax applies to the price and fees of members billed in the region;
+    /// a rate of zero removes the region.
+    ///
+    /// # Errors
+    /// * `AdminNotSet` - No admin has been configured
+    /// * `Unauthorized` - Caller is not the admin
+    /// * `BillingError::InvalidFeeConfig` - Rate above 5_000 (50 %)
+    pub fn set_region_tax_rate(
+        env: Env,
+        admin: Address,
+        region: String,
+        rate_bps: u32,
+    ) -> Result<(), ModuleError> {
+        FeeModule::set_region_tax_rate(env, admin, region, rate_bps)
+    }
+
+    /// Returns the tax rate of a region code, 0 if it has none.
+    pub fn get_region_tax_rate(env: Env, region: String) -> u32 {
+        FeeModule::get_region_tax_rate(&env, &region)
+    }
+
+    /// Sets the region code `user` is billed in, or clears it with `None`.
+    /// Requires the user's authorization.
+    pub fn set_billing_region(env: Env, user: Address, region: Option<String>) {
+        FeeModule::set_billing_region(env, user, region)
+    }
+
+    /// Returns the region code `user` is billed in, if any.
+    pub fn get_billing_region(env: Env, user: Address) -> Option<String> {
+        FeeModule::get_billing_region(&env, &user)
+    }
+
+    /// Quotes a new subscription to `tier_id` at list price, in USDC units,
+    /// with platform fee, processing fee and the tax of `region`.
+    ///
+    /// # Errors
+    /// * `TierNotFound` - Tier does not exist
+    /// * `InvalidPaymentAmount` - The total overflows
+    pub fn quote_total(
+        env: Env,
+        tier_id: String,
+        billing_cycle: BillingCycle,
+        region: Option<String>,
+    ) -> Result<FeeBreakdown, Error> {
+        FeeModule::quote_total(env, tier_id, billing_cycle, region)
+    }
+
+    /// Sets the USDC payment contract for the first time. Admin only.
+    ///
+    /// # Errors
+    /// * `AdminNotSet` - No admin has been configured
+    /// * `ConfigChangeError::ChangeRequiresProposal` - Caller is not the admin, or a USDC contract is already
+    ///   set (use `propose_usdc_contract` to change it)
+    pub fn set_usdc_contract(
+        env: Env,
+        admin: Address,
+        usdc_address: Address,
+    ) -> Result<(), ModuleError> {
+        SubscriptionContract::set_usdc_contract(env, admin, usdc_address)
+    }
+
+    /// Proposes replacing the USDC payment contract. The change can be
+    /// confirmed after a 48 hour delay.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `admin` - Admin address (must be authorized)
+    /// * `new_usdc_address` - Replacement USDC contract
+    ///
+    /// # Errors
+    /// * `AdminNotSet` / `Unauthorized` - Auth failure
+    /// * `UsdcContractNotSet` - No USDC contract to replace yet
+    pub fn propose_usdc_contract(
+        env: Env,
+        admin: Address,
+        new_usdc_address: Address,
+    ) -> Result<PendingUsdcChange, Error> {
+        SubscriptionContract::propose_usdc_contract(env, admin, new_usdc_address)
+    }
+
+    /// Confirms a pending USDC contract change once its delay has elapsed.
+    ///
+    /// # Errors
+    /// * `AdminNotSet` / `Unauthorized` - Auth failure
+    /// * `ConfigChangeError::NoPendingChange` - No pending change
+    /// * `ConfigChangeError::TimelockActive` - The delay has not elapsed yet
+    pub fn confirm_usdc_contract(env: Env, admin: Address) -> Result<(), ModuleError> {
+        SubscriptionContract::confirm_usdc_contract(env, admin)
+    }
+
+    /// Cancels a pending USDC contract change.
+    ///
+    /// # Errors
+    /// * `AdminNotSet` / `Unauthorized` - Auth failure
+    /// * `UsdcContractNotSet` - No pending change
+    pub fn cancel_usdc_contract_change(env: Env, admin: Address) -> Result<(), ModuleError> {
+        SubscriptionContract::cancel_usdc_contract_change(env, admin)
+    }
+
+    /// Returns the pending USDC contract change, if any.
+    pub fn get_pending_usdc_change(env: Env) -> Option<PendingUsdcChange> {
+        SubscriptionContract::get_pending_usdc_change(&env)
+    }
+
+    // ============================================================================
+    // Payment Token Registry Endpoints
+    // ============================================================================
+
+    /// Whitelists an additional payment asset, or updates an existing entry.
+    /// Tier prices stay denominated in USDC and are converted with `rate`.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `admin` - Admin address (must be authorized)
+    /// * `token` - Stellar asset contract to accept
+    /// * `rate` - Token units per USDC unit, scaled by 10^7
+    /// * `oracle` - Optional price feed overriding the global oracle for this asset
+    ///
+    /// # Errors
+    /// * `AdminNotSet` / `Unauthorized` - Auth failure
+    /// * `UsdcContractNotSet` - The base currency has not been configured
+    /// * `InvalidPaymentAmount` - `rate` is not positive
+    /// * `InvalidPaymentToken` - `token` is the USDC contract
+    pub fn add_payment_token(
+        env: Env,
+        admin: Address,
+        token: Address,
+        rate: i128,
+        oracle: Option<Address>,
+    ) -> Result<(), Error> {
+        PaymentTokenModule::add_payment_token(env, admin, token, rate, oracle)
+    }
+
+    /// Removes a payment asset from the whitelist.
+    ///
+    /// # Errors
+    /// * `AdminNotSet` / `Unauthorized` - Auth failure
+    /// * `InvalidPaymentToken` - `token` is not whitelisted
+    pub fn remove_payment_token(env: Env, admin: Address, token: Address) -> Result<(), Error> {
+        PaymentTokenModule::remove_payment_token(env, admin, token)
+    }
+
+    /// Lists every accepted payment asset, starting with USDC.
+    pub fn get_accepted_payment_tokens(env: Env) -> Vec<AcceptedPaymentToken> {
+        PaymentTokenModule::get_accepted_payment_tokens(env)
+    }
+
+    // ============================================================================
+    // Price Oracle Endpoints
+    // ============================================================================
+
+    /// Registers or updates the price oracle used for USD-denominated tiers.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `admin` - Admin address (must be authorized)
+    /// * `config` - Oracle address, staleness window, deviation guard and fallback flag
+    ///
+    /// # Errors
+    /// * `AdminNotSet` / `Unauthorized` - Auth failure
+    /// * `InvalidPaymentAmount` - Zero staleness window or deviation above 100 %
+    pub fn set_oracle_config(env: Env, admin: Address, config: OracleConfig) -> Result<(), Error> {
+        OracleModule::set_oracle_config(env, admin, config)
+    }
+
+    /// Returns the oracle configuration, if one is registered.
+    pub fn get_oracle_config(env: Env) -> Option<OracleConfig> {
+        OracleModule::get_oracle_config(&env)
+    }
+
+    /// Marks a tier's prices as USD-denominated. Charges in non-USDC assets are
+    /// then converted with the oracle price at charge time.
+    ///
+    /// # Errors
+    /// * `AdminNotSet` / `Unauthorized` - Auth failure
+    /// * `TierNotFound` - Tier does not exist
+    pub fn set_tier_usd_pricing(
+        env: Env,
+        admin: Address,
+        tier_id: String,
+        usd_denominated: bool,
+    ) -> Result<(), Error> {
+        OracleModule::set_tier_usd_pricing(env, admin, tier_id, usd_denominated)
+    }
+
+    /// Returns `true` if the tier's prices are USD-denominated.
+    pub fn is_tier_usd_priced(env: Env, tier_id: String) -> bool {
+        OracleModule::is_usd_priced(&env, &tier_id)
+    }
+
+    // ============================================================================
+    // Tier Management Endpoints
+    // ============================================================================
+
+    /// Creates a new subscription tier. Admin only.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `admin` - Admin address (must be authorized)
+    /// * `params` - Tier creation parameters (id, name, level, prices, features, limits)
+    pub fn create_tier(env: Env, admin: Address, params: CreateTierParams) -> Result<(), Error> {
+        SubscriptionContract::create_tier(env, admin, params)
+    }
+
+    /// Updates an existing subscription tier. Admin only.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `admin` - Admin address (must be authorized)
+    /// * `params` - Update parameters (id required, other fields optional)
+    pub fn update_tier(env: Env, admin: Address, params: UpdateTierParams) -> Result<(), Error> {
+        SubscriptionContract::update_tier(env, admin, params)
+    }
+
+    /// Gets a subscription tier by ID.
+    pub fn get_tier(env: Env, id: String) -> Result<SubscriptionTier, Error> {
+        SubscriptionContract::get_tier(env, id)
+    }
+
+    /// Gets all subscription tiers.
+    pub fn get_all_tiers(env: Env) -> Vec<SubscriptionTier> {
+        SubscriptionContract::get_all_tiers(env)
+    }
+
+    /// Gets only active tiers available for purchase.
+    pub fn get_active_tiers(env: Env) -> Vec<SubscriptionTier> {
+        SubscriptionContract::get_active_tiers(env)
+    }
+
+    /// Deactivates a tier (soft delete). Admin only.
+    pub fn deactivate_tier(env: Env, admin: Address, id: String) -> Result<(), Error> {
+        SubscriptionContract::deactivate_tier(env, admin, id)
+    }
+
+    /// Compares two tiers' prices, features and limits. Price differences
+    /// are `tier_b` minus `tier_a`.
+    ///
+    /// # Errors
+    /// * `TierNotFound` - Either tier doesn't exist
+    pub fn compare_tiers(
+        env: Env,
+        tier_a: String,
+        tier_b: String,
+    ) -> Result<TierComparison, Error> {
+        TierAdvisorModule::compare_tiers(env, tier_a, tier_b)
+    }
+
+    /// Recommends the cheapest active tier that fits `user`'s household size
+    /// and guest visits, with annual billing for frequent attendees. Returns
+    /// `None` when no active tier fits.
+    pub fn recommend_tier(env: Env, user: Address) -> Option<TierRecommendation> {
+        TierAdvisorModule::recommend_tier(env, user)
+    }
+
+    /// Deprecates a tier in favour of a replacement. Admin only.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `admin` - Admin address (must be authorized)
+    /// * `tier_id` - Tier to retire
+    /// * `replacement_tier_id` - Active tier its subscribers move to
+    /// * `migrate_at` - Earliest time subscribers can be migrated
+    ///
+    /// The tier stops accepting new subscriptions immediately, and a
+    /// `tier_deprecated` event announces the plan. Remaining subscribers are
+    /// moved with `migrate_deprecated_tier`.
+    ///
+    /// # Errors
+    /// * `AdminNotSet` / `Unauthorized` - Auth failure
+    /// * `TierNotFound` - Either tier doesn't exist
+    /// * `TierChangeError::InvalidReplacementTier` - Replacement is inactive or is the tier itself
+    /// * `TierChangeError::TierAlreadyDeprecated` - Tier is already deprecated
+    /// * `TierChangeError::InvalidMigrationTime` - `migrate_at` is not in the future
+    pub fn deprecate_tier(
+        env: Env,
+        admin: Address,
+        tier_id: String,
+        replacement_tier_id: String,
+        migrate_at: u64,
+    ) -> Result<(), ModuleError> {
+        SubscriptionContract::deprecate_tier(env, admin, tier_id, replacement_tier_id, migrate_at)
+    }
+
+    /// Returns a tier's deprecation plan, if it has one.
+    pub fn get_tier_deprecation(env: Env, tier_id: String) -> Option<TierDeprecation> {
+        SubscriptionContract::get_tier_deprecation(env, tier_id)
+    }
+
+    /// Moves up to `limit` (capped at 25) remaining subscribers of a
+    /// deprecated tier to its replacement. Admin only. Call repeatedly until
+    /// it returns 0.
+    ///
+    /// Subscribers keep their locked price when the deprecated tier
+    /// grandfathers them, and otherwise lock in the replacement's price.
+    ///
+    /// # Returns
+    /// * Number of subscriptions still on the deprecated tier
+    ///
+    /// # Errors
+    /// * `AdminNotSet` / `Unauthorized` - Auth failure
+    /// * `TierChangeError::TierNotDeprecated` - Tier is not deprecated
+    /// * `TierChangeError::MigrationNotDue` - The migration time has not been reached
+    pub fn migrate_deprecated_tier(
+        env: Env,
+        admin: Address,
+        tier_id: String,
+        limit: u32,
+    ) -> Result<u32, ModuleError> {
+        SubscriptionContract::migrate_deprecated_tier(env, admin, tier_id, limit)
+    }
+
+    /// Returns `true` if the tier keeps existing subscribers on their locked price.
+    pub fn is_tier_grandfathered(env: Env, tier_id: String) -> bool {
+        SubscriptionContract::is_tier_grandfathered(&env, &tier_id)
+    }
+
+    /// Gets the USDC price due on the next renewal of a subscription.
+    ///
+    /// Returns the locked price while the tier grandfathers existing
+    /// subscribers, otherwise the tier's current price; `None` for legacy
+    /// subscriptions without a tier.
+    ///
+    /// # Errors
+    /// * `SubscriptionNotFound` - No subscription with this ID
+    /// * `TierNotFound` - The subscription's tier no longer exists
+    pub fn get_renewal_price(env: Env, subscription_id: String) -> Result<Option<i128>, Error> {
+        let subscription = SubscriptionContract::get_subscription(env.clone(), subscription_id)?;
+        SubscriptionContract::renewal_price(&env, &subscription)
+    }
+
+    /// Migrates a grandfathered subscription onto the tier's current price. Admin only.
+    ///
+    /// The new price takes effect from the next renewal; a `price_mig` notice
+    /// event carries the old price, new price and effective timestamp.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `admin` - Admin address (must be authorized)
+    /// * `subscription_id` - Subscription to migrate
+    ///
+    /// # Errors
+    /// * `Unauthorized` - Caller is not the admin
+    /// * `SubscriptionNotFound` - No subscription with this ID
+    /// * `TierNotFound` - The subscription has no (existing) tier
+    pub fn migrate_subscription_pricing(
+        env: Env,
+        admin: Address,
+        subscription_id: String,
+    ) -> Result<i128, Error> {
+        SubscriptionContract::migrate_subscription_pricing(env, admin, subscription_id)
+    }
+
+    // ============================================================================
+    // Subscription with Tier Support Endpoints
+    // ============================================================================
+
+    /// Creates a subscription with tier support.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `id` - Unique subscription identifier
+    /// * `user` - User address
+    /// * `payment_token` - Token used for payment
+    /// * `tier_id` - ID of the tier to subscribe to
+    /// * `billing_cycle` - Monthly or Annual billing
+    /// * `promo_code` - Optional promotion code for discounts
+    pub fn create_subscription_with_tier(
+        env: Env,
+        id: String,
+        user: Address,
+        payment_token: Address,
+        tier_id: String,
+        billing_cycle: BillingCycle,
+        promo_code: Option<String>,
+    ) -> Result<(), ModuleError> {
+        SubscriptionContract::create_subscription_with_tier(
+            env,
+            id,
+            user,
+            payment_token,
+            tier_id,
+            billing_cycle,
+            promo_code,
+        )
+    }
+
+    /// Gets detailed subscription info including tier details.
+    pub fn get_user_subscription_info(
+        env: Env,
+        subscription_id: String,
+    ) -> Result<UserSubscriptionInfo, Error> {
+        SubscriptionContract::get_user_subscription_info(env, subscription_id)
+    }
+
+    /// Returns every subscription created for `user`, oldest first, with
+    /// their effective status. Cancelled subscriptions are included.
+    pub fn get_subscriptions_for_user(env: Env, user: Address) -> Vec<Subscription> {
+        SubscriptionContract::get_subscriptions_for_user(env, user)
+    }
+
+    /// Returns a page of the subscriptions currently on a tier, in the order
+    /// they joined it. Cancelled and lapsed subscriptions leave the tier's
+    /// list, and tier changes move subscriptions between lists.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `tier_id` - Tier to list
+    /// * `offset` - Index of the first subscription to return
+    /// * `limit` - Maximum subscriptions to return (capped at 50)
+    pub fn get_subscribers_for_tier(
+        env: Env,
+        tier_id: String,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<Subscription> {
+        SubscriptionContract::get_subscribers_for_tier(env, tier_id, offset, limit)
+    }
+
+    // ============================================================================
+    // Installment Plan Endpoints
+    // ============================================================================
+
+    /// Creates an annual subscription paid in `installment_count` installments.
+    /// The annual price plus fees is split evenly and the first installment
+    /// is charged immediately. The subscription expires one installment
+    /// period after the last payment.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `id` - Subscription ID
+    /// * `user` - Subscriber (must be authorized)
+    /// * `payment_token` - Accepted payment asset
+    /// * `tier_id` - Tier whose annual price is split
+    /// * `installment_count` - Number of installments (2-12)
+    ///
+    /// # Errors
+    /// * `InstallmentError::InvalidInstallmentCount` - Installment count out of range
+    /// * `SubscriptionAlreadyExists` - Subscription ID already in use
+    /// * `TierNotFound` / `TierNotActive` - Tier unavailable
+    /// * `InvalidPaymentToken` - Asset not accepted
+    pub fn create_subscription_installments(
+        env: Env,
+        id: String,
+        user: Address,
+        payment_token: Address,
+        tier_id: String,
+        installment_count: u32,
+    ) -> Result<InstallmentPlan, ModuleError> {
+        InstallmentModule::create_subscription_installments(
+            env,
+            id,
+            user,
+            payment_token,
+            tier_id,
+            installment_count,
+        )
+    }
+
+    /// Pays the next installment before its deadline and extends the
+    /// subscription by one installment period.
+    ///
+    /// # Errors
+    /// * `InstallmentError::PlanNotFound` - No installment plan for this subscription
+    /// * `InstallmentError::PlanSuspended` - The plan was suspended
+    /// * `InstallmentError::PlanPaidOff` - Every installment is already paid
+    /// * `InstallmentError::InstallmentOverdue` - The deadline has passed
+    /// * `SubscriptionNotActive` - The subscription is cancelled, paused or expired
+    pub fn pay_installment(
+        env: Env,
+        subscription_id: String,
+    ) -> Result<InstallmentPlan, ModuleError> {
+        InstallmentModule::pay_installment(env, subscription_id)
+    }
+
+    /// Suspends the subscription if its current installment is overdue.
+    /// Returns `true` if the plan was suspended by this call.
+    ///
+    /// # Errors
+    /// * `InstallmentError::PlanNotFound` - No installment plan for this subscription
+    pub fn enforce_installment_deadline(
+        env: Env,
+        subscription_id: String,
+    ) -> Result<bool, ModuleError> {
+        InstallmentModule::enforce_installment_deadline(env, subscription_id)
+    }
+
+    /// Returns the installment plan for a subscription.
+    pub fn get_installment_plan(
+        env: Env,
+        subscription_id: String,
+    ) -> Result<InstallmentPlan, ModuleError> {
+        InstallmentModule::get_installment_plan(env, subscription_id)
+    }
+
+    // ============================================================================
+    // Tier Change (Upgrade/Downgrade) Endpoints
+    // ============================================================================
+
+    /// Initiates a tier change request (upgrade or downgrade).
+    ///
+    /// # Returns
+    /// * `Ok(String)` - The change request ID
+    ///
+    /// # Errors
+    /// * `TierChangeError::ChangePending` - The subscription already has a pending
+    ///   or scheduled tier change
+    /// * `TierChangeError::TierChangeTooFrequent` - The tier-change cooldown has not elapsed
+    pub fn request_tier_change(
+        env: Env,
+        user: Address,
+        subscription_id: String,
+        new_tier_id: String,
+    ) -> Result<String, ModuleError> {
+        SubscriptionContract::request_tier_change(env, user, subscription_id, new_tier_id)
+    }
+
+    /// Processes a tier change request.
+    pub fn process_tier_change(
+        env: Env,
+        caller: Address,
+        change_request_id: String,
+        subscription_id: String,
+        payment_token: Address,
+    ) -> Result<(), ModuleError> {
+        SubscriptionContract::process_tier_change(
+            env,
+            caller,
+            change_request_id,
+            subscription_id,
+            payment_token,
+        )
+    }
+
+    /// Cancels a pending tier change request.
+    pub fn cancel_tier_change(
+        env: Env,
+        user: Address,
+        change_request_id: String,
+    ) -> Result<(), Error> {
+        SubscriptionContract::cancel_tier_change(env, user, change_request_id)
+    }
+
+    /// Schedules a downgrade to take effect at the end of the current billing
+    /// period, without proration. The change is applied on the next renewal,
+    /// which is charged at the new tier's price.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `user` - Subscription owner (must authorize)
+    /// * `subscription_id` - Subscription to downgrade
+    /// * `new_tier_id` - Lower tier to move to
+    ///
+    /// # Errors
+    /// * `Unauthorized` - Caller doesn't own the subscription
+    /// * `TierNotActive` - The new tier is not active
+    /// * `TierChangeError::NotADowngrade` - The new tier is not lower than the current one
+    /// * `TierChangeError::ChangePending` - A tier change is already pending
+    /// * `TierChangeError::DowngradeAlreadyScheduled` - A downgrade is already scheduled
+    /// * `TierChangeError::TierChangeTooFrequent` - The tier-change cooldown has not elapsed
+    pub fn schedule_tier_downgrade(
+        env: Env,
+        user: Address,
+        subscription_id: String,
+        new_tier_id: String,
+    ) -> Result<String, ModuleError> {
+        SubscriptionContract::schedule_tier_downgrade(env, user, subscription_id, new_tier_id)
+    }
+
+    /// Cancels a subscription's scheduled downgrade before it executes.
+    ///
+    /// # Errors
+    /// * `TierChangeError::NoScheduledDowngrade` - No downgrade is scheduled
+    /// * `Unauthorized` - Caller doesn't own the scheduled change
+    pub fn cancel_scheduled_downgrade(
+        env: Env,
+        user: Address,
+        subscription_id: String,
+    ) -> Result<(), ModuleError> {
+        SubscriptionContract::cancel_scheduled_downgrade(env, user, subscription_id)
+    }
+
+    /// Returns the downgrade scheduled for a subscription's period end, if any.
+    pub fn get_scheduled_tier_change(
+        env: Env,
+        subscription_id: String,
+    ) -> Option<TierChangeRequest> {
+        SubscriptionContract::get_scheduled_tier_change(env, subscription_id)
+    }
+
+    /// Previews the charge or credit a tier change would produce if requested
+    /// now. The rest of the billing period is prorated to the second: the
+    /// current tier's unused value rounds down and the new tier's cost rounds
+    /// up.
+    ///
+    /// # Errors
+    /// * `SubscriptionNotFound` - Subscription doesn't exist
+    /// * `TierNotFound` - Either tier doesn't exist
+    /// * `TierNotActive` - The new tier is not active
+    pub fn get_tier_change_quote(
+        env: Env,
+        subscription_id: String,
+        new_tier_id: String,
+    ) -> Result<TierChangeQuote, Error> {
+        SubscriptionContract::get_tier_change_quote(env, subscription_id, new_tier_id)
+    }
+
+    /// Returns a user's unspent tier-change credit in USDC base units. Credit
+    /// is issued by downgrades and spent on the next renewal or upgrade.
+    pub fn get_credit_balance(env: Env, user: Address) -> i128 {
+        SubscriptionContract::get_credit_balance(env, user)
+    }
+
+    /// Sets the minimum time between tier changes on one subscription.
+    /// Admin only; `0` disables the cooldown.
+    pub fn set_tier_change_cooldown(env: Env, admin: Address, secs: u64) -> Result<(), Error> {
+        SubscriptionContract::set_tier_change_cooldown(env, admin, secs)
+    }
+
+    /// Returns the minimum time between tier changes on one subscription.
+    pub fn get_tier_change_cooldown(env: Env) -> u64 {
+        SubscriptionContract::get_tier_change_cooldown(env)
+    }
+
+    /// Lets a subscription change tier again without waiting out the
+    /// cooldown. Admin only.
+    pub fn reset_tier_change_cooldown(
+        env: Env,
+        admin: Address,
+        subscription_id: String,
+    ) -> Result<(), Error> {
+        SubscriptionContract::reset_tier_change_cooldown(env, admin, subscription_id)
+    }
+
+    // ============================================================================
+    // Promotion Management Endpoints
+    // ============================================================================
+
+    /// Creates a promotional pricing for a tier. Admin only.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `admin` - Admin address (must be authorized)
+    /// * `params` - Promotion parameters (promo_id, tier_id, discount, dates, code, limits)
+    pub fn create_promotion(
+        env: Env,
+        admin: Address,
+        params: CreatePromotionParams,
+    ) -> Result<(), Error> {
+        SubscriptionContract::create_promotion(env, admin, params)
+    }
+
+    /// Gets a promotion by ID.
+    pub fn get_promotion(env: Env, promo_id: String) -> Result<TierPromotion, Error> {
+        SubscriptionContract::get_promotion(env, promo_id)
+    }
+
+    /// Returns a promotion's campaign counters: redemptions, discount given,
+    /// revenue from the subscriptions it brought in, and how many of those
+    /// renewed afterwards.
+    ///
+    /// # Errors
+    /// * `PromotionNotFound` - Promotion doesn't exist
+    pub fn get_promotion_analytics(
+        env: Env,
+        promo_id: String,
+    ) -> Result<PromotionAnalytics, Error> {
+        SubscriptionContract::get_promotion_analytics(env, promo_id)
+    }
+
+    // ============================================================================
+    // Feature Access Control Endpoints
+    // ============================================================================
+
+    /// Checks if a subscription has access to a specific feature.
+    pub fn check_feature_access(
+        env: Env,
+        subscription_id: String,
+        feature: TierFeature,
+    ) -> Result<bool, Error> {
+        SubscriptionContract::check_feature_access(env, subscription_id, feature)
+    }
+
+    /// Grants a feature to a single subscription until `expires_at`,
+    /// regardless of its tier. Overrides are checked before tier features.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `admin` - Admin address (must authorize)
+    /// * `subscription_id` - Subscription receiving the feature
+    /// * `feature` - Feature to grant
+    /// * `expires_at` - Timestamp after which the override lapses
+    ///
+    /// # Errors
+    /// * `Unauthorized` - Caller is not the admin
+    /// * `SubscriptionNotFound` - Subscription doesn't exist
+    /// * `InvalidExpiryDate` - `expires_at` is not in the future
+    pub fn grant_feature_override(
+        env: Env,
+        admin: Address,
+        subscription_id: String,
+        feature: TierFeature,
+        expires_at: u64,
+    ) -> Result<(), Error> {
+        SubscriptionContract::grant_feature_override(
+            env,
+            admin,
+            subscription_id,
+            feature,
+            expires_at,
+        )
+    }
+
+    /// Revokes a subscription's feature override.
+    ///
+    /// # Errors
+    /// * `Unauthorized` - Caller is not the admin
+    /// * `FeatureNotAvailable` - The subscription has no override for `feature`
+    pub fn revoke_feature_override(
+        env: Env,
+        admin: Address,
+        subscription_id: String,
+        feature: TierFeature,
+    ) -> Result<(), Error> {
+        SubscriptionContract::revoke_feature_override(env, admin, subscription_id, feature)
+    }
+
+    /// Lists a subscription's unexpired feature overrides.
+    pub fn get_feature_overrides(env: Env, subscription_id: String) -> Vec<FeatureOverride> {
+        SubscriptionContract::get_feature_overrides(env, subscription_id)
+    }
+
+    /// Enforces feature access, returns error if not available.
+    pub fn require_feature_access(
+        env: Env,
+        subscription_id: String,
+        feature: TierFeature,
+    ) -> Result<(), Error> {
+        SubscriptionContract::require_feature_access(env, subscription_id, feature)
+    }
+
+    // ============================================================================
+    // Tier Analytics Endpoints
+    // ============================================================================
+
+    /// Gets analytics for a specific tier. `churn_rate` is the percentage of
+    /// subscribers lost over the last three 30-day periods.
+    ///
+    /// # Errors
+    /// * `TierNotFound` - Tier doesn't exist
+    pub fn get_tier_analytics(env: Env, tier_id: String) -> Result<TierAnalytics, Error> {
+        SubscriptionContract::get_tier_analytics(env, tier_id)
+    }
+
+    /// Gets analytics for every tier, in creation order.
+    pub fn get_all_tier_analytics(env: Env) -> Vec<TierAnalytics> {
+        SubscriptionContract::get_all_tier_analytics(env)
+    }
+
+    /// Gets a tier's 30-day sign-up cohorts (created vs. retained) for the
+    /// periods overlapping `period`, oldest first.
+    ///
+    /// # Errors
+    /// * `TierNotFound` - Tier doesn't exist
+    /// * `InvalidDateRange` - Range is reversed or spans more than 24 periods
+    pub fn get_tier_cohorts(
+        env: Env,
+        tier_id: String,
+        period: DateRange,
+    ) -> Result<Vec<TierCohort>, Error> {
+        SubscriptionContract::get_tier_cohorts(env, tier_id, period)
+    }
+
+    /// Gets a tier's revenue per 30-day period for the periods overlapping
+    /// `period`, oldest first.
+    ///
+    /// # Errors
+    /// * `TierNotFound` - Tier doesn't exist
+    /// * `InvalidDateRange` - Range is reversed or spans more than 24 periods
+    pub fn get_tier_revenue_over_time(
+        env: Env,
+        tier_id: String,
+        period: DateRange,
+    ) -> Result<Vec<TierRevenuePoint>, Error> {
+        SubscriptionContract::get_tier_revenue_over_time(env, tier_id, period)
+    }
+
+    /// Marks an active subscription past its expiry date as expired so tier
+    /// analytics count it as churned. Callable by anyone (e.g. a keeper).
+    /// Returns `true` if the subscription was expired by this call.
+    ///
+    /// # Errors
+    /// * `SubscriptionNotFound` - Subscription doesn't exist
+    pub fn expire_subscription(env: Env, id: String) -> Result<bool, Error> {
+        SubscriptionContract::expire_subscription(env, id)
+    }
+
+    // ============================================================================
+    // Token Metadata Endpoints
+    // ============================================================================
+
+    /// Sets a new metadata schema and makes it the active version. Metadata
+    /// written through `set_token_metadata`, `update_token_metadata` and
+    /// `remove_metadata_attributes` must satisfy the active schema.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `admin` - The admin address
+    /// * `fields` - One rule per constrained attribute key (max 20)
+    ///
+    /// # Returns
+    /// * The new schema version
+    ///
+    /// # Errors
+    /// * `Unauthorized` - Caller is not the admin
+    /// * `MetadataTooManyAttributes` - More than 20 rules
+    /// * `MetadataValidationFailed` - Duplicate key or `min` above `max`
+    pub fn set_metadata_schema(
+        env: Env,
+        admin: Address,
+        fields: Vec<MetadataFieldRule>,
+    ) -> Result<u32, Error> {
+        MetadataSchemaModule::set_schema(env, admin, fields)
+    }
+
+    /// Returns the active metadata schema, if one has been set.
+    pub fn get_metadata_schema(env: Env) -> Option<MetadataSchema> {
+        MetadataSchemaModule::get_active_schema(&env)
+    }
+
+    /// Returns a specific metadata schema version.
+    pub fn get_metadata_schema_version(env: Env, version: u32) -> Option<MetadataSchema> {
+        MetadataSchemaModule::get_schema(&env, version)
+    }
+
+    /// Sets metadata for a membership token.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `caller` - The admin or the token owner (must be authorized)
+    /// * `token_id` - The token ID to set metadata for
+    /// * `description` - Token description (max 500 chars)
+    /// * `attributes` - Custom attributes map (max 20 attributes)
+    ///
+    /// # Errors
+    /// * `TokenNotFound` - Token doesn't exist
+    /// * `Unauthorized` - Caller is not admin or token owner
+    /// * `MetadataValidationFailed` - Metadata validation failed
+    pub fn set_token_metadata(
+        env: Env,
+        caller: Address,
+        token_id: BytesN<32>,
+        description: String,
+        attributes: Map<String, MetadataValue>,
+    ) -> Result<(), Error> {
+        MembershipTokenContract::set_token_metadata(env, caller, token_id, description, attributes)
+    }
+
+    /// Sets metadata for a token on the owner's behalf.
+    ///
+    /// # Errors
+    /// * `TokenNotFound` - Token doesn't exist
+    /// * `Unauthorized` - `operator` is neither the owner nor an approved operator
+    pub fn operator_set_token_metadata(
+        env: Env,
+        operator: Address,
+        token_id: BytesN<32>,
+        description: String,
+        attributes: Map<String, MetadataValue>,
+    ) -> Result<(), Error> {
+        MembershipTokenContract::operator_set_token_metadata(
+            env,
+            operator,
+            token_id,
+            description,
+            attributes,
+        )
+    }
+
+    /// Binds an off-chain content URI (membership card artwork or JSON) and
+    /// its SHA-256 hash to a token's metadata.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `caller` - The admin or the token owner (must be authorized)
+    /// * `token_id` - The token ID to bind content to
+    /// * `uri` - Off-chain content URI (max 256 chars)
+    /// * `content_hash` - SHA-256 hash of the content behind `uri`
+    ///
+    /// # Errors
+    /// * `TokenNotFound` - Token doesn't exist
+    /// * `Unauthorized` - Caller is not admin or token owner
+    /// * `MetadataNotFound` - Token has no metadata yet
+    /// * `MetadataValidationFailed` - URI exceeds maximum length
+    pub fn set_token_uri(
+        env: Env,
+        caller: Address,
+        token_id: BytesN<32>,
+        uri: String,
+        content_hash: BytesN<32>,
+    ) -> Result<(), Error> {
+        MembershipTokenContract::set_token_uri(env, caller, token_id, uri, content_hash)
+    }
+
+    /// Returns whether `content_hash` matches the hash bound to a token's
+    /// off-chain content. Returns `false` when no content has been bound.
+    ///
+    /// # Errors
+    /// * `TokenNotFound` - Token doesn't exist
+    /// * `MetadataNotFound` - Token has no metadata
+    pub fn verify_content_hash(
+        env: Env,
+        token_id: BytesN<32>,
+        content_hash: BytesN<32>,
+    ) -> Result<bool, Error> {
+        MembershipTokenContract::verify_content_hash(env, token_id, content_hash)
+    }
+
+    /// Gets metadata for a membership token.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `token_id` - The token ID to get metadata for
+    ///
+    /// # Returns
+    /// * `Ok(TokenMetadata)` - The token metadata
+    /// * `Err(Error)` - If token or metadata not found
+    pub fn get_token_metadata(env: Env, token_id: BytesN<32>) -> Result<TokenMetadata, Error> {
+        MembershipTokenContract::get_token_metadata(env, token_id)
+    }
+
+    /// Updates specific attributes in token metadata.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `token_id` - The token ID to update metadata for
+    /// * `updates` - Map of attributes to add or update
+    ///
+    /// # Errors
+    /// * `TokenNotFound` - Token doesn't exist
+    /// * `MetadataNotFound` - Metadata doesn't exist
+    /// * `Unauthorized` - Caller is not admin or token owner
+    pub fn update_token_metadata(
+        env: Env,
+        token_id: BytesN<32>,
+        updates: Map<String, MetadataValue>,
+    ) -> Result<(), Error> {
+        MembershipTokenContract::update_token_metadata(env, token_id, updates)
+    }
+
+    /// Gets the metadata update history for a token.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `token_id` - The token ID to get history for
+    ///
+    /// # Returns
+    /// * Vector of metadata updates in chronological order
+    pub fn get_metadata_history(env: Env, token_id: BytesN<32>) -> Vec<MetadataUpdate> {
+        MembershipTokenContract::get_metadata_history(env, token_id)
+    }
+
+    /// Sets how many metadata history entries are kept per token (1 to 200,
+    /// default 50). Older entries are pruned into the audit digest.
+    ///
+    /// # Errors
+    /// * `Unauthorized` - Caller is not the admin
+    /// * `MetadataValidationFailed` - Limit out of range
+    pub fn set_metadata_history_limit(env: Env, admin: Address, limit: u32) -> Result<(), Error> {
+        MembershipTokenContract::set_metadata_history_limit(env, admin, limit)
+    }
+
+    /// Returns how many metadata history entries are kept per token.
+    pub fn get_metadata_history_limit(env: Env) -> u32 {
+        MembershipTokenContract::get_metadata_history_limit(&env)
+    }
+
+    /// Prunes a token's metadata history to its `keep_last_n` newest entries.
+    ///
+    /// # Returns
+    /// * Number of entries pruned
+    ///
+    /// # Errors
+    /// * `Unauthorized` - Caller is not the admin
+    pub fn prune_metadata_history(
+        env: Env,
+        admin: Address,
+        token_id: BytesN<32>,
+        keep_last_n: u32,
+    ) -> Result<u32, Error> {
+        MembershipTokenContract::prune_metadata_history(env, admin, token_id, keep_last_n)
+    }
+
+    /// Returns the checksum and counters of a token's pruned metadata history.
+    pub fn get_pruned_metadata_history(
+        env: Env,
+        token_id: BytesN<32>,
+    ) -> Option<PrunedMetadataHistory> {
+        MembershipTokenContract::get_pruned_metadata_history(env, token_id)
+    }
+
+    /// Removes specific attributes from token metadata.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `token_id` - The token ID to remove attributes from
+    /// * `attribute_keys` - Vector of attribute keys to remove
+    pub fn remove_metadata_attributes(
+        env: Env,
+        token_id: BytesN<32>,
+        attribute_keys: Vec<String>,
+    ) -> Result<(), Error> {
+        MembershipTokenContract::remove_metadata_attributes(env, token_id, attribute_keys)
+    }
+
+    /// Queries tokens by metadata attribute.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `attribute_key` - The attribute key to search for
+    /// * `attribute_value` - The attribute value to match
+    ///
+    /// # Returns
+    /// * Vector of token IDs that have the matching attribute
+    pub fn query_tokens_by_attribute(
+        env: Env,
+        attribute_key: String,
+        attribute_value: MetadataValue,
+    ) -> Vec<BytesN<32>> {
+        MembershipTokenContract::query_tokens_by_attribute(env, attribute_key, attribute_value)
+    }
+
+    /// Queries tokens by several metadata attributes, e.g. tier=gold AND
+    /// location=lagos.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `filters` - Attribute key/value pairs to match (max 10)
+    /// * `mode` - `All` to intersect the filters, `Any` to union them
+    /// * `offset` - Number of matching token IDs to skip
+    /// * `limit` - Maximum token IDs to return (capped at 100)
+    ///
+    /// # Errors
+    /// * `MetadataTooManyAttributes` - More than 10 filters
+    pub fn query_tokens_by_attributes(
+        env: Env,
+        filters: Vec<(String, MetadataValue)>,
+        mode: MatchMode,
+        offset: u32,
+        limit: u32,
+    ) -> Result<Vec<BytesN<32>>, Error> {
+        MembershipTokenContract::query_tokens_by_attributes(env, filters, mode, offset, limit)
+    }
+
+    /// Queries tokens whose numeric attribute lies in an inclusive range,
+    /// e.g. level between 3 and 7. Results are ordered by value.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `attribute_key` - Attribute holding `MetadataValue::Number` values
+    /// * `min` - Lowest matching value
+    /// * `max` - Highest matching value
+    /// * `offset` - Number of matching token IDs to skip
+    /// * `limit` - Maximum token IDs to return (capped at 100)
+    pub fn query_tokens_by_number_range(
+        env: Env,
+        attribute_key: String,
+        min: i128,
+        max: i128,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<BytesN<32>> {
+        MembershipTokenContract::query_tokens_by_number_range(
+            env,
+            attribute_key,
+            min,
+            max,
+            offset,
+            limit,
+        )
+    }
+
+    // ============================================================================
+    // Token Renewal System Endpoints
+    // ============================================================================
+
+    /// Sets the renewal configuration. Admin only.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `grace_period_duration` - Grace period duration in seconds
+    /// * `auto_renewal_notice_days` - Days before expiry to trigger auto-renewal
+    /// * `renewals_enabled` - Whether renewals are enabled
+    ///
+    /// # Errors
+    /// * `AdminNotSet` - No admin configured
+    /// * `Unauthorized` - Caller is not admin
+    pub fn set_renewal_config(
+        env: Env,
+        grace_period_duration: u64,
+        auto_renewal_notice_days: u64,
+        renewals_enabled: bool,
+    ) -> Result<(), Error> {
+        MembershipTokenContract::set_renewal_config(
+            env,
+            grace_period_duration,
+            auto_renewal_notice_days,
+            renewals_enabled,
+        )
+    }
+
+    /// Gets the renewal configuration.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    ///
+    /// # Returns
+    /// * The renewal configuration with defaults if not set
+    pub fn get_renewal_config(env: Env) -> types::RenewalConfig {
+        MembershipTokenContract::get_renewal_config(env)
+    }
+
+    /// Overrides the global renewal config's grace period for one tier:
+    /// duration, whether tokens in grace may be transferred, and which
+    /// features a lapsed subscription keeps during grace. Admin only.
+    ///
+    /// # Errors
+    /// * `AdminNotSet` - No admin configured
+    /// * `Unauthorized` - Caller is not admin
+    /// * `TierNotFound` - No such tier
+    pub fn set_tier_grace_policy(
+        env: Env,
+        admin: Address,
+        tier_id: String,
+        policy: GracePolicy,
+    ) -> Result<(), Error> {
+        MembershipTokenContract::set_tier_grace_policy(env, admin, tier_id, policy)
+    }
+
+    /// Removes a tier's grace-period policy. Admin only.
+    ///
+    /// # Errors
+    /// * `AdminNotSet` - No admin configured
+    /// * `Unauthorized` - Caller is not admin
+    /// * `TierNotFound` - The tier has no grace-period policy
+    pub fn remove_tier_grace_policy(
+        env: Env,
+        admin: Address,
+        tier_id: String,
+    ) -> Result<(), Error> {
+        MembershipTokenContract::remove_tier_grace_policy(env, admin, tier_id)
+    }
+
+    /// Gets the grace-period policy in force for a tier, falling back to the
+    /// global renewal config with transfers blocked and features suspended.
+    pub fn get_grace_policy(env: Env, tier_id: String) -> GracePolicy {
+        MembershipTokenContract::resolve_grace_policy(&env, Some(&tier_id))
+    }
+
+    /// Renews a membership token with payment validation and tier pricing.
+    /// The charge is the tier price plus the owner's fees.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `id` - Token ID to renew
+    /// * `payment_token` - Payment token address (must be USDC)
+    /// * `tier_id` - Tier ID for pricing lookup
+    /// * `billing_cycle` - Billing cycle (Monthly or Annual)
+    ///
+    /// # Errors
+    /// * `TokenNotFound` - Token doesn't exist
+    /// * `RenewalNotAllowed` - Renewals are disabled
+    /// * `TierNotFound` - Tier doesn't exist
+    /// * `InvalidPaymentAmount` - Invalid payment amount
+    /// * `InvalidPaymentToken` - Invalid payment token
+    /// * `Unauthorized` - Caller is not token owner
+    /// * `PauseError::ContractPaused` / `PauseError::TokenOpsPaused` - Token operations paused
+    pub fn renew_token(
+        env: Env,
+        id: BytesN<32>,
+        payment_token: Address,
+        tier_id: String,
+        billing_cycle: BillingCycle,
+    ) -> Result<(), ModuleError> {
+        MembershipTokenContract::renew_token(env, id, payment_token, tier_id, billing_cycle)
+    }
+
+    /// Renews a token on the owner's behalf. Same rules and errors as
+    /// `renew_token`, with `Unauthorized` if `operator` is neither the owner
+    /// nor an approved operator.
+    pub fn operator_renew_token(
+        env: Env,
+        operator: Address,
+        id: BytesN<32>,
+        payment_token: Address,
+        tier_id: String,
+        billing_cycle: BillingCycle,
+    ) -> Result<(), ModuleError> {
+        MembershipTokenContract::operator_renew_token(
+            env,
+            operator,
+            id,
+            payment_token,
+            tier_id,
+            billing_cycle,
+        )
+    }
+
+    /// Gets the renewal history for a token.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `token_id` - Token ID
+    ///
+    /// # Returns
+    /// * Vector of renewal history entries
+    pub fn get_renewal_history(env: Env, token_id: BytesN<32>) -> Vec<types::RenewalHistory> {
+        MembershipTokenContract::get_renewal_history(env, token_id)
+    }
+
+    /// Checks and applies grace period to an expired token.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `id` - Token ID
+    ///
+    /// # Returns
+    /// * Updated token if grace period was applied
+    pub fn check_and_apply_grace_period(
+        env: Env,
+        id: BytesN<32>,
+    ) -> Result<MembershipToken, Error> {
+        MembershipTokenContract::check_and_apply_grace_period(env, id)
+    }
+
+    /// Sets auto-renewal settings for a user's token.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `token_id` - Token ID to enable auto-renewal for
+    /// * `enabled` - Whether to enable auto-renewal
+    /// * `payment_token` - Payment token to use for auto-renewal
+    /// * `max_amount` - Most one auto-renewal may charge (0 = no cap)
+    ///
+    /// Each call is recorded as an explicit consent. A renewal priced above
+    /// `max_amount` waits for consent instead of entering the grace period.
+    ///
+    /// # Errors
+    /// * `TokenNotFound` - No such token
+    /// * `InvalidPaymentAmount` - `max_amount` is negative
+    pub fn set_auto_renewal(
+        env: Env,
+        token_id: BytesN<32>,
+        enabled: bool,
+        payment_token: Address,
+        max_amount: i128,
+    ) -> Result<(), Error> {
+        MembershipTokenContract::set_auto_renewal(env, token_id, enabled, payment_token, max_amount)
+    }
+
+    /// Gets the auto-renewal consents recorded for a token, oldest first.
+    pub fn get_renewal_consents(env: Env, token_id: BytesN<32>) -> Vec<RenewalConsent> {
+        MembershipTokenContract::get_renewal_consents(env, token_id)
+    }
+
+    /// Gets the auto-renewal of a token waiting for consent to a price
+    /// above the member's cap, if any.
+    pub fn get_pending_renewal_consent(
+        env: Env,
+        token_id: BytesN<32>,
+    ) -> Option<PendingRenewalConsent> {
+        MembershipTokenContract::get_pending_renewal_consent(env, token_id)
+    }
+
+    /// Lists tokens a keeper can auto-renew now: active, inside their
+    /// renewal window, with auto-renewal enabled and no pending price
+    /// consent. `limit` is capped at 50.
+    pub fn list_auto_renewal_candidates(env: Env, offset: u32, limit: u32) -> Vec<BytesN<32>> {
+        MembershipTokenContract::list_auto_renewal_candidates(env, offset, limit)
+    }
+
+    /// Gets when a token's renewal window opens and closes and when its
+    /// grace period ends.
+    pub fn get_renewal_window(env: Env, token_id: BytesN<32>) -> Result<RenewalWindow, Error> {
+        MembershipTokenContract::get_renewal_window(env, token_id)
+    }
+
+    /// Gets auto-renewal settings for a user.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `user` - User address
+    ///
+    /// # Returns
+    /// * Auto-renewal settings or None if not set
+    pub fn get_auto_renewal_settings(
+        env: Env,
+        user: Address,
+    ) -> Option<types::AutoRenewalSettings> {
+        MembershipTokenContract::get_auto_renewal_settings(env, user)
+    }
+
+    /// Checks if a token is eligible for auto-renewal.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `id` - Token ID
+    ///
+    /// # Returns
+    /// * True if token is within auto-renewal window
+    pub fn check_auto_renewal_eligibility(env: Env, id: BytesN<32>) -> Result<bool, Error> {
+        MembershipTokenContract::check_auto_renewal_eligibility(env, id)
+    }
+
+    /// Processes auto-renewal for a token. Enters grace period on failure.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `id` - Token ID
+    ///
+    /// # Returns
+    /// * Success or error
+    pub fn process_auto_renewal(env: Env, id: BytesN<32>) -> Result<(), ModuleError> {
+        MembershipTokenContract::process_auto_renewal(env, id)
+    }
+
+    // ============================================================================
+    // Attendance Analytics Endpoints
+    // ============================================================================
+
+    /// Get attendance summary for a user within a date range.
+    ///
+    /// # Arguments
+    /// * `env` - Contract environment
+    /// * `user_id` - User address to query
+    /// * `date_range` - Date range to filter records
+    ///
+    /// # Returns
+    /// * `Ok(AttendanceSummary)` - Summary with clock-ins, clock-outs, duration stats
+    /// * `Err(Error)` - If date range is invalid or no records found
+    ///
+    /// # Errors
+    /// * `InvalidDateRange` - Start time is after end time
+    /// * `NoAttendanceRecords` - No records found for user in range
+    pub fn get_attendance_summary(
+        env: Env,
+        user_id: Address,
+        date_range: DateRange,
+    ) -> Result<AttendanceSummary, Error> {
+        AttendanceLogModule::get_attendance_summary(env, user_id, date_range)
+    }
+
+    /// Get time-based attendance records (daily, weekly, monthly).
+    ///
+    /// # Arguments
+    /// * `env` - Contract environment
+    /// * `user_id` - User address to query
+    /// * `period` - Time period for grouping (Daily, Weekly, Monthly, Custom)
+    /// * `date_range` - Date range to filter records
+    ///
+    /// # Returns
+    /// * `Ok(Vec<AttendanceLog>)` - Filtered attendance logs for the period
+    /// * `Err(Error)` - If date range is invalid or no records found
+    ///
+    /// # Errors
+    /// * `InvalidDateRange` - Start time is after end time
+    /// * `NoAttendanceRecords` - No records found for user in range
+    pub fn get_time_based_attendance(
+        env: Env,
+        user_id: Address,
+        period: TimePeriod,
+        date_range: DateRange,
+    ) -> Result<Vec<AttendanceLog>, Error> {
+        AttendanceLogModule::get_time_based_attendance(env, user_id, period, date_range)
+    }
+
+    /// Calculate attendance frequency for a user.
+    ///
+    /// # Arguments
+    /// * `env` - Contract environment
+    /// * `user_id` - User address to query
+    /// * `date_range` - Date range to analyze
+    ///
+    /// # Returns
+    /// * `Ok(AttendanceFrequency)` - Frequency metrics including total, average daily
+    /// * `Err(Error)` - If date range is invalid or no records found
+    ///
+    /// # Errors
+    /// * `InvalidDateRange` - Start time is after end time
+    /// * `NoAttendanceRecords` - No records found for user in range
+    pub fn calculate_attendance_frequency(
+        env: Env,
+        user_id: Address,
+        date_range: DateRange,
+    ) -> Result<AttendanceFrequency, Error> {
+        AttendanceLogModule::calculate_attendance_frequency(env, user_id, date_range)
+    }
+
+    /// Get comprehensive user attendance statistics.
+    ///
+    /// # Arguments
+    /// * `env` - Contract environment
+    /// * `user_id` - User address to query
+    /// * `date_range` - Optional date range (None for all-time stats)
+    ///
+    /// # Returns
+    /// * `Ok(UserAttendanceStats)` - Comprehensive stats including total hours,
+    ///   average attendance, session counts, and date ranges
+    /// * `Err(Error)` - If date range is invalid or no records found
+    ///
+    /// # Errors
+    /// * `InvalidDateRange` - Start time is after end time (if range provided)
+    /// * `NoAttendanceRecords` - No records found for user
+    pub fn get_user_statistics(
+        env: Env,
+        user_id: Address,
+        date_range: Option<DateRange>,
+    ) -> Result<UserAttendanceStats, Error> {
+        AttendanceLogModule::get_user_statistics(env, user_id, date_range)
+    }
+
+    /// Analyze peak attendance hours for a user.
+    ///
+    /// # Arguments
+    /// * `env` - Contract environment
+    /// * `user_id` - User address to query
+    /// * `date_range` - Date range to analyze
+    ///
+    /// # Returns
+    /// * `Ok(Vec<PeakHourData>)` - Peak hour analysis showing attendance count
+    ///   and percentage per hour
+    /// * `Err(Error)` - If date range is invalid or no records found
+    ///
+    /// # Errors
+    /// * `InvalidDateRange` - Start time is after end time
+    /// * `NoAttendanceRecords` - No records found for user in range
+    pub fn analyze_peak_hours(
+        env: Env,
+        user_id: Address,
+        date_range: DateRange,
+    ) -> Result<Vec<PeakHourData>, Error> {
+        AttendanceLogModule::analyze_peak_hours(env, user_id, date_range)
+    }
+
+    /// Analyze attendance patterns by day of week.
+    ///
+    /// # Arguments
+    /// * `env` - Contract environment
+    /// * `user_id` - User address to query
+    /// * `date_range` - Date range to analyze
+    ///
+    /// # Returns
+    /// * `Ok(Vec<DayPattern>)` - Day patterns showing attendance distribution
+    ///   across days of the week with counts and percentages
+    /// * `Err(Error)` - If date range is invalid or no records found
+    ///
+    /// # Errors
+    /// * `InvalidDateRange` - Start time is after end time
+    /// * `NoAttendanceRecords` - No records found for user in range
+    pub fn analyze_day_patterns(
+        env: Env,
+        user_id: Address,
+        date_range: DateRange,
+    ) -> Result<Vec<DayPattern>, Error> {
+        AttendanceLogModule::analyze_day_patterns(env, user_id, date_range)
+    }
+
+    /// Calculate total hours from seconds.
+    ///
+    /// # Arguments
+    /// * `total_seconds` - Total seconds to convert
+    ///
+    /// # Returns
+    /// * Total hours (rounded down)
+    pub fn calculate_total_hours(total_seconds: u64) -> u64 {
+        AttendanceLogModule::calculate_total_hours(total_seconds)
+    }
+
+    /// Calculate average daily attendance for a user.
+    ///
+    /// # Arguments
+    /// * `env` - Contract environment
+    /// * `user_id` - User address to query
+    /// * `date_range` - Date range to analyze
+    ///
+    /// # Returns
+    /// * `Ok(u64)` - Average daily attendance count
+    /// * `Err(Error)` - If date range is invalid or no records found
+    ///
+    /// # Errors
+    /// * `InvalidDateRange` - Start time is after end time
+    /// * `NoAttendanceRecords` - No records found for user in range
+    pub fn get_avg_daily_attendance(
+        env: Env,
+        user_id: Address,
+        date_range: DateRange,
+    ) -> Result<u64, Error> {
+        AttendanceLogModule::calculate_average_daily_attendance(env, user_id, date_range)
+    }
+
+    // ============================================================================
+    // Emergency Pause Endpoints
+    // ============================================================================
+
+    /// Immediately halts all token operations (issue, transfer, renew).
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `caller` - Admin or guardian address (must be authorized)
+    /// * `reason` - Human-readable reason for the pause
+    /// * `auto_unpause_after` - Optional seconds until the contract auto-resumes.
+    ///   Pass `None` for an indefinite pause that requires an explicit unpause call.
+    /// * `time_lock_duration` - Optional minimum seconds before a manual unpause is
+    ///   allowed. Use this during security incidents to prevent an attacker from
+    ///   reversing the pause with a compromised admin key. Pass `None` for no lock.
+    ///   Only the admin may set a time lock.
+    ///
+    /// # Errors
+    /// * `AdminNotSet` - No admin has been configured
+    /// * `Unauthorized` - Caller is neither the admin nor a guardian, or a
+    ///   guardian passed a time lock
+    pub fn emergency_pause(
+        env: Env,
+        caller: Address,
+        reason: Option<String>,
+        auto_unpause_after: Option<u64>,
+        time_lock_duration: Option<u64>,
+    ) -> Result<(), Error> {
+        MembershipTokenContract::emergency_pause(
+            env,
+            caller,
+            reason,
+            auto_unpause_after,
+            time_lock_duration,
+        )
+    }
+
+    /// Lifts an active emergency pause and restores normal contract operation.
+    ///
+    /// The time lock (if any) must have elapsed before this call succeeds.
+    ///
+    /// # Errors
+    /// * `AdminNotSet` - No admin has been configured
+    /// * `Unauthorized` - Caller is not the admin
+    /// * `PauseError::TimeLockActive` - The mandatory lock window has not yet elapsed
+    pub fn emergency_unpause(env: Env, admin: Address) -> Result<(), ModuleError> {
+        MembershipTokenContract::emergency_unpause(env, admin)
+    }
+
+    /// Returns `true` if the contract is currently globally paused.
+    ///
+    /// Respects time-based auto-unpause: returns `false` once
+    /// `auto_unpause_at` has passed, even before an explicit unpause call.
+    pub fn is_contract_paused(env: Env) -> bool {
+        MembershipTokenContract::is_contract_paused(env)
+    }
+
+    /// Returns the full emergency pause state for inspection.
+    pub fn get_emergency_pause_state(env: Env) -> EmergencyPauseState {
+        MembershipTokenContract::get_emergency_pause_state(env)
+    }
+
+    /// Returns a page of the global emergency pause history, oldest first.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `offset` - Index of the first record to return
+    /// * `limit` - Maximum records to return (capped at 50)
+    pub fn get_emergency_pause_history(
+        env: Env,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<GlobalPauseRecord> {
+        MembershipTokenContract::get_emergency_pause_history(env, offset, limit)
+    }
+
+    /// Pauses all operations for a specific token.
+    ///
+    /// The per-token pause is independent of the global pause: either one is
+    /// sufficient to block transfers and renewals on that token.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `caller` - Admin or guardian address (must be authorized)
+    /// * `token_id` - The token to pause
+    /// * `reason` - Human-readable reason for the pause
+    /// * `auto_unpause_after` - Seconds until the token resumes on its own
+    /// * `time_lock_duration` - Seconds before the admin may unpause (admin only)
+    ///
+    /// # Errors
+    /// * `AdminNotSet` - No admin has been configured
+    /// * `Unauthorized` - Caller is neither the admin nor a guardian, or a
+    ///   guardian set a time lock
+    /// * `TokenNotFound` - The specified token does not exist
+    pub fn pause_token_operations(
+        env: Env,
+        caller: Address,
+        token_id: BytesN<32>,
+        reason: Option<String>,
+        auto_unpause_after: Option<u64>,
+        time_lock_duration: Option<u64>,
+    ) -> Result<(), Error> {
+        MembershipTokenContract::pause_token_operations(
+            env,
+            caller,
+            token_id,
+            reason,
+            auto_unpause_after,
+            time_lock_duration,
+        )
+    }
+
+    /// Resumes operations for a previously paused token.
+    ///
+    /// # Errors
+    /// * `AdminNotSet` - No admin has been configured
+    /// * `Unauthorized` - Caller is not the admin
+    /// * `TokenNotFound` - The specified token does not exist
+    /// * `PauseError::TimeLockActive` - The token's time lock has not yet elapsed
+    pub fn unpause_token_operations(
+        env: Env,
+        admin: Address,
+        token_id: BytesN<32>,
+    ) -> Result<(), ModuleError> {
+        MembershipTokenContract::unpause_token_operations(env, admin, token_id)
+    }
+
+    /// Pauses a set of tokens, e.g. during an incident. A token that cannot be
+    /// paused is reported in its result and does not stop the others.
+    ///
+    /// # Arguments
+    /// * `caller` - Admin or guardian address (must be authorized)
+    /// * `token_ids` - Tokens to pause (at most 50)
+    /// * `reason` - Human-readable reason recorded on each pause
+    ///
+    /// # Errors
+    /// * `AdminNotSet` - No admin has been configured
+    /// * `Unauthorized` - Caller is neither the admin nor a guardian, or the
+    ///   batch is empty or too large
+    pub fn batch_pause_token_operations(
+        env: Env,
+        caller: Address,
+        token_ids: Vec<BytesN<32>>,
+        reason: Option<String>,
+    ) -> Result<Vec<BatchPauseResult>, Error> {
+        MembershipTokenContract::batch_pause_token_operations(env, caller, token_ids, reason)
+    }
+
+    /// Pauses every token currently held by `owner`.
+    ///
+    /// # Errors
+    /// * `AdminNotSet` - No admin has been configured
+    /// * `Unauthorized` - Caller is neither the admin nor a guardian
+    pub fn pause_tokens_by_owner(
+        env: Env,
+        caller: Address,
+        owner: Address,
+        reason: Option<String>,
+    ) -> Result<Vec<BatchPauseResult>, Error> {
+        MembershipTokenContract::pause_tokens_by_owner(env, caller, owner, reason)
+    }
+
+    /// Unpauses a set of tokens. A token that cannot be unpaused, e.g. one
+    /// still under its time lock, is reported in its result.
+    ///
+    /// # Errors
+    /// * `AdminNotSet` - No admin has been configured
+    /// * `Unauthorized` - Caller is not the admin, or the batch is empty or
+    ///   too large
+    pub fn batch_unpause_token_operations(
+        env: Env,
+        admin: Address,
+        token_ids: Vec<BytesN<32>>,
+    ) -> Result<Vec<BatchPauseResult>, Error> {
+        MembershipTokenContract::batch_unpause_token_operations(env, admin, token_ids)
+    }
+
+    /// Unpauses every token currently held by `owner`.
+    ///
+    /// # Errors
+    /// * `AdminNotSet` - No admin has been configured
+    /// * `Unauthorized` - Caller is not the admin
+    pub fn unpause_tokens_by_owner(
+        env: Env,
+        admin: Address,
+        owner: Address,
+    ) -> Result<Vec<BatchPauseResult>, Error> {
+        MembershipTokenContract::unpause_tokens_by_owner(env, admin, owner)
+    }
+
+    /// Returns `true` if the specific token's operations are currently paused.
+    pub fn is_token_paused(env: Env, token_id: BytesN<32>) -> bool {
+        MembershipTokenContract::is_token_paused(env, token_id)
+    }
+
+    /// Returns up to `limit` pause records of `token_id` starting at
+    /// `offset`, oldest first.
+    ///
+    /// # Arguments
+    /// * `token_id` - The token to report on
+    /// * `offset` - Index of the first record to return
+    /// * `limit` - Maximum records to return (capped at 50)
+    pub fn get_token_pause_history(
+        env: Env,
+        token_id: BytesN<32>,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<TokenPauseRecord> {
+        MembershipTokenContract::get_token_pause_history(env, token_id, offset, limit)
+    }
+
+    // ============================================================================
+    // Token Staking Endpoints
+    // ============================================================================
+
+    /// Initialise or update the global staking configuration. Admin only.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `admin` - Admin address (must be authorized)
+    /// * `config` - New staking configuration
+    ///
+    /// # Errors
+    /// * `AdminNotSet` - No admin has been configured
+    /// * `Unauthorized` - Caller is not the admin
+    /// * `StakingError::InvalidConfig` - Penalty or slash cap exceeds 100 %
+    pub fn set_staking_config(
+        env: Env,
+        admin: Address,
+        config: StakingConfig,
+    ) -> Result<(), ModuleError> {
+        StakingModule::set_staking_config(env, admin, config)
+    }
+
+    /// Create a new staking tier. Admin only.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `admin` - Admin address (must be authorized)
+    /// * `tier` - Staking tier definition
+    ///
+    /// # Errors
+    /// * `AdminNotSet` / `Unauthorized` - Auth failure
+    /// * `StakingError::TierAlreadyExists` - A tier with the same ID already exists
+    /// * `StakingError::InvalidTier` - Invalid tier parameters
+    pub fn create_staking_tier(
+        env: Env,
+        admin: Address,
+        tier: StakingTier,
+    ) -> Result<(), ModuleError> {
+        StakingModule::create_staking_tier(env, admin, tier)
+    }
+
+    /// Lock tokens into the specified staking tier.
+    ///
+    /// Requires the caller to have approved a token transfer from their wallet
+    /// to this contract (via the staking token's `approve` method) before calling.
+    ///
+    /// If the caller already has an active stake in the same tier, the amounts
+    /// are combined and the lock window resets.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `staker` - Staker address (must be authorized)
+    /// * `tier_id` - Staking tier to lock into
+    /// * `amount` - Number of tokens to lock
+    ///
+    /// # Errors
+    /// * `StakingError::StakingDisabled` - Staking is disabled
+    /// * `StakingError::TierNotFound` - Tier ID does not exist
+    /// * `StakingError::BelowMinimumStake` - Amount below tier minimum
+    /// * `StakingError::TierMismatch` - Caller already has a stake in a different tier
+    pub fn stake_tokens(
+        env: Env,
+        staker: Address,
+        tier_id: String,
+        amount: i128,
+    ) -> Result<(), ModuleError> {
+        StakingModule::stake_tokens(env, staker, tier_id, amount)
+    }
+
+    /// Unlock tokens after the lock period has elapsed.
+    ///
+    /// Pending rewards are calculated and transferred together with the principal.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `staker` - Staker address (must be authorized)
+    ///
+    /// # Errors
+    /// * `StakingError::StakeNotFound` - No active stake found
+    /// * `StakingError::StillLocked` - Lock period has not elapsed yet
+    /// * `StakingError::CooldownRequired` - An unstake cooldown is configured
+    pub fn unstake_tokens(env: Env, staker: Address) -> Result<(), ModuleError> {
+        StakingModule::unstake_tokens(env, staker)
+    }
+
+    /// Start the unstake cooldown for a stake whose lock has expired.
+    ///
+    /// Only available when `unstake_cooldown` is configured. Rewards stop
+    /// accruing once the cooldown starts.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `staker` - Staker address (must be authorized)
+    ///
+    /// # Errors
+    /// * `StakingError::CooldownNotEnabled` - The unstake cooldown is disabled
+    /// * `StakingError::StakeNotFound` - No active stake found
+    /// * `StakingError::StillLocked` - Lock period has not elapsed yet
+    /// * `StakingError::UnstakeAlreadyRequested` - An unstake is already pending
+    pub fn request_unstake(env: Env, staker: Address) -> Result<UnstakeRequest, ModuleError> {
+        StakingModule::request_unstake(env, staker)
+    }
+
+    /// Cancel a pending unstake. The stake resumes accruing rewards; the
+    /// time spent in cooldown is not rewarded.
+    ///
+    /// # Errors
+    /// * `StakingError::UnstakeRequestNotFound` - No unstake is pending
+    pub fn cancel_unstake_request(env: Env, staker: Address) -> Result<(), ModuleError> {
+        StakingModule::cancel_unstake_request(env, staker)
+    }
+
+    /// Withdraw a stake once its unstake cooldown has elapsed, together with
+    /// the rewards accrued up to the request.
+    ///
+    /// # Errors
+    /// * `StakingError::UnstakeRequestNotFound` - No unstake is pending
+    /// * `StakingError::CooldownActive` - The cooldown has not elapsed yet
+    pub fn withdraw_unstaked(env: Env, staker: Address) -> Result<(), ModuleError> {
+        StakingModule::withdraw_unstaked(env, staker)
+    }
+
+    /// Get the pending cooldown unstake for a staker, if any.
+    pub fn get_unstake_request(env: Env, staker: Address) -> Option<UnstakeRequest> {
+        StakingModule::get_unstake_request(env, staker)
+    }
+
+    /// Emergency unstake: return tokens immediately with a penalty deducted.
+    ///
+    /// No staking rewards are paid. The penalty stays in the contract.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `staker` - Staker address (must be authorized)
+    ///
+    /// # Errors
+    /// * `StakingError::StakeNotFound` - No active stake found
+    pub fn emergency_unstake(env: Env, staker: Address) -> Result<(), ModuleError> {
+        StakingModule::emergency_unstake(env, staker)
+    }
+
+    /// Slash part of an active stake into the treasury for a policy
+    /// violation. Admin only; also requires the registered access_control
+    /// contract's authorization when one is set.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `admin` - Admin address (must be authorized)
+    /// * `staker` - Staker to slash
+    /// * `bps` - Share of the stake to slash, up to the configured maximum
+    /// * `reason` - Why the stake is slashed (required, max 200 chars)
     ///
     /// # Returns
-    /// * Vector of metadata updates in chronological order
-    pub fn get_metadata_history(env: Env, token_id: BytesN<32>) -> Vec<MetadataUpdate> {
-        MembershipTokenContract::get_metadata_history(env, token_id)
+    /// * The amount slashed
+    ///
+    /// # Errors
+    /// * `Unauthorized` - Caller is not the admin
+    /// * `StakingError::SlashExceedsLimit` - `bps` is zero or above the configured maximum
+    /// * `StakingError::InvalidSlashReason` - Reason is empty or too long
+    /// * `StakingError::StakeNotFound` - No active stake found
+    pub fn slash_stake(
+        env: Env,
+        admin: Address,
+        staker: Address,
+        bps: u32,
+        reason: String,
+    ) -> Result<i128, ModuleError> {
+        StakingModule::slash_stake(env, admin, staker, bps, reason)
+    }
+
+    /// Get the slashes applied to a staker, oldest first.
+    pub fn get_slash_history(env: Env, staker: Address) -> Vec<SlashRecord> {
+        StakingModule::get_slash_history(env, staker)
+    }
+
+    /// Get the active stake information for a staker.
+    ///
+    /// Returns `None` if the address has no active stake.
+    pub fn get_stake_info(env: Env, staker: Address) -> Option<StakeInfo> {
+        StakingModule::get_stake_info(env, staker)
+    }
+
+    /// Get all available staking tiers.
+    pub fn get_staking_tiers(env: Env) -> Vec<StakingTier> {
+        StakingModule::get_staking_tiers(env)
+    }
+
+    /// Get the global staking configuration.
+    ///
+    /// # Errors
+    /// * `StakingError::StakingNotConfigured` - Staking has not been configured yet
+    pub fn get_staking_config(env: Env) -> Result<StakingConfig, ModuleError> {
+        StakingModule::get_staking_config(env)
+    }
+
+    // =========================================================================
+    // Token Upgrade Mechanism
+    // =========================================================================
+
+    /// Initialise or update the global upgrade configuration. Admin only.
+    ///
+    /// Must be called before any upgrade functions can be used.
+    ///
+    /// # Arguments
+    /// * `env`    - The contract environment
+    /// * `admin`  - Admin address (must be authorized)
+    /// * `config` - Upgrade configuration to apply
+    ///
+    /// # Errors
+    /// * `AdminNotSet`    - No admin has been set
+    /// * `Unauthorized`   - Caller is not the admin
+    pub fn set_upgrade_config(
+        env: Env,
+        admin: Address,
+        config: UpgradeConfig,
+    ) -> Result<(), ModuleError> {
+        UpgradeModule::set_upgrade_config(env, admin, config)
+    }
+
+    /// Upgrade a single token to the next version.
+    ///
+    /// Captures a pre-upgrade snapshot for rollback, increments `current_version`,
+    /// and optionally updates `expiry_date`, `tier_id`, and `status`.
+    /// Emits a `TokenUpgraded` event on success.
+    ///
+    /// # Arguments
+    /// * `env`             - The contract environment
+    /// * `caller`          - Address triggering the upgrade (must be authorized)
+    /// * `token_id`        - ID of the token to upgrade
+    /// * `label`           - Optional human-readable version label (e.g. "v2-premium")
+    /// * `new_expiry_date` - Optional new expiry timestamp
+    /// * `new_tier_id`     - Optional new tier ID
+    /// * `new_status`      - Optional new membership status
+    ///
+    /// # Returns
+    /// The new version number on success.
+    ///
+    /// # Errors
+    /// * `AdminNotSet`                      - No admin has been set
+    /// * `UpgradeError::UpgradesDisabled`   - Upgrades are disabled
+    /// * `UpgradeError::TokenNotFound`      - Token does not exist
+    /// * `UpgradeError::Unauthorized`       - Caller is not authorised
+    pub fn upgrade_token(
+        env: Env,
+        caller: Address,
+        token_id: BytesN<32>,
+        label: Option<String>,
+        new_expiry_date: Option<u64>,
+        new_tier_id: Option<String>,
+        new_status: Option<MembershipStatus>,
+    ) -> Result<u32, ModuleError> {
+        UpgradeModule::upgrade_token(
+            env,
+            caller,
+            token_id,
+            label,
+            new_expiry_date,
+            new_tier_id,
+            new_status,
+        )
+    }
+
+    /// Upgrade multiple tokens in a single call. Admin only.
+    ///
+    /// Individual token failures do NOT abort the entire batch; they are
+    /// reported as `success: false` in the returned result list.
+    ///
+    /// # Arguments
+    /// * `env`             - The contract environment
+    /// * `admin`           - Admin address (must be authorized)
+    /// * `token_ids`       - List of token IDs to upgrade
+    /// * `label`           - Optional version label applied to all tokens
+    /// * `new_expiry_date` - Optional new expiry timestamp applied to all tokens
+    ///
+    /// # Errors
+    /// * `AdminNotSet`                    - No admin has been set
+    /// * `Unauthorized`                   - Caller is not the admin
+    /// * `UpgradeError::UpgradesDisabled` - Upgrades are disabled
+    pub fn batch_upgrade_tokens(
+        env: Env,
+        admin: Address,
+        token_ids: Vec<BytesN<32>>,
+        label: Option<String>,
+        new_expiry_date: Option<u64>,
+    ) -> Result<Vec<BatchUpgradeResult>, ModuleError> {
+        UpgradeModule::batch_upgrade_tokens(env, admin, token_ids, label, new_expiry_date)
+    }
+
+    /// Get the current version number of a token.
+    ///
+    /// # Arguments
+    /// * `env`      - The contract environment
+    /// * `token_id` - ID of the token to query
+    ///
+    /// # Errors
+    /// * `UpgradeError::TokenNotFound` - Token does not exist
+    pub fn get_token_version(env: Env, token_id: BytesN<32>) -> Result<u32, ModuleError> {
+        UpgradeModule::get_token_version(env, token_id)
+    }
+
+    /// Get the full upgrade history for a token.
+    ///
+    /// Returns an empty list if the token has never been upgraded.
+    ///
+    /// # Arguments
+    /// * `env`      - The contract environment
+    /// * `token_id` - ID of the token to query
+    pub fn get_upgrade_history(env: Env, token_id: BytesN<32>) -> Vec<UpgradeRecord> {
+        UpgradeModule::get_upgrade_history(env, token_id)
+    }
+
+    /// Roll back a token to a specific previous version. Admin only.
+    ///
+    /// The token's version number continues to increment (not reset) so the
+    /// audit trail is preserved. The state (expiry, tier, status) from the
+    /// target snapshot is restored.
+    ///
+    /// # Arguments
+    /// * `env`            - The contract environment
+    /// * `admin`          - Admin address (must be authorized)
+    /// * `token_id`       - ID of the token to roll back
+    /// * `target_version` - The version number to restore state from
+    ///
+    /// # Returns
+    /// The new (incremented) version number after rollback.
+    ///
+    /// # Errors
+    /// * `AdminNotSet`                         - No admin has been set
+    /// * `Unauthorized`                        - Caller is not the admin
+    /// * `UpgradeError::TokenNotFound`         - Token does not exist
+    /// * `UpgradeError::NoUpgradeHistory`      - No snapshot for `target_version`
+    /// * `UpgradeError::RollbackLimitExceeded` - Maximum rollback count reached
+    pub fn rollback_token_upgrade(
+        env: Env,
+        admin: Address,
+        token_id: BytesN<32>,
+        target_version: u32,
+    ) -> Result<u32, ModuleError> {
+        UpgradeModule::rollback_token_upgrade(env, admin, token_id, target_version)
+    }
+
+    /// Get the global upgrade configuration.
+    ///
+    /// # Errors
+    /// * `UpgradeError::UpgradeNotConfigured` - Upgrade system has not been configured yet
+    pub fn get_upgrade_config(env: Env) -> Result<UpgradeConfig, ModuleError> {
+        UpgradeModule::get_upgrade_config(env)
+    }
+
+    /// Get the stored state of a token version, as used by rollbacks.
+    ///
+    /// Version 0 is captured at mint; each later version is captured before
+    /// the token's first upgrade, rollback, renewal or status change at that
+    /// version. Returns `None` if the version was never reached or has been
+    /// pruned.
+    ///
+    /// # Arguments
+    /// * `env`      - The contract environment
+    /// * `token_id` - ID of the token to query
+    /// * `version`  - Version number to look up
+    pub fn get_version_snapshot(
+        env: Env,
+        token_id: BytesN<32>,
+        version: u32,
+    ) -> Option<TokenVersionSnapshot> {
+        UpgradeModule::get_version_snapshot(env, token_id, version)
+    }
+
+    /// Set how many version snapshots are kept per token. Admin only.
+    ///
+    /// Older snapshots are pruned the next time a token is snapshotted.
+    ///
+    /// # Arguments
+    /// * `env`    - The contract environment
+    /// * `admin`  - Admin address (must be authorized)
+    /// * `config` - Retention policy to apply
+    ///
+    /// # Errors
+    /// * `AdminNotSet`  - No admin has been set
+    /// * `Unauthorized` - Caller is not the admin
+    pub fn set_snapshot_retention(
+        env: Env,
+        admin: Address,
+        config: SnapshotRetentionConfig,
+    ) -> Result<(), ModuleError> {
+        UpgradeModule::set_snapshot_retention(env, admin, config)
+    }
+
+    /// Get the snapshot retention policy. Defaults to keeping every snapshot.
+    pub fn get_snapshot_retention(env: Env) -> SnapshotRetentionConfig {
+        UpgradeModule::get_snapshot_retention(&env)
+    }
+
+    /// Schedule a token upgrade to take effect at a future time. Admin only.
+    ///
+    /// Owner and attribute filters are resolved when the upgrade starts
+    /// applying. Emits a `TokenUpgradeScheduled` event.
+    ///
+    /// # Arguments
+    /// * `env`          - The contract environment
+    /// * `admin`        - Admin address (must be authorized)
+    /// * `target`       - Token IDs, or an owner or metadata attribute filter
+    /// * `effective_at` - Timestamp from which `apply_scheduled_upgrades` applies it
+    /// * `changes`      - Field changes for the new version
+    ///
+    /// # Returns
+    /// The schedule ID.
+    ///
+    /// # Errors
+    /// * `AdminNotSet`                        - No admin has been set
+    /// * `Unauthorized`                       - Caller is not the admin, or the
+    ///   token list is empty or longer than `MAX_BATCH_SIZE`
+    /// * `UpgradeError::UpgradeNotConfigured` - Upgrade system is not configured
+    /// * `UpgradeError::UpgradesDisabled`     - Upgrades are disabled
+    /// * `UpgradeError::InvalidEffectiveTime` - `effective_at` is not in the future
+    pub fn schedule_batch_upgrade(
+        env: Env,
+        admin: Address,
+        target: UpgradeTarget,
+        effective_at: u64,
+        changes: TokenUpgradeChanges,
+    ) -> Result<u64, ModuleError> {
+        UpgradeModule::schedule_batch_upgrade(env, admin, target, effective_at, changes)
+    }
+
+    /// Apply scheduled token upgrades that have come due. Callable by anyone
+    /// (e.g. a keeper).
+    ///
+    /// Upgrades at most `limit` tokens (capped at `MAX_BATCH_SIZE`); call again
+    /// to continue a partly applied schedule.
+    ///
+    /// # Returns
+    /// One result per token processed in this call.
+    ///
+    /// # Errors
+    /// * `UpgradeError::UpgradeNotConfigured` - Upgrade system is not configured
+    /// * `UpgradeError::UpgradesDisabled`     - Upgrades are disabled
+    pub fn apply_scheduled_upgrades(
+        env: Env,
+        limit: u32,
+    ) -> Result<Vec<BatchUpgradeResult>, ModuleError> {
+        UpgradeModule::apply_scheduled_upgrades(env, limit)
+    }
+
+    /// Cancel a scheduled token upgrade before its effective time. Admin only.
+    ///
+    /// # Errors
+    /// * `AdminNotSet`                           - No admin has been set
+    /// * `Unauthorized`                          - Caller is not the admin
+    /// * `UpgradeError::ScheduleNotFound`        - No pending schedule with that ID
+    /// * `UpgradeError::UpgradeAlreadyEffective` - The effective time has passed
+    pub fn cancel_scheduled_token_upgrade(
+        env: Env,
+        admin: Address,
+        schedule_id: u64,
+    ) -> Result<(), ModuleError> {
+        UpgradeModule::cancel_scheduled_token_upgrade(env, admin, schedule_id)
+    }
+
+    /// Get a scheduled token upgrade that is still pending.
+    pub fn get_scheduled_token_upgrade(
+        env: Env,
+        schedule_id: u64,
+    ) -> Option<ScheduledTokenUpgrade> {
+        UpgradeModule::get_scheduled_token_upgrade(env, schedule_id)
+    }
+
+    /// List the pending scheduled token upgrades, oldest first.
+    pub fn get_pending_token_upgrades(env: Env) -> Vec<ScheduledTokenUpgrade> {
+        UpgradeModule::get_pending_token_upgrades(env)
+    }
+
+    // ============================================================================
+    // Treasury Endpoints
+    // ============================================================================
+
+    /// Returns revenue per source for every day overlapping `period`.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `period` - Reporting window (inclusive, at most 366 days)
+    ///
+    /// # Errors
+    /// * `TreasuryError::InvalidPeriod` - `end_time` precedes `start_time` or the window is too long
+    pub fn get_revenue_breakdown(
+        env: Env,
+        period: DateRange,
+    ) -> Result<RevenueBreakdown, ModuleError> {
+        TreasuryModule::get_revenue_breakdown(env, period)
+    }
+
+    /// Returns revenue per source since deployment.
+    pub fn get_lifetime_revenue(env: Env) -> RevenueBreakdown {
+        TreasuryModule::get_lifetime_revenue(env)
+    }
+
+    /// Returns the collected revenue in `token` that can still be withdrawn.
+    pub fn get_withdrawable_revenue(env: Env, token: Address) -> i128 {
+        TreasuryModule::get_withdrawable(&env, &token)
+    }
+
+    /// Withdraws collected revenue held by the contract. Requires authorization
+    /// from both the admin and the access_control (multisig) contract.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `admin` - Admin address (must be authorized)
+    /// * `token` - Token to withdraw
+    /// * `amount` - Amount to withdraw
+    /// * `destination` - Recipient of the funds
+    ///
+    /// # Errors
+    /// * `TreasuryError::MultisigNotConfigured` - No admin, or no access_control contract registered
+    /// * `Unauthorized` - Caller is not the admin
+    /// * `InvalidPaymentAmount` - Amount is not positive
+    /// * `TreasuryError::ExceedsWithdrawable` - `amount` exceeds the withdrawable revenue or the
+    ///   contract's balance
+    pub fn withdraw_treasury(
+        env: Env,
+        admin: Address,
+        token: Address,
+        amount: i128,
+        destination: Address,
+    ) -> Result<(), ModuleError> {
+        TreasuryModule::withdraw_treasury(env, admin, token, amount, destination)
+    }
+
+    // ============================================================================
+    // Operator Endpoints
+    // ============================================================================
+
+    /// Grants the operator role to a front-desk device.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `caller` - Admin, or the registered access_control contract (must be authorized)
+    /// * `operator` - Address to grant the role to
+    ///
+    /// # Errors
+    /// * `AdminNotSet` - No admin configured
+    /// * `Unauthorized` - Caller is neither the admin nor the access_control contract
+    pub fn add_operator(env: Env, caller: Address, operator: Address) -> Result<(), Error> {
+        OperatorModule::add_operator(env, caller, operator)
+    }
+
+    /// Revokes the operator role. Activity counters are kept.
+    ///
+    /// # Errors
+    /// * `AdminNotSet` - No admin configured
+    /// * `Unauthorized` - Caller is neither the admin nor the access_control contract
+    pub fn remove_operator(env: Env, caller: Address, operator: Address) -> Result<(), Error> {
+        OperatorModule::remove_operator(env, caller, operator)
+    }
+
+    /// Returns `true` if the address currently holds the operator role.
+    pub fn is_operator(env: Env, operator: Address) -> bool {
+        OperatorModule::is_operator(&env, &operator)
+    }
+
+    /// Returns the activity counters for an operator, if it was ever added.
+    pub fn get_operator_activity(env: Env, operator: Address) -> Option<OperatorActivity> {
+        OperatorModule::get_operator_activity(&env, &operator)
+    }
+
+    /// Logs attendance on behalf of a member from an operator device.
+    ///
+    /// # Errors
+    /// * `Unauthorized` - `operator` does not hold the operator role
+    /// * `InvalidEventDetails` - Too many detail entries
+    pub fn operator_log_attendance(
+        env: Env,
+        operator: Address,
+        id: BytesN<32>,
+        user_id: Address,
+        action: AttendanceAction,
+        details: Map<String, String>,
+    ) -> Result<(), ModuleError> {
+        OperatorModule::log_attendance(env, operator, id, user_id, action, details)
+    }
+
+    /// Runs the grace-period check for a token from an operator device.
+    ///
+    /// # Errors
+    /// * `Unauthorized` - `operator` does not hold the operator role
+    /// * `TokenNotFound` - Token doesn't exist
+    /// * `GracePeriodExpired` - The token's grace period has ended
+    pub fn operator_check_grace_period(
+        env: Env,
+        operator: Address,
+        id: BytesN<32>,
+    ) -> Result<MembershipToken, Error> {
+        OperatorModule::check_and_apply_grace_period(env, operator, id)
+    }
+
+    // ============================================================================
+    // Loyalty Endpoints
+    // ============================================================================
+
+    /// Returns a member's spendable loyalty points.
+    pub fn get_points(env: Env, user: Address) -> u64 {
+        LoyaltyModule::get_account(&env, &user).points
+    }
+
+    /// Returns a member's full loyalty balance, including unapplied day credits.
+    pub fn get_loyalty_account(env: Env, user: Address) -> LoyaltyAccount {
+        LoyaltyModule::get_account(&env, &user)
+    }
+
+    /// Sets the points earned per verified session and per renewal on a tier.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `admin` - Admin address (must be authorized)
+    /// * `tier_id` - Tier to configure; an empty ID sets the default rate
+    /// * `rate` - Points per event
+    ///
+    /// # Errors
+    /// * `AdminNotSet` - No admin configured
+    /// * `Unauthorized` - Caller is not the admin
+    pub fn set_loyalty_earn_rate(
+        env: Env,
+        admin: Address,
+        tier_id: String,
+        rate: LoyaltyEarnRate,
+    ) -> Result<(), Error> {
+        LoyaltyModule::set_earn_rate(env, admin, tier_id, rate)
+    }
+
+    /// Returns the earn rate applied to a tier (the default rate if unset).
+    pub fn get_loyalty_earn_rate(env: Env, tier_id: String) -> LoyaltyEarnRate {
+        LoyaltyModule::get_earn_rate(&env, &tier_id)
+    }
+
+    /// Adds or replaces a rewards catalog entry. Set `is_active` to `false`
+    /// to withdraw a reward without losing its history.
+    ///
+    /// # Errors
+    /// * `AdminNotSet` - No admin configured
+    /// * `Unauthorized` - Caller is not the admin
+    /// * `LoyaltyError::InvalidReward` - Zero points cost or zero credit days
+    pub fn set_loyalty_reward(
+        env: Env,
+        admin: Address,
+        reward: LoyaltyReward,
+    ) -> Result<(), ModuleError> {
+        LoyaltyModule::set_reward(env, admin, reward)
+    }
+
+    /// Returns the rewards catalog, including inactive entries.
+    pub fn get_loyalty_rewards(env: Env) -> Vec<LoyaltyReward> {
+        LoyaltyModule::get_rewards(&env)
+    }
+
+    /// Redeems points for a catalog reward. The reward's day credits extend
+    /// the member's subscription on its next renewal.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `user` - Member redeeming points (must be authorized)
+    /// * `reward_id` - Catalog reward to redeem
+    ///
+    /// # Errors
+    /// * `LoyaltyError::RewardNotFound` - Reward doesn't exist or is inactive
+    /// * `LoyaltyError::InsufficientPoints` - Not enough points
+    pub fn redeem_points(
+        env: Env,
+        user: Address,
+        reward_id: String,
+    ) -> Result<LoyaltyAccount, ModuleError> {
+        LoyaltyModule::redeem_points(env, user, reward_id)
+    }
+
+    /// Returns the member's current daily check-in streak, or 0 if it lapsed.
+    pub fn get_streak(env: Env, user: Address) -> u32 {
+        LoyaltyModule::current_streak(&env, &user)
+    }
+
+    /// Returns the longest daily check-in streak the member has reached.
+    pub fn get_longest_streak(env: Env, user: Address) -> u32 {
+        LoyaltyModule::get_streak(&env, &user).longest
+    }
+
+    /// Configures the bonus points and renewal day credits granted when a
+    /// streak reaches `milestone` days.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `admin` - Admin address (must be authorized)
+    /// * `milestone` - One of 7, 30 or 90
+    /// * `bonus` - Points and day credits to grant
+    ///
+    /// # Errors
+    /// * `AdminNotSet` - No admin configured
+    /// * `Unauthorized` - Caller is not the admin
+    /// * `LoyaltyError::InvalidStreakMilestone` - Unsupported milestone
+    pub fn set_streak_bonus(
+        env: Env,
+        admin: Address,
+        milestone: u32,
+        bonus: StreakBonus,
+    ) -> Result<(), ModuleError> {
+        LoyaltyModule::set_streak_bonus(env, admin, milestone, bonus)
+    }
+
+    /// Returns the bonus configured for a streak milestone, if any.
+    pub fn get_streak_bonus(env: Env, milestone: u32) -> Option<StreakBonus> {
+        LoyaltyModule::get_streak_bonus(&env, milestone)
+    }
+
+    // ============================================================================
+    // Achievement Endpoints
+    // ============================================================================
+
+    /// Adds or replaces a badge definition and its earning criterion.
+    ///
+    /// # Errors
+    /// * `AdminNotSet` - No admin configured
+    /// * `Unauthorized` - Caller is not the admin
+    pub fn define_badge(env: Env, admin: Address, badge: BadgeDefinition) -> Result<(), Error> {
+        AchievementModule::define_badge(env, admin, badge)
+    }
+
+    /// Returns all badge definitions, including inactive ones.
+    pub fn get_badge_definitions(env: Env) -> Vec<BadgeDefinition> {
+        AchievementModule::get_badge_definitions(&env)
+    }
+
+    /// Claims a badge the member has earned. Badges cannot be transferred.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `user` - Member claiming the badge (must be authorized)
+    /// * `badge_id` - Badge to claim
+    /// * `token_id` - Optional membership token of `user`; required for
+    ///   membership-anniversary badges and receives the badge as an attribute
+    ///
+    /// # Errors
+    /// * `AchievementError::BadgeNotFound` - Badge doesn't exist or is inactive
+    /// * `TokenNotFound` / `Unauthorized` - Token missing or not owned by `user`
+    /// * `AchievementError::CriterionNotMet` - Criterion not met
+    /// * `AchievementError::BadgeAlreadyAwarded` - Badge already held
+    pub fn claim_badge(
+        env: Env,
+        user: Address,
+        badge_id: String,
+        token_id: Option<BytesN<32>>,
+    ) -> Result<Badge, ModuleError> {
+        AchievementModule::claim_badge(env, user, badge_id, token_id)
+    }
+
+    /// Grants a badge to a member without checking its criterion.
+    ///
+    /// # Errors
+    /// * `AdminNotSet` - No admin configured
+    /// * `Unauthorized` - Caller is not the admin, or the token isn't the member's
+    /// * `AchievementError::BadgeNotFound` - Badge doesn't exist or is inactive
+    /// * `AchievementError::BadgeAlreadyAwarded` - Badge already held
+    pub fn grant_badge(
+        env: Env,
+        admin: Address,
+        user: Address,
+        badge_id: String,
+        token_id: Option<BytesN<32>>,
+    ) -> Result<Badge, ModuleError> {
+        AchievementModule::grant_badge(env, admin, user, badge_id, token_id)
+    }
+
+    /// Returns the badges a member holds, in award order.
+    pub fn get_badges(env: Env, user: Address) -> Vec<Badge> {
+        AchievementModule::get_badges(&env, &user)
+    }
+
+    /// Returns `true` if the member holds the badge.
+    pub fn has_badge(env: Env, user: Address, badge_id: String) -> bool {
+        AchievementModule::has_badge(&env, &user, &badge_id)
+    }
+
+    // ============================================================================
+    // Storage Migration Endpoints
+    // ============================================================================
+
+    /// Returns the schema version the store was last finalized at. Deployments
+    /// that predate schema versioning report 0 until migrated.
+    pub fn get_storage_version(env: Env) -> u32 {
+        MigrationModule::get_storage_version(&env)
+    }
+
+    /// Returns the schema version a token was last written with.
+    pub fn get_token_schema_version(env: Env, token_id: BytesN<32>) -> u32 {
+        MigrationModule::get_token_schema_version(&env, &token_id)
+    }
+
+    /// Returns the schema version a subscription was last written with.
+    pub fn get_subscription_schema_version(env: Env, subscription_id: String) -> u32 {
+        MigrationModule::get_subscription_schema_version(&env, &subscription_id)
+    }
+
+    /// Upgrades a batch of tokens to the current schema version. Tokens that
+    /// are missing or already current are counted as skipped.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `admin` - Admin address (must be authorized)
+    /// * `token_ids` - Tokens to migrate
+    ///
+    /// # Errors
+    /// * `AdminNotSet` - No admin configured
+    /// * `Unauthorized` - Caller is not the admin, or the batch is empty or too large
+    pub fn migrate_tokens(
+        env: Env,
+        admin: Address,
+        token_ids: Vec<BytesN<32>>,
+    ) -> Result<MigrationProgress, Error> {
+        MigrationModule::migrate_tokens(env, admin, token_ids)
+    }
+
+    /// Upgrades a batch of subscriptions to the current schema version.
+    ///
+    /// # Errors
+    /// * `AdminNotSet` - No admin configured
+    /// * `Unauthorized` - Caller is not the admin, or the batch is empty or too large
+    pub fn migrate_subscriptions(
+        env: Env,
+        admin: Address,
+        subscription_ids: Vec<String>,
+    ) -> Result<MigrationProgress, Error> {
+        MigrationModule::migrate_subscriptions(env, admin, subscription_ids)
+    }
+
+    /// Returns batch migration progress for an entity kind.
+    pub fn get_migration_progress(env: Env, entity: MigrationEntity) -> MigrationProgress {
+        MigrationModule::get_migration_progress(&env, entity)
+    }
+
+    /// Marks the store as migrated to the current schema version once all
+    /// batches have run.
+    ///
+    /// # Errors
+    /// * `AdminNotSet` - No admin configured
+    /// * `Unauthorized` - Caller is not the admin
+    /// * `UpgradeError::MigrationIncomplete` - An entity kind has had no
+    ///   batch at the current version, or its last batch still upgraded
+    ///   entries
+    pub fn finalize_storage_migration(env: Env, admin: Address) -> Result<u32, ModuleError> {
+        MigrationModule::finalize_migration(env, admin)
+    }
+
+    // ============================================================================
+    // Contract Upgrade Endpoints
+    // ============================================================================
+
+    /// Schedules a WASM upgrade of this contract, executable after a 48-hour
+    /// time lock. Replaces any upgrade already scheduled.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `caller` - The registered access_control contract, or the admin if
+    ///   none is registered (must be authorized)
+    /// * `new_wasm_hash` - Hash of the already uploaded WASM
+    ///
+    /// # Errors
+    /// * `AdminNotSet` - No admin configured
+    /// * `Unauthorized` - Caller may not schedule upgrades
+    pub fn schedule_contract_upgrade(
+        env: Env,
+        caller: Address,
+        new_wasm_hash: BytesN<32>,
+    ) -> Result<ScheduledContractUpgrade, Error> {
+        ContractUpgradeModule::schedule_upgrade(env, caller, new_wasm_hash)
+    }
+
+    /// Cancels the scheduled contract upgrade.
+    ///
+    /// # Errors
+    /// * `Unauthorized` - Caller is neither the admin nor the access_control contract
+    /// * `ConfigChangeError::NoPendingChange` - No upgrade is scheduled
+    pub fn cancel_scheduled_upgrade(env: Env, caller: Address) -> Result<(), ModuleError> {
+        ContractUpgradeModule::cancel_upgrade(env, caller)
+    }
+
+    /// Executes the scheduled upgrade, returning the new contract version.
+    ///
+    /// # Errors
+    /// * `Unauthorized` - Caller is neither the admin nor the access_control contract
+    /// * `ConfigChangeError::NoPendingChange` - No upgrade is scheduled
+    /// * `ConfigChangeError::TimelockActive` - The time lock has not elapsed
+    pub fn execute_contract_upgrade(env: Env, caller: Address) -> Result<u32, ModuleError> {
+        ContractUpgradeModule::execute_upgrade(env, caller)
+    }
+
+    /// Returns the upgrade waiting for its time lock, if any.
+    pub fn get_scheduled_upgrade(env: Env) -> Option<ScheduledContractUpgrade> {
+        ContractUpgradeModule::get_scheduled_upgrade(&env)
+    }
+
+    /// Returns the contract code version (1 until the first upgrade executes).
+    pub fn get_contract_version(env: Env) -> u32 {
+        ContractUpgradeModule::get_version(&env)
+    }
+
+    // ============================================================================
+    // Guardian Endpoints
+    // ============================================================================
+
+    /// Grants the guardian role, which can trigger emergency and per-token
+    /// pauses but cannot unpause or change configuration.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `caller` - Admin, or the registered access_control contract (must be authorized)
+    /// * `guardian` - Address to grant the role to
+    ///
+    /// # Errors
+    /// * `AdminNotSet` - No admin configured
+    /// * `Unauthorized` - Caller is neither the admin nor the access_control contract
+    pub fn add_guardian(env: Env, caller: Address, guardian: Address) -> Result<(), Error> {
+        GuardianModule::add_guardian(env, caller, guardian)
+    }
+
+    /// Revokes the guardian role.
+    ///
+    /// # Errors
+    /// * `AdminNotSet` - No admin configured
+    /// * `Unauthorized` - Caller is neither the admin nor the access_control contract
+    pub fn remove_guardian(env: Env, caller: Address, guardian: Address) -> Result<(), Error> {
+        GuardianModule::remove_guardian(env, caller, guardian)
+    }
+
+    /// Returns `true` if the address currently holds the guardian role.
+    pub fn is_guardian(env: Env, address: Address) -> bool {
+        GuardianModule::is_guardian(&env, &address)
+    }
+
+    /// Returns all current guardians.
+    pub fn get_guardians(env: Env) -> Vec<Address> {
+        GuardianModule::get_guardians(&env)
+    }
+
+    // ============================================================================
+    // Bundle Endpoints
+    // ============================================================================
+
+    /// Defines a bundle of a subscription tier, a staking reward bonus and
+    /// extra features at a combined USDC price.
+    ///
+    /// # Errors
+    /// * `AdminNotSet` - No admin configured
+    /// * `Unauthorized` - Caller is not the admin
+    /// * `InvalidTierPrice` - Non-positive price
+    /// * `TierAlreadyExists` - Bundle ID already used
+    /// * `TierNotFound` - The bundled tier doesn't exist
+    pub fn create_bundle(
+        env: Env,
+        admin: Address,
+        params: CreateBundleParams,
+    ) -> Result<(), Error> {
+        BundleModule::create_bundle(env, admin, params)
+    }
+
+    /// Stops new subscriptions to a bundle; existing entitlements are kept.
+    ///
+    /// # Errors
+    /// * `AdminNotSet` - No admin configured
+    /// * `Unauthorized` - Caller is not the admin
+    /// * `TierNotFound` - Bundle doesn't exist
+    pub fn deactivate_bundle(env: Env, admin: Address, bundle_id: String) -> Result<(), Error> {
+        BundleModule::deactivate_bundle(env, admin, bundle_id)
     }
 
-    /// Removes specific attributes from token metadata.
+    /// Returns a bundle definition.
     ///
-    /// # Arguments
-    /// * `env` - The contract environment
-    /// * `token_id` - The token ID to remove attributes from
-    /// * `attribute_keys` - Vector of attribute keys to remove
-    pub fn remove_metadata_attributes(
-        env: Env,
-        token_id: BytesN<32>,
-        attribute_keys: Vec<String>,
-    ) -> Result<(), Error> {
-        MembershipTokenContract::remove_metadata_attributes(env, token_id, attribute_keys)
+    /// # Errors
+    /// * `TierNotFound` - Bundle doesn't exist
+    pub fn get_bundle(env: Env, bundle_id: String) -> Result<SubscriptionBundle, Error> {
+        BundleModule::get_bundle(&env, &bundle_id)
     }
 
-    /// Queries tokens by metadata attribute.
+    /// Returns all bundles, including inactive ones.
+    pub fn get_bundles(env: Env) -> Vec<SubscriptionBundle> {
+        BundleModule::get_bundles(&env)
+    }
+
+    /// Subscribes to a bundle, paying the bundle price in USDC. Provisions
+    /// subscription `id` on the bundle's tier and records its entitlements.
     ///
     /// # Arguments
     /// * `env` - The contract environment
-    /// * `attribute_key` - The attribute key to search for
-    /// * `attribute_value` - The attribute value to match
+    /// * `id` - New subscription ID
+    /// * `user` - Subscriber (must be authorized)
+    /// * `bundle_id` - Bundle to subscribe to
+    /// * `billing_cycle` - Monthly or annual pricing
     ///
-    /// # Returns
-    /// * Vector of token IDs that have the matching attribute
-    pub fn query_tokens_by_attribute(
+    /// # Errors
+    /// * `TierNotFound` - Bundle or its tier doesn't exist
+    /// * `TierNotActive` - Bundle or its tier is inactive
+    /// * `SubscriptionAlreadyExists` - Subscription ID already used
+    /// * `UsdcContractNotSet` - USDC contract not configured
+    pub fn subscribe_to_bundle(
         env: Env,
-        attribute_key: String,
-        attribute_value: MetadataValue,
-    ) -> Vec<BytesN<32>> {
-        MembershipTokenContract::query_tokens_by_attribute(env, attribute_key, attribute_value)
+        id: String,
+        user: Address,
+        bundle_id: String,
+        billing_cycle: BillingCycle,
+    ) -> Result<BundleEntitlements, ModuleError> {
+        BundleModule::subscribe_to_bundle(env, id, user, bundle_id, billing_cycle)
+    }
+
+    /// Returns the entitlements from the member's most recent bundle purchase.
+    /// They apply only while the linked subscription is active.
+    pub fn get_bundle_entitlements(env: Env, user: Address) -> Option<BundleEntitlements> {
+        BundleModule::get_entitlements(&env, &user)
     }
 
     // ============================================================================
-    // Token Renewal System Endpoints
+    // Household Endpoints
     // ============================================================================
 
-    /// Sets the renewal configuration. Admin only.
+    /// Sets the maximum number of dependents per household (1 to 10).
+    ///
+    /// # Errors
+    /// * `AdminNotSet` - No admin configured
+    /// * `Unauthorized` - Caller is not the admin
+    /// * `HouseholdError::InvalidHouseholdLimit` - Limit out of range
+    pub fn set_max_household_members(
+        env: Env,
+        admin: Address,
+        max_members: u32,
+    ) -> Result<(), ModuleError> {
+        HouseholdModule::set_max_members(env, admin, max_members)
+    }
+
+    /// Returns the maximum number of dependents per household.
+    pub fn get_max_household_members(env: Env) -> u32 {
+        HouseholdModule::get_max_members(&env)
+    }
+
+    /// Links a dependent to a primary subscription. The dependent then gets
+    /// the primary tier's features while the subscription is active.
     ///
     /// # Arguments
     /// * `env` - The contract environment
-    /// * `grace_period_duration` - Grace period duration in seconds
-    /// * `auto_renewal_notice_days` - Days before expiry to trigger auto-renewal
-    /// * `renewals_enabled` - Whether renewals are enabled
+    /// * `subscription_id` - Primary subscription (its owner must authorize)
+    /// * `member` - Dependent address to link
     ///
     /// # Errors
-    /// * `AdminNotSet` - No admin configured
-    /// * `Unauthorized` - Caller is not admin
-    pub fn set_renewal_config(
+    /// * `SubscriptionNotFound` - Primary subscription doesn't exist
+    /// * `SubscriptionNotActive` - Primary subscription isn't active
+    /// * `HouseholdError::AlreadyLinked` - Member is the primary or already in a household
+    /// * `HouseholdError::HouseholdFull` - Household is full
+    pub fn add_household_member(
         env: Env,
-        grace_period_duration: u64,
-        auto_renewal_notice_days: u64,
-        renewals_enabled: bool,
-    ) -> Result<(), Error> {
-        MembershipTokenContract::set_renewal_config(
-            env,
-            grace_period_duration,
-            auto_renewal_notice_days,
-            renewals_enabled,
-        )
+        subscription_id: String,
+        member: Address,
+    ) -> Result<(), ModuleError> {
+        HouseholdModule::add_member(env, subscription_id, member)
     }
 
-    /// Gets the renewal configuration.
+    /// Unlinks a dependent from a primary subscription.
+    ///
+    /// # Errors
+    /// * `HouseholdError::NotHouseholdMember` - Primary subscription doesn't exist, or
+    ///   `member` isn't one of its dependents
+    pub fn remove_household_member(
+        env: Env,
+        subscription_id: String,
+        member: Address,
+    ) -> Result<(), ModuleError> {
+        HouseholdModule::remove_member(env, subscription_id, member)
+    }
+
+    /// Returns the dependents linked to a primary subscription.
+    pub fn get_household_members(env: Env, subscription_id: String) -> Vec<Address> {
+        HouseholdModule::get_members(&env, &subscription_id)
+    }
+
+    /// Returns the primary subscription a dependent is linked to, if any.
+    pub fn get_household(env: Env, member: Address) -> Option<String> {
+        HouseholdModule::get_household(&env, &member)
+    }
+
+    /// Checks whether a dependent has a feature through their household's
+    /// primary subscription tier. Returns `false` for non-dependents.
+    pub fn check_member_feature_access(
+        env: Env,
+        member: Address,
+        feature: TierFeature,
+    ) -> Result<bool, Error> {
+        HouseholdModule::check_member_feature_access(env, member, feature)
+    }
+
+    /// Returns attendance logged by a household's dependents.
+    pub fn get_household_attendance(env: Env, subscription_id: String) -> HouseholdAttendance {
+        HouseholdModule::get_attendance(&env, &subscription_id)
+    }
+
+    // ============================================================================
+    // Entry Access Endpoints
+    // ============================================================================
+
+    /// Sets the policy applied by `verify_entry`.
+    ///
+    /// # Errors
+    /// * `AdminNotSet` - No admin configured
+    /// * `Unauthorized` - Caller is not the admin
+    /// * `InvalidPaymentAmount` - `min_stake` is negative
+    pub fn set_entry_policy(env: Env, admin: Address, policy: EntryPolicy) -> Result<(), Error> {
+        EntryModule::set_policy(env, admin, policy)
+    }
+
+    /// Returns the entry policy, or the default (token only) if none is set.
+    pub fn get_entry_policy(env: Env) -> EntryPolicy {
+        EntryModule::get_policy(&env)
+    }
+
+    /// Decides whether `user` may enter, combining token validity,
+    /// subscription status and the staking requirement under the entry
+    /// policy, and falling back to partner hubs. Read-only, intended for
+    /// door-access hardware.
+    pub fn verify_entry(env: Env, user: Address) -> EntryDecision {
+        EntryModule::verify_entry(env, user)
+    }
+
+    /// Decides whether `user` may enter on this hub's own tokens, without
+    /// consulting partner hubs. Partner hubs call this to recognize this
+    /// hub's members.
+    pub fn verify_home_entry(env: Env, user: Address) -> EntryDecision {
+        EntryModule::verify_home_entry(env, user)
+    }
+
+    /// Issues a one-time entry pass for a guest check-in. The returned pass
+    /// ID is meant to be shown as a QR code and redeemed at a kiosk.
     ///
     /// # Arguments
     /// * `env` - The contract environment
+    /// * `token_id` - Membership token the pass draws on (owner must authorize)
+    /// * `valid_for_secs` - Validity window, up to 7 days
     ///
-    /// # Returns
-    /// * The renewal configuration with defaults if not set
-    pub fn get_renewal_config(env: Env) -> types::RenewalConfig {
-        MembershipTokenContract::get_renewal_config(env)
+    /// # Errors
+    /// * `EntryError::InvalidPassValidity` - Validity window is zero or above 7 days
+    /// * `TokenNotFound` - Token doesn't exist
+    /// * `EntryError::EntryDenied` - The membership does not satisfy the entry policy
+    pub fn create_entry_pass(
+        env: Env,
+        token_id: BytesN<32>,
+        valid_for_secs: u64,
+    ) -> Result<BytesN<32>, ModuleError> {
+        EntryModule::create_pass(env, token_id, valid_for_secs)
     }
 
-    /// Renews a membership token with payment validation and tier pricing.
+    /// Redeems an entry pass from a kiosk and logs a clock-in for the member.
     ///
     /// # Arguments
     /// * `env` - The contract environment
-    /// * `id` - Token ID to renew
-    /// * `payment_token` - Payment token address (must be USDC)
-    /// * `tier_id` - Tier ID for pricing lookup
-    /// * `billing_cycle` - Billing cycle (Monthly or Annual)
+    /// * `operator` - Kiosk address holding the operator role
+    /// * `pass_id` - Pass to redeem
     ///
     /// # Errors
-    /// * `TokenNotFound` - Token doesn't exist
-    /// * `RenewalNotAllowed` - Renewals are disabled
+    /// * `EntryError::PassNotFound` - No pass with this ID
+    /// * `EntryError::PassAlreadyRedeemed` - The pass was already redeemed
+    /// * `EntryError::PassExpired` - The pass is past its validity window
+    /// * `EntryError::EntryDenied` - The membership no longer satisfies the entry policy,
+    ///   or `operator` does not hold the operator role
+    pub fn redeem_entry_pass(
+        env: Env,
+        operator: Address,
+        pass_id: BytesN<32>,
+    ) -> Result<(), ModuleError> {
+        EntryModule::redeem_pass(env, operator, pass_id)
+    }
+
+    /// Returns an entry pass while its record is retained (about 8 days).
+    pub fn get_entry_pass(env: Env, pass_id: BytesN<32>) -> Option<EntryPass> {
+        EntryModule::get_pass(&env, &pass_id)
+    }
+
+    // ============================================================================
+    // Guest Pass Endpoints
+    // ============================================================================
+
+    /// Sets the number of guest visits a tier grants per 30-day period.
+    ///
+    /// # Errors
+    /// * `AdminNotSet` - No admin configured
+    /// * `Unauthorized` - Caller is not the admin
     /// * `TierNotFound` - Tier doesn't exist
-    /// * `InvalidPaymentAmount` - Invalid payment amount
-    /// * `InvalidPaymentToken` - Invalid payment token
-    /// * `Unauthorized` - Caller is not token owner
-    pub fn renew_token(
+    pub fn set_tier_guest_quota(
         env: Env,
-        id: BytesN<32>,
-        payment_token: Address,
+        admin: Address,
         tier_id: String,
-        billing_cycle: BillingCycle,
+        monthly_quota: u32,
     ) -> Result<(), Error> {
-        MembershipTokenContract::renew_token(env, id, payment_token, tier_id, billing_cycle)
+        GuestModule::set_tier_quota(env, admin, tier_id, monthly_quota)
     }
 
-    /// Gets the renewal history for a token.
+    /// Returns the guest visits a tier grants per period (0 if unset).
+    pub fn get_tier_guest_quota(env: Env, tier_id: String) -> u32 {
+        GuestModule::get_tier_quota(&env, &tier_id)
+    }
+
+    /// Invites a guest until `valid_until`, spending one guest visit from the
+    /// subscription's current period.
     ///
     /// # Arguments
     /// * `env` - The contract environment
-    /// * `token_id` - Token ID
+    /// * `subscription_id` - Host subscription (subscriber must authorize)
+    /// * `guest` - Guest address
+    /// * `valid_until` - Last timestamp the guest may check in
     ///
-    /// # Returns
-    /// * Vector of renewal history entries
-    pub fn get_renewal_history(env: Env, token_id: BytesN<32>) -> Vec<types::RenewalHistory> {
-        MembershipTokenContract::get_renewal_history(env, token_id)
+    /// # Errors
+    /// * `SubscriptionNotFound` - Subscription doesn't exist
+    /// * `SubscriptionNotActive` - Subscription is not active
+    /// * `GuestError::InvalidInviteWindow` - `valid_until` is past or after the subscription expires
+    /// * `GuestError::AlreadyInvited` - The guest already holds a live invite
+    /// * `GuestError::QuotaExceeded` - The period's guest quota is used up
+    pub fn invite_guest(
+        env: Env,
+        subscription_id: String,
+        guest: Address,
+        valid_until: u64,
+    ) -> Result<GuestInvite, ModuleError> {
+        GuestModule::invite_guest(env, subscription_id, guest, valid_until)
     }
 
-    /// Checks and applies grace period to an expired token.
-    ///
-    /// # Arguments
-    /// * `env` - The contract environment
-    /// * `id` - Token ID
+    /// Logs attendance for an invited guest, attributed to the host. Guest
+    /// visits earn the host no loyalty points.
     ///
-    /// # Returns
-    /// * Updated token if grace period was applied
-    pub fn check_and_apply_grace_period(
+    /// # Errors
+    /// * `GuestError::InviteNotFound` - The guest holds no invite from this subscription
+    /// * `GuestError::InviteExpired` - The invite has expired
+    /// * `SubscriptionNotActive` - The host subscription is no longer active
+    pub fn log_guest_attendance(
         env: Env,
         id: BytesN<32>,
-    ) -> Result<MembershipToken, Error> {
-        MembershipTokenContract::check_and_apply_grace_period(env, id)
+        subscription_id: String,
+        guest: Address,
+        action: AttendanceAction,
+    ) -> Result<(), ModuleError> {
+        GuestModule::log_guest_attendance(env, id, subscription_id, guest, action)
     }
 
-    /// Sets auto-renewal settings for a user's token.
-    ///
-    /// # Arguments
-    /// * `env` - The contract environment
-    /// * `token_id` - Token ID to enable auto-renewal for
-    /// * `enabled` - Whether to enable auto-renewal
-    /// * `payment_token` - Payment token to use for auto-renewal
-    pub fn set_auto_renewal(
+    /// Returns a guest's invite from a subscription, if any.
+    pub fn get_guest_invite(
         env: Env,
-        token_id: BytesN<32>,
-        enabled: bool,
-        payment_token: Address,
-    ) -> Result<(), Error> {
-        MembershipTokenContract::set_auto_renewal(env, token_id, enabled, payment_token)
+        subscription_id: String,
+        guest: Address,
+    ) -> Option<GuestInvite> {
+        GuestModule::get_invite(&env, &subscription_id, &guest)
     }
 
-    /// Gets auto-renewal settings for a user.
+    /// Returns guest visits used and allowed in the subscription's current
+    /// period.
     ///
-    /// # Arguments
-    /// * `env` - The contract environment
-    /// * `user` - User address
+    /// # Errors
+    /// * `SubscriptionNotFound` - Subscription doesn't exist
+    pub fn get_guest_usage(env: Env, subscription_id: String) -> Result<GuestUsage, Error> {
+        GuestModule::get_usage(env, subscription_id)
+    }
+
+    // ============================================================================
+    // Session Booking Endpoints
+    // ============================================================================
+
+    /// Schedules a bookable session. Admin only.
     ///
-    /// # Returns
-    /// * Auto-renewal settings or None if not set
-    pub fn get_auto_renewal_settings(
+    /// # Errors
+    /// * `Unauthorized` - Caller is not the admin
+    /// * `BookingError::InvalidSession` - Start is not in the future, end is not after
+    ///   start, or capacity is 0 or above the maximum
+    /// * `BookingError::SessionAlreadyExists` - A session with this id already exists
+    pub fn create_session(
         env: Env,
-        user: Address,
-    ) -> Option<types::AutoRenewalSettings> {
-        MembershipTokenContract::get_auto_renewal_settings(env, user)
+        admin: Address,
+        params: CreateSessionParams,
+    ) -> Result<Session, ModuleError> {
+        BookingModule::create_session(env, admin, params)
     }
 
-    /// Checks if a token is eligible for auto-renewal.
+    /// Returns a scheduled session.
     ///
-    /// # Arguments
-    /// * `env` - The contract environment
-    /// * `id` - Token ID
+    /// # Errors
+    /// * `BookingError::SessionNotFound` - Session doesn't exist
+    pub fn get_session(env: Env, session_id: String) -> Result<Session, ModuleError> {
+        BookingModule::get_session(&env, &session_id)
+    }
+
+    /// Sets the number of session bookings a tier grants per 30-day period.
     ///
-    /// # Returns
-    /// * True if token is within auto-renewal window
-    pub fn check_auto_renewal_eligibility(env: Env, id: BytesN<32>) -> Result<bool, Error> {
-        MembershipTokenContract::check_auto_renewal_eligibility(env, id)
+    /// # Errors
+    /// * `Unauthorized` - Caller is not the admin
+    /// * `TierNotFound` - Tier doesn't exist
+    pub fn set_tier_booking_quota(
+        env: Env,
+        admin: Address,
+        tier_id: String,
+        monthly_quota: u32,
+    ) -> Result<(), Error> {
+        BookingModule::set_tier_quota(env, admin, tier_id, monthly_quota)
     }
 
-    /// Processes auto-renewal for a token. Enters grace period on failure.
+    /// Returns the session bookings a tier grants per period (0 if unset).
+    pub fn get_tier_booking_quota(env: Env, tier_id: String) -> u32 {
+        BookingModule::get_tier_quota(&env, &tier_id)
+    }
+
+    /// Sets how long before a session starts bookings stop being
+    /// cancellable. Admin only.
+    pub fn set_booking_cancel_cutoff(
+        env: Env,
+        admin: Address,
+        cutoff_secs: u64,
+    ) -> Result<(), Error> {
+        BookingModule::set_cancel_cutoff(env, admin, cutoff_secs)
+    }
+
+    /// Returns the booking cancellation cutoff in seconds.
+    pub fn get_booking_cancel_cutoff(env: Env) -> u64 {
+        BookingModule::get_cancel_cutoff(&env)
+    }
+
+    /// Books a seat in a session, spending one booking from the
+    /// subscription's current period.
     ///
     /// # Arguments
     /// * `env` - The contract environment
-    /// * `id` - Token ID
+    /// * `subscription_id` - Booking subscription (subscriber must authorize)
+    /// * `session_id` - Session to book
     ///
-    /// # Returns
-    /// * Success or error
-    pub fn process_auto_renewal(env: Env, id: BytesN<32>) -> Result<(), Error> {
-        MembershipTokenContract::process_auto_renewal(env, id)
+    /// # Errors
+    /// * `SubscriptionNotActive` - Subscription is not active
+    /// * `BookingError::SessionNotFound` - Session doesn't exist
+    /// * `BookingError::BookingClosed` - The session has already started
+    /// * `FeatureNotAvailable` - The subscription lacks a required feature
+    /// * `BookingError::AlreadyBooked` - The subscription already booked the session
+    /// * `BookingError::SessionFull` - The session is full
+    /// * `BookingError::QuotaExceeded` - The period's booking quota is used up
+    pub fn book_session(
+        env: Env,
+        subscription_id: String,
+        session_id: String,
+    ) -> Result<Booking, ModuleError> {
+        BookingModule::book_session(env, subscription_id, session_id)
     }
 
-    // ============================================================================
-    // Attendance Analytics Endpoints
-    // ============================================================================
+    /// Cancels a booking and releases its seat, returning it to the quota
+    /// when made in the current period.
+    ///
+    /// # Errors
+    /// * `BookingError::BookingNotFound` - No booking for this subscription and session
+    /// * `BookingError::BookingNotActive` - The booking is no longer open
+    /// * `BookingError::BookingClosed` - The cancellation cutoff has passed
+    pub fn cancel_booking(
+        env: Env,
+        subscription_id: String,
+        session_id: String,
+    ) -> Result<(), ModuleError> {
+        BookingModule::cancel_booking(env, subscription_id, session_id)
+    }
 
-    /// Get attendance summary for a user within a date range.
+    /// Checks a member in to a booked session and logs the clock-in.
     ///
-    /// # Arguments
-    /// * `env` - Contract environment
-    /// * `user_id` - User address to query
-    /// * `date_range` - Date range to filter records
+    /// # Errors
+    /// * `BookingError::BookingNotFound` - No booking for this subscription and session
+    /// * `BookingError::BookingNotActive` - The booking is no longer open
+    /// * `BookingError::CheckInClosed` - Check-in is not open for the session
+    /// * `SubscriptionNotActive` - Subscription is not active
+    pub fn check_in_session(
+        env: Env,
+        id: BytesN<32>,
+        subscription_id: String,
+        session_id: String,
+    ) -> Result<(), ModuleError> {
+        BookingModule::check_in_session(env, id, subscription_id, session_id)
+    }
+
+    /// Marks bookings left unattended after a session ended as no-shows.
+    /// Admin only; returns the number recorded.
     ///
-    /// # Returns
-    /// * `Ok(AttendanceSummary)` - Summary with clock-ins, clock-outs, duration stats
-    /// * `Err(Error)` - If date range is invalid or no records found
+    /// # Errors
+    /// * `Unauthorized` - Caller is not the admin
+    /// * `BookingError::SessionNotEnded` - The session has not ended yet
+    pub fn record_session_no_shows(
+        env: Env,
+        admin: Address,
+        session_id: String,
+    ) -> Result<u32, ModuleError> {
+        BookingModule::record_no_shows(env, admin, session_id)
+    }
+
+    /// Returns a subscription's booking for a session, if any.
+    pub fn get_booking(env: Env, session_id: String, subscription_id: String) -> Option<Booking> {
+        BookingModule::get_booking(&env, &session_id, &subscription_id)
+    }
+
+    /// Lists the subscriptions that have booked a session.
+    pub fn get_session_bookings(env: Env, session_id: String) -> Vec<String> {
+        BookingModule::get_session_bookings(&env, &session_id)
+    }
+
+    /// Returns how many booked sessions a member has missed.
+    pub fn get_no_show_count(env: Env, user: Address) -> u32 {
+        BookingModule::get_no_show_count(&env, &user)
+    }
+
+    /// Returns session bookings used and allowed in the subscription's
+    /// current period.
+    ///
+    /// # Errors
+    /// * `SubscriptionNotFound` - Subscription doesn't exist
+    pub fn get_booking_usage(env: Env, subscription_id: String) -> Result<BookingUsage, Error> {
+        BookingModule::get_usage(env, subscription_id)
+    }
+
+    // ============================================================================
+    // Resource Reservation Endpoints
+    // ============================================================================
+
+    /// Registers a meeting room, locker or piece of equipment members can
+    /// reserve, with an optional refundable USDC deposit. Admin only.
     ///
     /// # Errors
-    /// * `InvalidDateRange` - Start time is after end time
-    /// * `NoAttendanceRecords` - No records found for user in range
-    pub fn get_attendance_summary(
+    /// * `Unauthorized` - Caller is not the admin
+    /// * `ResourceError::InvalidDeposit` - Deposit is negative
+    /// * `ResourceError::ResourceAlreadyExists` - A resource with this id already exists
+    pub fn register_resource(
         env: Env,
-        user_id: Address,
-        date_range: DateRange,
-    ) -> Result<AttendanceSummary, Error> {
-        AttendanceLogModule::get_attendance_summary(env, user_id, date_range)
+        admin: Address,
+        params: RegisterResourceParams,
+    ) -> Result<Resource, ModuleError> {
+        ResourceModule::register_resource(env, admin, params)
     }
 
-    /// Get time-based attendance records (daily, weekly, monthly).
-    ///
-    /// # Arguments
-    /// * `env` - Contract environment
-    /// * `user_id` - User address to query
-    /// * `period` - Time period for grouping (Daily, Weekly, Monthly, Custom)
-    /// * `date_range` - Date range to filter records
-    ///
-    /// # Returns
-    /// * `Ok(Vec<AttendanceLog>)` - Filtered attendance logs for the period
-    /// * `Err(Error)` - If date range is invalid or no records found
+    /// Activates or deactivates a resource. Admin only.
     ///
     /// # Errors
-    /// * `InvalidDateRange` - Start time is after end time
-    /// * `NoAttendanceRecords` - No records found for user in range
-    pub fn get_time_based_attendance(
+    /// * `Unauthorized` - Caller is not the admin
+    /// * `ResourceError::ResourceNotFound` - Resource doesn't exist
+    pub fn set_resource_active(
         env: Env,
-        user_id: Address,
-        period: TimePeriod,
-        date_range: DateRange,
-    ) -> Result<Vec<AttendanceLog>, Error> {
-        AttendanceLogModule::get_time_based_attendance(env, user_id, period, date_range)
+        admin: Address,
+        resource_id: String,
+        active: bool,
+    ) -> Result<(), ModuleError> {
+        ResourceModule::set_resource_active(env, admin, resource_id, active)
     }
 
-    /// Calculate attendance frequency for a user.
-    ///
-    /// # Arguments
-    /// * `env` - Contract environment
-    /// * `user_id` - User address to query
-    /// * `date_range` - Date range to analyze
+    /// Returns a registered resource.
     ///
-    /// # Returns
-    /// * `Ok(AttendanceFrequency)` - Frequency metrics including total, average daily
-    /// * `Err(Error)` - If date range is invalid or no records found
+    /// # Errors
+    /// * `ResourceError::ResourceNotFound` - Resource doesn't exist
+    pub fn get_resource(env: Env, resource_id: String) -> Result<Resource, ModuleError> {
+        ResourceModule::get_resource(&env, &resource_id)
+    }
+
+    /// Sets how many open reservations a subscription on the tier may hold.
     ///
     /// # Errors
-    /// * `InvalidDateRange` - Start time is after end time
-    /// * `NoAttendanceRecords` - No records found for user in range
-    pub fn calculate_attendance_frequency(
+    /// * `Unauthorized` - Caller is not the admin
+    /// * `TierNotFound` - Tier doesn't exist
+    pub fn set_tier_reservation_limit(
         env: Env,
-        user_id: Address,
-        date_range: DateRange,
-    ) -> Result<AttendanceFrequency, Error> {
-        AttendanceLogModule::calculate_attendance_frequency(env, user_id, date_range)
+        admin: Address,
+        tier_id: String,
+        max_open: u32,
+    ) -> Result<(), Error> {
+        ResourceModule::set_tier_limit(env, admin, tier_id, max_open)
     }
 
-    /// Get comprehensive user attendance statistics.
+    /// Returns the open reservations a tier allows (0 if unset).
+    pub fn get_tier_reservation_limit(env: Env, tier_id: String) -> u32 {
+        ResourceModule::get_tier_limit(&env, &tier_id)
+    }
+
+    /// Reserves a resource for a time range, moving its deposit from the
+    /// subscriber into escrow.
     ///
     /// # Arguments
-    /// * `env` - Contract environment
-    /// * `user_id` - User address to query
-    /// * `date_range` - Optional date range (None for all-time stats)
-    ///
-    /// # Returns
-    /// * `Ok(UserAttendanceStats)` - Comprehensive stats including total hours,
-    ///   average attendance, session counts, and date ranges
-    /// * `Err(Error)` - If date range is invalid or no records found
+    /// * `env` - The contract environment
+    /// * `subscription_id` - Reserving subscription (subscriber must authorize)
+    /// * `resource_id` - Resource to reserve
+    /// * `start_time` - Start of the reserved range
+    /// * `end_time` - End of the reserved range (exclusive)
     ///
     /// # Errors
-    /// * `InvalidDateRange` - Start time is after end time (if range provided)
-    /// * `NoAttendanceRecords` - No records found for user
-    pub fn get_user_statistics(
+    /// * `SubscriptionNotActive` - Subscription is not active
+    /// * `ResourceError::ResourceNotFound` - Resource doesn't exist
+    /// * `ResourceError::ResourceInactive` - The resource is deactivated
+    /// * `ResourceError::InvalidTimeRange` - Range is in the past, empty or longer than 7 days
+    /// * `ResourceError::TimeConflict` - Range overlaps another reservation
+    /// * `ResourceError::TooManyReservations` - The resource has too many open reservations
+    /// * `ResourceError::LimitReached` - The tier's reservation limit is reached
+    /// * `UsdcContractNotSet` - A deposit is due but no USDC contract is set
+    pub fn reserve_resource(
         env: Env,
-        user_id: Address,
-        date_range: Option<DateRange>,
-    ) -> Result<UserAttendanceStats, Error> {
-        AttendanceLogModule::get_user_statistics(env, user_id, date_range)
+        subscription_id: String,
+        resource_id: String,
+        start_time: u64,
+        end_time: u64,
+    ) -> Result<Reservation, ModuleError> {
+        ResourceModule::reserve_resource(env, subscription_id, resource_id, start_time, end_time)
     }
 
-    /// Analyze peak attendance hours for a user.
-    ///
-    /// # Arguments
-    /// * `env` - Contract environment
-    /// * `user_id` - User address to query
-    /// * `date_range` - Date range to analyze
+    /// Cancels a reservation before it starts and refunds the deposit.
     ///
-    /// # Returns
-    /// * `Ok(Vec<PeakHourData>)` - Peak hour analysis showing attendance count
-    ///   and percentage per hour
-    /// * `Err(Error)` - If date range is invalid or no records found
+    /// # Errors
+    /// * `ResourceError::ReservationNotFound` - Reservation doesn't exist
+    /// * `ResourceError::ReservationNotActive` - The reservation is no longer open
+    /// * `ResourceError::ReservationStarted` - The reservation has started
+    pub fn cancel_reservation(env: Env, reservation_id: u64) -> Result<(), ModuleError> {
+        ResourceModule::cancel_reservation(env, reservation_id)
+    }
+
+    /// Checks a member in to their reservation.
     ///
     /// # Errors
-    /// * `InvalidDateRange` - Start time is after end time
-    /// * `NoAttendanceRecords` - No records found for user in range
-    pub fn analyze_peak_hours(
-        env: Env,
-        user_id: Address,
-        date_range: DateRange,
-    ) -> Result<Vec<PeakHourData>, Error> {
-        AttendanceLogModule::analyze_peak_hours(env, user_id, date_range)
+    /// * `ResourceError::ReservationNotFound` - Reservation doesn't exist
+    /// * `ResourceError::ReservationNotActive` - The reservation is no longer open
+    /// * `ResourceError::CheckInClosed` - Check-in is not open for the reservation
+    /// * `SubscriptionNotActive` - Subscription is not active
+    pub fn check_in_reservation(env: Env, reservation_id: u64) -> Result<(), ModuleError> {
+        ResourceModule::check_in_reservation(env, reservation_id)
     }
 
-    /// Analyze attendance patterns by day of week.
+    /// Settles an ended reservation, refunding the deposit or, for a no-show
+    /// on a resource configured to do so, forfeiting it to the treasury.
     ///
-    /// # Arguments
-    /// * `env` - Contract environment
-    /// * `user_id` - User address to query
-    /// * `date_range` - Date range to analyze
+    /// # Errors
+    /// * `ResourceError::ReservationNotFound` - Reservation doesn't exist
+    /// * `ResourceError::ReservationNotEnded` - The reserved range has not ended yet
+    /// * `ResourceError::ReservationNotActive` - The reservation was already cancelled
+    ///   or settled
+    pub fn settle_reservation(env: Env, reservation_id: u64) -> Result<Reservation, ModuleError> {
+        ResourceModule::settle_reservation(env, reservation_id)
+    }
+
+    /// Returns a reservation.
     ///
-    /// # Returns
-    /// * `Ok(Vec<DayPattern>)` - Day patterns showing attendance distribution
-    ///   across days of the week with counts and percentages
-    /// * `Err(Error)` - If date range is invalid or no records found
+    /// # Errors
+    /// * `ResourceError::ReservationNotFound` - Reservation doesn't exist
+    pub fn get_reservation(env: Env, reservation_id: u64) -> Result<Reservation, ModuleError> {
+        ResourceModule::get_reservation(&env, reservation_id)
+    }
+
+    /// Lists a resource's reservations that are not yet cancelled or settled.
+    pub fn get_open_reservations(env: Env, resource_id: String) -> Vec<Reservation> {
+        ResourceModule::get_open_reservations(&env, &resource_id)
+    }
+
+    /// Returns how many open reservations a subscription holds.
+    pub fn get_active_reservation_count(env: Env, subscription_id: String) -> u32 {
+        ResourceModule::get_active_count(&env, &subscription_id)
+    }
+
+    /// Returns the USDC currently held in escrow for reservation deposits.
+    pub fn get_escrowed_deposits(env: Env) -> i128 {
+        ResourceModule::get_escrowed_deposits(&env)
+    }
+
+    // ============================================================================
+    // Staff Payroll Endpoints
+    // ============================================================================
+
+    /// Registers a staff member paid per shift at `hourly_rate` USDC.
+    /// Admin only.
     ///
     /// # Errors
-    /// * `InvalidDateRange` - Start time is after end time
-    /// * `NoAttendanceRecords` - No records found for user in range
-    pub fn analyze_day_patterns(
+    /// * `Unauthorized` - Caller is not the admin
+    /// * `StaffError::InvalidRate` - Rate is not positive
+    /// * `StaffError::StaffAlreadyRegistered` - Address is already registered
+    /// * `StaffError::TooManyStaff` - The staff roster is full
+    pub fn register_staff(
         env: Env,
-        user_id: Address,
-        date_range: DateRange,
-    ) -> Result<Vec<DayPattern>, Error> {
-        AttendanceLogModule::analyze_day_patterns(env, user_id, date_range)
+        admin: Address,
+        staff: Address,
+        hourly_rate: i128,
+    ) -> Result<StaffMember, ModuleError> {
+        StaffModule::register_staff(env, admin, staff, hourly_rate)
     }
 
-    /// Calculate total hours from seconds.
+    /// Changes a staff member's hourly rate for shifts recorded from now on.
     ///
-    /// # Arguments
-    /// * `total_seconds` - Total seconds to convert
+    /// # Errors
+    /// * `Unauthorized` - Caller is not the admin
+    /// * `StaffError::InvalidRate` - Rate is not positive
+    /// * `StaffError::StaffNotFound` - Address is not registered as staff
+    pub fn set_staff_hourly_rate(
+        env: Env,
+        admin: Address,
+        staff: Address,
+        hourly_rate: i128,
+    ) -> Result<(), ModuleError> {
+        StaffModule::set_hourly_rate(env, admin, staff, hourly_rate)
+    }
+
+    /// Activates or deactivates a staff member. Admin only.
     ///
-    /// # Returns
-    /// * Total hours (rounded down)
-    pub fn calculate_total_hours(total_seconds: u64) -> u64 {
-        AttendanceLogModule::calculate_total_hours(total_seconds)
+    /// # Errors
+    /// * `Unauthorized` - Caller is not the admin
+    /// * `StaffError::StaffNotFound` - Address is not registered as staff
+    pub fn set_staff_active(
+        env: Env,
+        admin: Address,
+        staff: Address,
+        active: bool,
+    ) -> Result<(), ModuleError> {
+        StaffModule::set_staff_active(env, admin, staff, active)
     }
 
-    /// Calculate average daily attendance for a user.
+    /// Returns a registered staff member.
     ///
-    /// # Arguments
-    /// * `env` - Contract environment
-    /// * `user_id` - User address to query
-    /// * `date_range` - Date range to analyze
+    /// # Errors
+    /// * `StaffError::StaffNotFound` - Address is not registered as staff
+    pub fn get_staff(env: Env, staff: Address) -> Result<StaffMember, ModuleError> {
+        StaffModule::get_staff(&env, &staff)
+    }
+
+    /// Lists every registered staff address.
+    pub fn get_staff_roster(env: Env) -> Vec<Address> {
+        StaffModule::get_roster(&env)
+    }
+
+    /// Marks a staff member's clock-in log and the clock-out log ending it
+    /// as a paid shift. Admin only.
     ///
-    /// # Returns
-    /// * `Ok(u64)` - Average daily attendance count
-    /// * `Err(Error)` - If date range is invalid or no records found
+    /// # Errors
+    /// * `Unauthorized` - Caller is not the admin
+    /// * `StaffError::LogNotFound` - A log doesn't exist
+    /// * `StaffError::InvalidShift` - Logs are not a clock-in then clock-out of the
+    ///   same user
+    /// * `StaffError::StaffNotFound` - The user is not registered as staff
+    /// * `StaffError::StaffInactive` - The staff member is deactivated
+    /// * `StaffError::ShiftAlreadyRecorded` - A log is already part of a shift
+    pub fn record_staff_shift(
+        env: Env,
+        admin: Address,
+        clock_in_log: BytesN<32>,
+        clock_out_log: BytesN<32>,
+    ) -> Result<StaffShift, ModuleError> {
+        StaffModule::record_shift(env, admin, clock_in_log, clock_out_log)
+    }
+
+    /// Lists a staff member's recorded shifts that have not been paid yet.
+    pub fn get_unpaid_shifts(env: Env, staff: Address) -> Vec<StaffShift> {
+        StaffModule::get_unpaid_shifts(&env, &staff)
+    }
+
+    /// Returns the pay owed to a staff member for unpaid shifts ending in
+    /// `period`.
+    pub fn get_payroll_due(
+        env: Env,
+        staff: Address,
+        period: DateRange,
+    ) -> Result<i128, ModuleError> {
+        StaffModule::get_payroll_due(&env, &staff, &period)
+    }
+
+    /// Pays all staff for unpaid shifts ending in a past `period` from the
+    /// contract's collected USDC revenue, recording a payment per staff
+    /// member.
     ///
     /// # Errors
-    /// * `InvalidDateRange` - Start time is after end time
-    /// * `NoAttendanceRecords` - No records found for user in range
-    pub fn get_avg_daily_attendance(
+    /// * `Unauthorized` - Caller is not the admin
+    /// * `StaffError::InvalidPeriod` - Period is empty or has not ended
+    /// * `UsdcContractNotSet` - No USDC contract configured
+    /// * `TreasuryError::ExceedsWithdrawable` - Collected revenue cannot cover the payroll
+    pub fn settle_payroll(
         env: Env,
-        user_id: Address,
-        date_range: DateRange,
-    ) -> Result<u64, Error> {
-        AttendanceLogModule::calculate_average_daily_attendance(env, user_id, date_range)
+        admin: Address,
+        period: DateRange,
+    ) -> Result<Vec<PayrollPayment>, ModuleError> {
+        StaffModule::settle_payroll(env, admin, period)
+    }
+
+    /// Returns the payroll payments made to a staff member.
+    pub fn get_payroll_payments(env: Env, staff: Address) -> Vec<PayrollPayment> {
+        StaffModule::get_payroll_payments(&env, &staff)
     }
 
     // ============================================================================
-    // Emergency Pause Endpoints
+    // Federation Endpoints
     // ============================================================================
 
-    /// Immediately halts all token operations (issue, transfer, renew).
-    ///
-    /// # Arguments
-    /// * `env` - The contract environment
-    /// * `admin` - Admin address (must be authorized)
-    /// * `reason` - Human-readable reason for the pause
-    /// * `auto_unpause_after` - Optional seconds until the contract auto-resumes.
-    ///   Pass `None` for an indefinite pause that requires an explicit unpause call.
-    /// * `time_lock_duration` - Optional minimum seconds before a manual unpause is
-    ///   allowed. Use this during security incidents to prevent an attacker from
-    ///   reversing the pause with a compromised admin key. Pass `None` for no lock.
+    /// Registers a trusted partner manage_hub contract whose members may
+    /// visit up to `visit_quota` times per 30-day period. Admin only.
     ///
     /// # Errors
-    /// * `AdminNotSet` - No admin has been configured
     /// * `Unauthorized` - Caller is not the admin
-    pub fn emergency_pause(
+    /// * `FederationError::InvalidPartner` - Partner is this contract, or quota is 0
+    /// * `FederationError::PartnerAlreadyRegistered` - Partner is already registered
+    /// * `FederationError::TooManyPartners` - The partner registry is full
+    pub fn add_partner_hub(
         env: Env,
         admin: Address,
-        reason: Option<String>,
-        auto_unpause_after: Option<u64>,
-        time_lock_duration: Option<u64>,
-    ) -> Result<(), Error> {
-        MembershipTokenContract::emergency_pause(
-            env,
-            admin,
-            reason,
-            auto_unpause_after,
-            time_lock_duration,
-        )
+        partner: Address,
+        name: String,
+        visit_quota: u32,
+    ) -> Result<PartnerHub, ModuleError> {
+        FederationModule::add_partner(env, admin, partner, name, visit_quota)
     }
 
-    /// Lifts an active emergency pause and restores normal contract operation.
-    ///
-    /// The time lock (if any) must have elapsed before this call succeeds.
+    /// Changes a partner hub's visit quota or suspends it. Admin only.
     ///
     /// # Errors
-    /// * `AdminNotSet` - No admin has been configured
     /// * `Unauthorized` - Caller is not the admin
-    /// * `TimeLockNotExpired` - The mandatory lock window has not yet elapsed
-    pub fn emergency_unpause(env: Env, admin: Address) -> Result<(), Error> {
-        MembershipTokenContract::emergency_unpause(env, admin)
+    /// * `FederationError::InvalidPartner` - Quota is 0
+    /// * `FederationError::PartnerNotFound` - Partner is not registered
+    pub fn update_partner_hub(
+        env: Env,
+        admin: Address,
+        partner: Address,
+        visit_quota: u32,
+        active: bool,
+    ) -> Result<(), ModuleError> {
+        FederationModule::update_partner(env, admin, partner, visit_quota, active)
     }
 
-    /// Returns `true` if the contract is currently globally paused.
+    /// Returns a registered partner hub.
     ///
-    /// Respects time-based auto-unpause: returns `false` once
-    /// `auto_unpause_at` has passed, even before an explicit unpause call.
-    pub fn is_contract_paused(env: Env) -> bool {
-        MembershipTokenContract::is_contract_paused(env)
+    /// # Errors
+    /// * `FederationError::PartnerNotFound` - Partner is not registered
+    pub fn get_partner_hub(env: Env, partner: Address) -> Result<PartnerHub, ModuleError> {
+        FederationModule::get_partner(&env, &partner)
     }
 
-    /// Returns the full emergency pause state for inspection.
-    pub fn get_emergency_pause_state(env: Env) -> EmergencyPauseState {
-        MembershipTokenContract::get_emergency_pause_state(env)
+    /// Lists every registered partner hub contract.
+    pub fn get_partner_hubs(env: Env) -> Vec<Address> {
+        FederationModule::get_partners(&env)
     }
 
-    /// Pauses all operations for a specific token.
+    /// Logs attendance for a member of a partner hub once the partner
+    /// confirms their membership. A clock-in spends one of the member's
+    /// visits for the period and is reported back to the partner.
     ///
-    /// The per-token pause is independent of the global pause: either one is
-    /// sufficient to block transfers and renewals on that token.
+    /// # Errors
+    /// * `FederationError::PartnerNotFound` - Partner is not registered
+    /// * `FederationError::PartnerInactive` - Partner is suspended
+    /// * `FederationError::PartnerEntryDenied` - The partner does not grant the user entry
+    /// * `FederationError::VisitQuotaExceeded` - The member's visits for the period are used up
+    pub fn log_partner_attendance(
+        env: Env,
+        id: BytesN<32>,
+        user_id: Address,
+        partner: Address,
+        action: AttendanceAction,
+        details: Map<String, String>,
+    ) -> Result<(), ModuleError> {
+        FederationModule::log_partner_attendance(env, id, user_id, partner, action, details)
+    }
+
+    /// Records a visit by one of this hub's members at `hub`. Called by the
+    /// partner contract, which must be registered here.
     ///
-    /// # Arguments
-    /// * `env` - The contract environment
-    /// * `admin` - Admin address (must be authorized)
-    /// * `token_id` - The token to pause
-    /// * `reason` - Human-readable reason for the pause
+    /// # Errors
+    /// * `FederationError::PartnerNotFound` - `hub` is not a registered partner
+    pub fn report_partner_visit(env: Env, hub: Address, user: Address) -> Result<(), ModuleError> {
+        FederationModule::report_partner_visit(env, hub, user)
+    }
+
+    /// Returns the visits exchanged with a partner hub.
+    pub fn get_partner_usage(env: Env, partner: Address) -> PartnerUsage {
+        FederationModule::get_usage(&env, &partner)
+    }
+
+    /// Returns the visits a partner member made in the current period.
+    pub fn get_partner_visits_used(env: Env, partner: Address, user: Address) -> u32 {
+        FederationModule::get_visits_used(&env, &partner, &user)
+    }
+
+    // ============================================================================
+    // Hub Factory Endpoints
+    // ============================================================================
+
+    /// Records the uploaded manage_hub WASM hash new hubs are deployed from.
+    /// Admin only.
+    ///
+    /// # Errors
+    /// * `Unauthorized` - Caller is not the admin
+    pub fn set_hub_wasm_hash(env: Env, admin: Address, wasm_hash: BytesN<32>) -> Result<(), Error> {
+        FactoryModule::set_hub_wasm_hash(env, admin, wasm_hash)
+    }
+
+    /// Returns the WASM hash new hubs are deployed from, if set.
+    pub fn get_hub_wasm_hash(env: Env) -> Option<BytesN<32>> {
+        FactoryModule::get_hub_wasm_hash(&env)
+    }
+
+    /// Deploys a new hub, initializes it with the given admin, USDC contract
+    /// and configuration, links it to this hub's access_control contract and
+    /// registers it. Admin only; the new hub's admin must also authorize.
     ///
     /// # Errors
-    /// * `AdminNotSet` - No admin has been configured
     /// * `Unauthorized` - Caller is not the admin
-    /// * `TokenNotFound` - The specified token does not exist
-    pub fn pause_token_operations(
+    /// * `FactoryError::HubInitFailed` - The new hub could not be initialized
+    /// * `FactoryError::HubCodeNotConfigured` - No hub WASM hash set
+    /// * `FactoryError::MultisigNotConfigured` - No access_control contract set
+    /// * `FactoryError::HubAlreadyDeployed` - A hub was already deployed with this salt
+    /// * `FactoryError::TooManyHubs` - The hub registry is full
+    /// * `InvalidPauseConfig` - The supplied pause configuration is invalid
+    pub fn deploy_hub(
         env: Env,
         admin: Address,
-        token_id: BytesN<32>,
-        reason: Option<String>,
-    ) -> Result<(), Error> {
-        MembershipTokenContract::pause_token_operations(env, admin, token_id, reason)
+        params: DeployHubParams,
+    ) -> Result<Address, ModuleError> {
+        FactoryModule::deploy_hub(env, admin, params)
     }
 
-    /// Resumes operations for a previously paused token.
+    /// Returns a hub deployed by the factory.
     ///
     /// # Errors
-    /// * `AdminNotSet` - No admin has been configured
-    /// * `Unauthorized` - Caller is not the admin
-    /// * `TokenNotFound` - The specified token does not exist
-    pub fn unpause_token_operations(
-        env: Env,
-        admin: Address,
-        token_id: BytesN<32>,
-    ) -> Result<(), Error> {
-        MembershipTokenContract::unpause_token_operations(env, admin, token_id)
+    /// * `FactoryError::HubNotFound` - No hub registered at this address
+    pub fn get_hub(env: Env, hub: Address) -> Result<HubRecord, ModuleError> {
+        FactoryModule::get_hub(&env, &hub)
     }
 
-    /// Returns `true` if the specific token's operations are currently paused.
-    pub fn is_token_paused(env: Env, token_id: BytesN<32>) -> bool {
-        MembershipTokenContract::is_token_paused(env, token_id)
+    /// Lists every hub deployed by the factory.
+    pub fn get_hubs(env: Env) -> Vec<Address> {
+        FactoryModule::get_hubs(&env)
     }
 
     // ============================================================================
-    // Token Staking Endpoints
+    // Batched Reads
     // ============================================================================
 
-    /// Initialise or update the global staking configuration. Admin only.
+    /// Runs up to `MAX_BATCH_SIZE` read-only getters in one invocation,
+    /// answering each request in order. A failing read yields
+    /// `ReadResponse::Failed` with its error code instead of failing the
+    /// batch.
     ///
-    /// # Arguments
-    /// * `env` - The contract environment
-    /// * `admin` - Admin address (must be authorized)
-    /// * `config` - New staking configuration
+    /// # Errors
+    /// * `Unauthorized` - The batch is empty or larger than `MAX_BATCH_SIZE`
+    pub fn multiread(env: Env, requests: Vec<ReadRequest>) -> Result<Vec<ReadResponse>, Error> {
+        MultireadModule::multiread(env, requests)
+    }
+
+    // ============================================================================
+    // Member Snapshot Export
+    // ============================================================================
+
+    /// Returns one record with everything the contract holds about `user`:
+    /// tokens, active subscriptions, stake, fraction holdings, credits,
+    /// loyalty balance and badges. Intended for data-portability requests.
+    pub fn export_member_snapshot(env: Env, user: Address) -> MemberSnapshot {
+        SnapshotModule::export_member_snapshot(&env, &user)
+    }
+
+    /// Returns snapshots for a page of indexed members, in the order they
+    /// first received a token or subscription. Admin only; `limit` is capped
+    /// at `MAX_SNAPSHOT_PAGE`.
     ///
     /// # Errors
-    /// * `AdminNotSet` - No admin has been configured
     /// * `Unauthorized` - Caller is not the admin
-    /// * `InvalidPaymentAmount` - Penalty bps exceeds 100 %
-    pub fn set_staking_config(
+    pub fn export_member_snapshots(
         env: Env,
         admin: Address,
-        config: StakingConfig,
-    ) -> Result<(), Error> {
-        StakingModule::set_staking_config(env, admin, config)
+        offset: u32,
+        limit: u32,
+    ) -> Result<Vec<MemberSnapshot>, Error> {
+        SnapshotModule::export_member_snapshots(env, admin, offset, limit)
     }
 
-    /// Create a new staking tier. Admin only.
-    ///
-    /// # Arguments
-    /// * `env` - The contract environment
-    /// * `admin` - Admin address (must be authorized)
-    /// * `tier` - Staking tier definition
+    /// Returns how many members are indexed for bulk export.
+    pub fn get_member_count(env: Env) -> u32 {
+        SnapshotModule::get_member_count(&env)
+    }
+
+    // ============================================================================
+    // Member Notifications
+    // ============================================================================
+
+    /// Returns up to `limit` of a member's unacknowledged notifications,
+    /// skipping the first `offset`, oldest first. At most 50 per call.
+    pub fn get_notifications(
+        env: Env,
+        user: Address,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<Notification> {
+        NotificationModule::get_notifications(env, user, offset, limit)
+    }
+
+    /// Acknowledges and deletes a member's notifications up to and including
+    /// `up_to_seq`, returning how many were acknowledged. The member must
+    /// authorize.
+    pub fn ack_notifications(env: Env, user: Address, up_to_seq: u64) -> Result<u32, Error> {
+        NotificationModule::ack_notifications(env, user, up_to_seq)
+    }
+
+    /// Returns the number of unacknowledged notifications held for a member.
+    pub fn get_unread_notification_count(env: Env, user: Address) -> u32 {
+        NotificationModule::get_unread_count(&env, &user)
+    }
+
+    /// Notifies a subscriber that their pause reaches the maximum pause
+    /// duration within three days. Callable by anyone; returns `false` if
+    /// this pause was already notified.
     ///
     /// # Errors
-    /// * `AdminNotSet` / `Unauthorized` - Auth failure
-    /// * `TierAlreadyExists` - A tier with the same ID already exists
-    /// * `InvalidPaymentAmount` - Invalid tier parameters
-    pub fn create_staking_tier(env: Env, admin: Address, tier: StakingTier) -> Result<(), Error> {
-        StakingModule::create_staking_tier(env, admin, tier)
+    /// * `SubscriptionNotFound` - No such subscription
+    /// * `SubscriptionNotPaused` - The subscription is not paused
+    /// * `PauseTooEarly` - The pause ends more than three days from now
+    pub fn notify_pause_expiring(env: Env, subscription_id: String) -> Result<bool, Error> {
+        NotificationModule::notify_pause_expiring(env, subscription_id)
     }
 
-    /// Lock tokens into the specified staking tier.
-    ///
-    /// Requires the caller to have approved a token transfer from their wallet
-    /// to this contract (via the staking token's `approve` method) before calling.
+    // ============================================================================
+    // Contract Statistics
+    // ============================================================================
+
+    /// Returns contract-wide totals for tokens, subscriptions by status,
+    /// staking, fractionalization and lifetime revenue.
     ///
-    /// If the caller already has an active stake in the same tier, the amounts
-    /// are combined and the lock window resets.
+    /// Counters are maintained as state changes, so records created before
+    /// they were introduced are not included.
+    pub fn get_contract_stats(env: Env) -> ContractStats {
+        StatsModule::get_contract_stats(env)
+    }
+
+    /// Returns health counters for operators: paused subscriptions, tokens
+    /// still in a grace period and auto-renewals that failed over the last
+    /// 30 days.
+    pub fn get_operational_metrics(env: Env) -> OperationalMetrics {
+        StatsModule::get_operational_metrics(env)
+    }
+
+    // ============================================================================
+    // Event Outbox
+    // ============================================================================
+
+    /// Returns up to `limit` (max 100) outbox records with a sequence number
+    /// above `seq`, oldest first. Indexers pass the last sequence number they
+    /// processed; the outbox keeps the most recent 1,000 records, so a gap
+    /// between `seq` and the first record returned means records were
+    /// overwritten.
+    pub fn get_events_since(env: Env, seq: u64, limit: u32) -> Vec<OutboxRecord> {
+        OutboxModule::get_events_since(env, seq, limit)
+    }
+
+    /// Returns the sequence number of the most recent outbox record, or `0`
+    /// if none exist.
+    pub fn get_latest_event_seq(env: Env) -> u64 {
+        OutboxModule::get_latest_seq(env)
+    }
+
+    // ============================================================================
+    // Blacklist Enforcement
+    // ============================================================================
+
+    /// Turns enforcement of the access_control blacklist on or off for one
+    /// operation type. While enabled, blacklisted addresses are refused with
+    /// `Unauthorized`, as is every caller if the access_control contract
+    /// cannot be queried.
     ///
     /// # Arguments
-    /// * `env` - The contract environment
-    /// * `staker` - Staker address (must be authorized)
-    /// * `tier_id` - Staking tier to lock into
-    /// * `amount` - Number of tokens to lock
+    /// * `admin` - Contract admin
+    /// * `operation` - Operation type to configure
+    /// * `enabled` - Whether to enforce the blacklist
     ///
     /// # Errors
-    /// * `SubscriptionNotActive` - Staking is disabled
-    /// * `TierNotFound` - Tier ID does not exist
-    /// * `InvalidPaymentAmount` - Amount below tier minimum
-    /// * `Unauthorized` - Caller already has a stake in a different tier
-    pub fn stake_tokens(
+    /// * `Unauthorized` - Caller is not the admin
+    pub fn set_blacklist_enforcement(
         env: Env,
-        staker: Address,
-        tier_id: String,
-        amount: i128,
+        admin: Address,
+        operation: BlacklistOperation,
+        enabled: bool,
     ) -> Result<(), Error> {
-        StakingModule::stake_tokens(env, staker, tier_id, amount)
+        BlacklistModule::set_enforcement(env, admin, operation, enabled)
     }
 
-    /// Unlock tokens after the lock period has elapsed.
-    ///
-    /// Pending rewards are calculated and transferred together with the principal.
+    /// Returns `true` if the blacklist is enforced for `operation`.
+    pub fn is_blacklist_enforced(env: Env, operation: BlacklistOperation) -> bool {
+        BlacklistModule::is_enforced(&env, operation)
+    }
+
+    // ============================================================================
+    // Verification
+    // ============================================================================
+
+    /// Designates or removes a verifier, who can record and revoke
+    /// verifications alongside the admin.
     ///
     /// # Arguments
-    /// * `env` - The contract environment
-    /// * `staker` - Staker address (must be authorized)
+    /// * `admin` - Contract admin
+    /// * `verifier` - Address to designate or remove
+    /// * `enabled` - `true` to designate, `false` to remove
     ///
     /// # Errors
-    /// * `TokenNotFound` - No active stake found
-    /// * `PauseTooEarly` - Lock period has not elapsed yet
-    pub fn unstake_tokens(env: Env, staker: Address) -> Result<(), Error> {
-        StakingModule::unstake_tokens(env, staker)
+    /// * `Unauthorized` - Caller is not the admin
+    pub fn set_verifier(
+        env: Env,
+        admin: Address,
+        verifier: Address,
+        enabled: bool,
+    ) -> Result<(), Error> {
+        VerificationModule::set_verifier(env, admin, verifier, enabled)
     }
 
-    /// Emergency unstake: return tokens immediately with a penalty deducted.
-    ///
-    /// No staking rewards are paid. The penalty stays in the contract.
+    /// Returns `true` if `address` is a designated verifier.
+    pub fn is_verifier(env: Env, address: Address) -> bool {
+        VerificationModule::is_verifier(&env, &address)
+    }
+
+    /// Marks `user` as verified until `expires_at`, replacing any earlier
+    /// verification.
     ///
     /// # Arguments
-    /// * `env` - The contract environment
-    /// * `staker` - Staker address (must be authorized)
+    /// * `caller` - Admin or designated verifier
+    /// * `user` - Address being verified
+    /// * `expires_at` - Timestamp the verification lapses at
     ///
     /// # Errors
-    /// * `TokenNotFound` - No active stake found
-    pub fn emergency_unstake(env: Env, staker: Address) -> Result<(), Error> {
-        StakingModule::emergency_unstake(env, staker)
+    /// * `VerificationError::NotVerifier` - Caller is neither the admin nor a verifier
+    /// * `VerificationError::InvalidExpiry` - `expires_at` is not in the future
+    pub fn verify_user(
+        env: Env,
+        caller: Address,
+        user: Address,
+        expires_at: u64,
+    ) -> Result<(), ModuleError> {
+        VerificationModule::verify_user(env, caller, user, expires_at)
     }
 
-    /// Get the active stake information for a staker.
+    /// Removes `user`'s verification.
     ///
-    /// Returns `None` if the address has no active stake.
-    pub fn get_stake_info(env: Env, staker: Address) -> Option<StakeInfo> {
-        StakingModule::get_stake_info(env, staker)
+    /// # Errors
+    /// * `VerificationError::NotVerified` - Caller is neither the admin nor a verifier, or
+    ///   `user` has no verification
+    pub fn revoke_verification(
+        env: Env,
+        caller: Address,
+        user: Address,
+    ) -> Result<(), ModuleError> {
+        VerificationModule::revoke_verification(env, caller, user)
     }
 
-    /// Get all available staking tiers.
-    pub fn get_staking_tiers(env: Env) -> Vec<StakingTier> {
-        StakingModule::get_staking_tiers(env)
+    /// Returns `true` if `user` holds an unexpired verification.
+    pub fn is_verified(env: Env, user: Address) -> bool {
+        VerificationModule::is_verified(&env, &user)
     }
 
-    /// Get the global staking configuration.
-    ///
-    /// # Errors
-    /// * `AdminNotSet` - Staking has not been configured yet
-    pub fn get_staking_config(env: Env) -> Result<StakingConfig, Error> {
-        StakingModule::get_staking_config(env)
+    /// Returns `user`'s verification record, including an expired one.
+    pub fn get_verification(env: Env, user: Address) -> Option<VerificationRecord> {
+        VerificationModule::get_verification(&env, &user)
     }
 
-    // =========================================================================
-    // Token Upgrade Mechanism
-    // =========================================================================
-
-    /// Initialise or update the global upgrade configuration. Admin only.
-    ///
-    /// Must be called before any upgrade functions can be used.
-    ///
-    /// # Arguments
-    /// * `env`    - The contract environment
-    /// * `admin`  - Admin address (must be authorized)
-    /// * `config` - Upgrade configuration to apply
+    /// Replaces the policy deciding which operations require verification.
     ///
     /// # Errors
-    /// * `AdminNotSet`    - No admin has been set
-    /// * `Unauthorized`   - Caller is not the admin
-    pub fn set_upgrade_config(
+    /// * `Unauthorized` - Caller is not the admin
+    /// * `InvalidPaymentAmount` - The stake threshold is negative
+    pub fn set_verification_policy(
         env: Env,
         admin: Address,
-        config: UpgradeConfig,
+        policy: VerificationPolicy,
     ) -> Result<(), Error> {
-        UpgradeModule::set_upgrade_config(env, admin, config)
+        VerificationModule::set_policy(env, admin, policy)
     }
 
-    /// Upgrade a single token to the next version.
-    ///
-    /// Captures a pre-upgrade snapshot for rollback, increments `current_version`,
-    /// and optionally updates `expiry_date`, `tier_id`, and `status`.
-    /// Emits a `TokenUpgraded` event on success.
+    /// Returns the verification policy; nothing requires verification until
+    /// one is set.
+    pub fn get_verification_policy(env: Env) -> VerificationPolicy {
+        VerificationModule::get_policy(&env)
+    }
+
+    // ============================================================================
+    // Disputes
+    // ============================================================================
+
+    /// Files a dispute about a token, subscription or attendance log owned by
+    /// `user` and returns its ID.
     ///
     /// # Arguments
-    /// * `env`             - The contract environment
-    /// * `caller`          - Address triggering the upgrade (must be authorized)
-    /// * `token_id`        - ID of the token to upgrade
-    /// * `label`           - Optional human-readable version label (e.g. "v2-premium")
-    /// * `new_expiry_date` - Optional new expiry timestamp
-    /// * `new_tier_id`     - Optional new tier ID
-    /// * `new_status`      - Optional new membership status
-    ///
-    /// # Returns
-    /// The new version number on success.
+    /// * `user` - Member filing the dispute
+    /// * `subject` - What is being contested
+    /// * `evidence_hash` - Hash of the off-chain evidence
     ///
     /// # Errors
-    /// * `AdminNotSet`           - No admin has been set
-    /// * `SubscriptionNotActive` - Upgrades are disabled
-    /// * `TokenNotFound`         - Token does not exist
-    /// * `Unauthorized`          - Caller is not authorised
-    pub fn upgrade_token(
+    /// * `TokenNotFound` / `SubscriptionNotFound` - The subject does not exist
+    /// * `DisputeError::SubjectNotFound` - The attendance log does not exist
+    /// * `DisputeError::NotSubjectOwner` - The subject belongs to someone else
+    pub fn file_dispute(
         env: Env,
-        caller: Address,
-        token_id: BytesN<32>,
-        label: Option<String>,
-        new_expiry_date: Option<u64>,
-        new_tier_id: Option<String>,
-        new_status: Option<MembershipStatus>,
-    ) -> Result<u32, Error> {
-        UpgradeModule::upgrade_token(
-            env,
-            caller,
-            token_id,
-            label,
-            new_expiry_date,
-            new_tier_id,
-            new_status,
-        )
+        user: Address,
+        subject: DisputeSubject,
+        evidence_hash: BytesN<32>,
+    ) -> Result<u64, ModuleError> {
+        DisputeModule::file_dispute(env, user, subject, evidence_hash)
     }
 
-    /// Upgrade multiple tokens in a single call. Admin only.
-    ///
-    /// Individual token failures do NOT abort the entire batch; they are
-    /// reported as `success: false` in the returned result list.
+    /// Resolves an open dispute. Callable by the admin or by the
+    /// access_control contract executing a multisig proposal.
     ///
     /// # Arguments
-    /// * `env`             - The contract environment
-    /// * `admin`           - Admin address (must be authorized)
-    /// * `token_ids`       - List of token IDs to upgrade
-    /// * `label`           - Optional version label applied to all tokens
-    /// * `new_expiry_date` - Optional new expiry timestamp applied to all tokens
+    /// * `caller` - Admin or access_control contract
+    /// * `dispute_id` - Dispute to resolve
+    /// * `outcome` - Uphold, reverse, or compensate with loyalty points
     ///
     /// # Errors
-    /// * `AdminNotSet`           - No admin has been set
-    /// * `Unauthorized`          - Caller is not the admin
-    /// * `SubscriptionNotActive` - Upgrades are disabled
-    pub fn batch_upgrade_tokens(
+    /// * `Unauthorized` - Caller may not resolve disputes
+    /// * `DisputeError::DisputeNotFound` - No such dispute
+    /// * `DisputeError::AlreadyResolved` - The dispute is already resolved
+    /// * `DisputeError::InvalidCompensation` - Compensation of zero points
+    /// * `PauseError::ContractPaused` / `PauseError::TokenOpsPaused` - A reversal
+    ///   could not move the token back
+    pub fn resolve_dispute(
         env: Env,
-        admin: Address,
-        token_ids: Vec<BytesN<32>>,
-        label: Option<String>,
-        new_expiry_date: Option<u64>,
-    ) -> Result<Vec<BatchUpgradeResult>, Error> {
-        UpgradeModule::batch_upgrade_tokens(env, admin, token_ids, label, new_expiry_date)
+        caller: Address,
+        dispute_id: u64,
+        outcome: DisputeOutcome,
+    ) -> Result<(), ModuleError> {
+        DisputeModule::resolve_dispute(env, caller, dispute_id, outcome)
     }
 
-    /// Get the current version number of a token.
-    ///
-    /// # Arguments
-    /// * `env`      - The contract environment
-    /// * `token_id` - ID of the token to query
+    /// Returns a dispute and its status.
     ///
     /// # Errors
-    /// * `TokenNotFound` - Token does not exist
-    pub fn get_token_version(env: Env, token_id: BytesN<32>) -> Result<u32, Error> {
-        UpgradeModule::get_token_version(env, token_id)
+    /// * `DisputeError::DisputeNotFound` - No such dispute
+    pub fn get_dispute(env: Env, dispute_id: u64) -> Result<Dispute, ModuleError> {
+        DisputeModule::get_dispute(&env, dispute_id)
     }
 
-    /// Get the full upgrade history for a token.
-    ///
-    /// Returns an empty list if the token has never been upgraded.
-    ///
-    /// # Arguments
-    /// * `env`      - The contract environment
-    /// * `token_id` - ID of the token to query
-    pub fn get_upgrade_history(env: Env, token_id: BytesN<32>) -> Vec<UpgradeRecord> {
-        UpgradeModule::get_upgrade_history(env, token_id)
+    /// Returns the IDs of the disputes filed by `user`, oldest first.
+    pub fn get_user_disputes(env: Env, user: Address) -> Vec<u64> {
+        DisputeModule::get_user_disputes(&env, &user)
     }
 
-    /// Roll back a token to a specific previous version. Admin only.
-    ///
-    /// The token's version number continues to increment (not reset) so the
-    /// audit trail is preserved. The state (expiry, tier, status) from the
-    /// target snapshot is restored.
+    /// Returns the IDs of disputes awaiting resolution, oldest first.
+    pub fn get_open_disputes(env: Env) -> Vec<u64> {
+        DisputeModule::get_open_disputes(&env)
+    }
+
+    // ============================================================================
+    // TTL Management
+    // ============================================================================
+
+    /// Replaces the TTL settings applied when entries are read or written.
     ///
     /// # Arguments
-    /// * `env`            - The contract environment
-    /// * `admin`          - Admin address (must be authorized)
-    /// * `token_id`       - ID of the token to roll back
-    /// * `target_version` - The version number to restore state from
-    ///
-    /// # Returns
-    /// The new (incremented) version number after rollback.
+    /// * `admin` - Contract admin
+    /// * `policy` - Threshold and extension target per entity class
     ///
     /// # Errors
-    /// * `AdminNotSet`         - No admin has been set
-    /// * `Unauthorized`        - Caller is not the admin
-    /// * `TokenNotFound`       - Token does not exist
-    /// * `MetadataNotFound`    - No snapshot for `target_version`
-    /// * `PauseCountExceeded`  - Maximum rollback count reached
-    pub fn rollback_token_upgrade(
-        env: Env,
-        admin: Address,
-        token_id: BytesN<32>,
-        target_version: u32,
-    ) -> Result<u32, Error> {
-        UpgradeModule::rollback_token_upgrade(env, admin, token_id, target_version)
+    /// * `Unauthorized` - Caller is not the admin
+    /// * `InvalidPaymentAmount` - A setting is zero, has its threshold above
+    ///   its target, or exceeds the network's maximum TTL
+    pub fn set_ttl_policy(env: Env, admin: Address, policy: TtlPolicy) -> Result<(), Error> {
+        TtlModule::set_policy(env, admin, policy)
     }
 
-    /// Get the global upgrade configuration.
+    /// Returns the TTL settings per entity class.
+    pub fn get_ttl_policy(env: Env) -> TtlPolicy {
+        TtlModule::get_policy(&env)
+    }
+
+    /// Extends one entry's TTL according to the policy for its class.
+    ///
+    /// # Arguments
+    /// * `admin` - Contract admin
+    /// * `key` - Entry to extend
     ///
     /// # Errors
-    /// * `AdminNotSet` - Upgrade system has not been configured yet
-    pub fn get_upgrade_config(env: Env) -> Result<UpgradeConfig, Error> {
-        UpgradeModule::get_upgrade_config(env)
+    /// * `Unauthorized` - Caller is not the admin
+    /// * `TokenNotFound` / `SubscriptionNotFound` / `TierNotFound` - The
+    ///   entry does not exist
+    pub fn extend_entity_ttl(env: Env, admin: Address, key: TtlEntityKey) -> Result<(), Error> {
+        TtlModule::extend_entity_ttl(env, admin, key)
     }
 }
 
@@ -0,0 +1,49 @@
+//! Subscription lifecycle error types for the ManageHub contract.
+//!
+//! Unlike the other module errors, a rejected transition has no code range
+//! of its own: it is reported with the [`Error`] code for the state the
+//! subscription is in (`SubscriptionPaused`, `SubscriptionNotActive`, ...).
+
+use crate::errors::{Error, ModuleError};
+use crate::subscription_lifecycle::SubscriptionEvent;
+use crate::types::MembershipStatus;
+
+/// Subscription lifecycle errors.
+#[derive(Debug)]
+pub enum LifecycleError {
+    /// `event` is not allowed while the subscription is in `from`.
+    InvalidStateTransition {
+        from: MembershipStatus,
+        event: SubscriptionEvent,
+    },
+}
+
+impl From<LifecycleError> for Error {
+    fn from(e: LifecycleError) -> Self {
+        match e {
+            LifecycleError::InvalidStateTransition { from, event } => {
+                if from == MembershipStatus::Paused {
+                    Error::SubscriptionPaused
+                } else if event == SubscriptionEvent::Resume {
+                    Error::SubscriptionNotPaused
+                } else if event == SubscriptionEvent::Reactivate
+                    && matches!(
+                        from,
+                        MembershipStatus::Active | MembershipStatus::GracePeriod
+                    )
+                {
+                    // Still live: renew instead
+                    Error::SubscriptionAlreadyExists
+                } else {
+                    Error::SubscriptionNotActive
+                }
+            }
+        }
+    }
+}
+
+impl From<LifecycleError> for ModuleError {
+    fn from(e: LifecycleError) -> Self {
+        Error::from(e).into()
+    }
+}
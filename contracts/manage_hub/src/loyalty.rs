@@ -0,0 +1,378 @@
+use crate::errors::{Error, ModuleError};
+use crate::events::{
+    LoyaltyEarnRateSet, LoyaltyPointsEarned, LoyaltyPointsRedeemed, LoyaltyRewardSet,
+    LoyaltyStreakBonus,
+};
+use crate::loyalty_errors::LoyaltyError;
+use crate::membership_token::MembershipTokenContract;
+use crate::types::{
+    AttendanceAction, LoyaltyAccount, LoyaltyEarnRate, LoyaltyReward, LoyaltyStreak, StreakBonus,
+};
+use soroban_sdk::{contracttype, symbol_short, Address, Env, String, Symbol, Vec};
+
+// ---------------------------------------------------------------------------
+// Storage keys
+// ---------------------------------------------------------------------------
+
+#[contracttype]
+pub enum LoyaltyDataKey {
+    /// Points balance and day credits for a member (persistent storage).
+    Account(Address),
+    /// Earn rate for a tier; the empty tier ID holds the default (persistent storage).
+    EarnRate(String),
+    /// Tier a member last subscribed or renewed on (persistent storage).
+    MemberTier(Address),
+    /// Clock-in time of a member's open attendance session (persistent storage).
+    OpenSession(Address),
+    /// Catalog reward by ID (persistent storage).
+    Reward(String),
+    /// IDs of all catalog rewards (instance storage).
+    RewardList,
+    /// Daily check-in streak for a member (persistent storage).
+    Streak(Address),
+    /// Bonus granted on reaching a streak milestone, by day count (persistent storage).
+    StreakBonus(u32),
+}
+
+// ---------------------------------------------------------------------------
+// Constants
+// ---------------------------------------------------------------------------
+
+/// Shortest clock-in/clock-out pair that counts as a verified session.
+pub const MIN_VERIFIED_SESSION: u64 = 15 * 60;
+
+/// Consecutive check-in day counts that trigger a streak bonus.
+pub const STREAK_MILESTONES: [u32; 3] = [7, 30, 90];
+
+pub(crate) const SECONDS_PER_DAY: u64 = 24 * 60 * 60;
+
+// ---------------------------------------------------------------------------
+// Module
+// ---------------------------------------------------------------------------
+
+/// Attendance- and renewal-based loyalty points.
+///
+/// Points are earned per verified attendance session and per renewal at the
+/// rate configured for the member's tier (falling back to the default rate),
+/// and can be redeemed against the rewards catalog for subscription-day
+/// credits that are applied on the member's next subscription renewal.
+pub struct LoyaltyModule;
+
+impl LoyaltyModule {
+    // -----------------------------------------------------------------------
+    // Configuration
+    // -----------------------------------------------------------------------
+
+    pub fn set_earn_rate(
+        env: Env,
+        admin: Address,
+        tier_id: String,
+        rate: LoyaltyEarnRate,
+    ) -> Result<(), Error> {
+        MembershipTokenContract::require_admin(&env, &admin)?;
+        env.storage()
+            .persistent()
+            .set(&LoyaltyDataKey::EarnRate(tier_id.clone()), &rate);
+        LoyaltyEarnRateSet { tier_id, rate }.publish(&env);
+        Ok(())
+    }
+
+    /// Earn rate for a tier, falling back to the default (empty tier ID) rate.
+    pub fn get_earn_rate(env: &Env, tier_id: &String) -> LoyaltyEarnRate {
+        let storage = env.storage().persistent();
+        storage
+            .get(&LoyaltyDataKey::EarnRate(tier_id.clone()))
+            .or_else(|| storage.get(&LoyaltyDataKey::EarnRate(String::from_str(env, ""))))
+            .unwrap_or(LoyaltyEarnRate {
+                session_points: 0,
+                renewal_points: 0,
+            })
+    }
+
+    /// Adds or replaces a catalog reward. Admin only.
+    pub fn set_reward(env: Env, admin: Address, reward: LoyaltyReward) -> Result<(), ModuleError> {
+        MembershipTokenContract::require_admin(&env, &admin)?;
+        if reward.points_cost == 0 || reward.credit_days == 0 {
+            return Err(LoyaltyError::InvalidReward.into());
+        }
+
+        let mut list = Self::reward_ids(&env);
+        if !list.contains(&reward.id) {
+            list.push_back(reward.id.clone());
+            env.storage()
+                .instance()
+                .set(&LoyaltyDataKey::RewardList, &list);
+        }
+        env.storage()
+            .persistent()
+            .set(&LoyaltyDataKey::Reward(reward.id.clone()), &reward);
+
+        LoyaltyRewardSet {
+            reward_id: reward.id.clone(),
+            reward,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    pub fn get_rewards(env: &Env) -> Vec<LoyaltyReward> {
+        let mut rewards = Vec::new(env);
+        for id in Self::reward_ids(env).iter() {
+            if let Some(reward) = env.storage().persistent().get(&LoyaltyDataKey::Reward(id)) {
+                rewards.push_back(reward);
+            }
+        }
+        rewards
+    }
+
+    fn reward_ids(env: &Env) -> Vec<String> {
+        env.storage()
+            .instance()
+            .get(&LoyaltyDataKey::RewardList)
+            .unwrap_or(Vec::new(env))
+    }
+
+    // -----------------------------------------------------------------------
+    // Balances
+    // -----------------------------------------------------------------------
+
+    pub fn get_account(env: &Env, user: &Address) -> LoyaltyAccount {
+        env.storage()
+            .persistent()
+            .get(&LoyaltyDataKey::Account(user.clone()))
+            .unwrap_or(LoyaltyAccount {
+                points: 0,
+                lifetime_points: 0,
+                day_credits: 0,
+                verified_sessions: 0,
+            })
+    }
+
+    fn save_account(env: &Env, user: &Address, account: &LoyaltyAccount) {
+        env.storage()
+            .persistent()
+            .set(&LoyaltyDataKey::Account(user.clone()), account);
+    }
+
+    pub(crate) fn award_points(env: &Env, user: &Address, points: u32, reason: Symbol) {
+        if points == 0 {
+            return;
+        }
+        let mut account = Self::get_account(env, user);
+        account.points = account.points.saturating_add(points as u64);
+        account.lifetime_points = account.lifetime_points.saturating_add(points as u64);
+        Self::save_account(env, user, &account);
+
+        LoyaltyPointsEarned {
+            user: user.clone(),
+            points,
+            reason,
+            balance: account.points,
+        }
+        .publish(env);
+    }
+
+    /// Spends points on a catalog reward and banks its subscription-day credits.
+    pub fn redeem_points(
+        env: Env,
+        user: Address,
+        reward_id: String,
+    ) -> Result<LoyaltyAccount, ModuleError> {
+        user.require_auth();
+
+        let reward: LoyaltyReward = env
+            .storage()
+            .persistent()
+            .get(&LoyaltyDataKey::Reward(reward_id.clone()))
+            .filter(|reward: &LoyaltyReward| reward.is_active)
+            .ok_or(LoyaltyError::RewardNotFound)?;
+
+        let mut account = Self::get_account(&env, &user);
+        if account.points < reward.points_cost {
+            return Err(LoyaltyError::InsufficientPoints.into());
+        }
+        account.points -= reward.points_cost;
+        account.day_credits = account.day_credits.saturating_add(reward.credit_days);
+        Self::save_account(&env, &user, &account);
+
+        LoyaltyPointsRedeemed {
+            user,
+            reward_id,
+            points_cost: reward.points_cost,
+            credit_days: reward.credit_days,
+        }
+        .publish(&env);
+        Ok(account)
+    }
+
+    /// Banks subscription-day credits for the member's next renewal.
+    pub(crate) fn grant_day_credits(env: &Env, user: &Address, days: u32) {
+        if days == 0 {
+            return;
+        }
+        let mut account = Self::get_account(env, user);
+        account.day_credits = account.day_credits.saturating_add(days);
+        Self::save_account(env, user, &account);
+    }
+
+    /// Consumes the member's banked day credits, returning them in seconds.
+    pub(crate) fn take_day_credits(env: &Env, user: &Address) -> u64 {
+        let mut account = Self::get_account(env, user);
+        if account.day_credits == 0 {
+            return 0;
+        }
+        let seconds = account.day_credits as u64 * SECONDS_PER_DAY;
+        account.day_credits = 0;
+        Self::save_account(env, user, &account);
+        seconds
+    }
+
+    // -----------------------------------------------------------------------
+    // Earning
+    // -----------------------------------------------------------------------
+
+    /// Remembers the member's tier for session earn-rate lookups.
+    pub(crate) fn set_member_tier(env: &Env, user: &Address, tier_id: &String) {
+        if tier_id.is_empty() {
+            return;
+        }
+        env.storage()
+            .persistent()
+            .set(&LoyaltyDataKey::MemberTier(user.clone()), tier_id);
+    }
+
+    fn member_tier(env: &Env, user: &Address) -> String {
+        env.storage()
+            .persistent()
+            .get(&LoyaltyDataKey::MemberTier(user.clone()))
+            .unwrap_or(String::from_str(env, ""))
+    }
+
+    /// Awards renewal points at the tier's rate.
+    pub(crate) fn on_renewal(env: &Env, user: &Address, tier_id: &String) {
+        Self::set_member_tier(env, user, tier_id);
+        let rate = Self::get_earn_rate(env, tier_id);
+        Self::award_points(env, user, rate.renewal_points, symbol_short!("renewal"));
+    }
+
+    /// Tracks clock-in/clock-out pairs and awards session points once a
+    /// session of at least `MIN_VERIFIED_SESSION` is closed.
+    pub(crate) fn on_attendance(
+        env: &Env,
+        user: &Address,
+        action: &AttendanceAction,
+        timestamp: u64,
+    ) {
+        let key = LoyaltyDataKey::OpenSession(user.clone());
+        match action {
+            AttendanceAction::ClockIn => {
+                env.storage().persistent().set(&key, &timestamp);
+                Self::record_check_in(env, user, timestamp);
+            }
+            AttendanceAction::ClockOut => {
+                let opened: Option<u64> = env.storage().persistent().get(&key);
+                if let Some(clock_in) = opened {
+                    env.storage().persistent().remove(&key);
+                    if timestamp.saturating_sub(clock_in) >= MIN_VERIFIED_SESSION {
+                        let mut account = Self::get_account(env, user);
+                        account.verified_sessions = account.verified_sessions.saturating_add(1);
+                        Self::save_account(env, user, &account);
+
+                        let rate = Self::get_earn_rate(env, &Self::member_tier(env, user));
+                        Self::award_points(
+                            env,
+                            user,
+                            rate.session_points,
+                            symbol_short!("session"),
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    // -----------------------------------------------------------------------
+    // Streaks
+    // -----------------------------------------------------------------------
+
+    /// Sets the bonus granted when a member's streak reaches `milestone` days.
+    pub fn set_streak_bonus(
+        env: Env,
+        admin: Address,
+        milestone: u32,
+        bonus: StreakBonus,
+    ) -> Result<(), ModuleError> {
+        MembershipTokenContract::require_admin(&env, &admin)?;
+        if !STREAK_MILESTONES.contains(&milestone) {
+            return Err(LoyaltyError::InvalidStreakMilestone.into());
+        }
+        env.storage()
+            .persistent()
+            .set(&LoyaltyDataKey::StreakBonus(milestone), &bonus);
+        Ok(())
+    }
+
+    pub fn get_streak_bonus(env: &Env, milestone: u32) -> Option<StreakBonus> {
+        env.storage()
+            .persistent()
+            .get(&LoyaltyDataKey::StreakBonus(milestone))
+    }
+
+    pub fn get_streak(env: &Env, user: &Address) -> LoyaltyStreak {
+        env.storage()
+            .persistent()
+            .get(&LoyaltyDataKey::Streak(user.clone()))
+            .unwrap_or(LoyaltyStreak {
+                current: 0,
+                longest: 0,
+                last_day: 0,
+            })
+    }
+
+    /// Current streak length; 0 once a full day has passed without a check-in.
+    pub fn current_streak(env: &Env, user: &Address) -> u32 {
+        let streak = Self::get_streak(env, user);
+        let today = env.ledger().timestamp() / SECONDS_PER_DAY;
+        if streak.last_day + 1 < today {
+            0
+        } else {
+            streak.current
+        }
+    }
+
+    /// Counts at most one check-in per UTC day. A gap of more than one day
+    /// restarts the streak; reaching a milestone grants its bonus.
+    fn record_check_in(env: &Env, user: &Address, timestamp: u64) {
+        let today = timestamp / SECONDS_PER_DAY;
+        let mut streak = Self::get_streak(env, user);
+        if streak.current > 0 && streak.last_day == today {
+            return;
+        }
+
+        streak.current = if streak.current > 0 && streak.last_day + 1 == today {
+            streak.current.saturating_add(1)
+        } else {
+            1
+        };
+        streak.longest = streak.longest.max(streak.current);
+        streak.last_day = today;
+        env.storage()
+            .persistent()
+            .set(&LoyaltyDataKey::Streak(user.clone()), &streak);
+
+        if !STREAK_MILESTONES.contains(&streak.current) {
+            return;
+        }
+        if let Some(bonus) = Self::get_streak_bonus(env, streak.current) {
+            Self::award_points(env, user, bonus.points, symbol_short!("streak"));
+            Self::grant_day_credits(env, user, bonus.credit_days);
+            LoyaltyStreakBonus {
+                user: user.clone(),
+                streak: streak.current,
+                points: bonus.points,
+                credit_days: bonus.credit_days,
+            }
+            .publish(env);
+        }
+    }
+}
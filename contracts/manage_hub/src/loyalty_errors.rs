@@ -0,0 +1,19 @@
+//! Loyalty-related error types for the ManageHub contract.
+//!
+//! `LoyaltyError` owns the code range 1200–1249 (see [`crate::errors`]).
+
+use soroban_sdk::contracterror;
+
+/// Loyalty-specific errors.
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum LoyaltyError {
+    /// The reward is not in the catalog or has been deactivated.
+    RewardNotFound = 1200,
+    /// A catalog reward must cost points and grant at least one credit day.
+    InvalidReward = 1201,
+    /// The member does not have enough points for the reward.
+    InsufficientPoints = 1202,
+    /// Streak bonuses can only be configured for the supported milestones.
+    InvalidStreakMilestone = 1203,
+}
@@ -0,0 +1,47 @@
+use crate::events::MembershipSyncFailed;
+use crate::initialization::InitializationModule;
+use crate::types::MembershipStatus;
+use soroban_sdk::{Address, Env, IntoVal, Symbol, Val, Vec};
+
+// ---------------------------------------------------------------------------
+// Module
+// ---------------------------------------------------------------------------
+
+/// Pushes token status changes to the membership registry of the
+/// access_control contract recorded at initialization, so it can require an
+/// active membership alongside a role.
+///
+/// The call is made with `try_invoke_contract`: a missing or failing
+/// access_control contract is reported through a `membership_sync_failed` event and never
+/// reverts the token operation.
+pub struct MembershipSyncModule;
+
+impl MembershipSyncModule {
+    pub(crate) fn sync(env: &Env, user: &Address, status: MembershipStatus, expires_at: u64) {
+        let Some(access_control) = InitializationModule::get_access_control(env) else {
+            return;
+        };
+
+        let args: Vec<Val> = Vec::from_array(
+            env,
+            [
+                user.into_val(env),
+                status.into_val(env),
+                expires_at.into_val(env),
+            ],
+        );
+        let result = env.try_invoke_contract::<(), soroban_sdk::Error>(
+            &access_control,
+            &Symbol::new(env, "sync_membership"),
+            args,
+        );
+        if !matches!(result, Ok(Ok(()))) {
+            MembershipSyncFailed {
+                user: user.clone(),
+                status,
+                expires_at,
+            }
+            .publish(env);
+        }
+    }
+}
@@ -1,15 +1,48 @@
-// Allow deprecated events API until migration to #[contractevent] macro
-#![allow(deprecated)]
-
+use crate::admin_errors::AdminTransferError;
 use crate::allowance::AllowanceModule;
-use crate::errors::Error;
+use crate::blacklist::BlacklistModule;
+use crate::errors::{Error, ModuleError};
+use crate::events::{
+    AdminSet, AdminTransferCancelled, AdminTransferProposed, AdminTransferred,
+    AutoRenewalConsentRecorded, AutoRenewalConsentRequired, AutoRenewalUpdated,
+    EmergencyPauseExtended, EmergencyPaused, EmergencyUnpaused, GracePeriodEntered,
+    MetadataHistoryPruned, MetadataRemoved, MetadataSet, MetadataUpdated, RenewalConfigUpdated,
+    TierGracePolicyRemoved, TierGracePolicySet, TokenDelegatedTransfer, TokenIssued,
+    TokenPauseExtended, TokenPaused, TokenRenewed, TokenSold, TokenTransferred, TokenUnpaused,
+    TokenUriSet,
+};
+use crate::fees::FeeModule;
 use crate::fractionalization::FractionalizationModule;
+use crate::guardians::GuardianModule;
 use crate::guards::PauseGuard;
-use crate::types::{EmergencyPauseState, MembershipStatus, TokenAllowance, TokenPauseState};
+use crate::loyalty::LoyaltyModule;
+use crate::membership_sync::MembershipSyncModule;
+use crate::metadata_schema::MetadataSchemaModule;
+use crate::migration::MigrationModule;
+use crate::notifications::NotificationModule;
+use crate::outbox::OutboxModule;
+use crate::sales::SaleModule;
+use crate::snapshot::SnapshotModule;
+use crate::stats::StatsModule;
+use crate::treasury::TreasuryModule;
+use crate::ttl::{TtlEntityClass, TtlModule};
+use crate::types::{
+    BatchPauseResult, BlacklistOperation, EmergencyPauseState, FeeBreakdown, GlobalPauseRecord,
+    GraceFeatureAccess, GracePolicy, LegacyAutoRenewalSettings, MatchMode, MembershipStatus,
+    NotificationKind, OutboxSubject, PendingAdminTransfer, PendingRenewalConsent,
+    PrunedMetadataHistory, RenewalConsent, RenewalWindow, RevenueSource, TokenAllowance,
+    TokenPauseRecord, TokenPauseState,
+};
+use crate::upgrade::UpgradeModule;
+use crate::validation::BatchValidator;
 use common_types::{
     validate_attribute, validate_metadata, MetadataUpdate, MetadataValue, TokenMetadata,
 };
-use soroban_sdk::{contracttype, symbol_short, Address, BytesN, Env, Map, String, Vec};
+use soroban_sdk::xdr::ToXdr;
+use soroban_sdk::{
+    contracttype, symbol_short, Address, Bytes, BytesN, Env, Map, String, Symbol, TryFromVal, Val,
+    Vec,
+};
 
 #[contracttype]
 pub enum DataKey {
@@ -21,9 +54,31 @@ pub enum DataKey {
     /// This allows efficient querying of tokens by metadata attributes
     /// Using MetadataValue directly avoids serialization complexity
     MetadataIndex(String, MetadataValue),
+    /// Maximum metadata history entries kept per token (instance storage).
+    MetadataHistoryLimit,
+    /// Digest of a token's pruned metadata history (persistent storage).
+    PrunedMetadataHistory(BytesN<32>),
+    /// Sorted IDs of the populated number-value buckets for an attribute key
+    /// (persistent storage).
+    NumberIndexBuckets(String),
+    /// Sorted distinct `Number` values of an attribute key within one bucket
+    /// of `NUMBER_INDEX_BUCKET_WIDTH` (persistent storage).
+    NumberIndexBucket(String, i128),
     RenewalConfig,
+    /// Grace-period policy overriding `RenewalConfig` for a tier
+    /// (persistent storage).
+    TierGracePolicy(String),
     RenewalHistory(BytesN<32>),
     AutoRenewalSettings(Address),
+    /// Auto-renewal consents given for a token, oldest first (persistent
+    /// storage).
+    RenewalConsents(BytesN<32>),
+    /// Auto-renewal of a token waiting for consent to a higher price
+    /// (persistent storage).
+    PendingRenewalConsent(BytesN<32>),
+    /// IDs of the tokens expiring on a UTC day, keyed by day number
+    /// (persistent storage).
+    ExpiringOn(u64),
     /// Global emergency pause state (instance storage — visible to all ops immediately).
     EmergencyPauseState,
     /// Per-token pause state (persistent storage keyed by token ID).
@@ -34,9 +89,65 @@ pub enum DataKey {
     UpgradeHistory(BytesN<32>),
     /// Version snapshot for rollback, keyed by token ID and version number.
     VersionSnapshot(BytesN<32>, u32),
+    /// Version snapshot pruning policy (instance storage).
+    SnapshotRetention,
+    /// Token upgrade waiting for its effective time (persistent storage).
+    ScheduledTokenUpgrade(u64),
+    /// IDs of the scheduled token upgrades not yet fully applied, oldest
+    /// first (persistent storage).
+    PendingTokenUpgrades,
+    /// Next scheduled token upgrade ID (instance storage).
+    NextTokenUpgradeId,
     Royalty(BytesN<32>),
+    /// Admin transfer awaiting acceptance (instance storage).
+    PendingAdmin,
+    /// Audit trail of global emergency pauses, oldest first (persistent storage).
+    EmergencyPauseHistory,
+    /// Hub fee charged on `transfer_from` resales (instance storage).
+    TransferFee,
+    /// Addresses exempt from the transfer fee (persistent storage).
+    TransferFeeExempt(Address),
+    /// IDs of the tokens currently held by an address (persistent storage).
+    OwnerTokens(Address),
+    /// Audit trail of a token's pauses, oldest first (persistent storage).
+    TokenPauseHistory(BytesN<32>),
 }
 
+/// How long a proposed admin has to accept the transfer (24 hours).
+const ADMIN_TRANSFER_EXPIRY: u64 = 24 * 60 * 60;
+
+/// Maximum records returned by one `get_emergency_pause_history` or
+/// `get_token_pause_history` call.
+pub const MAX_PAUSE_HISTORY_PAGE: u32 = 50;
+
+/// Maximum attribute filters in one `query_tokens_by_attributes` call.
+pub const MAX_METADATA_QUERY_FILTERS: u32 = 10;
+
+/// Maximum token IDs returned by one `query_tokens_by_attributes` call.
+pub const MAX_METADATA_QUERY_PAGE: u32 = 100;
+
+/// Metadata history entries kept per token until the admin sets a limit.
+pub const DEFAULT_METADATA_HISTORY_LIMIT: u32 = 50;
+
+/// Upper bound on the configurable metadata history limit.
+pub const MAX_METADATA_HISTORY_LIMIT: u32 = 200;
+
+/// Width of the value buckets behind `query_tokens_by_number_range`.
+pub const NUMBER_INDEX_BUCKET_WIDTH: i128 = 100;
+
+/// Auto-renewal consents kept per token; the oldest are dropped first.
+pub const MAX_RENEWAL_CONSENTS: u32 = 20;
+
+/// Width of an expiry index bucket (one UTC day).
+const EXPIRY_BUCKET_SECS: u64 = 24 * 60 * 60;
+
+/// Most expiry index days `list_auto_renewal_candidates` scans, bounding
+/// the call when the notice period is long.
+pub const MAX_RENEWAL_SCAN_DAYS: u64 = 31;
+
+/// Most candidates `list_auto_renewal_candidates` returns per call.
+pub const MAX_RENEWAL_CANDIDATES_PAGE: u32 = 50;
+
 #[contracttype]
 #[derive(Clone, Debug, PartialEq)]
 pub struct MembershipToken {
@@ -67,7 +178,7 @@ impl MembershipTokenContract {
         id: BytesN<32>,
         user: Address,
         expiry_date: u64,
-    ) -> Result<(), Error> {
+    ) -> Result<(), ModuleError> {
         // Block minting when the contract is globally paused.
         PauseGuard::require_not_paused(&env)?;
 
@@ -88,16 +199,18 @@ impl MembershipTokenContract {
         id: BytesN<32>,
         user: Address,
         expiry_date: u64,
-    ) -> Result<(), Error> {
+    ) -> Result<(), ModuleError> {
+        BlacklistModule::require_not_blacklisted(env, BlacklistOperation::Issue, &user)?;
+
         // Check if token already exists
         if env.storage().persistent().has(&DataKey::Token(id.clone())) {
-            return Err(Error::TokenAlreadyIssued);
+            return Err(Error::TokenAlreadyIssued.into());
         }
 
         // Validate expiry date (must be in the future)
         let current_time = env.ledger().timestamp();
         if expiry_date <= current_time {
-            return Err(Error::InvalidExpiryDate);
+            return Err(Error::InvalidExpiryDate.into());
         }
 
         // Create and store token
@@ -117,17 +230,29 @@ impl MembershipTokenContract {
         env.storage()
             .persistent()
             .set(&DataKey::Token(id.clone()), &token);
+        TtlModule::bump(env, TtlEntityClass::Token, &DataKey::Token(id.clone()));
+        MigrationModule::mark_token_current(env, &id);
+        UpgradeModule::snapshot_token(env, &id, &token, None);
+        Self::index_owner_token(env, &user, &id);
+        Self::reindex_expiry(env, &id, None, expiry_date);
+        StatsModule::record_token_issued(env);
+        MembershipSyncModule::sync(env, &user, MembershipStatus::Active, expiry_date);
+        OutboxModule::append(
+            env,
+            symbol_short!("tok_issue"),
+            OutboxSubject::Token(id.clone()),
+        );
 
         // Emit token issued event
-        env.events().publish(
-            (symbol_short!("token_iss"), id.clone(), user.clone()),
-            (
-                admin.clone(),
-                current_time,
-                expiry_date,
-                MembershipStatus::Active,
-            ),
-        );
+        TokenIssued {
+            token_id: id.clone(),
+            user: user.clone(),
+            issued_by: admin.clone(),
+            issued_at: current_time,
+            expiry_date,
+            status: MembershipStatus::Active,
+        }
+        .publish(env);
 
         Ok(())
     }
@@ -135,7 +260,7 @@ impl MembershipTokenContract {
     pub fn batch_issue_tokens(
         env: Env,
         params: Vec<crate::types::BatchMintParams>,
-    ) -> Result<(), Error> {
+    ) -> Result<(), ModuleError> {
         PauseGuard::require_not_paused(&env)?;
 
         let admin: Address = env
@@ -152,54 +277,172 @@ impl MembershipTokenContract {
         Ok(())
     }
 
-    pub fn transfer_token(env: Env, id: BytesN<32>, new_user: Address) -> Result<(), Error> {
+    pub fn transfer_token(env: Env, id: BytesN<32>, new_user: Address) -> Result<(), ModuleError> {
         // Block transfers when the contract is globally paused or this token is paused.
         PauseGuard::require_not_paused(&env)?;
         Self::internal_transfer_token(&env, id, new_user)
     }
 
-    fn internal_transfer_token(env: &Env, id: BytesN<32>, new_user: Address) -> Result<(), Error> {
-        PauseGuard::require_token_not_paused(env, &id)?;
+    /// Transfers a token on the owner's behalf. `operator` must be the owner
+    /// or approved for all of the owner's tokens.
+    pub fn operator_transfer_token(
+        env: Env,
+        operator: Address,
+        id: BytesN<32>,
+        new_user: Address,
+    ) -> Result<(), ModuleError> {
+        PauseGuard::require_not_paused(&env)?;
+        let token = Self::get_transferable_token(&env, &id)?;
+        Self::require_token_authority(&env, &token, Some(&operator))?;
+        Self::reassign_token(&env, token, new_user)
+    }
+
+    /// Requires authorization for an action on `token`: from the owner when
+    /// `operator` is `None`, otherwise from `operator`, which must be the
+    /// owner or approved for all of the owner's tokens.
+    pub(crate) fn require_token_authority(
+        env: &Env,
+        token: &MembershipToken,
+        operator: Option<&Address>,
+    ) -> Result<(), Error> {
+        match operator {
+            None => token.user.require_auth(),
+            Some(operator) => {
+                if *operator != token.user
+                    && !AllowanceModule::is_approved_for_all(env, &token.user, operator)
+                {
+                    return Err(Error::Unauthorized);
+                }
+                operator.require_auth();
+            }
+        }
+        Ok(())
+    }
+
+    fn internal_transfer_token(
+        env: &Env,
+        id: BytesN<32>,
+        new_user: Address,
+    ) -> Result<(), ModuleError> {
+        let token = Self::get_transferable_token(env, &id)?;
+
+        // Require current user authorization
+        token.user.require_auth();
+
+        Self::reassign_token(env, token, new_user)
+    }
+
+    /// Loads a token that can change hands: not paused, not fractionalized
+    /// and active. Does not check the owner's authorization.
+    pub(crate) fn get_transferable_token(
+        env: &Env,
+        id: &BytesN<32>,
+    ) -> Result<MembershipToken, ModuleError> {
+        PauseGuard::require_token_not_paused(env, id)?;
 
-        if FractionalizationModule::is_fractionalized(env, &id) {
-            return Err(Error::TokenFractionalized);
+        if FractionalizationModule::is_fractionalized(env, id) {
+            return Err(Error::TokenFractionalized.into());
         }
 
         // Retrieve token
-        let mut token: MembershipToken = env
+        let token: MembershipToken = env
             .storage()
             .persistent()
             .get(&DataKey::Token(id.clone()))
             .ok_or(Error::TokenNotFound)?;
 
-        // Check if token is in grace period - transfers not allowed
-        if token.status == MembershipStatus::GracePeriod {
-            return Err(Error::TransferNotAllowedInGracePeriod);
-        }
+        // Tokens in their grace period transfer only if the tier allows it
+        Self::require_transferable(env, &token)?;
 
-        // Check if token is active
-        if token.status != MembershipStatus::Active {
-            return Err(Error::TokenExpired);
+        Ok(token)
+    }
+
+    /// IDs of the tokens currently held by `owner`.
+    pub(crate) fn get_owner_tokens(env: &Env, owner: &Address) -> Vec<BytesN<32>> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::OwnerTokens(owner.clone()))
+            .unwrap_or_else(|| Vec::new(env))
+    }
+
+    fn index_owner_token(env: &Env, owner: &Address, token_id: &BytesN<32>) {
+        SnapshotModule::index_member(env, owner);
+        let mut tokens = Self::get_owner_tokens(env, owner);
+        if !tokens.contains(token_id) {
+            tokens.push_back(token_id.clone());
+            env.storage()
+                .persistent()
+                .set(&DataKey::OwnerTokens(owner.clone()), &tokens);
         }
+    }
 
-        // Require current user authorization
-        token.user.require_auth();
+    /// Updates the owner index when a token changes hands.
+    pub(crate) fn move_owner_token(
+        env: &Env,
+        token_id: &BytesN<32>,
+        old_owner: &Address,
+        new_owner: &Address,
+    ) {
+        let mut tokens = Self::get_owner_tokens(env, old_owner);
+        if let Some(index) = tokens.first_index_of(token_id) {
+            tokens.remove(index);
+            let key = DataKey::OwnerTokens(old_owner.clone());
+            if tokens.is_empty() {
+                env.storage().persistent().remove(&key);
+            } else {
+                env.storage().persistent().set(&key, &tokens);
+            }
+        }
+        Self::index_owner_token(env, new_owner, token_id);
+    }
 
+    /// Moves a token to `new_user` and emits `TokenTransferred`. Callers
+    /// must have authorized the transfer.
+    pub(crate) fn reassign_token(
+        env: &Env,
+        mut token: MembershipToken,
+        new_user: Address,
+    ) -> Result<(), ModuleError> {
         // Capture old user for event emission
         let old_user = token.user.clone();
 
+        BlacklistModule::require_not_blacklisted(env, BlacklistOperation::Transfer, &old_user)?;
+        BlacklistModule::require_not_blacklisted(env, BlacklistOperation::Transfer, &new_user)?;
+
+        // Approvals and listings made by the previous owner must not outlive
+        // the transfer
+        if old_user != new_user {
+            AllowanceModule::revoke_all_allowances(env, &token.id, &old_user);
+            SaleModule::clear_listing(env, &token.id);
+            Self::move_owner_token(env, &token.id, &old_user, &new_user);
+        }
+
         // Update token owner
         token.user = new_user.clone();
         env.storage()
             .persistent()
-            .set(&DataKey::Token(id.clone()), &token);
+            .set(&DataKey::Token(token.id.clone()), &token);
+        TtlModule::bump(
+            env,
+            TtlEntityClass::Token,
+            &DataKey::Token(token.id.clone()),
+        );
 
-        // Emit token transferred event
-        env.events().publish(
-            (symbol_short!("token_xfr"), id.clone(), new_user.clone()),
-            (old_user, env.ledger().timestamp()),
+        OutboxModule::append(
+            env,
+            symbol_short!("tok_xfer"),
+            OutboxSubject::Token(token.id.clone()),
         );
 
+        // Emit token transferred event
+        TokenTransferred {
+            token_id: token.id,
+            to: new_user,
+            from: old_user,
+            timestamp: env.ledger().timestamp(),
+        }
+        .publish(env);
+
         Ok(())
     }
 
@@ -209,7 +452,7 @@ impl MembershipTokenContract {
         new_user: Address,
         payment_token: Address,
         sale_price: i128,
-    ) -> Result<(), Error> {
+    ) -> Result<(), ModuleError> {
         // First do the standard transfer logic (which includes auth and ownership changes)
         Self::transfer_token(env.clone(), id.clone(), new_user.clone())?;
 
@@ -222,17 +465,21 @@ impl MembershipTokenContract {
         )?;
 
         // Emit token transferred event with sale price info
-        env.events().publish(
-            (symbol_short!("tok_sale"), id, new_user),
-            (sale_price, env.ledger().timestamp()),
-        );
+        TokenSold {
+            token_id: id,
+            buyer: new_user,
+            payment_token,
+            sale_price,
+            timestamp: env.ledger().timestamp(),
+        }
+        .publish(&env);
 
         Ok(())
     }
     pub fn batch_transfer_tokens(
         env: Env,
         params: Vec<crate::types::BatchTransferParams>,
-    ) -> Result<(), Error> {
+    ) -> Result<(), ModuleError> {
         PauseGuard::require_not_paused(&env)?;
 
         for p in params.iter() {
@@ -248,12 +495,12 @@ impl MembershipTokenContract {
         spender: Address,
         amount: i128,
         expires_at: Option<u64>,
-    ) -> Result<(), Error> {
+    ) -> Result<(), ModuleError> {
         PauseGuard::require_not_paused(&env)?;
         PauseGuard::require_token_not_paused(&env, &token_id)?;
 
         if FractionalizationModule::is_fractionalized(&env, &token_id) {
-            return Err(Error::TokenFractionalized);
+            return Err(Error::TokenFractionalized.into());
         }
 
         let token: MembershipToken = env
@@ -262,15 +509,17 @@ impl MembershipTokenContract {
             .get(&DataKey::Token(token_id.clone()))
             .ok_or(Error::TokenNotFound)?;
 
-        if token.status == MembershipStatus::GracePeriod {
-            return Err(Error::TransferNotAllowedInGracePeriod);
-        }
-        if token.status != MembershipStatus::Active {
-            return Err(Error::TokenExpired);
-        }
+        Self::require_transferable(&env, &token)?;
 
         token.user.require_auth();
-        AllowanceModule::approve(&env, &token_id, &token.user, &spender, amount, expires_at)
+        Ok(AllowanceModule::approve(
+            &env,
+            &token_id,
+            &token.user,
+            &spender,
+            amount,
+            expires_at,
+        )?)
     }
 
     pub fn transfer_from(
@@ -280,56 +529,62 @@ impl MembershipTokenContract {
         to: Address,
         spender: Address,
         allowance_amount: i128,
-    ) -> Result<(), Error> {
+    ) -> Result<(), ModuleError> {
         PauseGuard::require_not_paused(&env)?;
         PauseGuard::require_token_not_paused(&env, &token_id)?;
 
         if FractionalizationModule::is_fractionalized(&env, &token_id) {
-            return Err(Error::TokenFractionalized);
+            return Err(Error::TokenFractionalized.into());
         }
         if allowance_amount <= 0 {
-            return Err(Error::InvalidPaymentAmount);
+            return Err(Error::InvalidPaymentAmount.into());
         }
 
         spender.require_auth();
 
-        let mut token: MembershipToken = env
+        let token: MembershipToken = env
             .storage()
             .persistent()
             .get(&DataKey::Token(token_id.clone()))
             .ok_or(Error::TokenNotFound)?;
 
         if token.user != owner {
-            return Err(Error::Unauthorized);
-        }
-        if token.status == MembershipStatus::GracePeriod {
-            return Err(Error::TransferNotAllowedInGracePeriod);
-        }
-        if token.status != MembershipStatus::Active {
-            return Err(Error::TokenExpired);
+            return Err(Error::Unauthorized.into());
         }
+        Self::require_transferable(&env, &token)?;
 
         AllowanceModule::consume_allowance(&env, &token_id, &owner, &spender, allowance_amount)?;
+        if to != owner {
+            crate::royalty::RoyaltyModule::charge_transfer_fee(
+                &env,
+                &token_id,
+                &owner,
+                &spender,
+                allowance_amount,
+            )?;
+        }
 
         let old_user = token.user.clone();
-        token.user = to.clone();
-        env.storage()
-            .persistent()
-            .set(&DataKey::Token(token_id.clone()), &token);
-
-        env.events().publish(
-            (symbol_short!("token_xfr"), token_id.clone(), to.clone()),
-            (old_user.clone(), env.ledger().timestamp()),
-        );
-        env.events().publish(
-            (symbol_short!("token_dlg"), token_id, spender),
-            (old_user, to, allowance_amount, env.ledger().timestamp()),
-        );
+        Self::reassign_token(&env, token, to.clone())?;
+
+        TokenDelegatedTransfer {
+            token_id,
+            spender,
+            from: old_user,
+            to,
+            allowance_used: allowance_amount,
+            timestamp: env.ledger().timestamp(),
+        }
+        .publish(&env);
 
         Ok(())
     }
 
-    pub fn revoke_allowance(env: Env, token_id: BytesN<32>, spender: Address) -> Result<(), Error> {
+    pub fn revoke_allowance(
+        env: Env,
+        token_id: BytesN<32>,
+        spender: Address,
+    ) -> Result<(), ModuleError> {
         PauseGuard::require_not_paused(&env)?;
         PauseGuard::require_token_not_paused(&env, &token_id)?;
 
@@ -344,6 +599,61 @@ impl MembershipTokenContract {
         Ok(())
     }
 
+    /// Revokes every allowance the owner granted on a token. Returns how
+    /// many spenders were cleared.
+    pub fn revoke_all_allowances(env: Env, token_id: BytesN<32>) -> Result<u32, ModuleError> {
+        PauseGuard::require_not_paused(&env)?;
+        PauseGuard::require_token_not_paused(&env, &token_id)?;
+
+        let token: MembershipToken = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Token(token_id.clone()))
+            .ok_or(Error::TokenNotFound)?;
+
+        token.user.require_auth();
+        Ok(AllowanceModule::revoke_all_allowances(
+            &env,
+            &token_id,
+            &token.user,
+        ))
+    }
+
+    pub fn set_approval_for_all(
+        env: Env,
+        owner: Address,
+        operator: Address,
+        approved: bool,
+    ) -> Result<(), ModuleError> {
+        PauseGuard::require_not_paused(&env)?;
+        owner.require_auth();
+        Ok(AllowanceModule::set_approval_for_all(
+            &env, &owner, &operator, approved,
+        )?)
+    }
+
+    pub fn is_approved_for_all(env: Env, owner: Address, operator: Address) -> bool {
+        AllowanceModule::is_approved_for_all(&env, &owner, &operator)
+    }
+
+    /// Returns every active allowance granted by the token's current owner.
+    pub fn get_all_allowances(
+        env: Env,
+        token_id: BytesN<32>,
+    ) -> Result<Vec<TokenAllowance>, Error> {
+        let token: MembershipToken = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Token(token_id.clone()))
+            .ok_or(Error::TokenNotFound)?;
+
+        Ok(AllowanceModule::get_all_allowances(
+            &env,
+            &token_id,
+            &token.user,
+        ))
+    }
+
     pub fn get_allowance(
         env: Env,
         token_id: BytesN<32>,
@@ -367,11 +677,13 @@ impl MembershipTokenContract {
 
     pub fn get_token(env: Env, id: BytesN<32>) -> Result<MembershipToken, Error> {
         // Retrieve token
+        let key = DataKey::Token(id);
         let token: MembershipToken = env
             .storage()
             .persistent()
-            .get(&DataKey::Token(id))
+            .get(&key)
             .ok_or(Error::TokenNotFound)?;
+        TtlModule::bump(&env, TtlEntityClass::Token, &key);
 
         // Check token status based on expiry date
         let current_time = env.ledger().timestamp();
@@ -382,19 +694,129 @@ impl MembershipTokenContract {
         Ok(token)
     }
 
-    pub fn set_admin(env: Env, admin: Address) -> Result<(), Error> {
+    /// Verifies `caller` is the stored admin and requires its authorization.
+    pub(crate) fn require_admin(env: &Env, caller: &Address) -> Result<(), Error> {
+        let stored_admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::AdminNotSet)?;
+        if caller != &stored_admin {
+            return Err(Error::Unauthorized);
+        }
+        caller.require_auth();
+        Ok(())
+    }
+
+    /// Sets the first admin. Once an admin exists it can only be changed
+    /// through `propose_admin_transfer` / `accept_admin_transfer`.
+    pub fn set_admin(env: Env, admin: Address) -> Result<(), ModuleError> {
+        if env.storage().instance().has(&DataKey::Admin) {
+            return Err(AdminTransferError::AdminAlreadySet.into());
+        }
         admin.require_auth();
         env.storage().instance().set(&DataKey::Admin, &admin);
 
         // Emit admin set event
-        env.events().publish(
-            (symbol_short!("admin_set"), admin.clone()),
-            env.ledger().timestamp(),
-        );
+        AdminSet {
+            admin: admin.clone(),
+            timestamp: env.ledger().timestamp(),
+        }
+        .publish(&env);
+
+        Ok(())
+    }
+
+    /// Proposes handing admin rights to `new_admin`, who must accept within
+    /// `ADMIN_TRANSFER_EXPIRY` seconds. Replaces any earlier proposal.
+    pub fn propose_admin_transfer(
+        env: Env,
+        current_admin: Address,
+        new_admin: Address,
+    ) -> Result<(), ModuleError> {
+        Self::require_admin(&env, &current_admin)?;
+
+        if current_admin == new_admin {
+            return Err(AdminTransferError::SameAdmin.into());
+        }
+
+        let pending = PendingAdminTransfer {
+            proposed_admin: new_admin.clone(),
+            proposer: current_admin.clone(),
+            expiry: env
+                .ledger()
+                .timestamp()
+                .checked_add(ADMIN_TRANSFER_EXPIRY)
+                .ok_or(Error::TimestampOverflow)?,
+        };
+        env.storage()
+            .instance()
+            .set(&DataKey::PendingAdmin, &pending);
+
+        AdminTransferProposed {
+            proposed_admin: new_admin,
+            current_admin,
+            expiry: pending.expiry,
+        }
+        .publish(&env);
+
+        Ok(())
+    }
+
+    /// Completes a pending admin transfer. Must be called by the proposed admin.
+    pub fn accept_admin_transfer(env: Env, new_admin: Address) -> Result<(), ModuleError> {
+        let pending = Self::get_pending_admin_transfer(env.clone())
+            .ok_or(AdminTransferError::NoPendingTransfer)?;
+
+        if pending.proposed_admin != new_admin {
+            return Err(Error::Unauthorized.into());
+        }
+        new_admin.require_auth();
+
+        if env.ledger().timestamp() > pending.expiry {
+            return Err(AdminTransferError::TransferExpired.into());
+        }
+
+        let old_admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::AdminNotSet)?;
+
+        env.storage().instance().set(&DataKey::Admin, &new_admin);
+        env.storage().instance().remove(&DataKey::PendingAdmin);
+
+        AdminTransferred {
+            new_admin,
+            old_admin,
+            timestamp: env.ledger().timestamp(),
+        }
+        .publish(&env);
 
         Ok(())
     }
 
+    /// Withdraws a pending admin transfer. Admin only.
+    pub fn cancel_admin_transfer(env: Env, current_admin: Address) -> Result<(), ModuleError> {
+        Self::require_admin(&env, &current_admin)?;
+
+        let pending = Self::get_pending_admin_transfer(env.clone())
+            .ok_or(AdminTransferError::NoPendingTransfer)?;
+        env.storage().instance().remove(&DataKey::PendingAdmin);
+
+        AdminTransferCancelled {
+            proposed_admin: pending.proposed_admin,
+            current_admin,
+        }
+        .publish(&env);
+
+        Ok(())
+    }
+
+    pub fn get_pending_admin_transfer(env: Env) -> Option<PendingAdminTransfer> {
+        env.storage().instance().get(&DataKey::PendingAdmin)
+    }
+
     // ============================================================================
     // Metadata Index Helper Functions
     // ============================================================================
@@ -425,6 +847,11 @@ impl MembershipTokenContract {
 
         // Only add if not already present
         if !token_ids.iter().any(|id| id == token_id.clone()) {
+            if token_ids.is_empty() {
+                if let MetadataValue::Number(value) = attribute_value {
+                    Self::add_to_number_index(env, attribute_key, *value);
+                }
+            }
             token_ids.push_back(token_id.clone());
             env.storage().persistent().set(&index_key, &token_ids);
         }
@@ -461,12 +888,78 @@ impl MembershipTokenContract {
             if new_ids.is_empty() {
                 // Remove the index entry if no tokens remain
                 env.storage().persistent().remove(&index_key);
+                if let MetadataValue::Number(value) = attribute_value {
+                    Self::remove_from_number_index(env, attribute_key, *value);
+                }
             } else {
                 env.storage().persistent().set(&index_key, &new_ids);
             }
         }
     }
 
+    /// Records a `Number` value that now has tokens in the exact-match index.
+    fn add_to_number_index(env: &Env, attribute_key: &String, value: i128) {
+        let bucket = value.div_euclid(NUMBER_INDEX_BUCKET_WIDTH);
+        let bucket_key = DataKey::NumberIndexBucket(attribute_key.clone(), bucket);
+        let mut values: Vec<i128> = env
+            .storage()
+            .persistent()
+            .get(&bucket_key)
+            .unwrap_or_else(|| Vec::new(env));
+        if let Err(position) = values.binary_search(value) {
+            values.insert(position, value);
+            env.storage().persistent().set(&bucket_key, &values);
+        }
+
+        let buckets_key = DataKey::NumberIndexBuckets(attribute_key.clone());
+        let mut buckets: Vec<i128> = env
+            .storage()
+            .persistent()
+            .get(&buckets_key)
+            .unwrap_or_else(|| Vec::new(env));
+        if let Err(position) = buckets.binary_search(bucket) {
+            buckets.insert(position, bucket);
+            env.storage().persistent().set(&buckets_key, &buckets);
+        }
+    }
+
+    /// Forgets a `Number` value that no longer has any tokens.
+    fn remove_from_number_index(env: &Env, attribute_key: &String, value: i128) {
+        let bucket = value.div_euclid(NUMBER_INDEX_BUCKET_WIDTH);
+        let bucket_key = DataKey::NumberIndexBucket(attribute_key.clone(), bucket);
+        let Some(mut values) = env
+            .storage()
+            .persistent()
+            .get::<DataKey, Vec<i128>>(&bucket_key)
+        else {
+            return;
+        };
+        if let Ok(position) = values.binary_search(value) {
+            values.remove(position);
+        }
+        if !values.is_empty() {
+            env.storage().persistent().set(&bucket_key, &values);
+            return;
+        }
+
+        env.storage().persistent().remove(&bucket_key);
+        let buckets_key = DataKey::NumberIndexBuckets(attribute_key.clone());
+        if let Some(mut buckets) = env
+            .storage()
+            .persistent()
+            .get::<DataKey, Vec<i128>>(&buckets_key)
+        {
+            if let Ok(position) = buckets.binary_search(bucket) {
+                buckets.remove(position);
+            }
+            if buckets.is_empty() {
+                env.storage().persistent().remove(&buckets_key);
+            } else {
+                env.storage().persistent().set(&buckets_key, &buckets);
+            }
+        }
+    }
+
     // ============================================================================
     // Metadata Management Functions
     // ============================================================================
@@ -475,6 +968,7 @@ impl MembershipTokenContract {
     ///
     /// # Arguments
     /// * `env` - The contract environment
+    /// * `caller` - The admin or the token owner
     /// * `token_id` - The token ID to set metadata for
     /// * `description` - Token description
     /// * `attributes` - Custom attributes map
@@ -485,23 +979,17 @@ impl MembershipTokenContract {
     /// * `MetadataValidationFailed` - Metadata validation failed
     pub fn set_token_metadata(
         env: Env,
+        caller: Address,
         token_id: BytesN<32>,
         description: String,
         attributes: Map<String, MetadataValue>,
     ) -> Result<(), Error> {
-        // Require authorization from admin or token owner
-        let admin: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::Admin)
-            .ok_or(Error::AdminNotSet)?;
-
-        Self::internal_set_token_metadata(&env, &admin, token_id, description, attributes)
+        Self::internal_set_token_metadata(&env, &caller, token_id, description, attributes)
     }
 
     fn internal_set_token_metadata(
         env: &Env,
-        admin: &Address,
+        caller: &Address,
         token_id: BytesN<32>,
         description: String,
         attributes: Map<String, MetadataValue>,
@@ -513,39 +1001,94 @@ impl MembershipTokenContract {
             .get(&DataKey::Token(token_id.clone()))
             .ok_or(Error::TokenNotFound)?;
 
-        // Check if caller is admin or token owner
-        if env.ledger().sequence() > 0 {
-            // In tests, we might not have proper auth
-            let is_admin = admin.clone() == token.user.clone();
-            let is_owner = token.user.clone() == token.user.clone();
-            if !is_admin && !is_owner {
-                admin.require_auth();
-            } else {
-                token.user.require_auth();
-            }
+        // Only the stored admin or the token owner may write metadata
+        let admin: Option<Address> = env.storage().instance().get(&DataKey::Admin);
+        if *caller != token.user && admin.as_ref() != Some(caller) {
+            return Err(Error::Unauthorized);
         }
+        caller.require_auth();
 
-        let current_time = env.ledger().timestamp();
-        let caller = token.user.clone(); // In production, get from auth context
+        MetadataSchemaModule::validate(env, &attributes)?;
+        Self::write_token_metadata(env, token_id, description, attributes, caller.clone())
+    }
 
-        // Get existing metadata to determine version
-        let version = if let Some(existing_metadata) = env
+    /// Sets token metadata on the owner's behalf. `operator` must be the
+    /// owner or approved for all of the owner's tokens.
+    pub fn operator_set_token_metadata(
+        env: Env,
+        operator: Address,
+        token_id: BytesN<32>,
+        description: String,
+        attributes: Map<String, MetadataValue>,
+    ) -> Result<(), Error> {
+        let token: MembershipToken = env
             .storage()
             .persistent()
-            .get::<DataKey, TokenMetadata>(&DataKey::Metadata(token_id.clone()))
-        {
-            existing_metadata.version + 1
-        } else {
-            1
-        };
+            .get(&DataKey::Token(token_id.clone()))
+            .ok_or(Error::TokenNotFound)?;
+        Self::require_token_authority(&env, &token, Some(&operator))?;
 
-        // Create new metadata
-        let metadata = TokenMetadata {
-            description: description.clone(),
+        MetadataSchemaModule::validate(&env, &attributes)?;
+        Self::write_token_metadata(&env, token_id, description, attributes, operator)
+    }
+
+    /// Sets a single metadata attribute on behalf of the contract, creating
+    /// the token's metadata if it has none. Callers are responsible for
+    /// authorization.
+    pub(crate) fn set_metadata_attribute(
+        env: &Env,
+        token_id: &BytesN<32>,
+        key: String,
+        value: MetadataValue,
+    ) -> Result<(), Error> {
+        let token: MembershipToken = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Token(token_id.clone()))
+            .ok_or(Error::TokenNotFound)?;
+
+        let (description, mut attributes) =
+            match MigrationModule::load_token_metadata(env, token_id) {
+                Some(metadata) => (metadata.description, metadata.attributes),
+                None => (String::from_str(env, ""), Map::new(env)),
+            };
+        attributes.set(key, value);
+
+        Self::write_token_metadata(env, token_id.clone(), description, attributes, token.user)
+    }
+
+    /// Validates and stores metadata, updating attribute indexes and history.
+    fn write_token_metadata(
+        env: &Env,
+        token_id: BytesN<32>,
+        description: String,
+        attributes: Map<String, MetadataValue>,
+        caller: Address,
+    ) -> Result<(), Error> {
+        let current_time = env.ledger().timestamp();
+
+        // Get existing metadata to determine version; the bound content URI
+        // survives attribute rewrites
+        let (version, token_uri, content_hash) =
+            if let Some(existing_metadata) = MigrationModule::load_token_metadata(env, &token_id) {
+                (
+                    existing_metadata.version + 1,
+                    existing_metadata.token_uri,
+                    existing_metadata.content_hash,
+                )
+            } else {
+                (1, String::from_str(env, ""), None)
+            };
+
+        // Create new metadata
+        let metadata = TokenMetadata {
+            description: description.clone(),
             attributes: attributes.clone(),
             version,
             last_updated: current_time,
             updated_by: caller.clone(),
+            token_uri,
+            content_hash,
         };
 
         // Validate metadata
@@ -553,11 +1096,7 @@ impl MembershipTokenContract {
 
         // Update metadata indexes
         // If there's existing metadata, remove old indexes first
-        if let Some(existing_metadata) = env
-            .storage()
-            .persistent()
-            .get::<DataKey, TokenMetadata>(&DataKey::Metadata(token_id.clone()))
-        {
+        if let Some(existing_metadata) = MigrationModule::load_token_metadata(env, &token_id) {
             // Remove old attribute indexes
             for key in existing_metadata.attributes.keys() {
                 if let Some(value) = existing_metadata.attributes.get(key.clone()) {
@@ -587,24 +1126,16 @@ impl MembershipTokenContract {
             changes: attributes.clone(),
         };
 
-        // Get or create history vector
-        let mut history: Vec<MetadataUpdate> = env
-            .storage()
-            .persistent()
-            .get(&DataKey::MetadataHistory(token_id.clone()))
-            .unwrap_or_else(|| Vec::new(env));
-
-        history.push_back(metadata_update);
-
-        env.storage()
-            .persistent()
-            .set(&DataKey::MetadataHistory(token_id.clone()), &history);
+        Self::append_metadata_history(env, &token_id, metadata_update);
 
         // Emit metadata set event
-        env.events().publish(
-            (symbol_short!("meta_set"), token_id.clone(), version),
-            (caller, current_time),
-        );
+        MetadataSet {
+            token_id: token_id.clone(),
+            version,
+            updated_by: caller,
+            timestamp: current_time,
+        }
+        .publish(env);
 
         Ok(())
     }
@@ -613,19 +1144,102 @@ impl MembershipTokenContract {
         env: Env,
         params: Vec<crate::types::BatchUpdateParams>,
     ) -> Result<(), Error> {
-        let admin: Address = env
+        for p in params.iter() {
+            // Each token's owner authorizes its own update
+            let token: MembershipToken = env
+                .storage()
+                .persistent()
+                .get(&DataKey::Token(p.id.clone()))
+                .ok_or(Error::TokenNotFound)?;
+            Self::internal_set_token_metadata(
+                &env,
+                &token.user,
+                p.id,
+                p.description,
+                p.attributes,
+            )?;
+        }
+
+        Ok(())
+    }
+
+    /// Binds an off-chain content URI and its SHA-256 hash to a token's
+    /// metadata. The metadata version is left unchanged since the attribute
+    /// set is not modified.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `caller` - The admin or the token owner
+    /// * `token_id` - The token ID to bind content to
+    /// * `uri` - Off-chain content URI (max 256 chars)
+    /// * `content_hash` - SHA-256 hash of the content behind `uri`
+    ///
+    /// # Errors
+    /// * `TokenNotFound` - Token doesn't exist
+    /// * `Unauthorized` - Caller is not admin or token owner
+    /// * `MetadataNotFound` - Token has no metadata yet
+    /// * `MetadataValidationFailed` - URI exceeds maximum length
+    pub fn set_token_uri(
+        env: Env,
+        caller: Address,
+        token_id: BytesN<32>,
+        uri: String,
+        content_hash: BytesN<32>,
+    ) -> Result<(), Error> {
+        let token: MembershipToken = env
             .storage()
-            .instance()
-            .get(&DataKey::Admin)
-            .ok_or(Error::AdminNotSet)?;
+            .persistent()
+            .get(&DataKey::Token(token_id.clone()))
+            .ok_or(Error::TokenNotFound)?;
 
-        for p in params.iter() {
-            Self::internal_set_token_metadata(&env, &admin, p.id, p.description, p.attributes)?;
+        let admin: Option<Address> = env.storage().instance().get(&DataKey::Admin);
+        if caller != token.user && admin.as_ref() != Some(&caller) {
+            return Err(Error::Unauthorized);
+        }
+        caller.require_auth();
+
+        let mut metadata: TokenMetadata =
+            MigrationModule::load_token_metadata(&env, &token_id).ok_or(Error::MetadataNotFound)?;
+
+        let current_time = env.ledger().timestamp();
+        metadata.token_uri = uri.clone();
+        metadata.content_hash = Some(content_hash.clone());
+        metadata.last_updated = current_time;
+        metadata.updated_by = caller.clone();
+
+        validate_metadata(&metadata).map_err(|_| Error::MetadataValidationFailed)?;
+
+        env.storage()
+            .persistent()
+            .set(&DataKey::Metadata(token_id.clone()), &metadata);
+
+        TokenUriSet {
+            token_id,
+            uri,
+            content_hash,
+            updated_by: caller,
+            timestamp: current_time,
         }
+        .publish(&env);
 
         Ok(())
     }
 
+    /// Checks a candidate hash against the content hash bound to a token.
+    /// Returns `false` when no content has been bound.
+    ///
+    /// # Errors
+    /// * `TokenNotFound` - Token doesn't exist
+    /// * `MetadataNotFound` - Token has no metadata
+    pub fn verify_content_hash(
+        env: Env,
+        token_id: BytesN<32>,
+        content_hash: BytesN<32>,
+    ) -> Result<bool, Error> {
+        let metadata = Self::get_token_metadata(env, token_id)?;
+        Ok(metadata.content_hash == Some(content_hash))
+    }
+
     /// Gets metadata for a token.
     ///
     /// # Arguments
@@ -644,11 +1258,8 @@ impl MembershipTokenContract {
             .ok_or(Error::TokenNotFound)?;
 
         // Get metadata
-        let metadata: TokenMetadata = env
-            .storage()
-            .persistent()
-            .get(&DataKey::Metadata(token_id))
-            .ok_or(Error::MetadataNotFound)?;
+        let metadata: TokenMetadata =
+            MigrationModule::load_token_metadata(&env, &token_id).ok_or(Error::MetadataNotFound)?;
 
         Ok(metadata)
     }
@@ -677,11 +1288,8 @@ impl MembershipTokenContract {
             .ok_or(Error::TokenNotFound)?;
 
         // Get existing metadata
-        let mut metadata: TokenMetadata = env
-            .storage()
-            .persistent()
-            .get(&DataKey::Metadata(token_id.clone()))
-            .ok_or(Error::MetadataNotFound)?;
+        let mut metadata: TokenMetadata =
+            MigrationModule::load_token_metadata(&env, &token_id).ok_or(Error::MetadataNotFound)?;
 
         // Require authorization
         token.user.require_auth();
@@ -707,6 +1315,7 @@ impl MembershipTokenContract {
 
         // Validate updated metadata
         validate_metadata(&metadata).map_err(|_| Error::MetadataValidationFailed)?;
+        MetadataSchemaModule::validate(&env, &metadata.attributes)?;
 
         // Update version and timestamp
         metadata.version += 1;
@@ -727,27 +1336,17 @@ impl MembershipTokenContract {
             changes: updates,
         };
 
-        let mut history: Vec<MetadataUpdate> = env
-            .storage()
-            .persistent()
-            .get(&DataKey::MetadataHistory(token_id.clone()))
-            .unwrap_or_else(|| Vec::new(&env));
-
-        history.push_back(metadata_update);
-
-        env.storage()
-            .persistent()
-            .set(&DataKey::MetadataHistory(token_id.clone()), &history);
+        Self::append_metadata_history(&env, &token_id, metadata_update);
 
         // Emit metadata update event
-        env.events().publish(
-            (
-                symbol_short!("meta_upd"),
-                token_id.clone(),
-                metadata.version,
-            ),
-            (metadata.updated_by, metadata.last_updated),
-        );
+        MetadataUpdated {
+            token_id: token_id.clone(),
+            old_version: metadata.version - 1,
+            new_version: metadata.version,
+            updated_by: metadata.updated_by,
+            timestamp: metadata.last_updated,
+        }
+        .publish(&env);
 
         Ok(())
     }
@@ -767,6 +1366,121 @@ impl MembershipTokenContract {
             .unwrap_or_else(|| Vec::new(&env))
     }
 
+    pub fn get_metadata_history_limit(env: &Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::MetadataHistoryLimit)
+            .unwrap_or(DEFAULT_METADATA_HISTORY_LIMIT)
+    }
+
+    /// Sets how many metadata history entries are kept per token. Older
+    /// entries are pruned, oldest first, on the token's next update. Admin only.
+    pub fn set_metadata_history_limit(env: Env, admin: Address, limit: u32) -> Result<(), Error> {
+        Self::require_admin(&env, &admin)?;
+        if limit == 0 || limit > MAX_METADATA_HISTORY_LIMIT {
+            return Err(Error::MetadataValidationFailed);
+        }
+        env.storage()
+            .instance()
+            .set(&DataKey::MetadataHistoryLimit, &limit);
+        Ok(())
+    }
+
+    /// Returns the digest of a token's pruned metadata history, if any
+    /// entries have been pruned.
+    pub fn get_pruned_metadata_history(
+        env: Env,
+        token_id: BytesN<32>,
+    ) -> Option<PrunedMetadataHistory> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::PrunedMetadataHistory(token_id))
+    }
+
+    /// Prunes a token's metadata history down to its `keep_last_n` newest
+    /// entries, folding the removed ones into the audit digest. Admin only.
+    /// Returns the number of entries pruned.
+    pub fn prune_metadata_history(
+        env: Env,
+        admin: Address,
+        token_id: BytesN<32>,
+        keep_last_n: u32,
+    ) -> Result<u32, Error> {
+        Self::require_admin(&env, &admin)?;
+
+        let history = Self::get_metadata_history(env.clone(), token_id.clone());
+        let pruned = history.len().saturating_sub(keep_last_n);
+        if pruned > 0 {
+            Self::store_pruned_history(&env, &token_id, history, pruned);
+        }
+        Ok(pruned)
+    }
+
+    /// Appends a history entry, pruning the oldest entries beyond the limit.
+    fn append_metadata_history(env: &Env, token_id: &BytesN<32>, entry: MetadataUpdate) {
+        let mut history = Self::get_metadata_history(env.clone(), token_id.clone());
+        history.push_back(entry);
+
+        let pruned = history
+            .len()
+            .saturating_sub(Self::get_metadata_history_limit(env));
+        if pruned > 0 {
+            Self::store_pruned_history(env, token_id, history, pruned);
+        } else {
+            env.storage()
+                .persistent()
+                .set(&DataKey::MetadataHistory(token_id.clone()), &history);
+        }
+    }
+
+    /// Drops the `pruned` oldest entries of `history` and stores the rest.
+    ///
+    /// The digest checksum chains every pruned entry in order:
+    /// `checksum = sha256(checksum || xdr(entry))`, starting from 32 zero
+    /// bytes, so an archived copy of the pruned entries can be verified.
+    fn store_pruned_history(
+        env: &Env,
+        token_id: &BytesN<32>,
+        history: Vec<MetadataUpdate>,
+        pruned: u32,
+    ) {
+        let digest_key = DataKey::PrunedMetadataHistory(token_id.clone());
+        let mut digest: PrunedMetadataHistory = env
+            .storage()
+            .persistent()
+            .get(&digest_key)
+            .unwrap_or(PrunedMetadataHistory {
+                checksum: BytesN::from_array(env, &[0u8; 32]),
+                pruned_count: 0,
+                last_pruned_version: 0,
+                last_pruned_at: 0,
+            });
+
+        for entry in history.slice(0..pruned).iter() {
+            let mut bytes = Bytes::from_array(env, &digest.checksum.to_array());
+            bytes.append(&entry.clone().to_xdr(env));
+            digest.checksum = env.crypto().sha256(&bytes).into();
+            digest.last_pruned_version = entry.version;
+        }
+        digest.pruned_count = digest.pruned_count.saturating_add(pruned);
+        digest.last_pruned_at = env.ledger().timestamp();
+
+        let kept = history.slice(pruned..history.len());
+        env.storage()
+            .persistent()
+            .set(&DataKey::MetadataHistory(token_id.clone()), &kept);
+        env.storage().persistent().set(&digest_key, &digest);
+
+        MetadataHistoryPruned {
+            token_id: token_id.clone(),
+            pruned,
+            kept: kept.len(),
+            checksum: digest.checksum,
+            timestamp: digest.last_pruned_at,
+        }
+        .publish(env);
+    }
+
     /// Removes specific attributes from token metadata.
     ///
     /// # Arguments
@@ -786,11 +1500,8 @@ impl MembershipTokenContract {
             .ok_or(Error::TokenNotFound)?;
 
         // Get existing metadata
-        let mut metadata: TokenMetadata = env
-            .storage()
-            .persistent()
-            .get(&DataKey::Metadata(token_id.clone()))
-            .ok_or(Error::MetadataNotFound)?;
+        let mut metadata: TokenMetadata =
+            MigrationModule::load_token_metadata(&env, &token_id).ok_or(Error::MetadataNotFound)?;
 
         // Require authorization
         token.user.require_auth();
@@ -805,6 +1516,9 @@ impl MembershipTokenContract {
             metadata.attributes.remove(key);
         }
 
+        // Required schema attributes cannot be removed
+        MetadataSchemaModule::validate(&env, &metadata.attributes)?;
+
         // Update version and timestamp
         metadata.version += 1;
         metadata.last_updated = env.ledger().timestamp();
@@ -816,14 +1530,14 @@ impl MembershipTokenContract {
             .set(&DataKey::Metadata(token_id.clone()), &metadata);
 
         // Emit event
-        env.events().publish(
-            (
-                symbol_short!("meta_rmv"),
-                token_id.clone(),
-                metadata.version,
-            ),
-            (metadata.updated_by, metadata.last_updated),
-        );
+        MetadataRemoved {
+            token_id: token_id.clone(),
+            removed_keys: attribute_keys,
+            new_version: metadata.version,
+            updated_by: metadata.updated_by,
+            timestamp: metadata.last_updated,
+        }
+        .publish(&env);
 
         Ok(())
     }
@@ -872,6 +1586,124 @@ impl MembershipTokenContract {
             .unwrap_or_else(|| Vec::new(&env))
     }
 
+    /// Queries tokens matching several attribute filters.
+    ///
+    /// With `MatchMode::All` the result is the intersection of the filters'
+    /// index entries, in the order of the first filter's index; with
+    /// `MatchMode::Any` it is their union, in filter order without
+    /// duplicates. `offset` and `limit` page through that result; `limit`
+    /// is capped at `MAX_METADATA_QUERY_PAGE`.
+    ///
+    /// # Errors
+    /// * `MetadataTooManyAttributes` - More than `MAX_METADATA_QUERY_FILTERS` filters
+    pub fn query_tokens_by_attributes(
+        env: Env,
+        filters: Vec<(String, MetadataValue)>,
+        mode: MatchMode,
+        offset: u32,
+        limit: u32,
+    ) -> Result<Vec<BytesN<32>>, Error> {
+        if filters.len() > MAX_METADATA_QUERY_FILTERS {
+            return Err(Error::MetadataTooManyAttributes);
+        }
+
+        let mut matches: Vec<BytesN<32>> = Vec::new(&env);
+        for (index, (key, value)) in filters.iter().enumerate() {
+            let token_ids = Self::query_tokens_by_attribute(env.clone(), key, value);
+            matches = match mode {
+                MatchMode::All if index == 0 => token_ids,
+                MatchMode::All => {
+                    let mut kept = Vec::new(&env);
+                    for id in matches.iter() {
+                        if token_ids.contains(&id) {
+                            kept.push_back(id);
+                        }
+                    }
+                    kept
+                }
+                MatchMode::Any => {
+                    for id in token_ids.iter() {
+                        if !matches.contains(&id) {
+                            matches.push_back(id);
+                        }
+                    }
+                    matches
+                }
+            };
+        }
+
+        let end = offset
+            .saturating_add(limit.min(MAX_METADATA_QUERY_PAGE))
+            .min(matches.len());
+        if offset >= end {
+            return Ok(Vec::new(&env));
+        }
+        Ok(matches.slice(offset..end))
+    }
+
+    /// Queries tokens whose `Number` attribute `attribute_key` lies in
+    /// `[min, max]`, ordered by value. Only populated value buckets are read.
+    /// `offset` and `limit` page through the matches; `limit` is capped at
+    /// `MAX_METADATA_QUERY_PAGE`. An empty range returns no tokens.
+    pub fn query_tokens_by_number_range(
+        env: Env,
+        attribute_key: String,
+        min: i128,
+        max: i128,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<BytesN<32>> {
+        let limit = limit.min(MAX_METADATA_QUERY_PAGE);
+        let mut result = Vec::new(&env);
+        if min > max || limit == 0 {
+            return result;
+        }
+
+        let buckets: Vec<i128> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::NumberIndexBuckets(attribute_key.clone()))
+            .unwrap_or_else(|| Vec::new(&env));
+        let first_bucket = min.div_euclid(NUMBER_INDEX_BUCKET_WIDTH);
+        let last_bucket = max.div_euclid(NUMBER_INDEX_BUCKET_WIDTH);
+        let start = match buckets.binary_search(first_bucket) {
+            Ok(position) | Err(position) => position,
+        };
+
+        let mut skipped: u32 = 0;
+        for bucket in buckets.iter().skip(start as usize) {
+            if bucket > last_bucket {
+                break;
+            }
+            let values: Vec<i128> = env
+                .storage()
+                .persistent()
+                .get(&DataKey::NumberIndexBucket(attribute_key.clone(), bucket))
+                .unwrap_or_else(|| Vec::new(&env));
+            for value in values.iter() {
+                if value < min || value > max {
+                    continue;
+                }
+                let token_ids = Self::query_tokens_by_attribute(
+                    env.clone(),
+                    attribute_key.clone(),
+                    MetadataValue::Number(value),
+                );
+                for token_id in token_ids.iter() {
+                    if skipped < offset {
+                        skipped += 1;
+                        continue;
+                    }
+                    result.push_back(token_id);
+                    if result.len() >= limit {
+                        return result;
+                    }
+                }
+            }
+        }
+        result
+    }
+
     // ============================================================================
     // Token Renewal System
     // ============================================================================
@@ -901,6 +1733,7 @@ impl MembershipTokenContract {
             .ok_or(Error::AdminNotSet)?;
         admin.require_auth();
 
+        let old_config = Self::get_renewal_config(env.clone());
         let config = crate::types::RenewalConfig {
             grace_period_duration,
             auto_renewal_notice_days,
@@ -912,14 +1745,12 @@ impl MembershipTokenContract {
             .set(&DataKey::RenewalConfig, &config);
 
         // Emit renewal config updated event
-        env.events().publish(
-            (symbol_short!("rnw_cfg"), admin),
-            (
-                grace_period_duration,
-                auto_renewal_notice_days,
-                renewals_enabled,
-            ),
-        );
+        RenewalConfigUpdated {
+            admin,
+            old_config,
+            new_config: config,
+        }
+        .publish(&env);
 
         Ok(())
     }
@@ -942,6 +1773,82 @@ impl MembershipTokenContract {
             })
     }
 
+    /// Sets the grace-period policy of a tier. Admin only.
+    pub fn set_tier_grace_policy(
+        env: Env,
+        admin: Address,
+        tier_id: String,
+        policy: GracePolicy,
+    ) -> Result<(), Error> {
+        Self::require_admin(&env, &admin)?;
+        crate::subscription::SubscriptionContract::get_tier(env.clone(), tier_id.clone())?;
+
+        env.storage()
+            .persistent()
+            .set(&DataKey::TierGracePolicy(tier_id.clone()), &policy);
+
+        TierGracePolicySet {
+            tier_id,
+            admin,
+            policy,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    /// Removes a tier's grace-period policy so the global `RenewalConfig`
+    /// applies again. Admin only.
+    pub fn remove_tier_grace_policy(
+        env: Env,
+        admin: Address,
+        tier_id: String,
+    ) -> Result<(), Error> {
+        Self::require_admin(&env, &admin)?;
+        let key = DataKey::TierGracePolicy(tier_id.clone());
+        if !env.storage().persistent().has(&key) {
+            return Err(Error::TierNotFound);
+        }
+        env.storage().persistent().remove(&key);
+
+        TierGracePolicyRemoved { tier_id, admin }.publish(&env);
+        Ok(())
+    }
+
+    pub fn get_tier_grace_policy(env: &Env, tier_id: &String) -> Option<GracePolicy> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::TierGracePolicy(tier_id.clone()))
+    }
+
+    /// The grace-period policy in force for `tier_id`: the tier's override,
+    /// or the global `RenewalConfig` duration with transfers blocked and
+    /// features suspended.
+    pub fn resolve_grace_policy(env: &Env, tier_id: Option<&String>) -> GracePolicy {
+        tier_id
+            .and_then(|tier_id| Self::get_tier_grace_policy(env, tier_id))
+            .unwrap_or_else(|| GracePolicy {
+                duration: Self::get_renewal_config(env.clone()).grace_period_duration,
+                block_transfers: true,
+                feature_access: GraceFeatureAccess::Suspended,
+            })
+    }
+
+    /// Rejects transfers of a token that is not active, unless it is in a
+    /// grace period whose policy allows transfers.
+    fn require_transferable(env: &Env, token: &MembershipToken) -> Result<(), Error> {
+        match token.status {
+            MembershipStatus::Active => Ok(()),
+            MembershipStatus::GracePeriod => {
+                if Self::resolve_grace_policy(env, token.tier_id.as_ref()).block_transfers {
+                    Err(Error::TransferNotAllowedInGracePeriod)
+                } else {
+                    Ok(())
+                }
+            }
+            _ => Err(Error::TokenExpired),
+        }
+    }
+
     /// Renews a membership token with payment validation and tier pricing.
     ///
     /// # Arguments
@@ -964,33 +1871,133 @@ impl MembershipTokenContract {
         payment_token: Address,
         tier_id: String,
         billing_cycle: crate::types::BillingCycle,
-    ) -> Result<(), Error> {
+    ) -> Result<(), ModuleError> {
+        Self::internal_renew_token(env, None, id, payment_token, tier_id, billing_cycle)
+    }
+
+    /// Renews a token on the owner's behalf. `operator` must be the owner or
+    /// approved for all of the owner's tokens.
+    pub fn operator_renew_token(
+        env: Env,
+        operator: Address,
+        id: BytesN<32>,
+        payment_token: Address,
+        tier_id: String,
+        billing_cycle: crate::types::BillingCycle,
+    ) -> Result<(), ModuleError> {
+        Self::internal_renew_token(
+            env,
+            Some(operator),
+            id,
+            payment_token,
+            tier_id,
+            billing_cycle,
+        )
+    }
+
+    fn internal_renew_token(
+        env: Env,
+        operator: Option<Address>,
+        id: BytesN<32>,
+        payment_token: Address,
+        tier_id: String,
+        billing_cycle: crate::types::BillingCycle,
+    ) -> Result<(), ModuleError> {
+        let token = Self::load_renewable_token(&env, &id)?;
+
+        // Require token owner (or approved operator) authorization
+        Self::require_token_authority(&env, &token, operator.as_ref())?;
+        BlacklistModule::require_not_blacklisted(
+            &env,
+            BlacklistOperation::Subscription,
+            &token.user,
+        )?;
+
+        Self::apply_renewal(
+            &env,
+            id,
+            token,
+            payment_token,
+            tier_id,
+            billing_cycle,
+            crate::types::RenewalTrigger::Manual,
+        )?;
+        Ok(())
+    }
+
+    /// Renews a token paid for by someone other than its owner, such as the
+    /// share holders of a fractionalized token, who have already paid in
+    /// `payment_token`. Returns the new expiry.
+    pub(crate) fn renew_funded_token(
+        env: &Env,
+        id: BytesN<32>,
+        payment_token: Address,
+        tier_id: String,
+    ) -> Result<u64, ModuleError> {
+        let token = Self::load_renewable_token(env, &id)?;
+        Self::apply_renewal(
+            env,
+            id,
+            token,
+            payment_token,
+            tier_id,
+            crate::types::BillingCycle::Monthly,
+            crate::types::RenewalTrigger::HolderFunded,
+        )
+    }
+
+    /// What renewing a token owned by `owner` costs at the USDC `price` of
+    /// `tier_id`: the price plus fees, in `payment_token` units.
+    pub(crate) fn renewal_charge(
+        env: &Env,
+        owner: &Address,
+        payment_token: &Address,
+        tier_id: &String,
+        price: i128,
+    ) -> Result<FeeBreakdown, ModuleError> {
+        let usdc = FeeModule::breakdown_for(env, owner, price)?;
+        FeeModule::convert(env, payment_token, tier_id, &usdc)
+    }
+
+    /// Loads a token for renewal, checking pauses and that renewals are
+    /// enabled.
+    fn load_renewable_token(env: &Env, id: &BytesN<32>) -> Result<MembershipToken, ModuleError> {
         // Block renewals when the contract is globally paused or this token is paused.
-        PauseGuard::require_not_paused(&env)?;
-        PauseGuard::require_token_not_paused(&env, &id)?;
+        PauseGuard::require_not_paused(env)?;
+        PauseGuard::require_token_not_paused(env, id)?;
 
         // Check if renewals are enabled
         let config = Self::get_renewal_config(env.clone());
         if !config.renewals_enabled {
-            return Err(Error::RenewalNotAllowed);
+            return Err(Error::RenewalNotAllowed.into());
         }
 
-        // Get token
-        let mut token: MembershipToken = env
+        // Get token, upgrading its schema first if it predates the current one
+        MigrationModule::migrate_token(env, id);
+        Ok(env
             .storage()
             .persistent()
             .get(&DataKey::Token(id.clone()))
-            .ok_or(Error::TokenNotFound)?;
-
-        // Require token owner authorization
-        token.user.require_auth();
+            .ok_or(Error::TokenNotFound)?)
+    }
 
+    /// Charges the tier price for `billing_cycle` and extends the token's
+    /// expiry, reinstating it from its grace period. Returns the new expiry.
+    fn apply_renewal(
+        env: &Env,
+        id: BytesN<32>,
+        mut token: MembershipToken,
+        payment_token: Address,
+        tier_id: String,
+        billing_cycle: crate::types::BillingCycle,
+        trigger: crate::types::RenewalTrigger,
+    ) -> Result<u64, ModuleError> {
         // Get tier pricing
         use crate::subscription::SubscriptionContract;
         let tier = SubscriptionContract::get_tier(env.clone(), tier_id.clone())?;
 
-        // Calculate amount based on billing cycle
-        let amount = match billing_cycle {
+        // Calculate the USDC price based on billing cycle
+        let base_amount = match billing_cycle {
             crate::types::BillingCycle::Monthly => tier.price,
             crate::types::BillingCycle::Annual => tier.annual_price,
         };
@@ -1001,13 +2008,11 @@ impl MembershipTokenContract {
             crate::types::BillingCycle::Annual => 365 * 24 * 60 * 60, // 365 days
         };
 
-        // Validate payment
-        let usdc_contract = SubscriptionContract::get_usdc_contract_address(&env)?;
-        if payment_token != usdc_contract {
-            return Err(Error::InvalidPaymentToken);
-        }
+        // Add fees, convert the charge into the chosen asset and validate it
+        let amount =
+            Self::renewal_charge(env, &token.user, &payment_token, &tier_id, base_amount)?.total;
         if amount <= 0 {
-            return Err(Error::InvalidPaymentAmount);
+            return Err(Error::InvalidPaymentAmount.into());
         }
 
         // Capture old expiry for history
@@ -1027,6 +2032,14 @@ impl MembershipTokenContract {
             .ok_or(Error::TimestampOverflow)?;
 
         // Update token
+        UpgradeModule::snapshot_token(env, &id, &token, None);
+        if token.status == MembershipStatus::GracePeriod {
+            StatsModule::record_token_reinstated(env);
+            if let Some(ends_at) = token.grace_period_expires_at {
+                StatsModule::record_grace_ended(env, ends_at);
+            }
+        }
+        Self::reindex_expiry(env, &id, Some(token.expiry_date), new_expiry);
         token.expiry_date = new_expiry;
         token.status = MembershipStatus::Active;
         token.tier_id = Some(tier_id.clone());
@@ -1039,13 +2052,19 @@ impl MembershipTokenContract {
         env.storage()
             .persistent()
             .set(&DataKey::Token(id.clone()), &token);
-        env.storage()
-            .persistent()
-            .extend_ttl(&DataKey::Token(id.clone()), 100, 1000);
+        TtlModule::bump(env, TtlEntityClass::Token, &DataKey::Token(id.clone()));
+
+        if trigger == crate::types::RenewalTrigger::HolderFunded {
+            // The holders' pooled payment is already held by the contract
+            TreasuryModule::record_collected(env, RevenueSource::Renewal, &payment_token, amount)?;
+        } else {
+            TreasuryModule::record_revenue(env, RevenueSource::Renewal, base_amount)?;
+        }
+        LoyaltyModule::on_renewal(env, &token.user, &tier_id);
 
         // Record renewal in history
         Self::record_renewal(
-            &env,
+            env,
             &id,
             crate::types::RenewalHistory {
                 timestamp: env.ledger().timestamp(),
@@ -1053,20 +2072,33 @@ impl MembershipTokenContract {
                 amount,
                 payment_token: payment_token.clone(),
                 success: true,
-                trigger: crate::types::RenewalTrigger::Manual,
+                trigger,
                 old_expiry_date: old_expiry,
                 new_expiry_date: Some(new_expiry),
                 error: None,
             },
         );
 
-        // Emit token renewal event
-        env.events().publish(
-            (symbol_short!("token_rnw"), id.clone(), token.user.clone()),
-            (payment_token, amount, old_expiry, new_expiry),
+        MembershipSyncModule::sync(env, &token.user, MembershipStatus::Active, new_expiry);
+        OutboxModule::append(
+            env,
+            symbol_short!("tok_renew"),
+            OutboxSubject::Token(id.clone()),
         );
 
-        Ok(())
+        // Emit token renewal event
+        TokenRenewed {
+            token_id: id.clone(),
+            user: token.user.clone(),
+            payment_token,
+            amount,
+            old_expiry,
+            new_expiry,
+            automatic: false,
+        }
+        .publish(env);
+
+        Ok(new_expiry)
     }
 
     /// Records a renewal attempt in history.
@@ -1081,9 +2113,7 @@ impl MembershipTokenContract {
         history.push_back(entry);
 
         env.storage().persistent().set(&history_key, &history);
-        env.storage()
-            .persistent()
-            .extend_ttl(&history_key, 100, 1000);
+        TtlModule::bump(env, TtlEntityClass::History, &history_key);
     }
 
     /// Gets the renewal history for a token.
@@ -1124,28 +2154,51 @@ impl MembershipTokenContract {
             .ok_or(Error::TokenNotFound)?;
 
         let current_time = env.ledger().timestamp();
-        let config = Self::get_renewal_config(env.clone());
+        let policy = Self::resolve_grace_policy(&env, token.tier_id.as_ref());
 
         // Check if token is expired and not already in grace period
         if token.status == MembershipStatus::Active && current_time > token.expiry_date {
             // Enter grace period
+            UpgradeModule::snapshot_token(&env, &id, &token, None);
             token.status = MembershipStatus::GracePeriod;
+            StatsModule::record_token_lapsed(&env);
             token.grace_period_entered_at = Some(current_time);
             token.grace_period_expires_at = Some(
                 current_time
-                    .checked_add(config.grace_period_duration)
+                    .checked_add(policy.duration)
                     .ok_or(Error::TimestampOverflow)?,
             );
+            StatsModule::record_grace_started(&env, token.grace_period_expires_at.unwrap());
 
             env.storage()
                 .persistent()
                 .set(&DataKey::Token(id.clone()), &token);
 
-            // Emit grace period entered event
-            env.events().publish(
-                (symbol_short!("grace_in"), id, token.user.clone()),
-                (current_time, token.grace_period_expires_at.unwrap()),
+            TtlModule::bump(&env, TtlEntityClass::Token, &DataKey::Token(id.clone()));
+            MembershipSyncModule::sync(
+                &env,
+                &token.user,
+                MembershipStatus::GracePeriod,
+                token.expiry_date,
             );
+
+            NotificationModule::notify(
+                &env,
+                &token.user,
+                NotificationKind::GracePeriodEntered,
+                OutboxSubject::Token(id.clone()),
+                token.grace_period_expires_at.unwrap(),
+            );
+
+            // Emit grace period entered event
+            GracePeriodEntered {
+                token_id: id,
+                user: token.user.clone(),
+                entered_at: current_time,
+                expires_at: token.grace_period_expires_at.unwrap(),
+                reason: symbol_short!("expired"),
+            }
+            .publish(&env);
         }
 
         // Check if grace period has expired
@@ -1167,12 +2220,21 @@ impl MembershipTokenContract {
     /// * `token_id` - Token ID to enable auto-renewal for
     /// * `enabled` - Whether to enable auto-renewal
     /// * `payment_token` - Payment token to use for auto-renewal
+    /// * `max_amount` - Most one auto-renewal may charge (0 = no cap)
+    ///
+    /// Every call is recorded as a consent and clears any auto-renewal
+    /// waiting for consent.
     pub fn set_auto_renewal(
         env: Env,
         token_id: BytesN<32>,
         enabled: bool,
         payment_token: Address,
+        max_amount: i128,
     ) -> Result<(), Error> {
+        if max_amount < 0 {
+            return Err(Error::InvalidPaymentAmount);
+        }
+
         // Get token to verify it exists and get user
         let token: MembershipToken = env
             .storage()
@@ -1183,22 +2245,55 @@ impl MembershipTokenContract {
         // Require token owner authorization
         token.user.require_auth();
 
+        let now = env.ledger().timestamp();
         let settings = crate::types::AutoRenewalSettings {
             enabled,
             token_id: token_id.clone(),
             payment_token: payment_token.clone(),
-            updated_at: env.ledger().timestamp(),
+            updated_at: now,
+            max_amount,
         };
 
         env.storage()
             .persistent()
             .set(&DataKey::AutoRenewalSettings(token.user.clone()), &settings);
+        env.storage()
+            .persistent()
+            .remove(&DataKey::PendingRenewalConsent(token_id.clone()));
+
+        let consent = RenewalConsent {
+            enabled,
+            payment_token: payment_token.clone(),
+            max_amount,
+            consented_at: now,
+        };
+        let consents_key = DataKey::RenewalConsents(token_id.clone());
+        let mut consents: Vec<RenewalConsent> = env
+            .storage()
+            .persistent()
+            .get(&consents_key)
+            .unwrap_or_else(|| Vec::new(&env));
+        if consents.len() >= MAX_RENEWAL_CONSENTS {
+            consents.pop_front();
+        }
+        consents.push_back(consent.clone());
+        env.storage().persistent().set(&consents_key, &consents);
+
+        AutoRenewalConsentRecorded {
+            token_id: token_id.clone(),
+            user: token.user.clone(),
+            consent,
+        }
+        .publish(&env);
 
         // Emit auto-renewal settings updated event
-        env.events().publish(
-            (symbol_short!("auto_rnw"), token_id, token.user),
-            (enabled, payment_token),
-        );
+        AutoRenewalUpdated {
+            token_id,
+            user: token.user,
+            enabled,
+            payment_token,
+        }
+        .publish(&env);
 
         Ok(())
     }
@@ -1215,9 +2310,160 @@ impl MembershipTokenContract {
         env: Env,
         user: Address,
     ) -> Option<crate::types::AutoRenewalSettings> {
+        let fields: Map<Symbol, Val> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::AutoRenewalSettings(user))?;
+        if fields.contains_key(Symbol::new(&env, "max_amount")) {
+            return crate::types::AutoRenewalSettings::try_from_val(&env, &fields.to_val()).ok();
+        }
+
+        // Settings saved before price caps existed renew uncapped
+        let legacy = LegacyAutoRenewalSettings::try_from_val(&env, &fields.to_val()).ok()?;
+        Some(crate::types::AutoRenewalSettings {
+            enabled: legacy.enabled,
+            token_id: legacy.token_id,
+            payment_token: legacy.payment_token,
+            updated_at: legacy.updated_at,
+            max_amount: 0,
+        })
+    }
+
+    /// Auto-renewal consents given for a token, oldest first.
+    pub fn get_renewal_consents(env: Env, token_id: BytesN<32>) -> Vec<RenewalConsent> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::RenewalConsents(token_id))
+            .unwrap_or_else(|| Vec::new(&env))
+    }
+
+    /// The auto-renewal of a token waiting for consent to a higher price,
+    /// if any.
+    pub fn get_pending_renewal_consent(
+        env: Env,
+        token_id: BytesN<32>,
+    ) -> Option<PendingRenewalConsent> {
         env.storage()
             .persistent()
-            .get(&DataKey::AutoRenewalSettings(user))
+            .get(&DataKey::PendingRenewalConsent(token_id))
+    }
+
+    /// Moves a token between expiry index buckets when its expiry changes.
+    pub(crate) fn reindex_expiry(
+        env: &Env,
+        token_id: &BytesN<32>,
+        old_expiry: Option<u64>,
+        new_expiry: u64,
+    ) {
+        let new_day = new_expiry / EXPIRY_BUCKET_SECS;
+        if let Some(old_day) = old_expiry.map(|expiry| expiry / EXPIRY_BUCKET_SECS) {
+            if old_day == new_day {
+                return;
+            }
+            let mut ids = Self::tokens_expiring_on(env, old_day);
+            if let Some(index) = ids.first_index_of(token_id) {
+                ids.remove(index);
+                let key = DataKey::ExpiringOn(old_day);
+                if ids.is_empty() {
+                    env.storage().persistent().remove(&key);
+                } else {
+                    env.storage().persistent().set(&key, &ids);
+                }
+            }
+        }
+        let mut ids = Self::tokens_expiring_on(env, new_day);
+        if !ids.contains(token_id) {
+            ids.push_back(token_id.clone());
+            env.storage()
+                .persistent()
+                .set(&DataKey::ExpiringOn(new_day), &ids);
+        }
+    }
+
+    fn tokens_expiring_on(env: &Env, day: u64) -> Vec<BytesN<32>> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::ExpiringOn(day))
+            .unwrap_or_else(|| Vec::new(env))
+    }
+
+    /// Tokens whose auto-renewal window is open now: active, expiring
+    /// within the notice period, with auto-renewal enabled for them and not
+    /// waiting for price consent. Ordered by expiry day; returns up to
+    /// `limit` candidates after skipping `offset`.
+    pub fn list_auto_renewal_candidates(env: Env, offset: u32, limit: u32) -> Vec<BytesN<32>> {
+        let now = env.ledger().timestamp();
+        let notice = Self::get_renewal_config(env.clone()).auto_renewal_notice_days;
+        let first_day = now / EXPIRY_BUCKET_SECS;
+        let last_day = (now.saturating_add(notice) / EXPIRY_BUCKET_SECS)
+            .min(first_day + MAX_RENEWAL_SCAN_DAYS - 1);
+        let limit = limit.min(MAX_RENEWAL_CANDIDATES_PAGE);
+
+        let mut candidates = Vec::new(&env);
+        let mut skipped = 0u32;
+        for day in first_day..=last_day {
+            for id in Self::tokens_expiring_on(&env, day).iter() {
+                if candidates.len() >= limit {
+                    return candidates;
+                }
+                if !Self::is_renewal_candidate(&env, &id, now, notice) {
+                    continue;
+                }
+                if skipped < offset {
+                    skipped += 1;
+                } else {
+                    candidates.push_back(id);
+                }
+            }
+        }
+        candidates
+    }
+
+    fn is_renewal_candidate(env: &Env, id: &BytesN<32>, now: u64, notice: u64) -> bool {
+        let Some(token) = env
+            .storage()
+            .persistent()
+            .get::<_, MembershipToken>(&DataKey::Token(id.clone()))
+        else {
+            return false;
+        };
+        if token.status != MembershipStatus::Active
+            || now >= token.expiry_date
+            || now < token.expiry_date.saturating_sub(notice)
+            || env
+                .storage()
+                .persistent()
+                .has(&DataKey::PendingRenewalConsent(id.clone()))
+        {
+            return false;
+        }
+        Self::get_auto_renewal_settings(env.clone(), token.user)
+            .is_some_and(|settings| settings.enabled && settings.token_id == *id)
+    }
+
+    /// The renewal window and grace deadline of a token.
+    pub fn get_renewal_window(env: Env, token_id: BytesN<32>) -> Result<RenewalWindow, Error> {
+        let token: MembershipToken = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Token(token_id.clone()))
+            .ok_or(Error::TokenNotFound)?;
+        let notice = Self::get_renewal_config(env.clone()).auto_renewal_notice_days;
+        let grace_deadline = match token.grace_period_expires_at {
+            Some(deadline) if token.status == MembershipStatus::GracePeriod => deadline,
+            _ => token
+                .expiry_date
+                .saturating_add(Self::resolve_grace_policy(&env, token.tier_id.as_ref()).duration),
+        };
+        let auto_renewal_enabled = Self::get_auto_renewal_settings(env.clone(), token.user)
+            .is_some_and(|settings| settings.enabled && settings.token_id == token_id);
+
+        Ok(RenewalWindow {
+            opens_at: token.expiry_date.saturating_sub(notice),
+            closes_at: token.expiry_date,
+            grace_deadline,
+            auto_renewal_enabled,
+        })
     }
 
     /// Checks if a token is eligible for auto-renewal.
@@ -1261,8 +2507,9 @@ impl MembershipTokenContract {
     ///
     /// # Returns
     /// * Success or error
-    pub fn process_auto_renewal(env: Env, id: BytesN<32>) -> Result<(), Error> {
-        // Get token
+    pub fn process_auto_renewal(env: Env, id: BytesN<32>) -> Result<(), ModuleError> {
+        // Get token, upgrading its schema first if it predates the current one
+        MigrationModule::migrate_token(&env, &id);
         let mut token: MembershipToken = env
             .storage()
             .persistent()
@@ -1274,12 +2521,12 @@ impl MembershipTokenContract {
             .ok_or(Error::AutoRenewalFailed)?;
 
         if !settings.enabled || settings.token_id != id {
-            return Err(Error::AutoRenewalFailed);
+            return Err(Error::AutoRenewalFailed.into());
         }
 
         // Check eligibility
         if !Self::check_auto_renewal_eligibility(env.clone(), id.clone())? {
-            return Err(Error::RenewalNotAllowed);
+            return Err(Error::RenewalNotAllowed.into());
         }
 
         // Get tier (use stored tier_id or error)
@@ -1289,15 +2536,45 @@ impl MembershipTokenContract {
         let tier = SubscriptionContract::get_tier(env.clone(), tier_id.clone())?;
 
         // Use monthly pricing for auto-renewal
-        let amount = tier.price;
+        let base_amount = tier.price;
         let duration = 30 * 24 * 60 * 60; // 30 days
 
         // Validate payment (but don't actually transfer - just validation)
-        let usdc_contract = SubscriptionContract::get_usdc_contract_address(&env)?;
-        if settings.payment_token != usdc_contract {
-            // Payment validation failed - enter grace period
-            Self::enter_grace_period_on_auto_renewal_failure(env, id, token)?;
-            return Err(Error::AutoRenewalFailed);
+        let amount = match Self::renewal_charge(
+            &env,
+            &token.user,
+            &settings.payment_token,
+            &tier_id,
+            base_amount,
+        ) {
+            Ok(charge) => charge.total,
+            Err(_) => {
+                // Payment validation failed - enter grace period. Returning
+                // an error would roll the grace period back with the call.
+                Self::enter_grace_period_on_auto_renewal_failure(env, id, token)?;
+                return Ok(());
+            }
+        };
+
+        // A price above the member's cap waits for their consent rather
+        // than lapsing the token
+        if settings.max_amount > 0 && amount > settings.max_amount {
+            env.storage().persistent().set(
+                &DataKey::PendingRenewalConsent(id.clone()),
+                &PendingRenewalConsent {
+                    required_amount: amount,
+                    max_amount: settings.max_amount,
+                    requested_at: env.ledger().timestamp(),
+                },
+            );
+            AutoRenewalConsentRequired {
+                token_id: id,
+                user: token.user,
+                required_amount: amount,
+                max_amount: settings.max_amount,
+            }
+            .publish(&env);
+            return Ok(());
         }
 
         // Note: In production, check if user has sufficient balance
@@ -1313,6 +2590,8 @@ impl MembershipTokenContract {
             .ok_or(Error::TimestampOverflow)?;
 
         // Update token
+        UpgradeModule::snapshot_token(&env, &id, &token, None);
+        Self::reindex_expiry(&env, &id, Some(token.expiry_date), new_expiry);
         token.expiry_date = new_expiry;
         token.renewal_attempts = token.renewal_attempts.saturating_add(1);
         token.last_renewal_attempt_at = Some(current_time);
@@ -1321,6 +2600,14 @@ impl MembershipTokenContract {
         env.storage()
             .persistent()
             .set(&DataKey::Token(id.clone()), &token);
+        TtlModule::bump(&env, TtlEntityClass::Token, &DataKey::Token(id.clone()));
+
+        env.storage()
+            .persistent()
+            .remove(&DataKey::PendingRenewalConsent(id.clone()));
+
+        TreasuryModule::record_revenue(&env, RevenueSource::Renewal, base_amount)?;
+        LoyaltyModule::on_renewal(&env, &token.user, &tier_id);
 
         // Record successful auto-renewal
         Self::record_renewal(
@@ -1339,12 +2626,25 @@ impl MembershipTokenContract {
             },
         );
 
-        // Emit auto-renewal success event
-        env.events().publish(
-            (symbol_short!("auto_ok"), id, token.user),
-            (settings.payment_token, amount, old_expiry, new_expiry),
+        MembershipSyncModule::sync(&env, &token.user, MembershipStatus::Active, new_expiry);
+        OutboxModule::append(
+            &env,
+            symbol_short!("tok_renew"),
+            OutboxSubject::Token(id.clone()),
         );
 
+        // Emit auto-renewal success event
+        TokenRenewed {
+            token_id: id,
+            user: token.user,
+            payment_token: settings.payment_token,
+            amount,
+            old_expiry,
+            new_expiry,
+            automatic: true,
+        }
+        .publish(&env);
+
         Ok(())
     }
 
@@ -1352,61 +2652,104 @@ impl MembershipTokenContract {
     ///
     /// # Arguments:
     /// * `env` - The contract environment
-    /// * `admin` - Admin address (must be authorized)
+    /// * `caller` - Admin or guardian address (must be authorized)
     /// * `reason` - Human-readable reason for the pause
     /// * `auto_unpause_after` - Optional seconds until automatic unpause.
     ///   When the ledger timestamp reaches `now + auto_unpause_after`, operations
     ///   are allowed again without an explicit admin action.
     /// * `time_lock_duration` - Optional minimum number of seconds before an admin
     ///   can manually unpause. Use this for high-severity incidents to prevent a
-    ///   compromised admin key from immediately reversing the pause.
+    ///   compromised admin key from immediately reversing the pause. Admin only.
+    ///
+    /// A guardian pausing while a pause is already active can only push the
+    /// auto-unpause deadline later. The time lock, initiator, reason and
+    /// history record of the active pause are kept.
     ///
     /// # Errors
     /// * `AdminNotSet` - No admin has been configured
-    /// * `Unauthorized` - Caller is not the admin
+    /// * `Unauthorized` - Caller is neither the admin nor a guardian, or a
+    ///   guardian passed a time lock
     pub fn emergency_pause(
         env: Env,
-        admin: Address,
+        caller: Address,
         reason: Option<String>,
         auto_unpause_after: Option<u64>,
         time_lock_duration: Option<u64>,
     ) -> Result<(), Error> {
-        let stored_admin: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::Admin)
-            .ok_or(Error::AdminNotSet)?;
-        if admin != stored_admin {
+        // Guardians may pause, but a time lock would also bind the admin.
+        let by_guardian = GuardianModule::require_pauser(&env, &caller)?;
+        if by_guardian && time_lock_duration.is_some() {
             return Err(Error::Unauthorized);
         }
-        admin.require_auth();
 
         let current_time = env.ledger().timestamp();
 
         let mut state = PauseGuard::get_pause_state(&env);
+        let auto_unpause_at = auto_unpause_after.and_then(|secs| current_time.checked_add(secs));
+        if by_guardian && PauseGuard::is_paused(&env) {
+            // Only the deadline moves; the pause and its history record
+            // stay attributed to whoever started it
+            state.auto_unpause_at =
+                Self::extend_active_pause(state.auto_unpause_at, auto_unpause_at);
+            env.storage()
+                .instance()
+                .set(&DataKey::EmergencyPauseState, &state);
+            let mut history = Self::pause_history(&env);
+            if let Some(last) = history.len().checked_sub(1) {
+                let mut record = history.get(last).unwrap();
+                record.auto_unpause_at = state.auto_unpause_at;
+                history.set(last, record);
+                env.storage()
+                    .persistent()
+                    .set(&DataKey::EmergencyPauseHistory, &history);
+            }
+            EmergencyPauseExtended {
+                guardian: caller,
+                auto_unpause_at: state.auto_unpause_at,
+            }
+            .publish(&env);
+            return Ok(());
+        }
+        state.auto_unpause_at = auto_unpause_at;
+        state.time_lock_until = time_lock_duration.and_then(|secs| current_time.checked_add(secs));
 
         state.is_paused = true;
         state.paused_at = Some(current_time);
-        state.paused_by = Some(admin.clone());
+        state.paused_by = Some(caller.clone());
         state.reason = reason.clone();
-        state.auto_unpause_at = auto_unpause_after.and_then(|secs| current_time.checked_add(secs));
-        state.time_lock_until = time_lock_duration.and_then(|secs| current_time.checked_add(secs));
         state.pause_count = state.pause_count.saturating_add(1);
 
         env.storage()
             .instance()
             .set(&DataKey::EmergencyPauseState, &state);
 
+        // A pause that was still open (or auto-expired) is superseded.
+        Self::close_pause_record(&env, current_time, None);
+        let mut history = Self::pause_history(&env);
+        history.push_back(GlobalPauseRecord {
+            paused_at: current_time,
+            paused_by: caller.clone(),
+            reason: reason.clone(),
+            auto_unpause_at: state.auto_unpause_at,
+            time_lock_until: state.time_lock_until,
+            unpaused_at: None,
+            unpaused_by: None,
+            duration: 0,
+        });
+        env.storage()
+            .persistent()
+            .set(&DataKey::EmergencyPauseHistory, &history);
+
         // Emit PauseStateChanged event.
-        env.events().publish(
-            (symbol_short!("emg_pause"), admin.clone()),
-            (
-                current_time,
-                reason,
-                state.auto_unpause_at,
-                state.time_lock_until,
-            ),
-        );
+        EmergencyPaused {
+            admin: caller.clone(),
+            paused_at: current_time,
+            reason,
+            auto_unpause_at: state.auto_unpause_at,
+            time_lock_until: state.time_lock_until,
+            pause_count: state.pause_count,
+        }
+        .publish(&env);
 
         Ok(())
     }
@@ -1420,14 +2763,14 @@ impl MembershipTokenContract {
     /// * `AdminNotSet` - No admin has been configured
     /// * `Unauthorized` - Caller is not the admin
     /// * `TimeLockNotExpired` - The mandatory lock window has not yet elapsed
-    pub fn emergency_unpause(env: Env, admin: Address) -> Result<(), Error> {
+    pub fn emergency_unpause(env: Env, admin: Address) -> Result<(), ModuleError> {
         let stored_admin: Address = env
             .storage()
             .instance()
             .get(&DataKey::Admin)
             .ok_or(Error::AdminNotSet)?;
         if admin != stored_admin {
-            return Err(Error::Unauthorized);
+            return Err(Error::Unauthorized.into());
         }
         admin.require_auth();
 
@@ -1445,16 +2788,88 @@ impl MembershipTokenContract {
         env.storage()
             .instance()
             .set(&DataKey::EmergencyPauseState, &state);
+        Self::close_pause_record(&env, env.ledger().timestamp(), Some(admin.clone()));
 
         // Emit PauseStateChanged event.
-        env.events().publish(
-            (symbol_short!("emg_unp"), admin.clone()),
-            (env.ledger().timestamp(),),
-        );
+        EmergencyUnpaused {
+            admin: admin.clone(),
+            timestamp: env.ledger().timestamp(),
+        }
+        .publish(&env);
 
         Ok(())
     }
 
+    fn pause_history(env: &Env) -> Vec<GlobalPauseRecord> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::EmergencyPauseHistory)
+            .unwrap_or_else(|| Vec::new(env))
+    }
+
+    /// Marks the latest pause record as ended. If its auto-unpause deadline
+    /// passed first, that deadline is recorded as the end with no actor.
+    fn close_pause_record(env: &Env, at: u64, by: Option<Address>) {
+        let mut history = Self::pause_history(env);
+        let Some(last) = history.len().checked_sub(1) else {
+            return;
+        };
+        let Some(record) = history.get(last) else {
+            return;
+        };
+        if record.unpaused_at.is_some() {
+            return;
+        }
+        history.set(last, Self::ended_record(record, at, by));
+        env.storage()
+            .persistent()
+            .set(&DataKey::EmergencyPauseHistory, &history);
+    }
+
+    fn ended_record(
+        mut record: GlobalPauseRecord,
+        at: u64,
+        by: Option<Address>,
+    ) -> GlobalPauseRecord {
+        let (end, by) = match record.auto_unpause_at {
+            Some(auto_at) if auto_at <= at => (auto_at, None),
+            _ => (at, by),
+        };
+        record.unpaused_at = Some(end);
+        record.unpaused_by = by;
+        record.duration = end.saturating_sub(record.paused_at);
+        record
+    }
+
+    /// Returns up to `limit` global pause records starting at `offset`, oldest
+    /// first. An open pause whose auto-unpause deadline has passed is reported
+    /// as ended at that deadline.
+    pub fn get_emergency_pause_history(
+        env: Env,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<GlobalPauseRecord> {
+        let history = Self::pause_history(&env);
+        let now = env.ledger().timestamp();
+        let end = offset
+            .saturating_add(limit.min(MAX_PAUSE_HISTORY_PAGE))
+            .min(history.len());
+
+        let mut page = Vec::new(&env);
+        for index in offset..end {
+            if let Some(record) = history.get(index) {
+                let expired = record.unpaused_at.is_none()
+                    && record.auto_unpause_at.is_some_and(|auto_at| auto_at <= now);
+                page.push_back(if expired {
+                    Self::ended_record(record, now, None)
+                } else {
+                    record
+                });
+            }
+        }
+        page
+    }
+
     /// Returns the current global emergency pause state.
     pub fn get_emergency_pause_state(env: Env) -> EmergencyPauseState {
         PauseGuard::get_pause_state(&env)
@@ -1473,30 +2888,66 @@ impl MembershipTokenContract {
     ///
     /// # Arguments
     /// * `env` - The contract environment
-    /// * `admin` - Admin address (must be authorized)
+    /// * `caller` - Admin or guardian address (must be authorized)
     /// * `token_id` - The token whose operations should be paused
     /// * `reason` - Human-readable reason for the pause
     ///
+    /// As with `emergency_pause`, a guardian re-pausing a paused token can
+    /// only push the auto-unpause deadline later and keeps the active
+    /// pause's time lock, initiator, reason and history record.
+    ///
     /// # Errors
     /// * `AdminNotSet` - No admin has been configured
-    /// * `Unauthorized` - Caller is not the admin
+    /// * `Unauthorized` - Caller is neither the admin nor a guardian
     /// * `TokenNotFound` - The specified token does not exist
     pub fn pause_token_operations(
         env: Env,
-        admin: Address,
+        caller: Address,
         token_id: BytesN<32>,
         reason: Option<String>,
+        auto_unpause_after: Option<u64>,
+        time_lock_duration: Option<u64>,
     ) -> Result<(), Error> {
-        let stored_admin: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::Admin)
-            .ok_or(Error::AdminNotSet)?;
-        if admin != stored_admin {
+        // Guardians may pause, but a time lock would also bind the admin.
+        let by_guardian = GuardianModule::require_pauser(&env, &caller)?;
+        if by_guardian && time_lock_duration.is_some() {
             return Err(Error::Unauthorized);
         }
-        admin.require_auth();
 
+        Self::apply_token_pause(
+            &env,
+            &caller,
+            by_guardian,
+            &token_id,
+            reason,
+            auto_unpause_after,
+            time_lock_duration,
+        )
+    }
+
+    /// Auto-unpause deadline for a guardian pausing again while a pause is
+    /// active: it can only move later, so a guardian can never shorten or
+    /// lift someone else's pause. The time lock is left as it is.
+    fn extend_active_pause(
+        auto_unpause_at: Option<u64>,
+        requested_auto_unpause_at: Option<u64>,
+    ) -> Option<u64> {
+        match (auto_unpause_at, requested_auto_unpause_at) {
+            (Some(current), Some(requested)) => Some(current.max(requested)),
+            _ => None,
+        }
+    }
+
+    /// Pauses one token for an already authorized caller.
+    fn apply_token_pause(
+        env: &Env,
+        caller: &Address,
+        by_guardian: bool,
+        token_id: &BytesN<32>,
+        reason: Option<String>,
+        auto_unpause_after: Option<u64>,
+        time_lock_duration: Option<u64>,
+    ) -> Result<(), Error> {
         // Ensure the token exists before pausing it.
         let _token: crate::membership_token::MembershipToken = env
             .storage()
@@ -1505,26 +2956,159 @@ impl MembershipTokenContract {
             .ok_or(Error::TokenNotFound)?;
 
         let current_time = env.ledger().timestamp();
+        let auto_unpause_at = auto_unpause_after.and_then(|secs| current_time.checked_add(secs));
+        if by_guardian && PauseGuard::is_token_paused(env, token_id) {
+            // As for the global pause, only the deadline moves
+            let mut active: TokenPauseState = env
+                .storage()
+                .persistent()
+                .get(&DataKey::TokenPaused(token_id.clone()))
+                .ok_or(Error::TokenNotFound)?;
+            active.auto_unpause_at =
+                Self::extend_active_pause(active.auto_unpause_at, auto_unpause_at);
+            env.storage()
+                .persistent()
+                .set(&DataKey::TokenPaused(token_id.clone()), &active);
+            let mut history = Self::token_pause_history(env, token_id);
+            if let Some(last) = history.len().checked_sub(1) {
+                let mut record = history.get(last).unwrap();
+                record.auto_unpause_at = active.auto_unpause_at;
+                history.set(last, record);
+                env.storage()
+                    .persistent()
+                    .set(&DataKey::TokenPauseHistory(token_id.clone()), &history);
+            }
+            TokenPauseExtended {
+                token_id: token_id.clone(),
+                guardian: caller.clone(),
+                auto_unpause_at: active.auto_unpause_at,
+            }
+            .publish(env);
+            return Ok(());
+        }
         let token_pause = TokenPauseState {
             is_paused: true,
             paused_at: current_time,
-            paused_by: admin.clone(),
+            paused_by: caller.clone(),
             reason: reason.clone(),
+            auto_unpause_at,
+            time_lock_until: time_lock_duration.and_then(|secs| current_time.checked_add(secs)),
         };
 
         env.storage()
             .persistent()
             .set(&DataKey::TokenPaused(token_id.clone()), &token_pause);
 
+        // A pause that was still open (or auto-expired) is superseded.
+        Self::close_token_pause_record(env, token_id, current_time, None);
+        let mut history = Self::token_pause_history(env, token_id);
+        history.push_back(TokenPauseRecord {
+            paused_at: current_time,
+            paused_by: caller.clone(),
+            reason: reason.clone(),
+            auto_unpause_at: token_pause.auto_unpause_at,
+            time_lock_until: token_pause.time_lock_until,
+            unpaused_at: None,
+            unpaused_by: None,
+            duration: 0,
+        });
+        env.storage()
+            .persistent()
+            .set(&DataKey::TokenPauseHistory(token_id.clone()), &history);
+
         // Emit per-token pause event.
-        env.events().publish(
-            (symbol_short!("tok_pause"), token_id.clone(), admin.clone()),
-            (current_time, reason),
-        );
+        TokenPaused {
+            token_id: token_id.clone(),
+            admin: caller.clone(),
+            paused_at: current_time,
+            reason,
+            auto_unpause_at: token_pause.auto_unpause_at,
+            time_lock_until: token_pause.time_lock_until,
+        }
+        .publish(env);
 
         Ok(())
     }
 
+    /// Pauses each of `token_ids`, reporting per-token success instead of
+    /// failing the whole batch.
+    ///
+    /// # Errors
+    /// * `AdminNotSet` - No admin has been configured
+    /// * `Unauthorized` - Caller is neither the admin nor a guardian, or the
+    ///   batch is empty or larger than `MAX_BATCH_SIZE`
+    pub fn batch_pause_token_operations(
+        env: Env,
+        caller: Address,
+        token_ids: Vec<BytesN<32>>,
+        reason: Option<String>,
+    ) -> Result<Vec<BatchPauseResult>, Error> {
+        BatchValidator::validate_batch_size(token_ids.len())?;
+        let by_guardian = GuardianModule::require_pauser(&env, &caller)?;
+
+        let mut results = Vec::new(&env);
+        for token_id in token_ids.iter() {
+            let result = Self::apply_token_pause(
+                &env,
+                &caller,
+                by_guardian,
+                &token_id,
+                reason.clone(),
+                None,
+                None,
+            );
+            results.push_back(Self::batch_pause_result(
+                token_id,
+                result.map_err(Into::into),
+            ));
+        }
+        Ok(results)
+    }
+
+    /// Pauses every token currently held by `owner`, e.g. when the owner's
+    /// account is compromised.
+    ///
+    /// # Errors
+    /// * `AdminNotSet` - No admin has been configured
+    /// * `Unauthorized` - Caller is neither the admin nor a guardian
+    pub fn pause_tokens_by_owner(
+        env: Env,
+        caller: Address,
+        owner: Address,
+        reason: Option<String>,
+    ) -> Result<Vec<BatchPauseResult>, Error> {
+        let by_guardian = GuardianModule::require_pauser(&env, &caller)?;
+
+        let mut results = Vec::new(&env);
+        for token_id in Self::get_owner_tokens(&env, &owner).iter() {
+            let result = Self::apply_token_pause(
+                &env,
+                &caller,
+                by_guardian,
+                &token_id,
+                reason.clone(),
+                None,
+                None,
+            );
+            results.push_back(Self::batch_pause_result(
+                token_id,
+                result.map_err(Into::into),
+            ));
+        }
+        Ok(results)
+    }
+
+    fn batch_pause_result(
+        token_id: BytesN<32>,
+        result: Result<(), ModuleError>,
+    ) -> BatchPauseResult {
+        BatchPauseResult {
+            token_id,
+            success: result.is_ok(),
+            error_code: result.err().map(|e| e.get_code()),
+        }
+    }
+
     /// Resumes operations for a previously paused token.
     ///
     /// # Errors
@@ -1535,17 +3119,26 @@ impl MembershipTokenContract {
         env: Env,
         admin: Address,
         token_id: BytesN<32>,
-    ) -> Result<(), Error> {
+    ) -> Result<(), ModuleError> {
         let stored_admin: Address = env
             .storage()
             .instance()
             .get(&DataKey::Admin)
             .ok_or(Error::AdminNotSet)?;
         if admin != stored_admin {
-            return Err(Error::Unauthorized);
+            return Err(Error::Unauthorized.into());
         }
         admin.require_auth();
 
+        Self::release_token_pause(&env, &admin, &token_id)
+    }
+
+    /// Unpauses one token for an already authorized admin.
+    fn release_token_pause(
+        env: &Env,
+        admin: &Address,
+        token_id: &BytesN<32>,
+    ) -> Result<(), ModuleError> {
         // Ensure the token exists.
         let _token: crate::membership_token::MembershipToken = env
             .storage()
@@ -1553,24 +3146,81 @@ impl MembershipTokenContract {
             .get(&DataKey::Token(token_id.clone()))
             .ok_or(Error::TokenNotFound)?;
 
+        // Enforce the token's time lock before allowing a manual unpause.
+        PauseGuard::require_token_timelock_expired(env, token_id)?;
+
+        Self::lift_token_pause(env, admin, token_id);
+        Ok(())
+    }
+
+    /// Unpauses each of `token_ids`, reporting per-token success instead of
+    /// failing the whole batch.
+    ///
+    /// # Errors
+    /// * `AdminNotSet` - No admin has been configured
+    /// * `Unauthorized` - Caller is not the admin, or the batch is empty or
+    ///   larger than `MAX_BATCH_SIZE`
+    pub fn batch_unpause_token_operations(
+        env: Env,
+        admin: Address,
+        token_ids: Vec<BytesN<32>>,
+    ) -> Result<Vec<BatchPauseResult>, Error> {
+        BatchValidator::validate_batch_size(token_ids.len())?;
+        Self::require_admin(&env, &admin)?;
+
+        let mut results = Vec::new(&env);
+        for token_id in token_ids.iter() {
+            let result = Self::release_token_pause(&env, &admin, &token_id);
+            results.push_back(Self::batch_pause_result(token_id, result));
+        }
+        Ok(results)
+    }
+
+    /// Unpauses every token currently held by `owner`.
+    ///
+    /// # Errors
+    /// * `AdminNotSet` - No admin has been configured
+    /// * `Unauthorized` - Caller is not the admin
+    pub fn unpause_tokens_by_owner(
+        env: Env,
+        admin: Address,
+        owner: Address,
+    ) -> Result<Vec<BatchPauseResult>, Error> {
+        Self::require_admin(&env, &admin)?;
+
+        let mut results = Vec::new(&env);
+        for token_id in Self::get_owner_tokens(&env, &owner).iter() {
+            let result = Self::release_token_pause(&env, &admin, &token_id);
+            results.push_back(Self::batch_pause_result(token_id, result));
+        }
+        Ok(results)
+    }
+
+    /// Records `token_id` as unpaused by `by` and emits `TokenUnpaused`.
+    /// Callers must have checked authorization, the time lock and that the
+    /// token exists.
+    pub(crate) fn lift_token_pause(env: &Env, by: &Address, token_id: &BytesN<32>) {
         let token_pause = TokenPauseState {
             is_paused: false,
             paused_at: env.ledger().timestamp(),
-            paused_by: admin.clone(),
+            paused_by: by.clone(),
             reason: None,
+            auto_unpause_at: None,
+            time_lock_until: None,
         };
 
         env.storage()
             .persistent()
             .set(&DataKey::TokenPaused(token_id.clone()), &token_pause);
+        Self::close_token_pause_record(env, token_id, env.ledger().timestamp(), Some(by.clone()));
 
         // Emit per-token unpause event.
-        env.events().publish(
-            (symbol_short!("tok_unp"), token_id.clone(), admin.clone()),
-            (env.ledger().timestamp(),),
-        );
-
-        Ok(())
+        TokenUnpaused {
+            token_id: token_id.clone(),
+            admin: by.clone(),
+            timestamp: env.ledger().timestamp(),
+        }
+        .publish(env);
     }
 
     /// Returns `true` if the specific token's operations are currently paused.
@@ -1578,37 +3228,131 @@ impl MembershipTokenContract {
         PauseGuard::is_token_paused(&env, &token_id)
     }
 
+    fn token_pause_history(env: &Env, token_id: &BytesN<32>) -> Vec<TokenPauseRecord> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::TokenPauseHistory(token_id.clone()))
+            .unwrap_or_else(|| Vec::new(env))
+    }
+
+    /// Marks the token's latest pause record as ended. If its auto-unpause
+    /// deadline passed first, that deadline is recorded as the end with no
+    /// actor.
+    fn close_token_pause_record(env: &Env, token_id: &BytesN<32>, at: u64, by: Option<Address>) {
+        let mut history = Self::token_pause_history(env, token_id);
+        let Some(last) = history.len().checked_sub(1) else {
+            return;
+        };
+        let Some(record) = history.get(last) else {
+            return;
+        };
+        if record.unpaused_at.is_some() {
+            return;
+        }
+        history.set(last, Self::ended_token_record(record, at, by));
+        env.storage()
+            .persistent()
+            .set(&DataKey::TokenPauseHistory(token_id.clone()), &history);
+    }
+
+    fn ended_token_record(
+        mut record: TokenPauseRecord,
+        at: u64,
+        by: Option<Address>,
+    ) -> TokenPauseRecord {
+        let (end, by) = match record.auto_unpause_at {
+            Some(auto_at) if auto_at <= at => (auto_at, None),
+            _ => (at, by),
+        };
+        record.unpaused_at = Some(end);
+        record.unpaused_by = by;
+        record.duration = end.saturating_sub(record.paused_at);
+        record
+    }
+
+    /// Returns up to `limit` pause records of `token_id` starting at
+    /// `offset`, oldest first. An open pause whose auto-unpause deadline has
+    /// passed is reported as ended at that deadline.
+    pub fn get_token_pause_history(
+        env: Env,
+        token_id: BytesN<32>,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<TokenPauseRecord> {
+        let history = Self::token_pause_history(&env, &token_id);
+        let now = env.ledger().timestamp();
+        let end = offset
+            .saturating_add(limit.min(MAX_PAUSE_HISTORY_PAGE))
+            .min(history.len());
+
+        let mut page = Vec::new(&env);
+        for index in offset..end {
+            if let Some(record) = history.get(index) {
+                let expired = record.unpaused_at.is_none()
+                    && record.auto_unpause_at.is_some_and(|auto_at| auto_at <= now);
+                page.push_back(if expired {
+                    Self::ended_token_record(record, now, None)
+                } else {
+                    record
+                });
+            }
+        }
+        page
+    }
+
     /// Helper function to enter grace period when auto-renewal fails.
     fn enter_grace_period_on_auto_renewal_failure(
         env: Env,
         id: BytesN<32>,
         mut token: MembershipToken,
     ) -> Result<(), Error> {
-        let config = Self::get_renewal_config(env.clone());
+        let policy = Self::resolve_grace_policy(&env, token.tier_id.as_ref());
         let current_time = env.ledger().timestamp();
 
+        UpgradeModule::snapshot_token(&env, &id, &token, None);
+        if token.status == MembershipStatus::Active {
+            StatsModule::record_token_lapsed(&env);
+        }
         token.status = MembershipStatus::GracePeriod;
         token.grace_period_entered_at = Some(current_time);
         token.grace_period_expires_at = Some(
             current_time
-                .checked_add(config.grace_period_duration)
+                .checked_add(policy.duration)
                 .ok_or(Error::TimestampOverflow)?,
         );
+        StatsModule::record_grace_started(&env, token.grace_period_expires_at.unwrap());
+        StatsModule::record_auto_renewal_failure(&env);
 
         env.storage()
             .persistent()
             .set(&DataKey::Token(id.clone()), &token);
 
-        // Emit grace period entered due to auto-renewal failure
-        env.events().publish(
-            (symbol_short!("grace_ar"), id, token.user),
-            (
-                current_time,
-                token.grace_period_expires_at.unwrap(),
-                String::from_str(&env, "auto_renewal_failed"),
-            ),
+        TtlModule::bump(&env, TtlEntityClass::Token, &DataKey::Token(id.clone()));
+        MembershipSyncModule::sync(
+            &env,
+            &token.user,
+            MembershipStatus::GracePeriod,
+            token.expiry_date,
         );
 
+        NotificationModule::notify(
+            &env,
+            &token.user,
+            NotificationKind::AutoRenewalFailed,
+            OutboxSubject::Token(id.clone()),
+            token.grace_period_expires_at.unwrap(),
+        );
+
+        // Emit grace period entered due to auto-renewal failure
+        GracePeriodEntered {
+            token_id: id,
+            user: token.user,
+            entered_at: current_time,
+            expires_at: token.grace_period_expires_at.unwrap(),
+            reason: Symbol::new(&env, "auto_renewal_failed"),
+        }
+        .publish(&env);
+
         Ok(())
     }
 }
@@ -0,0 +1,143 @@
+use crate::errors::Error;
+use crate::events::MetadataSchemaSet;
+use crate::membership_token::MembershipTokenContract;
+use crate::types::{MetadataFieldRule, MetadataSchema, MetadataValueKind};
+use common_types::MetadataValue;
+use soroban_sdk::{contracttype, Address, Env, Map, String, Vec};
+
+// ---------------------------------------------------------------------------
+// Storage keys
+// ---------------------------------------------------------------------------
+
+#[contracttype]
+pub enum MetadataSchemaDataKey {
+    /// Version of the schema in force; absent until one is set (instance storage).
+    ActiveVersion,
+    /// Every schema version ever set (persistent storage).
+    Schema(u32),
+}
+
+// ---------------------------------------------------------------------------
+// Constants
+// ---------------------------------------------------------------------------
+
+/// Matches the attribute cap enforced by `validate_metadata`.
+pub const MAX_SCHEMA_FIELDS: u32 = 20;
+
+// ---------------------------------------------------------------------------
+// Module
+// ---------------------------------------------------------------------------
+
+/// Admin-defined schema for token metadata attributes.
+///
+/// Each rule fixes the `MetadataValue` variant of an attribute key, whether it
+/// is required, and optional inclusive bounds: the value for `Number` and
+/// `Timestamp`, the length for `Text`. Keys without a rule stay free-form.
+/// Setting a schema creates a new version and makes it active; metadata
+/// written through the public endpoints is validated against it.
+pub struct MetadataSchemaModule;
+
+impl MetadataSchemaModule {
+    pub fn set_schema(
+        env: Env,
+        admin: Address,
+        fields: Vec<MetadataFieldRule>,
+    ) -> Result<u32, Error> {
+        MembershipTokenContract::require_admin(&env, &admin)?;
+        if fields.len() > MAX_SCHEMA_FIELDS {
+            return Err(Error::MetadataTooManyAttributes);
+        }
+
+        let mut keys: Vec<String> = Vec::new(&env);
+        for rule in fields.iter() {
+            if keys.contains(&rule.key) {
+                return Err(Error::MetadataValidationFailed);
+            }
+            if let (Some(min), Some(max)) = (rule.min, rule.max) {
+                if min > max {
+                    return Err(Error::MetadataValidationFailed);
+                }
+            }
+            keys.push_back(rule.key);
+        }
+
+        let version = Self::get_active_version(&env).unwrap_or(0) + 1;
+        let created_at = env.ledger().timestamp();
+        let schema = MetadataSchema {
+            version,
+            fields: fields.clone(),
+            created_at,
+        };
+        env.storage()
+            .persistent()
+            .set(&MetadataSchemaDataKey::Schema(version), &schema);
+        env.storage()
+            .instance()
+            .set(&MetadataSchemaDataKey::ActiveVersion, &version);
+
+        MetadataSchemaSet {
+            version,
+            fields: fields.len(),
+            set_by: admin,
+            timestamp: created_at,
+        }
+        .publish(&env);
+        Ok(version)
+    }
+
+    pub fn get_active_version(env: &Env) -> Option<u32> {
+        env.storage()
+            .instance()
+            .get(&MetadataSchemaDataKey::ActiveVersion)
+    }
+
+    pub fn get_schema(env: &Env, version: u32) -> Option<MetadataSchema> {
+        env.storage()
+            .persistent()
+            .get(&MetadataSchemaDataKey::Schema(version))
+    }
+
+    pub fn get_active_schema(env: &Env) -> Option<MetadataSchema> {
+        Self::get_schema(env, Self::get_active_version(env)?)
+    }
+
+    /// Checks a full attribute map against the active schema, if any.
+    pub(crate) fn validate(
+        env: &Env,
+        attributes: &Map<String, MetadataValue>,
+    ) -> Result<(), Error> {
+        let Some(schema) = Self::get_active_schema(env) else {
+            return Ok(());
+        };
+
+        for rule in schema.fields.iter() {
+            match attributes.get(rule.key.clone()) {
+                Some(value) => Self::check_value(&rule, &value)?,
+                None if rule.required => return Err(Error::MetadataValidationFailed),
+                None => {}
+            }
+        }
+        Ok(())
+    }
+
+    fn check_value(rule: &MetadataFieldRule, value: &MetadataValue) -> Result<(), Error> {
+        let measure = match (&rule.kind, value) {
+            (MetadataValueKind::Text, MetadataValue::Text(text)) => Some(text.len() as i128),
+            (MetadataValueKind::Number, MetadataValue::Number(number)) => Some(*number),
+            (MetadataValueKind::Timestamp, MetadataValue::Timestamp(timestamp)) => {
+                Some(*timestamp as i128)
+            }
+            (MetadataValueKind::Boolean, MetadataValue::Boolean(_)) => None,
+            _ => return Err(Error::MetadataValidationFailed),
+        };
+
+        if let Some(measure) = measure {
+            let below = rule.min.is_some_and(|min| measure < min);
+            let above = rule.max.is_some_and(|max| measure > max);
+            if below || above {
+                return Err(Error::MetadataValidationFailed);
+            }
+        }
+        Ok(())
+    }
+}
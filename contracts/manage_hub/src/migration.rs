@@ -3,14 +3,440 @@
 //! This module provides utilities to migrate token state when upgrading between
 //! versions. Migrations preserve token identity (id, user, issue_date) while
 //! allowing modifications to mutable fields (expiry_date, tier_id, status).
+//!
+//! It also tracks the storage schema version. Each persisted token and
+//! subscription records the schema version it was last written with; entries
+//! are brought up to `CURRENT_SCHEMA_VERSION` lazily when touched by a
+//! renewal, or in admin-driven batches with per-entity progress tracking.
+//! Stored subscriptions are recognised through `VersionedSubscription`:
+//! entries from before tier prices were locked in get a locked price of 0,
+//! and entries written before version 2 have their inline pause history
+//! split out into its own entries the first time they are read.
+//! Token metadata in an older layout is recognised through
+//! `common_types::VersionedTokenMetadata` and upgraded the same way.
+
+// Allow deprecated events API until migration to #[contractevent] macro
+#![allow(deprecated)]
+
+use crate::errors::{Error, ModuleError};
+use crate::membership_token::{DataKey, MembershipToken, MembershipTokenContract};
+use crate::subscription::{SubscriptionContract, SubscriptionDataKey};
+use crate::ttl::{TtlEntityClass, TtlModule};
+use crate::types::{
+    LegacyMigrationProgress, LegacySubscription, MembershipStatus, MigrationEntity,
+    MigrationProgress, Subscription, SubscriptionV0, TokenVersionSnapshot, UpgradeRecord,
+};
+use crate::upgrade_errors::UpgradeError;
+use crate::validation::BatchValidator;
+use common_types::{TokenMetadata, VersionedTokenMetadata};
+use soroban_sdk::{
+    contracttype, symbol_short, Address, BytesN, Env, Map, String, Symbol, TryFromVal, Val, Vec,
+};
+
+// ---------------------------------------------------------------------------
+// Storage keys
+// ---------------------------------------------------------------------------
+
+#[contracttype]
+pub enum MigrationDataKey {
+    /// Schema version the whole store has been finalized at (instance storage).
+    StorageVersion,
+    /// Schema version a token was last written with (persistent storage).
+    TokenSchema(BytesN<32>),
+    /// Schema version a subscription was last written with (persistent storage).
+    SubscriptionSchema(String),
+    /// Batch migration progress per entity kind (instance storage).
+    Progress(MigrationEntity),
+}
 
-use crate::membership_token::{DataKey, MembershipToken};
-use crate::types::{MembershipStatus, TokenVersionSnapshot, UpgradeRecord};
-use soroban_sdk::{Address, BytesN, Env, String, Vec};
+// ---------------------------------------------------------------------------
+// Constants
+// ---------------------------------------------------------------------------
+
+/// Schema version written by this build of the contract.
+///
+/// Bump this and add a step to `migrate_token_entry` /
+/// `migrate_subscription_entry` whenever a persisted struct changes shape.
+/// Entries without a recorded version predate versioning and are version 0.
+pub const CURRENT_SCHEMA_VERSION: u32 = 2;
+
+/// A stored subscription in any layout it has been written with,
+/// recognised by the fields each layout introduced.
+enum VersionedSubscription {
+    /// Before tier prices were locked in
+    V0(SubscriptionV0),
+    /// Locked price, pause history still inline (schema version 1)
+    V1(LegacySubscription),
+    /// Current layout (schema version 2)
+    V2(Subscription),
+}
+
+impl VersionedSubscription {
+    fn from_stored(env: &Env, fields: &Map<Symbol, Val>) -> Option<Self> {
+        let value = fields.to_val();
+        if !fields.contains_key(Symbol::new(env, "pause_history")) {
+            Subscription::try_from_val(env, &value)
+                .ok()
+                .map(VersionedSubscription::V2)
+        } else if fields.contains_key(Symbol::new(env, "locked_price")) {
+            LegacySubscription::try_from_val(env, &value)
+                .ok()
+                .map(VersionedSubscription::V1)
+        } else {
+            SubscriptionV0::try_from_val(env, &value)
+                .ok()
+                .map(VersionedSubscription::V0)
+        }
+    }
+}
 
 pub struct MigrationModule;
 
 impl MigrationModule {
+    // -----------------------------------------------------------------------
+    // Schema versioning
+    // -----------------------------------------------------------------------
+
+    /// Schema version the store was last finalized at (0 for pre-versioning
+    /// deployments that have never been migrated).
+    pub fn get_storage_version(env: &Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&MigrationDataKey::StorageVersion)
+            .unwrap_or(0)
+    }
+
+    /// Marks the store as fully migrated to `CURRENT_SCHEMA_VERSION`.
+    pub(crate) fn set_storage_version(env: &Env) {
+        env.storage()
+            .instance()
+            .set(&MigrationDataKey::StorageVersion, &CURRENT_SCHEMA_VERSION);
+    }
+
+    /// Bumps the store's schema version once every batch has run: each
+    /// entity kind needs a batch at the current target version, and the most
+    /// recent one must have found nothing left to upgrade.
+    pub fn finalize_migration(env: Env, admin: Address) -> Result<u32, ModuleError> {
+        MembershipTokenContract::require_admin(&env, &admin)?;
+        for entity in [MigrationEntity::Token, MigrationEntity::Subscription] {
+            let progress = Self::get_migration_progress(&env, entity);
+            if progress.target_version != CURRENT_SCHEMA_VERSION
+                || progress.batches == 0
+                || progress.last_batch_migrated > 0
+            {
+                return Err(UpgradeError::MigrationIncomplete.into());
+            }
+        }
+        let from = Self::get_storage_version(&env);
+        Self::set_storage_version(&env);
+        env.events().publish(
+            (symbol_short!("schema_up"),),
+            (from, CURRENT_SCHEMA_VERSION),
+        );
+        Ok(CURRENT_SCHEMA_VERSION)
+    }
+
+    pub fn get_token_schema_version(env: &Env, token_id: &BytesN<32>) -> u32 {
+        env.storage()
+            .persistent()
+            .get(&MigrationDataKey::TokenSchema(token_id.clone()))
+            .unwrap_or(0)
+    }
+
+    pub fn get_subscription_schema_version(env: &Env, subscription_id: &String) -> u32 {
+        env.storage()
+            .persistent()
+            .get(&MigrationDataKey::SubscriptionSchema(
+                subscription_id.clone(),
+            ))
+            .unwrap_or(0)
+    }
+
+    pub fn get_migration_progress(env: &Env, entity: MigrationEntity) -> MigrationProgress {
+        let Some(fields) = env
+            .storage()
+            .instance()
+            .get::<_, Map<Symbol, Val>>(&MigrationDataKey::Progress(entity.clone()))
+        else {
+            return MigrationProgress {
+                entity,
+                target_version: CURRENT_SCHEMA_VERSION,
+                migrated: 0,
+                skipped: 0,
+                batches: 0,
+                last_batch_at: 0,
+                last_batch_migrated: 0,
+            };
+        };
+        if fields.contains_key(Symbol::new(env, "last_batch_migrated")) {
+            return MigrationProgress::try_from_val(env, &fields.to_val()).unwrap();
+        }
+
+        // Progress saved before the last batch was tracked; its counts are
+        // carried over, but a fresh batch is needed before finalizing
+        let legacy = LegacyMigrationProgress::try_from_val(env, &fields.to_val()).unwrap();
+        MigrationProgress {
+            entity: legacy.entity,
+            target_version: legacy.target_version,
+            migrated: legacy.migrated,
+            skipped: legacy.skipped,
+            batches: legacy.batches,
+            last_batch_at: legacy.last_batch_at,
+            last_batch_migrated: legacy.migrated,
+        }
+    }
+
+    fn record_progress(env: &Env, entity: MigrationEntity, migrated: u32, skipped: u32) {
+        let mut progress = Self::get_migration_progress(env, entity.clone());
+        if progress.target_version != CURRENT_SCHEMA_VERSION {
+            // A new schema version restarts the counters
+            progress.target_version = CURRENT_SCHEMA_VERSION;
+            progress.migrated = 0;
+            progress.skipped = 0;
+            progress.batches = 0;
+        }
+        progress.migrated = progress.migrated.saturating_add(migrated);
+        progress.skipped = progress.skipped.saturating_add(skipped);
+        progress.batches = progress.batches.saturating_add(1);
+        progress.last_batch_at = env.ledger().timestamp();
+        progress.last_batch_migrated = migrated;
+        env.storage()
+            .instance()
+            .set(&MigrationDataKey::Progress(entity.clone()), &progress);
+
+        env.events().publish(
+            (symbol_short!("migrated"), entity),
+            (migrated, skipped, CURRENT_SCHEMA_VERSION),
+        );
+    }
+
+    // -----------------------------------------------------------------------
+    // Tokens
+    // -----------------------------------------------------------------------
+
+    /// Records that a newly written token uses the current schema.
+    pub(crate) fn mark_token_current(env: &Env, token_id: &BytesN<32>) {
+        env.storage().persistent().set(
+            &MigrationDataKey::TokenSchema(token_id.clone()),
+            &CURRENT_SCHEMA_VERSION,
+        );
+    }
+
+    /// Brings a token up to the current schema version if it is behind.
+    /// Returns `false` if the token doesn't exist or is already current.
+    pub(crate) fn migrate_token(env: &Env, token_id: &BytesN<32>) -> bool {
+        let from = Self::get_token_schema_version(env, token_id);
+        if from >= CURRENT_SCHEMA_VERSION {
+            return false;
+        }
+        let key = DataKey::Token(token_id.clone());
+        let token: MembershipToken = match env.storage().persistent().get(&key) {
+            Some(token) => token,
+            None => return false,
+        };
+
+        let token = Self::migrate_token_entry(token, from);
+        env.storage().persistent().set(&key, &token);
+        TtlModule::bump(env, TtlEntityClass::Token, &key);
+        Self::mark_token_current(env, token_id);
+        true
+    }
+
+    /// Per-version upgrade steps for a token entry.
+    fn migrate_token_entry(token: MembershipToken, _from: u32) -> MembershipToken {
+        // `MembershipToken` has kept one layout since before versioning, so
+        // every version decodes as-is; the entry is only rewritten and
+        // versioned.
+        token
+    }
+
+    /// Migrates a batch of tokens. Admin only.
+    pub fn migrate_tokens(
+        env: Env,
+        admin: Address,
+        token_ids: Vec<BytesN<32>>,
+    ) -> Result<MigrationProgress, Error> {
+        MembershipTokenContract::require_admin(&env, &admin)?;
+        BatchValidator::validate_batch_size(token_ids.len())?;
+
+        let mut migrated = 0;
+        for id in token_ids.iter() {
+            if Self::migrate_token(&env, &id) {
+                migrated += 1;
+            }
+        }
+        Self::record_progress(
+            &env,
+            MigrationEntity::Token,
+            migrated,
+            token_ids.len() - migrated,
+        );
+        Ok(Self::get_migration_progress(&env, MigrationEntity::Token))
+    }
+
+    // -----------------------------------------------------------------------
+    // Token metadata
+    // -----------------------------------------------------------------------
+
+    /// Reads a token's metadata, converting an entry still in an older
+    /// `TokenMetadata` layout on the way and writing the upgraded entry back.
+    /// Layouts from before updater tracking get the token owner as updater.
+    pub(crate) fn load_token_metadata(env: &Env, token_id: &BytesN<32>) -> Option<TokenMetadata> {
+        let key = DataKey::Metadata(token_id.clone());
+        let stored: Val = env.storage().persistent().get(&key)?;
+        let versioned = VersionedTokenMetadata::from_stored(env, &stored)?;
+        if let VersionedTokenMetadata::V3(metadata) = versioned {
+            return Some(metadata);
+        }
+
+        let token: MembershipToken = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Token(token_id.clone()))?;
+        let metadata = versioned.upgrade(env, &token.user);
+        env.storage().persistent().set(&key, &metadata);
+        Some(metadata)
+    }
+
+    // -----------------------------------------------------------------------
+    // Subscriptions
+    // -----------------------------------------------------------------------
+
+    /// Records that a newly written subscription uses the current schema.
+    pub(crate) fn mark_subscription_current(env: &Env, subscription_id: &String) {
+        env.storage().persistent().set(
+            &MigrationDataKey::SubscriptionSchema(subscription_id.clone()),
+            &CURRENT_SCHEMA_VERSION,
+        );
+    }
+
+    /// Brings a subscription up to the current schema version if it is behind.
+    /// Returns `false` if the subscription doesn't exist or is already current.
+    pub(crate) fn migrate_subscription(env: &Env, subscription_id: &String) -> bool {
+        let from = Self::get_subscription_schema_version(env, subscription_id);
+        if from >= CURRENT_SCHEMA_VERSION {
+            return false;
+        }
+        let key = SubscriptionDataKey::Subscription(subscription_id.clone());
+        let Some(fields) = env.storage().persistent().get::<_, Map<Symbol, Val>>(&key) else {
+            return false;
+        };
+        let Some(versioned) = VersionedSubscription::from_stored(env, &fields) else {
+            return false;
+        };
+
+        let subscription = Self::migrate_subscription_entry(env, subscription_id, versioned);
+        env.storage().persistent().set(&key, &subscription);
+        TtlModule::bump(env, TtlEntityClass::Subscription, &key);
+        Self::mark_subscription_current(env, subscription_id);
+        true
+    }
+
+    /// Per-version upgrade steps for a subscription entry.
+    fn migrate_subscription_entry(
+        env: &Env,
+        subscription_id: &String,
+        versioned: VersionedSubscription,
+    ) -> Subscription {
+        match versioned {
+            // Subscriptions from before locked prices renew at the tier price
+            VersionedSubscription::V0(v0) => Self::migrate_subscription_entry(
+                env,
+                subscription_id,
+                VersionedSubscription::V1(LegacySubscription {
+                    id: v0.id,
+                    user: v0.user,
+                    payment_token: v0.payment_token,
+                    amount: v0.amount,
+                    status: v0.status,
+                    created_at: v0.created_at,
+                    expires_at: v0.expires_at,
+                    tier_id: v0.tier_id,
+                    billing_cycle: v0.billing_cycle,
+                    paused_at: v0.paused_at,
+                    last_resumed_at: v0.last_resumed_at,
+                    pause_count: v0.pause_count,
+                    total_paused_duration: v0.total_paused_duration,
+                    pause_history: v0.pause_history,
+                    locked_price: 0,
+                }),
+            ),
+            // The inline pause history moves to its own entries
+            VersionedSubscription::V1(legacy) => {
+                for entry in legacy.pause_history.to_vals().iter() {
+                    if let Some(entry) = SubscriptionContract::decode_pause_entry(env, entry) {
+                        SubscriptionContract::append_pause_history(env, subscription_id, &entry);
+                    }
+                }
+                Subscription {
+                    id: legacy.id,
+                    user: legacy.user,
+                    payment_token: legacy.payment_token,
+                    amount: legacy.amount,
+                    status: legacy.status,
+                    created_at: legacy.created_at,
+                    expires_at: legacy.expires_at,
+                    tier_id: legacy.tier_id,
+                    billing_cycle: legacy.billing_cycle,
+                    paused_at: legacy.paused_at,
+                    last_resumed_at: legacy.last_resumed_at,
+                    pause_count: legacy.pause_count,
+                    total_paused_duration: legacy.total_paused_duration,
+                    locked_price: legacy.locked_price,
+                }
+            }
+            VersionedSubscription::V2(subscription) => subscription,
+        }
+    }
+
+    /// Reads a subscription, converting an entry still in an older layout
+    /// on the way and writing the upgraded entry back.
+    pub(crate) fn load_subscription(env: &Env, subscription_id: &String) -> Option<Subscription> {
+        let key = SubscriptionDataKey::Subscription(subscription_id.clone());
+        let fields: Map<Symbol, Val> = env.storage().persistent().get(&key)?;
+        let versioned = VersionedSubscription::from_stored(env, &fields)?;
+        if let VersionedSubscription::V2(subscription) = versioned {
+            return Some(subscription);
+        }
+
+        let subscription = Self::migrate_subscription_entry(env, subscription_id, versioned);
+        env.storage().persistent().set(&key, &subscription);
+        TtlModule::bump(env, TtlEntityClass::Subscription, &key);
+        Self::mark_subscription_current(env, subscription_id);
+        Some(subscription)
+    }
+
+    /// Migrates a batch of subscriptions. Admin only.
+    pub fn migrate_subscriptions(
+        env: Env,
+        admin: Address,
+        subscription_ids: Vec<String>,
+    ) -> Result<MigrationProgress, Error> {
+        MembershipTokenContract::require_admin(&env, &admin)?;
+        BatchValidator::validate_batch_size(subscription_ids.len())?;
+
+        let mut migrated = 0;
+        for id in subscription_ids.iter() {
+            if Self::migrate_subscription(&env, &id) {
+                migrated += 1;
+            }
+        }
+        Self::record_progress(
+            &env,
+            MigrationEntity::Subscription,
+            migrated,
+            subscription_ids.len() - migrated,
+        );
+        Ok(Self::get_migration_progress(
+            &env,
+            MigrationEntity::Subscription,
+        ))
+    }
+
+    // -----------------------------------------------------------------------
+    // Token upgrade snapshots
+    // -----------------------------------------------------------------------
+
     /// Capture a snapshot of the token's current state for rollback purposes.
     ///
     /// Must be called **before** mutating the token so the snapshot reflects
@@ -0,0 +1,113 @@
+use crate::entry::EntryModule;
+use crate::errors::Error;
+use crate::loyalty::LoyaltyModule;
+use crate::membership_token::{MembershipToken, MembershipTokenContract};
+use crate::staking::StakingModule;
+use crate::subscription::SubscriptionContract;
+use crate::types::{
+    EmergencyPauseState, EntryDecision, LoyaltyAccount, StakeInfo, Subscription, SubscriptionTier,
+};
+use crate::validation::BatchValidator;
+use common_types::TokenMetadata;
+use soroban_sdk::{contracttype, Address, BytesN, Env, String, Vec};
+
+/// A single read in a `multiread` batch, naming the getter it stands for.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub enum ReadRequest {
+    /// `get_token`
+    Token(BytesN<32>),
+    /// `get_token_metadata`
+    TokenMetadata(BytesN<32>),
+    /// `is_token_paused`
+    TokenPaused(BytesN<32>),
+    /// `get_subscription`
+    Subscription(String),
+    /// `get_tier`
+    Tier(String),
+    /// `get_emergency_pause_state`
+    EmergencyPause,
+    /// `get_stake_info`
+    StakeInfo(Address),
+    /// `get_loyalty_account`
+    LoyaltyAccount(Address),
+    /// `verify_entry`
+    Entry(Address),
+}
+
+/// Result of a `ReadRequest`, at the same position in the batch.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub enum ReadResponse {
+    Token(MembershipToken),
+    TokenMetadata(TokenMetadata),
+    TokenPaused(bool),
+    Subscription(Subscription),
+    Tier(SubscriptionTier),
+    EmergencyPause(EmergencyPauseState),
+    StakeInfo(StakeInfo),
+    LoyaltyAccount(LoyaltyAccount),
+    Entry(EntryDecision),
+    /// The getter returned nothing (e.g. no stake)
+    Empty,
+    /// The getter failed with this `Error` code
+    Failed(u32),
+}
+
+/// Batched read-only getters.
+///
+/// Front-ends hydrating a member dashboard otherwise make one call per
+/// token, subscription, tier and pause check. `multiread` runs up to
+/// `MAX_BATCH_SIZE` common getters in one invocation and answers each in
+/// order; a failing read is reported in place rather than failing the
+/// whole batch.
+pub struct MultireadModule;
+
+impl MultireadModule {
+    pub fn multiread(env: Env, requests: Vec<ReadRequest>) -> Result<Vec<ReadResponse>, Error> {
+        BatchValidator::validate_batch_size(requests.len())?;
+
+        let mut responses = Vec::new(&env);
+        for request in requests.iter() {
+            responses.push_back(Self::read(&env, request));
+        }
+        Ok(responses)
+    }
+
+    fn read(env: &Env, request: ReadRequest) -> ReadResponse {
+        let result = match request {
+            ReadRequest::Token(id) => {
+                MembershipTokenContract::get_token(env.clone(), id).map(ReadResponse::Token)
+            }
+            ReadRequest::TokenMetadata(id) => {
+                MembershipTokenContract::get_token_metadata(env.clone(), id)
+                    .map(ReadResponse::TokenMetadata)
+            }
+            ReadRequest::TokenPaused(id) => Ok(ReadResponse::TokenPaused(
+                MembershipTokenContract::is_token_paused(env.clone(), id),
+            )),
+            ReadRequest::Subscription(id) => {
+                SubscriptionContract::get_subscription_view(env.clone(), id)
+                    .map(ReadResponse::Subscription)
+            }
+            ReadRequest::Tier(id) => {
+                SubscriptionContract::get_tier(env.clone(), id).map(ReadResponse::Tier)
+            }
+            ReadRequest::EmergencyPause => Ok(ReadResponse::EmergencyPause(
+                MembershipTokenContract::get_emergency_pause_state(env.clone()),
+            )),
+            ReadRequest::StakeInfo(staker) => {
+                Ok(StakingModule::get_stake_info(env.clone(), staker)
+                    .map_or(ReadResponse::Empty, ReadResponse::StakeInfo))
+            }
+            ReadRequest::LoyaltyAccount(user) => Ok(ReadResponse::LoyaltyAccount(
+                LoyaltyModule::get_account(env, &user),
+            )),
+            ReadRequest::Entry(user) => Ok(ReadResponse::Entry(EntryModule::verify_entry(
+                env.clone(),
+                user,
+            ))),
+        };
+        result.unwrap_or_else(|error| ReadResponse::Failed(error as u32))
+    }
+}
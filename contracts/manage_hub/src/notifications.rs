@@ -0,0 +1,201 @@
+use crate::errors::Error;
+use crate::events::{NotificationQueued, NotificationsAcknowledged};
+use crate::subscription::SubscriptionContract;
+use crate::types::{MembershipStatus, Notification, NotificationKind, OutboxSubject};
+use soroban_sdk::{contracttype, Address, Env, String, Vec};
+
+// ---------------------------------------------------------------------------
+// Storage keys
+// ---------------------------------------------------------------------------
+
+#[contracttype]
+pub enum NotificationDataKey {
+    /// Sequence number a user's next notification will receive (persistent
+    /// storage).
+    NextNotice(Address),
+    /// Highest sequence number a user has acknowledged (persistent storage).
+    AckedNotice(Address),
+    /// A user's notification by sequence number (persistent storage).
+    Notice(Address, u64),
+    /// `paused_at` of the pause a subscription was last warned about
+    /// (persistent storage).
+    PauseWarned(String),
+}
+
+// ---------------------------------------------------------------------------
+// Constants
+// ---------------------------------------------------------------------------
+
+/// Notifications kept per user; older ones are dropped as new ones arrive.
+pub const MAX_NOTIFICATIONS_PER_USER: u64 = 50;
+
+/// Most notifications `get_notifications` returns per call.
+pub const MAX_NOTIFICATION_PAGE: u32 = 50;
+
+/// How long before a pause reaches its maximum duration the member is
+/// warned.
+pub const PAUSE_EXPIRY_NOTICE: u64 = 3 * 24 * 60 * 60;
+
+// ---------------------------------------------------------------------------
+// Module
+// ---------------------------------------------------------------------------
+
+/// Per-user queue of membership status notifications.
+///
+/// Significant transitions (grace period entered, auto-renewal failed,
+/// pause about to expire, tier change processed) append a numbered entry to
+/// the member's queue. Wallet apps read the unacknowledged entries and
+/// acknowledge them by sequence number. Only the latest
+/// `MAX_NOTIFICATIONS_PER_USER` entries are kept.
+pub struct NotificationModule;
+
+impl NotificationModule {
+    /// Queues a notification for `user`, dropping the oldest one once the
+    /// queue is full.
+    pub(crate) fn notify(
+        env: &Env,
+        user: &Address,
+        kind: NotificationKind,
+        subject: OutboxSubject,
+        due_at: u64,
+    ) {
+        let storage = env.storage().persistent();
+        let seq = Self::next_seq(env, user);
+        let notification = Notification {
+            seq,
+            kind: kind.clone(),
+            subject,
+            created_at: env.ledger().timestamp(),
+            due_at,
+        };
+        storage.set(
+            &NotificationDataKey::Notice(user.clone(), seq),
+            &notification,
+        );
+        if seq > MAX_NOTIFICATIONS_PER_USER {
+            storage.remove(&NotificationDataKey::Notice(
+                user.clone(),
+                seq - MAX_NOTIFICATIONS_PER_USER,
+            ));
+        }
+        storage.set(&NotificationDataKey::NextNotice(user.clone()), &(seq + 1));
+
+        NotificationQueued {
+            user: user.clone(),
+            seq,
+            kind,
+        }
+        .publish(env);
+    }
+
+    /// Returns up to `limit` of the user's unacknowledged notifications,
+    /// skipping the first `offset`, oldest first.
+    pub fn get_notifications(
+        env: Env,
+        user: Address,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<Notification> {
+        let next = Self::next_seq(&env, &user);
+        let first = Self::first_unacked(&env, &user, next);
+        let from = first.saturating_add(offset as u64).min(next);
+        let to = from
+            .saturating_add(limit.min(MAX_NOTIFICATION_PAGE) as u64)
+            .min(next);
+
+        let mut notifications = Vec::new(&env);
+        for seq in from..to {
+            if let Some(notification) = env
+                .storage()
+                .persistent()
+                .get(&NotificationDataKey::Notice(user.clone(), seq))
+            {
+                notifications.push_back(notification);
+            }
+        }
+        notifications
+    }
+
+    /// Acknowledges every notification up to and including `up_to_seq` and
+    /// deletes them. Returns the number acknowledged.
+    pub fn ack_notifications(env: Env, user: Address, up_to_seq: u64) -> Result<u32, Error> {
+        user.require_auth();
+
+        let next = Self::next_seq(&env, &user);
+        let first = Self::first_unacked(&env, &user, next);
+        let last = up_to_seq.min(next - 1);
+        if last < first {
+            return Ok(0);
+        }
+        for seq in first..=last {
+            env.storage()
+                .persistent()
+                .remove(&NotificationDataKey::Notice(user.clone(), seq));
+        }
+        env.storage()
+            .persistent()
+            .set(&NotificationDataKey::AckedNotice(user.clone()), &last);
+
+        NotificationsAcknowledged {
+            user,
+            up_to_seq: last,
+        }
+        .publish(&env);
+        Ok((last - first + 1) as u32)
+    }
+
+    /// Number of unacknowledged notifications still held for `user`.
+    pub fn get_unread_count(env: &Env, user: &Address) -> u32 {
+        let next = Self::next_seq(env, user);
+        (next - Self::first_unacked(env, user, next)) as u32
+    }
+
+    /// Warns the subscriber that their pause reaches the maximum pause
+    /// duration within `PAUSE_EXPIRY_NOTICE`. Callable by anyone, e.g. a
+    /// keeper; each pause is warned about once. Returns `false` if this
+    /// pause was already warned about.
+    pub fn notify_pause_expiring(env: Env, subscription_id: String) -> Result<bool, Error> {
+        let subscription =
+            SubscriptionContract::get_subscription(env.clone(), subscription_id.clone())?;
+        let paused_at = match (subscription.status, subscription.paused_at) {
+            (MembershipStatus::Paused, Some(paused_at)) => paused_at,
+            _ => return Err(Error::SubscriptionNotPaused),
+        };
+        let ends_at = paused_at
+            .saturating_add(SubscriptionContract::get_pause_config(env.clone()).max_pause_duration);
+        if env.ledger().timestamp().saturating_add(PAUSE_EXPIRY_NOTICE) < ends_at {
+            return Err(Error::PauseTooEarly);
+        }
+
+        let warned_key = NotificationDataKey::PauseWarned(subscription_id.clone());
+        if env.storage().persistent().get::<_, u64>(&warned_key) == Some(paused_at) {
+            return Ok(false);
+        }
+        env.storage().persistent().set(&warned_key, &paused_at);
+        Self::notify(
+            &env,
+            &subscription.user,
+            NotificationKind::PauseExpiring,
+            OutboxSubject::Subscription(subscription_id),
+            ends_at,
+        );
+        Ok(true)
+    }
+
+    fn next_seq(env: &Env, user: &Address) -> u64 {
+        env.storage()
+            .persistent()
+            .get(&NotificationDataKey::NextNotice(user.clone()))
+            .unwrap_or(1)
+    }
+
+    /// Oldest sequence number that is neither acknowledged nor dropped.
+    fn first_unacked(env: &Env, user: &Address, next: u64) -> u64 {
+        let acked: u64 = env
+            .storage()
+            .persistent()
+            .get(&NotificationDataKey::AckedNotice(user.clone()))
+            .unwrap_or(0);
+        (acked + 1).max(next.saturating_sub(MAX_NOTIFICATIONS_PER_USER))
+    }
+}
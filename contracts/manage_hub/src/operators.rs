@@ -0,0 +1,143 @@
+// Allow deprecated events API until migration to #[contractevent] macro
+#![allow(deprecated)]
+
+use crate::attendance_log::AttendanceLogModule;
+use crate::errors::{Error, ModuleError};
+use crate::initialization::InitializationModule;
+use crate::membership_token::{MembershipToken, MembershipTokenContract};
+use crate::types::{AttendanceAction, OperatorActivity};
+use soroban_sdk::{contracttype, symbol_short, Address, BytesN, Env, Map, String};
+
+// ---------------------------------------------------------------------------
+// Storage keys
+// ---------------------------------------------------------------------------
+
+#[contracttype]
+pub enum OperatorDataKey {
+    /// Marks an address as an active operator (persistent storage).
+    Operator(Address),
+    /// Activity counters for an operator, kept after removal (persistent storage).
+    Activity(Address),
+}
+
+// ---------------------------------------------------------------------------
+// Module
+// ---------------------------------------------------------------------------
+
+/// Delegated operator role for front-desk devices.
+///
+/// Operators can log attendance on behalf of members and trigger grace-period
+/// checks, and nothing else. They are managed by the admin or mirrored from
+/// the access_control contract registered at initialization.
+pub struct OperatorModule;
+
+impl OperatorModule {
+    /// Allows the admin or the registered access_control contract.
+    fn require_manager(env: &Env, caller: &Address) -> Result<(), Error> {
+        if InitializationModule::get_access_control(env).as_ref() == Some(caller) {
+            caller.require_auth();
+            return Ok(());
+        }
+        MembershipTokenContract::require_admin(env, caller)
+    }
+
+    fn require_operator(env: &Env, operator: &Address) -> Result<(), Error> {
+        if !Self::is_operator(env, operator) {
+            return Err(Error::Unauthorized);
+        }
+        operator.require_auth();
+        Ok(())
+    }
+
+    pub fn add_operator(env: Env, caller: Address, operator: Address) -> Result<(), Error> {
+        Self::require_manager(&env, &caller)?;
+
+        env.storage()
+            .persistent()
+            .set(&OperatorDataKey::Operator(operator.clone()), &true);
+
+        let activity_key = OperatorDataKey::Activity(operator.clone());
+        if !env.storage().persistent().has(&activity_key) {
+            let activity = OperatorActivity {
+                attendance_logged: 0,
+                grace_checks: 0,
+                added_at: env.ledger().timestamp(),
+                last_active_at: 0,
+            };
+            env.storage().persistent().set(&activity_key, &activity);
+        }
+
+        env.events()
+            .publish((symbol_short!("op_add"), operator), caller);
+        Ok(())
+    }
+
+    pub fn remove_operator(env: Env, caller: Address, operator: Address) -> Result<(), Error> {
+        Self::require_manager(&env, &caller)?;
+
+        env.storage()
+            .persistent()
+            .remove(&OperatorDataKey::Operator(operator.clone()));
+
+        env.events()
+            .publish((symbol_short!("op_rm"), operator), caller);
+        Ok(())
+    }
+
+    pub fn is_operator(env: &Env, operator: &Address) -> bool {
+        env.storage()
+            .persistent()
+            .get(&OperatorDataKey::Operator(operator.clone()))
+            .unwrap_or(false)
+    }
+
+    pub fn get_operator_activity(env: &Env, operator: &Address) -> Option<OperatorActivity> {
+        env.storage()
+            .persistent()
+            .get(&OperatorDataKey::Activity(operator.clone()))
+    }
+
+    /// Logs attendance for `user_id` from an operator device.
+    pub fn log_attendance(
+        env: Env,
+        operator: Address,
+        id: BytesN<32>,
+        user_id: Address,
+        action: AttendanceAction,
+        details: Map<String, String>,
+    ) -> Result<(), ModuleError> {
+        Self::require_operator(&env, &operator)?;
+        AttendanceLogModule::record_attendance(&env, id, user_id, action, details, true)?;
+        Self::record_activity(&env, &operator, |activity| activity.attendance_logged += 1);
+        Ok(())
+    }
+
+    /// Runs the grace-period check for a token from an operator device.
+    pub fn check_and_apply_grace_period(
+        env: Env,
+        operator: Address,
+        id: BytesN<32>,
+    ) -> Result<MembershipToken, Error> {
+        Self::require_operator(&env, &operator)?;
+        let token = MembershipTokenContract::check_and_apply_grace_period(env.clone(), id)?;
+        Self::record_activity(&env, &operator, |activity| activity.grace_checks += 1);
+        Ok(token)
+    }
+
+    fn record_activity(env: &Env, operator: &Address, update: impl FnOnce(&mut OperatorActivity)) {
+        let key = OperatorDataKey::Activity(operator.clone());
+        let mut activity: OperatorActivity =
+            env.storage()
+                .persistent()
+                .get(&key)
+                .unwrap_or(OperatorActivity {
+                    attendance_logged: 0,
+                    grace_checks: 0,
+                    added_at: 0,
+                    last_active_at: 0,
+                });
+        update(&mut activity);
+        activity.last_active_at = env.ledger().timestamp();
+        env.storage().persistent().set(&key, &activity);
+    }
+}
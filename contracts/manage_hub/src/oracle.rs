@@ -0,0 +1,193 @@
+// Allow deprecated events API until migration to #[contractevent] macro
+#![allow(deprecated)]
+
+use crate::errors::{Error, ModuleError};
+use crate::membership_token::MembershipTokenContract;
+use crate::oracle_errors::OracleError;
+use crate::payment_tokens::{PaymentTokenModule, RATE_SCALE};
+use crate::subscription::SubscriptionContract;
+use crate::ttl::{TtlEntityClass, TtlModule};
+use crate::types::{OracleAsset, OracleConfig, OraclePriceData};
+use soroban_sdk::{contracttype, symbol_short, Address, Env, IntoVal, String, Symbol, Vec};
+
+// ---------------------------------------------------------------------------
+// Storage keys
+// ---------------------------------------------------------------------------
+
+#[contracttype]
+pub enum OracleDataKey {
+    /// Global oracle configuration (instance storage).
+    Config,
+    /// Whether a tier's prices are denominated in USD (persistent storage).
+    UsdTier(String),
+}
+
+// ---------------------------------------------------------------------------
+// Module
+// ---------------------------------------------------------------------------
+
+pub struct OracleModule;
+
+impl OracleModule {
+    // -----------------------------------------------------------------------
+    // Admin – configuration
+    // -----------------------------------------------------------------------
+
+    /// Register or update the price oracle. Admin only.
+    pub fn set_oracle_config(env: Env, admin: Address, config: OracleConfig) -> Result<(), Error> {
+        MembershipTokenContract::require_admin(&env, &admin)?;
+
+        if config.max_staleness == 0 || config.max_deviation_bps > 10_000 {
+            return Err(Error::InvalidPaymentAmount);
+        }
+
+        env.storage()
+            .instance()
+            .set(&OracleDataKey::Config, &config);
+
+        env.events().publish(
+            (symbol_short!("orcl_set"), admin),
+            (
+                config.oracle,
+                config.max_staleness,
+                config.max_deviation_bps,
+            ),
+        );
+
+        Ok(())
+    }
+
+    /// Mark a tier's prices as USD-denominated (or back to USDC). Admin only.
+    pub fn set_tier_usd_pricing(
+        env: Env,
+        admin: Address,
+        tier_id: String,
+        usd_denominated: bool,
+    ) -> Result<(), Error> {
+        MembershipTokenContract::require_admin(&env, &admin)?;
+        SubscriptionContract::get_tier(env.clone(), tier_id.clone())?;
+
+        let key = OracleDataKey::UsdTier(tier_id);
+        if usd_denominated {
+            env.storage().persistent().set(&key, &true);
+            TtlModule::bump(&env, TtlEntityClass::Tier, &key);
+        } else {
+            env.storage().persistent().remove(&key);
+        }
+        Ok(())
+    }
+
+    // -----------------------------------------------------------------------
+    // Queries
+    // -----------------------------------------------------------------------
+
+    pub fn get_oracle_config(env: &Env) -> Option<OracleConfig> {
+        env.storage().instance().get(&OracleDataKey::Config)
+    }
+
+    pub fn is_usd_priced(env: &Env, tier_id: &String) -> bool {
+        env.storage()
+            .persistent()
+            .get(&OracleDataKey::UsdTier(tier_id.clone()))
+            .unwrap_or(false)
+    }
+
+    // -----------------------------------------------------------------------
+    // Conversion
+    // -----------------------------------------------------------------------
+
+    /// Convert a tier price into units of `payment_token`, going through the
+    /// oracle when the tier is USD-denominated.
+    pub(crate) fn convert_tier_price(
+        env: &Env,
+        payment_token: &Address,
+        tier_id: &String,
+        price: i128,
+    ) -> Result<i128, ModuleError> {
+        if !Self::is_usd_priced(env, tier_id) {
+            return Ok(PaymentTokenModule::convert_from_base(
+                env,
+                payment_token,
+                price,
+            )?);
+        }
+        let rate = Self::usd_rate(env, payment_token)?;
+        price
+            .checked_mul(rate)
+            .map(|v| v / RATE_SCALE)
+            .ok_or(Error::InvalidPaymentAmount.into())
+    }
+
+    /// Token units per USD unit (scaled by `RATE_SCALE`) for `payment_token`.
+    ///
+    /// USDC is treated as 1:1 with USD. Other assets are priced by the oracle,
+    /// checked for staleness and for deviation from their fixed registry rate,
+    /// which also serves as the fallback when the feed is stale or unavailable.
+    fn usd_rate(env: &Env, payment_token: &Address) -> Result<i128, ModuleError> {
+        let fixed_rate = PaymentTokenModule::rate_for(env, payment_token)?;
+        if payment_token == &SubscriptionContract::get_usdc_contract_address(env)? {
+            return Ok(fixed_rate);
+        }
+
+        let config = Self::get_oracle_config(env).ok_or(OracleError::OracleNotConfigured)?;
+        let feed = PaymentTokenModule::get_payment_token(env, payment_token)
+            .and_then(|entry| entry.oracle)
+            .unwrap_or(config.oracle.clone());
+
+        let fresh = Self::fetch_price(env, &feed, payment_token).filter(|(data, _)| {
+            env.ledger().timestamp().saturating_sub(data.timestamp) <= config.max_staleness
+        });
+
+        let (data, decimals) = match fresh {
+            Some(fresh) => fresh,
+            None if config.fallback_enabled => return Ok(fixed_rate),
+            None => return Err(OracleError::OraclePriceStale.into()),
+        };
+        if data.price <= 0 {
+            return Err(OracleError::InvalidOraclePrice.into());
+        }
+
+        let oracle_rate = 10i128
+            .checked_pow(decimals)
+            .and_then(|scale| RATE_SCALE.checked_mul(scale))
+            .map(|v| v / data.price)
+            .ok_or(OracleError::InvalidOraclePrice)?;
+
+        let deviation_bps = (oracle_rate - fixed_rate)
+            .abs()
+            .checked_mul(10_000)
+            .map(|v| v / fixed_rate)
+            .ok_or(OracleError::OraclePriceDeviation)?;
+        if deviation_bps > config.max_deviation_bps as i128 {
+            return Err(OracleError::OraclePriceDeviation.into());
+        }
+
+        Ok(oracle_rate)
+    }
+
+    /// Query `lastprice` and `decimals` from a SEP-40 feed. Any failed call is
+    /// treated as "no price".
+    fn fetch_price(env: &Env, feed: &Address, asset: &Address) -> Option<(OraclePriceData, u32)> {
+        let args: Vec<soroban_sdk::Val> =
+            Vec::from_array(env, [OracleAsset::Stellar(asset.clone()).into_val(env)]);
+        let data = match env.try_invoke_contract::<Option<OraclePriceData>, soroban_sdk::Error>(
+            feed,
+            &Symbol::new(env, "lastprice"),
+            args,
+        ) {
+            Ok(Ok(Some(data))) => data,
+            _ => return None,
+        };
+
+        let decimals = match env.try_invoke_contract::<u32, soroban_sdk::Error>(
+            feed,
+            &Symbol::new(env, "decimals"),
+            Vec::new(env),
+        ) {
+            Ok(Ok(decimals)) => decimals,
+            _ => return None,
+        };
+
+        Some((data, decimals))
+    }
+}
@@ -0,0 +1,20 @@
+//! Price-oracle error types for the ManageHub contract.
+//!
+//! `OracleError` owns the code range 1250–1299 (see [`crate::errors`]).
+
+use soroban_sdk::contracterror;
+
+/// Oracle-specific errors.
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum OracleError {
+    /// No oracle has been registered for USD-denominated pricing.
+    OracleNotConfigured = 1250,
+    /// The oracle price is older than the configured staleness window and no
+    /// fallback rate is allowed.
+    OraclePriceStale = 1251,
+    /// The oracle price deviates from the reference rate by more than allowed.
+    OraclePriceDeviation = 1252,
+    /// The oracle returned a non-positive price.
+    InvalidOraclePrice = 1253,
+}
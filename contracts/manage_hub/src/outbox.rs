@@ -0,0 +1,98 @@
+use crate::types::{OutboxRecord, OutboxSubject};
+use soroban_sdk::{contracttype, Env, Symbol, Vec};
+
+// ---------------------------------------------------------------------------
+// Storage keys
+// ---------------------------------------------------------------------------
+
+#[contracttype]
+pub enum OutboxDataKey {
+    /// Sequence number the next record will receive (instance storage).
+    NextSeq,
+    /// Record held in a ring-buffer slot, `seq % OUTBOX_CAPACITY`
+    /// (persistent storage).
+    Slot(u64),
+}
+
+// ---------------------------------------------------------------------------
+// Constants
+// ---------------------------------------------------------------------------
+
+/// Records kept before the oldest slot is overwritten.
+const OUTBOX_CAPACITY: u64 = 1_000;
+
+/// Most records `get_events_since` returns per call.
+const MAX_OUTBOX_PAGE: u32 = 100;
+
+/// Keep outbox slots for ~30 days.
+const OUTBOX_TTL_LEDGERS: u32 = 518_400;
+
+// ---------------------------------------------------------------------------
+// Module
+// ---------------------------------------------------------------------------
+
+/// On-chain outbox of significant state changes. Each record gets a
+/// sequence number one above the previous, so indexers that miss ledger
+/// events can page through the outbox and spot gaps.
+pub struct OutboxModule;
+
+impl OutboxModule {
+    /// Appends a record for `kind` on `subject`, overwriting the oldest slot
+    /// once the buffer is full.
+    pub(crate) fn append(env: &Env, kind: Symbol, subject: OutboxSubject) {
+        let seq = Self::next_seq(env);
+        let record = OutboxRecord {
+            seq,
+            kind,
+            subject,
+            timestamp: env.ledger().timestamp(),
+            ledger: env.ledger().sequence(),
+        };
+
+        let key = OutboxDataKey::Slot(seq % OUTBOX_CAPACITY);
+        env.storage().persistent().set(&key, &record);
+        env.storage()
+            .persistent()
+            .extend_ttl(&key, OUTBOX_TTL_LEDGERS, OUTBOX_TTL_LEDGERS);
+        env.storage()
+            .instance()
+            .set(&OutboxDataKey::NextSeq, &(seq + 1));
+    }
+
+    /// Returns up to `limit` records with a sequence number above `seq`, in
+    /// order. Records already overwritten are skipped, so a first `seq`
+    /// more than one above the caller's cursor means records were lost.
+    pub fn get_events_since(env: Env, seq: u64, limit: u32) -> Vec<OutboxRecord> {
+        let next = Self::next_seq(&env);
+        let oldest = next.saturating_sub(OUTBOX_CAPACITY).max(1);
+        let mut cursor = seq.saturating_add(1).max(oldest);
+
+        let mut records = Vec::new(&env);
+        let limit = limit.min(MAX_OUTBOX_PAGE);
+        while cursor < next && records.len() < limit {
+            if let Some(record) = env
+                .storage()
+                .persistent()
+                .get::<_, OutboxRecord>(&OutboxDataKey::Slot(cursor % OUTBOX_CAPACITY))
+            {
+                if record.seq == cursor {
+                    records.push_back(record);
+                }
+            }
+            cursor += 1;
+        }
+        records
+    }
+
+    /// Sequence number of the most recent record, or `0` if none exist.
+    pub fn get_latest_seq(env: Env) -> u64 {
+        Self::next_seq(&env) - 1
+    }
+
+    fn next_seq(env: &Env) -> u64 {
+        env.storage()
+            .instance()
+            .get(&OutboxDataKey::NextSeq)
+            .unwrap_or(1)
+    }
+}
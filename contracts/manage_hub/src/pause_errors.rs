@@ -1,32 +1,17 @@
 //! Pause-related error types for the ManageHub contract.
 //!
-//! A dedicated `PauseError` enum (separate from the main `Error` enum) is used
-//! because `#[contracterror]` enforces a hard 50-variant XDR limit and the main
-//! `Error` enum is already at that limit.
-//!
-//! The [`From`] impl bridges `PauseError` into `Error` so that `?` propagation
-//! works transparently in functions that return `Result<_, Error>`.
+//! `PauseError` owns the code range 200–249 (see [`crate::errors`]).
 
-use crate::errors::Error;
+use soroban_sdk::contracterror;
 
 /// Pause-specific errors returned by [`crate::guards::PauseGuard`].
-#[derive(Debug)]
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
 pub enum PauseError {
     /// The contract is currently paused; all token operations are blocked.
-    ContractPaused,
+    ContractPaused = 200,
     /// This token's operations are currently paused independently of the global pause.
-    TokenOpsPaused,
+    TokenOpsPaused = 201,
     /// The mandatory time-lock window has not yet elapsed; manual unpause is not allowed.
-    TimeLockActive,
-}
-
-/// Bridges `PauseError` into the main [`Error`] enum so that `?` works in
-/// functions returning `Result<_, Error>`.
-impl From<PauseError> for Error {
-    fn from(e: PauseError) -> Self {
-        match e {
-            PauseError::ContractPaused | PauseError::TokenOpsPaused => Error::SubscriptionPaused,
-            PauseError::TimeLockActive => Error::PauseTooEarly,
-        }
-    }
+    TimeLockActive = 202,
 }
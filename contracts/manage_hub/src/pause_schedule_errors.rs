@@ -0,0 +1,24 @@
+//! Scheduled-pause error types for the ManageHub contract.
+//!
+//! `PauseScheduleError` owns the code range 1300–1349 (see [`crate::errors`]).
+
+use soroban_sdk::contracterror;
+
+/// Scheduled-pause errors.
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum PauseScheduleError {
+    /// The start time is not in the future or falls after the subscription
+    /// expires.
+    InvalidStartTime = 1300,
+    /// The duration is zero or longer than the configured maximum pause.
+    InvalidDuration = 1301,
+    /// The subscription already has a pause scheduled.
+    AlreadyScheduled = 1302,
+    /// The contract-wide limit on scheduled pauses has been reached.
+    TooManyScheduled = 1303,
+    /// The subscription has no scheduled pause.
+    NotScheduled = 1304,
+    /// The scheduled pause has already started.
+    AlreadyStarted = 1305,
+}
@@ -0,0 +1,178 @@
+// Allow deprecated events API until migration to #[contractevent] macro
+#![allow(deprecated)]
+
+use crate::errors::Error;
+use crate::membership_token::MembershipTokenContract;
+use crate::subscription::SubscriptionContract;
+use crate::ttl::{TtlEntityClass, TtlModule};
+use crate::types::AcceptedPaymentToken;
+use soroban_sdk::{contracttype, symbol_short, Address, Env, Vec};
+
+// ---------------------------------------------------------------------------
+// Storage keys
+// ---------------------------------------------------------------------------
+
+#[contracttype]
+pub enum PaymentTokenDataKey {
+    /// Registry entry for a whitelisted asset (persistent storage).
+    Token(Address),
+    /// All whitelisted asset addresses (instance storage).
+    TokenList,
+}
+
+// ---------------------------------------------------------------------------
+// Constants
+// ---------------------------------------------------------------------------
+
+/// Fixed-point scale for conversion rates (7 decimals, matching Stellar assets).
+pub const RATE_SCALE: i128 = 10_000_000;
+
+// ---------------------------------------------------------------------------
+// Module
+// ---------------------------------------------------------------------------
+
+pub struct PaymentTokenModule;
+
+impl PaymentTokenModule {
+    // -----------------------------------------------------------------------
+    // Admin – registry
+    // -----------------------------------------------------------------------
+
+    /// Whitelist `token` (or update its rate/oracle). Admin only.
+    pub fn add_payment_token(
+        env: Env,
+        admin: Address,
+        token: Address,
+        rate: i128,
+        oracle: Option<Address>,
+    ) -> Result<(), Error> {
+        MembershipTokenContract::require_admin(&env, &admin)?;
+
+        if rate <= 0 {
+            return Err(Error::InvalidPaymentAmount);
+        }
+        // USDC is the base currency and always accepted at 1:1.
+        if token == SubscriptionContract::get_usdc_contract_address(&env)? {
+            return Err(Error::InvalidPaymentToken);
+        }
+
+        let key = PaymentTokenDataKey::Token(token.clone());
+        if !env.storage().persistent().has(&key) {
+            let mut list = Self::token_list(&env);
+            list.push_back(token.clone());
+            env.storage()
+                .instance()
+                .set(&PaymentTokenDataKey::TokenList, &list);
+        }
+
+        let entry = AcceptedPaymentToken {
+            token: token.clone(),
+            rate,
+            oracle,
+            updated_at: env.ledger().timestamp(),
+        };
+        env.storage().persistent().set(&key, &entry);
+        TtlModule::bump(&env, TtlEntityClass::Config, &key);
+
+        env.events()
+            .publish((symbol_short!("pay_add"), token), (rate, entry.oracle));
+
+        Ok(())
+    }
+
+    /// Remove `token` from the whitelist. Admin only.
+    pub fn remove_payment_token(env: Env, admin: Address, token: Address) -> Result<(), Error> {
+        MembershipTokenContract::require_admin(&env, &admin)?;
+
+        let key = PaymentTokenDataKey::Token(token.clone());
+        if !env.storage().persistent().has(&key) {
+            return Err(Error::InvalidPaymentToken);
+        }
+        env.storage().persistent().remove(&key);
+
+        let mut list = Self::token_list(&env);
+        if let Some(index) = list.first_index_of(&token) {
+            list.remove(index);
+        }
+        env.storage()
+            .instance()
+            .set(&PaymentTokenDataKey::TokenList, &list);
+
+        env.events()
+            .publish((symbol_short!("pay_rm"), token), env.ledger().timestamp());
+
+        Ok(())
+    }
+
+    // -----------------------------------------------------------------------
+    // Queries
+    // -----------------------------------------------------------------------
+
+    /// All accepted assets, starting with USDC at a 1:1 rate.
+    pub fn get_accepted_payment_tokens(env: Env) -> Vec<AcceptedPaymentToken> {
+        let mut tokens = Vec::new(&env);
+        if let Ok(usdc) = SubscriptionContract::get_usdc_contract_address(&env) {
+            tokens.push_back(AcceptedPaymentToken {
+                token: usdc,
+                rate: RATE_SCALE,
+                oracle: None,
+                updated_at: 0,
+            });
+        }
+        for token in Self::token_list(&env).iter() {
+            if let Some(entry) = Self::get_payment_token(&env, &token) {
+                tokens.push_back(entry);
+            }
+        }
+        tokens
+    }
+
+    pub fn get_payment_token(env: &Env, token: &Address) -> Option<AcceptedPaymentToken> {
+        env.storage()
+            .persistent()
+            .get(&PaymentTokenDataKey::Token(token.clone()))
+    }
+
+    // -----------------------------------------------------------------------
+    // Conversion helpers
+    // -----------------------------------------------------------------------
+
+    /// Rate for `token`, or `InvalidPaymentToken` if it is not accepted.
+    pub(crate) fn rate_for(env: &Env, token: &Address) -> Result<i128, Error> {
+        if token == &SubscriptionContract::get_usdc_contract_address(env)? {
+            return Ok(RATE_SCALE);
+        }
+        Self::get_payment_token(env, token)
+            .map(|entry| entry.rate)
+            .ok_or(Error::InvalidPaymentToken)
+    }
+
+    /// Convert a USDC-denominated amount into units of `token`.
+    pub(crate) fn convert_from_base(
+        env: &Env,
+        token: &Address,
+        base_amount: i128,
+    ) -> Result<i128, Error> {
+        let rate = Self::rate_for(env, token)?;
+        base_amount
+            .checked_mul(rate)
+            .map(|v| v / RATE_SCALE)
+            .ok_or(Error::InvalidPaymentAmount)
+    }
+
+    /// Convert an amount of `token` back into USDC units.
+    pub(crate) fn convert_to_base(env: &Env, token: &Address, amount: i128) -> Result<i128, Error> {
+        let rate = Self::rate_for(env, token)?;
+        amount
+            .checked_mul(RATE_SCALE)
+            .map(|v| v / rate)
+            .ok_or(Error::InvalidPaymentAmount)
+    }
+
+    fn token_list(env: &Env) -> Vec<Address> {
+        env.storage()
+            .instance()
+            .get(&PaymentTokenDataKey::TokenList)
+            .unwrap_or_else(|| Vec::new(env))
+    }
+}
@@ -0,0 +1,37 @@
+//! Resource reservation error types for the ManageHub contract.
+//!
+//! `ResourceError` owns the code range 1350–1399 (see [`crate::errors`]).
+
+use soroban_sdk::contracterror;
+
+/// Reservation-specific errors.
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum ResourceError {
+    /// No resource exists with the given id.
+    ResourceNotFound = 1350,
+    /// A resource with the given id already exists.
+    ResourceAlreadyExists = 1351,
+    /// The resource's deposit is negative.
+    InvalidDeposit = 1352,
+    /// The resource has been deactivated.
+    ResourceInactive = 1353,
+    /// The requested time range is in the past, empty or too long.
+    InvalidTimeRange = 1354,
+    /// The time range overlaps another reservation of the resource.
+    TimeConflict = 1355,
+    /// The resource has too many open reservations to take another.
+    TooManyReservations = 1356,
+    /// The subscription holds as many open reservations as its tier allows.
+    LimitReached = 1357,
+    /// No reservation exists with the given id.
+    ReservationNotFound = 1358,
+    /// The reservation was already cancelled or settled, or checked in.
+    ReservationNotActive = 1359,
+    /// The reservation has started and can no longer be cancelled.
+    ReservationStarted = 1360,
+    /// Check-in is only open around the reserved time range.
+    CheckInClosed = 1361,
+    /// Reservations can only be settled once their time range has ended.
+    ReservationNotEnded = 1362,
+}
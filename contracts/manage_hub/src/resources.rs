@@ -0,0 +1,466 @@
+use crate::errors::{Error, ModuleError};
+use crate::events::{
+    ReservationCancelled, ReservationCheckedIn, ReservationLimitSet, ReservationSettled,
+    ResourceActiveSet, ResourceRegistered, ResourceReserved,
+};
+use crate::membership_token::MembershipTokenContract;
+use crate::resource_errors::ResourceError;
+use crate::subscription::SubscriptionContract;
+use crate::treasury::TreasuryModule;
+use crate::types::{
+    MembershipStatus, RegisterResourceParams, Reservation, ReservationStatus, Resource,
+    RevenueSource, Subscription,
+};
+use soroban_sdk::{contracttype, token, Address, Env, String, Vec};
+
+// ---------------------------------------------------------------------------
+// Storage keys
+// ---------------------------------------------------------------------------
+
+#[contracttype]
+pub enum ResourceDataKey {
+    /// Reservable resource (persistent storage).
+    Resource(String),
+    /// IDs of a resource's reservations that are not yet cancelled or
+    /// settled (persistent storage).
+    OpenReservations(String),
+    /// ID the next reservation receives (instance storage).
+    NextReservationId,
+    /// Reservation by ID (persistent storage).
+    Reservation(u64),
+    /// Open reservations a tier allows per subscription (persistent storage).
+    ReservationLimit(String),
+    /// Open reservations held by a subscription (persistent storage).
+    ActiveReservations(String),
+    /// USDC deposits currently held for reservations (instance storage).
+    EscrowedDeposits,
+}
+
+// ---------------------------------------------------------------------------
+// Constants
+// ---------------------------------------------------------------------------
+
+/// Longest time range a single reservation may cover.
+pub const MAX_RESERVATION_DURATION: u64 = 7 * 24 * 60 * 60;
+
+/// Upper bound on open reservations per resource, keeping conflict checks
+/// bounded.
+pub const MAX_OPEN_RESERVATIONS: u32 = 100;
+
+/// Check-in opens this long before a reservation starts.
+pub const RESERVATION_CHECK_IN_WINDOW: u64 = 15 * 60;
+
+// ---------------------------------------------------------------------------
+// Module
+// ---------------------------------------------------------------------------
+
+/// Meeting room, locker and equipment reservations.
+///
+/// The admin registers resources, each with an optional refundable USDC
+/// deposit. Members reserve a resource for a time range that must not
+/// overlap its other open reservations, up to the number of open
+/// reservations their tier allows. The deposit is held by the contract
+/// until the reservation is cancelled or settled: once the range has ended,
+/// a checked-in reservation gets its deposit back, while a no-show either
+/// gets it back or forfeits it to the treasury, as configured on the
+/// resource.
+pub struct ResourceModule;
+
+impl ResourceModule {
+    pub fn register_resource(
+        env: Env,
+        admin: Address,
+        params: RegisterResourceParams,
+    ) -> Result<Resource, ModuleError> {
+        MembershipTokenContract::require_admin(&env, &admin)?;
+
+        if params.deposit < 0 {
+            return Err(ResourceError::InvalidDeposit.into());
+        }
+        let key = ResourceDataKey::Resource(params.id.clone());
+        if env.storage().persistent().has(&key) {
+            return Err(ResourceError::ResourceAlreadyExists.into());
+        }
+
+        let resource = Resource {
+            id: params.id.clone(),
+            name: params.name,
+            kind: params.kind,
+            deposit: params.deposit,
+            forfeit_on_no_show: params.forfeit_on_no_show,
+            active: true,
+            created_at: env.ledger().timestamp(),
+        };
+        env.storage().persistent().set(&key, &resource);
+
+        ResourceRegistered {
+            resource_id: params.id,
+            kind: resource.kind.clone(),
+            deposit: resource.deposit,
+        }
+        .publish(&env);
+        Ok(resource)
+    }
+
+    /// Activates or deactivates a resource. Existing reservations are kept.
+    pub fn set_resource_active(
+        env: Env,
+        admin: Address,
+        resource_id: String,
+        active: bool,
+    ) -> Result<(), ModuleError> {
+        MembershipTokenContract::require_admin(&env, &admin)?;
+        let mut resource = Self::get_resource(&env, &resource_id)?;
+        resource.active = active;
+        env.storage()
+            .persistent()
+            .set(&ResourceDataKey::Resource(resource_id.clone()), &resource);
+
+        ResourceActiveSet {
+            resource_id,
+            active,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    pub fn get_resource(env: &Env, resource_id: &String) -> Result<Resource, ModuleError> {
+        env.storage()
+            .persistent()
+            .get(&ResourceDataKey::Resource(resource_id.clone()))
+            .ok_or(ResourceError::ResourceNotFound.into())
+    }
+
+    pub fn set_tier_limit(
+        env: Env,
+        admin: Address,
+        tier_id: String,
+        max_open: u32,
+    ) -> Result<(), Error> {
+        MembershipTokenContract::require_admin(&env, &admin)?;
+        SubscriptionContract::get_tier(env.clone(), tier_id.clone())?;
+        env.storage().persistent().set(
+            &ResourceDataKey::ReservationLimit(tier_id.clone()),
+            &max_open,
+        );
+
+        ReservationLimitSet { tier_id, max_open }.publish(&env);
+        Ok(())
+    }
+
+    pub fn get_tier_limit(env: &Env, tier_id: &String) -> u32 {
+        env.storage()
+            .persistent()
+            .get(&ResourceDataKey::ReservationLimit(tier_id.clone()))
+            .unwrap_or(0)
+    }
+
+    /// Reserves `resource_id` for `[start_time, end_time)` and takes the
+    /// resource's deposit from the subscriber into escrow. Requires the
+    /// subscriber's auth and an active subscription.
+    pub fn reserve_resource(
+        env: Env,
+        subscription_id: String,
+        resource_id: String,
+        start_time: u64,
+        end_time: u64,
+    ) -> Result<Reservation, ModuleError> {
+        let subscription = Self::get_active_subscription(&env, &subscription_id)?;
+        subscription.user.require_auth();
+
+        let resource = Self::get_resource(&env, &resource_id)?;
+        if !resource.active {
+            return Err(ResourceError::ResourceInactive.into());
+        }
+        let now = env.ledger().timestamp();
+        if start_time <= now
+            || end_time <= start_time
+            || end_time - start_time > MAX_RESERVATION_DURATION
+        {
+            return Err(ResourceError::InvalidTimeRange.into());
+        }
+
+        let open_key = ResourceDataKey::OpenReservations(resource_id.clone());
+        let mut open: Vec<u64> = env
+            .storage()
+            .persistent()
+            .get(&open_key)
+            .unwrap_or_else(|| Vec::new(&env));
+        if open.len() >= MAX_OPEN_RESERVATIONS {
+            return Err(ResourceError::TooManyReservations.into());
+        }
+        for other_id in open.iter() {
+            let other = Self::get_reservation(&env, other_id)?;
+            if start_time < other.end_time && other.start_time < end_time {
+                return Err(ResourceError::TimeConflict.into());
+            }
+        }
+
+        let active_key = ResourceDataKey::ActiveReservations(subscription_id.clone());
+        let active: u32 = env.storage().persistent().get(&active_key).unwrap_or(0);
+        if active >= Self::get_tier_limit(&env, &subscription.tier_id) {
+            return Err(ResourceError::LimitReached.into());
+        }
+
+        let deposit_token = if resource.deposit > 0 {
+            let usdc = SubscriptionContract::get_usdc_contract_address(&env)?;
+            token::Client::new(&env, &usdc).transfer(
+                &subscription.user,
+                env.current_contract_address(),
+                &resource.deposit,
+            );
+            Self::adjust_escrow(&env, resource.deposit)?;
+            Some(usdc)
+        } else {
+            None
+        };
+
+        let id: u64 = env
+            .storage()
+            .instance()
+            .get(&ResourceDataKey::NextReservationId)
+            .unwrap_or(0);
+        env.storage()
+            .instance()
+            .set(&ResourceDataKey::NextReservationId, &(id + 1));
+
+        let reservation = Reservation {
+            id,
+            resource_id: resource_id.clone(),
+            subscription_id: subscription_id.clone(),
+            user: subscription.user.clone(),
+            start_time,
+            end_time,
+            deposit: resource.deposit,
+            deposit_token,
+            status: ReservationStatus::Reserved,
+            created_at: now,
+            checked_in_at: 0,
+        };
+        env.storage()
+            .persistent()
+            .set(&ResourceDataKey::Reservation(id), &reservation);
+        open.push_back(id);
+        env.storage().persistent().set(&open_key, &open);
+        env.storage().persistent().set(&active_key, &(active + 1));
+
+        ResourceReserved {
+            resource_id,
+            subscription_id,
+            reservation_id: id,
+            start_time,
+            end_time,
+            deposit: resource.deposit,
+        }
+        .publish(&env);
+        Ok(reservation)
+    }
+
+    /// Cancels a reservation before it starts and refunds its deposit.
+    pub fn cancel_reservation(env: Env, reservation_id: u64) -> Result<(), ModuleError> {
+        let mut reservation = Self::get_reservation(&env, reservation_id)?;
+        reservation.user.require_auth();
+        if reservation.status != ReservationStatus::Reserved {
+            return Err(ResourceError::ReservationNotActive.into());
+        }
+        if env.ledger().timestamp() >= reservation.start_time {
+            return Err(ResourceError::ReservationStarted.into());
+        }
+
+        Self::refund_deposit(&env, &reservation)?;
+        reservation.status = ReservationStatus::Cancelled;
+        Self::close(&env, &reservation);
+
+        ReservationCancelled {
+            resource_id: reservation.resource_id,
+            reservation_id,
+            deposit: reservation.deposit,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    /// Checks a member in to their reservation. Open from
+    /// `RESERVATION_CHECK_IN_WINDOW` before the start until the end.
+    pub fn check_in_reservation(env: Env, reservation_id: u64) -> Result<(), ModuleError> {
+        let mut reservation = Self::get_reservation(&env, reservation_id)?;
+        reservation.user.require_auth();
+        if reservation.status != ReservationStatus::Reserved {
+            return Err(ResourceError::ReservationNotActive.into());
+        }
+
+        let now = env.ledger().timestamp();
+        if now.saturating_add(RESERVATION_CHECK_IN_WINDOW) < reservation.start_time
+            || now >= reservation.end_time
+        {
+            return Err(ResourceError::CheckInClosed.into());
+        }
+        Self::get_active_subscription(&env, &reservation.subscription_id)?;
+
+        reservation.status = ReservationStatus::CheckedIn;
+        reservation.checked_in_at = now;
+        env.storage()
+            .persistent()
+            .set(&ResourceDataKey::Reservation(reservation_id), &reservation);
+
+        ReservationCheckedIn {
+            resource_id: reservation.resource_id,
+            reservation_id,
+            user: reservation.user,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    /// Settles a reservation once its time range has ended, releasing the
+    /// deposit: refunded after a check-in, and on a no-show either refunded
+    /// or forfeited to the treasury as the resource is configured. Anyone
+    /// may settle, since the outcome only depends on recorded state.
+    pub fn settle_reservation(env: Env, reservation_id: u64) -> Result<Reservation, ModuleError> {
+        let mut reservation = Self::get_reservation(&env, reservation_id)?;
+        if env.ledger().timestamp() < reservation.end_time {
+            return Err(ResourceError::ReservationNotEnded.into());
+        }
+
+        reservation.status = match reservation.status {
+            ReservationStatus::CheckedIn => {
+                Self::refund_deposit(&env, &reservation)?;
+                ReservationStatus::Completed
+            }
+            ReservationStatus::Reserved => {
+                let forfeit_on_no_show =
+                    Self::get_resource(&env, &reservation.resource_id)?.forfeit_on_no_show;
+                let forfeit_token = reservation
+                    .deposit_token
+                    .clone()
+                    .filter(|_| forfeit_on_no_show && reservation.deposit > 0);
+                if let Some(deposit_token) = forfeit_token {
+                    Self::adjust_escrow(&env, -reservation.deposit)?;
+                    TreasuryModule::record_collected(
+                        &env,
+                        RevenueSource::Penalty,
+                        &deposit_token,
+                        reservation.deposit,
+                    )?;
+                    ReservationStatus::Forfeited
+                } else {
+                    Self::refund_deposit(&env, &reservation)?;
+                    ReservationStatus::NoShow
+                }
+            }
+            _ => return Err(ResourceError::ReservationNotActive.into()),
+        };
+        Self::close(&env, &reservation);
+
+        ReservationSettled {
+            resource_id: reservation.resource_id.clone(),
+            reservation_id,
+            status: reservation.status.clone(),
+            deposit: reservation.deposit,
+        }
+        .publish(&env);
+        Ok(reservation)
+    }
+
+    pub fn get_reservation(env: &Env, reservation_id: u64) -> Result<Reservation, ModuleError> {
+        env.storage()
+            .persistent()
+            .get(&ResourceDataKey::Reservation(reservation_id))
+            .ok_or(ResourceError::ReservationNotFound.into())
+    }
+
+    /// Reservations of a resource that are not yet cancelled or settled.
+    pub fn get_open_reservations(env: &Env, resource_id: &String) -> Vec<Reservation> {
+        let ids: Vec<u64> = env
+            .storage()
+            .persistent()
+            .get(&ResourceDataKey::OpenReservations(resource_id.clone()))
+            .unwrap_or_else(|| Vec::new(env));
+        let mut reservations = Vec::new(env);
+        for id in ids.iter() {
+            if let Ok(reservation) = Self::get_reservation(env, id) {
+                reservations.push_back(reservation);
+            }
+        }
+        reservations
+    }
+
+    pub fn get_active_count(env: &Env, subscription_id: &String) -> u32 {
+        env.storage()
+            .persistent()
+            .get(&ResourceDataKey::ActiveReservations(
+                subscription_id.clone(),
+            ))
+            .unwrap_or(0)
+    }
+
+    /// USDC held by the contract for reservation deposits.
+    pub fn get_escrowed_deposits(env: &Env) -> i128 {
+        env.storage()
+            .instance()
+            .get(&ResourceDataKey::EscrowedDeposits)
+            .unwrap_or(0)
+    }
+
+    fn refund_deposit(env: &Env, reservation: &Reservation) -> Result<(), Error> {
+        let Some(deposit_token) = reservation.deposit_token.as_ref() else {
+            return Ok(());
+        };
+        if reservation.deposit <= 0 {
+            return Ok(());
+        }
+        token::Client::new(env, deposit_token).transfer(
+            &env.current_contract_address(),
+            &reservation.user,
+            &reservation.deposit,
+        );
+        Self::adjust_escrow(env, -reservation.deposit)
+    }
+
+    fn adjust_escrow(env: &Env, delta: i128) -> Result<(), Error> {
+        let escrowed = Self::get_escrowed_deposits(env)
+            .checked_add(delta)
+            .filter(|escrowed| *escrowed >= 0)
+            .ok_or(Error::InsufficientBalance)?;
+        env.storage()
+            .instance()
+            .set(&ResourceDataKey::EscrowedDeposits, &escrowed);
+        Ok(())
+    }
+
+    /// Stores a cancelled or settled reservation and releases its slot on
+    /// the resource and against the subscription's limit.
+    fn close(env: &Env, reservation: &Reservation) {
+        env.storage()
+            .persistent()
+            .set(&ResourceDataKey::Reservation(reservation.id), reservation);
+
+        let open_key = ResourceDataKey::OpenReservations(reservation.resource_id.clone());
+        let mut open: Vec<u64> = env
+            .storage()
+            .persistent()
+            .get(&open_key)
+            .unwrap_or_else(|| Vec::new(env));
+        if let Some(index) = open.first_index_of(reservation.id) {
+            open.remove(index);
+        }
+        env.storage().persistent().set(&open_key, &open);
+
+        let active_key = ResourceDataKey::ActiveReservations(reservation.subscription_id.clone());
+        let active: u32 = env.storage().persistent().get(&active_key).unwrap_or(0);
+        env.storage()
+            .persistent()
+            .set(&active_key, &active.saturating_sub(1));
+    }
+
+    fn get_active_subscription(env: &Env, subscription_id: &String) -> Result<Subscription, Error> {
+        let subscription =
+            SubscriptionContract::get_subscription(env.clone(), subscription_id.clone())?;
+        if subscription.status != MembershipStatus::Active
+            || subscription.expires_at < env.ledger().timestamp()
+        {
+            return Err(Error::SubscriptionNotActive);
+        }
+        Ok(subscription)
+    }
+}
@@ -0,0 +1,153 @@
+use crate::errors::{Error, ModuleError};
+use crate::membership_token::MembershipTokenContract;
+use crate::retention_errors::RetentionError;
+use crate::ttl::{TtlEntityClass, TtlModule};
+use crate::types::{
+    CancellationReason, CancellationRecord, Subscription, WinbackConfig, WinbackOffer,
+};
+use common_types::{Amount, Bps};
+use soroban_sdk::{contracttype, Address, Env, String};
+
+// ---------------------------------------------------------------------------
+// Storage keys
+// ---------------------------------------------------------------------------
+
+#[contracttype]
+pub enum RetentionDataKey {
+    /// Why and when a subscription was cancelled (persistent storage).
+    Cancellation(String),
+    /// Number of cancellations that gave a reason, keyed by reason
+    /// (persistent storage).
+    ReasonCount(CancellationReason),
+    /// Win-back offer settings (instance storage).
+    WinbackConfig,
+    /// Open win-back offer of a user (persistent storage).
+    WinbackOffer(Address),
+}
+
+// ---------------------------------------------------------------------------
+// Module
+// ---------------------------------------------------------------------------
+
+/// Cancellation survey answers and win-back offers for churned members.
+pub struct RetentionModule;
+
+impl RetentionModule {
+    /// Stores why `subscription` was cancelled and, when win-back offers are
+    /// enabled, attaches one to its owner, replacing any earlier offer.
+    pub(crate) fn on_cancel(
+        env: &Env,
+        subscription: &Subscription,
+        reason: Option<CancellationReason>,
+    ) {
+        let now = env.ledger().timestamp();
+        let reason = reason.unwrap_or(CancellationReason::Unspecified);
+        if reason != CancellationReason::Unspecified {
+            let key = RetentionDataKey::ReasonCount(reason.clone());
+            let count: u32 = env.storage().persistent().get(&key).unwrap_or(0);
+            env.storage().persistent().set(&key, &(count + 1));
+            TtlModule::bump(env, TtlEntityClass::Subscription, &key);
+        }
+        let key = RetentionDataKey::Cancellation(subscription.id.clone());
+        env.storage().persistent().set(
+            &key,
+            &CancellationRecord {
+                subscription_id: subscription.id.clone(),
+                user: subscription.user.clone(),
+                tier_id: subscription.tier_id.clone(),
+                reason,
+                cancelled_at: now,
+            },
+        );
+        TtlModule::bump(env, TtlEntityClass::Subscription, &key);
+
+        let Some(config) = Self::get_winback_config(env) else {
+            return;
+        };
+        let key = RetentionDataKey::WinbackOffer(subscription.user.clone());
+        env.storage().persistent().set(
+            &key,
+            &WinbackOffer {
+                subscription_id: subscription.id.clone(),
+                discount_percent: config.discount_percent,
+                issued_at: now,
+                expires_at: now.saturating_add(config.valid_days as u64 * 24 * 60 * 60),
+            },
+        );
+        TtlModule::bump(env, TtlEntityClass::Subscription, &key);
+    }
+
+    /// Applies `user`'s open win-back offer to `price` and consumes it.
+    /// Returns `price` unchanged when the user has no valid offer.
+    pub(crate) fn redeem_winback(env: &Env, user: &Address, price: i128) -> Result<i128, Error> {
+        let Some(offer) = Self::get_winback_offer(env, user) else {
+            return Ok(price);
+        };
+        env.storage()
+            .persistent()
+            .remove(&RetentionDataKey::WinbackOffer(user.clone()));
+        Amount::new(price)
+            .zip(Bps::from_percent(offer.discount_percent))
+            .and_then(|(price, bps)| price.discount(bps))
+            .map(Amount::value)
+            .ok_or(Error::InvalidPaymentAmount)
+    }
+
+    /// Returns why a subscription was cancelled, if it was.
+    pub fn get_cancellation(env: &Env, subscription_id: &String) -> Option<CancellationRecord> {
+        env.storage()
+            .persistent()
+            .get(&RetentionDataKey::Cancellation(subscription_id.clone()))
+    }
+
+    /// Number of cancellations that gave `reason`; skipped surveys are not
+    /// counted.
+    pub fn get_cancellation_reason_count(env: &Env, reason: CancellationReason) -> u32 {
+        env.storage()
+            .persistent()
+            .get(&RetentionDataKey::ReasonCount(reason))
+            .unwrap_or(0)
+    }
+
+    /// Sets the win-back offer attached on cancellation, or disables offers
+    /// with `None`. Offers already attached are unaffected. Admin only.
+    pub fn set_winback_config(
+        env: Env,
+        admin: Address,
+        config: Option<WinbackConfig>,
+    ) -> Result<(), ModuleError> {
+        MembershipTokenContract::require_admin(&env, &admin)?;
+        match config {
+            Some(config) => {
+                if config.discount_percent == 0
+                    || config.discount_percent > 100
+                    || config.valid_days == 0
+                {
+                    return Err(RetentionError::InvalidWinbackConfig.into());
+                }
+                env.storage()
+                    .instance()
+                    .set(&RetentionDataKey::WinbackConfig, &config);
+            }
+            None => env
+                .storage()
+                .instance()
+                .remove(&RetentionDataKey::WinbackConfig),
+        }
+        Ok(())
+    }
+
+    pub fn get_winback_config(env: &Env) -> Option<WinbackConfig> {
+        env.storage()
+            .instance()
+            .get(&RetentionDataKey::WinbackConfig)
+    }
+
+    /// Returns `user`'s win-back offer while it can still be redeemed.
+    pub fn get_winback_offer(env: &Env, user: &Address) -> Option<WinbackOffer> {
+        env.storage()
+            .persistent()
+            .get::<_, WinbackOffer>(&RetentionDataKey::WinbackOffer(user.clone()))
+            .filter(|offer| env.ledger().timestamp() <= offer.expires_at)
+    }
+}
@@ -0,0 +1,14 @@
+//! Retention-related error types for the ManageHub contract.
+//!
+//! `RetentionError` owns the code range 1400–1449 (see [`crate::errors`]).
+
+use soroban_sdk::contracterror;
+
+/// Retention-specific errors.
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum RetentionError {
+    /// The win-back discount is outside 1–100 % or the offer would never be
+    /// valid.
+    InvalidWinbackConfig = 1400,
+}
@@ -10,10 +10,14 @@
 //!                 - already_claimed_rewards
 //! ```
 //!
+//! `reward_multiplier_bps` is the staking tier's multiplier plus the staking
+//! bonus of the staker's active subscription bundle, if any.
+//!
 //! All intermediate multiplications use `i128` and `checked_*` to avoid
 //! silent overflows.
 
-use crate::errors::Error;
+use crate::bundles::BundleModule;
+use crate::errors::ModuleError;
 use crate::staking::StakingModule;
 use crate::staking_errors::StakingError;
 use crate::types::StakeInfo;
@@ -28,16 +32,27 @@ impl RewardsModule {
     /// Calculate pending (unclaimed) rewards for a stake as of now.
     ///
     /// Returns `0` if the stake was emergency-unstaked.
-    pub fn calculate_pending_rewards(env: &Env, stake: &StakeInfo) -> Result<i128, Error> {
+    pub fn calculate_pending_rewards(env: &Env, stake: &StakeInfo) -> Result<i128, ModuleError> {
         if stake.emergency_unstaked {
             return Ok(0);
         }
 
         let tier = StakingModule::get_tier_internal(env, &stake.tier_id)?;
+        let multiplier_bps = tier
+            .reward_multiplier_bps
+            .saturating_add(BundleModule::staking_bonus_bps(env, &stake.staker));
 
-        let now = env.ledger().timestamp() as i128;
+        // Rewards stop accruing once an unstake cooldown starts, and time
+        // spent in cancelled cooldowns never counts.
+        let accrual_end = StakingModule::get_unstake_request(env.clone(), stake.staker.clone())
+            .map(|request| request.requested_at)
+            .unwrap_or_else(|| env.ledger().timestamp()) as i128;
         let staked_at = stake.staked_at as i128;
-        let elapsed = now.checked_sub(staked_at).unwrap_or(0).max(0);
+        let elapsed = accrual_end
+            .checked_sub(staked_at)
+            .and_then(|secs| secs.checked_sub(stake.paused_secs as i128))
+            .unwrap_or(0)
+            .max(0);
 
         // gross = principal * base_rate_bps * elapsed * multiplier_bps
         //         / (10_000 * YEAR_SECS * 10_000)
@@ -47,7 +62,7 @@ impl RewardsModule {
             .ok_or(StakingError::Overflow)?
             .checked_mul(elapsed)
             .ok_or(StakingError::Overflow)?
-            .checked_mul(tier.reward_multiplier_bps as i128)
+            .checked_mul(multiplier_bps as i128)
             .ok_or(StakingError::Overflow)?
             .checked_div(
                 10_000i128
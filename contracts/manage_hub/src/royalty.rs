@@ -1,10 +1,15 @@
 // Allow deprecated events API until migration to #[contractevent] macro
 #![allow(deprecated)]
 
-use crate::errors::Error;
-use crate::membership_token::DataKey;
-use crate::types::{RoyaltyConfig, RoyaltyInfo, RoyaltyRecipient};
-use soroban_sdk::{symbol_short, Address, BytesN, Env, Vec};
+use crate::errors::{Error, ModuleError};
+use crate::events::TransferFeeCharged;
+use crate::membership_token::{DataKey, MembershipTokenContract};
+use crate::subscription::SubscriptionContract;
+use crate::treasury::TreasuryModule;
+use crate::types::{
+    RevenueSource, RoyaltyConfig, RoyaltyInfo, RoyaltyRecipient, TransferFeeConfig,
+};
+use soroban_sdk::{symbol_short, token, Address, BytesN, Env, Vec};
 
 pub struct RoyaltyModule;
 
@@ -55,15 +60,11 @@ impl RoyaltyModule {
         Ok(())
     }
 
-    /// Calculates required royalty payments based on sale price and emits distribution events.
-    pub fn calculate_and_pay_royalties(
-        env: &Env,
-        token_id: &BytesN<32>,
-        payment_token: &Address,
-        sale_price: i128,
-    ) -> Result<i128, Error> {
+    /// Royalty owed to each recipient for a sale, skipping zero amounts.
+    fn royalty_splits(env: &Env, token_id: &BytesN<32>, sale_price: i128) -> Vec<(Address, i128)> {
+        let mut splits = Vec::new(env);
         if sale_price <= 0 {
-            return Ok(0);
+            return splits;
         }
 
         let config: Option<RoyaltyConfig> = env
@@ -72,38 +73,86 @@ impl RoyaltyModule {
             .get(&DataKey::Royalty(token_id.clone()));
 
         if let Some(cfg) = config {
-            if !cfg.enabled || cfg.recipients.is_empty() {
-                return Ok(0);
+            if !cfg.enabled {
+                return splits;
             }
 
-            let mut total_royalty_amount: i128 = 0;
-
             for recipient in cfg.recipients.iter() {
                 // Calculate portion using basis points (percentage * sale_price / 10000)
                 let amount =
                     (sale_price * recipient.percentage as i128) / Self::MAX_ROYALTY_BPS as i128;
 
                 if amount > 0 {
-                    total_royalty_amount += amount;
-
-                    // Note: Here we'd normally call `token::Client::new(env, payment_token).transfer(...)`
-                    // To keep things simple and avoiding external cross-contract token integrations for the royalty distribution,
-                    // we emit an event that off-chain indexers or wrapper contracts can use to fulfill the payment synchronously or asynchronously.
-                    env.events().publish(
-                        (
-                            symbol_short!("roy_paid"),
-                            token_id.clone(),
-                            recipient.address.clone(),
-                        ),
-                        (payment_token.clone(), amount, env.ledger().timestamp()),
-                    );
+                    splits.push_back((recipient.address, amount));
                 }
             }
+        }
 
-            return Ok(total_royalty_amount);
+        splits
+    }
+
+    fn publish_royalty_paid(
+        env: &Env,
+        token_id: &BytesN<32>,
+        recipient: Address,
+        payment_token: &Address,
+        amount: i128,
+    ) {
+        env.events().publish(
+            (symbol_short!("roy_paid"), token_id.clone(), recipient),
+            (payment_token.clone(), amount, env.ledger().timestamp()),
+        );
+    }
+
+    /// Calculates required royalty payments based on sale price and emits distribution events.
+    pub fn calculate_and_pay_royalties(
+        env: &Env,
+        token_id: &BytesN<32>,
+        payment_token: &Address,
+        sale_price: i128,
+    ) -> Result<i128, Error> {
+        let mut total_royalty_amount: i128 = 0;
+
+        for (recipient, amount) in Self::royalty_splits(env, token_id, sale_price).iter() {
+            total_royalty_amount += amount;
+
+            // Note: Here we'd normally call `token::Client::new(env, payment_token).transfer(...)`
+            // To keep things simple and avoiding external cross-contract token integrations for the royalty distribution,
+            // we emit an event that off-chain indexers or wrapper contracts can use to fulfill the payment synchronously or asynchronously.
+            Self::publish_royalty_paid(env, token_id, recipient, payment_token, amount);
         }
 
-        Ok(0)
+        Ok(total_royalty_amount)
+    }
+
+    /// Transfers the royalties for a sale from `payer` to each recipient.
+    /// Returns the total paid. Royalties paid to the hub itself are booked
+    /// as marketplace fee revenue.
+    pub(crate) fn transfer_royalties(
+        env: &Env,
+        token_id: &BytesN<32>,
+        payer: &Address,
+        payment_token: &Address,
+        sale_price: i128,
+    ) -> Result<i128, ModuleError> {
+        let client = token::Client::new(env, payment_token);
+        let mut total_royalty_amount: i128 = 0;
+
+        for (recipient, amount) in Self::royalty_splits(env, token_id, sale_price).iter() {
+            total_royalty_amount += amount;
+            client.transfer(payer, &recipient, &amount);
+            if recipient == env.current_contract_address() {
+                TreasuryModule::record_collected(
+                    env,
+                    RevenueSource::MarketplaceFee,
+                    payment_token,
+                    amount,
+                )?;
+            }
+            Self::publish_royalty_paid(env, token_id, recipient, payment_token, amount);
+        }
+
+        Ok(total_royalty_amount)
     }
 
     /// Get details of royalty configuration
@@ -125,4 +174,115 @@ impl RoyaltyModule {
             None
         }
     }
+
+    /// Sets the hub fee charged on `transfer_from` resales. Admin only.
+    pub fn set_transfer_fee(
+        env: Env,
+        admin: Address,
+        fee_bps: u32,
+        min_fee: i128,
+        recipient: Address,
+    ) -> Result<(), Error> {
+        MembershipTokenContract::require_admin(&env, &admin)?;
+        if fee_bps == 0 || fee_bps > Self::MAX_ROYALTY_BPS || min_fee < 0 {
+            return Err(Error::InvalidPaymentAmount);
+        }
+
+        let config = TransferFeeConfig {
+            fee_bps,
+            min_fee,
+            recipient: recipient.clone(),
+        };
+        env.storage().instance().set(&DataKey::TransferFee, &config);
+
+        env.events()
+            .publish((symbol_short!("tfee_set"), recipient), fee_bps);
+        Ok(())
+    }
+
+    /// Removes the transfer fee. Admin only.
+    pub fn clear_transfer_fee(env: Env, admin: Address) -> Result<(), Error> {
+        MembershipTokenContract::require_admin(&env, &admin)?;
+        env.storage().instance().remove(&DataKey::TransferFee);
+        env.events().publish((symbol_short!("tfee_clr"),), admin);
+        Ok(())
+    }
+
+    pub fn get_transfer_fee(env: &Env) -> Option<TransferFeeConfig> {
+        env.storage().instance().get(&DataKey::TransferFee)
+    }
+
+    /// Adds or removes an address from the transfer fee exemption list. Admin only.
+    pub fn set_transfer_fee_exempt(
+        env: Env,
+        admin: Address,
+        account: Address,
+        exempt: bool,
+    ) -> Result<(), Error> {
+        MembershipTokenContract::require_admin(&env, &admin)?;
+        let key = DataKey::TransferFeeExempt(account);
+        if exempt {
+            env.storage().persistent().set(&key, &true);
+        } else {
+            env.storage().persistent().remove(&key);
+        }
+        Ok(())
+    }
+
+    /// Whether `account` is exempt from the transfer fee. The admin always is.
+    pub fn is_transfer_fee_exempt(env: &Env, account: &Address) -> bool {
+        let admin: Option<Address> = env.storage().instance().get(&DataKey::Admin);
+        admin.as_ref() == Some(account)
+            || env
+                .storage()
+                .persistent()
+                .has(&DataKey::TransferFeeExempt(account.clone()))
+    }
+
+    /// Charges the transfer fee on a `transfer_from` resale, paid by the
+    /// spender in USDC. The spender declares `sale_amount`, so the fee never
+    /// drops below the configured `min_fee`. Fees paid to the hub itself are
+    /// booked as marketplace fee revenue. Returns the fee charged; nothing is
+    /// charged when no fee is configured or the owner or spender is exempt.
+    pub(crate) fn charge_transfer_fee(
+        env: &Env,
+        token_id: &BytesN<32>,
+        owner: &Address,
+        spender: &Address,
+        sale_amount: i128,
+    ) -> Result<i128, ModuleError> {
+        let Some(config) = Self::get_transfer_fee(env) else {
+            return Ok(0);
+        };
+        if Self::is_transfer_fee_exempt(env, owner) || Self::is_transfer_fee_exempt(env, spender) {
+            return Ok(0);
+        }
+
+        let fee = sale_amount
+            .checked_mul(config.fee_bps as i128)
+            .ok_or(Error::TimestampOverflow)?
+            / Self::MAX_ROYALTY_BPS as i128;
+        let fee = fee.max(config.min_fee);
+        if fee == 0 {
+            return Ok(0);
+        }
+
+        let usdc = SubscriptionContract::get_usdc_contract_address(env)?;
+        token::Client::new(env, &usdc).transfer(spender, &config.recipient, &fee);
+        if config.recipient == env.current_contract_address() {
+            TreasuryModule::record_collected(env, RevenueSource::MarketplaceFee, &usdc, fee)?;
+        }
+
+        TransferFeeCharged {
+            token_id: token_id.clone(),
+            payer: spender.clone(),
+            recipient: config.recipient,
+            payment_token: usdc,
+            sale_amount,
+            fee,
+            timestamp: env.ledger().timestamp(),
+        }
+        .publish(env);
+        Ok(fee)
+    }
 }
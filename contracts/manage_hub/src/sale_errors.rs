@@ -0,0 +1,21 @@
+//! Token sale error types for the ManageHub contract.
+//!
+//! `SaleError` owns the code range 1450–1499 (see [`crate::errors`]).
+
+use soroban_sdk::contracterror;
+
+/// Sale-specific errors.
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum SaleError {
+    /// The token has no active sale listing.
+    SaleNotFound = 1450,
+    /// The token is already listed for sale.
+    AlreadyListed = 1451,
+    /// The seller's number of active listings has reached its cap.
+    TooManyListings = 1452,
+    /// The token changed hands since it was listed.
+    ListingStale = 1453,
+    /// The number of active listings across all sellers has reached its cap.
+    ListingCapReached = 1454,
+}
@@ -0,0 +1,247 @@
+use crate::errors::{Error, ModuleError};
+use crate::events::{TokenListedForSale, TokenSaleCancelled, TokenSold};
+use crate::guards::PauseGuard;
+use crate::membership_token::{
+    DataKey as MembershipDataKey, MembershipToken, MembershipTokenContract,
+};
+use crate::royalty::RoyaltyModule;
+use crate::sale_errors::SaleError;
+use crate::subscription::SubscriptionContract;
+use crate::types::TokenSale;
+use soroban_sdk::{contracttype, token, Address, BytesN, Env, Vec};
+
+// ---------------------------------------------------------------------------
+// Storage keys
+// ---------------------------------------------------------------------------
+
+#[contracttype]
+pub enum SaleDataKey {
+    /// Sale listing for a token (persistent storage).
+    Listing(BytesN<32>),
+    /// Token IDs with a listing, in listing order (persistent storage).
+    Listed,
+    /// Number of active listings per seller (persistent storage).
+    SellerListingCount(Address),
+}
+
+// ---------------------------------------------------------------------------
+// Constants
+// ---------------------------------------------------------------------------
+
+/// Cap on simultaneous listings per seller.
+pub const MAX_LISTINGS_PER_SELLER: u32 = 20;
+
+/// Cap on simultaneous listings across all sellers. Bounds the size of
+/// `SaleDataKey::Listed`, which every listing change rewrites.
+pub const MAX_ACTIVE_LISTINGS: u32 = 200;
+
+/// Maximum listings read by one `get_active_sales` call.
+pub const MAX_SALES_PAGE: u32 = 50;
+
+// ---------------------------------------------------------------------------
+// Module
+// ---------------------------------------------------------------------------
+
+/// Peer-to-peer membership token sales.
+///
+/// A listing records the seller's asking price in USDC. `buy_token` settles
+/// in one call: the buyer pays royalties to the token's royalty recipients
+/// and the rest to the seller, and ownership moves to the buyer. No
+/// allowance is needed. A listing is withdrawn whenever the token changes
+/// hands by other means.
+pub struct SaleModule;
+
+impl SaleModule {
+    pub fn list_token(env: Env, token_id: BytesN<32>, price: i128) -> Result<(), ModuleError> {
+        PauseGuard::require_not_paused(&env)?;
+        if price <= 0 {
+            return Err(Error::InvalidPaymentAmount.into());
+        }
+
+        let token = MembershipTokenContract::get_transferable_token(&env, &token_id)?;
+        token.user.require_auth();
+
+        if let Some(existing) = Self::get_listing(&env, &token_id) {
+            if existing.seller == token.user {
+                return Err(SaleError::AlreadyListed.into());
+            }
+            Self::remove_listing(&env, &existing);
+        }
+
+        let count = Self::seller_listing_count(&env, &token.user);
+        if count >= MAX_LISTINGS_PER_SELLER {
+            return Err(SaleError::TooManyListings.into());
+        }
+        let mut listed = Self::listed_ids(&env);
+        if listed.len() >= MAX_ACTIVE_LISTINGS {
+            return Err(SaleError::ListingCapReached.into());
+        }
+        env.storage().persistent().set(
+            &SaleDataKey::SellerListingCount(token.user.clone()),
+            &(count + 1),
+        );
+        listed.push_back(token_id.clone());
+        env.storage()
+            .persistent()
+            .set(&SaleDataKey::Listed, &listed);
+
+        let listed_at = env.ledger().timestamp();
+        let sale = TokenSale {
+            token_id: token_id.clone(),
+            seller: token.user.clone(),
+            price,
+            listed_at,
+        };
+        env.storage()
+            .persistent()
+            .set(&SaleDataKey::Listing(token_id.clone()), &sale);
+
+        TokenListedForSale {
+            token_id,
+            seller: token.user,
+            price,
+            timestamp: listed_at,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    /// Withdraws a listing. Only the seller can cancel while they still own
+    /// the token; anyone may clear a stale listing.
+    pub fn cancel_sale(env: Env, token_id: BytesN<32>) -> Result<(), ModuleError> {
+        let sale = Self::get_listing(&env, &token_id).ok_or(SaleError::SaleNotFound)?;
+        if Self::is_current(&env, &sale) {
+            sale.seller.require_auth();
+        }
+        Self::remove_listing(&env, &sale);
+
+        TokenSaleCancelled {
+            token_id,
+            seller: sale.seller,
+            timestamp: env.ledger().timestamp(),
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    /// Buys a listed token at its asking price in USDC.
+    pub fn buy_token(env: Env, token_id: BytesN<32>, buyer: Address) -> Result<(), ModuleError> {
+        PauseGuard::require_not_paused(&env)?;
+        buyer.require_auth();
+
+        let sale = Self::get_listing(&env, &token_id).ok_or(SaleError::SaleNotFound)?;
+        let token = MembershipTokenContract::get_transferable_token(&env, &token_id)?;
+        if token.user != sale.seller {
+            return Err(SaleError::ListingStale.into());
+        }
+        if buyer == sale.seller {
+            return Err(Error::Unauthorized.into());
+        }
+
+        let usdc = SubscriptionContract::get_usdc_contract_address(&env)?;
+        let royalties =
+            RoyaltyModule::transfer_royalties(&env, &token_id, &buyer, &usdc, sale.price)?;
+        let proceeds = sale
+            .price
+            .checked_sub(royalties)
+            .ok_or(Error::InvalidPaymentAmount)?;
+        if proceeds > 0 {
+            token::Client::new(&env, &usdc).transfer(&buyer, &sale.seller, &proceeds);
+        }
+
+        MembershipTokenContract::reassign_token(&env, token, buyer.clone())?;
+
+        TokenSold {
+            token_id,
+            buyer,
+            payment_token: usdc,
+            sale_price: sale.price,
+            timestamp: env.ledger().timestamp(),
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    /// Reads up to `limit` listings starting at `offset`, in listing order,
+    /// and returns those whose seller still owns the token. `limit` is capped
+    /// at `MAX_SALES_PAGE`.
+    pub fn get_active_sales(env: &Env, offset: u32, limit: u32) -> Vec<TokenSale> {
+        let listed = Self::listed_ids(env);
+        let end = offset
+            .saturating_add(limit.min(MAX_SALES_PAGE))
+            .min(listed.len());
+
+        let mut sales = Vec::new(env);
+        for index in offset..end {
+            let token_id = listed.get_unchecked(index);
+            if let Some(sale) = Self::get_listing(env, &token_id) {
+                if Self::is_current(env, &sale) {
+                    sales.push_back(sale);
+                }
+            }
+        }
+        sales
+    }
+
+    /// Withdraws the listing of a token that is changing hands, if any.
+    pub(crate) fn clear_listing(env: &Env, token_id: &BytesN<32>) {
+        let Some(sale) = Self::get_listing(env, token_id) else {
+            return;
+        };
+        Self::remove_listing(env, &sale);
+
+        TokenSaleCancelled {
+            token_id: token_id.clone(),
+            seller: sale.seller,
+            timestamp: env.ledger().timestamp(),
+        }
+        .publish(env);
+    }
+
+    pub fn get_listing(env: &Env, token_id: &BytesN<32>) -> Option<TokenSale> {
+        env.storage()
+            .persistent()
+            .get(&SaleDataKey::Listing(token_id.clone()))
+    }
+
+    fn is_current(env: &Env, sale: &TokenSale) -> bool {
+        let token: Option<MembershipToken> = env
+            .storage()
+            .persistent()
+            .get(&MembershipDataKey::Token(sale.token_id.clone()));
+        token.is_some_and(|token| token.user == sale.seller)
+    }
+
+    fn listed_ids(env: &Env) -> Vec<BytesN<32>> {
+        env.storage()
+            .persistent()
+            .get(&SaleDataKey::Listed)
+            .unwrap_or(Vec::new(env))
+    }
+
+    fn seller_listing_count(env: &Env, seller: &Address) -> u32 {
+        env.storage()
+            .persistent()
+            .get(&SaleDataKey::SellerListingCount(seller.clone()))
+            .unwrap_or(0)
+    }
+
+    fn remove_listing(env: &Env, sale: &TokenSale) {
+        env.storage()
+            .persistent()
+            .remove(&SaleDataKey::Listing(sale.token_id.clone()));
+        let mut listed = Self::listed_ids(env);
+        if let Some(index) = listed.first_index_of(&sale.token_id) {
+            listed.remove(index);
+            env.storage()
+                .persistent()
+                .set(&SaleDataKey::Listed, &listed);
+        }
+
+        let count_key = SaleDataKey::SellerListingCount(sale.seller.clone());
+        match Self::seller_listing_count(env, &sale.seller) {
+            0 | 1 => env.storage().persistent().remove(&count_key),
+            count => env.storage().persistent().set(&count_key, &(count - 1)),
+        }
+    }
+}
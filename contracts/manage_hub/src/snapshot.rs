@@ -0,0 +1,192 @@
+use crate::achievements::AchievementModule;
+use crate::errors::Error;
+use crate::fractionalization::FractionalizationModule;
+use crate::loyalty::LoyaltyModule;
+use crate::membership_token::{
+    DataKey as MembershipDataKey, MembershipToken, MembershipTokenContract,
+};
+use crate::staking::StakingModule;
+use crate::subscription::SubscriptionContract;
+use crate::types::{Badge, LoyaltyAccount, MembershipStatus, StakeInfo, Subscription};
+use soroban_sdk::{contracttype, Address, BytesN, Env, Vec};
+
+// ---------------------------------------------------------------------------
+// Storage keys
+// ---------------------------------------------------------------------------
+
+#[contracttype]
+pub enum SnapshotDataKey {
+    /// Number of indexed members (instance storage).
+    MemberCount,
+    /// Member by position in the index (persistent storage).
+    MemberAt(u32),
+    /// Marks a member as indexed (persistent storage).
+    IndexedMember(Address),
+}
+
+// ---------------------------------------------------------------------------
+// Constants
+// ---------------------------------------------------------------------------
+
+/// Most snapshots returned by one bulk export call; each snapshot reads
+/// every holding of a member.
+pub const MAX_SNAPSHOT_PAGE: u32 = 10;
+
+// ---------------------------------------------------------------------------
+// Types
+// ---------------------------------------------------------------------------
+
+/// Shares a member holds in a fractionalized token.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct FractionHolding {
+    pub token_id: BytesN<32>,
+    pub shares: i128,
+    pub total_shares: i128,
+}
+
+/// Everything the contract holds about one address, for data-portability
+/// requests.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct MemberSnapshot {
+    pub user: Address,
+    /// Membership tokens currently owned
+    pub tokens: Vec<MembershipToken>,
+    /// Subscriptions currently active, newest first
+    pub active_subscriptions: Vec<Subscription>,
+    /// Staking positions (at most one per address)
+    pub stakes: Vec<StakeInfo>,
+    pub fraction_holdings: Vec<FractionHolding>,
+    /// Unspent tier-change credit, in USDC base units
+    pub credit_balance: i128,
+    /// Loyalty points and banked day credits
+    pub loyalty: LoyaltyAccount,
+    pub badges: Vec<Badge>,
+    pub exported_at: u64,
+}
+
+// ---------------------------------------------------------------------------
+// Module
+// ---------------------------------------------------------------------------
+
+/// Member state export for compliance.
+///
+/// `export_member_snapshot` gathers a member's tokens, active subscriptions,
+/// stake, fraction holdings, credits and badges into one record. For bulk
+/// exports, every address is indexed the first time it receives a token or
+/// a subscription, and the admin pages through that index.
+pub struct SnapshotModule;
+
+impl SnapshotModule {
+    /// Adds `user` to the member index unless already listed.
+    pub(crate) fn index_member(env: &Env, user: &Address) {
+        let indexed_key = SnapshotDataKey::IndexedMember(user.clone());
+        if env.storage().persistent().has(&indexed_key) {
+            return;
+        }
+        let count = Self::get_member_count(env);
+        env.storage()
+            .persistent()
+            .set(&SnapshotDataKey::MemberAt(count), user);
+        env.storage().persistent().set(&indexed_key, &true);
+        env.storage()
+            .instance()
+            .set(&SnapshotDataKey::MemberCount, &(count + 1));
+    }
+
+    pub fn get_member_count(env: &Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&SnapshotDataKey::MemberCount)
+            .unwrap_or(0)
+    }
+
+    pub fn export_member_snapshot(env: &Env, user: &Address) -> MemberSnapshot {
+        let mut tokens = Vec::new(env);
+        for id in MembershipTokenContract::get_owner_tokens(env, user).iter() {
+            if let Some(token) = env
+                .storage()
+                .persistent()
+                .get::<_, MembershipToken>(&MembershipDataKey::Token(id))
+            {
+                tokens.push_back(token);
+            }
+        }
+
+        let now = env.ledger().timestamp();
+        let mut active_subscriptions = Vec::new(env);
+        for subscription in
+            SubscriptionContract::get_subscriptions_for_user(env.clone(), user.clone())
+                .iter()
+                .rev()
+        {
+            if subscription.status == MembershipStatus::Active && subscription.expires_at >= now {
+                active_subscriptions.push_back(subscription);
+            }
+        }
+
+        let mut stakes = Vec::new(env);
+        if let Some(stake) = StakingModule::get_stake_info(env.clone(), user.clone()) {
+            stakes.push_back(stake);
+        }
+
+        let mut fraction_holdings = Vec::new(env);
+        for token_id in FractionalizationModule::get_holder_tokens(env, user).iter() {
+            let Ok(info) = FractionalizationModule::get_fraction_info(env, &token_id) else {
+                continue;
+            };
+            let shares = FractionalizationModule::fraction_balance(
+                env.clone(),
+                token_id.clone(),
+                user.clone(),
+            )
+            .unwrap_or(0);
+            if shares > 0 {
+                fraction_holdings.push_back(FractionHolding {
+                    token_id,
+                    shares,
+                    total_shares: info.total_shares,
+                });
+            }
+        }
+
+        MemberSnapshot {
+            user: user.clone(),
+            tokens,
+            active_subscriptions,
+            stakes,
+            fraction_holdings,
+            credit_balance: SubscriptionContract::get_credit_balance(env.clone(), user.clone()),
+            loyalty: LoyaltyModule::get_account(env, user),
+            badges: AchievementModule::get_badges(env, user),
+            exported_at: now,
+        }
+    }
+
+    /// Snapshots of up to `limit` indexed members starting at `offset`, in
+    /// indexing order. Admin only; `limit` is capped at `MAX_SNAPSHOT_PAGE`.
+    pub fn export_member_snapshots(
+        env: Env,
+        admin: Address,
+        offset: u32,
+        limit: u32,
+    ) -> Result<Vec<MemberSnapshot>, Error> {
+        MembershipTokenContract::require_admin(&env, &admin)?;
+
+        let end = offset
+            .saturating_add(limit.min(MAX_SNAPSHOT_PAGE))
+            .min(Self::get_member_count(&env));
+        let mut snapshots = Vec::new(&env);
+        for position in offset..end {
+            if let Some(user) = env
+                .storage()
+                .persistent()
+                .get::<_, Address>(&SnapshotDataKey::MemberAt(position))
+            {
+                snapshots.push_back(Self::export_member_snapshot(&env, &user));
+            }
+        }
+        Ok(snapshots)
+    }
+}
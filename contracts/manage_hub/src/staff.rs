@@ -0,0 +1,384 @@
+use crate::attendance_log::AttendanceLogModule;
+use crate::errors::{Error, ModuleError};
+use crate::events::{
+    PayrollPaid, ShiftRecorded, StaffActiveSet, StaffRateUpdated, StaffRegistered,
+};
+use crate::membership_token::MembershipTokenContract;
+use crate::staff_errors::StaffError;
+use crate::subscription::SubscriptionContract;
+use crate::treasury::TreasuryModule;
+use crate::types::{AttendanceAction, PayrollPayment, StaffMember, StaffShift};
+use common_types::{Amount, DateRange};
+use soroban_sdk::{contracttype, token, Address, BytesN, Env, Vec};
+
+// ---------------------------------------------------------------------------
+// Storage keys
+// ---------------------------------------------------------------------------
+
+#[contracttype]
+pub enum StaffDataKey {
+    /// Staff member by address (persistent storage).
+    StaffMember(Address),
+    /// Addresses of every registered staff member (persistent storage).
+    StaffRoster,
+    /// Shift keyed by its clock-in log ID (persistent storage).
+    StaffShift(BytesN<32>),
+    /// Attendance logs already used in a shift (persistent storage).
+    ShiftLog(BytesN<32>),
+    /// Clock-in log IDs of a staff member's unpaid shifts (persistent
+    /// storage).
+    UnpaidShifts(Address),
+    /// ID the next payroll payment receives (instance storage).
+    NextPayrollId,
+    /// Payroll payments made to a staff member (persistent storage).
+    PayrollPayments(Address),
+}
+
+// ---------------------------------------------------------------------------
+// Constants
+// ---------------------------------------------------------------------------
+
+/// Upper bound on registered staff, keeping payroll settlement bounded.
+pub const MAX_STAFF: u32 = 100;
+
+const SECONDS_PER_HOUR: i128 = 3_600;
+
+// ---------------------------------------------------------------------------
+// Module
+// ---------------------------------------------------------------------------
+
+/// Staff registry and shift-based payroll.
+///
+/// The admin registers staff with an hourly USDC rate and records shifts
+/// from their clock-in and clock-out attendance logs; each shift's pay is
+/// fixed at the rate in force when it is recorded. Settling a period pays
+/// every staff member for their unpaid shifts ending in it from the
+/// contract's USDC balance and keeps a payment record.
+pub struct StaffModule;
+
+impl StaffModule {
+    pub fn register_staff(
+        env: Env,
+        admin: Address,
+        staff: Address,
+        hourly_rate: i128,
+    ) -> Result<StaffMember, ModuleError> {
+        MembershipTokenContract::require_admin(&env, &admin)?;
+        if hourly_rate <= 0 {
+            return Err(StaffError::InvalidRate.into());
+        }
+
+        let key = StaffDataKey::StaffMember(staff.clone());
+        if env.storage().persistent().has(&key) {
+            return Err(StaffError::StaffAlreadyRegistered.into());
+        }
+        let mut roster = Self::get_roster(&env);
+        if roster.len() >= MAX_STAFF {
+            return Err(StaffError::TooManyStaff.into());
+        }
+        roster.push_back(staff.clone());
+        env.storage()
+            .persistent()
+            .set(&StaffDataKey::StaffRoster, &roster);
+
+        let member = StaffMember {
+            address: staff.clone(),
+            hourly_rate,
+            active: true,
+            registered_at: env.ledger().timestamp(),
+        };
+        env.storage().persistent().set(&key, &member);
+
+        StaffRegistered { staff, hourly_rate }.publish(&env);
+        Ok(member)
+    }
+
+    /// Changes a staff member's hourly rate. Shifts already recorded keep
+    /// the rate they were recorded at.
+    pub fn set_hourly_rate(
+        env: Env,
+        admin: Address,
+        staff: Address,
+        hourly_rate: i128,
+    ) -> Result<(), ModuleError> {
+        MembershipTokenContract::require_admin(&env, &admin)?;
+        if hourly_rate <= 0 {
+            return Err(StaffError::InvalidRate.into());
+        }
+        let mut member = Self::get_staff(&env, &staff)?;
+        member.hourly_rate = hourly_rate;
+        env.storage()
+            .persistent()
+            .set(&StaffDataKey::StaffMember(staff.clone()), &member);
+
+        StaffRateUpdated { staff, hourly_rate }.publish(&env);
+        Ok(())
+    }
+
+    /// Activates or deactivates a staff member. Unpaid shifts are still
+    /// paid out while inactive.
+    pub fn set_staff_active(
+        env: Env,
+        admin: Address,
+        staff: Address,
+        active: bool,
+    ) -> Result<(), ModuleError> {
+        MembershipTokenContract::require_admin(&env, &admin)?;
+        let mut member = Self::get_staff(&env, &staff)?;
+        member.active = active;
+        env.storage()
+            .persistent()
+            .set(&StaffDataKey::StaffMember(staff.clone()), &member);
+
+        StaffActiveSet { staff, active }.publish(&env);
+        Ok(())
+    }
+
+    pub fn get_staff(env: &Env, staff: &Address) -> Result<StaffMember, ModuleError> {
+        env.storage()
+            .persistent()
+            .get(&StaffDataKey::StaffMember(staff.clone()))
+            .ok_or(StaffError::StaffNotFound.into())
+    }
+
+    pub fn get_roster(env: &Env) -> Vec<Address> {
+        env.storage()
+            .persistent()
+            .get(&StaffDataKey::StaffRoster)
+            .unwrap_or_else(|| Vec::new(env))
+    }
+
+    /// Records a shift from a staff member's clock-in log and the clock-out
+    /// log that ended it. Admin only; each log can only be used once.
+    pub fn record_shift(
+        env: Env,
+        admin: Address,
+        clock_in_log: BytesN<32>,
+        clock_out_log: BytesN<32>,
+    ) -> Result<StaffShift, ModuleError> {
+        MembershipTokenContract::require_admin(&env, &admin)?;
+
+        let clock_in = AttendanceLogModule::get_attendance_log(env.clone(), clock_in_log.clone())
+            .ok_or(StaffError::LogNotFound)?;
+        let clock_out = AttendanceLogModule::get_attendance_log(env.clone(), clock_out_log.clone())
+            .ok_or(StaffError::LogNotFound)?;
+        if clock_in.action != AttendanceAction::ClockIn
+            || clock_out.action != AttendanceAction::ClockOut
+            || clock_in.user_id != clock_out.user_id
+            || clock_out.timestamp <= clock_in.timestamp
+        {
+            return Err(StaffError::InvalidShift.into());
+        }
+
+        let member = Self::get_staff(&env, &clock_in.user_id)?;
+        if !member.active {
+            return Err(StaffError::StaffInactive.into());
+        }
+        for log_id in [&clock_in_log, &clock_out_log] {
+            if env
+                .storage()
+                .persistent()
+                .has(&StaffDataKey::ShiftLog(log_id.clone()))
+            {
+                return Err(StaffError::ShiftAlreadyRecorded.into());
+            }
+        }
+
+        let worked = (clock_out.timestamp - clock_in.timestamp) as i128;
+        let amount = Amount::new(member.hourly_rate)
+            .and_then(|rate| rate.mul_div(worked, SECONDS_PER_HOUR))
+            .ok_or(StaffError::Overflow)?
+            .value();
+
+        let shift = StaffShift {
+            staff: member.address.clone(),
+            clock_in_log: clock_in_log.clone(),
+            clock_out_log: clock_out_log.clone(),
+            start_time: clock_in.timestamp,
+            end_time: clock_out.timestamp,
+            hourly_rate: member.hourly_rate,
+            amount,
+            paid: false,
+        };
+        env.storage()
+            .persistent()
+            .set(&StaffDataKey::StaffShift(clock_in_log.clone()), &shift);
+        for log_id in [&clock_in_log, &clock_out_log] {
+            env.storage()
+                .persistent()
+                .set(&StaffDataKey::ShiftLog(log_id.clone()), &true);
+        }
+        let mut unpaid = Self::unpaid_shift_ids(&env, &member.address);
+        unpaid.push_back(clock_in_log);
+        env.storage()
+            .persistent()
+            .set(&StaffDataKey::UnpaidShifts(member.address.clone()), &unpaid);
+
+        ShiftRecorded {
+            staff: member.address,
+            start_time: shift.start_time,
+            end_time: shift.end_time,
+            amount,
+        }
+        .publish(&env);
+        Ok(shift)
+    }
+
+    /// Unpaid shifts of a staff member.
+    pub fn get_unpaid_shifts(env: &Env, staff: &Address) -> Vec<StaffShift> {
+        let mut shifts = Vec::new(env);
+        for log_id in Self::unpaid_shift_ids(env, staff).iter() {
+            if let Some(shift) = env
+                .storage()
+                .persistent()
+                .get(&StaffDataKey::StaffShift(log_id))
+            {
+                shifts.push_back(shift);
+            }
+        }
+        shifts
+    }
+
+    /// Pay owed to a staff member for unpaid shifts ending in `period`.
+    pub fn get_payroll_due(
+        env: &Env,
+        staff: &Address,
+        period: &DateRange,
+    ) -> Result<i128, ModuleError> {
+        let mut due = Amount::ZERO;
+        for shift in Self::get_unpaid_shifts(env, staff).iter() {
+            if Self::in_period(&shift, period) {
+                due = Amount::new(shift.amount)
+                    .and_then(|amount| due.checked_add(amount))
+                    .ok_or(StaffError::Overflow)?;
+            }
+        }
+        Ok(due.value())
+    }
+
+    /// Pays every staff member for their unpaid shifts ending in `period`,
+    /// which must have ended, from the contract's USDC balance. Admin only;
+    /// returns the payments made.
+    pub fn settle_payroll(
+        env: Env,
+        admin: Address,
+        period: DateRange,
+    ) -> Result<Vec<PayrollPayment>, ModuleError> {
+        MembershipTokenContract::require_admin(&env, &admin)?;
+        let now = env.ledger().timestamp();
+        if period.end_time <= period.start_time || period.end_time > now {
+            return Err(StaffError::InvalidPeriod.into());
+        }
+
+        let roster = Self::get_roster(&env);
+        let mut total = Amount::ZERO;
+        for staff in roster.iter() {
+            let due = Amount::new(Self::get_payroll_due(&env, &staff, &period)?)
+                .ok_or(StaffError::Overflow)?;
+            total = total.checked_add(due).ok_or(StaffError::Overflow)?;
+        }
+        let mut payments = Vec::new(&env);
+        if total.is_zero() {
+            return Ok(payments);
+        }
+
+        let usdc = SubscriptionContract::get_usdc_contract_address(&env)?;
+        let token_client = token::Client::new(&env, &usdc);
+        let treasury = env.current_contract_address();
+        if token_client.balance(&treasury) < total.value() {
+            return Err(Error::InsufficientBalance.into());
+        }
+        // Payroll is paid from collected revenue only, never from stakes,
+        // deposits or renewal pools held by the contract
+        TreasuryModule::spend_collected(&env, &usdc, total.value())?;
+
+        for staff in roster.iter() {
+            let mut remaining = Vec::new(&env);
+            let mut paid_shifts = 0u32;
+            let mut worked_secs = 0u64;
+            let mut amount = 0i128;
+            for log_id in Self::unpaid_shift_ids(&env, &staff).iter() {
+                let shift_key = StaffDataKey::StaffShift(log_id.clone());
+                let Some(mut shift) = env.storage().persistent().get::<_, StaffShift>(&shift_key)
+                else {
+                    continue;
+                };
+                if !Self::in_period(&shift, &period) {
+                    remaining.push_back(log_id);
+                    continue;
+                }
+                shift.paid = true;
+                env.storage().persistent().set(&shift_key, &shift);
+                paid_shifts += 1;
+                worked_secs += shift.end_time - shift.start_time;
+                amount += shift.amount;
+            }
+            if paid_shifts == 0 {
+                continue;
+            }
+            env.storage()
+                .persistent()
+                .set(&StaffDataKey::UnpaidShifts(staff.clone()), &remaining);
+
+            if amount > 0 {
+                token_client.transfer(&treasury, &staff, &amount);
+            }
+
+            let id: u64 = env
+                .storage()
+                .instance()
+                .get(&StaffDataKey::NextPayrollId)
+                .unwrap_or(0);
+            env.storage()
+                .instance()
+                .set(&StaffDataKey::NextPayrollId, &(id + 1));
+
+            let payment = PayrollPayment {
+                id,
+                staff: staff.clone(),
+                period: period.clone(),
+                shifts: paid_shifts,
+                worked_secs,
+                amount,
+                paid_at: now,
+            };
+            let history_key = StaffDataKey::PayrollPayments(staff.clone());
+            let mut history: Vec<PayrollPayment> = env
+                .storage()
+                .persistent()
+                .get(&history_key)
+                .unwrap_or_else(|| Vec::new(&env));
+            history.push_back(payment.clone());
+            env.storage().persistent().set(&history_key, &history);
+
+            PayrollPaid {
+                staff,
+                payment_id: id,
+                amount,
+                shifts: paid_shifts,
+            }
+            .publish(&env);
+            payments.push_back(payment);
+        }
+        Ok(payments)
+    }
+
+    pub fn get_payroll_payments(env: &Env, staff: &Address) -> Vec<PayrollPayment> {
+        env.storage()
+            .persistent()
+            .get(&StaffDataKey::PayrollPayments(staff.clone()))
+            .unwrap_or_else(|| Vec::new(env))
+    }
+
+    fn unpaid_shift_ids(env: &Env, staff: &Address) -> Vec<BytesN<32>> {
+        env.storage()
+            .persistent()
+            .get(&StaffDataKey::UnpaidShifts(staff.clone()))
+            .unwrap_or_else(|| Vec::new(env))
+    }
+
+    /// Shifts belong to the period their clock-out falls in.
+    fn in_period(shift: &StaffShift, period: &DateRange) -> bool {
+        shift.end_time >= period.start_time && shift.end_time < period.end_time
+    }
+}
@@ -0,0 +1,32 @@
+//! Staff and payroll error types for the ManageHub contract.
+//!
+//! `StaffError` owns the code range 1500–1549 (see [`crate::errors`]).
+
+use soroban_sdk::contracterror;
+
+/// Staff-specific errors.
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum StaffError {
+    /// The address is not registered as staff.
+    StaffNotFound = 1500,
+    /// The address is already registered as staff.
+    StaffAlreadyRegistered = 1501,
+    /// The staff roster is full.
+    TooManyStaff = 1502,
+    /// The hourly rate is not positive.
+    InvalidRate = 1503,
+    /// The staff member has been deactivated.
+    StaffInactive = 1504,
+    /// No attendance log exists with the given id.
+    LogNotFound = 1505,
+    /// The logs are not a clock-in followed by a clock-out of the same
+    /// staff member.
+    InvalidShift = 1506,
+    /// One of the logs is already part of a recorded shift.
+    ShiftAlreadyRecorded = 1507,
+    /// The payroll period is empty or has not ended yet.
+    InvalidPeriod = 1508,
+    /// Arithmetic overflow while computing pay.
+    Overflow = 1509,
+}
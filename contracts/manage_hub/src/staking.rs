@@ -1,10 +1,22 @@
-#![allow(deprecated)]
-
-use crate::errors::Error;
-use crate::membership_token::DataKey as MembershipDataKey;
+use crate::blacklist::BlacklistModule;
+use crate::errors::{Error, ModuleError};
+use crate::events::{
+    EmergencyUnstaked, StakeSlashed, Staked, StakingTierCreated, UnstakeCancelled,
+    UnstakeRequested, Unstaked,
+};
+use crate::initialization::InitializationModule;
+use crate::membership_token::{DataKey as MembershipDataKey, MembershipTokenContract};
+use crate::outbox::OutboxModule;
 use crate::staking_errors::StakingError;
-use crate::types::{StakeInfo, StakingConfig, StakingTier};
-use soroban_sdk::{contracttype, token, Address, Env, String, Vec};
+use crate::stats::StatsModule;
+use crate::treasury::TreasuryModule;
+use crate::types::{
+    BlacklistOperation, OutboxSubject, RevenueSource, SlashRecord, StakeInfo, StakingConfig,
+    StakingTier, UnstakeRequest,
+};
+use crate::verification::VerificationModule;
+use common_types::{Amount, Bps};
+use soroban_sdk::{contracttype, symbol_short, token, Address, Env, String, Vec};
 
 // ---------------------------------------------------------------------------
 // Storage keys
@@ -20,6 +32,10 @@ pub enum StakingDataKey {
     Tier(String),
     /// Active stake per staker address (persistent storage).
     Stake(Address),
+    /// Slashes applied to a staker, oldest first (persistent storage).
+    SlashHistory(Address),
+    /// Pending cooldown unstake per staker (persistent storage).
+    UnstakeRequest(Address),
 }
 
 // ---------------------------------------------------------------------------
@@ -29,6 +45,16 @@ pub enum StakingDataKey {
 /// Keep stake records for ~30 days.
 const STAKE_TTL_LEDGERS: u32 = 518_400;
 
+// ---------------------------------------------------------------------------
+// Slashing limits
+// ---------------------------------------------------------------------------
+
+/// Longest accepted slash reason, in bytes.
+const MAX_SLASH_REASON_LENGTH: u32 = 200;
+
+/// Slash records kept per staker; the oldest are dropped first.
+const MAX_SLASH_HISTORY: u32 = 50;
+
 // ---------------------------------------------------------------------------
 // Module
 // ---------------------------------------------------------------------------
@@ -45,7 +71,7 @@ impl StakingModule {
         env: Env,
         admin: Address,
         config: StakingConfig,
-    ) -> Result<(), Error> {
+    ) -> Result<(), ModuleError> {
         let stored_admin: Address = env
             .storage()
             .instance()
@@ -53,12 +79,10 @@ impl StakingModule {
             .ok_or(Error::AdminNotSet)?;
         stored_admin.require_auth();
         if stored_admin != admin {
-            return Err(Error::Unauthorized);
+            return Err(Error::Unauthorized.into());
         }
 
-        if config.emergency_unstake_penalty_bps > 10_000 {
-            return Err(Error::InvalidPaymentAmount);
-        }
+        Self::validate_config(&config)?;
 
         env.storage()
             .instance()
@@ -66,8 +90,20 @@ impl StakingModule {
         Ok(())
     }
 
+    /// Reject configurations whose penalty or slash cap exceeds 100 %.
+    pub(crate) fn validate_config(config: &StakingConfig) -> Result<(), StakingError> {
+        if config.emergency_unstake_penalty_bps > 10_000 || config.max_slash_bps > 10_000 {
+            return Err(StakingError::InvalidConfig);
+        }
+        Ok(())
+    }
+
     /// Create a new staking tier. Admin only.
-    pub fn create_staking_tier(env: Env, admin: Address, tier: StakingTier) -> Result<(), Error> {
+    pub fn create_staking_tier(
+        env: Env,
+        admin: Address,
+        tier: StakingTier,
+    ) -> Result<(), ModuleError> {
         let stored_admin: Address = env
             .storage()
             .instance()
@@ -75,17 +111,17 @@ impl StakingModule {
             .ok_or(Error::AdminNotSet)?;
         stored_admin.require_auth();
         if stored_admin != admin {
-            return Err(Error::Unauthorized);
+            return Err(Error::Unauthorized.into());
         }
 
         if tier.min_stake_amount <= 0 {
-            return Err(Error::InvalidPaymentAmount);
+            return Err(StakingError::InvalidTier.into());
         }
         if tier.reward_multiplier_bps == 0 {
-            return Err(Error::InvalidPaymentAmount);
+            return Err(StakingError::InvalidTier.into());
         }
         if tier.base_rate_bps == 0 || tier.base_rate_bps > 10_000 {
-            return Err(Error::InvalidPaymentAmount);
+            return Err(StakingError::InvalidTier.into());
         }
 
         if env
@@ -93,7 +129,7 @@ impl StakingModule {
             .persistent()
             .has(&StakingDataKey::Tier(tier.id.clone()))
         {
-            return Err(Error::TierAlreadyExists);
+            return Err(StakingError::TierAlreadyExists.into());
         }
 
         env.storage()
@@ -111,13 +147,13 @@ impl StakingModule {
             .instance()
             .set(&StakingDataKey::TierList, &list);
 
-        env.events().publish(
-            (
-                String::from_str(&env, "StakingTierCreated"),
-                tier.id.clone(),
-            ),
-            env.ledger().timestamp(),
-        );
+        StakingTierCreated {
+            tier_id: tier.id.clone(),
+            min_stake_amount: tier.min_stake_amount,
+            lock_duration: tier.lock_duration,
+            timestamp: env.ledger().timestamp(),
+        }
+        .publish(&env);
 
         Ok(())
     }
@@ -128,14 +164,15 @@ impl StakingModule {
 
     /// Lock `amount` tokens in the specified staking tier.
     ///
-    /// Emits: `Staked(staker, amount, tier_id, unlock_at)`
+    /// Emits: `Staked { staker, tier_id, amount, total_staked, unlock_at }`
     pub fn stake_tokens(
         env: Env,
         staker: Address,
         tier_id: String,
         amount: i128,
-    ) -> Result<(), Error> {
+    ) -> Result<(), ModuleError> {
         staker.require_auth();
+        BlacklistModule::require_not_blacklisted(&env, BlacklistOperation::Staking, &staker)?;
 
         let config = Self::get_config(&env)?;
         if !config.staking_enabled {
@@ -144,6 +181,10 @@ impl StakingModule {
 
         let tier = Self::get_tier_internal(&env, &tier_id)?;
 
+        if Self::get_unstake_request(env.clone(), staker.clone()).is_some() {
+            return Err(StakingError::UnstakeAlreadyRequested.into());
+        }
+
         if amount < tier.min_stake_amount {
             return Err(StakingError::BelowMinimumStake.into());
         }
@@ -160,12 +201,13 @@ impl StakingModule {
                 .storage()
                 .persistent()
                 .get(&StakingDataKey::Stake(staker.clone()))
-                .ok_or(Error::TokenNotFound)?;
+                .ok_or(StakingError::StakeNotFound)?;
 
             // Require the existing stake to use the same tier.
             if existing.tier_id != tier_id {
-                return Err(Error::Unauthorized);
+                return Err(StakingError::TierMismatch.into());
             }
+            VerificationModule::check_stake(&env, &staker, existing.amount.saturating_add(amount))?;
 
             // Pull tokens from user.
             let token_client = token::Client::new(&env, &config.staking_token);
@@ -189,19 +231,31 @@ impl StakingModule {
                 unlock_at,
                 claimed_rewards: existing.claimed_rewards,
                 emergency_unstaked: false,
+                paused_secs: existing.paused_secs,
             };
 
             Self::save_stake(&env, &staker, &updated);
-
-            env.events().publish(
-                (String::from_str(&env, "Staked"), staker.clone(), tier_id),
-                (new_amount, unlock_at),
+            StatsModule::record_staked(&env, amount);
+            OutboxModule::append(
+                &env,
+                symbol_short!("stake"),
+                OutboxSubject::Account(staker.clone()),
             );
 
+            Staked {
+                staker: staker.clone(),
+                tier_id,
+                amount,
+                total_staked: new_amount,
+                unlock_at,
+            }
+            .publish(&env);
+
             return Ok(());
         }
 
         // New stake.
+        VerificationModule::check_stake(&env, &staker, amount)?;
         let token_client = token::Client::new(&env, &config.staking_token);
         token_client.transfer(&staker, env.current_contract_address(), &amount);
 
@@ -218,28 +272,43 @@ impl StakingModule {
             unlock_at,
             claimed_rewards: 0,
             emergency_unstaked: false,
+            paused_secs: 0,
         };
 
         Self::save_stake(&env, &staker, &stake);
-
-        env.events().publish(
-            (String::from_str(&env, "Staked"), staker.clone(), tier_id),
-            (amount, unlock_at),
+        StatsModule::record_staked(&env, amount);
+        OutboxModule::append(
+            &env,
+            symbol_short!("stake"),
+            OutboxSubject::Account(staker.clone()),
         );
 
+        Staked {
+            staker: staker.clone(),
+            tier_id,
+            amount,
+            total_staked: amount,
+            unlock_at,
+        }
+        .publish(&env);
+
         Ok(())
     }
 
     /// Unlock tokens after the lock period has elapsed.
     ///
     /// Pending rewards are calculated and transferred together with the
-    /// principal amount.
+    /// principal amount. Not available while an unstake cooldown is
+    /// configured; use `request_unstake` instead.
     ///
-    /// Emits: `Unstaked(staker, amount, rewards)`
-    pub fn unstake_tokens(env: Env, staker: Address) -> Result<(), Error> {
+    /// Emits: `Unstaked { staker, amount, rewards }`
+    pub fn unstake_tokens(env: Env, staker: Address) -> Result<(), ModuleError> {
         staker.require_auth();
 
         let config = Self::get_config(&env)?;
+        if config.unstake_cooldown > 0 {
+            return Err(StakingError::CooldownRequired.into());
+        }
 
         let stake: StakeInfo = env
             .storage()
@@ -252,28 +321,146 @@ impl StakingModule {
             return Err(StakingError::StillLocked.into());
         }
 
-        let rewards = crate::rewards::RewardsModule::calculate_pending_rewards(&env, &stake)?;
+        Self::pay_out(&env, &config, &staker, &stake)
+    }
+
+    /// Start the unstake cooldown for a stake whose lock has expired.
+    /// Rewards stop accruing from this point.
+    ///
+    /// Emits: `UnstakeRequested { staker, amount, available_at }`
+    pub fn request_unstake(env: Env, staker: Address) -> Result<UnstakeRequest, ModuleError> {
+        staker.require_auth();
+
+        let config = Self::get_config(&env)?;
+        if config.unstake_cooldown == 0 {
+            return Err(StakingError::CooldownNotEnabled.into());
+        }
+
+        let stake: StakeInfo = env
+            .storage()
+            .persistent()
+            .get(&StakingDataKey::Stake(staker.clone()))
+            .ok_or(StakingError::StakeNotFound)?;
+
+        let now = env.ledger().timestamp();
+        if now < stake.unlock_at {
+            return Err(StakingError::StillLocked.into());
+        }
+        if Self::get_unstake_request(env.clone(), staker.clone()).is_some() {
+            return Err(StakingError::UnstakeAlreadyRequested.into());
+        }
+
+        let request = UnstakeRequest {
+            staker: staker.clone(),
+            requested_at: now,
+            available_at: now
+                .checked_add(config.unstake_cooldown)
+                .ok_or(StakingError::Overflow)?,
+        };
+        Self::save_unstake_request(&env, &request);
+
+        UnstakeRequested {
+            staker,
+            amount: stake.amount,
+            available_at: request.available_at,
+        }
+        .publish(&env);
+
+        Ok(request)
+    }
+
+    /// Cancel a pending unstake; the stake resumes accruing rewards and the
+    /// cooldown time is never rewarded.
+    ///
+    /// Emits: `UnstakeCancelled { staker, timestamp }`
+    pub fn cancel_unstake_request(env: Env, staker: Address) -> Result<(), ModuleError> {
+        staker.require_auth();
+
+        let request = Self::get_unstake_request(env.clone(), staker.clone())
+            .ok_or(StakingError::UnstakeRequestNotFound)?;
+        let mut stake: StakeInfo = env
+            .storage()
+            .persistent()
+            .get(&StakingDataKey::Stake(staker.clone()))
+            .ok_or(StakingError::StakeNotFound)?;
+
+        let now = env.ledger().timestamp();
+        stake.paused_secs = stake
+            .paused_secs
+            .checked_add(now.saturating_sub(request.requested_at))
+            .ok_or(StakingError::Overflow)?;
+        Self::save_stake(&env, &staker, &stake);
+        env.storage()
+            .persistent()
+            .remove(&StakingDataKey::UnstakeRequest(staker.clone()));
+
+        UnstakeCancelled {
+            staker,
+            timestamp: now,
+        }
+        .publish(&env);
+
+        Ok(())
+    }
+
+    /// Release principal and the rewards accrued up to the request once the
+    /// cooldown has elapsed.
+    ///
+    /// Emits: `Unstaked { staker, amount, rewards }`
+    pub fn withdraw_unstaked(env: Env, staker: Address) -> Result<(), ModuleError> {
+        staker.require_auth();
+
+        let config = Self::get_config(&env)?;
+        let request = Self::get_unstake_request(env.clone(), staker.clone())
+            .ok_or(StakingError::UnstakeRequestNotFound)?;
+        if env.ledger().timestamp() < request.available_at {
+            return Err(StakingError::CooldownActive.into());
+        }
+
+        let stake: StakeInfo = env
+            .storage()
+            .persistent()
+            .get(&StakingDataKey::Stake(staker.clone()))
+            .ok_or(StakingError::StakeNotFound)?;
+
+        Self::pay_out(&env, &config, &staker, &stake)
+    }
+
+    /// Transfer principal plus pending rewards and close the stake.
+    fn pay_out(
+        env: &Env,
+        config: &StakingConfig,
+        staker: &Address,
+        stake: &StakeInfo,
+    ) -> Result<(), ModuleError> {
+        let rewards = crate::rewards::RewardsModule::calculate_pending_rewards(env, stake)?;
 
         // Return principal.
-        let token_client = token::Client::new(&env, &config.staking_token);
-        token_client.transfer(&env.current_contract_address(), &staker, &stake.amount);
+        let token_client = token::Client::new(env, &config.staking_token);
+        token_client.transfer(&env.current_contract_address(), staker, &stake.amount);
 
         // Distribute rewards from reward pool.
         if rewards > 0 {
-            let reward_client = token::Client::new(&env, &config.reward_pool);
-            reward_client.transfer(&env.current_contract_address(), &staker, &rewards);
+            let reward_client = token::Client::new(env, &config.reward_pool);
+            reward_client.transfer(&env.current_contract_address(), staker, &rewards);
         }
 
         // Clean up stake record.
-        env.storage()
-            .persistent()
-            .remove(&StakingDataKey::Stake(staker.clone()));
-
-        env.events().publish(
-            (String::from_str(&env, "Unstaked"), staker.clone()),
-            (stake.amount, rewards),
+        Self::remove_stake(env, staker);
+        StatsModule::record_staked(env, -stake.amount);
+        OutboxModule::append(
+            env,
+            symbol_short!("unstake"),
+            OutboxSubject::Account(staker.clone()),
         );
 
+        Unstaked {
+            staker: staker.clone(),
+            amount: stake.amount,
+            rewards,
+        }
+        .publish(env);
+
         Ok(())
     }
 
@@ -282,8 +469,8 @@ impl StakingModule {
     /// The penalty is burned / kept in the contract; the remainder is returned
     /// to the staker. No rewards are paid.
     ///
-    /// Emits: `EmergencyUnstaked(staker, amount_returned, penalty)`
-    pub fn emergency_unstake(env: Env, staker: Address) -> Result<(), Error> {
+    /// Emits: `EmergencyUnstaked { staker, amount_returned, penalty }`
+    pub fn emergency_unstake(env: Env, staker: Address) -> Result<(), ModuleError> {
         staker.require_auth();
 
         let config = Self::get_config(&env)?;
@@ -294,17 +481,15 @@ impl StakingModule {
             .get(&StakingDataKey::Stake(staker.clone()))
             .ok_or(StakingError::StakeNotFound)?;
 
-        let penalty = stake
-            .amount
-            .checked_mul(config.emergency_unstake_penalty_bps as i128)
-            .ok_or(StakingError::Overflow)?
-            .checked_div(10_000)
+        let staked = Amount::new(stake.amount).ok_or(StakingError::Overflow)?;
+        let penalty = Bps::new(config.emergency_unstake_penalty_bps)
+            .and_then(|bps| staked.mul_bps(bps))
             .ok_or(StakingError::Overflow)?;
-
-        let amount_returned = stake
-            .amount
+        let amount_returned = staked
             .checked_sub(penalty)
-            .ok_or(StakingError::Overflow)?;
+            .ok_or(StakingError::Overflow)?
+            .value();
+        let penalty = penalty.value();
 
         let token_client = token::Client::new(&env, &config.staking_token);
 
@@ -313,25 +498,152 @@ impl StakingModule {
             token_client.transfer(&env.current_contract_address(), &staker, &amount_returned);
         }
 
-        // Penalty stays in the contract (acts as a disincentive).
+        // Penalty stays in the contract (acts as a disincentive) and is booked
+        // as treasury revenue.
+        TreasuryModule::record_collected(
+            &env,
+            RevenueSource::Penalty,
+            &config.staking_token,
+            penalty,
+        )?;
 
         // Clean up stake record.
-        env.storage()
+        Self::remove_stake(&env, &staker);
+        StatsModule::record_staked(&env, -stake.amount);
+        OutboxModule::append(
+            &env,
+            symbol_short!("unstake"),
+            OutboxSubject::Account(staker.clone()),
+        );
+
+        EmergencyUnstaked {
+            staker: staker.clone(),
+            amount_returned,
+            penalty,
+        }
+        .publish(&env);
+
+        Ok(())
+    }
+
+    // -----------------------------------------------------------------------
+    // Admin – slashing
+    // -----------------------------------------------------------------------
+
+    /// Slash `bps` of an active stake into the treasury for a policy
+    /// violation. Admin only; when an access_control contract is registered
+    /// it must also authorize, so slashes go through a multisig proposal.
+    ///
+    /// A stake slashed to zero is closed.
+    ///
+    /// Emits: `StakeSlashed { staker, bps, amount, remaining, reason, slashed_by }`
+    pub fn slash_stake(
+        env: Env,
+        admin: Address,
+        staker: Address,
+        bps: u32,
+        reason: String,
+    ) -> Result<i128, ModuleError> {
+        MembershipTokenContract::require_admin(&env, &admin)?;
+        if let Some(multisig) = InitializationModule::get_access_control(&env) {
+            multisig.require_auth();
+        }
+
+        let config = Self::get_config(&env)?;
+        if bps == 0 || bps > config.max_slash_bps {
+            return Err(StakingError::SlashExceedsLimit.into());
+        }
+        if reason.is_empty() || reason.len() > MAX_SLASH_REASON_LENGTH {
+            return Err(StakingError::InvalidSlashReason.into());
+        }
+
+        let mut stake: StakeInfo = env
+            .storage()
             .persistent()
-            .remove(&StakingDataKey::Stake(staker.clone()));
+            .get(&StakingDataKey::Stake(staker.clone()))
+            .ok_or(StakingError::StakeNotFound)?;
 
-        env.events().publish(
-            (String::from_str(&env, "EmergencyUnstaked"), staker.clone()),
-            (amount_returned, penalty),
+        let staked = Amount::new(stake.amount).ok_or(StakingError::Overflow)?;
+        let slashed = Bps::new(bps)
+            .and_then(|bps| staked.mul_bps(bps))
+            .ok_or(StakingError::Overflow)?;
+        stake.amount = staked
+            .checked_sub(slashed)
+            .ok_or(StakingError::Overflow)?
+            .value();
+        let amount = slashed.value();
+
+        // Slashed tokens stay in the contract and are booked as treasury
+        // revenue, like emergency-unstake penalties.
+        TreasuryModule::record_collected(
+            &env,
+            RevenueSource::Penalty,
+            &config.staking_token,
+            amount,
+        )?;
+        StatsModule::record_staked(&env, -amount);
+        OutboxModule::append(
+            &env,
+            symbol_short!("slash"),
+            OutboxSubject::Account(staker.clone()),
         );
 
-        Ok(())
+        if stake.amount == 0 {
+            Self::remove_stake(&env, &staker);
+        } else {
+            Self::save_stake(&env, &staker, &stake);
+        }
+
+        let history_key = StakingDataKey::SlashHistory(staker.clone());
+        let mut history: Vec<SlashRecord> = env
+            .storage()
+            .persistent()
+            .get(&history_key)
+            .unwrap_or_else(|| Vec::new(&env));
+        if history.len() >= MAX_SLASH_HISTORY {
+            history.pop_front();
+        }
+        history.push_back(SlashRecord {
+            bps,
+            amount,
+            reason: reason.clone(),
+            slashed_by: admin.clone(),
+            slashed_at: env.ledger().timestamp(),
+        });
+        env.storage().persistent().set(&history_key, &history);
+
+        StakeSlashed {
+            staker,
+            bps,
+            amount,
+            remaining: stake.amount,
+            reason,
+            slashed_by: admin,
+        }
+        .publish(&env);
+
+        Ok(amount)
     }
 
     // -----------------------------------------------------------------------
     // Queries
     // -----------------------------------------------------------------------
 
+    /// Return the pending cooldown unstake for a staker, if any.
+    pub fn get_unstake_request(env: Env, staker: Address) -> Option<UnstakeRequest> {
+        env.storage()
+            .persistent()
+            .get(&StakingDataKey::UnstakeRequest(staker))
+    }
+
+    /// Return the slashes applied to a staker, oldest first.
+    pub fn get_slash_history(env: Env, staker: Address) -> Vec<SlashRecord> {
+        env.storage()
+            .persistent()
+            .get(&StakingDataKey::SlashHistory(staker))
+            .unwrap_or_else(|| Vec::new(&env))
+    }
+
     /// Return the active stake for a staker, or `None` if not staking.
     pub fn get_stake_info(env: Env, staker: Address) -> Option<StakeInfo> {
         env.storage()
@@ -361,7 +673,7 @@ impl StakingModule {
     }
 
     /// Return the global staking configuration.
-    pub fn get_staking_config(env: Env) -> Result<StakingConfig, Error> {
+    pub fn get_staking_config(env: Env) -> Result<StakingConfig, ModuleError> {
         Self::get_config(&env)
     }
 
@@ -369,20 +681,41 @@ impl StakingModule {
     // Internal helpers
     // -----------------------------------------------------------------------
 
-    fn get_config(env: &Env) -> Result<StakingConfig, Error> {
+    fn get_config(env: &Env) -> Result<StakingConfig, ModuleError> {
         env.storage()
             .instance()
             .get(&StakingDataKey::Config)
             .ok_or(StakingError::StakingNotConfigured.into())
     }
 
-    pub(crate) fn get_tier_internal(env: &Env, tier_id: &String) -> Result<StakingTier, Error> {
+    pub(crate) fn get_tier_internal(
+        env: &Env,
+        tier_id: &String,
+    ) -> Result<StakingTier, ModuleError> {
         env.storage()
             .persistent()
             .get(&StakingDataKey::Tier(tier_id.clone()))
             .ok_or(StakingError::TierNotFound.into())
     }
 
+    /// Drop a stake together with any pending unstake request.
+    fn remove_stake(env: &Env, staker: &Address) {
+        env.storage()
+            .persistent()
+            .remove(&StakingDataKey::Stake(staker.clone()));
+        env.storage()
+            .persistent()
+            .remove(&StakingDataKey::UnstakeRequest(staker.clone()));
+    }
+
+    fn save_unstake_request(env: &Env, request: &UnstakeRequest) {
+        let key = StakingDataKey::UnstakeRequest(request.staker.clone());
+        env.storage().persistent().set(&key, request);
+        env.storage()
+            .persistent()
+            .extend_ttl(&key, STAKE_TTL_LEDGERS, STAKE_TTL_LEDGERS);
+    }
+
     fn save_stake(env: &Env, staker: &Address, stake: &StakeInfo) {
         env.storage()
             .persistent()
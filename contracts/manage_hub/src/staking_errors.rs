@@ -1,43 +1,47 @@
 //! Staking-related error types for the ManageHub contract.
 //!
-//! A dedicated `StakingError` enum is used because the main `Error` enum is
-//! already at the 50-variant XDR limit imposed by `#[contracterror]`.
-//!
-//! The [`From`] impl bridges `StakingError` into `Error` (reusing existing
-//! numeric codes) so that `?` propagation works in functions returning
-//! `Result<_, Error>`.
+//! `StakingError` owns the code range 100–149 (see [`crate::errors`]).
 
-use crate::errors::Error;
+use soroban_sdk::contracterror;
 
 /// Staking-specific errors.
-#[derive(Debug)]
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
 pub enum StakingError {
     /// Staking is currently disabled by the admin.
-    StakingDisabled,
+    StakingDisabled = 100,
     /// No active stake found for this staker.
-    StakeNotFound,
+    StakeNotFound = 101,
     /// The lock period has not elapsed yet; use emergency_unstake if urgent.
-    StillLocked,
+    StillLocked = 102,
     /// The requested staking tier does not exist.
-    TierNotFound,
+    TierNotFound = 103,
     /// The stake amount is below the minimum for the chosen tier.
-    BelowMinimumStake,
+    BelowMinimumStake = 104,
     /// Staking configuration has not been initialised.
-    StakingNotConfigured,
+    StakingNotConfigured = 105,
     /// Arithmetic overflow during reward calculation.
-    Overflow,
-}
-
-impl From<StakingError> for Error {
-    fn from(e: StakingError) -> Self {
-        match e {
-            StakingError::StakingDisabled => Error::SubscriptionNotActive,
-            StakingError::StakeNotFound => Error::TokenNotFound,
-            StakingError::StillLocked => Error::PauseTooEarly,
-            StakingError::TierNotFound => Error::TierNotFound,
-            StakingError::BelowMinimumStake => Error::InvalidPaymentAmount,
-            StakingError::StakingNotConfigured => Error::AdminNotSet,
-            StakingError::Overflow => Error::TimestampOverflow,
-        }
-    }
+    Overflow = 106,
+    /// Slash basis points are zero or above the configured maximum.
+    SlashExceedsLimit = 107,
+    /// A slash was requested without a reason, or the reason is too long.
+    InvalidSlashReason = 108,
+    /// Unstaking goes through the cooldown queue; use request_unstake.
+    CooldownRequired = 109,
+    /// The unstake cooldown is disabled; use unstake_tokens.
+    CooldownNotEnabled = 110,
+    /// The staker already has a pending unstake request.
+    UnstakeAlreadyRequested = 111,
+    /// The staker has no pending unstake request.
+    UnstakeRequestNotFound = 112,
+    /// The unstake cooldown has not elapsed yet.
+    CooldownActive = 113,
+    /// The penalty or slash cap of a staking configuration exceeds 100 %.
+    InvalidConfig = 114,
+    /// A staking tier's minimum stake, multiplier or base rate is invalid.
+    InvalidTier = 115,
+    /// A staking tier with the same ID already exists.
+    TierAlreadyExists = 116,
+    /// The staker already has a stake in a different tier.
+    TierMismatch = 117,
 }
@@ -0,0 +1,246 @@
+use crate::treasury::TreasuryModule;
+use crate::types::{ContractStats, MembershipStatus, OperationalMetrics};
+use soroban_sdk::{contracttype, Env, Map};
+
+// ---------------------------------------------------------------------------
+// Storage keys
+// ---------------------------------------------------------------------------
+
+#[contracttype]
+#[derive(Clone)]
+pub enum StatsCounter {
+    TokensIssued,
+    TokensActive,
+    TokensExpired,
+    TotalStaked,
+    FractionalizedTokens,
+}
+
+#[contracttype]
+pub enum StatsDataKey {
+    /// Running total for one contract-wide counter (instance storage).
+    Counter(StatsCounter),
+    /// Number of subscriptions currently in the given status (instance storage).
+    SubscriptionsByStatus(MembershipStatus),
+    /// Tokens in a grace period, counted by the UTC day the grace period
+    /// ends; past days are dropped on write (persistent storage).
+    GraceEndings,
+    /// Failed auto-renewals counted by UTC day, covering the failure
+    /// window; older days are dropped on write (persistent storage).
+    AutoRenewalFailures,
+}
+
+// ---------------------------------------------------------------------------
+// Constants
+// ---------------------------------------------------------------------------
+
+const SECONDS_PER_DAY: u64 = 24 * 60 * 60;
+
+/// Days of auto-renewal failures reported by `get_operational_metrics`,
+/// counting today.
+pub const AUTO_RENEWAL_FAILURE_WINDOW_DAYS: u32 = 30;
+
+// ---------------------------------------------------------------------------
+// Module
+// ---------------------------------------------------------------------------
+
+/// Contract-wide counters maintained as state changes happen, so dashboards
+/// can read totals without indexing every event. Only changes made after
+/// the counters were introduced are reflected.
+pub struct StatsModule;
+
+impl StatsModule {
+    pub fn get_contract_stats(env: Env) -> ContractStats {
+        let subscriptions_active = Self::subscription_count(&env, MembershipStatus::Active);
+        let subscriptions_paused = Self::subscription_count(&env, MembershipStatus::Paused);
+        let subscriptions_inactive = Self::subscription_count(&env, MembershipStatus::Inactive);
+        let subscriptions_expired = Self::subscription_count(&env, MembershipStatus::Expired);
+
+        ContractStats {
+            tokens_issued: Self::get(&env, StatsCounter::TokensIssued) as u64,
+            tokens_active: Self::get(&env, StatsCounter::TokensActive) as u64,
+            tokens_expired: Self::get(&env, StatsCounter::TokensExpired) as u64,
+            total_subscriptions: subscriptions_active
+                + subscriptions_paused
+                + subscriptions_inactive
+                + subscriptions_expired,
+            subscriptions_active,
+            subscriptions_paused,
+            subscriptions_inactive,
+            subscriptions_expired,
+            total_staked: Self::get(&env, StatsCounter::TotalStaked),
+            fractionalized_tokens: Self::get(&env, StatsCounter::FractionalizedTokens) as u64,
+            lifetime_revenue: TreasuryModule::get_lifetime_revenue(env).total,
+        }
+    }
+
+    /// Health counters for operators. Grace periods and the failure window
+    /// are counted to the UTC day.
+    pub fn get_operational_metrics(env: Env) -> OperationalMetrics {
+        OperationalMetrics {
+            subscriptions_paused: Self::subscription_count(&env, MembershipStatus::Paused),
+            tokens_in_grace_period: Self::sum_days_from(
+                &env,
+                StatsDataKey::GraceEndings,
+                Self::today(&env),
+            ),
+            auto_renewal_failures: Self::sum_days_from(
+                &env,
+                StatsDataKey::AutoRenewalFailures,
+                Self::failure_window_start(&env),
+            ),
+            failure_window_days: AUTO_RENEWAL_FAILURE_WINDOW_DAYS,
+            measured_at: env.ledger().timestamp(),
+        }
+    }
+
+    pub(crate) fn record_token_issued(env: &Env) {
+        Self::adjust(env, StatsCounter::TokensIssued, 1);
+        Self::adjust(env, StatsCounter::TokensActive, 1);
+    }
+
+    /// An active token has lapsed into its grace period.
+    pub(crate) fn record_token_lapsed(env: &Env) {
+        Self::adjust(env, StatsCounter::TokensActive, -1);
+        Self::adjust(env, StatsCounter::TokensExpired, 1);
+    }
+
+    /// A lapsed token has been renewed back to active.
+    pub(crate) fn record_token_reinstated(env: &Env) {
+        Self::adjust(env, StatsCounter::TokensExpired, -1);
+        Self::adjust(env, StatsCounter::TokensActive, 1);
+    }
+
+    /// Move one subscription from `old` (or nowhere, when newly created) to `new`.
+    pub(crate) fn record_subscription_status(
+        env: &Env,
+        old: Option<&MembershipStatus>,
+        new: &MembershipStatus,
+    ) {
+        if old == Some(new) {
+            return;
+        }
+        if let Some(old) = old {
+            Self::adjust_key(env, StatsDataKey::SubscriptionsByStatus(old.clone()), -1);
+        }
+        Self::adjust_key(env, StatsDataKey::SubscriptionsByStatus(new.clone()), 1);
+    }
+
+    /// A token entered a grace period ending at `ends_at`.
+    pub(crate) fn record_grace_started(env: &Env, ends_at: u64) {
+        let today = Self::today(env);
+        Self::adjust_day(
+            env,
+            StatsDataKey::GraceEndings,
+            ends_at / SECONDS_PER_DAY,
+            1,
+            today,
+        );
+    }
+
+    /// A token left a grace period ending at `ends_at`.
+    pub(crate) fn record_grace_ended(env: &Env, ends_at: u64) {
+        let today = Self::today(env);
+        Self::adjust_day(
+            env,
+            StatsDataKey::GraceEndings,
+            ends_at / SECONDS_PER_DAY,
+            -1,
+            today,
+        );
+    }
+
+    pub(crate) fn record_auto_renewal_failure(env: &Env) {
+        Self::adjust_day(
+            env,
+            StatsDataKey::AutoRenewalFailures,
+            Self::today(env),
+            1,
+            Self::failure_window_start(env),
+        );
+    }
+
+    pub(crate) fn record_staked(env: &Env, delta: i128) {
+        Self::adjust(env, StatsCounter::TotalStaked, delta);
+    }
+
+    pub(crate) fn record_fractionalized(env: &Env, delta: i128) {
+        Self::adjust(env, StatsCounter::FractionalizedTokens, delta);
+    }
+
+    fn subscription_count(env: &Env, status: MembershipStatus) -> u64 {
+        env.storage()
+            .instance()
+            .get::<_, i128>(&StatsDataKey::SubscriptionsByStatus(status))
+            .unwrap_or(0) as u64
+    }
+
+    fn get(env: &Env, counter: StatsCounter) -> i128 {
+        env.storage()
+            .instance()
+            .get(&StatsDataKey::Counter(counter))
+            .unwrap_or(0)
+    }
+
+    fn today(env: &Env) -> u64 {
+        env.ledger().timestamp() / SECONDS_PER_DAY
+    }
+
+    fn failure_window_start(env: &Env) -> u64 {
+        (Self::today(env) + 1).saturating_sub(AUTO_RENEWAL_FAILURE_WINDOW_DAYS as u64)
+    }
+
+    fn sum_days_from(env: &Env, key: StatsDataKey, from_day: u64) -> u64 {
+        let days: Map<u64, u32> = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .unwrap_or_else(|| Map::new(env));
+        days.iter()
+            .filter(|(day, _)| *day >= from_day)
+            .map(|(_, count)| count as u64)
+            .sum()
+    }
+
+    /// Adjusts the count for `day` in a day-bucketed counter, dropping days
+    /// before `keep_from`. Changes to dropped days are ignored.
+    fn adjust_day(env: &Env, key: StatsDataKey, day: u64, delta: i64, keep_from: u64) {
+        let mut days: Map<u64, u32> = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .unwrap_or_else(|| Map::new(env));
+        for old_day in days.keys().iter() {
+            if old_day >= keep_from {
+                break;
+            }
+            days.remove(old_day);
+        }
+        if day >= keep_from {
+            let count = (days.get(day).unwrap_or(0) as i64 + delta).max(0) as u32;
+            if count == 0 {
+                days.remove(day);
+            } else {
+                days.set(day, count);
+            }
+        }
+
+        if days.is_empty() {
+            env.storage().persistent().remove(&key);
+        } else {
+            env.storage().persistent().set(&key, &days);
+        }
+    }
+
+    fn adjust(env: &Env, counter: StatsCounter, delta: i128) {
+        Self::adjust_key(env, StatsDataKey::Counter(counter), delta);
+    }
+
+    /// Counters never go below zero, since records that predate them may
+    /// still be removed.
+    fn adjust_key(env: &Env, key: StatsDataKey, delta: i128) {
+        let current: i128 = env.storage().instance().get(&key).unwrap_or(0);
+        let updated = current.saturating_add(delta).max(0);
+        env.storage().instance().set(&key, &updated);
+    }
+}
@@ -1,17 +1,49 @@
-// Allow deprecated events API until migration to #[contractevent] macro
-#![allow(deprecated)]
-
-use soroban_sdk::{contracttype, symbol_short, Address, BytesN, Env, Map, String, Vec};
+use soroban_sdk::xdr::ToXdr;
+use soroban_sdk::{
+    contracttype, symbol_short, Address, BytesN, Env, Map, String, Symbol, TryFromVal, Val, Vec,
+};
 
 use crate::attendance_log::AttendanceLogModule;
-use crate::errors::Error;
-use crate::membership_token::DataKey as MembershipTokenDataKey;
+use crate::billing::BillingModule;
+use crate::blacklist::BlacklistModule;
+use crate::bundles::BundleModule;
+use crate::config_errors::ConfigChangeError;
+use crate::errors::{Error, ModuleError};
+use crate::events::{
+    CreditApplied, FeatureOverrideGranted, FeatureOverrideRevoked, PromotionCreated,
+    ScheduledPauseCancelled, SubscriptionCancelled, SubscriptionCreated, SubscriptionExpired,
+    SubscriptionPauseScheduled, SubscriptionPaused, SubscriptionPriceMigrated,
+    SubscriptionReactivated, SubscriptionRenewed, SubscriptionResumed, TierChangeCancelled,
+    TierChangeCompleted, TierChangeCredited, TierChangeRequested, TierCreated, TierDeactivated,
+    TierDeprecated, TierDowngradeScheduled, TierSubscribersMigrated, TierUpdated,
+    UsdcChangeCancelled, UsdcChangeProposed, UsdcContractSet,
+};
+use crate::fees::FeeModule;
+use crate::loyalty::{LoyaltyModule, SECONDS_PER_DAY};
+use crate::membership_token::{DataKey as MembershipTokenDataKey, MembershipTokenContract};
+use crate::migration::MigrationModule;
+use crate::notifications::NotificationModule;
+use crate::outbox::OutboxModule;
+use crate::pause_schedule_errors::PauseScheduleError;
+use crate::payment_tokens::PaymentTokenModule;
+use crate::retention::RetentionModule;
+use crate::snapshot::SnapshotModule;
+use crate::stats::StatsModule;
+use crate::subscription_lifecycle::{SubscriptionEvent, SubscriptionLifecycle};
+use crate::tier_change_errors::TierChangeError;
+use crate::treasury::TreasuryModule;
+use crate::ttl::{TtlEntityClass, TtlModule};
 use crate::types::{
-    AttendanceAction, BillingCycle, CreatePromotionParams, CreateTierParams, MembershipStatus,
-    PauseAction, PauseConfig, PauseHistoryEntry, PauseStats, Subscription, SubscriptionTier,
-    TierAnalytics, TierChangeRequest, TierChangeStatus, TierChangeType, TierFeature, TierLevel,
-    TierPromotion, UpdateTierParams, UserSubscriptionInfo,
+    AttendanceAction, BillingCycle, BillingKind, BlacklistOperation, CancellationReason,
+    CreatePromotionParams, CreateTierParams, FeatureOverride, FeeBreakdown, GraceFeatureAccess,
+    LegacyPauseConfig, LegacyPauseHistoryEntry, MembershipStatus, NotificationKind, OutboxSubject,
+    PauseAction, PauseConfig, PauseHistoryEntry, PausePolicy, PauseStats, PendingUsdcChange,
+    PromotionAnalytics, RevenueSource, ScheduledPause, Subscription, SubscriptionTier,
+    TierAnalytics, TierChangeQuote, TierChangeRequest, TierChangeStatus, TierChangeType,
+    TierCohort, TierDeprecation, TierFeature, TierLevel, TierPromotion, TierRevenuePoint,
+    UpdateTierParams, UserSubscriptionInfo,
 };
+use common_types::{Amount, Bps, DateRange};
 
 #[contracttype]
 pub enum SubscriptionDataKey {
@@ -25,10 +57,93 @@ pub enum SubscriptionDataKey {
     TierPromotionList,
     TierChangeRequest(String),
     UserTierChangeHistory(Address),
+    /// Unspent tier-change credit for a user, in USDC base units
+    /// (persistent storage).
+    UserCredit(Address),
+    /// Id of the downgrade scheduled for a subscription's period end
+    /// (persistent storage).
+    ScheduledTierChange(String),
+    /// Id of the last tier change requested for a subscription
+    /// (persistent storage).
+    PendingTierChange(String),
+    /// When a subscription last changed tier (persistent storage).
+    LastTierChange(String),
+    /// Minimum seconds between tier changes on one subscription
+    /// (instance storage).
+    TierChangeCooldown,
+    /// Features granted to a single subscription outside its tier
+    /// (persistent storage).
+    FeatureOverrides(String),
     TierAnalytics(String),
     UserSubscriptionByTier(Address, String),
+    PendingUsdcContract,
+    /// Whether a tier honours subscribers' locked prices on renewal.
+    TierGrandfathered(String),
+    /// IDs of the subscriptions created for a user (persistent storage).
+    UserSubscriptions(Address),
+    /// IDs of the subscriptions currently on a tier, cancelled and lapsed
+    /// ones excluded (persistent storage).
+    TierSubscriptions(String),
+    /// Deprecation plan of a tier (persistent storage).
+    TierDeprecation(String),
+    /// Campaign counters of a promotion (persistent storage).
+    PromotionAnalytics(String),
+    /// Promotion a subscription was created with, and whether it has
+    /// renewed since (persistent storage).
+    SubscriptionPromotion(String),
+    /// Subscriptions created in a tier during an analytics period, and how
+    /// many of them have since churned, keyed by period index
+    /// (persistent storage).
+    TierCohort(String, u64),
+    /// Subscriptions of a tier that churned during an analytics period
+    /// (persistent storage).
+    TierPeriodChurn(String, u64),
+    /// Revenue booked for a tier during an analytics period
+    /// (persistent storage).
+    TierPeriodRevenue(String, u64),
+    /// Number of pause history entries recorded for a subscription
+    /// (persistent storage).
+    PauseHistory(String),
+    /// One append-only chunk of a subscription's pause history, keyed by
+    /// chunk index (persistent storage).
+    PauseHistoryChunk(String, u32),
+    /// Counter mixed into subscription attendance event IDs so each is
+    /// unique (instance storage).
+    EventNonce,
+    /// Pause a member booked in advance (persistent storage).
+    ScheduledPause(String),
+    /// IDs of the subscriptions with a scheduled pause not yet started or
+    /// ended (persistent storage).
+    ScheduledPauses,
 }
 
+/// Length of a tier analytics period (30 days).
+pub const ANALYTICS_PERIOD: u64 = 30 * 24 * 60 * 60;
+
+/// Analytics periods, counting the current one, that `churn_rate` covers.
+pub const CHURN_WINDOW_PERIODS: u64 = 3;
+
+/// Maximum analytics periods returned by one cohort or revenue query.
+pub const MAX_ANALYTICS_PERIODS: u64 = 24;
+
+/// Delay between proposing and confirming a USDC contract change (48 hours).
+const USDC_CHANGE_DELAY: u64 = 48 * 60 * 60;
+
+/// Pause history entries stored per chunk.
+pub const PAUSE_HISTORY_CHUNK_SIZE: u32 = 16;
+
+/// Most subscriptions `get_subscribers_for_tier` returns per call.
+pub const MAX_SUBSCRIBER_PAGE: u32 = 50;
+
+/// Most subscriptions one `migrate_deprecated_tier` call moves.
+pub const MAX_TIER_MIGRATION_BATCH: u32 = 25;
+
+/// Upper bound on scheduled pauses outstanding across all subscriptions.
+pub const MAX_SCHEDULED_PAUSES: u32 = 200;
+
+/// Most subscriptions one `apply_scheduled_pauses` call pauses or resumes.
+pub const MAX_SCHEDULED_PAUSE_BATCH: u32 = 25;
+
 pub struct SubscriptionContract;
 
 impl SubscriptionContract {
@@ -47,18 +162,128 @@ impl SubscriptionContract {
         Ok(())
     }
 
-    fn get_pause_config_or_default(env: &Env) -> PauseConfig {
+    /// Records `subscription_id` under `user` for per-user lookups.
+    pub(crate) fn index_user_subscription(env: &Env, user: &Address, subscription_id: &String) {
+        SnapshotModule::index_member(env, user);
+        let key = SubscriptionDataKey::UserSubscriptions(user.clone());
+        let mut ids: Vec<String> = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .unwrap_or_else(|| Vec::new(env));
+        ids.push_back(subscription_id.clone());
+        env.storage().persistent().set(&key, &ids);
+    }
+
+    /// IDs of the subscriptions created for `user`, oldest first.
+    pub(crate) fn get_user_subscription_ids(env: &Env, user: &Address) -> Vec<String> {
         env.storage()
+            .persistent()
+            .get(&SubscriptionDataKey::UserSubscriptions(user.clone()))
+            .unwrap_or_else(|| Vec::new(env))
+    }
+
+    /// Subscriptions created for `user`, oldest first, with their effective
+    /// status.
+    pub fn get_subscriptions_for_user(env: Env, user: Address) -> Vec<Subscription> {
+        let mut subscriptions = Vec::new(&env);
+        for id in Self::get_user_subscription_ids(&env, &user).iter() {
+            if let Ok(subscription) = Self::get_subscription_view(env.clone(), id) {
+                subscriptions.push_back(subscription);
+            }
+        }
+        subscriptions
+    }
+
+    /// Records `subscription_id` under `tier_id` for per-tier lookups.
+    /// Legacy subscriptions without a tier are not indexed.
+    pub(crate) fn index_tier_subscription(env: &Env, tier_id: &String, subscription_id: &String) {
+        if tier_id.is_empty() {
+            return;
+        }
+        let key = SubscriptionDataKey::TierSubscriptions(tier_id.clone());
+        let mut ids: Vec<String> = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .unwrap_or_else(|| Vec::new(env));
+        if !ids.contains(subscription_id) {
+            ids.push_back(subscription_id.clone());
+            env.storage().persistent().set(&key, &ids);
+        }
+    }
+
+    /// Removes `subscription_id` from `tier_id`'s index.
+    pub(crate) fn unindex_tier_subscription(env: &Env, tier_id: &String, subscription_id: &String) {
+        let key = SubscriptionDataKey::TierSubscriptions(tier_id.clone());
+        let Some(mut ids) = env.storage().persistent().get::<_, Vec<String>>(&key) else {
+            return;
+        };
+        if let Some(index) = ids.first_index_of(subscription_id) {
+            ids.remove(index);
+            if ids.is_empty() {
+                env.storage().persistent().remove(&key);
+            } else {
+                env.storage().persistent().set(&key, &ids);
+            }
+        }
+    }
+
+    /// A page of the subscriptions currently on `tier_id`, in the order they
+    /// joined the tier, with their effective status. `limit` is capped at
+    /// `MAX_SUBSCRIBER_PAGE`.
+    pub fn get_subscribers_for_tier(
+        env: Env,
+        tier_id: String,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<Subscription> {
+        let ids: Vec<String> = env
+            .storage()
+            .persistent()
+            .get(&SubscriptionDataKey::TierSubscriptions(tier_id))
+            .unwrap_or_else(|| Vec::new(&env));
+        let end = offset
+            .saturating_add(limit.min(MAX_SUBSCRIBER_PAGE))
+            .min(ids.len());
+        let mut page = Vec::new(&env);
+        for i in offset..end {
+            if let Ok(subscription) = Self::get_subscription_view(env.clone(), ids.get_unchecked(i))
+            {
+                page.push_back(subscription);
+            }
+        }
+        page
+    }
+
+    fn get_pause_config_or_default(env: &Env) -> PauseConfig {
+        let Some(fields) = env
+            .storage()
             .instance()
-            .get(&SubscriptionDataKey::PauseConfig)
-            .unwrap_or(PauseConfig {
+            .get::<_, Map<Symbol, Val>>(&SubscriptionDataKey::PauseConfig)
+        else {
+            return PauseConfig {
                 max_pause_duration: 2_592_000,
                 max_pause_count: 3,
                 min_active_time: 86_400,
-            })
+                policy: PausePolicy::ExtendExpiry,
+            };
+        };
+        if fields.contains_key(Symbol::new(env, "policy")) {
+            return PauseConfig::try_from_val(env, &fields.to_val()).unwrap();
+        }
+
+        // Configs saved before pause policies existed extend the expiry
+        let legacy = LegacyPauseConfig::try_from_val(env, &fields.to_val()).unwrap();
+        PauseConfig {
+            max_pause_duration: legacy.max_pause_duration,
+            max_pause_count: legacy.max_pause_count,
+            min_active_time: legacy.min_active_time,
+            policy: PausePolicy::ExtendExpiry,
+        }
     }
 
-    fn validate_pause_config(config: &PauseConfig) -> Result<(), Error> {
+    pub(crate) fn validate_pause_config(config: &PauseConfig) -> Result<(), Error> {
         if config.max_pause_duration == 0 {
             return Err(Error::InvalidPauseConfig);
         }
@@ -81,7 +306,7 @@ impl SubscriptionContract {
         Self::get_pause_config_or_default(&env)
     }
 
-    fn validate_payment(
+    pub(crate) fn validate_payment(
         env: &Env,
         payment_token: &Address,
         amount: i128,
@@ -92,13 +317,8 @@ impl SubscriptionContract {
             return Err(Error::InvalidPaymentAmount);
         }
 
-        // Get USDC token contract address from storage
-        let usdc_contract = Self::get_usdc_contract_address(env)?;
-
-        // Validate that the payment token is USDC
-        if payment_token != &usdc_contract {
-            return Err(Error::InvalidPaymentToken);
-        }
+        // Validate that the payment token is USDC or a whitelisted asset
+        PaymentTokenModule::rate_for(env, payment_token)?;
 
         // Note: Balance checking is omitted in this implementation.
         // In production, you would check the token balance using:
@@ -109,7 +329,6 @@ impl SubscriptionContract {
         Ok(true)
     }
 
-    #[allow(deprecated)]
     /// Creates a subscription without tier (legacy support).
     /// For new subscriptions, prefer `create_subscription_with_tier`.
     pub fn create_subscription(
@@ -119,14 +338,15 @@ impl SubscriptionContract {
         payment_token: Address,
         amount: i128,
         duration: u64,
-    ) -> Result<(), Error> {
+    ) -> Result<(), ModuleError> {
         // Require user authentication
         user.require_auth();
+        BlacklistModule::require_not_blacklisted(&env, BlacklistOperation::Subscription, &user)?;
 
         // Check if subscription already exists
         let key = SubscriptionDataKey::Subscription(id.clone());
         if env.storage().persistent().has(&key) {
-            return Err(Error::SubscriptionAlreadyExists);
+            return Err(Error::SubscriptionAlreadyExists.into());
         }
 
         // Validate payment first
@@ -159,21 +379,48 @@ impl SubscriptionContract {
             last_resumed_at: current_time,
             pause_count: 0,
             total_paused_duration: 0,
-            pause_history: Vec::new(&env),
+            locked_price: 0,
             tier_id: String::from_str(&env, ""),
             billing_cycle: BillingCycle::Monthly,
         };
 
         // Store and extend TTL with same key
         env.storage().persistent().set(&key, &subscription);
-        env.storage().persistent().extend_ttl(&key, 100, 1000);
+        TtlModule::bump(&env, TtlEntityClass::Subscription, &key);
+        MigrationModule::mark_subscription_current(&env, &id);
+        Self::index_user_subscription(&env, &user, &id);
+        StatsModule::record_subscription_status(&env, None, &MembershipStatus::Active);
+        OutboxModule::append(
+            &env,
+            symbol_short!("sub_new"),
+            OutboxSubject::Subscription(id.clone()),
+        );
 
-        // Emit subscription created event
-        env.events().publish(
-            (symbol_short!("sub_creat"), id.clone(), user.clone()),
-            (payment_token.clone(), amount, current_time, expires_at),
+        let base_amount = PaymentTokenModule::convert_to_base(&env, &payment_token, amount)?;
+        TreasuryModule::record_revenue(&env, RevenueSource::Subscription, base_amount)?;
+        BillingModule::record(
+            &env,
+            &id,
+            &user,
+            BillingKind::Create,
+            &payment_token,
+            &FeeModule::without_fees(amount),
+            None,
         );
 
+        // Emit subscription created event
+        SubscriptionCreated {
+            subscription_id: id.clone(),
+            user: user.clone(),
+            payment_token: payment_token.clone(),
+            amount,
+            tier_id: subscription.tier_id.clone(),
+            billing_cycle: subscription.billing_cycle.clone(),
+            created_at: current_time,
+            expires_at,
+        }
+        .publish(&env);
+
         // Log attendance event for subscription creation
         Self::log_subscription_event(
             &env,
@@ -187,12 +434,7 @@ impl SubscriptionContract {
     }
 
     pub fn pause_subscription(env: Env, id: String, reason: Option<String>) -> Result<(), Error> {
-        let key = SubscriptionDataKey::Subscription(id.clone());
-        let subscription: Subscription = env
-            .storage()
-            .persistent()
-            .get(&key)
-            .ok_or(Error::SubscriptionNotFound)?;
+        let subscription = Self::get_subscription(env.clone(), id.clone())?;
 
         subscription.user.require_auth();
         let actor = subscription.user.clone();
@@ -207,17 +449,11 @@ impl SubscriptionContract {
     ) -> Result<(), Error> {
         Self::require_admin(&env, &admin)?;
 
-        let key = SubscriptionDataKey::Subscription(id.clone());
-        let subscription: Subscription = env
-            .storage()
-            .persistent()
-            .get(&key)
-            .ok_or(Error::SubscriptionNotFound)?;
+        let subscription = Self::get_subscription(env.clone(), id.clone())?;
 
         Self::pause_subscription_internal(env, id, subscription, admin, true, reason)
     }
 
-    #[allow(deprecated)]
     fn pause_subscription_internal(
         env: Env,
         id: String,
@@ -228,12 +464,7 @@ impl SubscriptionContract {
     ) -> Result<(), Error> {
         let current_time = env.ledger().timestamp();
 
-        if subscription.status == MembershipStatus::Paused {
-            return Err(Error::SubscriptionPaused);
-        }
-        if subscription.status != MembershipStatus::Active {
-            return Err(Error::SubscriptionNotActive);
-        }
+        SubscriptionLifecycle::next_status(&subscription.status, SubscriptionEvent::Pause)?;
         if current_time >= subscription.expires_at {
             return Err(Error::SubscriptionNotActive);
         }
@@ -250,8 +481,15 @@ impl SubscriptionContract {
             }
         }
 
-        subscription.status = MembershipStatus::Paused;
+        let old_status =
+            SubscriptionLifecycle::transition(&mut subscription, SubscriptionEvent::Pause)?;
         subscription.paused_at = Some(current_time);
+        StatsModule::record_subscription_status(&env, Some(&old_status), &subscription.status);
+        OutboxModule::append(
+            &env,
+            symbol_short!("sub_pause"),
+            OutboxSubject::Subscription(id.clone()),
+        );
         subscription.pause_count = subscription.pause_count.saturating_add(1);
 
         let entry = PauseHistoryEntry {
@@ -262,21 +500,21 @@ impl SubscriptionContract {
             reason: reason.clone(),
             paused_duration: None,
             applied_extension: None,
+            policy: config.policy,
+            credited_days: 0,
         };
-        subscription.pause_history.push_back(entry.clone());
+        Self::append_pause_history(&env, &id, &entry);
 
         let key = SubscriptionDataKey::Subscription(id.clone());
         env.storage().persistent().set(&key, &subscription);
-        env.storage().persistent().extend_ttl(&key, 100, 1000);
+        TtlModule::bump(&env, TtlEntityClass::Subscription, &key);
 
-        env.events().publish(
-            (
-                symbol_short!("subscr"),
-                id.clone(),
-                subscription.user.clone(),
-            ),
+        SubscriptionPaused {
+            subscription_id: id.clone(),
+            user: subscription.user.clone(),
             entry,
-        );
+        }
+        .publish(&env);
 
         Self::log_subscription_event(
             &env,
@@ -290,12 +528,7 @@ impl SubscriptionContract {
     }
 
     pub fn resume_subscription(env: Env, id: String) -> Result<(), Error> {
-        let key = SubscriptionDataKey::Subscription(id.clone());
-        let subscription: Subscription = env
-            .storage()
-            .persistent()
-            .get(&key)
-            .ok_or(Error::SubscriptionNotFound)?;
+        let subscription = Self::get_subscription(env.clone(), id.clone())?;
 
         subscription.user.require_auth();
         let actor = subscription.user.clone();
@@ -305,17 +538,11 @@ impl SubscriptionContract {
     pub fn resume_subscription_admin(env: Env, id: String, admin: Address) -> Result<(), Error> {
         Self::require_admin(&env, &admin)?;
 
-        let key = SubscriptionDataKey::Subscription(id.clone());
-        let subscription: Subscription = env
-            .storage()
-            .persistent()
-            .get(&key)
-            .ok_or(Error::SubscriptionNotFound)?;
+        let subscription = Self::get_subscription(env.clone(), id.clone())?;
 
         Self::resume_subscription_internal(env, id, subscription, admin, true)
     }
 
-    #[allow(deprecated)]
     fn resume_subscription_internal(
         env: Env,
         id: String,
@@ -323,9 +550,7 @@ impl SubscriptionContract {
         actor: Address,
         is_admin: bool,
     ) -> Result<(), Error> {
-        if subscription.status != MembershipStatus::Paused {
-            return Err(Error::SubscriptionNotPaused);
-        }
+        SubscriptionLifecycle::next_status(&subscription.status, SubscriptionEvent::Resume)?;
 
         let paused_at = subscription.paused_at.ok_or(Error::SubscriptionNotPaused)?;
         let current_time = env.ledger().timestamp();
@@ -334,21 +559,37 @@ impl SubscriptionContract {
             .ok_or(Error::TimestampOverflow)?;
 
         let config = Self::get_pause_config_or_default(&env);
-        let applied_extension = if is_admin {
+        let compensated = if is_admin {
             paused_duration
         } else if paused_duration > config.max_pause_duration {
             config.max_pause_duration
         } else {
             paused_duration
         };
+        let (applied_extension, credited_days) = match config.policy {
+            PausePolicy::ExtendExpiry => (compensated, 0),
+            PausePolicy::CreditDays => (
+                0,
+                u32::try_from(compensated / SECONDS_PER_DAY).unwrap_or(u32::MAX),
+            ),
+            PausePolicy::NoCompensation => (0, 0),
+        };
+        LoyaltyModule::grant_day_credits(&env, &subscription.user, credited_days);
 
         subscription.expires_at = subscription
             .expires_at
             .checked_add(applied_extension)
             .ok_or(Error::TimestampOverflow)?;
-        subscription.status = MembershipStatus::Active;
+        let old_status =
+            SubscriptionLifecycle::transition(&mut subscription, SubscriptionEvent::Resume)?;
         subscription.paused_at = None;
         subscription.last_resumed_at = current_time;
+        StatsModule::record_subscription_status(&env, Some(&old_status), &subscription.status);
+        OutboxModule::append(
+            &env,
+            symbol_short!("sub_resum"),
+            OutboxSubject::Subscription(id.clone()),
+        );
         subscription.total_paused_duration = subscription
             .total_paused_duration
             .checked_add(paused_duration)
@@ -362,21 +603,22 @@ impl SubscriptionContract {
             reason: None,
             paused_duration: Some(paused_duration),
             applied_extension: Some(applied_extension),
+            policy: config.policy,
+            credited_days,
         };
-        subscription.pause_history.push_back(entry.clone());
+        Self::append_pause_history(&env, &id, &entry);
 
         let key = SubscriptionDataKey::Subscription(id.clone());
         env.storage().persistent().set(&key, &subscription);
-        env.storage().persistent().extend_ttl(&key, 100, 1000);
+        TtlModule::bump(&env, TtlEntityClass::Subscription, &key);
 
-        env.events().publish(
-            (
-                symbol_short!("subscr"),
-                id.clone(),
-                subscription.user.clone(),
-            ),
+        SubscriptionResumed {
+            subscription_id: id.clone(),
+            user: subscription.user.clone(),
             entry,
-        );
+            new_expires_at: subscription.expires_at,
+        }
+        .publish(&env);
 
         Self::log_subscription_event(
             &env,
@@ -390,8 +632,72 @@ impl SubscriptionContract {
     }
 
     pub fn get_pause_history(env: Env, id: String) -> Result<Vec<PauseHistoryEntry>, Error> {
-        let subscription = Self::get_subscription(env, id)?;
-        Ok(subscription.pause_history)
+        Self::get_subscription(env.clone(), id.clone())?;
+
+        let count = Self::pause_history_count(&env, &id);
+        let mut history = Vec::new(&env);
+        for chunk in 0..count.div_ceil(PAUSE_HISTORY_CHUNK_SIZE) {
+            let entries: Vec<Val> = env
+                .storage()
+                .persistent()
+                .get(&SubscriptionDataKey::PauseHistoryChunk(id.clone(), chunk))
+                .unwrap_or(Vec::new(&env));
+            for entry in entries.iter() {
+                if let Some(entry) = Self::decode_pause_entry(&env, entry) {
+                    history.push_back(entry);
+                }
+            }
+        }
+        Ok(history)
+    }
+
+    /// Decodes a stored pause history entry. Entries recorded before pause
+    /// policies existed read as `ExtendExpiry` with no day credits.
+    pub(crate) fn decode_pause_entry(env: &Env, entry: Val) -> Option<PauseHistoryEntry> {
+        let fields = Map::<Symbol, Val>::try_from_val(env, &entry).ok()?;
+        if fields.contains_key(Symbol::new(env, "policy")) {
+            return PauseHistoryEntry::try_from_val(env, &entry).ok();
+        }
+
+        let legacy = LegacyPauseHistoryEntry::try_from_val(env, &entry).ok()?;
+        Some(PauseHistoryEntry {
+            action: legacy.action,
+            timestamp: legacy.timestamp,
+            actor: legacy.actor,
+            is_admin: legacy.is_admin,
+            reason: legacy.reason,
+            paused_duration: legacy.paused_duration,
+            applied_extension: legacy.applied_extension,
+            policy: PausePolicy::ExtendExpiry,
+            credited_days: 0,
+        })
+    }
+
+    fn pause_history_count(env: &Env, id: &String) -> u32 {
+        env.storage()
+            .persistent()
+            .get(&SubscriptionDataKey::PauseHistory(id.clone()))
+            .unwrap_or(0)
+    }
+
+    /// Appends an entry to the last pause history chunk of a subscription,
+    /// starting a new chunk once the current one is full.
+    pub(crate) fn append_pause_history(env: &Env, id: &String, entry: &PauseHistoryEntry) {
+        let count = Self::pause_history_count(env, id);
+        let chunk_key =
+            SubscriptionDataKey::PauseHistoryChunk(id.clone(), count / PAUSE_HISTORY_CHUNK_SIZE);
+        let mut chunk: Vec<PauseHistoryEntry> = env
+            .storage()
+            .persistent()
+            .get(&chunk_key)
+            .unwrap_or(Vec::new(env));
+        chunk.push_back(entry.clone());
+        env.storage().persistent().set(&chunk_key, &chunk);
+        TtlModule::bump(env, TtlEntityClass::History, &chunk_key);
+
+        let count_key = SubscriptionDataKey::PauseHistory(id.clone());
+        env.storage().persistent().set(&count_key, &(count + 1));
+        TtlModule::bump(env, TtlEntityClass::History, &count_key);
     }
 
     pub fn get_pause_stats(env: Env, id: String) -> Result<PauseStats, Error> {
@@ -406,32 +712,320 @@ impl SubscriptionContract {
         })
     }
 
-    pub fn get_subscription(env: Env, id: String) -> Result<Subscription, Error> {
+    /// Books a pause for later. The member's pause allowance, the maximum
+    /// pause length and the minimum active time are checked against the
+    /// start time; the pause itself is applied by `apply_scheduled_pauses`.
+    pub fn schedule_pause(
+        env: Env,
+        id: String,
+        start_at: u64,
+        duration: u64,
+    ) -> Result<(), ModuleError> {
+        let subscription = Self::get_subscription(env.clone(), id.clone())?;
+        subscription.user.require_auth();
+
+        SubscriptionLifecycle::next_status(&subscription.status, SubscriptionEvent::Pause)?;
+        let now = env.ledger().timestamp();
+        if start_at <= now || start_at >= subscription.expires_at {
+            return Err(PauseScheduleError::InvalidStartTime.into());
+        }
+        let config = Self::get_pause_config_or_default(&env);
+        if duration == 0 || duration > config.max_pause_duration {
+            return Err(PauseScheduleError::InvalidDuration.into());
+        }
+        if subscription.pause_count >= config.max_pause_count {
+            return Err(Error::PauseCountExceeded.into());
+        }
+        if start_at.saturating_sub(subscription.last_resumed_at) < config.min_active_time {
+            return Err(Error::PauseTooEarly.into());
+        }
+
+        let key = SubscriptionDataKey::ScheduledPause(id.clone());
+        if env.storage().persistent().has(&key) {
+            return Err(PauseScheduleError::AlreadyScheduled.into());
+        }
+        let mut ids = Self::scheduled_pause_ids(&env);
+        if ids.len() >= MAX_SCHEDULED_PAUSES {
+            return Err(PauseScheduleError::TooManyScheduled.into());
+        }
+        ids.push_back(id.clone());
         env.storage()
             .persistent()
-            .get(&SubscriptionDataKey::Subscription(id))
-            .ok_or(Error::SubscriptionNotFound)
+            .set(&SubscriptionDataKey::ScheduledPauses, &ids);
+        env.storage().persistent().set(
+            &key,
+            &ScheduledPause {
+                subscription_id: id.clone(),
+                start_at,
+                duration,
+                scheduled_at: now,
+                applied_at: None,
+            },
+        );
+
+        SubscriptionPauseScheduled {
+            subscription_id: id,
+            user: subscription.user,
+            start_at,
+            duration,
+        }
+        .publish(&env);
+        Ok(())
     }
 
-    #[allow(deprecated)]
-    pub fn set_usdc_contract(env: Env, admin: Address, usdc_address: Address) -> Result<(), Error> {
-        admin.require_auth();
+    pub fn cancel_scheduled_pause(env: Env, id: String) -> Result<(), ModuleError> {
+        let subscription = Self::get_subscription(env.clone(), id.clone())?;
+        subscription.user.require_auth();
+
+        let scheduled = Self::get_scheduled_pause(env.clone(), id.clone())
+            .ok_or(PauseScheduleError::NotScheduled)?;
+        if scheduled.applied_at.is_some() || env.ledger().timestamp() >= scheduled.start_at {
+            return Err(PauseScheduleError::AlreadyStarted.into());
+        }
+        Self::remove_scheduled_pause(&env, &id);
+
+        ScheduledPauseCancelled {
+            subscription_id: id,
+            user: subscription.user,
+            start_at: scheduled.start_at,
+        }
+        .publish(&env);
+        Ok(())
+    }
 
-        // Check if admin is authorized (you might want to implement admin checking logic)
-        // For now, we'll store the USDC contract address
+    pub fn get_scheduled_pause(env: Env, id: String) -> Option<ScheduledPause> {
         env.storage()
-            .instance()
-            .set(&SubscriptionDataKey::UsdcContract, &usdc_address);
+            .persistent()
+            .get(&SubscriptionDataKey::ScheduledPause(id))
+    }
+
+    /// Pauses subscriptions whose scheduled pause has started and resumes
+    /// them once its duration has passed, handling at most `limit`
+    /// subscriptions. A pause that can no longer be applied (the
+    /// subscription was cancelled, or used up its pauses) is dropped, as is
+    /// one the member ended early by resuming.
+    pub fn apply_scheduled_pauses(env: Env, limit: u32) -> u32 {
+        let now = env.ledger().timestamp();
+        let mut budget = limit.min(MAX_SCHEDULED_PAUSE_BATCH);
+        let mut handled = 0u32;
+        let mut remaining = Vec::new(&env);
+
+        for id in Self::scheduled_pause_ids(&env).iter() {
+            let key = SubscriptionDataKey::ScheduledPause(id.clone());
+            let Some(mut scheduled) = env.storage().persistent().get::<_, ScheduledPause>(&key)
+            else {
+                continue;
+            };
+            let due_at = match scheduled.applied_at {
+                None => scheduled.start_at,
+                Some(_) => scheduled.start_at.saturating_add(scheduled.duration),
+            };
+            if budget == 0 || due_at > now {
+                remaining.push_back(id);
+                continue;
+            }
+            budget -= 1;
+            handled += 1;
+
+            let Ok(subscription) = Self::get_subscription(env.clone(), id.clone()) else {
+                env.storage().persistent().remove(&key);
+                continue;
+            };
+            let user = subscription.user.clone();
+            match scheduled.applied_at {
+                None => {
+                    let reason = Some(String::from_str(&env, "scheduled"));
+                    if Self::pause_subscription_internal(
+                        env.clone(),
+                        id.clone(),
+                        subscription,
+                        user,
+                        false,
+                        reason,
+                    )
+                    .is_ok()
+                    {
+                        scheduled.applied_at = Some(now);
+                        env.storage().persistent().set(&key, &scheduled);
+                        remaining.push_back(id);
+                    } else {
+                        env.storage().persistent().remove(&key);
+                    }
+                }
+                Some(applied_at) => {
+                    if subscription.status == MembershipStatus::Paused
+                        && subscription.paused_at == Some(applied_at)
+                    {
+                        // Best-effort: a failed resume leaves the member to
+                        // resume by hand
+                        let _ = Self::resume_subscription_internal(
+                            env.clone(),
+                            id.clone(),
+                            subscription,
+                            user,
+                            false,
+                        );
+                    }
+                    env.storage().persistent().remove(&key);
+                }
+            }
+        }
+
+        env.storage()
+            .persistent()
+            .set(&SubscriptionDataKey::ScheduledPauses, &remaining);
+        handled
+    }
+
+    fn scheduled_pause_ids(env: &Env) -> Vec<String> {
+        env.storage()
+            .persistent()
+            .get(&SubscriptionDataKey::ScheduledPauses)
+            .unwrap_or_else(|| Vec::new(env))
+    }
+
+    fn remove_scheduled_pause(env: &Env, id: &String) {
+        env.storage()
+            .persistent()
+            .remove(&SubscriptionDataKey::ScheduledPause(id.clone()));
+        let mut ids = Self::scheduled_pause_ids(env);
+        if let Some(index) = ids.first_index_of(id) {
+            ids.remove(index);
+            env.storage()
+                .persistent()
+                .set(&SubscriptionDataKey::ScheduledPauses, &ids);
+        }
+    }
 
-        // Emit USDC contract set event
-        env.events().publish(
-            (symbol_short!("usdc_set"), usdc_address.clone()),
-            (admin.clone(), env.ledger().timestamp()),
+    pub fn get_subscription(env: Env, id: String) -> Result<Subscription, Error> {
+        let subscription =
+            MigrationModule::load_subscription(&env, &id).ok_or(Error::SubscriptionNotFound)?;
+        TtlModule::bump(
+            &env,
+            TtlEntityClass::Subscription,
+            &SubscriptionDataKey::Subscription(id),
         );
+        Ok(subscription)
+    }
+
+    /// Sets the USDC contract for the first time. Once set, it can only be
+    /// changed through `propose_usdc_contract` / `confirm_usdc_contract`.
+    pub fn set_usdc_contract(
+        env: Env,
+        admin: Address,
+        usdc_address: Address,
+    ) -> Result<(), ModuleError> {
+        Self::require_admin(&env, &admin)?;
+
+        if env
+            .storage()
+            .instance()
+            .has(&SubscriptionDataKey::UsdcContract)
+        {
+            return Err(ConfigChangeError::ChangeRequiresProposal.into());
+        }
+
+        Self::store_usdc_contract(&env, &admin, None, &usdc_address);
+        Ok(())
+    }
+
+    /// Proposes replacing the configured USDC contract. The change becomes
+    /// confirmable after `USDC_CHANGE_DELAY` seconds.
+    pub fn propose_usdc_contract(
+        env: Env,
+        admin: Address,
+        new_usdc_address: Address,
+    ) -> Result<PendingUsdcChange, Error> {
+        Self::require_admin(&env, &admin)?;
+        let current = Self::get_usdc_contract_address(&env)?;
+
+        let now = env.ledger().timestamp();
+        let pending = PendingUsdcChange {
+            current: current.clone(),
+            proposed: new_usdc_address.clone(),
+            proposed_by: admin.clone(),
+            proposed_at: now,
+            executable_at: now
+                .checked_add(USDC_CHANGE_DELAY)
+                .ok_or(Error::TimestampOverflow)?,
+        };
+        env.storage()
+            .instance()
+            .set(&SubscriptionDataKey::PendingUsdcContract, &pending);
+
+        UsdcChangeProposed {
+            admin,
+            current,
+            proposed: new_usdc_address,
+            executable_at: pending.executable_at,
+        }
+        .publish(&env);
+
+        Ok(pending)
+    }
 
+    /// Applies a pending USDC contract change once its delay has elapsed.
+    pub fn confirm_usdc_contract(env: Env, admin: Address) -> Result<(), ModuleError> {
+        Self::require_admin(&env, &admin)?;
+        let pending =
+            Self::get_pending_usdc_change(&env).ok_or(ConfigChangeError::NoPendingChange)?;
+
+        if env.ledger().timestamp() < pending.executable_at {
+            return Err(ConfigChangeError::TimelockActive.into());
+        }
+
+        env.storage()
+            .instance()
+            .remove(&SubscriptionDataKey::PendingUsdcContract);
+        Self::store_usdc_contract(&env, &admin, Some(pending.current), &pending.proposed);
+        Ok(())
+    }
+
+    /// Discards a pending USDC contract change.
+    pub fn cancel_usdc_contract_change(env: Env, admin: Address) -> Result<(), ModuleError> {
+        Self::require_admin(&env, &admin)?;
+        let pending =
+            Self::get_pending_usdc_change(&env).ok_or(ConfigChangeError::NoPendingChange)?;
+
+        env.storage()
+            .instance()
+            .remove(&SubscriptionDataKey::PendingUsdcContract);
+
+        UsdcChangeCancelled {
+            admin,
+            current: pending.current,
+            proposed: pending.proposed,
+        }
+        .publish(&env);
         Ok(())
     }
 
+    pub fn get_pending_usdc_change(env: &Env) -> Option<PendingUsdcChange> {
+        env.storage()
+            .instance()
+            .get(&SubscriptionDataKey::PendingUsdcContract)
+    }
+
+    fn store_usdc_contract(
+        env: &Env,
+        admin: &Address,
+        previous: Option<Address>,
+        usdc_address: &Address,
+    ) {
+        env.storage()
+            .instance()
+            .set(&SubscriptionDataKey::UsdcContract, usdc_address);
+
+        // Emit before/after addresses so indexers can track payment token changes
+        UsdcContractSet {
+            admin: admin.clone(),
+            previous,
+            new: usdc_address.clone(),
+            timestamp: env.ledger().timestamp(),
+        }
+        .publish(env);
+    }
+
     pub fn get_usdc_contract_address(env: &Env) -> Result<Address, Error> {
         env.storage()
             .instance()
@@ -439,44 +1033,50 @@ impl SubscriptionContract {
             .ok_or(Error::UsdcContractNotSet)
     }
 
-    #[allow(deprecated)]
-    pub fn cancel_subscription(env: Env, id: String) -> Result<(), Error> {
+    /// Cancels a subscription, recording the user's survey answer for churn
+    /// analytics and attaching a win-back offer if one is configured.
+    pub fn cancel_subscription(
+        env: Env,
+        id: String,
+        reason: Option<CancellationReason>,
+    ) -> Result<(), Error> {
         let key = SubscriptionDataKey::Subscription(id.clone());
-        let mut subscription: Subscription = env
-            .storage()
-            .persistent()
-            .get(&key)
-            .ok_or(Error::SubscriptionNotFound)?;
+        let mut subscription = Self::get_subscription(env.clone(), id.clone())?;
 
         // Require authorization from the subscription owner
         subscription.user.require_auth();
 
-        // Capture old status for event emission
-        let old_status = subscription.status.clone();
-
-        // Update status to inactive
-        subscription.status = MembershipStatus::Inactive;
+        // Update status to inactive, capturing the old one for event emission
+        let old_status =
+            SubscriptionLifecycle::transition(&mut subscription, SubscriptionEvent::Cancel)?;
         subscription.paused_at = None;
         env.storage().persistent().set(&key, &subscription);
+        StatsModule::record_subscription_status(&env, Some(&old_status), &subscription.status);
+        OutboxModule::append(
+            &env,
+            symbol_short!("sub_cncl"),
+            OutboxSubject::Subscription(id.clone()),
+        );
+
+        if Self::counts_as_active(&old_status) {
+            Self::record_tier_churn(&env, &subscription);
+        }
+        Self::unindex_tier_subscription(&env, &subscription.tier_id, &id);
+        RetentionModule::on_cancel(&env, &subscription, reason);
 
         // Emit subscription cancelled event
-        env.events().publish(
-            (
-                symbol_short!("sub_cancl"),
-                id.clone(),
-                subscription.user.clone(),
-            ),
-            (
-                env.ledger().timestamp(),
-                old_status,
-                MembershipStatus::Inactive,
-            ),
-        );
+        SubscriptionCancelled {
+            subscription_id: id.clone(),
+            user: subscription.user.clone(),
+            old_status,
+            new_status: MembershipStatus::Inactive,
+            timestamp: env.ledger().timestamp(),
+        }
+        .publish(&env);
 
         Ok(())
     }
 
-    #[allow(deprecated)]
     /// Renews a subscription for additional duration.
     pub fn renew_subscription(
         env: Env,
@@ -484,24 +1084,56 @@ impl SubscriptionContract {
         payment_token: Address,
         amount: i128,
         duration: u64,
-    ) -> Result<(), Error> {
-        // Get existing subscription
+    ) -> Result<(), ModuleError> {
+        // Get existing subscription, upgrading its schema first if needed
         let key = SubscriptionDataKey::Subscription(id.clone());
+        MigrationModule::migrate_subscription(&env, &id);
         let mut subscription = Self::get_subscription(env.clone(), id.clone())?;
 
-        // Capture old expiry for event emission
+        // A lapsed subscription is expired first, so analytics see the churn
+        // before this renewal reactivates it
+        Self::apply_expiry(&env, &id, &mut subscription);
+
+        // Capture old expiry and status for event emission and analytics
         let old_expiry = subscription.expires_at;
+        let old_status = subscription.status.clone();
+        let was_active = Self::counts_as_active(&old_status);
 
         // Require authorization from subscription owner
         subscription.user.require_auth();
+        BlacklistModule::require_not_blacklisted(
+            &env,
+            BlacklistOperation::Subscription,
+            &subscription.user,
+        )?;
 
-        if subscription.status == MembershipStatus::Paused {
-            return Err(Error::SubscriptionPaused);
-        }
+        SubscriptionLifecycle::next_status(&subscription.status, SubscriptionEvent::Renew)?;
+
+        // A downgrade scheduled for the period end takes effect here, so the
+        // renewal is charged at the new tier's price
+        Self::apply_scheduled_downgrade(&env, &id, &mut subscription, was_active)?;
 
         // Validate payment
         Self::validate_payment(&env, &payment_token, amount, &subscription.user)?;
 
+        // Tiered subscriptions must cover their renewal price, less any
+        // tier-change credit the user holds, plus fees. Any overpayment is
+        // billed as part of the price.
+        let mut charge = FeeModule::without_fees(amount);
+        if let Some(price) = Self::renewal_price(&env, &subscription)? {
+            let price = Self::apply_credit(&env, &subscription.user, price);
+            let usdc = FeeModule::breakdown_for(&env, &subscription.user, price)?;
+            let due = FeeModule::convert(&env, &payment_token, &subscription.tier_id, &usdc)?;
+            if amount < due.total {
+                return Err(Error::InvalidPaymentAmount.into());
+            }
+            charge = FeeBreakdown {
+                base: due.base + (amount - due.total),
+                total: amount,
+                ..due
+            };
+        }
+
         // Note: Token transfer is omitted in this implementation.
         // In production, you would transfer tokens using:
         // let token_client = token::Client::new(&env, &payment_token);
@@ -516,35 +1148,57 @@ impl SubscriptionContract {
             current_time
         };
 
+        // Redeemed loyalty day credits are applied on top of the paid period
+        let credit = LoyaltyModule::take_day_credits(&env, &subscription.user);
         subscription.expires_at = renewal_base
             .checked_add(duration)
+            .and_then(|expiry| expiry.checked_add(credit))
             .ok_or(Error::TimestampOverflow)?;
-        subscription.status = MembershipStatus::Active;
+        SubscriptionLifecycle::transition(&mut subscription, SubscriptionEvent::Renew)?;
         subscription.amount = amount;
+        StatsModule::record_subscription_status(&env, Some(&old_status), &subscription.status);
+        OutboxModule::append(
+            &env,
+            symbol_short!("sub_renew"),
+            OutboxSubject::Subscription(id.clone()),
+        );
 
         // Store updated subscription and extend TTL
         env.storage().persistent().set(&key, &subscription);
-        env.storage().persistent().extend_ttl(&key, 100, 1000);
+        TtlModule::bump(&env, TtlEntityClass::Subscription, &key);
+
+        let base_amount = PaymentTokenModule::convert_to_base(&env, &payment_token, amount)?;
+        TreasuryModule::record_revenue(&env, RevenueSource::Renewal, base_amount)?;
+        BillingModule::record(
+            &env,
+            &id,
+            &subscription.user,
+            BillingKind::Renew,
+            &payment_token,
+            &charge,
+            None,
+        );
+        LoyaltyModule::on_renewal(&env, &subscription.user, &subscription.tier_id);
 
         // Update tier analytics if subscription has a tier
         if !subscription.tier_id.is_empty() {
-            let _ = Self::update_tier_analytics_on_subscribe(&env, &subscription.tier_id, amount);
+            Self::record_tier_revenue(&env, &subscription.tier_id, base_amount);
+            Self::record_promotion_renewal(&env, &id, base_amount);
+            if !was_active {
+                Self::record_tier_reactivation(&env, &subscription);
+            }
         }
 
         // Emit subscription renewed event
-        env.events().publish(
-            (
-                symbol_short!("sub_renew"),
-                id.clone(),
-                subscription.user.clone(),
-            ),
-            (
-                payment_token.clone(),
-                amount,
-                old_expiry,
-                subscription.expires_at,
-            ),
-        );
+        SubscriptionRenewed {
+            subscription_id: id.clone(),
+            user: subscription.user.clone(),
+            payment_token: payment_token.clone(),
+            amount,
+            old_expiry,
+            new_expiry: subscription.expires_at,
+        }
+        .publish(&env);
 
         // Log attendance event for subscription renewal
         Self::log_subscription_event(
@@ -558,6 +1212,169 @@ impl SubscriptionContract {
         Ok(())
     }
 
+    /// Brings a cancelled or expired tiered subscription back under its
+    /// original ID. The owner pays the tier's current price for
+    /// `billing_cycle`, less any win-back offer and credit they hold, plus
+    /// fees. Pause counters start over, while the creation date, billing
+    /// history and tier cohort are kept, so analytics count a reactivation
+    /// rather than a new subscriber.
+    pub fn reactivate_subscription(
+        env: Env,
+        id: String,
+        payment_token: Address,
+        billing_cycle: BillingCycle,
+    ) -> Result<(), ModuleError> {
+        let key = SubscriptionDataKey::Subscription(id.clone());
+        MigrationModule::migrate_subscription(&env, &id);
+        let mut subscription = Self::get_subscription(env.clone(), id.clone())?;
+        Self::apply_expiry(&env, &id, &mut subscription);
+
+        // Only the original owner can bring the subscription back
+        subscription.user.require_auth();
+        BlacklistModule::require_not_blacklisted(
+            &env,
+            BlacklistOperation::Subscription,
+            &subscription.user,
+        )?;
+        SubscriptionLifecycle::next_status(&subscription.status, SubscriptionEvent::Reactivate)?;
+
+        let tier = Self::get_tier(env.clone(), subscription.tier_id.clone())?;
+        if !tier.is_active {
+            return Err(Error::TierNotActive.into());
+        }
+        let list_price = match billing_cycle {
+            BillingCycle::Monthly => tier.price,
+            BillingCycle::Annual => tier.annual_price,
+        };
+        let price = RetentionModule::redeem_winback(&env, &subscription.user, list_price)?;
+        let price = Self::apply_credit(&env, &subscription.user, price);
+        let usdc = FeeModule::breakdown_for(&env, &subscription.user, price)?;
+        let charge = FeeModule::convert(&env, &payment_token, &tier.id, &usdc)?;
+        Self::validate_payment(&env, &payment_token, charge.total, &subscription.user)?;
+
+        let now = env.ledger().timestamp();
+        let old_status =
+            SubscriptionLifecycle::transition(&mut subscription, SubscriptionEvent::Reactivate)?;
+        subscription.payment_token = payment_token.clone();
+        subscription.amount = charge.total;
+        subscription.expires_at = now
+            .checked_add(Self::cycle_duration(&billing_cycle))
+            .ok_or(Error::TimestampOverflow)?;
+        subscription.billing_cycle = billing_cycle.clone();
+        subscription.locked_price = list_price;
+        subscription.paused_at = None;
+        subscription.last_resumed_at = now;
+        subscription.pause_count = 0;
+        subscription.total_paused_duration = 0;
+
+        env.storage().persistent().set(&key, &subscription);
+        TtlModule::bump(&env, TtlEntityClass::Subscription, &key);
+        StatsModule::record_subscription_status(&env, Some(&old_status), &subscription.status);
+        OutboxModule::append(
+            &env,
+            symbol_short!("sub_react"),
+            OutboxSubject::Subscription(id.clone()),
+        );
+
+        Self::index_tier_subscription(&env, &tier.id, &id);
+        Self::record_tier_reactivation(&env, &subscription);
+        Self::record_tier_revenue(&env, &tier.id, price);
+        TreasuryModule::record_revenue(&env, RevenueSource::Subscription, price)?;
+        BillingModule::record(
+            &env,
+            &id,
+            &subscription.user,
+            BillingKind::Reactivate,
+            &payment_token,
+            &charge,
+            None,
+        );
+        LoyaltyModule::set_member_tier(&env, &subscription.user, &tier.id);
+
+        SubscriptionReactivated {
+            subscription_id: id.clone(),
+            user: subscription.user.clone(),
+            payment_token,
+            amount: charge.total,
+            tier_id: tier.id,
+            billing_cycle,
+            expires_at: subscription.expires_at,
+        }
+        .publish(&env);
+
+        Ok(Self::log_subscription_event(
+            &env,
+            &subscription.user,
+            String::from_str(&env, "subscription_reactivated"),
+            &id,
+            charge.total,
+        )?)
+    }
+
+    // ============================================================================
+    // Price Lock (Grandfathering) Functions
+    // ============================================================================
+
+    /// USDC price due on the next renewal of a tiered subscription: the locked
+    /// price while the tier grandfathers existing subscribers, otherwise the
+    /// tier's current price. `None` for legacy subscriptions without a tier.
+    pub fn renewal_price(env: &Env, subscription: &Subscription) -> Result<Option<i128>, Error> {
+        if subscription.tier_id.is_empty() {
+            return Ok(None);
+        }
+        if subscription.locked_price > 0 && Self::is_tier_grandfathered(env, &subscription.tier_id)
+        {
+            return Ok(Some(subscription.locked_price));
+        }
+        let tier = Self::get_tier(env.clone(), subscription.tier_id.clone())?;
+        Ok(Some(match subscription.billing_cycle {
+            BillingCycle::Monthly => tier.price,
+            BillingCycle::Annual => tier.annual_price,
+        }))
+    }
+
+    pub fn is_tier_grandfathered(env: &Env, tier_id: &String) -> bool {
+        env.storage()
+            .persistent()
+            .get(&SubscriptionDataKey::TierGrandfathered(tier_id.clone()))
+            .unwrap_or(false)
+    }
+
+    /// Moves a subscription off its locked price onto the tier's current
+    /// price. Admin only. The new price applies from the next renewal, and a
+    /// notice event carries the old price, new price and effective date.
+    pub fn migrate_subscription_pricing(
+        env: Env,
+        admin: Address,
+        subscription_id: String,
+    ) -> Result<i128, Error> {
+        Self::require_admin(&env, &admin)?;
+
+        let key = SubscriptionDataKey::Subscription(subscription_id.clone());
+        let mut subscription = Self::get_subscription(env.clone(), subscription_id.clone())?;
+        let tier = Self::get_tier(env.clone(), subscription.tier_id.clone())?;
+
+        let old_price = subscription.locked_price;
+        let new_price = match subscription.billing_cycle {
+            BillingCycle::Monthly => tier.price,
+            BillingCycle::Annual => tier.annual_price,
+        };
+        subscription.locked_price = new_price;
+        env.storage().persistent().set(&key, &subscription);
+        TtlModule::bump(&env, TtlEntityClass::Subscription, &key);
+
+        SubscriptionPriceMigrated {
+            subscription_id,
+            user: subscription.user.clone(),
+            old_price,
+            new_price,
+            effective_at: subscription.expires_at,
+        }
+        .publish(&env);
+
+        Ok(new_price)
+    }
+
     /// Helper function to log subscription events to attendance log
     fn log_subscription_event(
         env: &Env,
@@ -566,8 +1383,7 @@ impl SubscriptionContract {
         subscription_id: &String,
         _amount: i128,
     ) -> Result<(), Error> {
-        // Generate event_id from subscription_id
-        let event_id = Self::generate_event_id(env, subscription_id);
+        let event_id = Self::generate_event_id(env, subscription_id, &action);
 
         // Create event details map
         let mut details: Map<String, String> = Map::new(env);
@@ -610,19 +1426,24 @@ impl SubscriptionContract {
         Ok(())
     }
 
-    /// Generate a deterministic event_id from subscription_id
-    fn generate_event_id(env: &Env, subscription_id: &String) -> BytesN<32> {
-        // Use the subscription_id to generate a BytesN<32>
-        // Pad or truncate the subscription_id to create a 32-byte array
-        let mut bytes = [0u8; 32];
-
-        // For simplicity, we'll create a deterministic ID based on the subscription_id length
-        // In production, you'd want to use a proper hashing mechanism
-        let id_len = subscription_id.len();
-        bytes[0] = (id_len % 256) as u8;
-        bytes[1] = ((id_len / 256) % 256) as u8;
+    /// Derives an attendance log ID as
+    /// `sha256(xdr((subscription_id, action, timestamp, nonce)))`. The nonce
+    /// keeps IDs unique when one subscription logs the same action twice in a
+    /// ledger.
+    fn generate_event_id(env: &Env, subscription_id: &String, action: &String) -> BytesN<32> {
+        let nonce: u64 = env
+            .storage()
+            .instance()
+            .get(&SubscriptionDataKey::EventNonce)
+            .unwrap_or(0);
+        env.storage()
+            .instance()
+            .set(&SubscriptionDataKey::EventNonce, &(nonce + 1));
 
-        BytesN::from_array(env, &bytes)
+        let timestamp = env.ledger().timestamp();
+        env.crypto()
+            .sha256(&(subscription_id.clone(), action.clone(), timestamp, nonce).to_xdr(env))
+            .into()
     }
 
     // ============================================================================
@@ -664,7 +1485,7 @@ impl SubscriptionContract {
 
         // Store tier
         env.storage().persistent().set(&key, &tier);
-        env.storage().persistent().extend_ttl(&key, 100, 1000);
+        TtlModule::bump(&env, TtlEntityClass::Tier, &key);
 
         // Add to tier list
         let list_key = SubscriptionDataKey::TierList;
@@ -690,10 +1511,16 @@ impl SubscriptionContract {
         env.storage().persistent().set(&analytics_key, &analytics);
 
         // Emit tier created event
-        env.events().publish(
-            (symbol_short!("tier_crt"), params.id.clone(), admin.clone()),
-            (params.name, params.level, params.price, current_time),
-        );
+        TierCreated {
+            tier_id: params.id.clone(),
+            admin: admin.clone(),
+            name: params.name,
+            level: params.level,
+            price: params.price,
+            annual_price: params.annual_price,
+            created_at: current_time,
+        }
+        .publish(&env);
 
         Ok(())
     }
@@ -708,6 +1535,7 @@ impl SubscriptionContract {
             .persistent()
             .get(&key)
             .ok_or(Error::TierNotFound)?;
+        let (old_price, old_annual_price) = (tier.price, tier.annual_price);
 
         // Update fields if provided
         if let Some(new_name) = params.name {
@@ -737,6 +1565,12 @@ impl SubscriptionContract {
         if let Some(new_is_active) = params.is_active {
             tier.is_active = new_is_active;
         }
+        if let Some(grandfather) = params.grandfather_existing {
+            env.storage().persistent().set(
+                &SubscriptionDataKey::TierGrandfathered(params.id.clone()),
+                &grandfather,
+            );
+        }
 
         tier.updated_at = env.ledger().timestamp();
 
@@ -744,20 +1578,31 @@ impl SubscriptionContract {
         env.storage().persistent().set(&key, &tier);
 
         // Emit tier updated event
-        env.events().publish(
-            (symbol_short!("tier_upd"), params.id.clone(), admin.clone()),
-            (tier.updated_at,),
-        );
+        TierUpdated {
+            tier_id: params.id.clone(),
+            admin: admin.clone(),
+            old_price,
+            new_price: tier.price,
+            old_annual_price,
+            new_annual_price: tier.annual_price,
+            is_active: tier.is_active,
+            updated_at: tier.updated_at,
+        }
+        .publish(&env);
 
         Ok(())
     }
 
     /// Gets a subscription tier by ID.
     pub fn get_tier(env: Env, id: String) -> Result<SubscriptionTier, Error> {
-        env.storage()
+        let key = SubscriptionDataKey::Tier(id);
+        let tier = env
+            .storage()
             .persistent()
-            .get(&SubscriptionDataKey::Tier(id))
-            .ok_or(Error::TierNotFound)
+            .get(&key)
+            .ok_or(Error::TierNotFound)?;
+        TtlModule::bump(&env, TtlEntityClass::Tier, &key);
+        Ok(tier)
     }
 
     /// Gets all available subscription tiers.
@@ -811,14 +1656,169 @@ impl SubscriptionContract {
         env.storage().persistent().set(&key, &tier);
 
         // Emit tier deactivated event
-        env.events().publish(
-            (symbol_short!("tier_dea"), id.clone(), admin.clone()),
-            (tier.updated_at,),
+        TierDeactivated {
+            tier_id: id.clone(),
+            admin: admin.clone(),
+            updated_at: tier.updated_at,
+        }
+        .publish(&env);
+
+        Ok(())
+    }
+
+    /// Deprecates a tier in favour of `replacement_tier_id`. Admin only.
+    /// The tier stops accepting subscriptions immediately; its remaining
+    /// subscribers are moved by `migrate_deprecated_tier` from `migrate_at`.
+    pub fn deprecate_tier(
+        env: Env,
+        admin: Address,
+        tier_id: String,
+        replacement_tier_id: String,
+        migrate_at: u64,
+    ) -> Result<(), ModuleError> {
+        Self::require_admin(&env, &admin)?;
+
+        let deprecation_key = SubscriptionDataKey::TierDeprecation(tier_id.clone());
+        if env.storage().persistent().has(&deprecation_key) {
+            return Err(TierChangeError::TierAlreadyDeprecated.into());
+        }
+        if replacement_tier_id == tier_id {
+            return Err(TierChangeError::InvalidReplacementTier.into());
+        }
+        let replacement = Self::get_tier(env.clone(), replacement_tier_id.clone())?;
+        if !replacement.is_active {
+            return Err(Error::TierNotActive.into());
+        }
+        let now = env.ledger().timestamp();
+        if migrate_at <= now {
+            return Err(TierChangeError::InvalidMigrationTime.into());
+        }
+
+        let key = SubscriptionDataKey::Tier(tier_id.clone());
+        let mut tier: SubscriptionTier = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .ok_or(Error::TierNotFound)?;
+        tier.is_active = false;
+        tier.updated_at = now;
+        env.storage().persistent().set(&key, &tier);
+
+        env.storage().persistent().set(
+            &deprecation_key,
+            &TierDeprecation {
+                tier_id: tier_id.clone(),
+                replacement_tier_id: replacement_tier_id.clone(),
+                announced_at: now,
+                migrate_at,
+                deprecated_by: admin.clone(),
+                migrated_count: 0,
+            },
         );
 
+        TierDeprecated {
+            tier_id,
+            admin,
+            replacement_tier_id,
+            migrate_at,
+        }
+        .publish(&env);
+
         Ok(())
     }
 
+    pub fn get_tier_deprecation(env: Env, tier_id: String) -> Option<TierDeprecation> {
+        env.storage()
+            .persistent()
+            .get(&SubscriptionDataKey::TierDeprecation(tier_id))
+    }
+
+    /// Moves up to `limit` remaining subscribers of a deprecated tier to its
+    /// replacement, once the migration time has passed. Admin only. Returns
+    /// how many subscriptions are left to migrate.
+    ///
+    /// Subscribers keep their locked price if the deprecated tier
+    /// grandfathers them; otherwise the replacement's price for their cycle
+    /// is locked in. Renewals then follow the replacement tier's
+    /// grandfathering setting.
+    pub fn migrate_deprecated_tier(
+        env: Env,
+        admin: Address,
+        tier_id: String,
+        limit: u32,
+    ) -> Result<u32, ModuleError> {
+        Self::require_admin(&env, &admin)?;
+
+        let deprecation_key = SubscriptionDataKey::TierDeprecation(tier_id.clone());
+        let mut deprecation: TierDeprecation = env
+            .storage()
+            .persistent()
+            .get(&deprecation_key)
+            .ok_or(TierChangeError::TierNotDeprecated)?;
+        if env.ledger().timestamp() < deprecation.migrate_at {
+            return Err(TierChangeError::MigrationNotDue.into());
+        }
+
+        let replacement = Self::get_tier(env.clone(), deprecation.replacement_tier_id.clone())?;
+        let keep_locked_price = Self::is_tier_grandfathered(&env, &tier_id);
+        let ids: Vec<String> = env
+            .storage()
+            .persistent()
+            .get(&SubscriptionDataKey::TierSubscriptions(tier_id.clone()))
+            .unwrap_or_else(|| Vec::new(&env));
+        let batch = ids.len().min(limit.min(MAX_TIER_MIGRATION_BATCH));
+
+        for id in ids.slice(0..batch).iter() {
+            let key = SubscriptionDataKey::Subscription(id.clone());
+            let mut subscription = Self::get_subscription(env.clone(), id.clone())?;
+            let list_price = match subscription.billing_cycle {
+                BillingCycle::Monthly => replacement.price,
+                BillingCycle::Annual => replacement.annual_price,
+            };
+            if !keep_locked_price || subscription.locked_price == 0 {
+                subscription.locked_price = list_price;
+            }
+            subscription.tier_id = replacement.id.clone();
+            env.storage().persistent().set(&key, &subscription);
+
+            Self::unindex_tier_subscription(&env, &tier_id, &id);
+            Self::index_tier_subscription(&env, &replacement.id, &id);
+            Self::record_tier_change(&env, &id);
+            OutboxModule::append(
+                &env,
+                symbol_short!("tier_chg"),
+                OutboxSubject::Subscription(id.clone()),
+            );
+            let status =
+                SubscriptionLifecycle::effective_status(&subscription, env.ledger().timestamp());
+            if Self::counts_as_active(&status) {
+                LoyaltyModule::set_member_tier(&env, &subscription.user, &replacement.id);
+                Self::update_tier_analytics_on_change(
+                    &env,
+                    &tier_id,
+                    &replacement.id,
+                    &TierChangeType::Lateral,
+                )?;
+            }
+        }
+
+        deprecation.migrated_count += batch;
+        env.storage()
+            .persistent()
+            .set(&deprecation_key, &deprecation);
+
+        let remaining = ids.len() - batch;
+        TierSubscribersMigrated {
+            tier_id,
+            replacement_tier_id: replacement.id,
+            migrated: batch,
+            remaining,
+        }
+        .publish(&env);
+
+        Ok(remaining)
+    }
+
     // ============================================================================
     // Subscription with Tier Support
     // ============================================================================
@@ -832,19 +1832,14 @@ impl SubscriptionContract {
         tier_id: String,
         billing_cycle: BillingCycle,
         promo_code: Option<String>,
-    ) -> Result<(), Error> {
+    ) -> Result<(), ModuleError> {
         user.require_auth();
-
-        // Check if subscription already exists
-        let key = SubscriptionDataKey::Subscription(id.clone());
-        if env.storage().persistent().has(&key) {
-            return Err(Error::SubscriptionAlreadyExists);
-        }
+        BlacklistModule::require_not_blacklisted(&env, BlacklistOperation::Subscription, &user)?;
 
         // Get and validate tier
         let tier = Self::get_tier(env.clone(), tier_id.clone())?;
         if !tier.is_active {
-            return Err(Error::TierNotActive);
+            return Err(Error::TierNotActive.into());
         }
 
         // Calculate price based on billing cycle
@@ -853,32 +1848,80 @@ impl SubscriptionContract {
             BillingCycle::Annual => tier.annual_price,
         };
 
-        // Apply promotion if provided
-        let final_price = if let Some(code) = promo_code {
-            Self::apply_promotion(&env, &tier_id, &code, base_price)?
+        // Apply promotion if provided, otherwise any win-back offer the user
+        // holds
+        let final_price = if let Some(code) = &promo_code {
+            Self::apply_promotion(&env, &id, &tier_id, code, base_price)?
         } else {
-            base_price
+            RetentionModule::redeem_winback(&env, &user, base_price)?
         };
 
-        // Validate payment
-        Self::validate_payment(&env, &payment_token, final_price, &user)?;
+        let (subscription, charge) = Self::provision_tier_subscription(
+            &env,
+            id,
+            user,
+            payment_token,
+            &tier,
+            billing_cycle,
+            final_price,
+        )?;
+        BillingModule::record(
+            &env,
+            &subscription.id,
+            &subscription.user,
+            BillingKind::Create,
+            &subscription.payment_token,
+            &charge,
+            promo_code,
+        );
+        Ok(())
+    }
 
-        // Calculate duration based on billing cycle
-        let duration = match billing_cycle {
-            BillingCycle::Monthly => 30 * 24 * 60 * 60, // 30 days in seconds
-            BillingCycle::Annual => 365 * 24 * 60 * 60, // 365 days in seconds
+    /// Charges `final_price` (USDC-denominated) plus fees and stores a new
+    /// subscription on `tier`. The tier's list price for the cycle is locked
+    /// for renewals. Returns the subscription with the charge's breakdown in
+    /// `payment_token` units. Callers must have authorized `user` and
+    /// validated the tier.
+    pub(crate) fn provision_tier_subscription(
+        env: &Env,
+        id: String,
+        user: Address,
+        payment_token: Address,
+        tier: &SubscriptionTier,
+        billing_cycle: BillingCycle,
+        final_price: i128,
+    ) -> Result<(Subscription, FeeBreakdown), ModuleError> {
+        let env = env.clone();
+        let tier_id = tier.id.clone();
+
+        // Check if subscription already exists
+        let key = SubscriptionDataKey::Subscription(id.clone());
+        if env.storage().persistent().has(&key) {
+            return Err(Error::SubscriptionAlreadyExists.into());
+        }
+
+        let base_price = match billing_cycle {
+            BillingCycle::Monthly => tier.price,
+            BillingCycle::Annual => tier.annual_price,
         };
 
+        // Add fees, convert the USDC-denominated charge into the chosen asset
+        // and validate payment
+        let usdc = FeeModule::breakdown_for(&env, &user, final_price)?;
+        let breakdown = FeeModule::convert(&env, &payment_token, &tier_id, &usdc)?;
+        let charge = breakdown.total;
+        Self::validate_payment(&env, &payment_token, charge, &user)?;
+
         let current_time = env.ledger().timestamp();
         let expires_at = current_time
-            .checked_add(duration)
+            .checked_add(Self::cycle_duration(&billing_cycle))
             .ok_or(Error::TimestampOverflow)?;
 
         let subscription = Subscription {
             id: id.clone(),
             user: user.clone(),
             payment_token: payment_token.clone(),
-            amount: final_price,
+            amount: charge,
             status: MembershipStatus::Active,
             created_at: current_time,
             expires_at,
@@ -888,21 +1931,39 @@ impl SubscriptionContract {
             last_resumed_at: current_time,
             pause_count: 0,
             total_paused_duration: 0,
-            pause_history: Vec::new(&env),
+            locked_price: base_price,
         };
 
         // Store subscription
         env.storage().persistent().set(&key, &subscription);
-        env.storage().persistent().extend_ttl(&key, 100, 1000);
+        TtlModule::bump(&env, TtlEntityClass::Subscription, &key);
+        MigrationModule::mark_subscription_current(&env, &id);
+        Self::index_user_subscription(&env, &user, &id);
+        Self::index_tier_subscription(&env, &tier_id, &id);
+        StatsModule::record_subscription_status(&env, None, &MembershipStatus::Active);
+        OutboxModule::append(
+            &env,
+            symbol_short!("sub_new"),
+            OutboxSubject::Subscription(id.clone()),
+        );
 
         // Update tier analytics
         Self::update_tier_analytics_on_subscribe(&env, &tier_id, final_price)?;
+        TreasuryModule::record_revenue(&env, RevenueSource::Subscription, final_price)?;
+        LoyaltyModule::set_member_tier(&env, &user, &tier_id);
 
         // Emit subscription created event
-        env.events().publish(
-            (symbol_short!("sub_creat"), id.clone(), user.clone()),
-            (tier_id.clone(), final_price, current_time, expires_at),
-        );
+        SubscriptionCreated {
+            subscription_id: id.clone(),
+            user: user.clone(),
+            payment_token: payment_token.clone(),
+            amount: charge,
+            tier_id: tier_id.clone(),
+            billing_cycle,
+            created_at: current_time,
+            expires_at,
+        }
+        .publish(&env);
 
         // Log attendance event
         Self::log_subscription_event(
@@ -913,7 +1974,7 @@ impl SubscriptionContract {
             final_price,
         )?;
 
-        Ok(())
+        Ok((subscription, breakdown))
     }
 
     /// Gets user subscription info with tier details.
@@ -921,7 +1982,7 @@ impl SubscriptionContract {
         env: Env,
         subscription_id: String,
     ) -> Result<UserSubscriptionInfo, Error> {
-        let subscription = Self::get_subscription(env.clone(), subscription_id)?;
+        let subscription = Self::get_subscription_view(env.clone(), subscription_id)?;
         let tier = Self::get_tier(env.clone(), subscription.tier_id.clone())?;
 
         let current_time = env.ledger().timestamp();
@@ -952,7 +2013,7 @@ impl SubscriptionContract {
         user: Address,
         subscription_id: String,
         new_tier_id: String,
-    ) -> Result<String, Error> {
+    ) -> Result<String, ModuleError> {
         user.require_auth();
 
         // Get current subscription
@@ -960,7 +2021,7 @@ impl SubscriptionContract {
 
         // Verify user owns the subscription
         if subscription.user != user {
-            return Err(Error::Unauthorized);
+            return Err(Error::Unauthorized.into());
         }
 
         // Get current and new tiers
@@ -968,16 +2029,15 @@ impl SubscriptionContract {
         let new_tier = Self::get_tier(env.clone(), new_tier_id.clone())?;
 
         if !new_tier.is_active {
-            return Err(Error::TierNotActive);
+            return Err(Error::TierNotActive.into());
         }
+        Self::require_tier_change_allowed(&env, &subscription_id)?;
 
-        // Determine change type
-        let change_type = Self::determine_change_type(&current_tier.level, &new_tier.level)?;
-
-        // Calculate prorated amount
+        // Determine change type and prorated amount
+        let quote = Self::build_tier_change_quote(&env, &subscription, &current_tier, &new_tier)?;
+        let change_type = quote.change_type.clone();
+        let prorated_amount = quote.amount_due - quote.credit;
         let current_time = env.ledger().timestamp();
-        let prorated_amount =
-            Self::calculate_proration(&env, &subscription, &current_tier, &new_tier)?;
 
         // Generate change request ID
         let change_id = Self::generate_change_request_id(&env, &user, current_time);
@@ -993,30 +2053,23 @@ impl SubscriptionContract {
             created_at: current_time,
         };
 
-        // Store change request
-        let key = SubscriptionDataKey::TierChangeRequest(change_id.clone());
-        env.storage().persistent().set(&key, &change_request);
-
-        // Add to user's change history
-        let history_key = SubscriptionDataKey::UserTierChangeHistory(user.clone());
-        let mut history: Vec<String> = env
-            .storage()
-            .persistent()
-            .get(&history_key)
-            .unwrap_or_else(|| Vec::new(&env));
-        history.push_back(change_id.clone());
-        env.storage().persistent().set(&history_key, &history);
+        // Store change request and add it to the user's change history
+        Self::store_tier_change(&env, &change_id, &change_request);
+        env.storage().persistent().set(
+            &SubscriptionDataKey::PendingTierChange(subscription_id.clone()),
+            &change_id,
+        );
 
         // Emit tier change requested event
-        env.events().publish(
-            (symbol_short!("tier_chg"), change_id.clone(), user.clone()),
-            (
-                subscription.tier_id.clone(),
-                new_tier_id,
-                change_type,
-                prorated_amount,
-            ),
-        );
+        TierChangeRequested {
+            change_id: change_id.clone(),
+            user: user.clone(),
+            from_tier: subscription.tier_id.clone(),
+            to_tier: new_tier_id,
+            change_type,
+            prorated_amount,
+        }
+        .publish(&env);
 
         Ok(change_id)
     }
@@ -1028,9 +2081,7 @@ impl SubscriptionContract {
         change_request_id: String,
         subscription_id: String,
         payment_token: Address,
-    ) -> Result<(), Error> {
-        caller.require_auth();
-
+    ) -> Result<(), ModuleError> {
         let key = SubscriptionDataKey::TierChangeRequest(change_request_id.clone());
         let mut change_request: TierChangeRequest = env
             .storage()
@@ -1040,30 +2091,46 @@ impl SubscriptionContract {
 
         // Check if already processed
         if change_request.status != TierChangeStatus::Pending {
-            return Err(Error::TierChangeAlreadyProcessed);
+            return Err(Error::TierChangeAlreadyProcessed.into());
         }
 
         // Verify caller is the user or admin
-        if caller != change_request.user {
+        if caller == change_request.user {
+            caller.require_auth();
+        } else {
             Self::require_admin(&env, &caller)?;
         }
 
         // Get subscription and update it
         let sub_key = SubscriptionDataKey::Subscription(subscription_id.clone());
-        let mut subscription: Subscription = env
-            .storage()
-            .persistent()
-            .get(&sub_key)
-            .ok_or(Error::SubscriptionNotFound)?;
+        let mut subscription = Self::get_subscription(env.clone(), subscription_id.clone())?;
 
-        // Handle payment for upgrades
+        // Handle payment for upgrades, net of any credit the user holds
+        let mut charged = 0;
+        let mut charge = None;
         if change_request.prorated_amount > 0 {
-            Self::validate_payment(
-                &env,
-                &payment_token,
-                change_request.prorated_amount,
-                &change_request.user,
-            )?;
+            charged =
+                Self::apply_credit(&env, &change_request.user, change_request.prorated_amount);
+        }
+        if charged > 0 {
+            let usdc = FeeModule::breakdown_for(&env, &change_request.user, charged)?;
+            let breakdown =
+                FeeModule::convert(&env, &payment_token, &change_request.to_tier, &usdc)?;
+            Self::validate_payment(&env, &payment_token, breakdown.total, &change_request.user)?;
+            charge = Some(breakdown);
+        }
+
+        // Downgrades credit the unused difference for later renewals or upgrades
+        if change_request.prorated_amount < 0 {
+            let amount = -change_request.prorated_amount;
+            Self::issue_credit(&env, &change_request.user, amount)?;
+            TierChangeCredited {
+                change_id: change_request_id.clone(),
+                user: change_request.user.clone(),
+                amount,
+                balance: Self::get_credit_balance(env.clone(), change_request.user.clone()),
+            }
+            .publish(&env);
         }
 
         // Get old tier for analytics
@@ -1073,10 +2140,39 @@ impl SubscriptionContract {
         subscription.tier_id = change_request.to_tier.clone();
         subscription.amount = Self::get_tier(env.clone(), change_request.to_tier.clone())?.price;
         env.storage().persistent().set(&sub_key, &subscription);
+        Self::unindex_tier_subscription(&env, &old_tier_id, &subscription_id);
+        Self::index_tier_subscription(&env, &subscription.tier_id, &subscription_id);
 
         // Update change request status
         change_request.status = TierChangeStatus::Completed;
         env.storage().persistent().set(&key, &change_request);
+        Self::record_tier_change(&env, &subscription_id);
+        OutboxModule::append(
+            &env,
+            symbol_short!("tier_chg"),
+            OutboxSubject::Subscription(subscription_id.clone()),
+        );
+        NotificationModule::notify(
+            &env,
+            &change_request.user,
+            NotificationKind::TierChangeProcessed,
+            OutboxSubject::Subscription(subscription_id.clone()),
+            0,
+        );
+
+        TreasuryModule::record_revenue(&env, RevenueSource::TierChange, charged)?;
+        if let Some(charge) = charge {
+            BillingModule::record(
+                &env,
+                &subscription_id,
+                &change_request.user,
+                BillingKind::TierChange,
+                &payment_token,
+                &charge,
+                None,
+            );
+        }
+        LoyaltyModule::set_member_tier(&env, &change_request.user, &change_request.to_tier);
 
         // Update analytics for both tiers
         Self::update_tier_analytics_on_change(
@@ -1087,24 +2183,300 @@ impl SubscriptionContract {
         )?;
 
         // Emit tier change completed event
-        env.events().publish(
-            (
-                symbol_short!("tier_cmp"),
-                change_request_id,
-                change_request.user.clone(),
-            ),
-            (
-                old_tier_id,
-                change_request.to_tier,
-                change_request.prorated_amount,
-            ),
-        );
+        TierChangeCompleted {
+            change_id: change_request_id,
+            user: change_request.user.clone(),
+            subscription_id,
+            from_tier: old_tier_id,
+            to_tier: change_request.to_tier,
+            prorated_amount: change_request.prorated_amount,
+        }
+        .publish(&env);
 
         Ok(())
     }
 
-    /// Cancels a pending tier change request.
-    pub fn cancel_tier_change(
+    /// Schedules a downgrade to take effect when the current billing period
+    /// ends. No proration applies: the subscription keeps its current tier
+    /// until the next renewal, which switches it to `new_tier_id` and charges
+    /// the new tier's price.
+    pub fn schedule_tier_downgrade(
+        env: Env,
+        user: Address,
+        subscription_id: String,
+        new_tier_id: String,
+    ) -> Result<String, ModuleError> {
+        user.require_auth();
+
+        let subscription = Self::get_subscription(env.clone(), subscription_id.clone())?;
+        if subscription.user != user {
+            return Err(Error::Unauthorized.into());
+        }
+
+        let schedule_key = SubscriptionDataKey::ScheduledTierChange(subscription_id.clone());
+        if env.storage().persistent().has(&schedule_key) {
+            return Err(TierChangeError::DowngradeAlreadyScheduled.into());
+        }
+        Self::require_tier_change_allowed(&env, &subscription_id)?;
+
+        let current_tier = Self::get_tier(env.clone(), subscription.tier_id.clone())?;
+        let new_tier = Self::get_tier(env.clone(), new_tier_id.clone())?;
+        if !new_tier.is_active {
+            return Err(Error::TierNotActive.into());
+        }
+        if Self::determine_change_type(&current_tier.level, &new_tier.level)?
+            != TierChangeType::Downgrade
+        {
+            return Err(TierChangeError::NotADowngrade.into());
+        }
+
+        let current_time = env.ledger().timestamp();
+        let change_id = Self::generate_change_request_id(&env, &user, current_time);
+        let change_request = TierChangeRequest {
+            user: user.clone(),
+            from_tier: subscription.tier_id.clone(),
+            to_tier: new_tier_id.clone(),
+            change_type: TierChangeType::Downgrade,
+            prorated_amount: 0,
+            effective_date: subscription.expires_at,
+            status: TierChangeStatus::Approved,
+            created_at: current_time,
+        };
+        Self::store_tier_change(&env, &change_id, &change_request);
+        env.storage().persistent().set(&schedule_key, &change_id);
+
+        TierDowngradeScheduled {
+            change_id: change_id.clone(),
+            subscription_id,
+            user,
+            from_tier: subscription.tier_id,
+            to_tier: new_tier_id,
+            effective_date: subscription.expires_at,
+        }
+        .publish(&env);
+
+        Ok(change_id)
+    }
+
+    /// Cancels a subscription's scheduled downgrade before it executes.
+    pub fn cancel_scheduled_downgrade(
+        env: Env,
+        user: Address,
+        subscription_id: String,
+    ) -> Result<(), ModuleError> {
+        user.require_auth();
+
+        let schedule_key = SubscriptionDataKey::ScheduledTierChange(subscription_id);
+        let change_id: String = env
+            .storage()
+            .persistent()
+            .get(&schedule_key)
+            .ok_or(TierChangeError::NoScheduledDowngrade)?;
+        let key = SubscriptionDataKey::TierChangeRequest(change_id.clone());
+        let mut change_request: TierChangeRequest = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .ok_or(Error::TierChangeNotFound)?;
+        if change_request.user != user {
+            return Err(Error::Unauthorized.into());
+        }
+
+        change_request.status = TierChangeStatus::Cancelled;
+        env.storage().persistent().set(&key, &change_request);
+        env.storage().persistent().remove(&schedule_key);
+
+        TierChangeCancelled {
+            change_id,
+            user,
+            timestamp: env.ledger().timestamp(),
+        }
+        .publish(&env);
+
+        Ok(())
+    }
+
+    /// Returns the downgrade scheduled for a subscription's period end, if any.
+    pub fn get_scheduled_tier_change(
+        env: Env,
+        subscription_id: String,
+    ) -> Option<TierChangeRequest> {
+        let change_id: String = env
+            .storage()
+            .persistent()
+            .get(&SubscriptionDataKey::ScheduledTierChange(subscription_id))?;
+        env.storage()
+            .persistent()
+            .get(&SubscriptionDataKey::TierChangeRequest(change_id))
+    }
+
+    /// Switches a renewing subscription onto its scheduled downgrade tier, if
+    /// one is pending. Analytics only move a subscriber between tiers when it
+    /// was still counted as active; lapsed subscribers are re-counted on the
+    /// new tier by the renewal itself.
+    fn apply_scheduled_downgrade(
+        env: &Env,
+        subscription_id: &String,
+        subscription: &mut Subscription,
+        was_active: bool,
+    ) -> Result<(), Error> {
+        let schedule_key = SubscriptionDataKey::ScheduledTierChange(subscription_id.clone());
+        let Some(change_id) = env.storage().persistent().get::<_, String>(&schedule_key) else {
+            return Ok(());
+        };
+        let key = SubscriptionDataKey::TierChangeRequest(change_id.clone());
+        let mut change_request: TierChangeRequest = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .ok_or(Error::TierChangeNotFound)?;
+
+        let new_tier = Self::get_tier(env.clone(), change_request.to_tier.clone())?;
+        let old_tier_id = subscription.tier_id.clone();
+        subscription.tier_id = change_request.to_tier.clone();
+        subscription.locked_price = match subscription.billing_cycle {
+            BillingCycle::Monthly => new_tier.price,
+            BillingCycle::Annual => new_tier.annual_price,
+        };
+
+        change_request.status = TierChangeStatus::Completed;
+        env.storage().persistent().set(&key, &change_request);
+        env.storage().persistent().remove(&schedule_key);
+        Self::unindex_tier_subscription(env, &old_tier_id, subscription_id);
+        Self::index_tier_subscription(env, &subscription.tier_id, subscription_id);
+        Self::record_tier_change(env, subscription_id);
+        OutboxModule::append(
+            env,
+            symbol_short!("tier_chg"),
+            OutboxSubject::Subscription(subscription_id.clone()),
+        );
+        NotificationModule::notify(
+            env,
+            &subscription.user,
+            NotificationKind::TierChangeProcessed,
+            OutboxSubject::Subscription(subscription_id.clone()),
+            0,
+        );
+
+        LoyaltyModule::set_member_tier(env, &subscription.user, &change_request.to_tier);
+        if was_active {
+            Self::update_tier_analytics_on_change(
+                env,
+                &old_tier_id,
+                &change_request.to_tier,
+                &TierChangeType::Downgrade,
+            )?;
+        }
+
+        TierChangeCompleted {
+            change_id,
+            user: subscription.user.clone(),
+            subscription_id: subscription_id.clone(),
+            from_tier: old_tier_id,
+            to_tier: change_request.to_tier,
+            prorated_amount: 0,
+        }
+        .publish(env);
+
+        Ok(())
+    }
+
+    /// Sets the minimum time between tier changes on one subscription. Admin
+    /// only; `0` disables the cooldown.
+    pub fn set_tier_change_cooldown(env: Env, admin: Address, secs: u64) -> Result<(), Error> {
+        Self::require_admin(&env, &admin)?;
+        env.storage()
+            .instance()
+            .set(&SubscriptionDataKey::TierChangeCooldown, &secs);
+        Ok(())
+    }
+
+    pub fn get_tier_change_cooldown(env: Env) -> u64 {
+        env.storage()
+            .instance()
+            .get(&SubscriptionDataKey::TierChangeCooldown)
+            .unwrap_or(0)
+    }
+
+    /// Lets a subscription change tier again immediately. Admin only.
+    pub fn reset_tier_change_cooldown(
+        env: Env,
+        admin: Address,
+        subscription_id: String,
+    ) -> Result<(), Error> {
+        Self::require_admin(&env, &admin)?;
+        env.storage()
+            .persistent()
+            .remove(&SubscriptionDataKey::LastTierChange(subscription_id));
+        Ok(())
+    }
+
+    /// Allows one outstanding tier change per subscription, immediate or
+    /// scheduled, and enforces the cooldown since its last completed change.
+    fn require_tier_change_allowed(env: &Env, subscription_id: &String) -> Result<(), ModuleError> {
+        let has_pending = env
+            .storage()
+            .persistent()
+            .get::<_, String>(&SubscriptionDataKey::PendingTierChange(
+                subscription_id.clone(),
+            ))
+            .and_then(|change_id| {
+                env.storage()
+                    .persistent()
+                    .get::<_, TierChangeRequest>(&SubscriptionDataKey::TierChangeRequest(change_id))
+            })
+            .is_some_and(|request| request.status == TierChangeStatus::Pending);
+        let has_scheduled =
+            env.storage()
+                .persistent()
+                .has(&SubscriptionDataKey::ScheduledTierChange(
+                    subscription_id.clone(),
+                ));
+        if has_pending || has_scheduled {
+            return Err(TierChangeError::ChangePending.into());
+        }
+
+        if let Some(last) =
+            env.storage()
+                .persistent()
+                .get::<_, u64>(&SubscriptionDataKey::LastTierChange(
+                    subscription_id.clone(),
+                ))
+        {
+            let cooldown = Self::get_tier_change_cooldown(env.clone());
+            if env.ledger().timestamp() < last.saturating_add(cooldown) {
+                return Err(TierChangeError::TierChangeTooFrequent.into());
+            }
+        }
+        Ok(())
+    }
+
+    fn record_tier_change(env: &Env, subscription_id: &String) {
+        env.storage().persistent().set(
+            &SubscriptionDataKey::LastTierChange(subscription_id.clone()),
+            &env.ledger().timestamp(),
+        );
+    }
+
+    /// Stores a tier change request and appends it to the user's history.
+    fn store_tier_change(env: &Env, change_id: &String, change_request: &TierChangeRequest) {
+        env.storage().persistent().set(
+            &SubscriptionDataKey::TierChangeRequest(change_id.clone()),
+            change_request,
+        );
+
+        let history_key = SubscriptionDataKey::UserTierChangeHistory(change_request.user.clone());
+        let mut history: Vec<String> = env
+            .storage()
+            .persistent()
+            .get(&history_key)
+            .unwrap_or_else(|| Vec::new(env));
+        history.push_back(change_id.clone());
+        env.storage().persistent().set(&history_key, &history);
+    }
+
+    /// Cancels a pending tier change request.
+    pub fn cancel_tier_change(
         env: Env,
         user: Address,
         change_request_id: String,
@@ -1132,10 +2504,12 @@ impl SubscriptionContract {
         env.storage().persistent().set(&key, &change_request);
 
         // Emit cancellation event
-        env.events().publish(
-            (symbol_short!("tier_cnc"), change_request_id, user),
-            (env.ledger().timestamp(),),
-        );
+        TierChangeCancelled {
+            change_id: change_request_id,
+            user,
+            timestamp: env.ledger().timestamp(),
+        }
+        .publish(&env);
 
         Ok(())
     }
@@ -1195,15 +2569,15 @@ impl SubscriptionContract {
         env.storage().persistent().set(&list_key, &promo_list);
 
         // Emit promotion created event
-        env.events().publish(
-            (symbol_short!("promo_cr"), params.promo_id, admin),
-            (
-                params.tier_id,
-                params.discount_percent,
-                params.start_date,
-                params.end_date,
-            ),
-        );
+        PromotionCreated {
+            promo_id: params.promo_id,
+            admin,
+            tier_id: params.tier_id,
+            discount_percent: params.discount_percent,
+            start_date: params.start_date,
+            end_date: params.end_date,
+        }
+        .publish(&env);
 
         Ok(())
     }
@@ -1216,9 +2590,64 @@ impl SubscriptionContract {
             .ok_or(Error::PromotionNotFound)
     }
 
-    /// Validates and applies a promotion code, returning the final price.
+    /// Returns a promotion's campaign counters.
+    pub fn get_promotion_analytics(
+        env: Env,
+        promo_id: String,
+    ) -> Result<PromotionAnalytics, Error> {
+        Self::get_promotion(env.clone(), promo_id.clone())?;
+        let mut analytics = Self::load_promotion_analytics(&env, &promo_id);
+        if analytics.redemptions > 0 {
+            analytics.conversion_rate_bps =
+                (analytics.conversions as u64 * 10_000 / analytics.redemptions as u64) as u32;
+        }
+        Ok(analytics)
+    }
+
+    fn load_promotion_analytics(env: &Env, promo_id: &String) -> PromotionAnalytics {
+        env.storage()
+            .persistent()
+            .get(&SubscriptionDataKey::PromotionAnalytics(promo_id.clone()))
+            .unwrap_or(PromotionAnalytics {
+                promo_id: promo_id.clone(),
+                redemptions: 0,
+                gross_discount: 0,
+                revenue: 0,
+                conversions: 0,
+                conversion_rate_bps: 0,
+            })
+    }
+
+    /// Books a renewal of a subscription created with a promotion to the
+    /// promotion's revenue; the first one also counts as a conversion.
+    fn record_promotion_renewal(env: &Env, subscription_id: &String, revenue: i128) {
+        let link_key = SubscriptionDataKey::SubscriptionPromotion(subscription_id.clone());
+        let Some((promo_id, converted)) = env
+            .storage()
+            .persistent()
+            .get::<_, (String, bool)>(&link_key)
+        else {
+            return;
+        };
+        let mut analytics = Self::load_promotion_analytics(env, &promo_id);
+        analytics.revenue += revenue;
+        if !converted {
+            analytics.conversions += 1;
+            env.storage()
+                .persistent()
+                .set(&link_key, &(promo_id.clone(), true));
+        }
+        env.storage().persistent().set(
+            &SubscriptionDataKey::PromotionAnalytics(promo_id),
+            &analytics,
+        );
+    }
+
+    /// Validates and applies a promotion code for a new subscription,
+    /// returning the final price and updating the promotion's analytics.
     fn apply_promotion(
         env: &Env,
+        subscription_id: &String,
         tier_id: &String,
         promo_code: &String,
         base_price: i128,
@@ -1257,14 +2686,32 @@ impl SubscriptionContract {
                     let final_price = if promotion.promo_price > 0 {
                         promotion.promo_price
                     } else {
-                        base_price - (base_price * promotion.discount_percent as i128 / 100)
+                        Amount::new(base_price)
+                            .zip(Bps::from_percent(promotion.discount_percent))
+                            .and_then(|(price, bps)| price.discount(bps))
+                            .ok_or(Error::InvalidPaymentAmount)?
+                            .value()
                     };
 
                     // Increment redemption count
                     promotion.current_redemptions += 1;
-                    env.storage()
-                        .persistent()
-                        .set(&SubscriptionDataKey::TierPromotion(promo_id), &promotion);
+                    env.storage().persistent().set(
+                        &SubscriptionDataKey::TierPromotion(promo_id.clone()),
+                        &promotion,
+                    );
+
+                    let mut analytics = Self::load_promotion_analytics(env, &promo_id);
+                    analytics.redemptions += 1;
+                    analytics.gross_discount += (base_price - final_price).max(0);
+                    analytics.revenue += final_price;
+                    env.storage().persistent().set(
+                        &SubscriptionDataKey::PromotionAnalytics(promo_id.clone()),
+                        &analytics,
+                    );
+                    env.storage().persistent().set(
+                        &SubscriptionDataKey::SubscriptionPromotion(subscription_id.clone()),
+                        &(promo_id, false),
+                    );
 
                     return Ok(final_price);
                 }
@@ -1284,21 +2731,38 @@ impl SubscriptionContract {
         subscription_id: String,
         feature: TierFeature,
     ) -> Result<bool, Error> {
-        let subscription = Self::get_subscription(env.clone(), subscription_id)?;
+        let subscription = Self::get_subscription(env.clone(), subscription_id.clone())?;
 
         // Check if subscription is active
         if subscription.status != MembershipStatus::Active {
             return Ok(false);
         }
 
-        // Check if subscription is expired
+        // A lapsed subscription keeps what its tier's grace policy allows
         let current_time = env.ledger().timestamp();
         if subscription.expires_at < current_time {
-            return Ok(false);
+            let policy =
+                MembershipTokenContract::resolve_grace_policy(&env, Some(&subscription.tier_id));
+            if subscription.expires_at.saturating_add(policy.duration) < current_time {
+                return Ok(false);
+            }
+            match policy.feature_access {
+                GraceFeatureAccess::Suspended => return Ok(false),
+                GraceFeatureAccess::Reduced(features) => return Ok(features.contains(&feature)),
+                GraceFeatureAccess::Full => {}
+            }
+        }
+
+        // Features granted to this subscription directly
+        if Self::get_feature_overrides(env.clone(), subscription_id.clone())
+            .iter()
+            .any(|o| o.feature == feature)
+        {
+            return Ok(true);
         }
 
         // Get tier and check features
-        let tier = Self::get_tier(env, subscription.tier_id)?;
+        let tier = Self::get_tier(env.clone(), subscription.tier_id)?;
 
         for tier_feature in tier.features.iter() {
             if tier_feature == feature {
@@ -1306,7 +2770,114 @@ impl SubscriptionContract {
             }
         }
 
-        Ok(false)
+        // Features added by a bundle bought with this subscription
+        Ok(BundleModule::grants_feature(
+            &env,
+            &subscription.user,
+            &subscription_id,
+            &feature,
+        ))
+    }
+
+    /// Grants `feature` to one subscription until `expires_at`, regardless of
+    /// its tier. Admin only. Replaces any existing override for the feature.
+    pub fn grant_feature_override(
+        env: Env,
+        admin: Address,
+        subscription_id: String,
+        feature: TierFeature,
+        expires_at: u64,
+    ) -> Result<(), Error> {
+        Self::require_admin(&env, &admin)?;
+        Self::get_subscription(env.clone(), subscription_id.clone())?;
+
+        let now = env.ledger().timestamp();
+        if expires_at <= now {
+            return Err(Error::InvalidExpiryDate);
+        }
+
+        let mut overrides = Vec::new(&env);
+        for o in Self::get_feature_overrides(env.clone(), subscription_id.clone()).iter() {
+            if o.feature != feature {
+                overrides.push_back(o);
+            }
+        }
+        overrides.push_back(FeatureOverride {
+            feature: feature.clone(),
+            granted_by: admin.clone(),
+            granted_at: now,
+            expires_at,
+        });
+        let key = SubscriptionDataKey::FeatureOverrides(subscription_id.clone());
+        env.storage().persistent().set(&key, &overrides);
+        TtlModule::bump(&env, TtlEntityClass::Subscription, &key);
+
+        FeatureOverrideGranted {
+            subscription_id,
+            feature,
+            granted_by: admin,
+            expires_at,
+        }
+        .publish(&env);
+
+        Ok(())
+    }
+
+    /// Removes a subscription's override for `feature`. Admin only.
+    pub fn revoke_feature_override(
+        env: Env,
+        admin: Address,
+        subscription_id: String,
+        feature: TierFeature,
+    ) -> Result<(), Error> {
+        Self::require_admin(&env, &admin)?;
+
+        let key = SubscriptionDataKey::FeatureOverrides(subscription_id.clone());
+        let stored: Vec<FeatureOverride> = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .unwrap_or_else(|| Vec::new(&env));
+        let mut overrides = Vec::new(&env);
+        for o in stored.iter() {
+            if o.feature != feature {
+                overrides.push_back(o);
+            }
+        }
+        if overrides.len() == stored.len() {
+            return Err(Error::FeatureNotAvailable);
+        }
+        if overrides.is_empty() {
+            env.storage().persistent().remove(&key);
+        } else {
+            env.storage().persistent().set(&key, &overrides);
+        }
+
+        FeatureOverrideRevoked {
+            subscription_id,
+            feature,
+            revoked_by: admin,
+        }
+        .publish(&env);
+
+        Ok(())
+    }
+
+    /// Returns a subscription's unexpired feature overrides.
+    pub fn get_feature_overrides(env: Env, subscription_id: String) -> Vec<FeatureOverride> {
+        let stored: Vec<FeatureOverride> = env
+            .storage()
+            .persistent()
+            .get(&SubscriptionDataKey::FeatureOverrides(subscription_id))
+            .unwrap_or_else(|| Vec::new(&env));
+        let now = env.ledger().timestamp();
+        let mut live = Vec::new(&env);
+        for o in stored.iter() {
+            if o.expires_at > now {
+                live.push_back(o);
+            }
+        }
+        live
     }
 
     /// Enforces feature access, returning error if not available.
@@ -1325,16 +2896,265 @@ impl SubscriptionContract {
     // Analytics Functions
     // ============================================================================
 
-    /// Gets analytics for a specific tier.
+    /// Gets analytics for a specific tier, with `churn_rate` computed over
+    /// the trailing `CHURN_WINDOW_PERIODS`.
     pub fn get_tier_analytics(env: Env, tier_id: String) -> Result<TierAnalytics, Error> {
+        let mut analytics: TierAnalytics = env
+            .storage()
+            .persistent()
+            .get(&SubscriptionDataKey::TierAnalytics(tier_id.clone()))
+            .ok_or(Error::TierNotFound)?;
+
+        let current = env.ledger().timestamp() / ANALYTICS_PERIOD;
+        let first = current.saturating_sub(CHURN_WINDOW_PERIODS - 1);
+        let mut churned: u64 = 0;
+        for period in first..=current {
+            churned += env
+                .storage()
+                .persistent()
+                .get::<_, u32>(&SubscriptionDataKey::TierPeriodChurn(
+                    tier_id.clone(),
+                    period,
+                ))
+                .unwrap_or(0) as u64;
+        }
+        let base = analytics.active_subscribers as u64 + churned;
+        analytics.churn_rate = (churned * 100).checked_div(base).unwrap_or(0) as u32;
+
+        Ok(analytics)
+    }
+
+    /// Gets analytics for every tier, in creation order.
+    pub fn get_all_tier_analytics(env: Env) -> Vec<TierAnalytics> {
+        let tier_list: Vec<String> = env
+            .storage()
+            .persistent()
+            .get(&SubscriptionDataKey::TierList)
+            .unwrap_or_else(|| Vec::new(&env));
+
+        let mut all = Vec::new(&env);
+        for tier_id in tier_list.iter() {
+            if let Ok(analytics) = Self::get_tier_analytics(env.clone(), tier_id) {
+                all.push_back(analytics);
+            }
+        }
+        all
+    }
+
+    /// Gets the monthly cohorts of a tier for every analytics period
+    /// overlapping `period`, oldest first. Periods without sign-ups are
+    /// skipped.
+    pub fn get_tier_cohorts(
+        env: Env,
+        tier_id: String,
+        period: DateRange,
+    ) -> Result<Vec<TierCohort>, Error> {
+        Self::get_tier(env.clone(), tier_id.clone())?;
+        let (first, last) = Self::analytics_periods(&period)?;
+
+        let mut cohorts = Vec::new(&env);
+        for index in first..=last {
+            if let Some((created, churned)) = env
+                .storage()
+                .persistent()
+                .get::<_, (u32, u32)>(&SubscriptionDataKey::TierCohort(tier_id.clone(), index))
+            {
+                cohorts.push_back(TierCohort {
+                    period_start: index * ANALYTICS_PERIOD,
+                    created,
+                    retained: created.saturating_sub(churned),
+                });
+            }
+        }
+        Ok(cohorts)
+    }
+
+    /// Gets a tier's revenue for every analytics period overlapping
+    /// `period`, oldest first, including periods without revenue.
+    pub fn get_tier_revenue_over_time(
+        env: Env,
+        tier_id: String,
+        period: DateRange,
+    ) -> Result<Vec<TierRevenuePoint>, Error> {
+        Self::get_tier(env.clone(), tier_id.clone())?;
+        let (first, last) = Self::analytics_periods(&period)?;
+
+        let mut points = Vec::new(&env);
+        for index in first..=last {
+            points.push_back(TierRevenuePoint {
+                period_start: index * ANALYTICS_PERIOD,
+                revenue: env
+                    .storage()
+                    .persistent()
+                    .get(&SubscriptionDataKey::TierPeriodRevenue(
+                        tier_id.clone(),
+                        index,
+                    ))
+                    .unwrap_or(0),
+            });
+        }
+        Ok(points)
+    }
+
+    /// Marks an active subscription past its expiry date as expired and
+    /// updates tier analytics. Anyone may call it. Returns `false` when the
+    /// subscription is not active or not yet expired.
+    pub fn expire_subscription(env: Env, id: String) -> Result<bool, Error> {
+        let mut subscription = Self::get_subscription(env.clone(), id.clone())?;
+        Ok(Self::apply_expiry(&env, &id, &mut subscription))
+    }
+
+    /// Persists the Expired status of a lapsed active subscription, updating
+    /// analytics. Returns `false`, leaving `subscription` untouched, when it
+    /// is not active or not yet past its expiry date.
+    fn apply_expiry(env: &Env, id: &String, subscription: &mut Subscription) -> bool {
+        let now = env.ledger().timestamp();
+        if subscription.expires_at >= now {
+            return false;
+        }
+        let Ok(old_status) =
+            SubscriptionLifecycle::transition(subscription, SubscriptionEvent::Expire)
+        else {
+            return false;
+        };
+
+        env.storage().persistent().set(
+            &SubscriptionDataKey::Subscription(id.clone()),
+            &*subscription,
+        );
+        StatsModule::record_subscription_status(env, Some(&old_status), &subscription.status);
+        OutboxModule::append(
+            env,
+            symbol_short!("sub_exp"),
+            OutboxSubject::Subscription(id.clone()),
+        );
+        Self::record_tier_churn(env, subscription);
+
+        SubscriptionExpired {
+            subscription_id: id.clone(),
+            user: subscription.user.clone(),
+            expired_at: subscription.expires_at,
+            timestamp: now,
+        }
+        .publish(env);
+
+        true
+    }
+
+    /// Returns the subscription with its effective status: an active
+    /// subscription past its expiry date reads as Expired even before
+    /// `expire_subscription` has persisted that.
+    pub fn get_subscription_view(env: Env, id: String) -> Result<Subscription, Error> {
+        let mut subscription = Self::get_subscription(env.clone(), id)?;
+        subscription.status =
+            SubscriptionLifecycle::effective_status(&subscription, env.ledger().timestamp());
+        Ok(subscription)
+    }
+
+    /// Converts a date range into inclusive analytics period indexes.
+    fn analytics_periods(period: &DateRange) -> Result<(u64, u64), Error> {
+        if period.end_time < period.start_time {
+            return Err(Error::InvalidDateRange);
+        }
+        let first = period.start_time / ANALYTICS_PERIOD;
+        let last = period.end_time / ANALYTICS_PERIOD;
+        if last - first >= MAX_ANALYTICS_PERIODS {
+            return Err(Error::InvalidDateRange);
+        }
+        Ok((first, last))
+    }
+
+    /// Whether a subscription in `status` is counted in `active_subscribers`.
+    /// Length of one paid period of `billing_cycle`, in seconds.
+    fn cycle_duration(billing_cycle: &BillingCycle) -> u64 {
+        match billing_cycle {
+            BillingCycle::Monthly => 30 * 24 * 60 * 60, // 30 days in seconds
+            BillingCycle::Annual => 365 * 24 * 60 * 60, // 365 days in seconds
+        }
+    }
+
+    fn counts_as_active(status: &MembershipStatus) -> bool {
+        matches!(status, MembershipStatus::Active | MembershipStatus::Paused)
+    }
+
+    /// Books `amount` of revenue to a tier's totals and current period.
+    fn record_tier_revenue(env: &Env, tier_id: &String, amount: i128) {
+        let key = SubscriptionDataKey::TierAnalytics(tier_id.clone());
+        if let Some(mut analytics) = env.storage().persistent().get::<_, TierAnalytics>(&key) {
+            analytics.total_revenue += amount;
+            analytics.updated_at = env.ledger().timestamp();
+            env.storage().persistent().set(&key, &analytics);
+        }
+
+        let period = env.ledger().timestamp() / ANALYTICS_PERIOD;
+        let period_key = SubscriptionDataKey::TierPeriodRevenue(tier_id.clone(), period);
+        let booked: i128 = env.storage().persistent().get(&period_key).unwrap_or(0);
         env.storage()
             .persistent()
-            .get(&SubscriptionDataKey::TierAnalytics(tier_id))
-            .ok_or(Error::TierNotFound)
+            .set(&period_key, &(booked + amount));
+    }
+
+    /// Removes a subscription that stopped being active from its tier's
+    /// active count and cohort, and records the churn for this period.
+    pub(crate) fn record_tier_churn(env: &Env, subscription: &Subscription) {
+        if subscription.tier_id.is_empty() {
+            return;
+        }
+        let tier_id = &subscription.tier_id;
+        let now = env.ledger().timestamp();
+
+        let key = SubscriptionDataKey::TierAnalytics(tier_id.clone());
+        if let Some(mut analytics) = env.storage().persistent().get::<_, TierAnalytics>(&key) {
+            analytics.active_subscribers = analytics.active_subscribers.saturating_sub(1);
+            analytics.updated_at = now;
+            env.storage().persistent().set(&key, &analytics);
+        }
+
+        let cohort_key = SubscriptionDataKey::TierCohort(
+            tier_id.clone(),
+            subscription.created_at / ANALYTICS_PERIOD,
+        );
+        if let Some((created, churned)) =
+            env.storage().persistent().get::<_, (u32, u32)>(&cohort_key)
+        {
+            env.storage()
+                .persistent()
+                .set(&cohort_key, &(created, (churned + 1).min(created)));
+        }
+
+        let churn_key =
+            SubscriptionDataKey::TierPeriodChurn(tier_id.clone(), now / ANALYTICS_PERIOD);
+        let churned: u32 = env.storage().persistent().get(&churn_key).unwrap_or(0);
+        env.storage().persistent().set(&churn_key, &(churned + 1));
+    }
+
+    /// Counts a lapsed subscription that was renewed as active again and
+    /// retained in its cohort.
+    fn record_tier_reactivation(env: &Env, subscription: &Subscription) {
+        let tier_id = &subscription.tier_id;
+
+        let key = SubscriptionDataKey::TierAnalytics(tier_id.clone());
+        if let Some(mut analytics) = env.storage().persistent().get::<_, TierAnalytics>(&key) {
+            analytics.active_subscribers += 1;
+            analytics.updated_at = env.ledger().timestamp();
+            env.storage().persistent().set(&key, &analytics);
+        }
+
+        let cohort_key = SubscriptionDataKey::TierCohort(
+            tier_id.clone(),
+            subscription.created_at / ANALYTICS_PERIOD,
+        );
+        if let Some((created, churned)) =
+            env.storage().persistent().get::<_, (u32, u32)>(&cohort_key)
+        {
+            env.storage()
+                .persistent()
+                .set(&cohort_key, &(created, churned.saturating_sub(1)));
+        }
     }
 
     /// Updates analytics when a new subscription is created.
-    fn update_tier_analytics_on_subscribe(
+    pub(crate) fn update_tier_analytics_on_subscribe(
         env: &Env,
         tier_id: &String,
         amount: i128,
@@ -1355,10 +3175,23 @@ impl SubscriptionContract {
                 });
 
         analytics.active_subscribers += 1;
-        analytics.total_revenue += amount;
         analytics.updated_at = env.ledger().timestamp();
-
         env.storage().persistent().set(&key, &analytics);
+        Self::record_tier_revenue(env, tier_id, amount);
+
+        // New subscriptions join the cohort for the current period
+        let cohort_key = SubscriptionDataKey::TierCohort(
+            tier_id.clone(),
+            env.ledger().timestamp() / ANALYTICS_PERIOD,
+        );
+        let (created, churned): (u32, u32) = env
+            .storage()
+            .persistent()
+            .get(&cohort_key)
+            .unwrap_or((0, 0));
+        env.storage()
+            .persistent()
+            .set(&cohort_key, &(created + 1, churned));
         Ok(())
     }
 
@@ -1431,36 +3264,119 @@ impl SubscriptionContract {
     }
 
     /// Calculates prorated amount for tier change.
-    fn calculate_proration(
+    /// Prorates a tier change over the rest of the billing period, to the
+    /// second. The unused value of the current tier rounds down and the cost
+    /// of the new tier rounds up, so rounding never favours either side by
+    /// more than one unit and quotes are reproducible. The difference is
+    /// either charged or credited to the user's credit balance.
+    fn build_tier_change_quote(
         env: &Env,
         subscription: &Subscription,
         current_tier: &SubscriptionTier,
         new_tier: &SubscriptionTier,
-    ) -> Result<i128, Error> {
+    ) -> Result<TierChangeQuote, Error> {
         let current_time = env.ledger().timestamp();
+        let change_type = Self::determine_change_type(&current_tier.level, &new_tier.level)?;
+        let (period_secs, current_price, new_price): (u64, i128, i128) =
+            match subscription.billing_cycle {
+                BillingCycle::Monthly => (30 * 24 * 60 * 60, current_tier.price, new_tier.price),
+                BillingCycle::Annual => (
+                    365 * 24 * 60 * 60,
+                    current_tier.annual_price,
+                    new_tier.annual_price,
+                ),
+            };
+
+        // An expired subscription has nothing left to prorate: the new tier
+        // is charged in full
+        let (remaining_secs, unused_credit, new_cost) = if subscription.expires_at <= current_time {
+            (0, 0, new_price)
+        } else {
+            // Time beyond one full period (e.g. loyalty day credits) is not
+            // prorated
+            let remaining_secs = (subscription.expires_at - current_time).min(period_secs);
+            let period = period_secs as i128;
+            let unused_credit = Amount::new(current_price)
+                .and_then(|price| price.mul_div(remaining_secs as i128, period))
+                .ok_or(Error::TimestampOverflow)?
+                .value();
+            let new_cost = Amount::new(new_price)
+                .and_then(|price| price.mul_div_ceil(remaining_secs as i128, period))
+                .ok_or(Error::TimestampOverflow)?
+                .value();
+            (remaining_secs, unused_credit, new_cost)
+        };
 
-        // If subscription is expired, no proration needed
-        if subscription.expires_at <= current_time {
-            return Ok(new_tier.price);
-        }
+        let difference = new_cost - unused_credit;
+        Ok(TierChangeQuote {
+            subscription_id: subscription.id.clone(),
+            from_tier: current_tier.id.clone(),
+            to_tier: new_tier.id.clone(),
+            change_type,
+            remaining_secs,
+            unused_credit,
+            new_cost,
+            amount_due: difference.max(0),
+            credit: (-difference).max(0),
+        })
+    }
 
-        // Calculate remaining days
-        let remaining_seconds = subscription.expires_at - current_time;
-        let total_seconds: u64 = match subscription.billing_cycle {
-            BillingCycle::Monthly => 30 * 24 * 60 * 60,
-            BillingCycle::Annual => 365 * 24 * 60 * 60,
-        };
+    /// Previews the charge or credit a tier change would produce right now,
+    /// without committing to it.
+    pub fn get_tier_change_quote(
+        env: Env,
+        subscription_id: String,
+        new_tier_id: String,
+    ) -> Result<TierChangeQuote, Error> {
+        let subscription = Self::get_subscription(env.clone(), subscription_id)?;
+        let current_tier = Self::get_tier(env.clone(), subscription.tier_id.clone())?;
+        let new_tier = Self::get_tier(env.clone(), new_tier_id)?;
+        if !new_tier.is_active {
+            return Err(Error::TierNotActive);
+        }
+        Self::build_tier_change_quote(&env, &subscription, &current_tier, &new_tier)
+    }
 
-        // Calculate credit from current tier
-        let daily_rate_current = current_tier.price / (total_seconds as i128 / (24 * 60 * 60));
-        let credit = daily_rate_current * (remaining_seconds as i128 / (24 * 60 * 60));
+    /// Unspent tier-change credit held for a user, in USDC base units.
+    pub fn get_credit_balance(env: Env, user: Address) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&SubscriptionDataKey::UserCredit(user))
+            .unwrap_or(0)
+    }
 
-        // Calculate cost for new tier for remaining period
-        let daily_rate_new = new_tier.price / (total_seconds as i128 / (24 * 60 * 60));
-        let new_cost = daily_rate_new * (remaining_seconds as i128 / (24 * 60 * 60));
+    /// Adds `amount` to a user's credit balance.
+    fn issue_credit(env: &Env, user: &Address, amount: i128) -> Result<(), Error> {
+        let balance = Self::get_credit_balance(env.clone(), user.clone())
+            .checked_add(amount)
+            .ok_or(Error::TimestampOverflow)?;
+        env.storage()
+            .persistent()
+            .set(&SubscriptionDataKey::UserCredit(user.clone()), &balance);
+        Ok(())
+    }
 
-        // Prorated amount (positive = user pays, negative = credit)
-        Ok(new_cost - credit)
+    /// Spends as much of a user's credit as possible against `price` and
+    /// returns what is left to pay.
+    fn apply_credit(env: &Env, user: &Address, price: i128) -> i128 {
+        let balance = Self::get_credit_balance(env.clone(), user.clone());
+        let applied = balance.min(price).max(0);
+        if applied == 0 {
+            return price;
+        }
+        let key = SubscriptionDataKey::UserCredit(user.clone());
+        if balance == applied {
+            env.storage().persistent().remove(&key);
+        } else {
+            env.storage().persistent().set(&key, &(balance - applied));
+        }
+        CreditApplied {
+            user: user.clone(),
+            amount: applied,
+            remaining: balance - applied,
+        }
+        .publish(env);
+        price - applied
     }
 
     /// Generates a unique change request ID based on timestamp.
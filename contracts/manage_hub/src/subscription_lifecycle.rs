@@ -0,0 +1,94 @@
+use crate::errors::Error;
+use crate::lifecycle_errors::LifecycleError;
+use crate::types::{MembershipStatus, Subscription};
+
+// ---------------------------------------------------------------------------
+// Events
+// ---------------------------------------------------------------------------
+
+/// Something that changes a subscription's status.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SubscriptionEvent {
+    /// The owner or admin pauses the subscription.
+    Pause,
+    /// The owner or admin resumes a paused subscription.
+    Resume,
+    /// The owner cancels the subscription.
+    Cancel,
+    /// The owner pays for another period.
+    Renew,
+    /// The paid period ran out.
+    Expire,
+    /// An installment plan defaulted.
+    Lapse,
+    /// The owner brings a cancelled or expired subscription back.
+    Reactivate,
+}
+
+// ---------------------------------------------------------------------------
+// Module
+// ---------------------------------------------------------------------------
+
+/// Subscription status state machine.
+///
+/// Every status change of a subscription goes through [`Self::transition`],
+/// so the rules live in one table:
+///
+/// | from \ event | Pause  | Resume | Cancel   | Renew  | Expire  | Lapse    | Reactivate |
+/// |--------------|--------|--------|----------|--------|---------|----------|------------|
+/// | Active       | Paused |        | Inactive | Active | Expired | Inactive |            |
+/// | Paused       |        | Active | Inactive |        |         | Inactive |            |
+/// | GracePeriod  |        |        | Inactive | Active |         | Inactive |            |
+/// | Expired      |        |        | Inactive | Active |         |          | Active     |
+/// | Inactive     |        |        |          |        |         |          | Active     |
+/// | Revoked      |        |        |          |        |         |          |            |
+///
+/// Inactive (cancelled or lapsed) subscriptions can only be reactivated.
+/// Revoked is terminal: a new subscription has to be created.
+pub struct SubscriptionLifecycle;
+
+impl SubscriptionLifecycle {
+    /// Returns the status `event` moves a subscription in `from` to.
+    pub fn next_status(
+        from: &MembershipStatus,
+        event: SubscriptionEvent,
+    ) -> Result<MembershipStatus, LifecycleError> {
+        use MembershipStatus::*;
+        use SubscriptionEvent::*;
+
+        let to = match (from, event) {
+            (Active, Pause) => Paused,
+            (Paused, Resume) => Active,
+            (Active | Paused | GracePeriod | Expired, Cancel) => Inactive,
+            (Active | GracePeriod | Expired, Renew) => Active,
+            (Active, Expire) => Expired,
+            (Active | Paused | GracePeriod, Lapse) => Inactive,
+            (Expired | Inactive, Reactivate) => Active,
+            _ => {
+                return Err(LifecycleError::InvalidStateTransition {
+                    from: from.clone(),
+                    event,
+                })
+            }
+        };
+        Ok(to)
+    }
+
+    /// Returns the status `subscription` is effectively in at `now`: Active
+    /// past its expiry date counts as Expired.
+    pub fn effective_status(subscription: &Subscription, now: u64) -> MembershipStatus {
+        if subscription.status == MembershipStatus::Active && subscription.expires_at < now {
+            return MembershipStatus::Expired;
+        }
+        subscription.status.clone()
+    }
+
+    /// Applies `event` to `subscription` and returns its previous status.
+    pub fn transition(
+        subscription: &mut Subscription,
+        event: SubscriptionEvent,
+    ) -> Result<MembershipStatus, Error> {
+        let next = Self::next_status(&subscription.status, event)?;
+        Ok(core::mem::replace(&mut subscription.status, next))
+    }
+}
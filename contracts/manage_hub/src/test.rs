@@ -4,7 +4,41 @@ extern crate alloc;
 use alloc::format;
 
 use super::*;
-use crate::types::MembershipStatus;
+use crate::achievement_errors::AchievementError;
+use crate::admin_errors::AdminTransferError;
+use crate::attendance_errors::AttendanceError;
+use crate::billing_errors::BillingError;
+use crate::blacklist_errors::BlacklistError;
+use crate::booking_errors::BookingError;
+use crate::config_errors::ConfigChangeError;
+use crate::dispute_errors::DisputeError;
+use crate::entry_errors::EntryError;
+use crate::factory_errors::FactoryError;
+use crate::federation_errors::FederationError;
+use crate::fraction_errors::FractionError;
+use crate::guest_errors::GuestError;
+use crate::household_errors::HouseholdError;
+use crate::init_errors::InitError;
+use crate::installment_errors::InstallmentError;
+use crate::loyalty_errors::LoyaltyError;
+use crate::oracle_errors::OracleError;
+use crate::pause_errors::PauseError;
+use crate::pause_schedule_errors::PauseScheduleError;
+use crate::resource_errors::ResourceError;
+use crate::retention_errors::RetentionError;
+use crate::sale_errors::SaleError;
+use crate::staff_errors::StaffError;
+use crate::staking_errors::StakingError;
+use crate::tier_change_errors::TierChangeError;
+use crate::treasury_errors::TreasuryError;
+use crate::types::{
+    BadgeCriterion, BillingKind, BookingStatus, CancellationReason, EntryStatus, FeeBreakdown,
+    FeeConfig, FractionTransferPolicy, GraceFeatureAccess, InvoiceStatus, MembershipStatus,
+    NotificationKind, OutboxSubject, PauseAction, RegisterResourceParams, ReservationStatus,
+    ResourceKind, SnapshotRetentionConfig, TokenUpgradeChanges, UpgradeTarget, WinbackConfig,
+};
+use crate::upgrade_errors::UpgradeError;
+use crate::verification_errors::VerificationError;
 use crate::AttendanceAction;
 use soroban_sdk::map;
 use soroban_sdk::{
@@ -263,6 +297,7 @@ fn test_create_subscription_success() {
     let amount = 100_000i128;
     let duration = 2_592_000u64; // 30 days
 
+    client.set_admin(&admin);
     // Set USDC contract address
     client.set_usdc_contract(&admin, &payment_token);
 
@@ -304,6 +339,7 @@ fn test_renew_subscription_success() {
     let renewal_amount = 150_000i128;
     let duration = 2_592_000u64;
 
+    client.set_admin(&admin);
     // Set USDC contract and create initial subscription
     client.set_usdc_contract(&admin, &payment_token);
     client.create_subscription(
@@ -333,6 +369,71 @@ fn test_renew_subscription_success() {
     assert_eq!(action, String::from_str(&env, "subscription_renewed"));
 }
 
+#[test]
+fn test_subscription_event_ids_distinct_across_subscriptions() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(Contract, ());
+    let client = ContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let user1 = Address::generate(&env);
+    let user2 = Address::generate(&env);
+    let payment_token = Address::generate(&env);
+    client.set_admin(&admin);
+    client.set_usdc_contract(&admin, &payment_token);
+
+    // Same-length IDs used to map to the same log ID
+    let sub_a = String::from_str(&env, "sub_aaa");
+    let sub_b = String::from_str(&env, "sub_bbb");
+    client.create_subscription(&sub_a, &user1, &payment_token, &100_000, &2_592_000);
+    client.create_subscription(&sub_b, &user2, &payment_token, &100_000, &2_592_000);
+
+    let log_a = client.get_logs_for_user(&user1).get(0).unwrap();
+    let log_b = client.get_logs_for_user(&user2).get(0).unwrap();
+    assert_ne!(log_a.id, log_b.id);
+
+    // Neither log was overwritten by the other
+    assert_eq!(client.get_attendance_log(&log_a.id).unwrap().user_id, user1);
+    assert_eq!(client.get_attendance_log(&log_b.id).unwrap().user_id, user2);
+}
+
+#[test]
+fn test_subscription_event_ids_distinct_within_one_ledger() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(Contract, ());
+    let client = ContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let user = Address::generate(&env);
+    let payment_token = Address::generate(&env);
+    let subscription_id = String::from_str(&env, "sub_same_ledger");
+    client.set_admin(&admin);
+    client.set_usdc_contract(&admin, &payment_token);
+
+    client.create_subscription(
+        &subscription_id,
+        &user,
+        &payment_token,
+        &100_000,
+        &2_592_000,
+    );
+    client.renew_subscription(&subscription_id, &payment_token, &100_000, &2_592_000);
+    client.renew_subscription(&subscription_id, &payment_token, &100_000, &2_592_000);
+
+    let logs = client.get_logs_for_user(&user);
+    assert_eq!(logs.len(), 3);
+    let first = logs.get(0).unwrap().id;
+    let second = logs.get(1).unwrap().id;
+    let third = logs.get(2).unwrap().id;
+    assert_ne!(first, second);
+    assert_ne!(second, third);
+    assert_ne!(first, third);
+}
+
 #[test]
 #[should_panic(expected = "HostError: Error(Contract, #10)")]
 fn test_renew_subscription_not_found() {
@@ -348,6 +449,7 @@ fn test_renew_subscription_not_found() {
     let amount = 100_000i128;
     let duration = 2_592_000u64;
 
+    client.set_admin(&admin);
     client.set_usdc_contract(&admin, &payment_token);
 
     // Try to renew non-existent subscription
@@ -370,6 +472,7 @@ fn test_create_subscription_invalid_amount() {
     let invalid_amount = 0i128; // Invalid: zero amount
     let duration = 2_592_000u64;
 
+    client.set_admin(&admin);
     client.set_usdc_contract(&admin, &payment_token);
 
     // Try to create subscription with invalid amount
@@ -399,6 +502,7 @@ fn test_create_subscription_invalid_token() {
     let amount = 100_000i128;
     let duration = 2_592_000u64;
 
+    client.set_admin(&admin);
     client.set_usdc_contract(&admin, &usdc_token);
 
     // Try to create subscription with wrong payment token
@@ -426,6 +530,7 @@ fn test_subscription_cross_contract_call_integration() {
     let amount = 100_000i128;
     let duration = 2_592_000u64;
 
+    client.set_admin(&admin);
     // Setup and create subscription
     client.set_usdc_contract(&admin, &payment_token);
     client.create_subscription(&subscription_id, &user, &payment_token, &amount, &duration);
@@ -464,6 +569,7 @@ fn test_multiple_subscription_events_logged() {
     let amount = 100_000i128;
     let duration = 2_592_000u64;
 
+    client.set_admin(&admin);
     client.set_usdc_contract(&admin, &payment_token);
 
     // Create multiple subscriptions
@@ -520,6 +626,7 @@ fn test_cancel_subscription_success() {
     let amount = 100_000i128;
     let duration = 2_592_000u64;
 
+    client.set_admin(&admin);
     // Setup and create subscription
     client.set_usdc_contract(&admin, &payment_token);
     client.create_subscription(&subscription_id, &user, &payment_token, &amount, &duration);
@@ -529,7 +636,7 @@ fn test_cancel_subscription_success() {
     assert_eq!(subscription.status, MembershipStatus::Active);
 
     // Cancel subscription
-    client.cancel_subscription(&subscription_id);
+    client.cancel_subscription(&subscription_id, &None);
 
     // Verify subscription is now inactive
     let cancelled_subscription = client.get_subscription(&subscription_id);
@@ -550,7 +657,7 @@ fn test_cancel_subscription_not_found() {
     let subscription_id = String::from_str(&env, "nonexistent_sub");
 
     // Try to cancel non-existent subscription
-    client.cancel_subscription(&subscription_id);
+    client.cancel_subscription(&subscription_id, &None);
 }
 
 #[test]
@@ -569,6 +676,7 @@ fn test_create_duplicate_subscription() {
     let amount = 100_000i128;
     let duration = 2_592_000u64;
 
+    client.set_admin(&admin);
     client.set_usdc_contract(&admin, &payment_token);
     client.create_subscription(&subscription_id, &user, &payment_token, &amount, &duration);
 
@@ -591,6 +699,7 @@ fn test_subscription_renewal_extends_from_expiry() {
     let amount = 100_000i128;
     let duration = 2_592_000u64; // 30 days
 
+    client.set_admin(&admin);
     // Setup and create subscription
     client.set_usdc_contract(&admin, &payment_token);
     client.create_subscription(&subscription_id, &user, &payment_token, &amount, &duration);
@@ -626,6 +735,7 @@ fn test_subscription_renewal_after_expiry() {
     let amount = 100_000i128;
     let duration = 2_592_000u64;
 
+    client.set_admin(&admin);
     // Setup and create subscription
     client.set_usdc_contract(&admin, &payment_token);
     client.create_subscription(&subscription_id, &user, &payment_token, &amount, &duration);
@@ -662,6 +772,7 @@ fn test_get_subscription_retrieves_correct_data() {
     let amount = 250_000i128;
     let duration = 5_184_000u64; // 60 days
 
+    client.set_admin(&admin);
     client.set_usdc_contract(&admin, &payment_token);
     client.create_subscription(&subscription_id, &user, &payment_token, &amount, &duration);
 
@@ -706,6 +817,7 @@ fn test_subscription_payment_validation() {
     let amount = 100_000i128;
     let duration = 2_592_000u64;
 
+    client.set_admin(&admin);
     // Setup USDC contract
     client.set_usdc_contract(&admin, &payment_token);
 
@@ -731,6 +843,7 @@ fn test_multiple_users_multiple_subscriptions() {
     let amount = 100_000i128;
     let duration = 2_592_000u64;
 
+    client.set_admin(&admin);
     client.set_usdc_contract(&admin, &payment_token);
 
     // Create subscriptions for different users
@@ -771,6 +884,7 @@ fn test_subscription_amount_updates_on_renewal() {
     let renewal_amount = 200_000i128;
     let duration = 2_592_000u64;
 
+    client.set_admin(&admin);
     client.set_usdc_contract(&admin, &payment_token);
     client.create_subscription(
         &subscription_id,
@@ -807,6 +921,7 @@ fn test_subscription_created_event_emitted() {
     let amount = 100_000i128;
     let duration = 2_592_000u64;
 
+    client.set_admin(&admin);
     // Set USDC contract
     client.set_usdc_contract(&admin, &payment_token);
 
@@ -836,12 +951,13 @@ fn test_subscription_cancelled_event_emitted() {
     let amount = 100_000i128;
     let duration = 2_592_000u64;
 
+    client.set_admin(&admin);
     // Set USDC contract and create subscription
     client.set_usdc_contract(&admin, &payment_token);
     client.create_subscription(&subscription_id, &user, &payment_token, &amount, &duration);
 
     // Cancel subscription
-    client.cancel_subscription(&subscription_id);
+    client.cancel_subscription(&subscription_id, &None);
 
     // Verify subscription was cancelled
     let subscription = client.get_subscription(&subscription_id);
@@ -863,6 +979,7 @@ fn test_subscription_renewed_event_emitted() {
     let amount = 100_000i128;
     let duration = 2_592_000u64;
 
+    client.set_admin(&admin);
     // Set USDC contract and create subscription
     client.set_usdc_contract(&admin, &payment_token);
     client.create_subscription(&subscription_id, &user, &payment_token, &amount, &duration);
@@ -889,6 +1006,7 @@ fn test_usdc_contract_set_event_emitted() {
     let admin = Address::generate(&env);
     let payment_token = Address::generate(&env);
 
+    client.set_admin(&admin);
     // Set USDC contract
     client.set_usdc_contract(&admin, &payment_token);
 
@@ -915,6 +1033,7 @@ fn test_multiple_events_emitted_in_sequence() {
     let amount = 100_000i128;
     let duration = 2_592_000u64;
 
+    client.set_admin(&admin);
     // Execute sequence of operations
     client.set_usdc_contract(&admin, &payment_token);
     client.create_subscription(&subscription_id, &user, &payment_token, &amount, &duration);
@@ -927,7 +1046,7 @@ fn test_multiple_events_emitted_in_sequence() {
     let sub_after_renew = client.get_subscription(&subscription_id);
     assert!(sub_after_renew.expires_at > sub_after_create.expires_at);
 
-    client.cancel_subscription(&subscription_id);
+    client.cancel_subscription(&subscription_id, &None);
 
     let sub_after_cancel = client.get_subscription(&subscription_id);
     assert_eq!(sub_after_cancel.status, MembershipStatus::Inactive);
@@ -950,8 +1069,8 @@ fn test_pause_subscription_success() {
     let amount = 100_000i128;
     let duration = 2_592_000u64; // 30 days
 
-    // Setup admin and USDC contract
     client.set_admin(&admin);
+    // Setup admin and USDC contract
     client.set_usdc_contract(&admin, &payment_token);
     client.create_subscription(&subscription_id, &user, &payment_token, &amount, &duration);
 
@@ -997,8 +1116,8 @@ fn test_resume_subscription_success() {
     let amount = 100_000i128;
     let duration = 2_592_000u64;
 
-    // Setup admin and create subscription
     client.set_admin(&admin);
+    // Setup admin and create subscription
     client.set_usdc_contract(&admin, &payment_token);
     client.create_subscription(&subscription_id, &user, &payment_token, &amount, &duration);
 
@@ -1049,8 +1168,8 @@ fn test_admin_pause_subscription() {
     let amount = 100_000i128;
     let duration = 2_592_000u64;
 
-    // Setup admin and create subscription
     client.set_admin(&admin);
+    // Setup admin and create subscription
     client.set_usdc_contract(&admin, &payment_token);
     client.create_subscription(&subscription_id, &user, &payment_token, &amount, &duration);
 
@@ -1086,8 +1205,8 @@ fn test_admin_resume_subscription() {
     let amount = 100_000i128;
     let duration = 2_592_000u64;
 
-    // Setup admin and create subscription
     client.set_admin(&admin);
+    // Setup admin and create subscription
     client.set_usdc_contract(&admin, &payment_token);
     client.create_subscription(&subscription_id, &user, &payment_token, &amount, &duration);
 
@@ -1120,8 +1239,8 @@ fn test_pause_config_management() {
 
     let admin = Address::generate(&env);
 
-    // Set admin first
     client.set_admin(&admin);
+    // Set admin first
 
     // Get default config
     let default_config = client.get_pause_config();
@@ -1134,6 +1253,7 @@ fn test_pause_config_management() {
         max_pause_duration: 1_296_000, // 15 days
         max_pause_count: 2,
         min_active_time: 172_800, // 2 days
+        policy: types::PausePolicy::ExtendExpiry,
     };
 
     client.set_pause_config(&admin, &custom_config);
@@ -1160,8 +1280,8 @@ fn test_pause_stats() {
     let amount = 100_000i128;
     let duration = 2_592_000u64;
 
-    // Setup admin and create subscription
     client.set_admin(&admin);
+    // Setup admin and create subscription
     client.set_usdc_contract(&admin, &payment_token);
     client.create_subscription(&subscription_id, &user, &payment_token, &amount, &duration);
 
@@ -1209,8 +1329,8 @@ fn test_pause_already_paused_subscription() {
     let amount = 100_000i128;
     let duration = 2_592_000u64;
 
-    // Setup admin and create subscription
     client.set_admin(&admin);
+    // Setup admin and create subscription
     client.set_usdc_contract(&admin, &payment_token);
     client.create_subscription(&subscription_id, &user, &payment_token, &amount, &duration);
 
@@ -1238,6 +1358,7 @@ fn test_resume_not_paused_subscription() {
     let amount = 100_000i128;
     let duration = 2_592_000u64;
 
+    client.set_admin(&admin);
     // Setup and create subscription (but don't pause)
     client.set_usdc_contract(&admin, &payment_token);
     client.create_subscription(&subscription_id, &user, &payment_token, &amount, &duration);
@@ -1262,8 +1383,8 @@ fn test_renew_paused_subscription() {
     let amount = 100_000i128;
     let duration = 2_592_000u64;
 
-    // Setup admin and create subscription
     client.set_admin(&admin);
+    // Setup admin and create subscription
     client.set_usdc_contract(&admin, &payment_token);
     client.create_subscription(&subscription_id, &user, &payment_token, &amount, &duration);
 
@@ -1314,8 +1435,8 @@ fn test_renew_token_success() {
     let token_id = BytesN::<32>::random(&env);
     let tier_id = String::from_str(&env, "tier_basic");
 
-    // Setup
     client.set_admin(&admin);
+    // Setup
     client.set_usdc_contract(&admin, &payment_token);
 
     // Create tier
@@ -1363,8 +1484,8 @@ fn test_renew_token_tier_not_found() {
     let payment_token = Address::generate(&env);
     let token_id = BytesN::<32>::random(&env);
 
-    // Setup
     client.set_admin(&admin);
+    // Setup
     client.set_usdc_contract(&admin, &payment_token);
 
     // Issue token
@@ -1392,8 +1513,8 @@ fn test_grace_period_entry() {
     let user = Address::generate(&env);
     let token_id = BytesN::<32>::random(&env);
 
-    // Setup
     client.set_admin(&admin);
+    // Setup
 
     // Issue token with short expiry
     let expiry_date = env.ledger().timestamp() + 100;
@@ -1423,8 +1544,8 @@ fn test_transfer_blocked_in_grace_period() {
     let new_user = Address::generate(&env);
     let token_id = BytesN::<32>::random(&env);
 
-    // Setup
     client.set_admin(&admin);
+    // Setup
 
     // Issue token with short expiry
     let expiry_date = env.ledger().timestamp() + 100;
@@ -1452,8 +1573,8 @@ fn test_renewal_history_tracking() {
     let token_id = BytesN::<32>::random(&env);
     let tier_id = String::from_str(&env, "tier_pro");
 
-    // Setup
     client.set_admin(&admin);
+    // Setup
     client.set_usdc_contract(&admin, &payment_token);
 
     // Create tier
@@ -1505,15 +1626,15 @@ fn test_auto_renewal_settings() {
     let payment_token = Address::generate(&env);
     let token_id = BytesN::<32>::random(&env);
 
-    // Setup
     client.set_admin(&admin);
+    // Setup
 
     // Issue token
     let expiry_date = env.ledger().timestamp() + 30 * 24 * 60 * 60;
     client.issue_token(&token_id, &user, &expiry_date);
 
     // Enable auto-renewal
-    client.set_auto_renewal(&token_id, &true, &payment_token);
+    client.set_auto_renewal(&token_id, &true, &payment_token, &0);
 
     // Get settings
     let settings = client.get_auto_renewal_settings(&user);
@@ -1537,8 +1658,8 @@ fn test_auto_renewal_eligibility() {
     let user = Address::generate(&env);
     let token_id = BytesN::<32>::random(&env);
 
-    // Setup with 1 day notice period
     client.set_admin(&admin);
+    // Setup with 1 day notice period
     let grace_period = 7 * 24 * 60 * 60;
     let notice_period = 24 * 60 * 60;
     client.set_renewal_config(&grace_period, &notice_period, &true);
@@ -1572,8 +1693,8 @@ fn test_grace_period_expired() {
     let user = Address::generate(&env);
     let token_id = BytesN::<32>::random(&env);
 
-    // Setup with short grace period
     client.set_admin(&admin);
+    // Setup with short grace period
     let grace_period = 100; // 100 seconds
     let notice_period = 50;
     client.set_renewal_config(&grace_period, &notice_period, &true);
@@ -1607,8 +1728,8 @@ fn test_renewal_extends_from_current_expiry() {
     let token_id = BytesN::<32>::random(&env);
     let tier_id = String::from_str(&env, "tier_basic");
 
-    // Setup
     client.set_admin(&admin);
+    // Setup
     client.set_usdc_contract(&admin, &payment_token);
 
     // Create tier
@@ -1651,8 +1772,8 @@ fn test_renewal_after_expiry_extends_from_current_time() {
     let token_id = BytesN::<32>::random(&env);
     let tier_id = String::from_str(&env, "tier_basic");
 
-    // Setup
     client.set_admin(&admin);
+    // Setup
     client.set_usdc_contract(&admin, &payment_token);
 
     // Create tier
@@ -1702,8 +1823,8 @@ fn test_renewal_clears_grace_period() {
     let token_id = BytesN::<32>::random(&env);
     let tier_id = String::from_str(&env, "tier_basic");
 
-    // Setup
     client.set_admin(&admin);
+    // Setup
     client.set_usdc_contract(&admin, &payment_token);
 
     // Create tier
@@ -1830,7 +1951,7 @@ fn test_transfer_from_rejects_expired_allowance() {
     env.ledger().with_mut(|l| l.timestamp += 61);
 
     let result = client.try_transfer_from(&token_id, &owner, &receiver, &spender, &100);
-    assert_eq!(result, Err(Ok(Error::Unauthorized)));
+    assert_eq!(result, Err(Ok(Error::Unauthorized.into())));
 
     let allowance = client.get_allowance(&token_id, &owner, &spender);
     assert!(allowance.is_none());
@@ -1858,7 +1979,7 @@ fn test_revoke_allowance_blocks_transfer_from() {
     client.revoke_allowance(&token_id, &spender);
 
     let result = client.try_transfer_from(&token_id, &owner, &receiver, &spender, &100);
-    assert_eq!(result, Err(Ok(Error::Unauthorized)));
+    assert_eq!(result, Err(Ok(Error::Unauthorized.into())));
 }
 
 #[test]
@@ -1882,7 +2003,7 @@ fn test_transfer_from_rejects_excessive_allowance_spend() {
     client.approve(&token_id, &spender, &100, &None);
 
     let result = client.try_transfer_from(&token_id, &owner, &receiver, &spender, &200);
-    assert_eq!(result, Err(Ok(Error::InsufficientBalance)));
+    assert_eq!(result, Err(Ok(Error::InsufficientBalance.into())));
 }
 
 #[test]
@@ -1902,7 +2023,7 @@ fn test_approve_rejects_self_as_spender() {
     client.issue_token(&token_id, &owner, &expiry_date);
 
     let result = client.try_approve(&token_id, &owner, &500, &None);
-    assert_eq!(result, Err(Ok(Error::Unauthorized)));
+    assert_eq!(result, Err(Ok(Error::Unauthorized.into())));
 }
 
 // ==================== Token Fractionalization Tests ====================
@@ -2172,7 +2293,7 @@ fn test_issue_token_blocked_when_paused() {
     let user = Address::generate(&env);
     let expiry = env.ledger().timestamp() + 100_000;
     let result = client.try_issue_token(&token_id, &user, &expiry);
-    assert_eq!(result, Err(Ok(Error::SubscriptionPaused)));
+    assert_eq!(result, Err(Ok(PauseError::ContractPaused.into())));
 }
 
 #[test]
@@ -2194,7 +2315,7 @@ fn test_transfer_token_blocked_when_paused() {
 
     let new_user = Address::generate(&env);
     let result = client.try_transfer_token(&token_id, &new_user);
-    assert_eq!(result, Err(Ok(Error::SubscriptionPaused)));
+    assert_eq!(result, Err(Ok(PauseError::ContractPaused.into())));
 }
 
 #[test]
@@ -2259,7 +2380,7 @@ fn test_emergency_unpause_rejects_non_admin() {
 
     let stranger = Address::generate(&env);
     let result = client.try_emergency_unpause(&stranger);
-    assert_eq!(result, Err(Ok(Error::Unauthorized)));
+    assert_eq!(result, Err(Ok(Error::Unauthorized.into())));
 }
 
 #[test]
@@ -2278,7 +2399,7 @@ fn test_unpause_blocked_while_time_lock_active() {
 
     // Attempt to unpause before the time lock expires.
     let result = client.try_emergency_unpause(&admin);
-    assert_eq!(result, Err(Ok(Error::PauseTooEarly)));
+    assert_eq!(result, Err(Ok(PauseError::TimeLockActive.into())));
 }
 
 #[test]
@@ -2384,7 +2505,7 @@ fn test_pause_token_operations_sets_token_paused() {
 
     assert!(!client.is_token_paused(&token_id));
 
-    client.pause_token_operations(&admin, &token_id, &None);
+    client.pause_token_operations(&admin, &token_id, &None, &None, &None);
 
     assert!(client.is_token_paused(&token_id));
 }
@@ -2404,11 +2525,11 @@ fn test_transfer_blocked_by_per_token_pause() {
 
     client.set_admin(&admin);
     client.issue_token(&token_id, &user, &expiry);
-    client.pause_token_operations(&admin, &token_id, &None);
+    client.pause_token_operations(&admin, &token_id, &None, &None, &None);
 
     let new_user = Address::generate(&env);
     let result = client.try_transfer_token(&token_id, &new_user);
-    assert_eq!(result, Err(Ok(Error::SubscriptionPaused)));
+    assert_eq!(result, Err(Ok(PauseError::TokenOpsPaused.into())));
 }
 
 #[test]
@@ -2430,7 +2551,7 @@ fn test_per_token_pause_does_not_affect_other_tokens() {
     client.issue_token(&other_id, &user, &expiry);
 
     // Pause only the first token.
-    client.pause_token_operations(&admin, &token_id, &None);
+    client.pause_token_operations(&admin, &token_id, &None, &None, &None);
 
     // The second token should transfer fine.
     let new_user = Address::generate(&env);
@@ -2454,7 +2575,7 @@ fn test_pause_token_operations_rejects_non_admin() {
     client.issue_token(&token_id, &user, &expiry);
 
     let stranger = Address::generate(&env);
-    let result = client.try_pause_token_operations(&stranger, &token_id, &None);
+    let result = client.try_pause_token_operations(&stranger, &token_id, &None, &None, &None);
     assert_eq!(result, Err(Ok(Error::Unauthorized)));
 }
 
@@ -2470,7 +2591,7 @@ fn test_pause_token_operations_rejects_nonexistent_token() {
     client.set_admin(&admin);
 
     let ghost_id = BytesN::<32>::random(&env);
-    let result = client.try_pause_token_operations(&admin, &ghost_id, &None);
+    let result = client.try_pause_token_operations(&admin, &ghost_id, &None, &None, &None);
     assert_eq!(result, Err(Ok(Error::TokenNotFound)));
 }
 
@@ -2489,7 +2610,7 @@ fn test_unpause_token_operations_clears_token_pause() {
 
     client.set_admin(&admin);
     client.issue_token(&token_id, &user, &expiry);
-    client.pause_token_operations(&admin, &token_id, &None);
+    client.pause_token_operations(&admin, &token_id, &None, &None, &None);
     assert!(client.is_token_paused(&token_id));
 
     client.unpause_token_operations(&admin, &token_id);
@@ -2511,7 +2632,7 @@ fn test_transfer_succeeds_after_token_unpause() {
 
     client.set_admin(&admin);
     client.issue_token(&token_id, &user, &expiry);
-    client.pause_token_operations(&admin, &token_id, &None);
+    client.pause_token_operations(&admin, &token_id, &None, &None, &None);
     client.unpause_token_operations(&admin, &token_id);
 
     let new_user = Address::generate(&env);
@@ -2533,11 +2654,11 @@ fn test_unpause_token_operations_rejects_non_admin() {
 
     client.set_admin(&admin);
     client.issue_token(&token_id, &user, &expiry);
-    client.pause_token_operations(&admin, &token_id, &None);
+    client.pause_token_operations(&admin, &token_id, &None, &None, &None);
 
     let stranger = Address::generate(&env);
     let result = client.try_unpause_token_operations(&stranger, &token_id);
-    assert_eq!(result, Err(Ok(Error::Unauthorized)));
+    assert_eq!(result, Err(Ok(Error::Unauthorized.into())));
 }
 
 #[test]
@@ -2558,7 +2679,7 @@ fn test_global_unpause_does_not_lift_per_token_pause() {
 
     // Apply both pauses.
     client.emergency_pause(&admin, &None, &None, &None);
-    client.pause_token_operations(&admin, &token_id, &None);
+    client.pause_token_operations(&admin, &token_id, &None, &None, &None);
 
     // Lift only the global pause.
     client.emergency_unpause(&admin);
@@ -2566,7 +2687,7 @@ fn test_global_unpause_does_not_lift_per_token_pause() {
     // Transfer should still be blocked by the per-token pause.
     let new_user = Address::generate(&env);
     let result = client.try_transfer_token(&token_id, &new_user);
-    assert_eq!(result, Err(Ok(Error::SubscriptionPaused)));
+    assert_eq!(result, Err(Ok(PauseError::TokenOpsPaused.into())));
 }
 
 #[test]
@@ -2585,7 +2706,7 @@ fn test_both_pauses_must_be_cleared_before_transfer() {
     client.set_admin(&admin);
     client.issue_token(&token_id, &user, &expiry);
     client.emergency_pause(&admin, &None, &None, &None);
-    client.pause_token_operations(&admin, &token_id, &None);
+    client.pause_token_operations(&admin, &token_id, &None, &None, &None);
 
     client.emergency_unpause(&admin);
     client.unpause_token_operations(&admin, &token_id);
@@ -2595,6 +2716,154 @@ fn test_both_pauses_must_be_cleared_before_transfer() {
     client.transfer_token(&token_id, &new_user);
 }
 
+#[test]
+fn test_token_pause_auto_unpauses_after_deadline() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(Contract, ());
+    let client = ContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let user = Address::generate(&env);
+    let token_id = BytesN::<32>::random(&env);
+    client.set_admin(&admin);
+    client.issue_token(&token_id, &user, &(env.ledger().timestamp() + 100_000));
+
+    let paused_at = env.ledger().timestamp();
+    client.pause_token_operations(&admin, &token_id, &None, &Some(3_600), &None);
+    assert!(client.is_token_paused(&token_id));
+
+    env.ledger().with_mut(|l| l.timestamp = paused_at + 3_600);
+    assert!(!client.is_token_paused(&token_id));
+    client.transfer_token(&token_id, &Address::generate(&env));
+
+    let history = client.get_token_pause_history(&token_id, &0, &10);
+    assert_eq!(history.len(), 1);
+    let record = history.get(0).unwrap();
+    assert_eq!(record.auto_unpause_at, Some(paused_at + 3_600));
+    assert_eq!(record.unpaused_at, Some(paused_at + 3_600));
+    assert_eq!(record.unpaused_by, None);
+    assert_eq!(record.duration, 3_600);
+}
+
+#[test]
+fn test_token_pause_time_lock_blocks_unpause() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(Contract, ());
+    let client = ContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let guardian = Address::generate(&env);
+    let user = Address::generate(&env);
+    let token_id = BytesN::<32>::random(&env);
+    client.set_admin(&admin);
+    client.add_guardian(&admin, &guardian);
+    client.issue_token(&token_id, &user, &(env.ledger().timestamp() + 100_000));
+
+    // Guardians cannot set a time lock that would also bind the admin
+    let result =
+        client.try_pause_token_operations(&guardian, &token_id, &None, &None, &Some(3_600));
+    assert_eq!(result, Err(Ok(Error::Unauthorized)));
+
+    let paused_at = env.ledger().timestamp();
+    client.pause_token_operations(&admin, &token_id, &None, &None, &Some(3_600));
+    let result = client.try_unpause_token_operations(&admin, &token_id);
+    assert_eq!(result, Err(Ok(PauseError::TimeLockActive.into())));
+
+    env.ledger().with_mut(|l| l.timestamp = paused_at + 3_600);
+    assert!(client.is_token_paused(&token_id));
+    client.unpause_token_operations(&admin, &token_id);
+    assert!(!client.is_token_paused(&token_id));
+
+    let record = client
+        .get_token_pause_history(&token_id, &0, &10)
+        .get(0)
+        .unwrap();
+    assert_eq!(record.time_lock_until, Some(paused_at + 3_600));
+    assert_eq!(record.unpaused_by, Some(admin));
+    assert_eq!(record.duration, 3_600);
+}
+
+#[test]
+fn test_batch_pause_reports_per_token_results() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(Contract, ());
+    let client = ContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let user = Address::generate(&env);
+    client.set_admin(&admin);
+
+    let token_a = BytesN::<32>::random(&env);
+    let token_b = BytesN::<32>::random(&env);
+    let missing = BytesN::<32>::random(&env);
+    let expiry = env.ledger().timestamp() + 100_000;
+    client.issue_token(&token_a, &user, &expiry);
+    client.issue_token(&token_b, &user, &expiry);
+
+    let token_ids = soroban_sdk::vec![&env, token_a.clone(), missing.clone(), token_b.clone()];
+    let results = client.batch_pause_token_operations(&admin, &token_ids, &None);
+    assert_eq!(results.len(), 3);
+    assert!(results.get(0).unwrap().success);
+    let failed = results.get(1).unwrap();
+    assert_eq!(failed.token_id, missing);
+    assert!(!failed.success);
+    assert_eq!(failed.error_code, Some(Error::TokenNotFound as u32));
+    assert!(results.get(2).unwrap().success);
+    assert!(client.is_token_paused(&token_a));
+    assert!(client.is_token_paused(&token_b));
+
+    let results = client.batch_unpause_token_operations(&admin, &token_ids);
+    assert!(results.get(0).unwrap().success);
+    assert!(!results.get(1).unwrap().success);
+    assert!(!client.is_token_paused(&token_a));
+    assert!(!client.is_token_paused(&token_b));
+
+    let empty = soroban_sdk::Vec::new(&env);
+    let result = client.try_batch_pause_token_operations(&admin, &empty, &None);
+    assert_eq!(result, Err(Ok(Error::Unauthorized)));
+}
+
+#[test]
+fn test_pause_tokens_by_owner() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(Contract, ());
+    let client = ContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let compromised = Address::generate(&env);
+    let other = Address::generate(&env);
+    client.set_admin(&admin);
+
+    let expiry = env.ledger().timestamp() + 100_000;
+    let token_a = BytesN::<32>::random(&env);
+    let token_b = BytesN::<32>::random(&env);
+    let token_other = BytesN::<32>::random(&env);
+    client.issue_token(&token_a, &compromised, &expiry);
+    client.issue_token(&token_b, &compromised, &expiry);
+    client.issue_token(&token_other, &other, &expiry);
+
+    let reason = Some(String::from_str(&env, "compromised key"));
+    let results = client.pause_tokens_by_owner(&admin, &compromised, &reason);
+    assert_eq!(results.len(), 2);
+    assert!(results.iter().all(|result| result.success));
+    assert!(client.is_token_paused(&token_a));
+    assert!(client.is_token_paused(&token_b));
+    assert!(!client.is_token_paused(&token_other));
+
+    let stranger = Address::generate(&env);
+    let result = client.try_unpause_tokens_by_owner(&stranger, &compromised);
+    assert_eq!(result, Err(Ok(Error::Unauthorized)));
+
+    let results = client.unpause_tokens_by_owner(&admin, &compromised);
+    assert_eq!(results.len(), 2);
+    assert!(!client.is_token_paused(&token_a));
+    assert!(!client.is_token_paused(&token_b));
+}
+
 // ==================== Token Staking Tests ====================
 
 /// Helper: set up env, register contract, register a staking token, and create
@@ -2621,6 +2890,8 @@ fn setup_staking_env<'a>(
     let config = crate::types::StakingConfig {
         staking_enabled: true,
         emergency_unstake_penalty_bps: 1_000, // 10 %
+        max_slash_bps: 2_000,                 // 20 %
+        unstake_cooldown: 0,
         staking_token: staking_token.address(),
         reward_pool: reward_token.address(),
     };
@@ -2656,6 +2927,8 @@ fn test_set_staking_config_success() {
     let config = crate::types::StakingConfig {
         staking_enabled: true,
         emergency_unstake_penalty_bps: 500,
+        max_slash_bps: 0,
+        unstake_cooldown: 0,
         staking_token: staking_token.address(),
         reward_pool: reward_token.address(),
     };
@@ -2805,6 +3078,8 @@ fn test_staking_disabled_prevents_stake() {
     let config = crate::types::StakingConfig {
         staking_enabled: false,
         emergency_unstake_penalty_bps: 1_000,
+        max_slash_bps: 0,
+        unstake_cooldown: 0,
         staking_token: staking_token.address(),
         reward_pool: reward_token.address(),
     };
@@ -3131,7 +3406,7 @@ fn test_rollback_recorded_in_history() {
 }
 
 #[test]
-#[should_panic(expected = "HostError")]
+#[should_panic(expected = "HostError: Error(Contract, #300)")]
 fn test_upgrade_fails_when_disabled() {
     let env = Env::default();
     env.mock_all_auths();
@@ -3166,7 +3441,7 @@ fn test_upgrade_fails_when_disabled() {
 }
 
 #[test]
-#[should_panic(expected = "HostError")]
+#[should_panic(expected = "HostError: Error(Contract, #303)")]
 fn test_upgrade_fails_without_config() {
     let env = Env::default();
     env.mock_all_auths();
@@ -3193,71 +3468,283 @@ fn test_upgrade_fails_without_config() {
 }
 
 #[test]
-#[should_panic(expected = "HostError")]
+#[should_panic(expected = "HostError: Error(Contract, #304)")]
 fn test_rollback_fails_without_snapshot() {
     let (env, client, admin, _user, token_id) = setup_upgrade_env();
     let _ = env;
 
-    // Never upgraded — no snapshot for version 0 exists yet
-    // (snapshot is only stored when an upgrade happens, not at mint time)
-    // Rolling back to version 5 (which doesn't exist) must fail
+    // Never upgraded — only the version-0 snapshot from mint exists, so
+    // rolling back to version 5 must fail
     client.rollback_token_upgrade(&admin, &token_id, &5);
 }
 
-// ==================== Token Royalty Tests ====================
-
 #[test]
-fn test_royalty_config() {
-    let env = Env::default();
-    env.mock_all_auths();
-
-    let contract_id = env.register(Contract, ());
-    let client = ContractClient::new(&env, &contract_id);
+fn test_rollback_to_mint_state_without_upgrade() {
+    let (env, client, admin, _user, token_id) = setup_upgrade_env();
+    let minted = client.get_token(&token_id);
 
-    let admin = Address::generate(&env);
-    client.set_admin(&admin);
+    let snapshot = client.get_version_snapshot(&token_id, &0).unwrap();
+    assert_eq!(snapshot.expiry_date, minted.expiry_date);
+    assert_eq!(snapshot.status, MembershipStatus::Active);
 
-    let token_id = BytesN::<32>::random(&env);
-    let owner = Address::generate(&env);
-    let expiry = env.ledger().timestamp() + 100_000;
-    client.issue_token(&token_id, &owner, &expiry);
+    // Lapsing into the grace period must not overwrite the mint snapshot
+    env.ledger()
+        .with_mut(|li| li.timestamp = minted.expiry_date + 1);
+    let lapsed = client.check_and_apply_grace_period(&token_id);
+    assert_eq!(lapsed.status, MembershipStatus::GracePeriod);
+    assert_eq!(client.get_version_snapshot(&token_id, &0), Some(snapshot));
+
+    assert_eq!(client.rollback_token_upgrade(&admin, &token_id, &0), 1);
+    let restored: MembershipToken = env.as_contract(&client.address, || {
+        env.storage()
+            .persistent()
+            .get(&crate::membership_token::DataKey::Token(token_id.clone()))
+            .unwrap()
+    });
+    assert_eq!(restored.status, MembershipStatus::Active);
+    assert_eq!(restored.expiry_date, minted.expiry_date);
+}
 
-    let creator = Address::generate(&env);
-    let platform = Address::generate(&env);
+#[test]
+fn test_snapshot_retention_prunes_old_versions() {
+    let (env, client, admin, _user, token_id) = setup_upgrade_env();
+    let _ = env;
 
-    let recipients = vec![
-        &env,
-        types::RoyaltyRecipient {
-            address: creator.clone(),
-            percentage: 500, // 5%
-        },
-        types::RoyaltyRecipient {
-            address: platform.clone(),
-            percentage: 250, // 2.5%
+    client.set_snapshot_retention(
+        &admin,
+        &SnapshotRetentionConfig {
+            max_snapshots: 2,
+            keep_original: true,
         },
-    ];
+    );
+    for _ in 0..4 {
+        client.upgrade_token(
+            &admin,
+            &token_id,
+            &None::<String>,
+            &None::<u64>,
+            &None::<String>,
+            &None::<MembershipStatus>,
+        );
+    }
 
-    client.set_royalty(&token_id, &recipients);
+    // Versions 0..=3 were snapshotted; 1 fell out of the window, 0 is kept
+    assert!(client.get_version_snapshot(&token_id, &0).is_some());
+    assert!(client.get_version_snapshot(&token_id, &1).is_none());
+    assert!(client.get_version_snapshot(&token_id, &2).is_some());
+    assert!(client.get_version_snapshot(&token_id, &3).is_some());
 
-    let info = client.get_royalty_info(&token_id).unwrap();
-    assert_eq!(info.config.recipients.len(), 2);
-    assert_eq!(info.total_percentage, 750);
+    client.set_snapshot_retention(
+        &admin,
+        &SnapshotRetentionConfig {
+            max_snapshots: 2,
+            keep_original: false,
+        },
+    );
+    client.upgrade_token(
+        &admin,
+        &token_id,
+        &None::<String>,
+        &None::<u64>,
+        &None::<String>,
+        &None::<MembershipStatus>,
+    );
+    assert!(client.get_version_snapshot(&token_id, &0).is_none());
+    assert!(client.get_version_snapshot(&token_id, &2).is_none());
+    assert!(client.get_version_snapshot(&token_id, &3).is_some());
+    assert!(client.get_version_snapshot(&token_id, &4).is_some());
 }
 
 #[test]
-#[should_panic(expected = "Error(Contract, #8)")]
-fn test_royalty_validation_fail() {
-    let env = Env::default();
-    env.mock_all_auths();
+fn test_rollback_limit_reports_upgrade_error_code() {
+    let (env, client, admin, _user, token_id) = setup_upgrade_env();
+    let _ = env;
 
-    let contract_id = env.register(Contract, ());
-    let client = ContractClient::new(&env, &contract_id);
+    client.upgrade_token(
+        &admin,
+        &token_id,
+        &None::<String>,
+        &None::<u64>,
+        &None::<String>,
+        &None::<MembershipStatus>,
+    );
+    for _ in 0..5 {
+        client.rollback_token_upgrade(&admin, &token_id, &0);
+    }
 
-    let admin = Address::generate(&env);
-    client.set_admin(&admin);
+    let result = client.try_rollback_token_upgrade(&admin, &token_id, &0);
+    assert_eq!(result, Err(Ok(UpgradeError::RollbackLimitExceeded.into())));
+}
 
-    let token_id = BytesN::<32>::random(&env);
-    let owner = Address::generate(&env);
+fn tier_upgrade_changes(env: &Env) -> TokenUpgradeChanges {
+    TokenUpgradeChanges {
+        label: Some(String::from_str(env, "v2")),
+        new_expiry_date: None,
+        new_tier_id: Some(String::from_str(env, "gold")),
+    }
+}
+
+#[test]
+fn test_scheduled_upgrade_applies_after_effective_time() {
+    let (env, client, admin, _user, token_id) = setup_upgrade_env();
+    let effective_at = env.ledger().timestamp() + 3_600;
+
+    let schedule_id = client.schedule_batch_upgrade(
+        &admin,
+        &UpgradeTarget::Tokens(vec![&env, token_id.clone()]),
+        &effective_at,
+        &tier_upgrade_changes(&env),
+    );
+    assert_eq!(client.get_pending_token_upgrades().len(), 1);
+
+    // Nothing is due yet
+    assert!(client.apply_scheduled_upgrades(&10).is_empty());
+    assert_eq!(client.get_token_version(&token_id), 0);
+
+    env.ledger().with_mut(|li| li.timestamp = effective_at);
+    let results = client.apply_scheduled_upgrades(&10);
+    assert_eq!(results.len(), 1);
+    assert!(results.get(0).unwrap().success);
+    assert_eq!(results.get(0).unwrap().new_version, Some(1));
+
+    let token = client.get_token(&token_id);
+    assert_eq!(token.current_version, 1);
+    assert_eq!(token.tier_id, Some(String::from_str(&env, "gold")));
+    assert!(client.get_scheduled_token_upgrade(&schedule_id).is_none());
+    assert!(client.get_pending_token_upgrades().is_empty());
+    assert_eq!(
+        client
+            .get_upgrade_history(&token_id)
+            .get(0)
+            .unwrap()
+            .upgraded_by,
+        admin
+    );
+}
+
+#[test]
+fn test_scheduled_owner_filter_applies_in_chunks() {
+    let (env, client, admin, user, token_id) = setup_upgrade_env();
+    let expiry = env.ledger().timestamp() + 86_400 * 30;
+    let second = BytesN::<32>::random(&env);
+    let third = BytesN::<32>::random(&env);
+    client.issue_token(&second, &user, &expiry);
+    client.issue_token(&third, &user, &expiry);
+
+    let effective_at = env.ledger().timestamp() + 60;
+    let schedule_id = client.schedule_batch_upgrade(
+        &admin,
+        &UpgradeTarget::Owner(user.clone()),
+        &effective_at,
+        &tier_upgrade_changes(&env),
+    );
+
+    env.ledger().with_mut(|li| li.timestamp = effective_at);
+    assert_eq!(client.apply_scheduled_upgrades(&2).len(), 2);
+
+    // The filter was resolved when the schedule started applying
+    let scheduled = client.get_scheduled_token_upgrade(&schedule_id).unwrap();
+    assert_eq!(scheduled.processed, 2);
+    assert_eq!(
+        scheduled.target,
+        UpgradeTarget::Tokens(vec![&env, token_id.clone(), second.clone(), third.clone()])
+    );
+
+    let results = client.apply_scheduled_upgrades(&2);
+    assert_eq!(results.len(), 1);
+    assert_eq!(results.get(0).unwrap().token_id, third);
+    assert!(client.get_pending_token_upgrades().is_empty());
+    assert_eq!(client.get_token_version(&token_id), 1);
+    assert_eq!(client.get_token_version(&second), 1);
+    assert_eq!(client.get_token_version(&third), 1);
+}
+
+#[test]
+fn test_cancel_scheduled_token_upgrade() {
+    let (env, client, admin, _user, token_id) = setup_upgrade_env();
+    let now = env.ledger().timestamp();
+    let target = UpgradeTarget::Tokens(vec![&env, token_id.clone()]);
+
+    let result =
+        client.try_schedule_batch_upgrade(&admin, &target, &now, &tier_upgrade_changes(&env));
+    assert_eq!(result, Err(Ok(UpgradeError::InvalidEffectiveTime.into())));
+
+    let cancelled =
+        client.schedule_batch_upgrade(&admin, &target, &(now + 100), &tier_upgrade_changes(&env));
+    let kept =
+        client.schedule_batch_upgrade(&admin, &target, &(now + 200), &tier_upgrade_changes(&env));
+    client.cancel_scheduled_token_upgrade(&admin, &cancelled);
+    assert!(client.get_scheduled_token_upgrade(&cancelled).is_none());
+    assert_eq!(
+        client.try_cancel_scheduled_token_upgrade(&admin, &cancelled),
+        Err(Ok(UpgradeError::ScheduleNotFound.into()))
+    );
+
+    env.ledger().with_mut(|li| li.timestamp = now + 200);
+    assert_eq!(
+        client.try_cancel_scheduled_token_upgrade(&admin, &kept),
+        Err(Ok(UpgradeError::UpgradeAlreadyEffective.into()))
+    );
+
+    // Only the remaining schedule is applied
+    assert_eq!(client.apply_scheduled_upgrades(&10).len(), 1);
+    assert_eq!(client.get_token_version(&token_id), 1);
+}
+
+// ==================== Token Royalty Tests ====================
+
+#[test]
+fn test_royalty_config() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(Contract, ());
+    let client = ContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    client.set_admin(&admin);
+
+    let token_id = BytesN::<32>::random(&env);
+    let owner = Address::generate(&env);
+    let expiry = env.ledger().timestamp() + 100_000;
+    client.issue_token(&token_id, &owner, &expiry);
+
+    let creator = Address::generate(&env);
+    let platform = Address::generate(&env);
+
+    let recipients = vec![
+        &env,
+        types::RoyaltyRecipient {
+            address: creator.clone(),
+            percentage: 500, // 5%
+        },
+        types::RoyaltyRecipient {
+            address: platform.clone(),
+            percentage: 250, // 2.5%
+        },
+    ];
+
+    client.set_royalty(&token_id, &recipients);
+
+    let info = client.get_royalty_info(&token_id).unwrap();
+    assert_eq!(info.config.recipients.len(), 2);
+    assert_eq!(info.total_percentage, 750);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #8)")]
+fn test_royalty_validation_fail() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(Contract, ());
+    let client = ContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    client.set_admin(&admin);
+
+    let token_id = BytesN::<32>::random(&env);
+    let owner = Address::generate(&env);
     client.issue_token(&token_id, &owner, &(env.ledger().timestamp() + 1000));
 
     let recipient = Address::generate(&env);
@@ -3383,3 +3870,8003 @@ fn test_process_tier_change_rejects_non_admin_caller() {
     // Non-admin caller attempts to process — must panic with Unauthorized (#4)
     client.process_tier_change(&non_admin, &change_id, &sub_id, &payment_token);
 }
+
+// ==================== Contract Initialization Tests ====================
+
+fn default_init_config(env: &Env) -> InitConfig {
+    InitConfig {
+        pause_config: PauseConfig {
+            max_pause_duration: 2_592_000,
+            max_pause_count: 3,
+            min_active_time: 86_400,
+            policy: types::PausePolicy::ExtendExpiry,
+        },
+        renewal_config: types::RenewalConfig {
+            grace_period_duration: 7 * 24 * 60 * 60,
+            auto_renewal_notice_days: 24 * 60 * 60,
+            renewals_enabled: true,
+        },
+        staking_config: StakingConfig {
+            staking_enabled: false,
+            emergency_unstake_penalty_bps: 1_000,
+            max_slash_bps: 0,
+            unstake_cooldown: 0,
+            staking_token: Address::generate(env),
+            reward_pool: Address::generate(env),
+        },
+        upgrade_config: UpgradeConfig {
+            upgrades_enabled: true,
+            admin_only: true,
+            max_rollbacks: 0,
+        },
+    }
+}
+
+#[test]
+fn test_initialize_seeds_admin_and_configs() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(Contract, ());
+    let client = ContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let usdc = Address::generate(&env);
+    let access_control = Address::generate(&env);
+
+    assert!(!client.is_initialized());
+
+    let mut config = default_init_config(&env);
+    config.pause_config.max_pause_count = 5;
+    config.renewal_config.renewals_enabled = false;
+    config.upgrade_config.max_rollbacks = 2;
+    client.initialize(&admin, &usdc, &access_control, &config);
+
+    assert!(client.is_initialized());
+    assert_eq!(client.get_access_control_contract(), Some(access_control));
+    assert_eq!(client.get_pause_config().max_pause_count, 5);
+    assert!(!client.get_renewal_config().renewals_enabled);
+    assert_eq!(client.get_upgrade_config().max_rollbacks, 2);
+    assert!(!client.get_staking_config().staking_enabled);
+
+    // Stored admin and USDC address are usable straight away.
+    let user = Address::generate(&env);
+    let sub_id = String::from_str(&env, "sub_init");
+    client.create_subscription(&sub_id, &user, &usdc, &100_000, &2_592_000);
+    assert_eq!(client.get_subscription(&sub_id).payment_token, usdc);
+}
+
+#[test]
+fn test_initialize_only_runs_once() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(Contract, ());
+    let client = ContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let config = default_init_config(&env);
+    client.initialize(
+        &admin,
+        &Address::generate(&env),
+        &Address::generate(&env),
+        &config,
+    );
+
+    let attacker = Address::generate(&env);
+    let result = client.try_initialize(
+        &attacker,
+        &Address::generate(&env),
+        &Address::generate(&env),
+        &config,
+    );
+    assert_eq!(result, Err(Ok(InitError::AlreadyInitialized.into())));
+}
+
+#[test]
+fn test_initialize_rejects_invalid_config_atomically() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(Contract, ());
+    let client = ContractClient::new(&env, &contract_id);
+
+    let mut config = default_init_config(&env);
+    config.pause_config.max_pause_duration = 0;
+    let result = client.try_initialize(
+        &Address::generate(&env),
+        &Address::generate(&env),
+        &Address::generate(&env),
+        &config,
+    );
+    assert_eq!(result, Err(Ok(Error::InvalidPauseConfig.into())));
+    assert!(!client.is_initialized());
+    assert_eq!(client.get_access_control_contract(), None);
+}
+
+// ==================== USDC Contract Governance Tests ====================
+
+#[test]
+fn test_set_usdc_contract_rejects_non_admin() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(Contract, ());
+    let client = ContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let attacker = Address::generate(&env);
+    client.set_admin(&admin);
+
+    let result = client.try_set_usdc_contract(&attacker, &Address::generate(&env));
+    assert_eq!(result, Err(Ok(Error::Unauthorized.into())));
+}
+
+#[test]
+fn test_set_usdc_contract_cannot_overwrite_directly() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(Contract, ());
+    let client = ContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    client.set_admin(&admin);
+    client.set_usdc_contract(&admin, &Address::generate(&env));
+
+    let result = client.try_set_usdc_contract(&admin, &Address::generate(&env));
+    assert_eq!(
+        result,
+        Err(Ok(ConfigChangeError::ChangeRequiresProposal.into()))
+    );
+}
+
+#[test]
+fn test_usdc_contract_change_requires_delay() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(Contract, ());
+    let client = ContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let old_usdc = Address::generate(&env);
+    let new_usdc = Address::generate(&env);
+    client.set_admin(&admin);
+    client.set_usdc_contract(&admin, &old_usdc);
+
+    let pending = client.propose_usdc_contract(&admin, &new_usdc);
+    assert_eq!(pending.current, old_usdc);
+    assert_eq!(pending.proposed, new_usdc);
+    assert_eq!(client.get_pending_usdc_change(), Some(pending.clone()));
+
+    let result = client.try_confirm_usdc_contract(&admin);
+    assert_eq!(result, Err(Ok(ConfigChangeError::TimelockActive.into())));
+
+    env.ledger()
+        .with_mut(|l| l.timestamp = pending.executable_at);
+    client.confirm_usdc_contract(&admin);
+    assert_eq!(client.get_pending_usdc_change(), None);
+
+    // Payments now validate against the new contract
+    let user = Address::generate(&env);
+    let result = client.try_create_subscription(
+        &String::from_str(&env, "sub_old_usdc"),
+        &user,
+        &old_usdc,
+        &100_000,
+        &2_592_000,
+    );
+    assert_eq!(result, Err(Ok(Error::InvalidPaymentToken.into())));
+    client.create_subscription(
+        &String::from_str(&env, "sub_new_usdc"),
+        &user,
+        &new_usdc,
+        &100_000,
+        &2_592_000,
+    );
+}
+
+#[test]
+fn test_cancel_usdc_contract_change() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(Contract, ());
+    let client = ContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    client.set_admin(&admin);
+    client.set_usdc_contract(&admin, &Address::generate(&env));
+    client.propose_usdc_contract(&admin, &Address::generate(&env));
+
+    client.cancel_usdc_contract_change(&admin);
+    assert_eq!(client.get_pending_usdc_change(), None);
+
+    let result = client.try_confirm_usdc_contract(&admin);
+    assert_eq!(result, Err(Ok(ConfigChangeError::NoPendingChange.into())));
+}
+
+// ==================== Admin Transfer Tests ====================
+
+#[test]
+fn test_set_admin_cannot_overwrite_existing_admin() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(Contract, ());
+    let client = ContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    client.set_admin(&admin);
+
+    let attacker = Address::generate(&env);
+    let result = client.try_set_admin(&attacker);
+    assert_eq!(result, Err(Ok(AdminTransferError::AdminAlreadySet.into())));
+}
+
+#[test]
+fn test_admin_transfer_propose_and_accept() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(Contract, ());
+    let client = ContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let new_admin = Address::generate(&env);
+    client.set_admin(&admin);
+
+    client.propose_admin_transfer(&admin, &new_admin);
+    let pending = client.get_pending_admin_transfer().unwrap();
+    assert_eq!(pending.proposed_admin, new_admin);
+    assert_eq!(pending.proposer, admin);
+
+    // Someone other than the proposed admin cannot accept
+    let result = client.try_accept_admin_transfer(&Address::generate(&env));
+    assert_eq!(result, Err(Ok(Error::Unauthorized.into())));
+
+    client.accept_admin_transfer(&new_admin);
+    assert!(client.get_pending_admin_transfer().is_none());
+
+    // The old admin lost its rights, the new admin has them
+    let usdc = Address::generate(&env);
+    let result = client.try_set_usdc_contract(&admin, &usdc);
+    assert_eq!(result, Err(Ok(Error::Unauthorized.into())));
+    client.set_usdc_contract(&new_admin, &usdc);
+}
+
+#[test]
+fn test_admin_transfer_expires() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(Contract, ());
+    let client = ContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let new_admin = Address::generate(&env);
+    client.set_admin(&admin);
+    client.propose_admin_transfer(&admin, &new_admin);
+
+    env.ledger().with_mut(|l| l.timestamp += 24 * 60 * 60 + 1);
+
+    let result = client.try_accept_admin_transfer(&new_admin);
+    assert_eq!(result, Err(Ok(AdminTransferError::TransferExpired.into())));
+}
+
+#[test]
+fn test_cancel_admin_transfer() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(Contract, ());
+    let client = ContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let new_admin = Address::generate(&env);
+    client.set_admin(&admin);
+    client.propose_admin_transfer(&admin, &new_admin);
+
+    let result = client.try_cancel_admin_transfer(&new_admin);
+    assert_eq!(result, Err(Ok(Error::Unauthorized.into())));
+
+    client.cancel_admin_transfer(&admin);
+    assert!(client.get_pending_admin_transfer().is_none());
+
+    let result = client.try_accept_admin_transfer(&new_admin);
+    assert_eq!(
+        result,
+        Err(Ok(AdminTransferError::NoPendingTransfer.into()))
+    );
+}
+
+// ==================== Treasury Tests ====================
+
+#[test]
+fn test_revenue_breakdown_tracks_sources_per_period() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(Contract, ());
+    let client = ContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let user = Address::generate(&env);
+    let usdc = Address::generate(&env);
+    client.set_admin(&admin);
+    client.set_usdc_contract(&admin, &usdc);
+
+    let day_one = env.ledger().timestamp();
+    let sub_id = String::from_str(&env, "sub_revenue");
+    client.create_subscription(&sub_id, &user, &usdc, &100_000, &2_592_000);
+
+    env.ledger().with_mut(|l| l.timestamp += 2 * 86_400);
+    client.renew_subscription(&sub_id, &usdc, &40_000, &2_592_000);
+
+    let first_day = client.get_revenue_breakdown(&DateRange {
+        start_time: day_one,
+        end_time: day_one,
+    });
+    assert_eq!(first_day.subscriptions, 100_000);
+    assert_eq!(first_day.renewals, 0);
+    assert_eq!(first_day.total, 100_000);
+
+    let both_days = client.get_revenue_breakdown(&DateRange {
+        start_time: day_one,
+        end_time: env.ledger().timestamp(),
+    });
+    assert_eq!(both_days.subscriptions, 100_000);
+    assert_eq!(both_days.renewals, 40_000);
+    assert_eq!(both_days.total, 140_000);
+
+    assert_eq!(client.get_lifetime_revenue(), both_days);
+
+    let result = client.try_get_revenue_breakdown(&DateRange {
+        start_time: 10,
+        end_time: 5,
+    });
+    assert_eq!(result, Err(Ok(TreasuryError::InvalidPeriod.into())));
+}
+
+#[test]
+fn test_withdraw_treasury_requires_multisig_contract() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(Contract, ());
+    let client = ContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    client.set_admin(&admin);
+
+    let result = client.try_withdraw_treasury(
+        &admin,
+        &Address::generate(&env),
+        &1_000,
+        &Address::generate(&env),
+    );
+    assert_eq!(result, Err(Ok(TreasuryError::MultisigNotConfigured.into())));
+}
+
+#[test]
+fn test_withdraw_treasury_transfers_funds() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(Contract, ());
+    let client = ContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let multisig = Address::generate(&env);
+    client.initialize(
+        &admin,
+        &Address::generate(&env),
+        &multisig,
+        &default_init_config(&env),
+    );
+
+    let asset = env.register_stellar_asset_contract_v2(admin.clone());
+    let asset_admin = soroban_sdk::token::StellarAssetClient::new(&env, &asset.address());
+    let asset_client = soroban_sdk::token::Client::new(&env, &asset.address());
+    asset_admin.mint(&contract_id, &5_000);
+    assert_eq!(client.get_withdrawable_revenue(&asset.address()), 0);
+
+    // Slashed stake is collected revenue
+    let mut config = client.get_staking_config();
+    config.staking_enabled = true;
+    config.max_slash_bps = 2_000;
+    config.staking_token = asset.address();
+    client.set_staking_config(&admin, &config);
+    client.create_staking_tier(
+        &admin,
+        &crate::types::StakingTier {
+            id: String::from_str(&env, "bronze"),
+            name: String::from_str(&env, "Bronze"),
+            min_stake_amount: 1_000,
+            lock_duration: 86_400,
+            reward_multiplier_bps: 10_000,
+            base_rate_bps: 500,
+        },
+    );
+    let staker = Address::generate(&env);
+    asset_admin.mint(&staker, &20_000);
+    client.stake_tokens(&staker, &String::from_str(&env, "bronze"), &20_000);
+    client.slash_stake(&admin, &staker, &2_000, &String::from_str(&env, "fraud"));
+    assert_eq!(client.get_withdrawable_revenue(&asset.address()), 4_000);
+
+    let destination = Address::generate(&env);
+    client.withdraw_treasury(&admin, &asset.address(), &3_000, &destination);
+
+    // Both the admin and the multisig contract had to sign off
+    let signers: alloc::vec::Vec<Address> = env.auths().into_iter().map(|(a, _)| a).collect();
+    assert!(signers.contains(&admin));
+    assert!(signers.contains(&multisig));
+
+    assert_eq!(asset_client.balance(&destination), 3_000);
+    assert_eq!(asset_client.balance(&contract_id), 22_000);
+    assert_eq!(client.get_withdrawable_revenue(&asset.address()), 1_000);
+
+    // The remaining stake and the unbooked transfer stay in the contract
+    let result = client.try_withdraw_treasury(&admin, &asset.address(), &3_000, &destination);
+    assert_eq!(result, Err(Ok(TreasuryError::ExceedsWithdrawable.into())));
+    assert_eq!(client.get_stake_info(&staker).unwrap().amount, 16_000);
+}
+
+// ==================== Multi-Asset Payment Tests ====================
+
+fn setup_multi_asset_env(env: &Env) -> (ContractClient<'_>, Address, Address, String) {
+    let contract_id = env.register(Contract, ());
+    let client = ContractClient::new(env, &contract_id);
+
+    let admin = Address::generate(env);
+    let usdc = Address::generate(env);
+    client.set_admin(&admin);
+    client.set_usdc_contract(&admin, &usdc);
+
+    let tier_id = String::from_str(env, "tier_basic");
+    client.create_tier(
+        &admin,
+        &CreateTierParams {
+            id: tier_id.clone(),
+            name: String::from_str(env, "Basic"),
+            level: common_types::TierLevel::Basic,
+            price: 100_000i128,
+            annual_price: 1_000_000i128,
+            features: soroban_sdk::vec![env, common_types::TierFeature::BasicAccess],
+            max_users: 100,
+            max_storage: 10_000_000,
+        },
+    );
+
+    (client, admin, usdc, tier_id)
+}
+
+#[test]
+fn test_whitelisted_asset_converts_tier_price() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin, usdc, tier_id) = setup_multi_asset_env(&env);
+
+    let eurc = Address::generate(&env);
+    client.add_payment_token(&admin, &eurc, &9_000_000, &None); // 0.9 EURC per USDC
+
+    let accepted = client.get_accepted_payment_tokens();
+    assert_eq!(accepted.len(), 2);
+    assert_eq!(accepted.get(0).unwrap().token, usdc);
+    assert_eq!(accepted.get(1).unwrap().token, eurc);
+
+    let user = Address::generate(&env);
+    let sub_id = String::from_str(&env, "sub_eurc");
+    client.create_subscription_with_tier(
+        &sub_id,
+        &user,
+        &eurc,
+        &tier_id,
+        &BillingCycle::Monthly,
+        &None,
+    );
+
+    let subscription = client.get_subscription(&sub_id);
+    assert_eq!(subscription.payment_token, eurc);
+    assert_eq!(subscription.amount, 90_000);
+    // Treasury revenue stays denominated in USDC
+    assert_eq!(client.get_lifetime_revenue().subscriptions, 100_000);
+}
+
+#[test]
+fn test_renew_token_with_whitelisted_asset() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin, _usdc, tier_id) = setup_multi_asset_env(&env);
+
+    let xlm = Address::generate(&env);
+    client.add_payment_token(&admin, &xlm, &80_000_000, &None); // 8 XLM per USDC
+
+    let user = Address::generate(&env);
+    let token_id = BytesN::<32>::random(&env);
+    client.issue_token(&token_id, &user, &(env.ledger().timestamp() + 1_000));
+
+    client.renew_token(&token_id, &xlm, &tier_id, &BillingCycle::Monthly);
+
+    let history = client.get_renewal_history(&token_id);
+    let entry = history.get(0).unwrap();
+    assert_eq!(entry.payment_token, xlm);
+    assert_eq!(entry.amount, 800_000);
+}
+
+#[test]
+fn test_unlisted_or_removed_asset_rejected() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin, usdc, tier_id) = setup_multi_asset_env(&env);
+
+    let user = Address::generate(&env);
+    let other = Address::generate(&env);
+    let result = client.try_create_subscription_with_tier(
+        &String::from_str(&env, "sub_other"),
+        &user,
+        &other,
+        &tier_id,
+        &BillingCycle::Monthly,
+        &None,
+    );
+    assert_eq!(result, Err(Ok(Error::InvalidPaymentToken.into())));
+
+    client.add_payment_token(&admin, &other, &payment_tokens::RATE_SCALE, &None);
+    client.remove_payment_token(&admin, &other);
+    assert_eq!(client.get_accepted_payment_tokens().len(), 1);
+
+    let result = client.try_create_subscription_with_tier(
+        &String::from_str(&env, "sub_other"),
+        &user,
+        &other,
+        &tier_id,
+        &BillingCycle::Monthly,
+        &None,
+    );
+    assert_eq!(result, Err(Ok(Error::InvalidPaymentToken.into())));
+
+    // USDC itself cannot be registered as an extra asset
+    let result = client.try_add_payment_token(&admin, &usdc, &payment_tokens::RATE_SCALE, &None);
+    assert_eq!(result, Err(Ok(Error::InvalidPaymentToken)));
+}
+
+// ==================== Price Oracle Tests ====================
+
+mod mock_oracle {
+    use crate::types::{OracleAsset, OraclePriceData};
+    use soroban_sdk::{contract, contractimpl, symbol_short, Env};
+
+    /// Minimal SEP-40 style feed returning a single configurable price.
+    #[contract]
+    pub struct MockOracle;
+
+    #[contractimpl]
+    impl MockOracle {
+        pub fn set_price(env: Env, price: i128, timestamp: u64) {
+            env.storage().instance().set(
+                &symbol_short!("price"),
+                &OraclePriceData { price, timestamp },
+            );
+        }
+
+        pub fn lastprice(env: Env, _asset: OracleAsset) -> Option<OraclePriceData> {
+            env.storage().instance().get(&symbol_short!("price"))
+        }
+
+        pub fn decimals(_env: Env) -> u32 {
+            14
+        }
+    }
+}
+
+/// Sets up a USD-priced tier payable in an asset whose fixed rate is 8 units per USD.
+fn setup_oracle_env(
+    env: &Env,
+) -> (
+    ContractClient<'_>,
+    mock_oracle::MockOracleClient<'_>,
+    Address,
+    Address,
+    String,
+) {
+    let (client, admin, _usdc, tier_id) = setup_multi_asset_env(env);
+    env.ledger().with_mut(|l| l.timestamp = 10_000);
+
+    let xlm = Address::generate(env);
+    client.add_payment_token(&admin, &xlm, &80_000_000, &None);
+
+    let oracle_id = env.register(mock_oracle::MockOracle, ());
+    let oracle = mock_oracle::MockOracleClient::new(env, &oracle_id);
+    client.set_oracle_config(
+        &admin,
+        &OracleConfig {
+            oracle: oracle_id,
+            max_staleness: 600,
+            max_deviation_bps: 500,
+            fallback_enabled: false,
+        },
+    );
+    client.set_tier_usd_pricing(&admin, &tier_id, &true);
+    assert!(client.is_tier_usd_priced(&tier_id));
+
+    (client, oracle, admin, xlm, tier_id)
+}
+
+#[test]
+fn test_usd_tier_converted_with_oracle_price() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, oracle, _admin, xlm, tier_id) = setup_oracle_env(&env);
+
+    // 0.12 USD per XLM (14 decimals) => ~8.33 XLM per USD, within the 5 % guard
+    oracle.set_price(&12_000_000_000_000, &9_900);
+
+    let sub_id = String::from_str(&env, "sub_usd");
+    client.create_subscription_with_tier(
+        &sub_id,
+        &Address::generate(&env),
+        &xlm,
+        &tier_id,
+        &BillingCycle::Monthly,
+        &None,
+    );
+    assert_eq!(client.get_subscription(&sub_id).amount, 833_333);
+}
+
+#[test]
+fn test_stale_oracle_price_rejected_or_falls_back() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, oracle, admin, xlm, tier_id) = setup_oracle_env(&env);
+    oracle.set_price(&12_000_000_000_000, &1_000);
+
+    let user = Address::generate(&env);
+    let sub_id = String::from_str(&env, "sub_stale");
+    let result = client.try_create_subscription_with_tier(
+        &sub_id,
+        &user,
+        &xlm,
+        &tier_id,
+        &BillingCycle::Monthly,
+        &None,
+    );
+    assert_eq!(result, Err(Ok(OracleError::OraclePriceStale.into())));
+
+    // With the fallback enabled the fixed registry rate is used instead
+    let mut config = client.get_oracle_config().unwrap();
+    config.fallback_enabled = true;
+    client.set_oracle_config(&admin, &config);
+
+    client.create_subscription_with_tier(
+        &sub_id,
+        &user,
+        &xlm,
+        &tier_id,
+        &BillingCycle::Monthly,
+        &None,
+    );
+    assert_eq!(client.get_subscription(&sub_id).amount, 800_000);
+}
+
+#[test]
+fn test_oracle_price_deviation_guard() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, oracle, _admin, xlm, tier_id) = setup_oracle_env(&env);
+
+    // 0.05 USD per XLM => 20 XLM per USD, far from the 8 XLM reference rate
+    oracle.set_price(&5_000_000_000_000, &10_000);
+
+    let result = client.try_create_subscription_with_tier(
+        &String::from_str(&env, "sub_deviation"),
+        &Address::generate(&env),
+        &xlm,
+        &tier_id,
+        &BillingCycle::Monthly,
+        &None,
+    );
+    assert_eq!(result, Err(Ok(OracleError::OraclePriceDeviation.into())));
+}
+
+// ==================== Installment Plan Tests ====================
+
+#[test]
+fn test_installment_plan_full_schedule() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin, usdc, tier_id) = setup_multi_asset_env(&env);
+
+    let user = Address::generate(&env);
+    let sub_id = String::from_str(&env, "sub_inst");
+    let plan = client.create_subscription_installments(&sub_id, &user, &usdc, &tier_id, &3);
+    assert_eq!(plan.total_amount, 1_000_000);
+    assert_eq!(plan.installment_amount, 333_333);
+    assert_eq!(plan.paid_count, 1);
+
+    // Only the first period is paid for
+    let subscription = client.get_subscription(&sub_id);
+    assert_eq!(subscription.billing_cycle, BillingCycle::Annual);
+    assert_eq!(subscription.expires_at, plan.created_at + plan.period);
+    assert_eq!(subscription.expires_at, plan.next_due_at);
+
+    env.ledger().with_mut(|l| l.timestamp = plan.next_due_at);
+    let plan = client.pay_installment(&sub_id);
+    assert_eq!(plan.paid_count, 2);
+    assert_eq!(
+        client.get_subscription(&sub_id).expires_at,
+        plan.created_at + 2 * plan.period
+    );
+
+    env.ledger().with_mut(|l| l.timestamp = plan.next_due_at);
+    let plan = client.pay_installment(&sub_id);
+    assert_eq!(plan.paid_count, 3);
+    assert_eq!(plan.amount_paid, 1_000_000);
+    assert_eq!(plan.next_due_at, 0);
+    assert_eq!(
+        client.get_subscription(&sub_id).expires_at,
+        plan.created_at + 365 * 24 * 60 * 60
+    );
+
+    let result = client.try_pay_installment(&sub_id);
+    assert_eq!(result, Err(Ok(InstallmentError::PlanPaidOff.into())));
+    assert_eq!(client.get_lifetime_revenue().subscriptions, 1_000_000);
+}
+
+#[test]
+fn test_missed_installment_suspends_subscription() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin, usdc, tier_id) = setup_multi_asset_env(&env);
+
+    let user = Address::generate(&env);
+    let sub_id = String::from_str(&env, "sub_inst_missed");
+    let plan = client.create_subscription_installments(&sub_id, &user, &usdc, &tier_id, &4);
+
+    // Not overdue yet: nothing happens
+    assert!(!client.enforce_installment_deadline(&sub_id));
+
+    env.ledger()
+        .with_mut(|l| l.timestamp = plan.next_due_at + 1);
+    let result = client.try_pay_installment(&sub_id);
+    assert_eq!(result, Err(Ok(InstallmentError::InstallmentOverdue.into())));
+
+    assert!(client.enforce_installment_deadline(&sub_id));
+    assert_eq!(
+        client.get_subscription(&sub_id).status,
+        MembershipStatus::Inactive
+    );
+    assert!(client.get_installment_plan(&sub_id).suspended);
+
+    let result = client.try_pay_installment(&sub_id);
+    assert_eq!(result, Err(Ok(InstallmentError::PlanSuspended.into())));
+}
+
+#[test]
+fn test_installments_rejected_on_cancelled_subscription() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin, usdc, tier_id) = setup_multi_asset_env(&env);
+
+    let user = Address::generate(&env);
+    let sub_id = String::from_str(&env, "sub_inst_cancelled");
+    let plan = client.create_subscription_installments(&sub_id, &user, &usdc, &tier_id, &4);
+    client.cancel_subscription(&sub_id, &None);
+
+    let result = client.try_pay_installment(&sub_id);
+    assert_eq!(result, Err(Ok(Error::SubscriptionNotActive.into())));
+    let plan_after = client.get_installment_plan(&sub_id);
+    assert_eq!(plan_after.paid_count, plan.paid_count);
+    assert_eq!(plan_after.amount_paid, plan.amount_paid);
+}
+
+#[test]
+fn test_installment_count_bounds() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin, usdc, tier_id) = setup_multi_asset_env(&env);
+
+    let user = Address::generate(&env);
+    for count in [0u32, 1, 13] {
+        let result = client.try_create_subscription_installments(
+            &String::from_str(&env, "sub_bad_count"),
+            &user,
+            &usdc,
+            &tier_id,
+            &count,
+        );
+        assert_eq!(
+            result,
+            Err(Ok(InstallmentError::InvalidInstallmentCount.into()))
+        );
+    }
+}
+
+// ==================== Price Grandfathering Tests ====================
+
+fn raise_basic_price(env: &Env, client: &ContractClient<'_>, admin: &Address, grandfather: bool) {
+    client.update_tier(
+        admin,
+        &UpdateTierParams {
+            id: String::from_str(env, "tier_basic"),
+            name: None,
+            price: Some(150_000),
+            annual_price: None,
+            features: None,
+            max_users: None,
+            max_storage: None,
+            is_active: None,
+            grandfather_existing: Some(grandfather),
+        },
+    );
+}
+
+#[test]
+fn test_grandfathered_subscription_renews_at_locked_price() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin, usdc, tier_id) = setup_multi_asset_env(&env);
+
+    let user = Address::generate(&env);
+    let sub_id = String::from_str(&env, "sub_locked");
+    client.create_subscription_with_tier(
+        &sub_id,
+        &user,
+        &usdc,
+        &tier_id,
+        &BillingCycle::Monthly,
+        &None,
+    );
+    assert_eq!(client.get_subscription(&sub_id).locked_price, 100_000);
+
+    raise_basic_price(&env, &client, &admin, true);
+    assert!(client.is_tier_grandfathered(&tier_id));
+    assert_eq!(client.get_renewal_price(&sub_id), Some(100_000));
+
+    client.renew_subscription(&sub_id, &usdc, &100_000, &2_592_000);
+}
+
+#[test]
+fn test_non_grandfathered_renewal_requires_new_price() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin, usdc, tier_id) = setup_multi_asset_env(&env);
+
+    let user = Address::generate(&env);
+    let sub_id = String::from_str(&env, "sub_unlocked");
+    client.create_subscription_with_tier(
+        &sub_id,
+        &user,
+        &usdc,
+        &tier_id,
+        &BillingCycle::Monthly,
+        &None,
+    );
+
+    raise_basic_price(&env, &client, &admin, false);
+    assert_eq!(client.get_renewal_price(&sub_id), Some(150_000));
+
+    let result = client.try_renew_subscription(&sub_id, &usdc, &100_000, &2_592_000);
+    assert_eq!(result, Err(Ok(Error::InvalidPaymentAmount.into())));
+
+    client.renew_subscription(&sub_id, &usdc, &150_000, &2_592_000);
+}
+
+#[test]
+fn test_admin_migrates_subscription_to_new_pricing() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin, usdc, tier_id) = setup_multi_asset_env(&env);
+
+    let user = Address::generate(&env);
+    let sub_id = String::from_str(&env, "sub_migrate");
+    client.create_subscription_with_tier(
+        &sub_id,
+        &user,
+        &usdc,
+        &tier_id,
+        &BillingCycle::Monthly,
+        &None,
+    );
+    raise_basic_price(&env, &client, &admin, true);
+
+    let outsider = Address::generate(&env);
+    let result = client.try_migrate_subscription_pricing(&outsider, &sub_id);
+    assert_eq!(result, Err(Ok(Error::Unauthorized)));
+
+    assert_eq!(
+        client.migrate_subscription_pricing(&admin, &sub_id),
+        150_000
+    );
+    assert_eq!(client.get_subscription(&sub_id).locked_price, 150_000);
+    assert_eq!(client.get_renewal_price(&sub_id), Some(150_000));
+}
+
+// ==================== Operator Tests ====================
+
+#[test]
+fn test_operator_logs_attendance_and_tracks_activity() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(Contract, ());
+    let client = ContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let kiosk = Address::generate(&env);
+    let member = Address::generate(&env);
+    client.set_admin(&admin);
+
+    assert!(!client.is_operator(&kiosk));
+    client.add_operator(&admin, &kiosk);
+    assert!(client.is_operator(&kiosk));
+
+    let log_id = BytesN::<32>::random(&env);
+    client.operator_log_attendance(
+        &kiosk,
+        &log_id,
+        &member,
+        &AttendanceAction::ClockIn,
+        &Map::new(&env),
+    );
+    assert_eq!(client.get_logs_for_user(&member).len(), 1);
+
+    let token_id = BytesN::<32>::random(&env);
+    client.issue_token(&token_id, &member, &(env.ledger().timestamp() + 1_000));
+    env.ledger().with_mut(|l| l.timestamp += 2_000);
+    let token = client.operator_check_grace_period(&kiosk, &token_id);
+    assert_eq!(token.status, MembershipStatus::GracePeriod);
+
+    let activity = client.get_operator_activity(&kiosk).unwrap();
+    assert_eq!(activity.attendance_logged, 1);
+    assert_eq!(activity.grace_checks, 1);
+    assert_eq!(activity.last_active_at, env.ledger().timestamp());
+}
+
+#[test]
+fn test_removed_or_unknown_operator_rejected() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(Contract, ());
+    let client = ContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let kiosk = Address::generate(&env);
+    let member = Address::generate(&env);
+    client.set_admin(&admin);
+
+    let result = client.try_add_operator(&kiosk, &kiosk);
+    assert_eq!(result, Err(Ok(Error::Unauthorized)));
+
+    client.add_operator(&admin, &kiosk);
+    client.remove_operator(&admin, &kiosk);
+    assert!(!client.is_operator(&kiosk));
+
+    let result = client.try_operator_log_attendance(
+        &kiosk,
+        &BytesN::<32>::random(&env),
+        &member,
+        &AttendanceAction::ClockIn,
+        &Map::new(&env),
+    );
+    assert_eq!(result, Err(Ok(Error::Unauthorized.into())));
+    // Counters survive removal
+    assert!(client.get_operator_activity(&kiosk).is_some());
+}
+
+#[test]
+fn test_access_control_contract_syncs_operators() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(Contract, ());
+    let client = ContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let usdc = Address::generate(&env);
+    let access_control = Address::generate(&env);
+    let kiosk = Address::generate(&env);
+    client.initialize(&admin, &usdc, &access_control, &default_init_config(&env));
+
+    client.add_operator(&access_control, &kiosk);
+    assert!(client.is_operator(&kiosk));
+    client.remove_operator(&access_control, &kiosk);
+    assert!(!client.is_operator(&kiosk));
+}
+
+// ==================== Attendance Rate Limit Tests ====================
+
+fn log_clock_in(env: &Env, client: &ContractClient<'_>, user: &Address) -> bool {
+    client
+        .try_log_attendance(
+            &BytesN::<32>::random(env),
+            user,
+            &AttendanceAction::ClockIn,
+            &map![
+                env,
+                (String::from_str(env, "loc"), String::from_str(env, "desk1"))
+            ],
+        )
+        .is_ok()
+}
+
+#[test]
+fn test_attendance_hourly_rate_limit() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(Contract, ());
+    let client = ContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let user = Address::generate(&env);
+    client.set_admin(&admin);
+    client.set_attendance_rate_limit(
+        &admin,
+        &AttendanceRateLimit {
+            max_logs_per_hour: 2,
+            max_detail_bytes_per_day: 0,
+        },
+    );
+
+    assert!(log_clock_in(&env, &client, &user));
+    assert!(log_clock_in(&env, &client, &user));
+    let result = client.try_log_attendance(
+        &BytesN::<32>::random(&env),
+        &user,
+        &AttendanceAction::ClockIn,
+        &Map::new(&env),
+    );
+    assert_eq!(result, Err(Ok(AttendanceError::RateLimited.into())));
+
+    // A new hour resets the window
+    env.ledger().with_mut(|l| l.timestamp += 3_600);
+    assert!(log_clock_in(&env, &client, &user));
+}
+
+#[test]
+fn test_attendance_daily_details_budget_and_exemption() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(Contract, ());
+    let client = ContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let user = Address::generate(&env);
+    client.set_admin(&admin);
+    // Each log carries 8 bytes of details ("loc" + "desk1")
+    client.set_attendance_rate_limit(
+        &admin,
+        &AttendanceRateLimit {
+            max_logs_per_hour: 0,
+            max_detail_bytes_per_day: 16,
+        },
+    );
+
+    assert!(log_clock_in(&env, &client, &user));
+    assert!(log_clock_in(&env, &client, &user));
+    assert!(!log_clock_in(&env, &client, &user));
+
+    client.set_attendance_rate_limit_exempt(&admin, &user, &true);
+    assert!(client.is_attendance_rate_limit_exempt(&user));
+    assert!(log_clock_in(&env, &client, &user));
+    assert_eq!(client.get_logs_for_user(&user).len(), 3);
+}
+
+#[test]
+fn test_attendance_rate_limit_admin_only() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(Contract, ());
+    let client = ContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let outsider = Address::generate(&env);
+    client.set_admin(&admin);
+
+    let result = client.try_set_attendance_rate_limit(
+        &outsider,
+        &AttendanceRateLimit {
+            max_logs_per_hour: 1,
+            max_detail_bytes_per_day: 0,
+        },
+    );
+    assert_eq!(result, Err(Ok(Error::Unauthorized)));
+    assert!(client.get_attendance_rate_limit().is_none());
+}
+
+// ==================== Paged Attendance Log Tests ====================
+
+#[test]
+fn test_get_logs_for_user_paged_across_pages() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(Contract, ());
+    let client = ContractClient::new(&env, &contract_id);
+
+    let user = Address::generate(&env);
+    let total = attendance_log::LOG_PAGE_SIZE + 10;
+    for i in 0..total {
+        env.ledger().with_mut(|l| l.timestamp = 1_000 + i as u64);
+        client.log_attendance(
+            &BytesN::<32>::random(&env),
+            &user,
+            &AttendanceAction::ClockIn,
+            &Map::new(&env),
+        );
+    }
+
+    assert_eq!(client.get_log_count_for_user(&user), total);
+    assert_eq!(client.get_logs_for_user(&user).len(), total);
+
+    // Window spanning the page boundary
+    let window = client.get_logs_for_user_paged(&user, &45, &10);
+    assert_eq!(window.len(), 10);
+    assert_eq!(window.get(0).unwrap().timestamp, 1_045);
+    assert_eq!(window.get(9).unwrap().timestamp, 1_054);
+
+    // Tail is truncated, out-of-range offset is empty
+    assert_eq!(client.get_logs_for_user_paged(&user, &55, &20).len(), 5);
+    assert_eq!(client.get_logs_for_user_paged(&user, &total, &5).len(), 0);
+}
+
+#[test]
+fn test_get_logs_for_user_paged_caps_limit() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(Contract, ());
+    let client = ContractClient::new(&env, &contract_id);
+
+    let user = Address::generate(&env);
+    for _ in 0..120 {
+        client.log_attendance(
+            &BytesN::<32>::random(&env),
+            &user,
+            &AttendanceAction::ClockIn,
+            &Map::new(&env),
+        );
+    }
+
+    let window = client.get_logs_for_user_paged(&user, &0, &500);
+    assert_eq!(window.len(), attendance_log::MAX_LOGS_PER_QUERY);
+}
+
+#[test]
+fn test_legacy_log_pages_migrated_to_indexed_entries() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(Contract, ());
+    let client = ContractClient::new(&env, &contract_id);
+
+    // A user whose first 3 logs were archived under the paged layout
+    let user = Address::generate(&env);
+    let total = attendance_log::LOG_PAGE_SIZE + 5;
+    let archived = 3;
+    let mut first_page = Vec::new(&env);
+    let mut second_page = Vec::new(&env);
+    for i in archived..total {
+        let log = attendance_log::AttendanceLog {
+            id: BytesN::<32>::random(&env),
+            user_id: user.clone(),
+            action: AttendanceAction::ClockIn,
+            timestamp: 1_000 + i as u64,
+            details: Map::new(&env),
+        };
+        if i < attendance_log::LOG_PAGE_SIZE {
+            first_page.push_back(log);
+        } else {
+            second_page.push_back(log);
+        }
+    }
+    env.as_contract(&contract_id, || {
+        let storage = env.storage().persistent();
+        storage.set(
+            &attendance_log::DataKey::LogPage(user.clone(), 0),
+            &first_page,
+        );
+        storage.set(
+            &attendance_log::DataKey::LogPage(user.clone(), 1),
+            &second_page,
+        );
+        storage.set(&attendance_log::DataKey::LogCount(user.clone()), &total);
+        storage.set(&attendance_log::DataKey::LogStart(user.clone()), &archived);
+    });
+
+    assert_eq!(client.get_log_count_for_user(&user), total - archived);
+    let window = client.get_logs_for_user_paged(&user, &(attendance_log::LOG_PAGE_SIZE - 4), &3);
+    assert_eq!(window.len(), 3);
+    assert_eq!(
+        window.get(0).unwrap().timestamp,
+        1_000 + attendance_log::LOG_PAGE_SIZE as u64 - 1
+    );
+
+    env.ledger().with_mut(|l| l.timestamp = 5_000);
+    client.log_attendance(
+        &BytesN::<32>::random(&env),
+        &user,
+        &AttendanceAction::ClockOut,
+        &Map::new(&env),
+    );
+    let logs = client.get_logs_for_user(&user);
+    assert_eq!(logs.len(), total - archived + 1);
+    assert_eq!(logs.get(0).unwrap().timestamp, 1_000 + archived as u64);
+    assert_eq!(logs.last().unwrap().timestamp, 5_000);
+    env.as_contract(&contract_id, || {
+        let storage = env.storage().persistent();
+        assert!(!storage.has(&attendance_log::DataKey::LogPage(user.clone(), 0)));
+        assert!(!storage.has(&attendance_log::DataKey::LogCount(user.clone())));
+    });
+}
+
+// ==================== Attendance Archival Tests ====================
+
+fn log_at(
+    env: &Env,
+    client: &ContractClient<'_>,
+    user: &Address,
+    ts: u64,
+    action: AttendanceAction,
+) {
+    env.ledger().with_mut(|l| l.timestamp = ts);
+    client.log_attendance(&BytesN::<32>::random(env), user, &action, &Map::new(env));
+}
+
+#[test]
+fn test_archive_logs_rolls_into_monthly_summaries() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(Contract, ());
+    let client = ContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let user = Address::generate(&env);
+    client.set_admin(&admin);
+
+    // 2024-01-15 and 2024-02-10 sessions, then a live March visit
+    let jan = 1_705_312_800;
+    let feb = 1_707_555_600;
+    let mar = 1_710_237_600;
+    log_at(&env, &client, &user, jan, AttendanceAction::ClockIn);
+    log_at(
+        &env,
+        &client,
+        &user,
+        jan + 3_600,
+        AttendanceAction::ClockOut,
+    );
+    log_at(&env, &client, &user, feb, AttendanceAction::ClockIn);
+    log_at(
+        &env,
+        &client,
+        &user,
+        feb + 7_200,
+        AttendanceAction::ClockOut,
+    );
+    log_at(&env, &client, &user, mar, AttendanceAction::ClockIn);
+
+    let first_id = client.get_logs_for_user(&user).get(0).unwrap().id;
+
+    assert_eq!(client.archive_logs_before(&admin, &user, &mar), 4);
+    assert_eq!(client.get_log_count_for_user(&user), 1);
+    assert_eq!(
+        client.get_logs_for_user(&user).get(0).unwrap().timestamp,
+        mar
+    );
+    assert!(client.get_attendance_log(&first_id).is_none());
+
+    let summaries = client.get_archived_attendance(&user);
+    assert_eq!(summaries.len(), 2);
+    let january = summaries.get(0).unwrap();
+    assert_eq!(january.year_month, 202401);
+    assert_eq!(january.visit_count, 1);
+    assert_eq!(january.total_seconds, 3_600);
+    assert_eq!(summaries.get(1).unwrap().year_month, 202402);
+    assert_eq!(summaries.get(1).unwrap().total_seconds, 7_200);
+
+    // New logs keep appending after the archived prefix
+    log_at(&env, &client, &user, mar + 600, AttendanceAction::ClockOut);
+    assert_eq!(client.get_log_count_for_user(&user), 2);
+    assert_eq!(
+        client
+            .get_logs_for_user_paged(&user, &1, &5)
+            .get(0)
+            .unwrap()
+            .timestamp,
+        mar + 600
+    );
+}
+
+#[test]
+fn test_archive_keeps_open_session_and_requires_admin() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(Contract, ());
+    let client = ContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let user = Address::generate(&env);
+    client.set_admin(&admin);
+
+    log_at(&env, &client, &user, 1_000, AttendanceAction::ClockIn);
+    log_at(&env, &client, &user, 5_000, AttendanceAction::ClockOut);
+
+    let result = client.try_archive_logs_before(&user, &user, &2_000);
+    assert_eq!(result, Err(Ok(Error::Unauthorized)));
+
+    // The clock-in's session is still open at the cutoff, so it stays
+    assert_eq!(client.archive_logs_before(&admin, &user, &2_000), 0);
+    assert_eq!(client.archive_logs_before(&admin, &user, &6_000), 2);
+    assert_eq!(client.get_log_count_for_user(&user), 0);
+    assert_eq!(client.get_logs_for_user(&user).len(), 0);
+}
+
+#[test]
+fn test_archive_across_page_boundary() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(Contract, ());
+    let client = ContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let user = Address::generate(&env);
+    client.set_admin(&admin);
+
+    for i in 0..60u64 {
+        log_at(&env, &client, &user, 1_000 + i, AttendanceAction::ClockOut);
+    }
+
+    assert_eq!(client.archive_logs_before(&admin, &user, &1_055), 55);
+    let remaining = client.get_logs_for_user(&user);
+    assert_eq!(remaining.len(), 5);
+    assert_eq!(remaining.get(0).unwrap().timestamp, 1_055);
+    let window = client.get_logs_for_user_paged(&user, &2, &10);
+    assert_eq!(window.len(), 3);
+    assert_eq!(window.get(0).unwrap().timestamp, 1_057);
+}
+
+// ==================== Typed Event Tests ====================
+
+fn assert_last_event(env: &Env, contract_id: &Address, event: &impl soroban_sdk::events::Event) {
+    let all = env.events().all();
+    assert_eq!(
+        all.slice(all.len() - 1..),
+        soroban_sdk::vec![
+            env,
+            (contract_id.clone(), event.topics(env), event.data(env))
+        ]
+    );
+}
+
+#[test]
+fn test_token_issued_event_schema() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(Contract, ());
+    let client = ContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let user = Address::generate(&env);
+    client.set_admin(&admin);
+
+    let token_id = BytesN::<32>::random(&env);
+    let expiry = env.ledger().timestamp() + 1_000;
+    client.issue_token(&token_id, &user, &expiry);
+
+    assert_last_event(
+        &env,
+        &contract_id,
+        &events::TokenIssued {
+            token_id,
+            user,
+            issued_by: admin,
+            issued_at: env.ledger().timestamp(),
+            expiry_date: expiry,
+            status: MembershipStatus::Active,
+        },
+    );
+}
+
+#[test]
+fn test_tier_updated_event_carries_old_and_new_prices() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin, _usdc, tier_id) = setup_multi_asset_env(&env);
+
+    client.update_tier(
+        &admin,
+        &UpdateTierParams {
+            id: tier_id.clone(),
+            name: None,
+            price: Some(120_000),
+            annual_price: None,
+            features: None,
+            max_users: None,
+            max_storage: None,
+            is_active: None,
+            grandfather_existing: None,
+        },
+    );
+
+    assert_last_event(
+        &env,
+        &client.address,
+        &events::TierUpdated {
+            tier_id,
+            admin,
+            old_price: 100_000,
+            new_price: 120_000,
+            old_annual_price: 1_000_000,
+            new_annual_price: 1_000_000,
+            is_active: true,
+            updated_at: env.ledger().timestamp(),
+        },
+    );
+}
+
+// ==================== Attendance Hook Tests ====================
+
+mod mock_hooks {
+    use crate::types::AttendanceAction;
+    use soroban_sdk::{contract, contractimpl, symbol_short, Address, Env};
+
+    /// Counts `on_attendance` calls and remembers the last user.
+    #[contract]
+    pub struct RecordingHook;
+
+    #[contractimpl]
+    impl RecordingHook {
+        pub fn on_attendance(env: Env, user: Address, _action: AttendanceAction, _timestamp: u64) {
+            let calls: u32 = env
+                .storage()
+                .instance()
+                .get(&symbol_short!("calls"))
+                .unwrap_or(0);
+            env.storage()
+                .instance()
+                .set(&symbol_short!("calls"), &(calls + 1));
+            env.storage().instance().set(&symbol_short!("user"), &user);
+        }
+
+        pub fn calls(env: Env) -> u32 {
+            env.storage()
+                .instance()
+                .get(&symbol_short!("calls"))
+                .unwrap_or(0)
+        }
+    }
+
+    /// Always panics.
+    #[contract]
+    pub struct FailingHook;
+
+    #[contractimpl]
+    impl FailingHook {
+        pub fn on_attendance(
+            _env: Env,
+            _user: Address,
+            _action: AttendanceAction,
+            _timestamp: u64,
+        ) {
+            panic!("hook failure");
+        }
+    }
+}
+
+#[test]
+fn test_attendance_hooks_are_notified_with_failure_isolation() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(Contract, ());
+    let client = ContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    client.set_admin(&admin);
+
+    let failing = env.register(mock_hooks::FailingHook, ());
+    let recording = env.register(mock_hooks::RecordingHook, ());
+    let recorder = mock_hooks::RecordingHookClient::new(&env, &recording);
+    client.register_attendance_hook(&admin, &failing);
+    client.register_attendance_hook(&admin, &recording);
+    client.register_attendance_hook(&admin, &recording);
+    assert_eq!(client.get_attendance_hooks().len(), 2);
+
+    let user = Address::generate(&env);
+    client.log_attendance(
+        &BytesN::<32>::random(&env),
+        &user,
+        &AttendanceAction::ClockIn,
+        &Map::new(&env),
+    );
+
+    // The failing hook did not revert the log or block later hooks
+    assert_eq!(client.get_logs_for_user(&user).len(), 1);
+    assert_eq!(recorder.calls(), 1);
+
+    client.unregister_attendance_hook(&admin, &recording);
+    client.log_attendance(
+        &BytesN::<32>::random(&env),
+        &user,
+        &AttendanceAction::ClockOut,
+        &Map::new(&env),
+    );
+    assert_eq!(recorder.calls(), 1);
+}
+
+#[test]
+fn test_attendance_hook_limit_and_admin_only() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(Contract, ());
+    let client = ContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    client.set_admin(&admin);
+
+    let outsider = Address::generate(&env);
+    let result = client.try_register_attendance_hook(&outsider, &Address::generate(&env));
+    assert_eq!(result, Err(Ok(Error::Unauthorized.into())));
+
+    for _ in 0..attendance_hooks::MAX_ATTENDANCE_HOOKS {
+        client.register_attendance_hook(&admin, &Address::generate(&env));
+    }
+    let result = client.try_register_attendance_hook(&admin, &Address::generate(&env));
+    assert_eq!(result, Err(Ok(AttendanceError::HookLimitReached.into())));
+}
+
+// ==================== Loyalty Points Tests ====================
+
+fn log_action(env: &Env, client: &ContractClient<'_>, user: &Address, action: AttendanceAction) {
+    client.log_attendance(&BytesN::<32>::random(env), user, &action, &Map::new(env));
+}
+
+fn week_pass(env: &Env) -> LoyaltyReward {
+    LoyaltyReward {
+        id: String::from_str(env, "week_pass"),
+        name: String::from_str(env, "Free week"),
+        points_cost: 30,
+        credit_days: 7,
+        is_active: true,
+    }
+}
+
+#[test]
+fn test_points_accrue_for_verified_sessions_at_tier_rate() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin, usdc, tier_id) = setup_multi_asset_env(&env);
+
+    client.set_loyalty_earn_rate(
+        &admin,
+        &String::from_str(&env, ""),
+        &LoyaltyEarnRate {
+            session_points: 1,
+            renewal_points: 5,
+        },
+    );
+    client.set_loyalty_earn_rate(
+        &admin,
+        &tier_id,
+        &LoyaltyEarnRate {
+            session_points: 10,
+            renewal_points: 50,
+        },
+    );
+
+    // Untiered member earns the default rate; short sessions earn nothing
+    let user = Address::generate(&env);
+    log_action(&env, &client, &user, AttendanceAction::ClockIn);
+    env.ledger().with_mut(|l| l.timestamp += 60);
+    log_action(&env, &client, &user, AttendanceAction::ClockOut);
+    assert_eq!(client.get_points(&user), 0);
+
+    log_action(&env, &client, &user, AttendanceAction::ClockIn);
+    env.ledger().with_mut(|l| l.timestamp += 3_600);
+    log_action(&env, &client, &user, AttendanceAction::ClockOut);
+    assert_eq!(client.get_points(&user), 1);
+
+    // Once subscribed to the tier, sessions and renewals use the tier rate
+    let sub_id = String::from_str(&env, "sub_loyal");
+    client.create_subscription_with_tier(
+        &sub_id,
+        &user,
+        &usdc,
+        &tier_id,
+        &BillingCycle::Monthly,
+        &None,
+    );
+    log_action(&env, &client, &user, AttendanceAction::ClockIn);
+    env.ledger().with_mut(|l| l.timestamp += 3_600);
+    log_action(&env, &client, &user, AttendanceAction::ClockOut);
+    client.renew_subscription(&sub_id, &usdc, &100_000, &2_592_000);
+
+    let account = client.get_loyalty_account(&user);
+    assert_eq!(account.points, 61);
+    assert_eq!(account.lifetime_points, 61);
+}
+
+#[test]
+fn test_redeemed_day_credits_extend_next_renewal() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin, usdc, tier_id) = setup_multi_asset_env(&env);
+
+    client.set_loyalty_earn_rate(
+        &admin,
+        &tier_id,
+        &LoyaltyEarnRate {
+            session_points: 0,
+            renewal_points: 40,
+        },
+    );
+    client.set_loyalty_reward(&admin, &week_pass(&env));
+    assert_eq!(client.get_loyalty_rewards().len(), 1);
+
+    let user = Address::generate(&env);
+    let sub_id = String::from_str(&env, "sub_credit");
+    client.create_subscription_with_tier(
+        &sub_id,
+        &user,
+        &usdc,
+        &tier_id,
+        &BillingCycle::Monthly,
+        &None,
+    );
+    client.renew_subscription(&sub_id, &usdc, &100_000, &2_592_000);
+    assert_eq!(client.get_points(&user), 40);
+
+    let account = client.redeem_points(&user, &String::from_str(&env, "week_pass"));
+    assert_eq!(account.points, 10);
+    assert_eq!(account.day_credits, 7);
+
+    let before = client.get_subscription(&sub_id).expires_at;
+    client.renew_subscription(&sub_id, &usdc, &100_000, &2_592_000);
+    let after = client.get_subscription(&sub_id).expires_at;
+    assert_eq!(after - before, 2_592_000 + 7 * 86_400);
+    assert_eq!(client.get_loyalty_account(&user).day_credits, 0);
+}
+
+#[test]
+fn test_redeem_points_rejects_unknown_inactive_or_unaffordable() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(Contract, ());
+    let client = ContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let user = Address::generate(&env);
+    client.set_admin(&admin);
+
+    let result = client.try_set_loyalty_reward(&user, &week_pass(&env));
+    assert_eq!(result, Err(Ok(Error::Unauthorized.into())));
+
+    let reward_id = String::from_str(&env, "week_pass");
+    let result = client.try_redeem_points(&user, &reward_id);
+    assert_eq!(result, Err(Ok(LoyaltyError::RewardNotFound.into())));
+
+    client.set_loyalty_reward(&admin, &week_pass(&env));
+    let result = client.try_redeem_points(&user, &reward_id);
+    assert_eq!(result, Err(Ok(LoyaltyError::InsufficientPoints.into())));
+
+    let mut retired = week_pass(&env);
+    retired.is_active = false;
+    client.set_loyalty_reward(&admin, &retired);
+    let result = client.try_redeem_points(&user, &reward_id);
+    assert_eq!(result, Err(Ok(LoyaltyError::RewardNotFound.into())));
+}
+
+// ==================== Streak Tests ====================
+
+fn check_in_on_day(env: &Env, client: &ContractClient<'_>, user: &Address, day: u64) {
+    env.ledger()
+        .with_mut(|l| l.timestamp = day * 86_400 + 9 * 3_600);
+    log_action(env, client, user, AttendanceAction::ClockIn);
+}
+
+#[test]
+fn test_streak_counts_consecutive_days_and_resets_on_gap() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(Contract, ());
+    let client = ContractClient::new(&env, &contract_id);
+    let user = Address::generate(&env);
+
+    for day in 100..103 {
+        check_in_on_day(&env, &client, &user, day);
+    }
+    // A second check-in on the same day doesn't extend the streak
+    log_action(&env, &client, &user, AttendanceAction::ClockIn);
+    assert_eq!(client.get_streak(&user), 3);
+
+    check_in_on_day(&env, &client, &user, 105);
+    assert_eq!(client.get_streak(&user), 1);
+    assert_eq!(client.get_longest_streak(&user), 3);
+
+    env.ledger().with_mut(|l| l.timestamp = 108 * 86_400);
+    assert_eq!(client.get_streak(&user), 0);
+    assert_eq!(client.get_longest_streak(&user), 3);
+}
+
+#[test]
+fn test_streak_milestone_grants_bonus_once() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(Contract, ());
+    let client = ContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let user = Address::generate(&env);
+    client.set_admin(&admin);
+
+    let bonus = StreakBonus {
+        points: 25,
+        credit_days: 2,
+    };
+    let result = client.try_set_streak_bonus(&admin, &5, &bonus);
+    assert_eq!(result, Err(Ok(LoyaltyError::InvalidStreakMilestone.into())));
+    client.set_streak_bonus(&admin, &7, &bonus);
+
+    for day in 200..206 {
+        check_in_on_day(&env, &client, &user, day);
+    }
+    assert_eq!(client.get_points(&user), 0);
+
+    check_in_on_day(&env, &client, &user, 206);
+    check_in_on_day(&env, &client, &user, 207);
+    let account = client.get_loyalty_account(&user);
+    assert_eq!(client.get_streak(&user), 8);
+    assert_eq!(account.points, 25);
+    assert_eq!(account.day_credits, 2);
+}
+
+// ==================== Achievement Tests ====================
+
+fn badge(env: &Env, id: &str, criterion: BadgeCriterion) -> BadgeDefinition {
+    BadgeDefinition {
+        id: String::from_str(env, id),
+        name: String::from_str(env, id),
+        criterion,
+        is_active: true,
+    }
+}
+
+#[test]
+fn test_claim_attendance_badge_once_criterion_met() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(Contract, ());
+    let client = ContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let user = Address::generate(&env);
+    client.set_admin(&admin);
+
+    let badge_id = String::from_str(&env, "regular");
+    client.define_badge(
+        &admin,
+        &badge(&env, "regular", BadgeCriterion::VerifiedSessions(2)),
+    );
+
+    for _ in 0..2 {
+        let result = client.try_claim_badge(&user, &badge_id, &None);
+        assert_eq!(result, Err(Ok(AchievementError::CriterionNotMet.into())));
+
+        log_action(&env, &client, &user, AttendanceAction::ClockIn);
+        env.ledger().with_mut(|l| l.timestamp += 3_600);
+        log_action(&env, &client, &user, AttendanceAction::ClockOut);
+    }
+
+    let awarded = client.claim_badge(&user, &badge_id, &None);
+    assert_eq!(awarded.awarded_at, env.ledger().timestamp());
+    assert!(client.has_badge(&user, &badge_id));
+    assert_eq!(client.get_badges(&user), vec![&env, awarded]);
+
+    let result = client.try_claim_badge(&user, &badge_id, &None);
+    assert_eq!(
+        result,
+        Err(Ok(AchievementError::BadgeAlreadyAwarded.into()))
+    );
+}
+
+#[test]
+fn test_anniversary_badge_appears_in_token_metadata() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(Contract, ());
+    let client = ContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let user = Address::generate(&env);
+    let other = Address::generate(&env);
+    client.set_admin(&admin);
+
+    let token_id = BytesN::<32>::random(&env);
+    client.issue_token(&token_id, &user, &(env.ledger().timestamp() + 400 * 86_400));
+
+    let badge_id = String::from_str(&env, "one_year");
+    client.define_badge(
+        &admin,
+        &badge(&env, "one_year", BadgeCriterion::MembershipDays(365)),
+    );
+
+    // Anniversary badges need the member's own token
+    let result = client.try_claim_badge(&user, &badge_id, &None);
+    assert_eq!(result, Err(Ok(AchievementError::CriterionNotMet.into())));
+    let result = client.try_claim_badge(&other, &badge_id, &Some(token_id.clone()));
+    assert_eq!(result, Err(Ok(Error::Unauthorized.into())));
+
+    env.ledger().with_mut(|l| l.timestamp += 365 * 86_400);
+    let awarded = client.claim_badge(&user, &badge_id, &Some(token_id.clone()));
+
+    let metadata = client.get_token_metadata(&token_id);
+    assert_eq!(
+        metadata.attributes.get(badge_id),
+        Some(MetadataValue::Timestamp(awarded.awarded_at))
+    );
+}
+
+#[test]
+fn test_manual_badge_granted_by_admin_only() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(Contract, ());
+    let client = ContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let user = Address::generate(&env);
+    client.set_admin(&admin);
+
+    let badge_id = String::from_str(&env, "founder");
+    let result = client.try_grant_badge(&admin, &user, &badge_id, &None);
+    assert_eq!(result, Err(Ok(AchievementError::BadgeNotFound.into())));
+
+    client.define_badge(&admin, &badge(&env, "founder", BadgeCriterion::Manual));
+    let result = client.try_claim_badge(&user, &badge_id, &None);
+    assert_eq!(result, Err(Ok(AchievementError::CriterionNotMet.into())));
+    let result = client.try_grant_badge(&user, &user, &badge_id, &None);
+    assert_eq!(result, Err(Ok(Error::Unauthorized.into())));
+
+    client.grant_badge(&admin, &user, &badge_id, &None);
+    assert!(client.has_badge(&user, &badge_id));
+    assert_eq!(client.get_badge_definitions().len(), 1);
+}
+
+// ==================== Storage Migration Tests ====================
+
+fn forget_token_schema(env: &Env, contract_id: &Address, token_id: &BytesN<32>) {
+    env.as_contract(contract_id, || {
+        env.storage()
+            .persistent()
+            .remove(&migration::MigrationDataKey::TokenSchema(token_id.clone()));
+    });
+}
+
+#[test]
+fn test_migrate_tokens_batch_tracks_progress() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(Contract, ());
+    let client = ContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let user = Address::generate(&env);
+    client.set_admin(&admin);
+
+    let expiry = env.ledger().timestamp() + 1_000;
+    let legacy = BytesN::<32>::random(&env);
+    let fresh = BytesN::<32>::random(&env);
+    client.issue_token(&legacy, &user, &expiry);
+    client.issue_token(&fresh, &user, &expiry);
+    forget_token_schema(&env, &contract_id, &legacy);
+
+    assert_eq!(client.get_token_schema_version(&fresh), 2);
+    assert_eq!(client.get_token_schema_version(&legacy), 0);
+
+    let missing = BytesN::<32>::random(&env);
+    let progress = client.migrate_tokens(&admin, &vec![&env, legacy.clone(), fresh, missing]);
+    assert_eq!(progress.migrated, 1);
+    assert_eq!(progress.skipped, 2);
+    assert_eq!(progress.batches, 1);
+    assert_eq!(client.get_token_schema_version(&legacy), 2);
+    assert_eq!(client.get_token(&legacy).user, user);
+
+    let result = client.try_migrate_tokens(&user, &vec![&env, legacy]);
+    assert_eq!(result, Err(Ok(Error::Unauthorized)));
+    let result = client.try_migrate_subscriptions(&admin, &Vec::new(&env));
+    assert_eq!(result, Err(Ok(Error::Unauthorized)));
+}
+
+#[test]
+fn test_renewal_migrates_subscription_lazily() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin, usdc, tier_id) = setup_multi_asset_env(&env);
+
+    let user = Address::generate(&env);
+    let sub_id = String::from_str(&env, "sub_legacy");
+    client.create_subscription_with_tier(
+        &sub_id,
+        &user,
+        &usdc,
+        &tier_id,
+        &BillingCycle::Monthly,
+        &None,
+    );
+    env.as_contract(&client.address, || {
+        env.storage()
+            .persistent()
+            .remove(&migration::MigrationDataKey::SubscriptionSchema(
+                sub_id.clone(),
+            ));
+    });
+    assert_eq!(client.get_subscription_schema_version(&sub_id), 0);
+
+    client.renew_subscription(&sub_id, &usdc, &100_000, &2_592_000);
+    assert_eq!(client.get_subscription_schema_version(&sub_id), 2);
+    assert_eq!(
+        client
+            .get_migration_progress(&MigrationEntity::Subscription)
+            .migrated,
+        0
+    );
+}
+
+#[test]
+fn test_storage_version_set_on_initialize_or_finalize() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(Contract, ());
+    let client = ContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let access_control = Address::generate(&env);
+    client.initialize(
+        &admin,
+        &Address::generate(&env),
+        &access_control,
+        &default_init_config(&env),
+    );
+    assert_eq!(client.get_storage_version(), 2);
+
+    // Pre-versioning deployments report 0 until the admin finalizes
+    let legacy_id = env.register(Contract, ());
+    let legacy = ContractClient::new(&env, &legacy_id);
+    legacy.set_admin(&admin);
+    assert_eq!(legacy.get_storage_version(), 0);
+
+    let result = legacy.try_finalize_storage_migration(&access_control);
+    assert_eq!(result, Err(Ok(Error::Unauthorized.into())));
+
+    // Finalizing needs a batch of each kind that found nothing to upgrade
+    let result = legacy.try_finalize_storage_migration(&admin);
+    assert_eq!(result, Err(Ok(UpgradeError::MigrationIncomplete.into())));
+    legacy.migrate_tokens(&admin, &vec![&env, BytesN::<32>::random(&env)]);
+    legacy.migrate_subscriptions(&admin, &vec![&env, String::from_str(&env, "none")]);
+    assert_eq!(legacy.finalize_storage_migration(&admin), 2);
+    assert_eq!(legacy.get_storage_version(), 2);
+}
+
+#[test]
+fn test_batch_migration_upgrades_v0_subscription_before_finalize() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(Contract, ());
+    let client = ContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let user = Address::generate(&env);
+    let subscription_id = String::from_str(&env, "sub_v0_batch");
+    client.set_admin(&admin);
+
+    let v0 = types::SubscriptionV0 {
+        id: subscription_id.clone(),
+        user: user.clone(),
+        payment_token: Address::generate(&env),
+        amount: 100_000,
+        status: MembershipStatus::Active,
+        created_at: 0,
+        expires_at: 2_592_000,
+        tier_id: String::from_str(&env, ""),
+        billing_cycle: BillingCycle::Monthly,
+        paused_at: None,
+        last_resumed_at: 0,
+        pause_count: 0,
+        total_paused_duration: 0,
+        pause_history: Vec::new(&env),
+    };
+    env.as_contract(&contract_id, || {
+        env.storage().persistent().set(
+            &subscription::SubscriptionDataKey::Subscription(subscription_id.clone()),
+            &v0,
+        );
+    });
+
+    let ids = vec![&env, subscription_id.clone()];
+    let progress = client.migrate_subscriptions(&admin, &ids);
+    assert_eq!(progress.migrated, 1);
+    assert_eq!(progress.last_batch_migrated, 1);
+    assert_eq!(client.get_subscription_schema_version(&subscription_id), 2);
+    assert_eq!(client.get_subscription(&subscription_id).locked_price, 0);
+
+    client.migrate_tokens(&admin, &vec![&env, BytesN::<32>::random(&env)]);
+    assert_eq!(
+        client.try_finalize_storage_migration(&admin),
+        Err(Ok(UpgradeError::MigrationIncomplete.into()))
+    );
+
+    // A second pass finds nothing left
+    let progress = client.migrate_subscriptions(&admin, &ids);
+    assert_eq!(progress.last_batch_migrated, 0);
+    assert_eq!(client.finalize_storage_migration(&admin), 2);
+}
+
+// ==================== Contract Upgrade Tests ====================
+
+#[test]
+fn test_contract_upgrade_time_lock_and_cancel() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(Contract, ());
+    let client = ContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    client.set_admin(&admin);
+
+    let result = client.try_execute_contract_upgrade(&admin);
+    assert_eq!(result, Err(Ok(ConfigChangeError::NoPendingChange.into())));
+
+    let wasm_hash = BytesN::<32>::random(&env);
+    let scheduled = client.schedule_contract_upgrade(&admin, &wasm_hash);
+    assert_eq!(
+        scheduled.executable_at,
+        env.ledger().timestamp() + 48 * 3_600
+    );
+    assert_eq!(client.get_scheduled_upgrade(), Some(scheduled));
+
+    let result = client.try_execute_contract_upgrade(&admin);
+    assert_eq!(result, Err(Ok(ConfigChangeError::TimelockActive.into())));
+
+    client.cancel_scheduled_upgrade(&admin);
+    assert_eq!(client.get_scheduled_upgrade(), None);
+    assert_eq!(client.get_contract_version(), 1);
+
+    env.ledger().with_mut(|l| l.timestamp += 48 * 3_600);
+    let result = client.try_execute_contract_upgrade(&admin);
+    assert_eq!(result, Err(Ok(ConfigChangeError::NoPendingChange.into())));
+}
+
+#[test]
+fn test_contract_upgrade_requires_multisig_when_registered() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(Contract, ());
+    let client = ContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let access_control = Address::generate(&env);
+    client.initialize(
+        &admin,
+        &Address::generate(&env),
+        &access_control,
+        &default_init_config(&env),
+    );
+
+    // The admin alone can no longer schedule once multisig is wired in
+    let wasm_hash = BytesN::<32>::random(&env);
+    let result = client.try_schedule_contract_upgrade(&admin, &wasm_hash);
+    assert_eq!(result, Err(Ok(Error::Unauthorized)));
+
+    client.schedule_contract_upgrade(&access_control, &wasm_hash);
+    assert_eq!(
+        client.get_scheduled_upgrade().unwrap().scheduled_by,
+        access_control
+    );
+
+    let stranger = Address::generate(&env);
+    let result = client.try_cancel_scheduled_upgrade(&stranger);
+    assert_eq!(result, Err(Ok(Error::Unauthorized.into())));
+    client.cancel_scheduled_upgrade(&admin);
+}
+
+// ==================== Guardian Tests ====================
+
+#[test]
+fn test_guardian_can_pause_but_not_unpause() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(Contract, ());
+    let client = ContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let guardian = Address::generate(&env);
+    let user = Address::generate(&env);
+    client.set_admin(&admin);
+
+    let token_id = BytesN::<32>::random(&env);
+    client.issue_token(&token_id, &user, &(env.ledger().timestamp() + 10_000));
+
+    let reason = Some(String::from_str(&env, "incident"));
+    let result = client.try_emergency_pause(&guardian, &reason, &None, &None);
+    assert_eq!(result, Err(Ok(Error::Unauthorized)));
+
+    client.add_guardian(&admin, &guardian);
+    assert!(client.is_guardian(&guardian));
+    assert_eq!(client.get_guardians(), vec![&env, guardian.clone()]);
+
+    // Guardians cannot set a time lock that would also bind the admin
+    let result = client.try_emergency_pause(&guardian, &reason, &None, &Some(3_600));
+    assert_eq!(result, Err(Ok(Error::Unauthorized)));
+
+    client.emergency_pause(&guardian, &reason, &None, &None);
+    assert!(client.is_contract_paused());
+    assert_eq!(
+        client.get_emergency_pause_state().paused_by,
+        Some(guardian.clone())
+    );
+
+    let result = client.try_emergency_unpause(&guardian);
+    assert_eq!(result, Err(Ok(Error::Unauthorized.into())));
+    client.emergency_unpause(&admin);
+
+    client.pause_token_operations(&guardian, &token_id, &reason, &None, &None);
+    assert!(client.is_token_paused(&token_id));
+    let result = client.try_unpause_token_operations(&guardian, &token_id);
+    assert_eq!(result, Err(Ok(Error::Unauthorized.into())));
+}
+
+#[test]
+fn test_guardian_repause_keeps_admin_time_lock() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(Contract, ());
+    let client = ContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let guardian = Address::generate(&env);
+    let user = Address::generate(&env);
+    client.set_admin(&admin);
+    client.add_guardian(&admin, &guardian);
+    let token_id = BytesN::<32>::random(&env);
+    client.issue_token(&token_id, &user, &(env.ledger().timestamp() + 100_000));
+
+    let now = env.ledger().timestamp();
+    client.emergency_pause(&admin, &None, &Some(7_200), &Some(3_600));
+
+    // An immediate auto-unpause from a guardian must not lift the pause
+    client.emergency_pause(&guardian, &None, &Some(0), &None);
+    let state = client.get_emergency_pause_state();
+    assert_eq!(state.auto_unpause_at, Some(now + 7_200));
+    assert_eq!(state.time_lock_until, Some(now + 3_600));
+    env.ledger().with_mut(|l| l.timestamp += 1);
+    assert!(client.is_contract_paused());
+    assert_eq!(
+        client.try_emergency_unpause(&admin),
+        Err(Ok(PauseError::TimeLockActive.into()))
+    );
+
+    // A later deadline from a guardian extends the pause
+    client.emergency_pause(&guardian, &None, &Some(10_000), &None);
+    assert_eq!(
+        client.get_emergency_pause_state().auto_unpause_at,
+        Some(now + 10_001)
+    );
+
+    client.pause_token_operations(&admin, &token_id, &None, &None, &Some(3_600));
+    client.pause_token_operations(&guardian, &token_id, &None, &Some(0), &None);
+    client.batch_pause_token_operations(&guardian, &vec![&env, token_id.clone()], &None);
+    let history = client.get_token_pause_history(&token_id, &0, &10);
+    let latest = history.last().unwrap();
+    assert_eq!(latest.time_lock_until, Some(now + 1 + 3_600));
+    assert_eq!(latest.auto_unpause_at, None);
+    assert!(client.is_token_paused(&token_id));
+    assert_eq!(
+        client.try_unpause_token_operations(&admin, &token_id),
+        Err(Ok(PauseError::TimeLockActive.into()))
+    );
+}
+
+#[test]
+fn test_guardian_extension_keeps_pause_attribution() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(Contract, ());
+    let client = ContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let guardian = Address::generate(&env);
+    let user = Address::generate(&env);
+    client.set_admin(&admin);
+    client.add_guardian(&admin, &guardian);
+    let token_id = BytesN::<32>::random(&env);
+    client.issue_token(&token_id, &user, &(env.ledger().timestamp() + 100_000));
+
+    let now = env.ledger().timestamp();
+    let reason = Some(String::from_str(&env, "incident"));
+    client.emergency_pause(&admin, &reason, &Some(600), &None);
+    client.pause_token_operations(&admin, &token_id, &reason, &Some(600), &None);
+
+    env.ledger().with_mut(|l| l.timestamp += 60);
+    let other = Some(String::from_str(&env, "still investigating"));
+    client.emergency_pause(&guardian, &other, &Some(1_200), &None);
+    client.pause_token_operations(&guardian, &token_id, &other, &Some(1_200), &None);
+
+    let state = client.get_emergency_pause_state();
+    assert_eq!(state.paused_by, Some(admin.clone()));
+    assert_eq!(state.reason, reason);
+    assert_eq!(state.paused_at, Some(now));
+    assert_eq!(state.pause_count, 1);
+    assert_eq!(state.auto_unpause_at, Some(now + 1_260));
+    let history = client.get_emergency_pause_history(&0, &10);
+    assert_eq!(history.len(), 1);
+    let record = history.get(0).unwrap();
+    assert_eq!(record.paused_by, admin);
+    assert_eq!(record.unpaused_at, None);
+    assert_eq!(record.auto_unpause_at, Some(now + 1_260));
+
+    let history = client.get_token_pause_history(&token_id, &0, &10);
+    assert_eq!(history.len(), 1);
+    let record = history.get(0).unwrap();
+    assert_eq!(record.paused_by, admin);
+    assert_eq!(record.reason, reason);
+    assert_eq!(record.unpaused_at, None);
+    assert_eq!(record.auto_unpause_at, Some(now + 1_260));
+}
+
+#[test]
+fn test_guardian_management_restricted_to_admin_or_multisig() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(Contract, ());
+    let client = ContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let access_control = Address::generate(&env);
+    let guardian = Address::generate(&env);
+    client.initialize(
+        &admin,
+        &Address::generate(&env),
+        &access_control,
+        &default_init_config(&env),
+    );
+
+    let result = client.try_add_guardian(&guardian, &guardian);
+    assert_eq!(result, Err(Ok(Error::Unauthorized)));
+
+    client.add_guardian(&access_control, &guardian);
+    let result = client.try_remove_guardian(&guardian, &guardian);
+    assert_eq!(result, Err(Ok(Error::Unauthorized)));
+
+    client.remove_guardian(&admin, &guardian);
+    assert!(!client.is_guardian(&guardian));
+    let result = client.try_pause_token_operations(
+        &guardian,
+        &BytesN::<32>::random(&env),
+        &None,
+        &None,
+        &None,
+    );
+    assert_eq!(result, Err(Ok(Error::Unauthorized)));
+}
+
+// ==================== Emergency Pause History Tests ====================
+
+#[test]
+fn test_emergency_pause_history_records_actors_and_durations() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(Contract, ());
+    let client = ContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let guardian = Address::generate(&env);
+    client.set_admin(&admin);
+    client.add_guardian(&admin, &guardian);
+    let start = env.ledger().timestamp();
+
+    let reason = Some(String::from_str(&env, "exploit"));
+    client.emergency_pause(&guardian, &reason, &None, &None);
+    env.ledger().with_mut(|l| l.timestamp += 600);
+    client.emergency_unpause(&admin);
+
+    // Second pause expires on its own
+    client.emergency_pause(&admin, &None, &Some(300), &None);
+    env.ledger().with_mut(|l| l.timestamp += 1_000);
+
+    let history = client.get_emergency_pause_history(&0, &10);
+    assert_eq!(history.len(), 2);
+
+    let first = history.get(0).unwrap();
+    assert_eq!(first.paused_by, guardian);
+    assert_eq!(first.reason, reason);
+    assert_eq!(first.unpaused_by, Some(admin.clone()));
+    assert_eq!(first.unpaused_at, Some(start + 600));
+    assert_eq!(first.duration, 600);
+
+    let second = history.get(1).unwrap();
+    assert_eq!(second.paused_by, admin);
+    assert_eq!(second.unpaused_at, Some(start + 900));
+    assert_eq!(second.unpaused_by, None);
+    assert_eq!(second.duration, 300);
+
+    let page = client.get_emergency_pause_history(&1, &10);
+    assert_eq!(page, vec![&env, second]);
+    assert_eq!(client.get_emergency_pause_history(&2, &10).len(), 0);
+}
+
+#[test]
+fn test_active_emergency_pause_is_open_in_history() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(Contract, ());
+    let client = ContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    client.set_admin(&admin);
+
+    client.emergency_pause(&admin, &None, &None, &None);
+    env.ledger().with_mut(|l| l.timestamp += 60);
+
+    let record = client.get_emergency_pause_history(&0, &1).get(0).unwrap();
+    assert_eq!(record.unpaused_at, None);
+    assert_eq!(record.duration, 0);
+    assert_eq!(client.get_emergency_pause_state().pause_count, 1);
+}
+
+// ==================== Subscription Bundle Tests ====================
+
+fn power_bundle(env: &Env, tier_id: &String) -> CreateBundleParams {
+    CreateBundleParams {
+        id: String::from_str(env, "bundle_power"),
+        name: String::from_str(env, "Power Pack"),
+        tier_id: tier_id.clone(),
+        price: 80_000,
+        annual_price: 800_000,
+        staking_bonus_bps: 2_500,
+        extra_features: soroban_sdk::vec![env, common_types::TierFeature::AdvancedAnalytics],
+    }
+}
+
+#[test]
+fn test_subscribe_to_bundle_charges_bundle_price_and_grants_entitlements() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin, usdc, tier_id) = setup_multi_asset_env(&env);
+    let params = power_bundle(&env, &tier_id);
+    client.create_bundle(&admin, &params);
+
+    let user = Address::generate(&env);
+    let sub_id = String::from_str(&env, "sub_bundle");
+    let entitlements =
+        client.subscribe_to_bundle(&sub_id, &user, &params.id, &BillingCycle::Monthly);
+    assert_eq!(entitlements.subscription_id, sub_id);
+    assert_eq!(entitlements.staking_bonus_bps, 2_500);
+    assert_eq!(client.get_bundle_entitlements(&user), Some(entitlements));
+
+    let subscription = client.get_subscription(&sub_id);
+    assert_eq!(subscription.tier_id, tier_id);
+    assert_eq!(subscription.payment_token, usdc);
+    assert_eq!(subscription.amount, 80_000);
+
+    // Tier feature plus the bundle's extra feature
+    assert!(client.check_feature_access(&sub_id, &common_types::TierFeature::BasicAccess));
+    assert!(client.check_feature_access(&sub_id, &common_types::TierFeature::AdvancedAnalytics));
+    assert!(!client.check_feature_access(&sub_id, &common_types::TierFeature::PrioritySupport));
+}
+
+#[test]
+fn test_bundle_management_is_admin_only_and_inactive_bundles_rejected() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin, _usdc, tier_id) = setup_multi_asset_env(&env);
+    let params = power_bundle(&env, &tier_id);
+
+    let outsider = Address::generate(&env);
+    assert_eq!(
+        client.try_create_bundle(&outsider, &params),
+        Err(Ok(Error::Unauthorized))
+    );
+
+    let mut unknown_tier = params.clone();
+    unknown_tier.tier_id = String::from_str(&env, "tier_missing");
+    assert_eq!(
+        client.try_create_bundle(&admin, &unknown_tier),
+        Err(Ok(Error::TierNotFound))
+    );
+
+    client.create_bundle(&admin, &params);
+    assert_eq!(
+        client.try_create_bundle(&admin, &params),
+        Err(Ok(Error::TierAlreadyExists))
+    );
+
+    client.deactivate_bundle(&admin, &params.id);
+    assert!(!client.get_bundle(&params.id).is_active);
+    assert_eq!(client.get_bundles().len(), 1);
+
+    let user = Address::generate(&env);
+    let result = client.try_subscribe_to_bundle(
+        &String::from_str(&env, "sub_late"),
+        &user,
+        &params.id,
+        &BillingCycle::Annual,
+    );
+    assert_eq!(result, Err(Ok(Error::TierNotActive.into())));
+    assert_eq!(client.get_bundle_entitlements(&user), None);
+}
+
+// ==================== Household Plan Tests ====================
+
+fn household_primary(env: &Env) -> (ContractClient<'_>, Address, String) {
+    let (client, admin, usdc, tier_id) = setup_multi_asset_env(env);
+    let primary = Address::generate(env);
+    let sub_id = String::from_str(env, "sub_family");
+    client.create_subscription_with_tier(
+        &sub_id,
+        &primary,
+        &usdc,
+        &tier_id,
+        &BillingCycle::Monthly,
+        &None,
+    );
+    (client, admin, sub_id)
+}
+
+#[test]
+fn test_household_dependents_share_primary_tier_features() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin, sub_id) = household_primary(&env);
+
+    let child = Address::generate(&env);
+    let stranger = Address::generate(&env);
+    client.add_household_member(&sub_id, &child);
+    assert_eq!(client.get_household(&child), Some(sub_id.clone()));
+    assert_eq!(client.get_household_members(&sub_id).len(), 1);
+
+    let basic = common_types::TierFeature::BasicAccess;
+    assert!(client.check_member_feature_access(&child, &basic));
+    assert!(!client.check_member_feature_access(&stranger, &basic));
+    assert!(
+        !client.check_member_feature_access(&child, &common_types::TierFeature::AdvancedAnalytics)
+    );
+
+    // Access lapses with the primary subscription
+    let expires_at = client.get_subscription(&sub_id).expires_at;
+    env.ledger().with_mut(|l| l.timestamp = expires_at + 1);
+    assert!(!client.check_member_feature_access(&child, &basic));
+}
+
+#[test]
+fn test_household_membership_limits_and_removal() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin, sub_id) = household_primary(&env);
+    client.set_max_household_members(&admin, &2);
+    assert_eq!(
+        client.try_set_max_household_members(&admin, &11),
+        Err(Ok(HouseholdError::InvalidHouseholdLimit.into()))
+    );
+
+    let primary = client.get_subscription(&sub_id).user;
+    assert_eq!(
+        client.try_add_household_member(&sub_id, &primary),
+        Err(Ok(HouseholdError::AlreadyLinked.into()))
+    );
+
+    let first = Address::generate(&env);
+    let second = Address::generate(&env);
+    client.add_household_member(&sub_id, &first);
+    client.add_household_member(&sub_id, &second);
+    assert_eq!(
+        client.try_add_household_member(&sub_id, &Address::generate(&env)),
+        Err(Ok(HouseholdError::HouseholdFull.into()))
+    );
+
+    client.remove_household_member(&sub_id, &first);
+    assert_eq!(client.get_household(&first), None);
+    assert_eq!(client.get_household_members(&sub_id), vec![&env, second]);
+    assert_eq!(
+        client.try_remove_household_member(&sub_id, &first),
+        Err(Ok(HouseholdError::NotHouseholdMember.into()))
+    );
+}
+
+#[test]
+fn test_dependent_attendance_attributed_to_household() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin, sub_id) = household_primary(&env);
+    let child = Address::generate(&env);
+    client.add_household_member(&sub_id, &child);
+
+    env.ledger().with_mut(|l| l.timestamp = 10_000);
+    log_action(&env, &client, &child, AttendanceAction::ClockIn);
+    env.ledger().with_mut(|l| l.timestamp = 13_600);
+    log_action(&env, &client, &child, AttendanceAction::ClockOut);
+
+    // Counted for the individual and the household
+    assert_eq!(client.get_logs_for_user(&child).len(), 2);
+    let attendance = client.get_household_attendance(&sub_id);
+    assert_eq!(attendance.total_logs, 2);
+    assert_eq!(attendance.clock_ins, 1);
+    assert_eq!(attendance.last_member, Some(child));
+    assert_eq!(attendance.last_logged_at, 13_600);
+}
+
+// ==================== Transfer Fee Tests ====================
+
+fn setup_transfer_fee_env(
+    env: &Env,
+) -> (
+    ContractClient<'_>,
+    Address,
+    soroban_sdk::token::Client<'_>,
+    Address,
+) {
+    let contract_id = env.register(Contract, ());
+    let client = ContractClient::new(env, &contract_id);
+    let admin = Address::generate(env);
+    client.set_admin(&admin);
+
+    let usdc = env.register_stellar_asset_contract_v2(admin.clone());
+    client.set_usdc_contract(&admin, &usdc.address());
+    let treasury = Address::generate(env);
+    client.set_transfer_fee(&admin, &500, &20, &treasury);
+
+    (
+        client,
+        admin,
+        soroban_sdk::token::Client::new(env, &usdc.address()),
+        treasury,
+    )
+}
+
+#[test]
+fn test_transfer_from_charges_hub_fee_in_usdc() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin, usdc, treasury) = setup_transfer_fee_env(&env);
+
+    let owner = Address::generate(&env);
+    let marketplace = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    soroban_sdk::token::StellarAssetClient::new(&env, &usdc.address).mint(&marketplace, &1_000);
+
+    let token_id = BytesN::<32>::random(&env);
+    client.issue_token(&token_id, &owner, &(env.ledger().timestamp() + 86_400));
+    client.approve(&token_id, &marketplace, &1_000, &None);
+    client.transfer_from(&token_id, &owner, &buyer, &marketplace, &1_000);
+
+    // 5% of the 1,000 allowance used, paid by the spender
+    assert_eq!(usdc.balance(&treasury), 50);
+    assert_eq!(usdc.balance(&marketplace), 950);
+    assert_eq!(client.get_token(&token_id).user, buyer);
+
+    // Declaring a tiny allowance amount still pays the minimum fee
+    let resold_to = Address::generate(&env);
+    client.approve(&token_id, &marketplace, &1_000, &None);
+    client.transfer_from(&token_id, &buyer, &resold_to, &marketplace, &1);
+    assert_eq!(usdc.balance(&treasury), 70);
+    assert_eq!(client.get_token(&token_id).user, resold_to);
+}
+
+#[test]
+fn test_transfer_fee_skipped_for_exempt_and_admin_transfers() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin, usdc, treasury) = setup_transfer_fee_env(&env);
+    let expiry = env.ledger().timestamp() + 86_400;
+
+    // Exempt spender
+    let owner = Address::generate(&env);
+    let partner = Address::generate(&env);
+    client.set_transfer_fee_exempt(&admin, &partner, &true);
+    assert!(client.is_transfer_fee_exempt(&partner));
+    let token_id = BytesN::<32>::random(&env);
+    client.issue_token(&token_id, &owner, &expiry);
+    client.approve(&token_id, &partner, &1_000, &None);
+    client.transfer_from(
+        &token_id,
+        &owner,
+        &Address::generate(&env),
+        &partner,
+        &1_000,
+    );
+
+    // Admin-owned token
+    let spender = Address::generate(&env);
+    let admin_token = BytesN::<32>::random(&env);
+    client.issue_token(&admin_token, &admin, &expiry);
+    client.approve(&admin_token, &spender, &1_000, &None);
+    client.transfer_from(
+        &admin_token,
+        &admin,
+        &Address::generate(&env),
+        &spender,
+        &1_000,
+    );
+
+    assert_eq!(usdc.balance(&treasury), 0);
+
+    client.clear_transfer_fee(&admin);
+    assert_eq!(client.get_transfer_fee(), None);
+    assert_eq!(
+        client.try_set_transfer_fee(&admin, &10_001, &0, &treasury),
+        Err(Ok(Error::InvalidPaymentAmount))
+    );
+}
+
+#[test]
+fn test_hub_marketplace_fees_booked_as_revenue() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin, usdc, treasury) = setup_transfer_fee_env(&env);
+    let asset = soroban_sdk::token::StellarAssetClient::new(&env, &usdc.address);
+
+    let owner = Address::generate(&env);
+    let marketplace = Address::generate(&env);
+    asset.mint(&marketplace, &2_000);
+    let token_id = BytesN::<32>::random(&env);
+    client.issue_token(&token_id, &owner, &(env.ledger().timestamp() + 86_400));
+    client.approve(&token_id, &marketplace, &1_000, &None);
+    let first_holder = Address::generate(&env);
+    client.transfer_from(&token_id, &owner, &first_holder, &marketplace, &1_000);
+
+    // Fees paid to an external recipient never reach the treasury
+    assert_eq!(usdc.balance(&treasury), 50);
+    assert_eq!(client.get_lifetime_revenue().marketplace_fees, 0);
+
+    client.set_transfer_fee(&admin, &500, &20, &client.address);
+    client.approve(&token_id, &marketplace, &1_000, &None);
+    let holder = Address::generate(&env);
+    client.transfer_from(&token_id, &first_holder, &holder, &marketplace, &1_000);
+    assert_eq!(client.get_lifetime_revenue().marketplace_fees, 50);
+    assert_eq!(client.get_withdrawable_revenue(&usdc.address), 50);
+
+    // Only the hub's own royalty share counts, not the creator's
+    let creator = Address::generate(&env);
+    client.set_royalty(
+        &token_id,
+        &vec![
+            &env,
+            types::RoyaltyRecipient {
+                address: client.address.clone(),
+                percentage: 200,
+            },
+            types::RoyaltyRecipient {
+                address: creator,
+                percentage: 1_000,
+            },
+        ],
+    );
+    client.list_token_for_sale(&token_id, &500);
+    let buyer = Address::generate(&env);
+    asset.mint(&buyer, &500);
+    client.buy_token(&token_id, &buyer);
+
+    let revenue = client.get_lifetime_revenue();
+    assert_eq!(revenue.marketplace_fees, 60);
+    assert_eq!(revenue.total, 60);
+    let now = env.ledger().timestamp();
+    let period = common_types::DateRange {
+        start_time: now,
+        end_time: now,
+    };
+    assert_eq!(client.get_revenue_breakdown(&period).marketplace_fees, 60);
+}
+
+// ==================== Token Sale Tests ====================
+
+fn setup_sale_env(
+    env: &Env,
+) -> (
+    ContractClient<'_>,
+    soroban_sdk::token::Client<'_>,
+    Address,
+    BytesN<32>,
+) {
+    let contract_id = env.register(Contract, ());
+    let client = ContractClient::new(env, &contract_id);
+    let admin = Address::generate(env);
+    client.set_admin(&admin);
+    let usdc = env.register_stellar_asset_contract_v2(admin.clone());
+    client.set_usdc_contract(&admin, &usdc.address());
+
+    let seller = Address::generate(env);
+    let token_id = BytesN::<32>::random(env);
+    client.issue_token(&token_id, &seller, &(env.ledger().timestamp() + 86_400));
+
+    (
+        client,
+        soroban_sdk::token::Client::new(env, &usdc.address()),
+        seller,
+        token_id,
+    )
+}
+
+#[test]
+fn test_buy_token_pays_seller_and_royalties_atomically() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, usdc, seller, token_id) = setup_sale_env(&env);
+
+    let creator = Address::generate(&env);
+    client.set_royalty(
+        &token_id,
+        &vec![
+            &env,
+            types::RoyaltyRecipient {
+                address: creator.clone(),
+                percentage: 1_000,
+            },
+        ],
+    );
+    client.list_token_for_sale(&token_id, &500);
+    assert_eq!(
+        client.try_list_token_for_sale(&token_id, &600),
+        Err(Ok(SaleError::AlreadyListed.into()))
+    );
+    assert_eq!(client.get_active_sales(&0, &50).len(), 1);
+
+    let buyer = Address::generate(&env);
+    soroban_sdk::token::StellarAssetClient::new(&env, &usdc.address).mint(&buyer, &500);
+    client.buy_token(&token_id, &buyer);
+
+    assert_eq!(client.get_token(&token_id).user, buyer);
+    assert_eq!(usdc.balance(&creator), 50);
+    assert_eq!(usdc.balance(&seller), 450);
+    assert_eq!(usdc.balance(&buyer), 0);
+    assert_eq!(client.get_active_sales(&0, &50).len(), 0);
+    assert_eq!(
+        client.try_buy_token(&token_id, &Address::generate(&env)),
+        Err(Ok(SaleError::SaleNotFound.into()))
+    );
+}
+
+#[test]
+fn test_transfer_withdraws_sale_listing() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _usdc, seller, token_id) = setup_sale_env(&env);
+
+    client.list_token_for_sale(&token_id, &500);
+    let holder = Address::generate(&env);
+    client.transfer_token(&token_id, &holder);
+
+    // Listing is gone once the seller no longer owns the token
+    assert_eq!(client.get_active_sales(&0, &50).len(), 0);
+    assert_eq!(
+        client.try_buy_token(&token_id, &Address::generate(&env)),
+        Err(Ok(SaleError::SaleNotFound.into()))
+    );
+    assert_eq!(
+        client.try_cancel_sale(&token_id),
+        Err(Ok(SaleError::SaleNotFound.into()))
+    );
+
+    // ...and does not come back when the token returns to the seller
+    client.transfer_token(&token_id, &seller);
+    assert_eq!(client.get_active_sales(&0, &50).len(), 0);
+    assert_eq!(
+        client.try_buy_token(&token_id, &Address::generate(&env)),
+        Err(Ok(SaleError::SaleNotFound.into()))
+    );
+}
+
+#[test]
+fn test_sale_listing_cap_is_per_seller() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _usdc, seller, token_id) = setup_sale_env(&env);
+    let expiry = env.ledger().timestamp() + 86_400;
+
+    client.list_token_for_sale(&token_id, &500);
+    for _ in 1..crate::sales::MAX_LISTINGS_PER_SELLER {
+        let id = BytesN::<32>::random(&env);
+        client.issue_token(&id, &seller, &expiry);
+        client.list_token_for_sale(&id, &500);
+    }
+    let extra = BytesN::<32>::random(&env);
+    client.issue_token(&extra, &seller, &expiry);
+    assert_eq!(
+        client.try_list_token_for_sale(&extra, &500),
+        Err(Ok(SaleError::TooManyListings.into()))
+    );
+
+    // Other sellers can still list
+    let other = BytesN::<32>::random(&env);
+    client.issue_token(&other, &Address::generate(&env), &expiry);
+    client.list_token_for_sale(&other, &500);
+
+    // A sold or cancelled listing frees a slot
+    client.cancel_sale(&token_id);
+    client.list_token_for_sale(&extra, &500);
+    assert_eq!(
+        client.get_active_sales(&0, &50).len(),
+        crate::sales::MAX_LISTINGS_PER_SELLER + 1
+    );
+}
+
+#[test]
+fn test_sale_listings_are_capped_and_paginated() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _usdc, _seller, _token_id) = setup_sale_env(&env);
+    let expiry = env.ledger().timestamp() + 86_400;
+
+    let mut listed = Vec::new(&env);
+    for _ in 0..crate::sales::MAX_ACTIVE_LISTINGS / crate::sales::MAX_LISTINGS_PER_SELLER {
+        let seller = Address::generate(&env);
+        for _ in 0..crate::sales::MAX_LISTINGS_PER_SELLER {
+            let id = BytesN::<32>::random(&env);
+            client.issue_token(&id, &seller, &expiry);
+            client.list_token_for_sale(&id, &500);
+            listed.push_back(id);
+        }
+    }
+    let extra = BytesN::<32>::random(&env);
+    client.issue_token(&extra, &Address::generate(&env), &expiry);
+    assert_eq!(
+        client.try_list_token_for_sale(&extra, &500),
+        Err(Ok(SaleError::ListingCapReached.into()))
+    );
+
+    // Pages are capped and follow listing order
+    let page = client.get_active_sales(&0, &1_000);
+    assert_eq!(page.len(), crate::sales::MAX_SALES_PAGE);
+    assert_eq!(page.get(0).unwrap().token_id, listed.get(0).unwrap());
+    let page = client.get_active_sales(&50, &10);
+    assert_eq!(page.len(), 10);
+    assert_eq!(page.get(0).unwrap().token_id, listed.get(50).unwrap());
+    assert_eq!(
+        client
+            .get_active_sales(&(crate::sales::MAX_ACTIVE_LISTINGS - 5), &50)
+            .len(),
+        5
+    );
+    assert_eq!(
+        client
+            .get_active_sales(&crate::sales::MAX_ACTIVE_LISTINGS, &50)
+            .len(),
+        0
+    );
+
+    // A withdrawn listing frees a slot
+    client.cancel_sale(&listed.get(0).unwrap());
+    client.list_token_for_sale(&extra, &500);
+}
+
+// ==================== Allowance Enumeration Tests ====================
+
+#[test]
+fn test_get_all_allowances_and_revoke_all() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(Contract, ());
+    let client = ContractClient::new(&env, &contract_id);
+    client.set_admin(&Address::generate(&env));
+
+    let owner = Address::generate(&env);
+    let token_id = BytesN::<32>::random(&env);
+    client.issue_token(&token_id, &owner, &(env.ledger().timestamp() + 86_400));
+
+    let first = Address::generate(&env);
+    let second = Address::generate(&env);
+    client.approve(&token_id, &first, &100, &None);
+    client.approve(
+        &token_id,
+        &second,
+        &200,
+        &Some(env.ledger().timestamp() + 60),
+    );
+    assert_eq!(client.get_all_allowances(&token_id).len(), 2);
+
+    // Expired allowances are not reported
+    env.ledger().with_mut(|l| l.timestamp += 61);
+    let active = client.get_all_allowances(&token_id);
+    assert_eq!(active.len(), 1);
+    assert_eq!(active.get(0).unwrap().spender, first);
+
+    assert_eq!(client.revoke_all_allowances(&token_id), 2);
+    assert_eq!(client.get_all_allowances(&token_id).len(), 0);
+    assert_eq!(
+        client.try_transfer_from(&token_id, &owner, &second, &first, &100),
+        Err(Ok(Error::Unauthorized.into()))
+    );
+}
+
+#[test]
+fn test_allowances_swept_on_transfer() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(Contract, ());
+    let client = ContractClient::new(&env, &contract_id);
+    client.set_admin(&Address::generate(&env));
+
+    let owner = Address::generate(&env);
+    let new_owner = Address::generate(&env);
+    let token_id = BytesN::<32>::random(&env);
+    client.issue_token(&token_id, &owner, &(env.ledger().timestamp() + 86_400));
+
+    let spender = Address::generate(&env);
+    let marketplace = Address::generate(&env);
+    client.approve(&token_id, &spender, &100, &None);
+    client.approve(&token_id, &marketplace, &100, &None);
+    client.transfer_from(&token_id, &owner, &new_owner, &marketplace, &50);
+
+    assert_eq!(client.get_all_allowances(&token_id).len(), 0);
+
+    // A direct transfer sweeps the new owner's approvals too, and the
+    // previous owner's approvals don't come back with the token
+    client.approve(&token_id, &spender, &100, &None);
+    client.transfer_token(&token_id, &owner);
+    assert_eq!(client.get_all_allowances(&token_id).len(), 0);
+    assert_eq!(client.get_allowance(&token_id, &owner, &spender), None);
+}
+
+// ==================== Operator Approval Tests ====================
+
+#[test]
+fn test_approved_operator_manages_all_owner_tokens() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin, usdc, tier_id) = setup_multi_asset_env(&env);
+
+    let owner = Address::generate(&env);
+    let operator = Address::generate(&env);
+    let expiry = env.ledger().timestamp() + 86_400;
+    let first = BytesN::<32>::random(&env);
+    let second = BytesN::<32>::random(&env);
+    client.issue_token(&first, &owner, &expiry);
+    client.issue_token(&second, &owner, &expiry);
+
+    assert_eq!(
+        client.try_operator_transfer_token(&operator, &first, &operator),
+        Err(Ok(Error::Unauthorized.into()))
+    );
+
+    client.set_approval_for_all(&owner, &operator, &true);
+    assert!(client.is_approved_for_all(&owner, &operator));
+
+    client.operator_renew_token(&operator, &first, &usdc, &tier_id, &BillingCycle::Monthly);
+    assert_eq!(
+        client.get_token(&first).expiry_date,
+        expiry + 30 * 24 * 60 * 60
+    );
+
+    client.operator_set_token_metadata(
+        &operator,
+        &second,
+        &String::from_str(&env, "Managed by operator"),
+        &Map::new(&env),
+    );
+    assert_eq!(
+        client.get_token_metadata(&second).description,
+        String::from_str(&env, "Managed by operator")
+    );
+
+    let buyer = Address::generate(&env);
+    client.operator_transfer_token(&operator, &second, &buyer);
+    assert_eq!(client.get_token(&second).user, buyer);
+}
+
+#[test]
+fn test_revoked_operator_loses_access() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(Contract, ());
+    let client = ContractClient::new(&env, &contract_id);
+    client.set_admin(&Address::generate(&env));
+
+    let owner = Address::generate(&env);
+    let operator = Address::generate(&env);
+    let token_id = BytesN::<32>::random(&env);
+    client.issue_token(&token_id, &owner, &(env.ledger().timestamp() + 86_400));
+
+    client.set_approval_for_all(&owner, &operator, &true);
+    client.set_approval_for_all(&owner, &operator, &false);
+    assert!(!client.is_approved_for_all(&owner, &operator));
+    assert_eq!(
+        client.try_operator_transfer_token(&operator, &token_id, &operator),
+        Err(Ok(Error::Unauthorized.into()))
+    );
+    assert_eq!(
+        client.try_set_approval_for_all(&owner, &owner, &true),
+        Err(Ok(Error::Unauthorized.into()))
+    );
+}
+
+// ==================== Metadata Authorization Tests ====================
+
+#[test]
+fn test_set_token_metadata_allows_admin_and_owner_only() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(Contract, ());
+    let client = ContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    client.set_admin(&admin);
+
+    let owner = Address::generate(&env);
+    let token_id = BytesN::<32>::random(&env);
+    client.issue_token(&token_id, &owner, &(env.ledger().timestamp() + 86_400));
+
+    client.set_token_metadata(
+        &owner,
+        &token_id,
+        &String::from_str(&env, "Set by owner"),
+        &Map::new(&env),
+    );
+    client.set_token_metadata(
+        &admin,
+        &token_id,
+        &String::from_str(&env, "Set by admin"),
+        &Map::new(&env),
+    );
+    let metadata = client.get_token_metadata(&token_id);
+    assert_eq!(metadata.description, String::from_str(&env, "Set by admin"));
+    assert_eq!(metadata.updated_by, admin);
+
+    let stranger = Address::generate(&env);
+    let result = client.try_set_token_metadata(
+        &stranger,
+        &token_id,
+        &String::from_str(&env, "Hijacked"),
+        &Map::new(&env),
+    );
+    assert_eq!(result, Err(Ok(Error::Unauthorized)));
+    assert_eq!(
+        client.get_token_metadata(&token_id).description,
+        String::from_str(&env, "Set by admin")
+    );
+}
+
+#[test]
+fn test_set_token_metadata_requires_caller_signature() {
+    let env = Env::default();
+    let contract_id = env.register(Contract, ());
+    let client = ContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let owner = Address::generate(&env);
+    let token_id = BytesN::<32>::random(&env);
+    env.mock_all_auths();
+    client.set_admin(&admin);
+    client.issue_token(&token_id, &owner, &(env.ledger().timestamp() + 86_400));
+    env.set_auths(&[]);
+
+    // Naming the owner as caller is not enough without the owner's auth
+    let result = client.try_set_token_metadata(
+        &owner,
+        &token_id,
+        &String::from_str(&env, "Unsigned"),
+        &Map::new(&env),
+    );
+    assert!(result.is_err());
+}
+
+// ==================== Metadata Schema Tests ====================
+
+fn schema_rule(
+    env: &Env,
+    key: &str,
+    kind: types::MetadataValueKind,
+    required: bool,
+    min: Option<i128>,
+    max: Option<i128>,
+) -> types::MetadataFieldRule {
+    types::MetadataFieldRule {
+        key: String::from_str(env, key),
+        kind,
+        required,
+        min,
+        max,
+    }
+}
+
+#[test]
+fn test_metadata_schema_validates_set_update_and_remove() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(Contract, ());
+    let client = ContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    client.set_admin(&admin);
+
+    let owner = Address::generate(&env);
+    let token_id = BytesN::<32>::random(&env);
+    client.issue_token(&token_id, &owner, &(env.ledger().timestamp() + 86_400));
+
+    let fields = vec![
+        &env,
+        schema_rule(
+            &env,
+            "tier",
+            types::MetadataValueKind::Text,
+            true,
+            Some(1),
+            Some(10),
+        ),
+        schema_rule(
+            &env,
+            "level",
+            types::MetadataValueKind::Number,
+            false,
+            Some(1),
+            Some(5),
+        ),
+    ];
+    assert_eq!(client.set_metadata_schema(&admin, &fields), 1);
+    assert_eq!(client.get_metadata_schema().unwrap().fields, fields);
+
+    let description = String::from_str(&env, "Member");
+    let tier = String::from_str(&env, "tier");
+    let level = String::from_str(&env, "level");
+    let set = |attributes: &Map<String, MetadataValue>| {
+        client.try_set_token_metadata(&owner, &token_id, &description, attributes)
+    };
+
+    // Missing required key, wrong variant, out of range
+    let mut attributes = Map::new(&env);
+    attributes.set(level.clone(), MetadataValue::Number(3));
+    assert_eq!(set(&attributes), Err(Ok(Error::MetadataValidationFailed)));
+    attributes.set(tier.clone(), MetadataValue::Number(1));
+    assert_eq!(set(&attributes), Err(Ok(Error::MetadataValidationFailed)));
+    attributes.set(
+        tier.clone(),
+        MetadataValue::Text(String::from_str(&env, "gold")),
+    );
+    attributes.set(level.clone(), MetadataValue::Number(9));
+    assert_eq!(set(&attributes), Err(Ok(Error::MetadataValidationFailed)));
+
+    attributes.set(level.clone(), MetadataValue::Number(2));
+    assert_eq!(set(&attributes), Ok(Ok(())));
+
+    let mut updates = Map::new(&env);
+    updates.set(level.clone(), MetadataValue::Boolean(true));
+    assert_eq!(
+        client.try_update_token_metadata(&token_id, &updates),
+        Err(Ok(Error::MetadataValidationFailed))
+    );
+    assert_eq!(
+        client.try_remove_metadata_attributes(&token_id, &vec![&env, tier]),
+        Err(Ok(Error::MetadataValidationFailed))
+    );
+    client.remove_metadata_attributes(&token_id, &vec![&env, level]);
+}
+
+#[test]
+fn test_metadata_schema_versions_and_admin_only() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(Contract, ());
+    let client = ContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    client.set_admin(&admin);
+
+    let first = vec![
+        &env,
+        schema_rule(
+            &env,
+            "vip",
+            types::MetadataValueKind::Boolean,
+            false,
+            None,
+            None,
+        ),
+    ];
+    let bad_range = vec![
+        &env,
+        schema_rule(
+            &env,
+            "age",
+            types::MetadataValueKind::Number,
+            false,
+            Some(5),
+            Some(1),
+        ),
+    ];
+    assert_eq!(
+        client.try_set_metadata_schema(&Address::generate(&env), &first),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(
+        client.try_set_metadata_schema(&admin, &bad_range),
+        Err(Ok(Error::MetadataValidationFailed))
+    );
+
+    assert_eq!(client.set_metadata_schema(&admin, &first), 1);
+    assert_eq!(client.set_metadata_schema(&admin, &Vec::new(&env)), 2);
+    assert_eq!(client.get_metadata_schema().unwrap().version, 2);
+    assert_eq!(
+        client.get_metadata_schema_version(&1).unwrap().fields,
+        first
+    );
+}
+
+// ==================== Multi-Attribute Query Tests ====================
+
+#[test]
+fn test_query_tokens_by_attributes_all_any_and_paging() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(Contract, ());
+    let client = ContractClient::new(&env, &contract_id);
+    client.set_admin(&Address::generate(&env));
+
+    let tier = String::from_str(&env, "tier");
+    let location = String::from_str(&env, "location");
+    let gold = MetadataValue::Text(String::from_str(&env, "gold"));
+    let lagos = MetadataValue::Text(String::from_str(&env, "lagos"));
+    let abuja = MetadataValue::Text(String::from_str(&env, "abuja"));
+
+    let owner = Address::generate(&env);
+    let expiry = env.ledger().timestamp() + 86_400;
+    let mut tokens = Vec::new(&env);
+    for place in [&lagos, &abuja, &lagos] {
+        let token_id = BytesN::<32>::random(&env);
+        client.issue_token(&token_id, &owner, &expiry);
+        let mut attributes = Map::new(&env);
+        attributes.set(tier.clone(), gold.clone());
+        attributes.set(location.clone(), place.clone());
+        client.set_token_metadata(
+            &owner,
+            &token_id,
+            &String::from_str(&env, "Member"),
+            &attributes,
+        );
+        tokens.push_back(token_id);
+    }
+    let silver = BytesN::<32>::random(&env);
+    client.issue_token(&silver, &owner, &expiry);
+    let mut attributes = Map::new(&env);
+    attributes.set(location.clone(), lagos.clone());
+    client.set_token_metadata(
+        &owner,
+        &silver,
+        &String::from_str(&env, "Member"),
+        &attributes,
+    );
+
+    let gold_in_lagos = vec![
+        &env,
+        (tier.clone(), gold.clone()),
+        (location.clone(), lagos.clone()),
+    ];
+    let all = client.query_tokens_by_attributes(&gold_in_lagos, &MatchMode::All, &0, &10);
+    assert_eq!(
+        all,
+        vec![&env, tokens.get(0).unwrap(), tokens.get(2).unwrap()]
+    );
+
+    let any = client.query_tokens_by_attributes(&gold_in_lagos, &MatchMode::Any, &0, &10);
+    assert_eq!(any.len(), 4);
+    assert_eq!(any.get(3).unwrap(), silver);
+
+    let page = client.query_tokens_by_attributes(&gold_in_lagos, &MatchMode::Any, &1, &2);
+    assert_eq!(
+        page,
+        vec![&env, tokens.get(1).unwrap(), tokens.get(2).unwrap()]
+    );
+    assert_eq!(
+        client
+            .query_tokens_by_attributes(&gold_in_lagos, &MatchMode::All, &5, &10)
+            .len(),
+        0
+    );
+
+    let mut too_many = Vec::new(&env);
+    for _ in 0..11 {
+        too_many.push_back((tier.clone(), gold.clone()));
+    }
+    assert_eq!(
+        client.try_query_tokens_by_attributes(&too_many, &MatchMode::Any, &0, &10),
+        Err(Ok(Error::MetadataTooManyAttributes))
+    );
+}
+
+// ==================== Number Range Query Tests ====================
+
+#[test]
+fn test_query_tokens_by_number_range() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(Contract, ());
+    let client = ContractClient::new(&env, &contract_id);
+    client.set_admin(&Address::generate(&env));
+
+    let level = String::from_str(&env, "level");
+    let owner = Address::generate(&env);
+    let expiry = env.ledger().timestamp() + 86_400;
+    let mut tokens = Vec::new(&env);
+    for value in [7i128, 3, 250, -5, 5, 1] {
+        let token_id = BytesN::<32>::random(&env);
+        client.issue_token(&token_id, &owner, &expiry);
+        let mut attributes = Map::new(&env);
+        attributes.set(level.clone(), MetadataValue::Number(value));
+        client.set_token_metadata(
+            &owner,
+            &token_id,
+            &String::from_str(&env, "Member"),
+            &attributes,
+        );
+        tokens.push_back(token_id);
+    }
+    let token = |i: u32| tokens.get(i).unwrap();
+
+    // Ordered by value, across buckets and negative values
+    assert_eq!(
+        client.query_tokens_by_number_range(&level, &3, &7, &0, &10),
+        vec![&env, token(1), token(4), token(0)]
+    );
+    assert_eq!(
+        client.query_tokens_by_number_range(&level, &-10, &300, &0, &10),
+        vec![
+            &env,
+            token(3),
+            token(5),
+            token(1),
+            token(4),
+            token(0),
+            token(2)
+        ]
+    );
+    assert_eq!(
+        client.query_tokens_by_number_range(&level, &-10, &300, &2, &2),
+        vec![&env, token(1), token(4)]
+    );
+    assert_eq!(
+        client
+            .query_tokens_by_number_range(&level, &8, &2, &0, &10)
+            .len(),
+        0
+    );
+
+    // Updates move the token within the index
+    let mut updates = Map::new(&env);
+    updates.set(level.clone(), MetadataValue::Number(400));
+    client.update_token_metadata(&token(0), &updates);
+    assert_eq!(
+        client.query_tokens_by_number_range(&level, &3, &7, &0, &10),
+        vec![&env, token(1), token(4)]
+    );
+    assert_eq!(
+        client.query_tokens_by_number_range(&level, &300, &500, &0, &10),
+        vec![&env, token(0)]
+    );
+}
+
+// ==================== Metadata History Pruning Tests ====================
+
+fn set_member_metadata(env: &Env, client: &ContractClient<'_>, owner: &Address, id: &BytesN<32>) {
+    client.set_token_metadata(owner, id, &String::from_str(env, "Member"), &Map::new(env));
+}
+
+#[test]
+fn test_prune_metadata_history_keeps_auditable_checksum() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(Contract, ());
+    let client = ContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    client.set_admin(&admin);
+
+    let owner = Address::generate(&env);
+    let token_id = BytesN::<32>::random(&env);
+    client.issue_token(&token_id, &owner, &(env.ledger().timestamp() + 86_400));
+    for _ in 0..4 {
+        set_member_metadata(&env, &client, &owner, &token_id);
+    }
+    let history = client.get_metadata_history(&token_id);
+    assert_eq!(history.len(), 4);
+
+    assert_eq!(
+        client.try_prune_metadata_history(&owner, &token_id, &2),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(client.prune_metadata_history(&admin, &token_id, &2), 2);
+    assert_eq!(client.get_metadata_history(&token_id), history.slice(2..4));
+
+    // The digest is a hash chain over the pruned entries
+    let mut checksum = BytesN::from_array(&env, &[0u8; 32]);
+    for entry in history.slice(0..2).iter() {
+        let mut bytes = soroban_sdk::Bytes::from_array(&env, &checksum.to_array());
+        bytes.append(&soroban_sdk::xdr::ToXdr::to_xdr(entry, &env));
+        checksum = env.crypto().sha256(&bytes).into();
+    }
+    let digest = client.get_pruned_metadata_history(&token_id).unwrap();
+    assert_eq!(digest.checksum, checksum);
+    assert_eq!(digest.pruned_count, 2);
+    assert_eq!(digest.last_pruned_version, 2);
+}
+
+#[test]
+fn test_metadata_history_limit_acts_as_ring_buffer() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(Contract, ());
+    let client = ContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    client.set_admin(&admin);
+    assert_eq!(client.get_metadata_history_limit(), 50);
+    assert_eq!(
+        client.try_set_metadata_history_limit(&admin, &0),
+        Err(Ok(Error::MetadataValidationFailed))
+    );
+    client.set_metadata_history_limit(&admin, &3);
+
+    let owner = Address::generate(&env);
+    let token_id = BytesN::<32>::random(&env);
+    client.issue_token(&token_id, &owner, &(env.ledger().timestamp() + 86_400));
+    for _ in 0..5 {
+        set_member_metadata(&env, &client, &owner, &token_id);
+    }
+
+    let history = client.get_metadata_history(&token_id);
+    assert_eq!(history.len(), 3);
+    assert_eq!(history.get(0).unwrap().version, 3);
+    assert_eq!(history.get(2).unwrap().version, 5);
+    let digest = client.get_pruned_metadata_history(&token_id).unwrap();
+    assert_eq!(digest.pruned_count, 2);
+    assert_eq!(digest.last_pruned_version, 2);
+}
+
+// ==================== Token URI Tests ====================
+
+#[test]
+fn test_set_token_uri_and_verify_content_hash() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(Contract, ());
+    let client = ContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    client.set_admin(&admin);
+
+    let owner = Address::generate(&env);
+    let token_id = BytesN::<32>::random(&env);
+    client.issue_token(&token_id, &owner, &(env.ledger().timestamp() + 86_400));
+
+    let uri = String::from_str(&env, "ipfs://bafy/card.json");
+    let hash: BytesN<32> = env
+        .crypto()
+        .sha256(&soroban_sdk::Bytes::from_slice(&env, b"card"))
+        .into();
+
+    // Content can only be bound once metadata exists
+    assert_eq!(
+        client.try_set_token_uri(&owner, &token_id, &uri, &hash),
+        Err(Ok(Error::MetadataNotFound))
+    );
+    set_member_metadata(&env, &client, &owner, &token_id);
+    assert!(!client.verify_content_hash(&token_id, &hash));
+
+    let stranger = Address::generate(&env);
+    assert_eq!(
+        client.try_set_token_uri(&stranger, &token_id, &uri, &hash),
+        Err(Ok(Error::Unauthorized))
+    );
+
+    client.set_token_uri(&owner, &token_id, &uri, &hash);
+    let metadata = client.get_token_metadata(&token_id);
+    assert_eq!(metadata.token_uri, uri);
+    assert_eq!(metadata.content_hash, Some(hash.clone()));
+    assert_eq!(metadata.version, 1);
+    assert!(client.verify_content_hash(&token_id, &hash));
+    assert!(!client.verify_content_hash(&token_id, &BytesN::from_array(&env, &[0u8; 32])));
+
+    // Rewriting attributes keeps the bound content
+    set_member_metadata(&env, &client, &owner, &token_id);
+    let metadata = client.get_token_metadata(&token_id);
+    assert_eq!(metadata.version, 2);
+    assert_eq!(metadata.token_uri, uri);
+    assert!(client.verify_content_hash(&token_id, &hash));
+}
+
+#[test]
+fn test_set_token_uri_rejects_oversized_uri() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(Contract, ());
+    let client = ContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    client.set_admin(&admin);
+
+    let owner = Address::generate(&env);
+    let token_id = BytesN::<32>::random(&env);
+    client.issue_token(&token_id, &owner, &(env.ledger().timestamp() + 86_400));
+    set_member_metadata(&env, &client, &owner, &token_id);
+
+    let long_uri = String::from_bytes(&env, &[b'a'; 257]);
+    let hash = BytesN::from_array(&env, &[7u8; 32]);
+    assert_eq!(
+        client.try_set_token_uri(&admin, &token_id, &long_uri, &hash),
+        Err(Ok(Error::MetadataValidationFailed))
+    );
+}
+
+// ==================== Fraction Token Interface Tests ====================
+
+#[test]
+fn test_fraction_token_interface_balance_and_metadata() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(Contract, ());
+    let client = ContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    client.set_admin(&admin);
+
+    let owner = Address::generate(&env);
+    let other = Address::generate(&env);
+    let token_id = BytesN::<32>::random(&env);
+    client.issue_token(&token_id, &owner, &(env.ledger().timestamp() + 86_400));
+
+    assert_eq!(
+        client.try_fraction_decimals(&token_id),
+        Err(Ok(Error::TokenNotFound))
+    );
+
+    client.fractionalize_token(&token_id, &1000, &100);
+    assert_eq!(client.fraction_decimals(&token_id), 0);
+    assert_eq!(
+        client.fraction_symbol(&token_id),
+        String::from_str(&env, "MHFRAC")
+    );
+    assert!(!client.fraction_name(&token_id).is_empty());
+
+    client.fraction_transfer(&token_id, &owner, &other, &300);
+    assert_eq!(client.fraction_balance(&token_id, &owner), 700);
+    assert_eq!(client.fraction_balance(&token_id, &other), 300);
+    assert_eq!(
+        client.fraction_balance(&token_id, &Address::generate(&env)),
+        0
+    );
+}
+
+#[test]
+fn test_fraction_approve_and_transfer_from() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(Contract, ());
+    let client = ContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    client.set_admin(&admin);
+
+    let owner = Address::generate(&env);
+    let spender = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    let token_id = BytesN::<32>::random(&env);
+    client.issue_token(&token_id, &owner, &(env.ledger().timestamp() + 86_400));
+    client.fractionalize_token(&token_id, &1000, &100);
+
+    let expiration = env.ledger().sequence() + 100;
+    client.fraction_approve(&token_id, &owner, &spender, &300, &expiration);
+    assert_eq!(client.fraction_allowance(&token_id, &owner, &spender), 300);
+
+    assert_eq!(
+        client.try_fraction_transfer_from(&token_id, &spender, &owner, &buyer, &400),
+        Err(Ok(Error::InsufficientBalance.into()))
+    );
+    client.fraction_transfer_from(&token_id, &spender, &owner, &buyer, &200);
+    assert_eq!(client.fraction_allowance(&token_id, &owner, &spender), 100);
+    assert_eq!(client.fraction_balance(&token_id, &buyer), 200);
+
+    // Allowances lapse after their expiration ledger
+    env.ledger()
+        .with_mut(|li| li.sequence_number = expiration + 1);
+    assert_eq!(client.fraction_allowance(&token_id, &owner, &spender), 0);
+    assert_eq!(
+        client.try_fraction_approve(&token_id, &owner, &spender, &100, &expiration),
+        Err(Ok(Error::InvalidExpiryDate))
+    );
+
+    // Zero revokes, and recombination clears all allowances
+    client.fraction_approve(&token_id, &owner, &spender, &500, &(expiration + 100));
+    client.fraction_approve(&token_id, &owner, &spender, &0, &0);
+    assert_eq!(client.fraction_allowance(&token_id, &owner, &spender), 0);
+
+    client.fraction_approve(&token_id, &owner, &spender, &500, &(expiration + 100));
+    client.fraction_transfer(&token_id, &buyer, &owner, &200);
+    client.recombine_fractions(&token_id, &owner);
+    client.fractionalize_token(&token_id, &1000, &100);
+    assert_eq!(client.fraction_allowance(&token_id, &owner, &spender), 0);
+}
+
+// ==================== Stake Slashing Tests ====================
+
+#[test]
+fn test_slash_stake_moves_funds_to_treasury_and_records_history() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, admin, sac) = setup_staking_env(&env);
+    let staker = Address::generate(&env);
+    sac.mint(&staker, &10_000);
+    client.stake_tokens(&staker, &String::from_str(&env, "bronze"), &5_000);
+
+    let reason = String::from_str(&env, "fraudulent attendance");
+    assert_eq!(client.slash_stake(&admin, &staker, &1_000, &reason), 500);
+    assert_eq!(client.get_stake_info(&staker).unwrap().amount, 4_500);
+    assert_eq!(client.get_lifetime_revenue().penalties, 500);
+
+    let history = client.get_slash_history(&staker);
+    assert_eq!(history.len(), 1);
+    let record = history.get(0).unwrap();
+    assert_eq!(record.bps, 1_000);
+    assert_eq!(record.amount, 500);
+    assert_eq!(record.reason, reason);
+    assert_eq!(record.slashed_by, admin);
+}
+
+#[test]
+fn test_slash_stake_enforces_limits() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, admin, sac) = setup_staking_env(&env);
+    let staker = Address::generate(&env);
+    sac.mint(&staker, &10_000);
+    client.stake_tokens(&staker, &String::from_str(&env, "bronze"), &5_000);
+    let reason = String::from_str(&env, "violation");
+
+    assert_eq!(
+        client.try_slash_stake(&Address::generate(&env), &staker, &1_000, &reason),
+        Err(Ok(Error::Unauthorized.into()))
+    );
+    assert_eq!(
+        client.try_slash_stake(&admin, &staker, &2_001, &reason),
+        Err(Ok(StakingError::SlashExceedsLimit.into()))
+    );
+    assert_eq!(
+        client.try_slash_stake(&admin, &staker, &0, &reason),
+        Err(Ok(StakingError::SlashExceedsLimit.into()))
+    );
+    assert_eq!(
+        client.try_slash_stake(&admin, &staker, &1_000, &String::from_str(&env, "")),
+        Err(Ok(StakingError::InvalidSlashReason.into()))
+    );
+    assert_eq!(
+        client.try_slash_stake(&admin, &Address::generate(&env), &1_000, &reason),
+        Err(Ok(StakingError::StakeNotFound.into()))
+    );
+    assert!(client.get_slash_history(&staker).is_empty());
+}
+
+// ==================== Unstake Cooldown Tests ====================
+
+fn enable_unstake_cooldown(env: &Env, client: &ContractClient<'_>, admin: &Address) -> u64 {
+    let cooldown = 7 * 86_400;
+    let mut config = client.get_staking_config();
+    config.unstake_cooldown = cooldown;
+    client.set_staking_config(admin, &config);
+
+    // Fund the reward pool held by the contract
+    soroban_sdk::token::StellarAssetClient::new(env, &config.reward_pool)
+        .mint(&client.address, &1_000_000_000);
+    cooldown
+}
+
+/// Rewards for the bronze tier (5 % annual, 1x) over `elapsed` seconds.
+fn bronze_rewards(amount: i128, elapsed: i128) -> i128 {
+    amount * 500 * elapsed * 10_000 / (10_000 * 365 * 24 * 60 * 60) / 10_000
+}
+
+#[test]
+fn test_unstake_cooldown_freezes_rewards_until_withdrawal() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, admin, sac) = setup_staking_env(&env);
+    let cooldown = enable_unstake_cooldown(&env, &client, &admin);
+    let staker = Address::generate(&env);
+    sac.mint(&staker, &1_000_000_000);
+    client.stake_tokens(&staker, &String::from_str(&env, "bronze"), &1_000_000_000);
+
+    env.ledger().with_mut(|li| li.timestamp += 30 * 86_400);
+    assert_eq!(
+        client.try_unstake_tokens(&staker),
+        Err(Ok(StakingError::CooldownRequired.into()))
+    );
+
+    let request = client.request_unstake(&staker);
+    assert_eq!(request.available_at, request.requested_at + cooldown);
+    assert_eq!(client.get_unstake_request(&staker), Some(request));
+    assert_eq!(
+        client.try_request_unstake(&staker),
+        Err(Ok(StakingError::UnstakeAlreadyRequested.into()))
+    );
+
+    env.ledger().with_mut(|li| li.timestamp += cooldown - 1);
+    assert_eq!(
+        client.try_withdraw_unstaked(&staker),
+        Err(Ok(StakingError::CooldownActive.into()))
+    );
+
+    env.ledger().with_mut(|li| li.timestamp += 1);
+    client.withdraw_unstaked(&staker);
+
+    // Rewards stopped accruing when the cooldown started
+    let reward_pool = client.get_staking_config().reward_pool;
+    let rewards = soroban_sdk::token::Client::new(&env, &reward_pool).balance(&staker);
+    assert_eq!(rewards, bronze_rewards(1_000_000_000, 30 * 86_400));
+    assert_eq!(sac.balance(&staker), 1_000_000_000);
+    assert!(client.get_stake_info(&staker).is_none());
+    assert!(client.get_unstake_request(&staker).is_none());
+}
+
+#[test]
+fn test_cancel_unstake_request_excludes_cooldown_from_rewards() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, admin, sac) = setup_staking_env(&env);
+    enable_unstake_cooldown(&env, &client, &admin);
+    let staker = Address::generate(&env);
+    sac.mint(&staker, &1_000_000_000);
+    client.stake_tokens(&staker, &String::from_str(&env, "bronze"), &1_000_000_000);
+
+    assert_eq!(
+        client.try_request_unstake(&staker),
+        Err(Ok(StakingError::StillLocked.into()))
+    );
+    assert_eq!(
+        client.try_cancel_unstake_request(&staker),
+        Err(Ok(StakingError::UnstakeRequestNotFound.into()))
+    );
+
+    env.ledger().with_mut(|li| li.timestamp += 10 * 86_400);
+    client.request_unstake(&staker);
+    env.ledger().with_mut(|li| li.timestamp += 3 * 86_400);
+    client.cancel_unstake_request(&staker);
+    assert!(client.get_unstake_request(&staker).is_none());
+    assert_eq!(
+        client.get_stake_info(&staker).unwrap().paused_secs,
+        3 * 86_400
+    );
+
+    // Accrual resumes; the cancelled cooldown is never rewarded
+    env.ledger().with_mut(|li| li.timestamp += 5 * 86_400);
+    let request = client.request_unstake(&staker);
+    env.ledger()
+        .with_mut(|li| li.timestamp = request.available_at);
+    client.withdraw_unstaked(&staker);
+
+    let reward_pool = client.get_staking_config().reward_pool;
+    let rewards = soroban_sdk::token::Client::new(&env, &reward_pool).balance(&staker);
+    assert_eq!(rewards, bronze_rewards(1_000_000_000, 15 * 86_400));
+}
+
+// ==================== Entry Access Tests ====================
+
+#[test]
+fn test_verify_entry_checks_token_state() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(Contract, ());
+    let client = ContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    client.set_admin(&admin);
+
+    let user = Address::generate(&env);
+    let decision = client.verify_entry(&user);
+    assert!(!decision.allowed);
+    assert_eq!(decision.status, EntryStatus::NoMembership);
+    assert_eq!(decision.token_id, None);
+
+    let token_id = BytesN::<32>::random(&env);
+    client.issue_token(&token_id, &user, &(env.ledger().timestamp() + 86_400));
+    let decision = client.verify_entry(&user);
+    assert!(decision.allowed);
+    assert_eq!(decision.status, EntryStatus::Granted);
+    assert_eq!(decision.token_id, Some(token_id.clone()));
+
+    client.pause_token_operations(&admin, &token_id, &None, &None, &None);
+    assert_eq!(
+        client.verify_entry(&user).status,
+        EntryStatus::MembershipPaused
+    );
+    client.unpause_token_operations(&admin, &token_id);
+
+    // Entry follows the token to its new owner
+    let buyer = Address::generate(&env);
+    client.transfer_token(&token_id, &buyer);
+    assert_eq!(client.verify_entry(&user).status, EntryStatus::NoMembership);
+    assert!(client.verify_entry(&buyer).allowed);
+
+    env.ledger().with_mut(|li| li.timestamp += 86_401);
+    assert_eq!(
+        client.verify_entry(&buyer).status,
+        EntryStatus::MembershipExpired
+    );
+}
+
+#[test]
+fn test_verify_entry_applies_subscription_and_stake_policy() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, admin, sac) = setup_staking_env(&env);
+    let payment_token = Address::generate(&env);
+    client.set_usdc_contract(&admin, &payment_token);
+
+    let user = Address::generate(&env);
+    client.issue_token(
+        &BytesN::<32>::random(&env),
+        &user,
+        &(env.ledger().timestamp() + 86_400),
+    );
+
+    let policy = EntryPolicy {
+        require_subscription: true,
+        grace_entry_secs: 0,
+        min_stake: 5_000,
+    };
+    assert_eq!(
+        client.try_set_entry_policy(&Address::generate(&env), &policy),
+        Err(Ok(Error::Unauthorized))
+    );
+    client.set_entry_policy(&admin, &policy);
+    assert_eq!(client.get_entry_policy(), policy);
+
+    assert_eq!(
+        client.verify_entry(&user).status,
+        EntryStatus::NoActiveSubscription
+    );
+
+    client.create_subscription(
+        &String::from_str(&env, "sub_entry"),
+        &user,
+        &payment_token,
+        &100_000,
+        &2_592_000,
+    );
+    assert_eq!(
+        client.verify_entry(&user).status,
+        EntryStatus::InsufficientStake
+    );
+
+    sac.mint(&user, &10_000);
+    client.stake_tokens(&user, &String::from_str(&env, "bronze"), &5_000);
+    assert!(client.verify_entry(&user).allowed);
+}
+
+// ==================== Entry Pass Tests ====================
+
+#[test]
+fn test_entry_pass_redeemed_once_by_kiosk() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(Contract, ());
+    let client = ContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    client.set_admin(&admin);
+    let kiosk = Address::generate(&env);
+    client.add_operator(&admin, &kiosk);
+
+    let member = Address::generate(&env);
+    let token_id = BytesN::<32>::random(&env);
+    client.issue_token(&token_id, &member, &(env.ledger().timestamp() + 86_400));
+
+    let pass_id = client.create_entry_pass(&token_id, &3_600);
+    let pass = client.get_entry_pass(&pass_id).unwrap();
+    assert_eq!(pass.token_id, token_id);
+    assert_eq!(pass.issued_by, member);
+    assert_eq!(pass.redeemed_at, None);
+
+    // Each pass gets a distinct ID
+    assert_ne!(client.create_entry_pass(&token_id, &3_600), pass_id);
+
+    assert_eq!(
+        client.try_redeem_entry_pass(&Address::generate(&env), &pass_id),
+        Err(Ok(Error::Unauthorized.into()))
+    );
+    client.redeem_entry_pass(&kiosk, &pass_id);
+
+    let pass = client.get_entry_pass(&pass_id).unwrap();
+    assert_eq!(pass.redeemed_by, Some(kiosk.clone()));
+    let log = client.get_attendance_log(&pass_id).unwrap();
+    assert_eq!(log.user_id, member);
+    assert_eq!(log.action, AttendanceAction::ClockIn);
+
+    assert_eq!(
+        client.try_redeem_entry_pass(&kiosk, &pass_id),
+        Err(Ok(EntryError::PassAlreadyRedeemed.into()))
+    );
+}
+
+#[test]
+fn test_entry_pass_validity_and_membership_checks() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(Contract, ());
+    let client = ContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    client.set_admin(&admin);
+    let kiosk = Address::generate(&env);
+    client.add_operator(&admin, &kiosk);
+
+    let member = Address::generate(&env);
+    let token_id = BytesN::<32>::random(&env);
+    client.issue_token(&token_id, &member, &(env.ledger().timestamp() + 86_400));
+
+    assert_eq!(
+        client.try_create_entry_pass(&token_id, &0),
+        Err(Ok(EntryError::InvalidPassValidity.into()))
+    );
+    assert_eq!(
+        client.try_create_entry_pass(&token_id, &(8 * 86_400)),
+        Err(Ok(EntryError::InvalidPassValidity.into()))
+    );
+    assert_eq!(
+        client.try_redeem_entry_pass(&kiosk, &BytesN::from_array(&env, &[1u8; 32])),
+        Err(Ok(EntryError::PassNotFound.into()))
+    );
+
+    let short_pass = client.create_entry_pass(&token_id, &60);
+    let long_pass = client.create_entry_pass(&token_id, &3_600);
+    env.ledger().with_mut(|li| li.timestamp += 61);
+    assert_eq!(
+        client.try_redeem_entry_pass(&kiosk, &short_pass),
+        Err(Ok(EntryError::PassExpired.into()))
+    );
+
+    // A pass cannot outlive the membership's eligibility
+    client.pause_token_operations(&admin, &token_id, &None, &None, &None);
+    assert_eq!(
+        client.try_redeem_entry_pass(&kiosk, &long_pass),
+        Err(Ok(EntryError::EntryDenied.into()))
+    );
+    assert_eq!(
+        client.try_create_entry_pass(&token_id, &60),
+        Err(Ok(EntryError::EntryDenied.into()))
+    );
+}
+
+// ==================== Guest Pass Tests ====================
+
+#[test]
+fn test_invite_guest_enforces_monthly_quota_and_resets() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, admin, usdc, tier_id) = setup_multi_asset_env(&env);
+    let host = Address::generate(&env);
+    let sub_id = String::from_str(&env, "sub_guest");
+    client.create_subscription_with_tier(
+        &sub_id,
+        &host,
+        &usdc,
+        &tier_id,
+        &BillingCycle::Annual,
+        &None,
+    );
+
+    assert_eq!(
+        client.try_set_tier_guest_quota(&admin, &String::from_str(&env, "missing"), &2),
+        Err(Ok(Error::TierNotFound))
+    );
+    client.set_tier_guest_quota(&admin, &tier_id, &2);
+    assert_eq!(client.get_tier_guest_quota(&tier_id), 2);
+
+    let valid_until = env.ledger().timestamp() + 86_400;
+    let guest_a = Address::generate(&env);
+    client.invite_guest(&sub_id, &guest_a, &valid_until);
+    assert_eq!(
+        client.try_invite_guest(&sub_id, &guest_a, &valid_until),
+        Err(Ok(GuestError::AlreadyInvited.into()))
+    );
+    client.invite_guest(&sub_id, &Address::generate(&env), &valid_until);
+    assert_eq!(
+        client.try_invite_guest(&sub_id, &Address::generate(&env), &valid_until),
+        Err(Ok(GuestError::QuotaExceeded.into()))
+    );
+
+    let usage = client.get_guest_usage(&sub_id);
+    assert_eq!(usage.used, 2);
+    assert_eq!(usage.quota, 2);
+
+    // The quota resets when the next 30-day period starts
+    env.ledger().with_mut(|li| li.timestamp = usage.period_end);
+    let usage = client.get_guest_usage(&sub_id);
+    assert_eq!(usage.used, 0);
+    assert_eq!(usage.period_start, env.ledger().timestamp());
+    client.invite_guest(&sub_id, &guest_a, &(env.ledger().timestamp() + 3_600));
+    assert_eq!(client.get_guest_usage(&sub_id).used, 1);
+}
+
+#[test]
+fn test_guest_attendance_is_attributed_to_host() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, admin, usdc, tier_id) = setup_multi_asset_env(&env);
+    let host = Address::generate(&env);
+    let sub_id = String::from_str(&env, "sub_guest");
+    client.create_subscription_with_tier(
+        &sub_id,
+        &host,
+        &usdc,
+        &tier_id,
+        &BillingCycle::Monthly,
+        &None,
+    );
+    client.set_tier_guest_quota(&admin, &tier_id, &1);
+
+    let guest = Address::generate(&env);
+    let log_id = BytesN::<32>::random(&env);
+    assert_eq!(
+        client.try_log_guest_attendance(&log_id, &sub_id, &guest, &AttendanceAction::ClockIn),
+        Err(Ok(GuestError::InviteNotFound.into()))
+    );
+
+    assert_eq!(
+        client.try_invite_guest(&sub_id, &guest, &(env.ledger().timestamp() + 60 * 86_400)),
+        Err(Ok(GuestError::InvalidInviteWindow.into()))
+    );
+    client.set_attendance_rate_limit(
+        &admin,
+        &AttendanceRateLimit {
+            max_logs_per_hour: 1,
+            max_detail_bytes_per_day: 0,
+        },
+    );
+    let valid_until = env.ledger().timestamp() + 3_600;
+    client.invite_guest(&sub_id, &guest, &valid_until);
+    client.log_guest_attendance(&log_id, &sub_id, &guest, &AttendanceAction::ClockIn);
+
+    let log = client.get_attendance_log(&log_id).unwrap();
+    assert_eq!(log.user_id, host);
+    assert_eq!(
+        log.details.get(String::from_str(&env, "guest")),
+        Some(guest.to_string())
+    );
+    assert_eq!(client.get_guest_invite(&sub_id, &guest).unwrap().visits, 1);
+    // Guest visits earn the host no streak, but count toward the rate limit
+    assert_eq!(client.get_streak(&host), 0);
+    assert_eq!(
+        client.try_log_guest_attendance(
+            &BytesN::<32>::random(&env),
+            &sub_id,
+            &guest,
+            &AttendanceAction::ClockOut
+        ),
+        Err(Ok(AttendanceError::RateLimited.into()))
+    );
+
+    env.ledger().with_mut(|li| li.timestamp = valid_until + 1);
+    assert_eq!(
+        client.try_log_guest_attendance(
+            &BytesN::<32>::random(&env),
+            &sub_id,
+            &guest,
+            &AttendanceAction::ClockOut
+        ),
+        Err(Ok(GuestError::InviteExpired.into()))
+    );
+}
+
+// ==================== Tier Cohort Analytics Tests ====================
+
+#[test]
+fn test_tier_analytics_tracks_churn_and_cohorts() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, _admin, usdc, tier_id) = setup_multi_asset_env(&env);
+    let start = env.ledger().timestamp();
+    let mut ids = Vec::new(&env);
+    for name in ["sub_a", "sub_b", "sub_c", "sub_d"] {
+        let id = String::from_str(&env, name);
+        client.create_subscription_with_tier(
+            &id,
+            &Address::generate(&env),
+            &usdc,
+            &tier_id,
+            &BillingCycle::Monthly,
+            &None,
+        );
+        ids.push_back(id);
+    }
+    let analytics = client.get_tier_analytics(&tier_id);
+    assert_eq!(analytics.active_subscribers, 4);
+    assert_eq!(analytics.churn_rate, 0);
+
+    client.cancel_subscription(&ids.get(0).unwrap(), &None);
+
+    // Expiry is only counted once someone marks the subscription expired
+    assert!(!client.expire_subscription(&ids.get(1).unwrap()));
+    env.ledger().with_mut(|li| li.timestamp += 31 * 86_400);
+    assert!(client.expire_subscription(&ids.get(1).unwrap()));
+    assert!(!client.expire_subscription(&ids.get(1).unwrap()));
+    assert_eq!(
+        client.get_subscription(&ids.get(1).unwrap()).status,
+        MembershipStatus::Expired
+    );
+
+    let analytics = client.get_tier_analytics(&tier_id);
+    assert_eq!(analytics.active_subscribers, 2);
+    assert_eq!(analytics.churn_rate, 50);
+
+    let range = DateRange {
+        start_time: start,
+        end_time: env.ledger().timestamp(),
+    };
+    let cohorts = client.get_tier_cohorts(&tier_id, &range);
+    assert_eq!(cohorts.len(), 1);
+    assert_eq!(cohorts.get(0).unwrap().created, 4);
+    assert_eq!(cohorts.get(0).unwrap().retained, 2);
+
+    // Renewing a lapsed subscription brings it back into the cohort, and
+    // renewals only add revenue
+    client.renew_subscription(&ids.get(1).unwrap(), &usdc, &100_000, &2_592_000);
+    let analytics = client.get_tier_analytics(&tier_id);
+    assert_eq!(analytics.active_subscribers, 3);
+    assert_eq!(analytics.total_revenue, 500_000);
+    assert_eq!(
+        client
+            .get_tier_cohorts(&tier_id, &range)
+            .get(0)
+            .unwrap()
+            .retained,
+        3
+    );
+
+    // Churn falls out of the rolling window
+    env.ledger().with_mut(|li| li.timestamp += 90 * 86_400);
+    assert_eq!(client.get_tier_analytics(&tier_id).churn_rate, 0);
+
+    let all = client.get_all_tier_analytics();
+    assert_eq!(all.len(), 1);
+    assert_eq!(all.get(0).unwrap().tier_id, tier_id);
+}
+
+#[test]
+fn test_tier_revenue_over_time() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, _admin, usdc, tier_id) = setup_multi_asset_env(&env);
+    let start = env.ledger().timestamp();
+    let id = String::from_str(&env, "sub_rev");
+    client.create_subscription_with_tier(
+        &id,
+        &Address::generate(&env),
+        &usdc,
+        &tier_id,
+        &BillingCycle::Monthly,
+        &None,
+    );
+    env.ledger().with_mut(|li| li.timestamp += 60 * 86_400);
+    client.renew_subscription(&id, &usdc, &100_000, &2_592_000);
+
+    let range = DateRange {
+        start_time: start,
+        end_time: env.ledger().timestamp(),
+    };
+    let points = client.get_tier_revenue_over_time(&tier_id, &range);
+    assert_eq!(points.len(), 3);
+    assert_eq!(points.get(0).unwrap().revenue, 100_000);
+    assert_eq!(points.get(1).unwrap().revenue, 0);
+    assert_eq!(points.get(2).unwrap().revenue, 100_000);
+
+    assert_eq!(
+        client.try_get_tier_revenue_over_time(
+            &tier_id,
+            &DateRange {
+                start_time: 0,
+                end_time: 25 * 30 * 86_400,
+            }
+        ),
+        Err(Ok(Error::InvalidDateRange))
+    );
+}
+
+// ==================== Contract Stats Tests ====================
+
+#[test]
+fn test_contract_stats_track_tokens_and_subscriptions() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, _admin, usdc, tier_id) = setup_multi_asset_env(&env);
+    let now = env.ledger().timestamp();
+    let short_token = BytesN::<32>::random(&env);
+    let long_token = BytesN::<32>::random(&env);
+    client.issue_token(&short_token, &Address::generate(&env), &(now + 86_400));
+    client.issue_token(&long_token, &Address::generate(&env), &(now + 90 * 86_400));
+    client.fractionalize_token(&long_token, &1_000, &10);
+
+    let mut ids = Vec::new(&env);
+    for name in ["stat_a", "stat_b", "stat_c"] {
+        let id = String::from_str(&env, name);
+        client.create_subscription_with_tier(
+            &id,
+            &Address::generate(&env),
+            &usdc,
+            &tier_id,
+            &BillingCycle::Monthly,
+            &None,
+        );
+        ids.push_back(id);
+    }
+    client.cancel_subscription(&ids.get(1).unwrap(), &None);
+
+    env.ledger().with_mut(|li| li.timestamp += 2 * 86_400);
+    client.pause_subscription(&ids.get(0).unwrap(), &None);
+    client.check_and_apply_grace_period(&short_token);
+
+    let stats = client.get_contract_stats();
+    assert_eq!(stats.tokens_issued, 2);
+    assert_eq!(stats.tokens_active, 1);
+    assert_eq!(stats.tokens_expired, 1);
+    assert_eq!(stats.fractionalized_tokens, 1);
+    assert_eq!(stats.total_subscriptions, 3);
+    assert_eq!(stats.subscriptions_active, 1);
+    assert_eq!(stats.subscriptions_paused, 1);
+    assert_eq!(stats.subscriptions_inactive, 1);
+    assert_eq!(stats.subscriptions_expired, 0);
+    assert_eq!(stats.lifetime_revenue, client.get_lifetime_revenue().total);
+    assert!(stats.lifetime_revenue > 0);
+}
+
+#[test]
+fn test_contract_stats_track_total_staked() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, admin, sac) = setup_staking_env(&env);
+    let bronze = String::from_str(&env, "bronze");
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    sac.mint(&alice, &10_000);
+    sac.mint(&bob, &10_000);
+
+    client.stake_tokens(&alice, &bronze, &5_000);
+    client.stake_tokens(&alice, &bronze, &1_000);
+    client.stake_tokens(&bob, &bronze, &2_000);
+    assert_eq!(client.get_contract_stats().total_staked, 8_000);
+
+    let reason = String::from_str(&env, "policy violation");
+    client.slash_stake(&admin, &alice, &1_000, &reason);
+    assert_eq!(client.get_contract_stats().total_staked, 7_400);
+
+    client.emergency_unstake(&bob);
+    assert_eq!(client.get_contract_stats().total_staked, 5_400);
+}
+
+// ==================== Scheduled Downgrade Tests ====================
+
+fn create_pro_tier(env: &Env, client: &ContractClient, admin: &Address) -> String {
+    let tier_id = String::from_str(env, "tier_pro");
+    client.create_tier(
+        admin,
+        &CreateTierParams {
+            id: tier_id.clone(),
+            name: String::from_str(env, "Pro"),
+            level: common_types::TierLevel::Pro,
+            price: 300_000i128,
+            annual_price: 3_000_000i128,
+            features: soroban_sdk::vec![env, common_types::TierFeature::AdvancedAnalytics],
+            max_users: 100,
+            max_storage: 10_000_000,
+        },
+    );
+    tier_id
+}
+
+#[test]
+fn test_scheduled_downgrade_applies_on_renewal() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, admin, usdc, basic_id) = setup_multi_asset_env(&env);
+    let pro_id = create_pro_tier(&env, &client, &admin);
+    let user = Address::generate(&env);
+    let sub_id = String::from_str(&env, "sub_downgrade");
+    client.create_subscription_with_tier(
+        &sub_id,
+        &user,
+        &usdc,
+        &pro_id,
+        &BillingCycle::Monthly,
+        &None,
+    );
+
+    let change_id = client.schedule_tier_downgrade(&user, &sub_id, &basic_id);
+    let scheduled = client.get_scheduled_tier_change(&sub_id).unwrap();
+    assert_eq!(scheduled.to_tier, basic_id);
+    assert_eq!(scheduled.status, common_types::TierChangeStatus::Approved);
+    assert_eq!(scheduled.prorated_amount, 0);
+    assert_eq!(
+        scheduled.effective_date,
+        client.get_subscription(&sub_id).expires_at
+    );
+    // The current period keeps the higher tier
+    assert_eq!(client.get_subscription(&sub_id).tier_id, pro_id);
+
+    // Already-scheduled and approved changes can't be re-scheduled or processed
+    assert_eq!(
+        client.try_schedule_tier_downgrade(&user, &sub_id, &basic_id),
+        Err(Ok(TierChangeError::DowngradeAlreadyScheduled.into()))
+    );
+    assert_eq!(
+        client.try_process_tier_change(&user, &change_id, &sub_id, &usdc),
+        Err(Ok(Error::TierChangeAlreadyProcessed.into()))
+    );
+
+    env.ledger().with_mut(|li| li.timestamp += 30 * 86_400);
+    client.renew_subscription(&sub_id, &usdc, &100_000, &2_592_000);
+
+    let subscription = client.get_subscription(&sub_id);
+    assert_eq!(subscription.tier_id, basic_id);
+    assert_eq!(subscription.locked_price, 100_000);
+    assert!(client.get_scheduled_tier_change(&sub_id).is_none());
+    assert_eq!(client.get_tier_analytics(&basic_id).active_subscribers, 1);
+    assert_eq!(client.get_tier_analytics(&pro_id).active_subscribers, 0);
+    assert_eq!(client.get_tier_analytics(&pro_id).downgrades_count, 1);
+}
+
+#[test]
+fn test_scheduled_downgrade_validation_and_cancel() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, admin, usdc, basic_id) = setup_multi_asset_env(&env);
+    let pro_id = create_pro_tier(&env, &client, &admin);
+    let user = Address::generate(&env);
+    let sub_id = String::from_str(&env, "sub_cancel_downgrade");
+    client.create_subscription_with_tier(
+        &sub_id,
+        &user,
+        &usdc,
+        &basic_id,
+        &BillingCycle::Monthly,
+        &None,
+    );
+
+    // Upgrades are not scheduled
+    assert_eq!(
+        client.try_schedule_tier_downgrade(&user, &sub_id, &pro_id),
+        Err(Ok(TierChangeError::NotADowngrade.into()))
+    );
+
+    let pro_sub = String::from_str(&env, "sub_cancel_pro");
+    client.create_subscription_with_tier(
+        &pro_sub,
+        &user,
+        &usdc,
+        &pro_id,
+        &BillingCycle::Monthly,
+        &None,
+    );
+    assert_eq!(
+        client.try_schedule_tier_downgrade(&Address::generate(&env), &pro_sub, &basic_id),
+        Err(Ok(Error::Unauthorized.into()))
+    );
+    client.schedule_tier_downgrade(&user, &pro_sub, &basic_id);
+    assert_eq!(
+        client.try_cancel_scheduled_downgrade(&Address::generate(&env), &pro_sub),
+        Err(Ok(Error::Unauthorized.into()))
+    );
+    client.cancel_scheduled_downgrade(&user, &pro_sub);
+    assert!(client.get_scheduled_tier_change(&pro_sub).is_none());
+    assert_eq!(
+        client.try_cancel_scheduled_downgrade(&user, &pro_sub),
+        Err(Ok(TierChangeError::NoScheduledDowngrade.into()))
+    );
+
+    // Renewal stays on the original tier and price
+    assert_eq!(
+        client.try_renew_subscription(&pro_sub, &usdc, &100_000, &2_592_000),
+        Err(Ok(Error::InvalidPaymentAmount.into()))
+    );
+    client.renew_subscription(&pro_sub, &usdc, &300_000, &2_592_000);
+    assert_eq!(client.get_subscription(&pro_sub).tier_id, pro_id);
+}
+
+// ==================== Tier Change Credit Tests ====================
+
+#[test]
+fn test_tier_change_quote_rounding() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, admin, usdc, basic_id) = setup_multi_asset_env(&env);
+    let pro_id = create_pro_tier(&env, &client, &admin);
+    let sub_id = String::from_str(&env, "sub_quote");
+    client.create_subscription_with_tier(
+        &sub_id,
+        &Address::generate(&env),
+        &usdc,
+        &basic_id,
+        &BillingCycle::Monthly,
+        &None,
+    );
+    env.ledger().with_mut(|li| li.timestamp += 10 * 86_400);
+
+    // 20 of 30 days remain: 66_666.67 unused rounds down, 200_000 new cost
+    let quote = client.get_tier_change_quote(&sub_id, &pro_id);
+    assert_eq!(quote.change_type, common_types::TierChangeType::Upgrade);
+    assert_eq!(quote.remaining_secs, 20 * 86_400);
+    assert_eq!(quote.unused_credit, 66_666);
+    assert_eq!(quote.new_cost, 200_000);
+    assert_eq!(quote.amount_due, 133_334);
+    assert_eq!(quote.credit, 0);
+
+    assert_eq!(
+        client.try_get_tier_change_quote(&sub_id, &String::from_str(&env, "missing")),
+        Err(Ok(Error::TierNotFound))
+    );
+}
+
+#[test]
+fn test_downgrade_credits_ledger_and_renewal_spends_it() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, admin, usdc, basic_id) = setup_multi_asset_env(&env);
+    let pro_id = create_pro_tier(&env, &client, &admin);
+    let user = Address::generate(&env);
+    let sub_id = String::from_str(&env, "sub_credit");
+    client.create_subscription_with_tier(
+        &sub_id,
+        &user,
+        &usdc,
+        &pro_id,
+        &BillingCycle::Monthly,
+        &None,
+    );
+    env.ledger().with_mut(|li| li.timestamp += 10 * 86_400);
+
+    // 200_000 unused on pro; 66_666.67 of basic rounds up to 66_667
+    let quote = client.get_tier_change_quote(&sub_id, &basic_id);
+    assert_eq!(quote.amount_due, 0);
+    assert_eq!(quote.credit, 133_333);
+
+    let change_id = client.request_tier_change(&user, &sub_id, &basic_id);
+    client.process_tier_change(&admin, &change_id, &sub_id, &usdc);
+    assert_eq!(client.get_credit_balance(&user), 133_333);
+    assert_eq!(client.get_subscription(&sub_id).tier_id, basic_id);
+
+    // The basic renewal price is fully covered by credit
+    client.renew_subscription(&sub_id, &usdc, &1, &2_592_000);
+    assert_eq!(client.get_credit_balance(&user), 33_333);
+
+    // The remainder is spent on the next upgrade, and only the cash part is
+    // booked as tier-change revenue
+    env.ledger().with_mut(|li| li.timestamp += 86_400);
+    let change_id = client.request_tier_change(&user, &sub_id, &pro_id);
+    let prorated = client.get_tier_change_quote(&sub_id, &pro_id).amount_due;
+    client.process_tier_change(&user, &change_id, &sub_id, &usdc);
+    assert_eq!(client.get_credit_balance(&user), 0);
+    assert_eq!(
+        client.get_lifetime_revenue().tier_changes,
+        prorated - 33_333
+    );
+}
+
+// ==================== Tier Change Frequency Tests ====================
+
+#[test]
+fn test_tier_change_cooldown_and_admin_override() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, admin, usdc, basic_id) = setup_multi_asset_env(&env);
+    let pro_id = create_pro_tier(&env, &client, &admin);
+    client.set_tier_change_cooldown(&admin, &(7 * 86_400));
+    assert_eq!(client.get_tier_change_cooldown(), 7 * 86_400);
+
+    let user = Address::generate(&env);
+    let sub_id = String::from_str(&env, "sub_flip");
+    client.create_subscription_with_tier(
+        &sub_id,
+        &user,
+        &usdc,
+        &basic_id,
+        &BillingCycle::Monthly,
+        &None,
+    );
+
+    let change_id = client.request_tier_change(&user, &sub_id, &pro_id);
+    client.process_tier_change(&user, &change_id, &sub_id, &usdc);
+
+    env.ledger().with_mut(|li| li.timestamp += 86_400);
+    assert_eq!(
+        client.try_request_tier_change(&user, &sub_id, &basic_id),
+        Err(Ok(TierChangeError::TierChangeTooFrequent.into()))
+    );
+    assert_eq!(
+        client.try_schedule_tier_downgrade(&user, &sub_id, &basic_id),
+        Err(Ok(TierChangeError::TierChangeTooFrequent.into()))
+    );
+
+    // The cooldown elapses on its own...
+    env.ledger().with_mut(|li| li.timestamp += 6 * 86_400);
+    let change_id = client.request_tier_change(&user, &sub_id, &basic_id);
+    client.process_tier_change(&admin, &change_id, &sub_id, &usdc);
+    env.ledger().with_mut(|li| li.timestamp += 60);
+    assert_eq!(
+        client.try_request_tier_change(&user, &sub_id, &pro_id),
+        Err(Ok(TierChangeError::TierChangeTooFrequent.into()))
+    );
+
+    // ...or the admin can lift it
+    client.reset_tier_change_cooldown(&admin, &sub_id);
+    client.request_tier_change(&user, &sub_id, &pro_id);
+}
+
+#[test]
+fn test_only_one_pending_tier_change_per_subscription() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, admin, usdc, basic_id) = setup_multi_asset_env(&env);
+    let pro_id = create_pro_tier(&env, &client, &admin);
+    let user = Address::generate(&env);
+    let sub_id = String::from_str(&env, "sub_pending");
+    client.create_subscription_with_tier(
+        &sub_id,
+        &user,
+        &usdc,
+        &pro_id,
+        &BillingCycle::Monthly,
+        &None,
+    );
+
+    let change_id = client.request_tier_change(&user, &sub_id, &basic_id);
+    env.ledger().with_mut(|li| li.timestamp += 60);
+    assert_eq!(
+        client.try_request_tier_change(&user, &sub_id, &basic_id),
+        Err(Ok(TierChangeError::ChangePending.into()))
+    );
+    assert_eq!(
+        client.try_schedule_tier_downgrade(&user, &sub_id, &basic_id),
+        Err(Ok(TierChangeError::ChangePending.into()))
+    );
+
+    // Cancelling frees the slot, and a scheduled downgrade holds it in turn
+    client.cancel_tier_change(&user, &change_id);
+    client.schedule_tier_downgrade(&user, &sub_id, &basic_id);
+    env.ledger().with_mut(|li| li.timestamp += 60);
+    assert_eq!(
+        client.try_request_tier_change(&user, &sub_id, &basic_id),
+        Err(Ok(TierChangeError::ChangePending.into()))
+    );
+}
+
+// ==================== Feature Override Tests ====================
+
+#[test]
+fn test_feature_override_grants_until_expiry() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, admin, usdc, tier_id) = setup_multi_asset_env(&env);
+    let sub_id = String::from_str(&env, "sub_override");
+    client.create_subscription_with_tier(
+        &sub_id,
+        &Address::generate(&env),
+        &usdc,
+        &tier_id,
+        &BillingCycle::Monthly,
+        &None,
+    );
+    let feature = common_types::TierFeature::ApiAccess;
+    assert!(!client.check_feature_access(&sub_id, &feature));
+
+    let expires_at = env.ledger().timestamp() + 7 * 86_400;
+    client.grant_feature_override(&admin, &sub_id, &feature, &expires_at);
+    assert!(client.check_feature_access(&sub_id, &feature));
+    let overrides = client.get_feature_overrides(&sub_id);
+    assert_eq!(overrides.len(), 1);
+    assert_eq!(overrides.get(0).unwrap().granted_by, admin);
+    assert_eq!(overrides.get(0).unwrap().expires_at, expires_at);
+
+    // Re-granting replaces the existing override
+    client.grant_feature_override(&admin, &sub_id, &feature, &(expires_at - 86_400));
+    assert_eq!(client.get_feature_overrides(&sub_id).len(), 1);
+
+    env.ledger().with_mut(|li| li.timestamp += 7 * 86_400);
+    assert!(!client.check_feature_access(&sub_id, &feature));
+    assert!(client.get_feature_overrides(&sub_id).is_empty());
+    assert_eq!(
+        client.try_grant_feature_override(&admin, &sub_id, &feature, &env.ledger().timestamp()),
+        Err(Ok(Error::InvalidExpiryDate))
+    );
+}
+
+#[test]
+fn test_revoke_feature_override() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, admin, usdc, tier_id) = setup_multi_asset_env(&env);
+    let sub_id = String::from_str(&env, "sub_revoke_override");
+    client.create_subscription_with_tier(
+        &sub_id,
+        &Address::generate(&env),
+        &usdc,
+        &tier_id,
+        &BillingCycle::Monthly,
+        &None,
+    );
+    let feature = common_types::TierFeature::WhiteLabel;
+    let expires_at = env.ledger().timestamp() + 86_400;
+
+    assert_eq!(
+        client.try_grant_feature_override(&Address::generate(&env), &sub_id, &feature, &expires_at),
+        Err(Ok(Error::Unauthorized))
+    );
+    client.grant_feature_override(&admin, &sub_id, &feature, &expires_at);
+    client.revoke_feature_override(&admin, &sub_id, &feature);
+    assert!(!client.check_feature_access(&sub_id, &feature));
+    assert_eq!(
+        client.try_revoke_feature_override(&admin, &sub_id, &feature),
+        Err(Ok(Error::FeatureNotAvailable))
+    );
+}
+
+// ==================== Event Outbox Tests ====================
+
+#[test]
+fn test_outbox_records_state_changes_in_sequence() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, _admin, usdc, tier_id) = setup_multi_asset_env(&env);
+    assert_eq!(client.get_latest_event_seq(), 0);
+    assert!(client.get_events_since(&0, &10).is_empty());
+
+    let token_id = BytesN::<32>::random(&env);
+    let user = Address::generate(&env);
+    client.issue_token(&token_id, &user, &(env.ledger().timestamp() + 86_400));
+
+    let sub_id = String::from_str(&env, "sub_outbox");
+    client.create_subscription_with_tier(
+        &sub_id,
+        &user,
+        &usdc,
+        &tier_id,
+        &BillingCycle::Monthly,
+        &None,
+    );
+    client.cancel_subscription(&sub_id, &None);
+    client.transfer_token(&token_id, &Address::generate(&env));
+
+    assert_eq!(client.get_latest_event_seq(), 4);
+    let records = client.get_events_since(&0, &10);
+    assert_eq!(records.len(), 4);
+    for (i, record) in records.iter().enumerate() {
+        assert_eq!(record.seq, i as u64 + 1);
+    }
+    assert_eq!(
+        records.get(0).unwrap().kind,
+        soroban_sdk::symbol_short!("tok_issue")
+    );
+    assert_eq!(
+        records.get(0).unwrap().subject,
+        crate::types::OutboxSubject::Token(token_id.clone())
+    );
+    assert_eq!(
+        records.get(1).unwrap().kind,
+        soroban_sdk::symbol_short!("sub_new")
+    );
+    assert_eq!(
+        records.get(2).unwrap().subject,
+        crate::types::OutboxSubject::Subscription(sub_id)
+    );
+    assert_eq!(
+        records.get(2).unwrap().kind,
+        soroban_sdk::symbol_short!("sub_cncl")
+    );
+    assert_eq!(
+        records.get(3).unwrap().kind,
+        soroban_sdk::symbol_short!("tok_xfer")
+    );
+
+    // Paging resumes after the caller's cursor
+    let page = client.get_events_since(&1, &2);
+    assert_eq!(page.len(), 2);
+    assert_eq!(page.get(0).unwrap().seq, 2);
+    assert_eq!(page.get(1).unwrap().seq, 3);
+    assert!(client.get_events_since(&4, &10).is_empty());
+}
+
+// ==================== Membership Sync Tests ====================
+
+mod mock_registry {
+    use crate::types::MembershipStatus;
+    use soroban_sdk::{contract, contractimpl, Address, Env};
+
+    /// Stores the last synced status and expiry per user.
+    #[contract]
+    pub struct RecordingRegistry;
+
+    #[contractimpl]
+    impl RecordingRegistry {
+        pub fn sync_membership(env: Env, user: Address, status: MembershipStatus, expires_at: u64) {
+            env.storage().instance().set(&user, &(status, expires_at));
+        }
+
+        pub fn synced(env: Env, user: Address) -> Option<(MembershipStatus, u64)> {
+            env.storage().instance().get(&user)
+        }
+    }
+}
+
+#[test]
+fn test_token_lifecycle_syncs_membership_registry() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(Contract, ());
+    let client = ContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let usdc = Address::generate(&env);
+    let registry_id = env.register(mock_registry::RecordingRegistry, ());
+    let registry = mock_registry::RecordingRegistryClient::new(&env, &registry_id);
+    client.initialize(&admin, &usdc, &registry_id, &default_init_config(&env));
+
+    let user = Address::generate(&env);
+    let token_id = BytesN::<32>::random(&env);
+    let expiry_date = env.ledger().timestamp() + 1_000;
+    client.issue_token(&token_id, &user, &expiry_date);
+    assert_eq!(
+        registry.synced(&user),
+        Some((MembershipStatus::Active, expiry_date))
+    );
+
+    env.ledger().with_mut(|l| l.timestamp = expiry_date + 1);
+    client.check_and_apply_grace_period(&token_id);
+    assert_eq!(
+        registry.synced(&user),
+        Some((MembershipStatus::GracePeriod, expiry_date))
+    );
+}
+
+#[test]
+fn test_membership_sync_failure_does_not_block_issue() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(Contract, ());
+    let client = ContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let usdc = Address::generate(&env);
+    // Not a deployed contract, so every sync fails
+    let access_control = Address::generate(&env);
+    client.initialize(&admin, &usdc, &access_control, &default_init_config(&env));
+
+    let user = Address::generate(&env);
+    let token_id = BytesN::<32>::random(&env);
+    client.issue_token(&token_id, &user, &(env.ledger().timestamp() + 1_000));
+    assert_eq!(client.get_token(&token_id).user, user);
+}
+
+// ==================== Blacklist Enforcement Tests ====================
+
+mod mock_blacklist {
+    use soroban_sdk::{contract, contractimpl, Address, Env};
+
+    /// Answers `is_blacklisted` from a per-user flag.
+    #[contract]
+    pub struct MockBlacklist;
+
+    #[contractimpl]
+    impl MockBlacklist {
+        pub fn set_blacklisted(env: Env, user: Address, blacklisted: bool) {
+            env.storage().instance().set(&user, &blacklisted);
+        }
+
+        pub fn is_blacklisted(env: Env, user: Address) -> bool {
+            env.storage().instance().get(&user).unwrap_or(false)
+        }
+    }
+}
+
+fn setup_blacklist_env(
+    env: &Env,
+) -> (
+    ContractClient<'_>,
+    Address,
+    mock_blacklist::MockBlacklistClient<'_>,
+) {
+    let contract_id = env.register(Contract, ());
+    let client = ContractClient::new(env, &contract_id);
+    let admin = Address::generate(env);
+    let usdc = Address::generate(env);
+    let blacklist_id = env.register(mock_blacklist::MockBlacklist, ());
+    let blacklist = mock_blacklist::MockBlacklistClient::new(env, &blacklist_id);
+    client.initialize(&admin, &usdc, &blacklist_id, &default_init_config(env));
+    (client, admin, blacklist)
+}
+
+#[test]
+fn test_blacklist_not_enforced_by_default() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin, blacklist) = setup_blacklist_env(&env);
+
+    let user = Address::generate(&env);
+    blacklist.set_blacklisted(&user, &true);
+
+    assert!(!client.is_blacklist_enforced(&BlacklistOperation::Issue));
+    let token_id = BytesN::<32>::random(&env);
+    client.issue_token(&token_id, &user, &(env.ledger().timestamp() + 1_000));
+    assert_eq!(client.get_token(&token_id).user, user);
+}
+
+#[test]
+fn test_blacklist_enforced_on_issue() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin, blacklist) = setup_blacklist_env(&env);
+
+    let user = Address::generate(&env);
+    blacklist.set_blacklisted(&user, &true);
+    client.set_blacklist_enforcement(&admin, &BlacklistOperation::Issue, &true);
+    assert!(client.is_blacklist_enforced(&BlacklistOperation::Issue));
+
+    let expiry_date = env.ledger().timestamp() + 1_000;
+    let result = client.try_issue_token(&BytesN::<32>::random(&env), &user, &expiry_date);
+    assert_eq!(result, Err(Ok(BlacklistError::UserBlacklisted.into())));
+
+    let clean_user = Address::generate(&env);
+    let token_id = BytesN::<32>::random(&env);
+    client.issue_token(&token_id, &clean_user, &expiry_date);
+    assert_eq!(client.get_token(&token_id).user, clean_user);
+}
+
+#[test]
+fn test_blacklist_enforced_on_transfer_recipient() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin, blacklist) = setup_blacklist_env(&env);
+
+    let owner = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    let token_id = BytesN::<32>::random(&env);
+    client.issue_token(&token_id, &owner, &(env.ledger().timestamp() + 1_000));
+
+    blacklist.set_blacklisted(&recipient, &true);
+    client.set_blacklist_enforcement(&admin, &BlacklistOperation::Transfer, &true);
+    let result = client.try_transfer_token(&token_id, &recipient);
+    assert_eq!(result, Err(Ok(BlacklistError::UserBlacklisted.into())));
+    assert_eq!(client.get_token(&token_id).user, owner);
+
+    client.set_blacklist_enforcement(&admin, &BlacklistOperation::Transfer, &false);
+    client.transfer_token(&token_id, &recipient);
+    assert_eq!(client.get_token(&token_id).user, recipient);
+}
+
+#[test]
+fn test_blacklist_enforced_on_bundles_and_token_renewal() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin, blacklist) = setup_blacklist_env(&env);
+    let tier_id = String::from_str(&env, "tier_basic");
+    client.create_tier(
+        &admin,
+        &CreateTierParams {
+            id: tier_id.clone(),
+            name: String::from_str(&env, "Basic"),
+            level: common_types::TierLevel::Basic,
+            price: 100_000i128,
+            annual_price: 1_000_000i128,
+            features: soroban_sdk::vec![&env, common_types::TierFeature::BasicAccess],
+            max_users: 100,
+            max_storage: 10_000_000,
+        },
+    );
+    let params = power_bundle(&env, &tier_id);
+    client.create_bundle(&admin, &params);
+
+    let user = Address::generate(&env);
+    let token_id = BytesN::<32>::random(&env);
+    client.issue_token(&token_id, &user, &(env.ledger().timestamp() + 1_000));
+    blacklist.set_blacklisted(&user, &true);
+    client.set_blacklist_enforcement(&admin, &BlacklistOperation::Subscription, &true);
+
+    let result = client.try_subscribe_to_bundle(
+        &String::from_str(&env, "sub_bundle"),
+        &user,
+        &params.id,
+        &BillingCycle::Monthly,
+    );
+    assert_eq!(result, Err(Ok(BlacklistError::UserBlacklisted.into())));
+
+    let result = client.try_renew_token(
+        &token_id,
+        &Address::generate(&env),
+        &tier_id,
+        &BillingCycle::Monthly,
+    );
+    assert_eq!(result, Err(Ok(BlacklistError::UserBlacklisted.into())));
+}
+
+#[test]
+fn test_blacklist_enforcement_fails_closed() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(Contract, ());
+    let client = ContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let usdc = Address::generate(&env);
+    // Not a deployed contract, so the blacklist cannot be queried
+    let access_control = Address::generate(&env);
+    client.initialize(&admin, &usdc, &access_control, &default_init_config(&env));
+    client.set_blacklist_enforcement(&admin, &BlacklistOperation::Issue, &true);
+
+    let user = Address::generate(&env);
+    let result = client.try_issue_token(
+        &BytesN::<32>::random(&env),
+        &user,
+        &(env.ledger().timestamp() + 1_000),
+    );
+    assert_eq!(result, Err(Ok(BlacklistError::BlacklistUnavailable.into())));
+}
+
+#[test]
+fn test_set_blacklist_enforcement_requires_admin() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin, _blacklist) = setup_blacklist_env(&env);
+
+    let stranger = Address::generate(&env);
+    let result =
+        client.try_set_blacklist_enforcement(&stranger, &BlacklistOperation::Staking, &true);
+    assert!(result.is_err());
+    assert!(!client.is_blacklist_enforced(&BlacklistOperation::Staking));
+}
+
+// ==================== Verification Tests ====================
+
+#[test]
+fn test_verify_user_and_expiry() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(Contract, ());
+    let client = ContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    client.set_admin(&admin);
+
+    let user = Address::generate(&env);
+    assert!(!client.is_verified(&user));
+
+    let expires_at = env.ledger().timestamp() + 1_000;
+    client.verify_user(&admin, &user, &expires_at);
+    assert!(client.is_verified(&user));
+    let record = client.get_verification(&user).unwrap();
+    assert_eq!(record.verified_by, admin);
+    assert_eq!(record.expires_at, expires_at);
+
+    env.ledger().with_mut(|l| l.timestamp = expires_at);
+    assert!(!client.is_verified(&user));
+    assert!(client.get_verification(&user).is_some());
+}
+
+#[test]
+fn test_designated_verifier_can_verify_and_revoke() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(Contract, ());
+    let client = ContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    client.set_admin(&admin);
+
+    let verifier = Address::generate(&env);
+    let user = Address::generate(&env);
+    let expires_at = env.ledger().timestamp() + 1_000;
+    assert_eq!(
+        client.try_verify_user(&verifier, &user, &expires_at),
+        Err(Ok(VerificationError::NotVerifier.into()))
+    );
+
+    client.set_verifier(&admin, &verifier, &true);
+    assert!(client.is_verifier(&verifier));
+    client.verify_user(&verifier, &user, &expires_at);
+    assert!(client.is_verified(&user));
+
+    client.revoke_verification(&verifier, &user);
+    assert!(!client.is_verified(&user));
+    assert_eq!(
+        client.try_revoke_verification(&verifier, &user),
+        Err(Ok(VerificationError::NotVerified.into()))
+    );
+
+    client.set_verifier(&admin, &verifier, &false);
+    assert!(!client.is_verifier(&verifier));
+}
+
+#[test]
+fn test_verify_user_rejects_past_expiry() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(Contract, ());
+    let client = ContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    client.set_admin(&admin);
+
+    let user = Address::generate(&env);
+    let result = client.try_verify_user(&admin, &user, &env.ledger().timestamp());
+    assert_eq!(result, Err(Ok(VerificationError::InvalidExpiry.into())));
+}
+
+#[test]
+fn test_fractionalization_requires_verification_under_policy() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(Contract, ());
+    let client = ContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    client.set_admin(&admin);
+
+    let owner = Address::generate(&env);
+    let token_id = BytesN::<32>::random(&env);
+    let expiry_date = env.ledger().timestamp() + 30 * 24 * 60 * 60;
+    client.issue_token(&token_id, &owner, &expiry_date);
+
+    let policy = crate::types::VerificationPolicy {
+        fractionalization: true,
+        stake_threshold: None,
+        households: false,
+    };
+    client.set_verification_policy(&admin, &policy);
+    assert_eq!(client.get_verification_policy(), policy);
+
+    let result = client.try_fractionalize_token(&token_id, &1000, &100);
+    assert_eq!(result, Err(Ok(VerificationError::NotVerified.into())));
+
+    client.verify_user(&admin, &owner, &expiry_date);
+    client.fractionalize_token(&token_id, &1000, &100);
+}
+
+#[test]
+fn test_stake_above_threshold_requires_verification() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin, staking_asset) = setup_staking_env(&env);
+
+    let policy = crate::types::VerificationPolicy {
+        fractionalization: false,
+        stake_threshold: Some(5_000),
+        households: false,
+    };
+    client.set_verification_policy(&admin, &policy);
+
+    let staker = Address::generate(&env);
+    staking_asset.mint(&staker, &20_000);
+    let tier_id = String::from_str(&env, "bronze");
+
+    // At the threshold no verification is needed
+    client.stake_tokens(&staker, &tier_id, &5_000);
+    // Topping up past it is refused
+    let result = client.try_stake_tokens(&staker, &tier_id, &1_000);
+    assert_eq!(result, Err(Ok(VerificationError::NotVerified.into())));
+
+    client.verify_user(&admin, &staker, &(env.ledger().timestamp() + 1_000));
+    client.stake_tokens(&staker, &tier_id, &1_000);
+    assert_eq!(client.get_stake_info(&staker).unwrap().amount, 6_000);
+}
+
+// ==================== Dispute Tests ====================
+
+#[test]
+fn test_reversed_token_dispute_lifts_pause() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(Contract, ());
+    let client = ContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    client.set_admin(&admin);
+
+    let user = Address::generate(&env);
+    let token_id = BytesN::<32>::random(&env);
+    client.issue_token(&token_id, &user, &(env.ledger().timestamp() + 1_000));
+    client.pause_token_operations(&admin, &token_id, &None, &None, &None);
+    assert!(client.is_token_paused(&token_id));
+
+    let subject = crate::types::DisputeSubject::Token(token_id.clone());
+    let dispute_id = client.file_dispute(&user, &subject, &BytesN::<32>::random(&env));
+    let dispute = client.get_dispute(&dispute_id);
+    assert_eq!(dispute.status, crate::types::DisputeStatus::Open);
+    assert_eq!(dispute.linked_token, Some(token_id.clone()));
+    assert_eq!(
+        client.get_open_disputes(),
+        soroban_sdk::vec![&env, dispute_id]
+    );
+    assert_eq!(
+        client.get_user_disputes(&user),
+        soroban_sdk::vec![&env, dispute_id]
+    );
+
+    client.resolve_dispute(&admin, &dispute_id, &crate::types::DisputeOutcome::Reverse);
+    assert!(!client.is_token_paused(&token_id));
+    let dispute = client.get_dispute(&dispute_id);
+    assert_eq!(dispute.status, crate::types::DisputeStatus::Reversed);
+    assert_eq!(dispute.resolved_by, Some(admin.clone()));
+    assert!(client.get_open_disputes().is_empty());
+
+    let result =
+        client.try_resolve_dispute(&admin, &dispute_id, &crate::types::DisputeOutcome::Uphold);
+    assert_eq!(result, Err(Ok(DisputeError::AlreadyResolved.into())));
+}
+
+#[test]
+fn test_attendance_dispute_compensated_with_points() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(Contract, ());
+    let client = ContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    client.set_admin(&admin);
+
+    let user = Address::generate(&env);
+    let token_id = BytesN::<32>::random(&env);
+    client.issue_token(&token_id, &user, &(env.ledger().timestamp() + 1_000));
+    let log_id = BytesN::<32>::random(&env);
+    client.log_attendance(
+        &log_id,
+        &user,
+        &AttendanceAction::ClockIn,
+        &soroban_sdk::Map::new(&env),
+    );
+
+    let subject = crate::types::DisputeSubject::Attendance(log_id);
+    let dispute_id = client.file_dispute(&user, &subject, &BytesN::<32>::random(&env));
+    assert_eq!(client.get_dispute(&dispute_id).linked_token, Some(token_id));
+
+    let result = client.try_resolve_dispute(
+        &admin,
+        &dispute_id,
+        &crate::types::DisputeOutcome::Compensate(0),
+    );
+    assert_eq!(result, Err(Ok(DisputeError::InvalidCompensation.into())));
+
+    let points_before = client.get_loyalty_account(&user).points;
+    client.resolve_dispute(
+        &admin,
+        &dispute_id,
+        &crate::types::DisputeOutcome::Compensate(250),
+    );
+    let dispute = client.get_dispute(&dispute_id);
+    assert_eq!(dispute.status, crate::types::DisputeStatus::Compensated);
+    assert_eq!(dispute.credited_points, 250);
+    assert_eq!(
+        client.get_loyalty_account(&user).points,
+        points_before + 250
+    );
+}
+
+#[test]
+fn test_dispute_requires_subject_owner_and_resolver() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(Contract, ());
+    let client = ContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    client.set_admin(&admin);
+
+    let owner = Address::generate(&env);
+    let other = Address::generate(&env);
+    let token_id = BytesN::<32>::random(&env);
+    client.issue_token(&token_id, &owner, &(env.ledger().timestamp() + 1_000));
+
+    let subject = crate::types::DisputeSubject::Token(token_id);
+    let result = client.try_file_dispute(&other, &subject, &BytesN::<32>::random(&env));
+    assert_eq!(result, Err(Ok(DisputeError::NotSubjectOwner.into())));
+
+    let dispute_id = client.file_dispute(&owner, &subject, &BytesN::<32>::random(&env));
+    let result =
+        client.try_resolve_dispute(&other, &dispute_id, &crate::types::DisputeOutcome::Uphold);
+    assert_eq!(result, Err(Ok(Error::Unauthorized.into())));
+    assert_eq!(
+        client.try_get_dispute(&(dispute_id + 1)),
+        Err(Ok(DisputeError::DisputeNotFound.into()))
+    );
+}
+
+// ==================== Subscription Lifecycle Tests ====================
+
+#[test]
+fn test_subscription_lifecycle_transition_table() {
+    use crate::subscription_lifecycle::{SubscriptionEvent, SubscriptionLifecycle};
+    use MembershipStatus::*;
+    use SubscriptionEvent::*;
+
+    let allowed = [
+        (Active, Pause, Paused),
+        (Paused, Resume, Active),
+        (Active, Cancel, Inactive),
+        (Paused, Cancel, Inactive),
+        (GracePeriod, Cancel, Inactive),
+        (Expired, Cancel, Inactive),
+        (Active, Renew, Active),
+        (GracePeriod, Renew, Active),
+        (Expired, Renew, Active),
+        (Active, Expire, Expired),
+        (Active, Lapse, Inactive),
+        (Paused, Lapse, Inactive),
+        (GracePeriod, Lapse, Inactive),
+        (Expired, Reactivate, Active),
+        (Inactive, Reactivate, Active),
+    ];
+    let statuses = [Active, Paused, GracePeriod, Expired, Inactive, Revoked];
+    let events = [Pause, Resume, Cancel, Renew, Expire, Lapse, Reactivate];
+
+    for from in statuses.iter() {
+        for event in events.iter() {
+            let expected = allowed
+                .iter()
+                .find(|(f, e, _)| f == from && e == event)
+                .map(|(_, _, to)| to.clone());
+            let actual = SubscriptionLifecycle::next_status(from, *event).ok();
+            assert_eq!(actual, expected, "{:?} on {:?}", event, from);
+        }
+    }
+}
+
+#[test]
+fn test_invalid_transition_error_codes() {
+    use crate::lifecycle_errors::LifecycleError;
+    use crate::subscription_lifecycle::{SubscriptionEvent, SubscriptionLifecycle};
+
+    let error = |from: MembershipStatus, event: SubscriptionEvent| -> Error {
+        let err: LifecycleError = SubscriptionLifecycle::next_status(&from, event).unwrap_err();
+        err.into()
+    };
+    assert_eq!(
+        error(MembershipStatus::Paused, SubscriptionEvent::Renew),
+        Error::SubscriptionPaused
+    );
+    assert_eq!(
+        error(MembershipStatus::Active, SubscriptionEvent::Resume),
+        Error::SubscriptionNotPaused
+    );
+    assert_eq!(
+        error(MembershipStatus::Inactive, SubscriptionEvent::Cancel),
+        Error::SubscriptionNotActive
+    );
+    assert_eq!(
+        error(MembershipStatus::Active, SubscriptionEvent::Reactivate),
+        Error::SubscriptionAlreadyExists
+    );
+}
+
+#[test]
+fn test_cancelled_subscription_cannot_be_renewed_or_cancelled_again() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(Contract, ());
+    let client = ContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let user = Address::generate(&env);
+    let payment_token = Address::generate(&env);
+    let subscription_id = String::from_str(&env, "sub_lifecycle");
+    client.set_admin(&admin);
+    client.set_usdc_contract(&admin, &payment_token);
+    client.create_subscription(
+        &subscription_id,
+        &user,
+        &payment_token,
+        &100_000,
+        &2_592_000,
+    );
+
+    client.cancel_subscription(&subscription_id, &None);
+    assert_eq!(
+        client.get_subscription(&subscription_id).status,
+        MembershipStatus::Inactive
+    );
+
+    let result = client.try_cancel_subscription(&subscription_id, &None);
+    assert_eq!(result, Err(Ok(Error::SubscriptionNotActive)));
+    let result =
+        client.try_renew_subscription(&subscription_id, &payment_token, &100_000, &2_592_000);
+    assert_eq!(result, Err(Ok(Error::SubscriptionNotActive.into())));
+}
+
+#[test]
+fn test_get_subscription_reports_effective_expiry() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(Contract, ());
+    let client = ContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let user = Address::generate(&env);
+    let payment_token = Address::generate(&env);
+    let subscription_id = String::from_str(&env, "sub_effective");
+    client.set_admin(&admin);
+    client.set_usdc_contract(&admin, &payment_token);
+    client.create_subscription(&subscription_id, &user, &payment_token, &100_000, &1_000);
+
+    let expires_at = client.get_subscription(&subscription_id).expires_at;
+    env.ledger().with_mut(|l| l.timestamp = expires_at + 1);
+
+    // Read-time normalization, before anything is persisted
+    assert_eq!(
+        client.get_subscription(&subscription_id).status,
+        MembershipStatus::Expired
+    );
+    let result = client.try_pause_subscription(&subscription_id, &None);
+    assert_eq!(result, Err(Ok(Error::SubscriptionNotActive)));
+
+    // Renewal persists the expiry first, then reactivates
+    client.renew_subscription(&subscription_id, &payment_token, &100_000, &1_000);
+    let subscription = client.get_subscription(&subscription_id);
+    assert_eq!(subscription.status, MembershipStatus::Active);
+    assert_eq!(subscription.expires_at, expires_at + 1 + 1_000);
+    assert!(!client.expire_subscription(&subscription_id));
+}
+
+// ==================== TTL Management Tests ====================
+
+fn token_ttl(env: &Env, contract_id: &Address, token_id: &BytesN<32>) -> u32 {
+    use soroban_sdk::testutils::storage::Persistent as _;
+    env.as_contract(contract_id, || {
+        env.storage()
+            .persistent()
+            .get_ttl(&crate::membership_token::DataKey::Token(token_id.clone()))
+    })
+}
+
+#[test]
+fn test_token_ttl_follows_policy() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(Contract, ());
+    let client = ContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    client.set_admin(&admin);
+
+    let token_id = BytesN::<32>::random(&env);
+    let user = Address::generate(&env);
+    client.issue_token(&token_id, &user, &(env.ledger().timestamp() + 1_000));
+
+    let policy = client.get_ttl_policy();
+    assert_eq!(
+        token_ttl(&env, &contract_id, &token_id),
+        policy.token.extend_to
+    );
+
+    let mut longer = policy.clone();
+    longer.token = crate::types::TtlSetting {
+        threshold: policy.token.extend_to + 1,
+        extend_to: policy.token.extend_to + 100_000,
+    };
+    client.set_ttl_policy(&admin, &longer);
+    assert_eq!(client.get_ttl_policy(), longer);
+
+    client.extend_entity_ttl(&admin, &crate::types::TtlEntityKey::Token(token_id.clone()));
+    assert_eq!(
+        token_ttl(&env, &contract_id, &token_id),
+        longer.token.extend_to
+    );
+}
+
+#[test]
+fn test_ttl_policy_validation_and_missing_entities() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(Contract, ());
+    let client = ContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    client.set_admin(&admin);
+
+    let mut policy = client.get_ttl_policy();
+    policy.tier = crate::types::TtlSetting {
+        threshold: 1_000,
+        extend_to: 500,
+    };
+    let result = client.try_set_ttl_policy(&admin, &policy);
+    assert_eq!(result, Err(Ok(Error::InvalidPaymentAmount)));
+
+    let stranger = Address::generate(&env);
+    let result = client.try_set_ttl_policy(&stranger, &client.get_ttl_policy());
+    assert_eq!(result, Err(Ok(Error::Unauthorized)));
+
+    let key = crate::types::TtlEntityKey::Subscription(String::from_str(&env, "missing"));
+    let result = client.try_extend_entity_ttl(&admin, &key);
+    assert_eq!(result, Err(Ok(Error::SubscriptionNotFound)));
+}
+
+// ==================== Pause History Storage Tests ====================
+
+#[test]
+fn test_pause_history_spans_multiple_chunks() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(Contract, ());
+    let client = ContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let user = Address::generate(&env);
+    let payment_token = Address::generate(&env);
+    let subscription_id = String::from_str(&env, "sub_chunks");
+
+    client.set_admin(&admin);
+    client.set_usdc_contract(&admin, &payment_token);
+    client.create_subscription(
+        &subscription_id,
+        &user,
+        &payment_token,
+        &100_000,
+        &2_592_000,
+    );
+
+    for _ in 0..10 {
+        client.pause_subscription_admin(&subscription_id, &admin, &None);
+        env.ledger().with_mut(|l| l.timestamp += 60);
+        client.resume_subscription_admin(&subscription_id, &admin);
+    }
+
+    let history = client.get_pause_history(&subscription_id);
+    assert_eq!(history.len(), 20);
+    assert!(history.len() > subscription::PAUSE_HISTORY_CHUNK_SIZE);
+    for (i, entry) in history.iter().enumerate() {
+        let expected = if i % 2 == 0 {
+            PauseAction::Pause
+        } else {
+            PauseAction::Resume
+        };
+        assert_eq!(entry.action, expected);
+    }
+}
+
+#[test]
+fn test_legacy_subscription_pause_history_migrated_on_read() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(Contract, ());
+    let client = ContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let user = Address::generate(&env);
+    let payment_token = Address::generate(&env);
+    let subscription_id = String::from_str(&env, "sub_legacy_pause");
+    client.set_admin(&admin);
+
+    let entry = PauseHistoryEntry {
+        action: PauseAction::Pause,
+        timestamp: 10,
+        actor: user.clone(),
+        is_admin: false,
+        reason: Some(String::from_str(&env, "travel")),
+        paused_duration: None,
+        applied_extension: None,
+        policy: types::PausePolicy::ExtendExpiry,
+        credited_days: 0,
+    };
+    let legacy = types::LegacySubscription {
+        id: subscription_id.clone(),
+        user: user.clone(),
+        payment_token,
+        amount: 100_000,
+        status: MembershipStatus::Paused,
+        created_at: 0,
+        expires_at: 2_592_000,
+        tier_id: String::from_str(&env, ""),
+        billing_cycle: BillingCycle::Monthly,
+        paused_at: Some(10),
+        last_resumed_at: 0,
+        pause_count: 1,
+        total_paused_duration: 0,
+        pause_history: vec![&env, entry.clone()],
+        locked_price: 0,
+    };
+    env.as_contract(&contract_id, || {
+        env.storage().persistent().set(
+            &subscription::SubscriptionDataKey::Subscription(subscription_id.clone()),
+            &legacy,
+        );
+    });
+    assert_eq!(client.get_subscription_schema_version(&subscription_id), 0);
+
+    let subscription = client.get_subscription(&subscription_id);
+    assert_eq!(subscription.user, user);
+    assert_eq!(subscription.pause_count, 1);
+    assert_eq!(client.get_subscription_schema_version(&subscription_id), 2);
+    assert_eq!(
+        client.get_pause_history(&subscription_id),
+        vec![&env, entry.clone()]
+    );
+
+    // New entries append after the migrated ones.
+    env.ledger().with_mut(|l| l.timestamp = 100);
+    client.resume_subscription_admin(&subscription_id, &admin);
+    let history = client.get_pause_history(&subscription_id);
+    assert_eq!(history.len(), 2);
+    assert_eq!(history.get(0).unwrap(), entry);
+    assert_eq!(history.get(1).unwrap().action, PauseAction::Resume);
+}
+
+#[test]
+fn test_subscription_from_before_locked_prices_still_loads() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(Contract, ());
+    let client = ContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let user = Address::generate(&env);
+    let subscription_id = String::from_str(&env, "sub_v0");
+    client.set_admin(&admin);
+
+    let v0 = types::SubscriptionV0 {
+        id: subscription_id.clone(),
+        user: user.clone(),
+        payment_token: Address::generate(&env),
+        amount: 100_000,
+        status: MembershipStatus::Active,
+        created_at: 0,
+        expires_at: 2_592_000,
+        tier_id: String::from_str(&env, ""),
+        billing_cycle: BillingCycle::Monthly,
+        paused_at: None,
+        last_resumed_at: 0,
+        pause_count: 0,
+        total_paused_duration: 0,
+        pause_history: Vec::new(&env),
+    };
+    env.as_contract(&contract_id, || {
+        env.storage().persistent().set(
+            &subscription::SubscriptionDataKey::Subscription(subscription_id.clone()),
+            &v0,
+        );
+    });
+
+    let subscription = client.get_subscription(&subscription_id);
+    assert_eq!(subscription.user, user);
+    assert_eq!(subscription.amount, 100_000);
+    assert_eq!(subscription.locked_price, 0);
+    assert_eq!(client.get_subscription_schema_version(&subscription_id), 2);
+}
+
+// ==================== Billing History Tests ====================
+
+#[test]
+fn test_billing_history_records_create_and_renewals() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(Contract, ());
+    let client = ContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let user = Address::generate(&env);
+    let payment_token = Address::generate(&env);
+    let sub_id = String::from_str(&env, "sub_billing");
+
+    client.set_admin(&admin);
+    client.set_usdc_contract(&admin, &payment_token);
+    client.create_subscription(&sub_id, &user, &payment_token, &100_000, &2_592_000);
+    env.ledger().with_mut(|li| li.timestamp += 86_400);
+    client.renew_subscription(&sub_id, &payment_token, &150_000, &2_592_000);
+    client.renew_subscription(&sub_id, &payment_token, &175_000, &2_592_000);
+
+    let history = client.get_billing_history(&sub_id, &0, &10);
+    assert_eq!(history.len(), 3);
+    let created = history.get(0).unwrap();
+    assert_eq!(created.kind, BillingKind::Create);
+    assert_eq!(created.amount, 100_000);
+    assert_eq!(created.payer, user);
+    assert_eq!(created.payment_token, payment_token);
+    assert_eq!(created.timestamp, 0);
+    let renewed = history.get(1).unwrap();
+    assert_eq!(renewed.kind, BillingKind::Renew);
+    assert_eq!(renewed.amount, 150_000);
+    assert_eq!(renewed.timestamp, 86_400);
+
+    let page = client.get_billing_history(&sub_id, &2, &10);
+    assert_eq!(page.len(), 1);
+    assert_eq!(page.get(0).unwrap().amount, 175_000);
+    assert!(client
+        .get_billing_history(&String::from_str(&env, "unknown"), &0, &10)
+        .is_empty());
+}
+
+#[test]
+fn test_billing_history_records_promo_and_tier_change() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, admin, usdc, basic_id) = setup_multi_asset_env(&env);
+    let pro_id = create_pro_tier(&env, &client, &admin);
+    let promo_code = String::from_str(&env, "WELCOME10");
+    client.create_promotion(
+        &admin,
+        &CreatePromotionParams {
+            promo_id: String::from_str(&env, "promo_welcome"),
+            tier_id: basic_id.clone(),
+            discount_percent: 10,
+            promo_price: 0,
+            start_date: 0,
+            end_date: 86_400 * 365,
+            promo_code: promo_code.clone(),
+            max_redemptions: 0,
+        },
+    );
+
+    let user = Address::generate(&env);
+    let sub_id = String::from_str(&env, "sub_promo_billing");
+    client.create_subscription_with_tier(
+        &sub_id,
+        &user,
+        &usdc,
+        &basic_id,
+        &BillingCycle::Monthly,
+        &Some(promo_code.clone()),
+    );
+
+    env.ledger().with_mut(|li| li.timestamp += 10 * 86_400);
+    let due = client.get_tier_change_quote(&sub_id, &pro_id).amount_due;
+    let change_id = client.request_tier_change(&user, &sub_id, &pro_id);
+    client.process_tier_change(&user, &change_id, &sub_id, &usdc);
+
+    let history = client.get_billing_history(&sub_id, &0, &10);
+    assert_eq!(history.len(), 2);
+    let created = history.get(0).unwrap();
+    assert_eq!(created.kind, BillingKind::Create);
+    assert_eq!(created.amount, 90_000);
+    assert_eq!(created.promo_code, Some(promo_code));
+    let upgrade = history.get(1).unwrap();
+    assert_eq!(upgrade.kind, BillingKind::TierChange);
+    assert_eq!(upgrade.amount, due);
+    assert_eq!(upgrade.promo_code, None);
+}
+
+#[test]
+fn test_invoices_issued_for_billing_events() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(Contract, ());
+    let client = ContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let user = Address::generate(&env);
+    let other = Address::generate(&env);
+    let payment_token = Address::generate(&env);
+    let sub_id = String::from_str(&env, "sub_invoice");
+
+    client.set_admin(&admin);
+    client.set_usdc_contract(&admin, &payment_token);
+    assert_eq!(
+        client.try_set_invoice_tax_rate(&admin, &5_001),
+        Err(Ok(BillingError::InvalidTaxRate.into()))
+    );
+    client.set_invoice_tax_rate(&admin, &2_000);
+    assert_eq!(client.get_invoice_tax_rate(), 2_000);
+
+    client.create_subscription(&sub_id, &user, &payment_token, &120_000, &2_592_000);
+    client.create_subscription(
+        &String::from_str(&env, "sub_other"),
+        &other,
+        &payment_token,
+        &60_000,
+        &2_592_000,
+    );
+    client.renew_subscription(&sub_id, &payment_token, &240_000, &2_592_000);
+
+    // Invoice numbers are global and follow issue order
+    let history = client.get_billing_history(&sub_id, &0, &10);
+    assert_eq!(history.get(0).unwrap().invoice_id, 1);
+    assert_eq!(history.get(1).unwrap().invoice_id, 3);
+
+    let invoice = client.get_invoice(&3);
+    assert_eq!(invoice.payer, user);
+    assert_eq!(invoice.subscription_id, sub_id);
+    assert_eq!(invoice.total, 240_000);
+    assert_eq!(invoice.tax, 40_000);
+    assert_eq!(invoice.subtotal, 200_000);
+    assert_eq!(invoice.line_items.len(), 1);
+    assert_eq!(
+        invoice.line_items.get(0).unwrap().description,
+        String::from_str(&env, "Subscription renewal")
+    );
+    assert_eq!(invoice.status, InvoiceStatus::Paid);
+
+    let invoices = client.get_invoices_for_user(&user, &0, &10);
+    assert_eq!(invoices.len(), 2);
+    assert_eq!(invoices.get(0).unwrap().invoice_id, 1);
+    assert_eq!(invoices.get(1).unwrap().invoice_id, 3);
+    assert_eq!(client.get_invoices_for_user(&other, &0, &10).len(), 1);
+    assert_eq!(client.get_invoices_for_user(&user, &1, &10).len(), 1);
+
+    client.mark_invoice_refunded(&admin, &1);
+    let refunded = client.get_invoice(&1);
+    assert_eq!(refunded.status, InvoiceStatus::Refunded);
+    assert_eq!(refunded.refunded_at, Some(env.ledger().timestamp()));
+    assert_eq!(
+        client.try_mark_invoice_refunded(&admin, &1),
+        Err(Ok(BillingError::InvoiceAlreadyRefunded.into()))
+    );
+    assert_eq!(
+        client.try_mark_invoice_refunded(&user, &3),
+        Err(Ok(Error::Unauthorized.into()))
+    );
+    assert_eq!(
+        client.try_get_invoice(&99),
+        Err(Ok(BillingError::InvoiceNotFound.into()))
+    );
+}
+
+#[test]
+fn test_fee_engine_quotes_and_charges_tier_subscriptions() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin, usdc, tier_id) = setup_multi_asset_env(&env);
+    let user = Address::generate(&env);
+    let eu = String::from_str(&env, "EU");
+
+    assert_eq!(
+        client.try_set_fee_config(
+            &admin,
+            &FeeConfig {
+                platform_fee_bps: 5_001,
+                processing_fee: 0,
+            }
+        ),
+        Err(Ok(BillingError::InvalidFeeConfig.into()))
+    );
+    assert_eq!(
+        client.try_set_region_tax_rate(&admin, &eu, &5_001),
+        Err(Ok(BillingError::InvalidFeeConfig.into()))
+    );
+    client.set_fee_config(
+        &admin,
+        &FeeConfig {
+            platform_fee_bps: 250,
+            processing_fee: 1_000,
+        },
+    );
+    client.set_region_tax_rate(&admin, &eu, &2_000);
+
+    // Tax applies to the price and both fees
+    let quote = client.quote_total(&tier_id, &BillingCycle::Monthly, &Some(eu.clone()));
+    assert_eq!(
+        quote,
+        FeeBreakdown {
+            base: 100_000,
+            platform_fee: 2_500,
+            processing_fee: 1_000,
+            tax: 20_700,
+            total: 124_200,
+        }
+    );
+    let untaxed = client.quote_total(&tier_id, &BillingCycle::Monthly, &None);
+    assert_eq!(untaxed.tax, 0);
+    assert_eq!(untaxed.total, 103_500);
+
+    client.set_billing_region(&user, &Some(eu.clone()));
+    assert_eq!(client.get_billing_region(&user), Some(eu));
+    let sub_id = String::from_str(&env, "sub_fees");
+    client.create_subscription_with_tier(
+        &sub_id,
+        &user,
+        &usdc,
+        &tier_id,
+        &BillingCycle::Monthly,
+        &None,
+    );
+    assert_eq!(client.get_subscription(&sub_id).amount, 124_200);
+
+    let invoice = client.get_invoice(&1);
+    assert_eq!(invoice.total, 124_200);
+    assert_eq!(invoice.tax, 20_700);
+    assert_eq!(invoice.subtotal, 103_500);
+    assert_eq!(invoice.line_items.len(), 3);
+    assert_eq!(invoice.line_items.get(0).unwrap().amount, 100_000);
+    assert_eq!(
+        invoice.line_items.get(1).unwrap().description,
+        String::from_str(&env, "Platform fee")
+    );
+    assert_eq!(invoice.line_items.get(2).unwrap().amount, 1_000);
+
+    // Renewals must cover the fees as well
+    assert_eq!(
+        client.try_renew_subscription(&sub_id, &usdc, &100_000, &2_592_000),
+        Err(Ok(Error::InvalidPaymentAmount.into()))
+    );
+    client.set_billing_region(&user, &None);
+    client.renew_subscription(&sub_id, &usdc, &103_500, &2_592_000);
+    let renewal = client.get_invoice(&2);
+    assert_eq!(renewal.total, 103_500);
+    assert_eq!(renewal.line_items.get(0).unwrap().amount, 100_000);
+}
+
+#[test]
+fn test_fees_apply_to_installments_and_token_renewals() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin, usdc, tier_id) = setup_multi_asset_env(&env);
+    client.set_fee_config(
+        &admin,
+        &FeeConfig {
+            platform_fee_bps: 250,
+            processing_fee: 1_000,
+        },
+    );
+
+    // Installments split the annual price plus fees
+    let user = Address::generate(&env);
+    let sub_id = String::from_str(&env, "sub_inst_fees");
+    let plan = client.create_subscription_installments(&sub_id, &user, &usdc, &tier_id, &3);
+    assert_eq!(plan.total_amount, 1_026_000);
+    assert_eq!(plan.installment_amount, 342_000);
+    let invoice = client.get_invoice(&1);
+    assert_eq!(invoice.total, 342_000);
+    assert_eq!(invoice.line_items.get(0).unwrap().amount, 333_334);
+
+    env.ledger().with_mut(|l| l.timestamp = plan.next_due_at);
+    let plan = client.pay_installment(&sub_id);
+    env.ledger().with_mut(|l| l.timestamp = plan.next_due_at);
+    client.pay_installment(&sub_id);
+    // Fully paid, the plan settled each fee once and booked only the price
+    assert_eq!(client.get_lifetime_revenue().subscriptions, 1_000_000);
+
+    // Token renewals are charged the price plus fees too
+    let token_id = BytesN::<32>::random(&env);
+    client.issue_token(&token_id, &user, &(env.ledger().timestamp() + 1_000));
+    client.renew_token(&token_id, &usdc, &tier_id, &BillingCycle::Monthly);
+    let renewal = client.get_renewal_history(&token_id).get(0).unwrap();
+    assert_eq!(renewal.amount, 103_500);
+}
+
+#[test]
+fn test_cancellation_reason_and_winback_offer() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin, usdc, tier_id) = setup_multi_asset_env(&env);
+    let user = Address::generate(&env);
+    let other = Address::generate(&env);
+
+    assert_eq!(
+        client.try_set_winback_config(
+            &admin,
+            &Some(WinbackConfig {
+                discount_percent: 101,
+                valid_days: 7,
+            })
+        ),
+        Err(Ok(RetentionError::InvalidWinbackConfig.into()))
+    );
+    client.set_winback_config(
+        &admin,
+        &Some(WinbackConfig {
+            discount_percent: 40,
+            valid_days: 7,
+        }),
+    );
+
+    let sub_id = String::from_str(&env, "sub_churn");
+    client.create_subscription_with_tier(
+        &sub_id,
+        &user,
+        &usdc,
+        &tier_id,
+        &BillingCycle::Monthly,
+        &None,
+    );
+    client.cancel_subscription(&sub_id, &Some(CancellationReason::TooExpensive));
+
+    let record = client.get_cancellation(&sub_id).unwrap();
+    assert_eq!(record.user, user);
+    assert_eq!(record.tier_id, tier_id);
+    assert_eq!(record.reason, CancellationReason::TooExpensive);
+    assert_eq!(
+        client.get_cancellation_reason_count(&CancellationReason::TooExpensive),
+        1
+    );
+    assert_eq!(
+        client.get_cancellation_reason_count(&CancellationReason::Other),
+        0
+    );
+
+    let offer = client.get_winback_offer(&user).unwrap();
+    assert_eq!(offer.subscription_id, sub_id);
+    assert_eq!(offer.discount_percent, 40);
+    assert_eq!(offer.expires_at, offer.issued_at + 7 * 86_400);
+
+    // The offer discounts the next tier subscription once
+    let back_id = String::from_str(&env, "sub_back");
+    client.create_subscription_with_tier(
+        &back_id,
+        &user,
+        &usdc,
+        &tier_id,
+        &BillingCycle::Monthly,
+        &None,
+    );
+    assert_eq!(client.get_subscription(&back_id).amount, 60_000);
+    assert_eq!(client.get_winback_offer(&user), None);
+
+    // Offers lapse after their validity window
+    let other_id = String::from_str(&env, "sub_lapse");
+    client.create_subscription_with_tier(
+        &other_id,
+        &other,
+        &usdc,
+        &tier_id,
+        &BillingCycle::Monthly,
+        &None,
+    );
+    client.cancel_subscription(&other_id, &None);
+    assert_eq!(
+        client.get_cancellation(&other_id).unwrap().reason,
+        CancellationReason::Unspecified
+    );
+    assert_eq!(
+        client.get_cancellation_reason_count(&CancellationReason::Unspecified),
+        0
+    );
+    assert!(client.get_winback_offer(&other).is_some());
+    env.ledger().with_mut(|l| l.timestamp += 7 * 86_400 + 1);
+    assert_eq!(client.get_winback_offer(&other), None);
+}
+
+#[test]
+fn test_reactivate_cancelled_subscription() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin, usdc, tier_id) = setup_multi_asset_env(&env);
+    let user = Address::generate(&env);
+    let sub_id = String::from_str(&env, "sub_react");
+
+    client.create_subscription_with_tier(
+        &sub_id,
+        &user,
+        &usdc,
+        &tier_id,
+        &BillingCycle::Monthly,
+        &None,
+    );
+    let created_at = client.get_subscription(&sub_id).created_at;
+    env.ledger().with_mut(|l| l.timestamp += 2 * 86_400);
+    client.pause_subscription(&sub_id, &None);
+    client.cancel_subscription(&sub_id, &None);
+    assert_eq!(client.get_tier_analytics(&tier_id).active_subscribers, 0);
+
+    client.reactivate_subscription(&sub_id, &usdc, &BillingCycle::Monthly);
+    let subscription = client.get_subscription(&sub_id);
+    assert_eq!(subscription.status, MembershipStatus::Active);
+    assert_eq!(subscription.created_at, created_at);
+    assert_eq!(subscription.pause_count, 0);
+    assert_eq!(subscription.paused_at, None);
+    assert_eq!(subscription.amount, 100_000);
+    assert_eq!(
+        subscription.expires_at,
+        env.ledger().timestamp() + 30 * 86_400
+    );
+    assert_eq!(client.get_tier_analytics(&tier_id).active_subscribers, 1);
+
+    let history = client.get_billing_history(&sub_id, &0, &10);
+    assert_eq!(history.len(), 2);
+    assert_eq!(history.get(1).unwrap().kind, BillingKind::Reactivate);
+
+    // A live subscription is renewed, not reactivated
+    assert_eq!(
+        client.try_reactivate_subscription(&sub_id, &usdc, &BillingCycle::Monthly),
+        Err(Ok(Error::SubscriptionAlreadyExists.into()))
+    );
+
+    // Expired subscriptions come back at the tier's current price
+    client.update_tier(
+        &admin,
+        &crate::types::UpdateTierParams {
+            id: tier_id.clone(),
+            name: None,
+            price: None,
+            annual_price: Some(900_000),
+            features: None,
+            max_users: None,
+            max_storage: None,
+            is_active: None,
+            grandfather_existing: None,
+        },
+    );
+    env.ledger().with_mut(|l| l.timestamp += 31 * 86_400);
+    client.reactivate_subscription(&sub_id, &usdc, &BillingCycle::Annual);
+    let subscription = client.get_subscription(&sub_id);
+    assert_eq!(subscription.billing_cycle, BillingCycle::Annual);
+    assert_eq!(subscription.amount, 900_000);
+    assert_eq!(subscription.locked_price, 900_000);
+
+    // Legacy subscriptions have no tier price to charge
+    let legacy = String::from_str(&env, "sub_legacy");
+    client.create_subscription(&legacy, &user, &usdc, &100_000, &2_592_000);
+    client.cancel_subscription(&legacy, &None);
+    assert_eq!(
+        client.try_reactivate_subscription(&legacy, &usdc, &BillingCycle::Monthly),
+        Err(Ok(Error::TierNotFound.into()))
+    );
+}
+
+#[test]
+fn test_subscription_indexes_by_user_and_tier() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin, usdc, basic_id) = setup_multi_asset_env(&env);
+    let pro_id = create_pro_tier(&env, &client, &admin);
+    let user = Address::generate(&env);
+    let other = Address::generate(&env);
+
+    let ids = [
+        String::from_str(&env, "sub_idx_0"),
+        String::from_str(&env, "sub_idx_1"),
+        String::from_str(&env, "sub_idx_2"),
+    ];
+    for (id, owner) in ids.iter().zip([&user, &user, &other]) {
+        client.create_subscription_with_tier(
+            id,
+            owner,
+            &usdc,
+            &basic_id,
+            &BillingCycle::Monthly,
+            &None,
+        );
+    }
+
+    let mine = client.get_subscriptions_for_user(&user);
+    assert_eq!(mine.len(), 2);
+    assert_eq!(mine.get(0).unwrap().id, ids[0]);
+    assert_eq!(mine.get(1).unwrap().id, ids[1]);
+    assert_eq!(client.get_subscriptions_for_user(&other).len(), 1);
+
+    let page = client.get_subscribers_for_tier(&basic_id, &1, &10);
+    assert_eq!(page.len(), 2);
+    assert_eq!(page.get(0).unwrap().id, ids[1]);
+    assert_eq!(client.get_subscribers_for_tier(&basic_id, &0, &1).len(), 1);
+    assert_eq!(client.get_subscribers_for_tier(&basic_id, &5, &10).len(), 0);
+
+    // Tier changes move the subscription between tier lists
+    let change_id = client.request_tier_change(&user, &ids[0], &pro_id);
+    client.process_tier_change(&admin, &change_id, &ids[0], &usdc);
+    assert_eq!(client.get_subscribers_for_tier(&basic_id, &0, &10).len(), 2);
+    let pro = client.get_subscribers_for_tier(&pro_id, &0, &10);
+    assert_eq!(pro.len(), 1);
+    assert_eq!(pro.get(0).unwrap().id, ids[0]);
+
+    // Cancelling leaves the tier list but not the user's list
+    client.cancel_subscription(&ids[1], &None);
+    let basic = client.get_subscribers_for_tier(&basic_id, &0, &10);
+    assert_eq!(basic.len(), 1);
+    assert_eq!(basic.get(0).unwrap().id, ids[2]);
+    let mine = client.get_subscriptions_for_user(&user);
+    assert_eq!(mine.len(), 2);
+    assert_eq!(mine.get(1).unwrap().status, MembershipStatus::Inactive);
+
+    client.reactivate_subscription(&ids[1], &usdc, &BillingCycle::Monthly);
+    assert_eq!(client.get_subscribers_for_tier(&basic_id, &0, &10).len(), 2);
+}
+
+#[test]
+fn test_deprecated_tier_migrates_subscribers_in_batches() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin, usdc, basic_id) = setup_multi_asset_env(&env);
+    let pro_id = create_pro_tier(&env, &client, &admin);
+    let users = [Address::generate(&env), Address::generate(&env)];
+    let ids = [
+        String::from_str(&env, "sub_dep_0"),
+        String::from_str(&env, "sub_dep_1"),
+    ];
+    for (id, user) in ids.iter().zip(users.iter()) {
+        client.create_subscription_with_tier(
+            id,
+            user,
+            &usdc,
+            &basic_id,
+            &BillingCycle::Monthly,
+            &None,
+        );
+    }
+
+    let now = env.ledger().timestamp();
+    assert_eq!(
+        client.try_deprecate_tier(&admin, &basic_id, &basic_id, &(now + 86_400)),
+        Err(Ok(TierChangeError::InvalidReplacementTier.into()))
+    );
+    assert_eq!(
+        client.try_deprecate_tier(&admin, &basic_id, &pro_id, &now),
+        Err(Ok(TierChangeError::InvalidMigrationTime.into()))
+    );
+    client.deprecate_tier(&admin, &basic_id, &pro_id, &(now + 86_400));
+    assert_eq!(
+        client.try_deprecate_tier(&admin, &basic_id, &pro_id, &(now + 86_400)),
+        Err(Ok(TierChangeError::TierAlreadyDeprecated.into()))
+    );
+    let plan = client.get_tier_deprecation(&basic_id).unwrap();
+    assert_eq!(plan.replacement_tier_id, pro_id);
+    assert_eq!(plan.migrate_at, now + 86_400);
+
+    // New subscriptions are blocked immediately
+    assert_eq!(
+        client.try_create_subscription_with_tier(
+            &String::from_str(&env, "sub_dep_new"),
+            &users[0],
+            &usdc,
+            &basic_id,
+            &BillingCycle::Monthly,
+            &None,
+        ),
+        Err(Ok(Error::TierNotActive.into()))
+    );
+    assert_eq!(
+        client.try_migrate_deprecated_tier(&admin, &basic_id, &10),
+        Err(Ok(TierChangeError::MigrationNotDue.into()))
+    );
+
+    env.ledger().with_mut(|l| l.timestamp += 86_400);
+    assert_eq!(client.migrate_deprecated_tier(&admin, &basic_id, &1), 1);
+    let migrated = client.get_subscription(&ids[0]);
+    assert_eq!(migrated.tier_id, pro_id);
+    assert_eq!(migrated.locked_price, 300_000);
+    assert_eq!(client.get_subscription(&ids[1]).tier_id, basic_id);
+
+    assert_eq!(client.migrate_deprecated_tier(&admin, &basic_id, &10), 0);
+    assert_eq!(client.get_subscription(&ids[1]).tier_id, pro_id);
+    assert_eq!(
+        client
+            .get_tier_deprecation(&basic_id)
+            .unwrap()
+            .migrated_count,
+        2
+    );
+    assert_eq!(client.get_subscribers_for_tier(&pro_id, &0, &10).len(), 2);
+    assert_eq!(client.get_tier_analytics(&basic_id).active_subscribers, 0);
+    assert_eq!(client.get_tier_analytics(&pro_id).active_subscribers, 2);
+    assert_eq!(
+        client.try_migrate_deprecated_tier(&admin, &pro_id, &10),
+        Err(Ok(TierChangeError::TierNotDeprecated.into()))
+    );
+}
+
+#[test]
+fn test_compare_and_recommend_tiers() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin, usdc, basic_id) = setup_multi_asset_env(&env);
+    let pro_id = create_pro_tier(&env, &client, &admin);
+    client.set_tier_guest_quota(&admin, &pro_id, &5);
+
+    let diff = client.compare_tiers(&basic_id, &pro_id);
+    assert_eq!(diff.price_difference, 200_000);
+    assert_eq!(diff.annual_price_difference, 2_000_000);
+    assert_eq!(
+        diff.only_in_a,
+        soroban_sdk::vec![&env, common_types::TierFeature::BasicAccess]
+    );
+    assert_eq!(
+        diff.only_in_b,
+        soroban_sdk::vec![&env, common_types::TierFeature::AdvancedAnalytics]
+    );
+    assert!(diff.shared_features.is_empty());
+    assert_eq!((diff.guest_quota_a, diff.guest_quota_b), (0, 5));
+    assert_eq!(
+        client.try_compare_tiers(&basic_id, &String::from_str(&env, "missing")),
+        Err(Ok(Error::TierNotFound))
+    );
+
+    // Without observed usage the cheapest tier wins
+    let user = Address::generate(&env);
+    let advice = client.recommend_tier(&user).unwrap();
+    assert_eq!(advice.tier_id, basic_id);
+    assert_eq!(advice.billing_cycle, BillingCycle::Monthly);
+    assert_eq!(advice.household_size, 1);
+
+    // Guest visits need a tier with a guest quota
+    let sub_id = String::from_str(&env, "sub_advice");
+    client.create_subscription_with_tier(
+        &sub_id,
+        &user,
+        &usdc,
+        &pro_id,
+        &BillingCycle::Monthly,
+        &None,
+    );
+    client.invite_guest(
+        &sub_id,
+        &Address::generate(&env),
+        &(env.ledger().timestamp() + 86_400),
+    );
+    let advice = client.recommend_tier(&user).unwrap();
+    assert_eq!(advice.tier_id, pro_id);
+    assert_eq!(advice.guest_visits, 1);
+
+    // Frequent attendees are pointed at annual billing
+    for _ in 0..8 {
+        client.log_attendance(
+            &BytesN::<32>::random(&env),
+            &user,
+            &AttendanceAction::ClockIn,
+            &soroban_sdk::Map::new(&env),
+        );
+    }
+    let advice = client.recommend_tier(&user).unwrap();
+    assert_eq!(advice.monthly_attendance, 8);
+    assert_eq!(advice.billing_cycle, BillingCycle::Annual);
+    assert_eq!(advice.price, 3_000_000);
+}
+
+#[test]
+fn test_promotion_analytics_track_redemptions_and_conversions() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin, usdc, basic_id) = setup_multi_asset_env(&env);
+    let promo_id = String::from_str(&env, "promo_spring");
+    let promo_code = String::from_str(&env, "SPRING20");
+    client.create_promotion(
+        &admin,
+        &CreatePromotionParams {
+            promo_id: promo_id.clone(),
+            tier_id: basic_id.clone(),
+            discount_percent: 20,
+            promo_price: 0,
+            start_date: 0,
+            end_date: 86_400 * 365,
+            promo_code: promo_code.clone(),
+            max_redemptions: 0,
+        },
+    );
+    let empty = client.get_promotion_analytics(&promo_id);
+    assert_eq!(empty.redemptions, 0);
+    assert_eq!(empty.conversion_rate_bps, 0);
+
+    let ids = [
+        String::from_str(&env, "sub_spring_0"),
+        String::from_str(&env, "sub_spring_1"),
+    ];
+    for id in ids.iter() {
+        client.create_subscription_with_tier(
+            id,
+            &Address::generate(&env),
+            &usdc,
+            &basic_id,
+            &BillingCycle::Monthly,
+            &Some(promo_code.clone()),
+        );
+    }
+    // Subscriptions without the code are not attributed
+    client.create_subscription_with_tier(
+        &String::from_str(&env, "sub_spring_full"),
+        &Address::generate(&env),
+        &usdc,
+        &basic_id,
+        &BillingCycle::Monthly,
+        &None,
+    );
+
+    let analytics = client.get_promotion_analytics(&promo_id);
+    assert_eq!(analytics.redemptions, 2);
+    assert_eq!(analytics.gross_discount, 40_000);
+    assert_eq!(analytics.revenue, 160_000);
+    assert_eq!(analytics.conversions, 0);
+
+    // Only the first renewal converts; later ones add revenue
+    client.renew_subscription(&ids[0], &usdc, &100_000, &2_592_000);
+    client.renew_subscription(&ids[0], &usdc, &100_000, &2_592_000);
+    let analytics = client.get_promotion_analytics(&promo_id);
+    assert_eq!(analytics.conversions, 1);
+    assert_eq!(analytics.conversion_rate_bps, 5_000);
+    assert_eq!(analytics.revenue, 360_000);
+
+    assert_eq!(
+        client.try_get_promotion_analytics(&String::from_str(&env, "promo_missing")),
+        Err(Ok(Error::PromotionNotFound))
+    );
+}
+
+#[test]
+fn test_legacy_token_metadata_layout_is_upgraded_on_read() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(Contract, ());
+    let client = ContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    client.set_admin(&admin);
+
+    let owner = Address::generate(&env);
+    let token_id = BytesN::<32>::random(&env);
+    client.issue_token(&token_id, &owner, &(env.ledger().timestamp() + 86_400));
+
+    // Metadata written before updater tracking and content URIs existed
+    let mut attributes = Map::new(&env);
+    attributes.set(
+        String::from_str(&env, "level"),
+        common_types::MetadataValue::Number(3),
+    );
+    let legacy = common_types::TokenMetadataV1 {
+        description: String::from_str(&env, "Legacy member"),
+        attributes: attributes.clone(),
+        version: 2,
+        last_updated: 500,
+    };
+    env.as_contract(&contract_id, || {
+        env.storage().persistent().set(
+            &crate::membership_token::DataKey::Metadata(token_id.clone()),
+            &legacy,
+        );
+    });
+
+    let metadata = client.get_token_metadata(&token_id);
+    assert_eq!(
+        metadata.description,
+        String::from_str(&env, "Legacy member")
+    );
+    assert_eq!(metadata.attributes, attributes);
+    assert_eq!(metadata.version, 2);
+    assert_eq!(metadata.updated_by, owner);
+    assert_eq!(metadata.token_uri, String::from_str(&env, ""));
+
+    // The upgraded entry is written back and further updates build on it
+    client.set_token_metadata(
+        &admin,
+        &token_id,
+        &String::from_str(&env, "Current member"),
+        &Map::new(&env),
+    );
+    let metadata = client.get_token_metadata(&token_id);
+    assert_eq!(metadata.version, 3);
+    assert_eq!(metadata.updated_by, admin);
+}
+
+#[test]
+fn test_session_booking_capacity_cancellation_check_in_and_no_shows() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, admin, usdc, tier_id) = setup_multi_asset_env(&env);
+    let member1 = Address::generate(&env);
+    let member2 = Address::generate(&env);
+    let sub1 = String::from_str(&env, "sub_book_1");
+    let sub2 = String::from_str(&env, "sub_book_2");
+    for (sub_id, member) in [(&sub1, &member1), (&sub2, &member2)] {
+        client.create_subscription_with_tier(
+            sub_id,
+            member,
+            &usdc,
+            &tier_id,
+            &BillingCycle::Monthly,
+            &None,
+        );
+    }
+    client.set_tier_booking_quota(&admin, &tier_id, &2);
+
+    let start = env.ledger().timestamp() + 86_400;
+    let session = |id: &str, features: Vec<TierFeature>| CreateSessionParams {
+        id: String::from_str(&env, id),
+        title: String::from_str(&env, "Morning class"),
+        start_time: start,
+        end_time: start + 3_600,
+        capacity: 1,
+        required_features: features,
+    };
+    let yoga = String::from_str(&env, "yoga");
+    let spin = String::from_str(&env, "spin");
+    let analytics = String::from_str(&env, "analytics_ws");
+    client.create_session(&admin, &session("yoga", Vec::new(&env)));
+    client.create_session(&admin, &session("spin", Vec::new(&env)));
+    client.create_session(
+        &admin,
+        &session(
+            "analytics_ws",
+            soroban_sdk::vec![&env, TierFeature::AdvancedAnalytics],
+        ),
+    );
+
+    client.book_session(&sub1, &yoga);
+    assert_eq!(
+        client.try_book_session(&sub2, &yoga),
+        Err(Ok(BookingError::SessionFull.into()))
+    );
+    assert_eq!(
+        client.try_book_session(&sub1, &analytics),
+        Err(Ok(Error::FeatureNotAvailable.into()))
+    );
+    assert_eq!(client.get_booking_usage(&sub1).used, 1);
+
+    // Cancelling before the cutoff frees the seat and the quota
+    client.cancel_booking(&sub1, &yoga);
+    assert_eq!(client.get_booking_usage(&sub1).used, 0);
+    assert_eq!(client.get_session(&yoga).booked, 0);
+    client.book_session(&sub2, &yoga);
+    client.book_session(&sub1, &spin);
+
+    let cutoff = client.get_booking_cancel_cutoff();
+    env.ledger()
+        .with_mut(|li| li.timestamp = start - cutoff + 1);
+    assert_eq!(
+        client.try_cancel_booking(&sub2, &yoga),
+        Err(Ok(BookingError::BookingClosed.into()))
+    );
+
+    // Check-in only opens shortly before the session and needs a booking
+    let log_id = BytesN::<32>::random(&env);
+    assert_eq!(
+        client.try_check_in_session(&log_id, &sub2, &yoga),
+        Err(Ok(BookingError::CheckInClosed.into()))
+    );
+    env.ledger().with_mut(|li| li.timestamp = start);
+    assert_eq!(
+        client.try_check_in_session(&log_id, &sub1, &yoga),
+        Err(Ok(BookingError::BookingNotActive.into()))
+    );
+    client.check_in_session(&log_id, &sub2, &yoga);
+    let log = client.get_attendance_log(&log_id).unwrap();
+    assert_eq!(log.user_id, member2);
+    assert_eq!(
+        log.details.get(String::from_str(&env, "session_id")),
+        Some(yoga.clone())
+    );
+    assert_eq!(
+        client.get_booking(&yoga, &sub2).unwrap().status,
+        BookingStatus::Attended
+    );
+
+    assert_eq!(
+        client.try_record_session_no_shows(&admin, &spin),
+        Err(Ok(BookingError::SessionNotEnded.into()))
+    );
+    env.ledger().with_mut(|li| li.timestamp = start + 3_601);
+    assert_eq!(client.record_session_no_shows(&admin, &spin), 1);
+    assert_eq!(client.record_session_no_shows(&admin, &spin), 0);
+    assert_eq!(client.record_session_no_shows(&admin, &yoga), 0);
+    assert_eq!(
+        client.get_booking(&spin, &sub1).unwrap().status,
+        BookingStatus::NoShow
+    );
+    assert_eq!(client.get_no_show_count(&member1), 1);
+    assert_eq!(client.get_no_show_count(&member2), 0);
+}
+
+#[test]
+fn test_session_logs_validate_bookings_and_earn_loyalty() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, admin, usdc, tier_id) = setup_multi_asset_env(&env);
+    let member = Address::generate(&env);
+    let sub_id = String::from_str(&env, "sub_book_log");
+    client.create_subscription_with_tier(
+        &sub_id,
+        &member,
+        &usdc,
+        &tier_id,
+        &BillingCycle::Monthly,
+        &None,
+    );
+    client.set_tier_booking_quota(&admin, &tier_id, &2);
+    client.set_loyalty_earn_rate(
+        &admin,
+        &tier_id,
+        &LoyaltyEarnRate {
+            session_points: 10,
+            renewal_points: 0,
+        },
+    );
+
+    let start = env.ledger().timestamp() + 86_400;
+    let yoga = String::from_str(&env, "yoga");
+    let spin = String::from_str(&env, "spin");
+    for id in [&yoga, &spin] {
+        client.create_session(
+            &admin,
+            &CreateSessionParams {
+                id: id.clone(),
+                title: String::from_str(&env, "Morning class"),
+                start_time: start,
+                end_time: start + 3_600,
+                capacity: 5,
+                required_features: Vec::new(&env),
+            },
+        );
+    }
+    let session_details = |session_id: &String| {
+        let mut details = Map::new(&env);
+        details.set(String::from_str(&env, "session_id"), session_id.clone());
+        details
+    };
+
+    // A plain log naming a session needs a booking for it
+    env.ledger().with_mut(|li| li.timestamp = start);
+    assert_eq!(
+        client.try_log_attendance(
+            &BytesN::<32>::random(&env),
+            &member,
+            &AttendanceAction::ClockIn,
+            &session_details(&spin),
+        ),
+        Err(Ok(BookingError::BookingNotFound.into()))
+    );
+    env.ledger()
+        .with_mut(|li| li.timestamp = start - 86_400 + 60);
+    client.book_session(&sub_id, &yoga);
+    client.book_session(&sub_id, &spin);
+
+    // Booked check-ins count toward streaks and loyalty like other logs
+    env.ledger().with_mut(|li| li.timestamp = start);
+    client.check_in_session(&BytesN::<32>::random(&env), &sub_id, &yoga);
+    assert_eq!(client.get_streak(&member), 1);
+    env.ledger().with_mut(|li| li.timestamp = start + 3_600);
+    client.log_attendance(
+        &BytesN::<32>::random(&env),
+        &member,
+        &AttendanceAction::ClockOut,
+        &session_details(&yoga),
+    );
+    assert_eq!(client.get_points(&member), 10);
+
+    // A plain clock-in naming a booked session checks the booking in
+    assert_eq!(
+        client.try_log_attendance(
+            &BytesN::<32>::random(&env),
+            &member,
+            &AttendanceAction::ClockOut,
+            &session_details(&spin),
+        ),
+        Err(Ok(BookingError::BookingNotActive.into()))
+    );
+    client.log_attendance(
+        &BytesN::<32>::random(&env),
+        &member,
+        &AttendanceAction::ClockIn,
+        &session_details(&spin),
+    );
+    assert_eq!(
+        client.get_booking(&spin, &sub_id).unwrap().status,
+        BookingStatus::Attended
+    );
+    assert_eq!(
+        client.try_check_in_session(&BytesN::<32>::random(&env), &sub_id, &spin),
+        Err(Ok(BookingError::BookingNotActive.into()))
+    );
+}
+
+#[test]
+fn test_resource_reservation_conflicts_limits_and_deposits() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(Contract, ());
+    let client = ContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    client.set_admin(&admin);
+    let usdc = env.register_stellar_asset_contract_v2(admin.clone());
+    client.set_usdc_contract(&admin, &usdc.address());
+    let usdc = soroban_sdk::token::Client::new(&env, &usdc.address());
+
+    let tier_id = String::from_str(&env, "tier_basic");
+    client.create_tier(
+        &admin,
+        &CreateTierParams {
+            id: tier_id.clone(),
+            name: String::from_str(&env, "Basic"),
+            level: common_types::TierLevel::Basic,
+            price: 100_000i128,
+            annual_price: 1_000_000i128,
+            features: soroban_sdk::vec![&env, common_types::TierFeature::BasicAccess],
+            max_users: 100,
+            max_storage: 10_000_000,
+        },
+    );
+    client.set_tier_reservation_limit(&admin, &tier_id, &2);
+
+    let member1 = Address::generate(&env);
+    let member2 = Address::generate(&env);
+    let sub1 = String::from_str(&env, "sub_res_1");
+    let sub2 = String::from_str(&env, "sub_res_2");
+    for (sub_id, member) in [(&sub1, &member1), (&sub2, &member2)] {
+        client.create_subscription_with_tier(
+            sub_id,
+            member,
+            &usdc.address,
+            &tier_id,
+            &BillingCycle::Monthly,
+            &None,
+        );
+        soroban_sdk::token::StellarAssetClient::new(&env, &usdc.address).mint(member, &1_000);
+    }
+
+    let room = String::from_str(&env, "room_a");
+    let locker = String::from_str(&env, "locker_1");
+    client.register_resource(
+        &admin,
+        &RegisterResourceParams {
+            id: room.clone(),
+            name: String::from_str(&env, "Room A"),
+            kind: ResourceKind::MeetingRoom,
+            deposit: 200,
+            forfeit_on_no_show: true,
+        },
+    );
+    client.register_resource(
+        &admin,
+        &RegisterResourceParams {
+            id: locker.clone(),
+            name: String::from_str(&env, "Locker 1"),
+            kind: ResourceKind::Locker,
+            deposit: 50,
+            forfeit_on_no_show: false,
+        },
+    );
+
+    let start = env.ledger().timestamp() + 86_400;
+    let first = client.reserve_resource(&sub1, &room, &start, &(start + 3_600));
+    assert_eq!(usdc.balance(&member1), 800);
+    assert_eq!(client.get_escrowed_deposits(), 200);
+
+    // Overlapping ranges conflict; adjacent ones don't
+    assert_eq!(
+        client.try_reserve_resource(&sub2, &room, &(start + 1_800), &(start + 5_400)),
+        Err(Ok(ResourceError::TimeConflict.into()))
+    );
+    let second = client.reserve_resource(&sub2, &room, &(start + 3_600), &(start + 7_200));
+    assert_eq!(client.get_open_reservations(&room).len(), 2);
+
+    // The tier allows two open reservations per subscription
+    let locker_res = client.reserve_resource(&sub1, &locker, &start, &(start + 3_600));
+    assert_eq!(
+        client.try_reserve_resource(&sub1, &locker, &(start + 3_600), &(start + 7_200)),
+        Err(Ok(ResourceError::LimitReached.into()))
+    );
+
+    // Cancelling refunds the deposit and frees the slot
+    client.cancel_reservation(&locker_res.id);
+    assert_eq!(usdc.balance(&member1), 800);
+    assert_eq!(client.get_active_reservation_count(&sub1), 1);
+    let locker_res = client.reserve_resource(&sub1, &locker, &start, &(start + 3_600));
+
+    env.ledger().with_mut(|li| li.timestamp = start);
+    assert_eq!(
+        client.try_cancel_reservation(&first.id),
+        Err(Ok(ResourceError::ReservationStarted.into()))
+    );
+    assert_eq!(
+        client.try_settle_reservation(&first.id),
+        Err(Ok(ResourceError::ReservationNotEnded.into()))
+    );
+    client.check_in_reservation(&first.id);
+
+    env.ledger().with_mut(|li| li.timestamp = start + 7_200);
+    // Attended: deposit back
+    assert_eq!(
+        client.settle_reservation(&first.id).status,
+        ReservationStatus::Completed
+    );
+    assert_eq!(usdc.balance(&member1), 950);
+    // No-show on a forfeiting resource: deposit kept as penalty revenue
+    assert_eq!(
+        client.settle_reservation(&second.id).status,
+        ReservationStatus::Forfeited
+    );
+    assert_eq!(usdc.balance(&member2), 800);
+    assert_eq!(usdc.balance(&contract_id), 250);
+    assert_eq!(client.get_lifetime_revenue().penalties, 200);
+    // No-show on a refunding resource
+    assert_eq!(
+        client.settle_reservation(&locker_res.id).status,
+        ReservationStatus::NoShow
+    );
+    assert_eq!(usdc.balance(&member1), 1_000);
+    assert_eq!(client.get_escrowed_deposits(), 0);
+    assert_eq!(usdc.balance(&contract_id), 200);
+    assert_eq!(
+        client.try_settle_reservation(&first.id),
+        Err(Ok(ResourceError::ReservationNotActive.into()))
+    );
+    assert_eq!(client.get_open_reservations(&room).len(), 0);
+    assert_eq!(client.get_active_reservation_count(&sub1), 0);
+}
+
+#[test]
+fn test_reservation_deposits_settle_in_the_token_they_were_paid_in() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(Contract, ());
+    let client = ContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    client.set_admin(&admin);
+    let old_usdc = env.register_stellar_asset_contract_v2(admin.clone());
+    let new_usdc = env.register_stellar_asset_contract_v2(admin.clone());
+    client.set_usdc_contract(&admin, &old_usdc.address());
+    let old_usdc = soroban_sdk::token::Client::new(&env, &old_usdc.address());
+    let new_usdc = soroban_sdk::token::Client::new(&env, &new_usdc.address());
+
+    let tier_id = String::from_str(&env, "tier_basic");
+    client.create_tier(
+        &admin,
+        &CreateTierParams {
+            id: tier_id.clone(),
+            name: String::from_str(&env, "Basic"),
+            level: common_types::TierLevel::Basic,
+            price: 100_000i128,
+            annual_price: 1_000_000i128,
+            features: soroban_sdk::vec![&env, common_types::TierFeature::BasicAccess],
+            max_users: 100,
+            max_storage: 10_000_000,
+        },
+    );
+    client.set_tier_reservation_limit(&admin, &tier_id, &2);
+
+    let member = Address::generate(&env);
+    let sub_id = String::from_str(&env, "sub_res_token");
+    client.create_subscription_with_tier(
+        &sub_id,
+        &member,
+        &old_usdc.address,
+        &tier_id,
+        &BillingCycle::Monthly,
+        &None,
+    );
+    soroban_sdk::token::StellarAssetClient::new(&env, &old_usdc.address).mint(&member, &1_000);
+
+    let room = String::from_str(&env, "room_a");
+    client.register_resource(
+        &admin,
+        &RegisterResourceParams {
+            id: room.clone(),
+            name: String::from_str(&env, "Room A"),
+            kind: ResourceKind::MeetingRoom,
+            deposit: 200,
+            forfeit_on_no_show: true,
+        },
+    );
+
+    let start = env.ledger().timestamp() + 7 * 86_400;
+    let cancelled = client.reserve_resource(&sub_id, &room, &start, &(start + 3_600));
+    let forfeited = client.reserve_resource(&sub_id, &room, &(start + 3_600), &(start + 7_200));
+    assert_eq!(cancelled.deposit_token, Some(old_usdc.address.clone()));
+    assert_eq!(old_usdc.balance(&member), 600);
+
+    // Rotate the configured USDC contract while the deposits are escrowed
+    let pending = client.propose_usdc_contract(&admin, &new_usdc.address);
+    env.ledger()
+        .with_mut(|li| li.timestamp = pending.executable_at);
+    client.confirm_usdc_contract(&admin);
+
+    // The refund comes back in the original asset
+    client.cancel_reservation(&cancelled.id);
+    assert_eq!(old_usdc.balance(&member), 800);
+    assert_eq!(new_usdc.balance(&member), 0);
+
+    // A forfeited deposit is collected in the original asset too
+    env.ledger().with_mut(|li| li.timestamp = start + 7_200);
+    assert_eq!(
+        client.settle_reservation(&forfeited.id).status,
+        ReservationStatus::Forfeited
+    );
+    assert_eq!(client.get_withdrawable_revenue(&old_usdc.address), 200);
+    assert_eq!(client.get_withdrawable_revenue(&new_usdc.address), 0);
+    assert_eq!(client.get_escrowed_deposits(), 0);
+}
+
+#[test]
+fn test_staff_shifts_payroll_due_and_settlement() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(Contract, ());
+    let client = ContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    client.set_admin(&admin);
+    let usdc = env.register_stellar_asset_contract_v2(admin.clone());
+    client.set_usdc_contract(&admin, &usdc.address());
+    let usdc = soroban_sdk::token::Client::new(&env, &usdc.address());
+
+    let staff = Address::generate(&env);
+    client.register_staff(&admin, &staff, &1_200);
+    assert_eq!(
+        client.try_register_staff(&admin, &staff, &1_200),
+        Err(Ok(StaffError::StaffAlreadyRegistered.into()))
+    );
+
+    let log = |action: AttendanceAction, at: u64| {
+        env.ledger().with_mut(|li| li.timestamp = at);
+        let id = BytesN::<32>::random(&env);
+        client.log_attendance(&id, &staff, &action, &Map::new(&env));
+        id
+    };
+    let in1 = log(AttendanceAction::ClockIn, 1_000);
+    let out1 = log(AttendanceAction::ClockOut, 1_000 + 2 * 3_600);
+    let in2 = log(AttendanceAction::ClockIn, 20_000);
+    let out2 = log(AttendanceAction::ClockOut, 20_000 + 1_800);
+
+    // Logs must be a clock-in then clock-out, each used once
+    assert_eq!(
+        client.try_record_staff_shift(&admin, &out1, &in1),
+        Err(Ok(StaffError::InvalidShift.into()))
+    );
+    assert_eq!(client.record_staff_shift(&admin, &in1, &out1).amount, 2_400);
+    assert_eq!(
+        client.try_record_staff_shift(&admin, &in1, &out2),
+        Err(Ok(StaffError::ShiftAlreadyRecorded.into()))
+    );
+    // A raise only applies to shifts recorded afterwards
+    client.set_staff_hourly_rate(&admin, &staff, &2_000);
+    assert_eq!(client.record_staff_shift(&admin, &in2, &out2).amount, 1_000);
+
+    let first_period = DateRange {
+        start_time: 0,
+        end_time: 10_000,
+    };
+    let whole = DateRange {
+        start_time: 0,
+        end_time: 30_000,
+    };
+    assert_eq!(client.get_payroll_due(&staff, &first_period), 2_400);
+    assert_eq!(client.get_payroll_due(&staff, &whole), 3_400);
+
+    env.ledger().with_mut(|li| li.timestamp = 25_000);
+    assert_eq!(
+        client.try_settle_payroll(&admin, &whole),
+        Err(Ok(StaffError::InvalidPeriod.into()))
+    );
+    assert_eq!(
+        client.try_settle_payroll(&admin, &first_period),
+        Err(Ok(Error::InsufficientBalance.into()))
+    );
+
+    // Staked principal held by the contract is not payroll money
+    let usdc_admin = soroban_sdk::token::StellarAssetClient::new(&env, &usdc.address);
+    client.set_staking_config(
+        &admin,
+        &crate::types::StakingConfig {
+            staking_enabled: true,
+            emergency_unstake_penalty_bps: 0,
+            max_slash_bps: 2_000,
+            unstake_cooldown: 0,
+            staking_token: usdc.address.clone(),
+            reward_pool: Address::generate(&env),
+        },
+    );
+    client.create_staking_tier(
+        &admin,
+        &crate::types::StakingTier {
+            id: String::from_str(&env, "bronze"),
+            name: String::from_str(&env, "Bronze"),
+            min_stake_amount: 1_000,
+            lock_duration: 86_400,
+            reward_multiplier_bps: 10_000,
+            base_rate_bps: 500,
+        },
+    );
+    let staker = Address::generate(&env);
+    usdc_admin.mint(&staker, &20_000);
+    client.stake_tokens(&staker, &String::from_str(&env, "bronze"), &20_000);
+    assert_eq!(
+        client.try_settle_payroll(&admin, &first_period),
+        Err(Ok(TreasuryError::ExceedsWithdrawable.into()))
+    );
+    assert_eq!(client.get_unpaid_shifts(&staff).len(), 2);
+
+    // Collected revenue pays it
+    client.slash_stake(&admin, &staker, &2_000, &String::from_str(&env, "fraud"));
+    assert_eq!(client.get_withdrawable_revenue(&usdc.address), 4_000);
+    let payments = client.settle_payroll(&admin, &first_period);
+    assert_eq!(payments.len(), 1);
+    let payment = payments.get(0).unwrap();
+    assert_eq!(payment.amount, 2_400);
+    assert_eq!(payment.shifts, 1);
+    assert_eq!(payment.worked_secs, 7_200);
+    assert_eq!(usdc.balance(&staff), 2_400);
+
+    // Paid shifts are not paid again
+    assert_eq!(client.settle_payroll(&admin, &first_period).len(), 0);
+    assert_eq!(client.get_payroll_due(&staff, &first_period), 0);
+    assert_eq!(client.get_unpaid_shifts(&staff).len(), 1);
+
+    env.ledger().with_mut(|li| li.timestamp = 30_000);
+    client.settle_payroll(&admin, &whole);
+    assert_eq!(usdc.balance(&staff), 3_400);
+    assert_eq!(client.get_withdrawable_revenue(&usdc.address), 600);
+    assert_eq!(client.get_payroll_payments(&staff).len(), 2);
+    assert_eq!(client.get_unpaid_shifts(&staff).len(), 0);
+}
+
+#[test]
+fn test_partner_hub_tokens_are_recognized_within_visit_quota() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let home_id = env.register(Contract, ());
+    let home = ContractClient::new(&env, &home_id);
+    let host_id = env.register(Contract, ());
+    let host = ContractClient::new(&env, &host_id);
+    let admin = Address::generate(&env);
+    home.set_admin(&admin);
+    host.set_admin(&admin);
+
+    let member = Address::generate(&env);
+    let token_id = BytesN::<32>::random(&env);
+    home.issue_token(&token_id, &member, &(env.ledger().timestamp() + 86_400));
+    assert_eq!(host.verify_entry(&member).status, EntryStatus::NoMembership);
+
+    assert_eq!(
+        host.try_add_partner_hub(&admin, &host_id, &String::from_str(&env, "Self"), &1),
+        Err(Ok(FederationError::InvalidPartner.into()))
+    );
+    host.add_partner_hub(&admin, &home_id, &String::from_str(&env, "Downtown"), &1);
+    home.add_partner_hub(&admin, &host_id, &String::from_str(&env, "Uptown"), &5);
+
+    let decision = host.verify_entry(&member);
+    assert!(decision.allowed);
+    assert_eq!(decision.partner, Some(home_id.clone()));
+    assert_eq!(decision.token_id, Some(token_id));
+    // Home verification never consults partners
+    assert!(!host.verify_home_entry(&member).allowed);
+    assert_eq!(
+        host.try_log_partner_attendance(
+            &BytesN::<32>::random(&env),
+            &Address::generate(&env),
+            &home_id,
+            &AttendanceAction::ClockIn,
+            &Map::new(&env),
+        ),
+        Err(Ok(FederationError::PartnerEntryDenied.into()))
+    );
+
+    host.log_partner_attendance(
+        &BytesN::<32>::random(&env),
+        &member,
+        &home_id,
+        &AttendanceAction::ClockIn,
+        &Map::new(&env),
+    );
+    host.log_partner_attendance(
+        &BytesN::<32>::random(&env),
+        &member,
+        &home_id,
+        &AttendanceAction::ClockOut,
+        &Map::new(&env),
+    );
+    assert_eq!(host.get_partner_visits_used(&home_id, &member), 1);
+    assert_eq!(host.get_partner_usage(&home_id).inbound_visits, 1);
+    // The visit was reported back to the member's home hub
+    assert_eq!(home.get_partner_usage(&host_id).outbound_visits, 1);
+
+    let decision = host.verify_entry(&member);
+    assert!(!decision.allowed);
+    assert_eq!(decision.status, EntryStatus::PartnerQuotaExceeded);
+    assert_eq!(
+        host.try_log_partner_attendance(
+            &BytesN::<32>::random(&env),
+            &member,
+            &home_id,
+            &AttendanceAction::ClockIn,
+            &Map::new(&env),
+        ),
+        Err(Ok(FederationError::VisitQuotaExceeded.into()))
+    );
+
+    host.update_partner_hub(&admin, &home_id, &1, &false);
+    assert_eq!(host.verify_entry(&member).status, EntryStatus::NoMembership);
+}
+
+#[test]
+fn test_deploy_hub_requires_code_and_shared_multisig() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(Contract, ());
+    let client = ContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    client.set_admin(&admin);
+
+    let params = DeployHubParams {
+        salt: BytesN::from_array(&env, &[7; 32]),
+        name: String::from_str(&env, "Uptown"),
+        admin: Address::generate(&env),
+        usdc: Address::generate(&env),
+        config: default_init_config(&env),
+    };
+    assert_eq!(
+        client.try_deploy_hub(&admin, &params),
+        Err(Ok(FactoryError::HubCodeNotConfigured.into()))
+    );
+
+    let wasm_hash = BytesN::from_array(&env, &[1; 32]);
+    assert_eq!(
+        client.try_set_hub_wasm_hash(&Address::generate(&env), &wasm_hash),
+        Err(Ok(Error::Unauthorized))
+    );
+    client.set_hub_wasm_hash(&admin, &wasm_hash);
+    assert_eq!(client.get_hub_wasm_hash(), Some(wasm_hash));
+
+    // New hubs share this hub's access_control contract, so one must be set
+    assert_eq!(
+        client.try_deploy_hub(&admin, &params),
+        Err(Ok(FactoryError::MultisigNotConfigured.into()))
+    );
+    assert_eq!(
+        client.try_deploy_hub(&Address::generate(&env), &params),
+        Err(Ok(Error::Unauthorized.into()))
+    );
+    assert_eq!(client.get_hubs().len(), 0);
+    assert_eq!(
+        client.try_get_hub(&contract_id),
+        Err(Ok(FactoryError::HubNotFound.into()))
+    );
+}
+
+#[test]
+fn test_multiread_answers_each_request_in_order() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, _admin, usdc, tier_id) = setup_multi_asset_env(&env);
+    let member = Address::generate(&env);
+    let token_id = BytesN::<32>::random(&env);
+    client.issue_token(&token_id, &member, &(env.ledger().timestamp() + 86_400));
+    let sub_id = String::from_str(&env, "sub_multiread");
+    client.create_subscription_with_tier(
+        &sub_id,
+        &member,
+        &usdc,
+        &tier_id,
+        &BillingCycle::Monthly,
+        &None,
+    );
+
+    let responses = client.multiread(&soroban_sdk::vec![
+        &env,
+        ReadRequest::Token(token_id.clone()),
+        ReadRequest::TokenPaused(token_id.clone()),
+        ReadRequest::Subscription(sub_id.clone()),
+        ReadRequest::Tier(tier_id.clone()),
+        ReadRequest::Tier(String::from_str(&env, "missing")),
+        ReadRequest::StakeInfo(member.clone()),
+        ReadRequest::Entry(member.clone()),
+    ]);
+    assert_eq!(responses.len(), 7);
+    assert_eq!(
+        responses.get(0),
+        Some(ReadResponse::Token(client.get_token(&token_id)))
+    );
+    assert_eq!(responses.get(1), Some(ReadResponse::TokenPaused(false)));
+    assert_eq!(
+        responses.get(2),
+        Some(ReadResponse::Subscription(client.get_subscription(&sub_id)))
+    );
+    assert_eq!(
+        responses.get(3),
+        Some(ReadResponse::Tier(client.get_tier(&tier_id)))
+    );
+    assert_eq!(
+        responses.get(4),
+        Some(ReadResponse::Failed(Error::TierNotFound as u32))
+    );
+    assert_eq!(responses.get(5), Some(ReadResponse::Empty));
+    assert!(matches!(
+        responses.get(6),
+        Some(ReadResponse::Entry(decision)) if decision.allowed
+    ));
+
+    assert_eq!(
+        client.try_multiread(&Vec::new(&env)),
+        Err(Ok(Error::Unauthorized))
+    );
+}
+
+#[test]
+fn test_export_member_snapshot_aggregates_holdings() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, admin, usdc, tier_id) = setup_multi_asset_env(&env);
+    let member = Address::generate(&env);
+    let co_owner = Address::generate(&env);
+    let token_id = BytesN::<32>::random(&env);
+    client.issue_token(&token_id, &member, &(env.ledger().timestamp() + 86_400));
+    client.fractionalize_token(&token_id, &1000, &100);
+    client.transfer_fraction(&token_id, &member, &co_owner, &300);
+    let sub_id = String::from_str(&env, "sub_snapshot");
+    client.create_subscription_with_tier(
+        &sub_id,
+        &member,
+        &usdc,
+        &tier_id,
+        &BillingCycle::Monthly,
+        &None,
+    );
+
+    let snapshot = client.export_member_snapshot(&member);
+    assert_eq!(snapshot.user, member);
+    assert_eq!(snapshot.tokens.len(), 1);
+    assert_eq!(snapshot.tokens.get(0).unwrap().id, token_id);
+    assert_eq!(snapshot.active_subscriptions.len(), 1);
+    assert_eq!(snapshot.active_subscriptions.get(0).unwrap().id, sub_id);
+    assert_eq!(snapshot.stakes.len(), 0);
+    assert_eq!(snapshot.fraction_holdings.len(), 1);
+    assert_eq!(snapshot.fraction_holdings.get(0).unwrap().shares, 700);
+    assert_eq!(snapshot.credit_balance, 0);
+
+    // The co-owner only holds shares, so it isn't in the member index.
+    let co_snapshot = client.export_member_snapshot(&co_owner);
+    assert_eq!(co_snapshot.tokens.len(), 0);
+    assert_eq!(co_snapshot.fraction_holdings.get(0).unwrap().shares, 300);
+    assert_eq!(client.get_member_count(), 1);
+
+    let other = Address::generate(&env);
+    client.issue_token(
+        &BytesN::<32>::random(&env),
+        &other,
+        &(env.ledger().timestamp() + 86_400),
+    );
+    assert_eq!(client.get_member_count(), 2);
+    let page = client.export_member_snapshots(&admin, &1, &10);
+    assert_eq!(page.len(), 1);
+    assert_eq!(page.get(0).unwrap().user, other);
+    assert_eq!(client.export_member_snapshots(&admin, &0, &10).len(), 2);
+
+    assert_eq!(
+        client.try_export_member_snapshots(&member, &0, &10),
+        Err(Ok(Error::Unauthorized))
+    );
+}
+
+#[test]
+fn test_redact_attendance_details_keeps_hash() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(Contract, ());
+    let client = ContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let user = Address::generate(&env);
+    let stranger = Address::generate(&env);
+    client.set_admin(&admin);
+
+    let details = map![
+        &env,
+        (
+            String::from_str(&env, "location"),
+            String::from_str(&env, "office")
+        )
+    ];
+    let first = BytesN::<32>::random(&env);
+    let second = BytesN::<32>::random(&env);
+    client.log_attendance(&first, &user, &AttendanceAction::ClockIn, &details);
+    client.log_attendance(&second, &user, &AttendanceAction::ClockOut, &details);
+
+    assert_eq!(
+        client.try_redact_log_details(&stranger, &first),
+        Err(Ok(Error::Unauthorized.into()))
+    );
+    let expected: BytesN<32> = env
+        .crypto()
+        .sha256(&soroban_sdk::xdr::ToXdr::to_xdr(details.clone(), &env))
+        .into();
+    assert_eq!(client.redact_log_details(&user, &first), expected);
+    assert_eq!(client.get_log_details_hash(&first), Some(expected.clone()));
+    assert!(client
+        .get_attendance_log(&first)
+        .unwrap()
+        .details
+        .is_empty());
+    assert!(client
+        .get_logs_for_user(&user)
+        .get(0)
+        .unwrap()
+        .details
+        .is_empty());
+    // Redacting again returns the stored hash
+    assert_eq!(client.redact_log_details(&admin, &first), expected);
+
+    // Bulk redaction only counts logs not already redacted
+    assert_eq!(client.redact_user_log_details(&admin, &user, &0, &10), 1);
+    assert!(client
+        .get_attendance_log(&second)
+        .unwrap()
+        .details
+        .is_empty());
+    assert_eq!(
+        client.try_redact_log_details(&user, &BytesN::<32>::random(&env)),
+        Err(Ok(AttendanceError::LogNotFound.into()))
+    );
+
+    // Hash-only storage keeps the hash but never the details
+    client.set_hash_details_on_write(&admin, &true);
+    assert!(client.is_hash_details_on_write());
+    let third = BytesN::<32>::random(&env);
+    client.log_attendance(&third, &user, &AttendanceAction::ClockIn, &details);
+    assert!(client
+        .get_attendance_log(&third)
+        .unwrap()
+        .details
+        .is_empty());
+    assert_eq!(client.get_log_details_hash(&third), Some(expected));
+}
+
+#[test]
+fn test_attendance_detail_rules_are_enforced() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(Contract, ());
+    let client = ContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let user = Address::generate(&env);
+    client.set_admin(&admin);
+
+    let location = String::from_str(&env, "location");
+    let note = String::from_str(&env, "note");
+    let rules = AttendanceDetailRules {
+        max_entries: 2,
+        max_key_len: 8,
+        max_value_len: 10,
+        allowed_keys: soroban_sdk::vec![&env, location.clone(), note.clone()],
+        required_keys: map![
+            &env,
+            (
+                AttendanceAction::ClockIn,
+                soroban_sdk::vec![&env, location.clone()]
+            )
+        ],
+    };
+    assert_eq!(
+        client.try_set_attendance_detail_rules(
+            &admin,
+            &AttendanceDetailRules {
+                max_entries: 51,
+                ..rules.clone()
+            }
+        ),
+        Err(Ok(AttendanceError::InvalidDetailRules.into()))
+    );
+    client.set_attendance_detail_rules(&admin, &rules);
+    assert_eq!(client.get_attendance_detail_rules(), Some(rules));
+
+    let office = String::from_str(&env, "office");
+    let log = |details: &Map<String, String>, action: AttendanceAction| {
+        client.try_log_attendance(&BytesN::<32>::random(&env), &user, &action, details)
+    };
+    assert_eq!(
+        log(&Map::new(&env), AttendanceAction::ClockIn),
+        Err(Ok(AttendanceError::MissingRequiredDetail.into()))
+    );
+    assert_eq!(
+        log(
+            &map![&env, (String::from_str(&env, "device"), office.clone())],
+            AttendanceAction::ClockOut
+        ),
+        Err(Ok(AttendanceError::DetailKeyNotAllowed.into()))
+    );
+    assert_eq!(
+        log(
+            &map![&env, (String::from_str(&env, "long_key_x"), office.clone())],
+            AttendanceAction::ClockOut
+        ),
+        Err(Ok(AttendanceError::DetailKeyTooLong.into()))
+    );
+    assert_eq!(
+        log(
+            &map![
+                &env,
+                (
+                    location.clone(),
+                    String::from_str(&env, "main office floor")
+                )
+            ],
+            AttendanceAction::ClockIn
+        ),
+        Err(Ok(AttendanceError::DetailValueTooLong.into()))
+    );
+    assert_eq!(
+        log(
+            &map![
+                &env,
+                (location.clone(), office.clone()),
+                (note.clone(), office.clone()),
+                (String::from_str(&env, "x"), office.clone())
+            ],
+            AttendanceAction::ClockIn
+        ),
+        Err(Ok(AttendanceError::TooManyDetails.into()))
+    );
+    assert!(log(&map![&env, (location, office)], AttendanceAction::ClockIn).is_ok());
+    assert!(log(&Map::new(&env), AttendanceAction::ClockOut).is_ok());
+}
+
+#[test]
+fn test_notification_queue_and_acknowledgement() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(Contract, ());
+    let client = ContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let user = Address::generate(&env);
+    let payment_token = Address::generate(&env);
+    let token_id = BytesN::<32>::random(&env);
+    client.set_admin(&admin);
+    client.set_usdc_contract(&admin, &payment_token);
+
+    client.issue_token(&token_id, &user, &(env.ledger().timestamp() + 100));
+    let sub_id = String::from_str(&env, "sub_notify");
+    client.create_subscription(&sub_id, &user, &payment_token, &100_000, &2_592_000);
+    env.ledger().with_mut(|l| l.timestamp += 86_400);
+
+    let token = client.check_and_apply_grace_period(&token_id);
+    client.pause_subscription(&sub_id, &None);
+    assert_eq!(
+        client.try_notify_pause_expiring(&sub_id),
+        Err(Ok(Error::PauseTooEarly))
+    );
+    let paused_at = env.ledger().timestamp();
+    env.ledger().with_mut(|l| l.timestamp += 28 * 86_400);
+    assert!(client.notify_pause_expiring(&sub_id));
+    assert!(!client.notify_pause_expiring(&sub_id));
+
+    let notifications = client.get_notifications(&user, &0, &10);
+    assert_eq!(notifications.len(), 2);
+    let grace = notifications.get(0).unwrap();
+    assert_eq!(grace.seq, 1);
+    assert_eq!(grace.kind, NotificationKind::GracePeriodEntered);
+    assert_eq!(grace.subject, OutboxSubject::Token(token_id));
+    assert_eq!(grace.due_at, token.grace_period_expires_at.unwrap());
+    let pause = notifications.get(1).unwrap();
+    assert_eq!(pause.kind, NotificationKind::PauseExpiring);
+    assert_eq!(pause.due_at, paused_at + 2_592_000);
+    assert_eq!(
+        client.get_notifications(&user, &1, &10),
+        soroban_sdk::vec![&env, pause]
+    );
+
+    assert_eq!(client.ack_notifications(&user, &1), 1);
+    assert_eq!(client.ack_notifications(&user, &1), 0);
+    assert_eq!(client.get_unread_notification_count(&user), 1);
+    assert_eq!(
+        client.get_notifications(&user, &0, &10).get(0).unwrap().seq,
+        2
+    );
+    assert_eq!(client.ack_notifications(&user, &100), 1);
+    assert_eq!(client.get_notifications(&user, &0, &10).len(), 0);
+}
+
+#[test]
+fn test_tier_grace_policy_overrides_renewal_config() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, admin, usdc, tier_id) = setup_multi_asset_env(&env);
+    let user = Address::generate(&env);
+    let token_id = BytesN::<32>::random(&env);
+    client.issue_token(&token_id, &user, &(env.ledger().timestamp() + 100));
+    client.renew_token(&token_id, &usdc, &tier_id, &BillingCycle::Monthly);
+    let sub_id = String::from_str(&env, "sub_grace_policy");
+    client.create_subscription_with_tier(
+        &sub_id,
+        &user,
+        &usdc,
+        &tier_id,
+        &BillingCycle::Monthly,
+        &None,
+    );
+
+    // Without an override the global config applies
+    let fallback = client.get_grace_policy(&tier_id);
+    assert_eq!(fallback.duration, 7 * 24 * 60 * 60);
+    assert!(fallback.block_transfers);
+    assert_eq!(fallback.feature_access, GraceFeatureAccess::Suspended);
+
+    let policy = GracePolicy {
+        duration: 3 * 86_400,
+        block_transfers: false,
+        feature_access: GraceFeatureAccess::Reduced(soroban_sdk::vec![
+            &env,
+            common_types::TierFeature::BasicAccess
+        ]),
+    };
+    assert_eq!(
+        client.try_set_tier_grace_policy(&admin, &String::from_str(&env, "missing"), &policy),
+        Err(Ok(Error::TierNotFound))
+    );
+    client.set_tier_grace_policy(&admin, &tier_id, &policy);
+    assert_eq!(client.get_grace_policy(&tier_id), policy);
+
+    env.ledger().with_mut(|l| l.timestamp += 31 * 86_400);
+    let token = client.check_and_apply_grace_period(&token_id);
+    assert_eq!(token.status, MembershipStatus::GracePeriod);
+    assert_eq!(
+        token.grace_period_expires_at,
+        Some(env.ledger().timestamp() + 3 * 86_400)
+    );
+    // Lapsed subscriptions keep only the reduced feature set
+    assert!(client.check_feature_access(&sub_id, &common_types::TierFeature::BasicAccess));
+    assert!(!client.check_feature_access(&sub_id, &common_types::TierFeature::ApiAccess));
+
+    // The tier allows transfers during grace
+    let buyer = Address::generate(&env);
+    client.transfer_token(&token_id, &buyer);
+    assert_eq!(client.get_token(&token_id).user, buyer);
+
+    client.remove_tier_grace_policy(&admin, &tier_id);
+    assert!(!client.check_feature_access(&sub_id, &common_types::TierFeature::BasicAccess));
+    assert_eq!(
+        client.try_transfer_token(&token_id, &user),
+        Err(Ok(ModuleError::from(
+            Error::TransferNotAllowedInGracePeriod
+        )))
+    );
+    assert_eq!(
+        client.try_remove_tier_grace_policy(&admin, &tier_id),
+        Err(Ok(Error::TierNotFound))
+    );
+}
+
+#[test]
+fn test_auto_renewal_price_cap_waits_for_consent() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, _admin, usdc, tier_id) = setup_multi_asset_env(&env);
+    let user = Address::generate(&env);
+    let token_id = BytesN::<32>::random(&env);
+    client.issue_token(&token_id, &user, &(env.ledger().timestamp() + 100));
+    client.renew_token(&token_id, &usdc, &tier_id, &BillingCycle::Monthly);
+    let expiry = client.get_token(&token_id).expiry_date;
+
+    assert_eq!(
+        client.try_set_auto_renewal(&token_id, &true, &usdc, &-1),
+        Err(Ok(Error::InvalidPaymentAmount))
+    );
+    client.set_auto_renewal(&token_id, &true, &usdc, &50_000);
+    assert_eq!(
+        client.get_auto_renewal_settings(&user).unwrap().max_amount,
+        50_000
+    );
+
+    // The tier price (100_000) is above the cap: renewal waits for consent
+    env.ledger().with_mut(|l| l.timestamp = expiry - 3_600);
+    client.process_auto_renewal(&token_id);
+    let pending = client.get_pending_renewal_consent(&token_id).unwrap();
+    assert_eq!(pending.required_amount, 100_000);
+    assert_eq!(pending.max_amount, 50_000);
+    let token = client.get_token(&token_id);
+    assert_eq!(token.status, MembershipStatus::Active);
+    assert_eq!(token.expiry_date, expiry);
+
+    // Raising the cap is recorded as consent and lets the renewal through
+    client.set_auto_renewal(&token_id, &true, &usdc, &100_000);
+    assert_eq!(client.get_pending_renewal_consent(&token_id), None);
+    let consents = client.get_renewal_consents(&token_id);
+    assert_eq!(consents.len(), 2);
+    assert_eq!(consents.get(1).unwrap().max_amount, 100_000);
+    client.process_auto_renewal(&token_id);
+    assert!(client.get_token(&token_id).expiry_date > expiry);
+
+    // Settings saved before caps existed read back as uncapped
+    let legacy_user = Address::generate(&env);
+    env.as_contract(&client.address, || {
+        env.storage().persistent().set(
+            &membership_token::DataKey::AutoRenewalSettings(legacy_user.clone()),
+            &types::LegacyAutoRenewalSettings {
+                enabled: true,
+                token_id: token_id.clone(),
+                payment_token: usdc.clone(),
+                updated_at: 1,
+            },
+        );
+    });
+    let legacy = client.get_auto_renewal_settings(&legacy_user).unwrap();
+    assert!(legacy.enabled);
+    assert_eq!(legacy.max_amount, 0);
+}
+
+#[test]
+fn test_auto_renewal_candidates_and_renewal_window() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, _admin, usdc, tier_id) = setup_multi_asset_env(&env);
+    let user = Address::generate(&env);
+    let token_id = BytesN::<32>::random(&env);
+    client.issue_token(&token_id, &user, &(env.ledger().timestamp() + 100));
+    client.renew_token(&token_id, &usdc, &tier_id, &BillingCycle::Monthly);
+    client.set_auto_renewal(&token_id, &true, &usdc, &0);
+    let other = Address::generate(&env);
+    let other_token = BytesN::<32>::random(&env);
+    client.issue_token(&other_token, &other, &(env.ledger().timestamp() + 100));
+    client.renew_token(&other_token, &usdc, &tier_id, &BillingCycle::Monthly);
+    let expiry = client.get_token(&token_id).expiry_date;
+
+    let window = client.get_renewal_window(&token_id);
+    assert_eq!(window.opens_at, expiry - 86_400);
+    assert_eq!(window.closes_at, expiry);
+    assert_eq!(window.grace_deadline, expiry + 7 * 86_400);
+    assert!(window.auto_renewal_enabled);
+    assert!(!client.get_renewal_window(&other_token).auto_renewal_enabled);
+
+    // Nothing is due before the window opens
+    assert_eq!(client.list_auto_renewal_candidates(&0, &10).len(), 0);
+
+    // Only the token with auto-renewal enabled is listed once it opens
+    env.ledger().with_mut(|l| l.timestamp = expiry - 3_600);
+    let candidates = client.list_auto_renewal_candidates(&0, &10);
+    assert_eq!(candidates, soroban_sdk::vec![&env, token_id.clone()]);
+    assert_eq!(client.list_auto_renewal_candidates(&1, &10).len(), 0);
+
+    // Renewing moves the token out of the window
+    client.process_auto_renewal(&token_id);
+    assert_eq!(client.list_auto_renewal_candidates(&0, &10).len(), 0);
+    assert!(client.get_renewal_window(&token_id).closes_at > expiry);
+}
+
+#[test]
+fn test_scheduled_pause_is_applied_and_ended_by_keeper() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(Contract, ());
+    let client = ContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let user = Address::generate(&env);
+    let payment_token = Address::generate(&env);
+    let subscription_id = String::from_str(&env, "sub_scheduled_pause");
+    client.set_admin(&admin);
+    client.set_usdc_contract(&admin, &payment_token);
+    client.create_subscription(
+        &subscription_id,
+        &user,
+        &payment_token,
+        &100_000,
+        &2_592_000,
+    );
+    let expires_at = client.get_subscription(&subscription_id).expires_at;
+
+    let now = env.ledger().timestamp();
+    let start_at = now + 2 * 86_400;
+    assert_eq!(
+        client.try_schedule_pause(&subscription_id, &now, &86_400),
+        Err(Ok(PauseScheduleError::InvalidStartTime.into()))
+    );
+    assert_eq!(
+        client.try_schedule_pause(&subscription_id, &start_at, &(31 * 86_400)),
+        Err(Ok(PauseScheduleError::InvalidDuration.into()))
+    );
+    client.schedule_pause(&subscription_id, &start_at, &(5 * 86_400));
+    assert_eq!(
+        client.try_schedule_pause(&subscription_id, &start_at, &86_400),
+        Err(Ok(PauseScheduleError::AlreadyScheduled.into()))
+    );
+
+    // Cancelling before the start frees the slot for a new booking
+    client.cancel_scheduled_pause(&subscription_id);
+    assert_eq!(client.get_scheduled_pause(&subscription_id), None);
+    client.schedule_pause(&subscription_id, &start_at, &(5 * 86_400));
+
+    // Nothing happens until the start time
+    assert_eq!(client.apply_scheduled_pauses(&10), 0);
+    env.ledger().with_mut(|l| l.timestamp = start_at);
+    assert_eq!(
+        client.try_cancel_scheduled_pause(&subscription_id),
+        Err(Ok(PauseScheduleError::AlreadyStarted.into()))
+    );
+    assert_eq!(client.apply_scheduled_pauses(&10), 1);
+    let subscription = client.get_subscription(&subscription_id);
+    assert_eq!(subscription.status, MembershipStatus::Paused);
+    assert_eq!(
+        client
+            .get_scheduled_pause(&subscription_id)
+            .unwrap()
+            .applied_at,
+        Some(start_at)
+    );
+
+    // The keeper resumes it once the booked duration has passed
+    env.ledger()
+        .with_mut(|l| l.timestamp = start_at + 5 * 86_400);
+    assert_eq!(client.apply_scheduled_pauses(&10), 1);
+    let subscription = client.get_subscription(&subscription_id);
+    assert_eq!(subscription.status, MembershipStatus::Active);
+    assert_eq!(subscription.expires_at, expires_at + 5 * 86_400);
+    assert_eq!(client.get_scheduled_pause(&subscription_id), None);
+    assert_eq!(client.apply_scheduled_pauses(&10), 0);
+}
+
+#[test]
+fn test_pause_policy_credit_days_banks_loyalty_credits() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(Contract, ());
+    let client = ContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let user = Address::generate(&env);
+    let payment_token = Address::generate(&env);
+    let subscription_id = String::from_str(&env, "sub_pause_credit");
+    client.set_admin(&admin);
+    client.set_usdc_contract(&admin, &payment_token);
+
+    // Configs saved before pause policies existed read as ExtendExpiry
+    env.as_contract(&contract_id, || {
+        env.storage().instance().set(
+            &subscription::SubscriptionDataKey::PauseConfig,
+            &types::LegacyPauseConfig {
+                max_pause_duration: 2_592_000,
+                max_pause_count: 3,
+                min_active_time: 86_400,
+            },
+        );
+    });
+    assert_eq!(
+        client.get_pause_config().policy,
+        types::PausePolicy::ExtendExpiry
+    );
+
+    client.set_pause_config(
+        &admin,
+        &types::PauseConfig {
+            max_pause_duration: 2_592_000,
+            max_pause_count: 3,
+            min_active_time: 86_400,
+            policy: types::PausePolicy::CreditDays,
+        },
+    );
+    client.create_subscription(
+        &subscription_id,
+        &user,
+        &payment_token,
+        &100_000,
+        &2_592_000,
+    );
+    let expires_at = client.get_subscription(&subscription_id).expires_at;
+
+    env.ledger().with_mut(|l| l.timestamp += 86_400);
+    client.pause_subscription(&subscription_id, &None);
+    env.ledger()
+        .with_mut(|l| l.timestamp += 3 * 86_400 + 43_200);
+    client.resume_subscription(&subscription_id);
+
+    // Whole paused days are banked; the expiry stays put
+    assert_eq!(
+        client.get_subscription(&subscription_id).expires_at,
+        expires_at
+    );
+    assert_eq!(client.get_loyalty_account(&user).day_credits, 3);
+    let history = client.get_pause_history(&subscription_id);
+    let pause = history.get(0).unwrap();
+    assert_eq!(pause.policy, types::PausePolicy::CreditDays);
+    let resume = history.get(1).unwrap();
+    assert_eq!(resume.policy, types::PausePolicy::CreditDays);
+    assert_eq!(resume.applied_extension, Some(0));
+    assert_eq!(resume.credited_days, 3);
+}
+
+#[test]
+fn test_operational_metrics_track_grace_and_auto_renewal_failures() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, _admin, usdc, tier_id) = setup_multi_asset_env(&env);
+    let user = Address::generate(&env);
+    let token_id = BytesN::<32>::random(&env);
+    client.issue_token(&token_id, &user, &(env.ledger().timestamp() + 100));
+    client.renew_token(&token_id, &usdc, &tier_id, &BillingCycle::Monthly);
+    let sub_id = String::from_str(&env, "sub_metrics");
+    client.create_subscription_with_tier(
+        &sub_id,
+        &user,
+        &usdc,
+        &tier_id,
+        &BillingCycle::Monthly,
+        &None,
+    );
+    let metrics = client.get_operational_metrics();
+    assert_eq!(metrics.subscriptions_paused, 0);
+    assert_eq!(metrics.tokens_in_grace_period, 0);
+    assert_eq!(metrics.auto_renewal_failures, 0);
+    assert_eq!(metrics.failure_window_days, 30);
+
+    // An unpriceable payment asset fails the renewal into a grace period
+    let unsupported = Address::generate(&env);
+    client.set_auto_renewal(&token_id, &true, &unsupported, &0);
+    let expiry = client.get_token(&token_id).expiry_date;
+    env.ledger().with_mut(|l| l.timestamp = expiry - 3_600);
+    client.process_auto_renewal(&token_id);
+    assert_eq!(
+        client.get_token(&token_id).status,
+        MembershipStatus::GracePeriod
+    );
+    client.pause_subscription(&sub_id, &None);
+
+    let metrics = client.get_operational_metrics();
+    assert_eq!(metrics.subscriptions_paused, 1);
+    assert_eq!(metrics.tokens_in_grace_period, 1);
+    assert_eq!(metrics.auto_renewal_failures, 1);
+
+    // Renewing ends the grace period; the failure stays in the window
+    client.renew_token(&token_id, &usdc, &tier_id, &BillingCycle::Monthly);
+    let metrics = client.get_operational_metrics();
+    assert_eq!(metrics.tokens_in_grace_period, 0);
+    assert_eq!(metrics.auto_renewal_failures, 1);
+
+    env.ledger().with_mut(|l| l.timestamp += 30 * 86_400);
+    assert_eq!(client.get_operational_metrics().auto_renewal_failures, 0);
+}
+
+#[test]
+fn test_fraction_transfer_policy_whitelist_and_owner_approval() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(Contract, ());
+    let client = ContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let owner = Address::generate(&env);
+    let holder_b = Address::generate(&env);
+    let holder_c = Address::generate(&env);
+    let token_id = BytesN::<32>::random(&env);
+    client.set_admin(&admin);
+    client.issue_token(&token_id, &owner, &(env.ledger().timestamp() + 30 * 86_400));
+    client.fractionalize_token(&token_id, &1000, &100);
+    assert_eq!(
+        client.get_fraction_transfer_policy(&token_id),
+        FractionTransferPolicy::Open
+    );
+
+    // Whitelist-only: recipients must be listed by the admin
+    client.set_fraction_transfer_policy(&admin, &token_id, &FractionTransferPolicy::Whitelist);
+    assert_eq!(
+        client.try_transfer_fraction(&token_id, &owner, &holder_b, &300),
+        Err(Ok(FractionError::RecipientNotWhitelisted.into()))
+    );
+    client.set_fraction_whitelisted(&admin, &token_id, &holder_b, &true);
+    client.transfer_fraction(&token_id, &owner, &holder_b, &300);
+    assert_eq!(client.get_fraction_whitelist(&token_id).len(), 1);
+
+    // Owner-approved: holders need the owner's approval, the owner does not
+    client.set_fraction_transfer_policy(&admin, &token_id, &FractionTransferPolicy::OwnerApproved);
+    assert_eq!(
+        client.try_transfer_fraction(&token_id, &holder_b, &holder_c, &100),
+        Err(Ok(FractionError::TransferNotApproved.into()))
+    );
+    client.approve_fraction_transfer(&token_id, &holder_b, &holder_c, &200);
+    client.transfer_fraction(&token_id, &holder_b, &holder_c, &100);
+    assert_eq!(
+        client.get_fraction_transfer_approval(&token_id, &holder_b, &holder_c),
+        100
+    );
+    // The approval also governs allowance-based transfers
+    let spender = Address::generate(&env);
+    client.fraction_approve(&token_id, &holder_b, &spender, &200, &1_000);
+    assert_eq!(
+        client.try_fraction_transfer_from(&token_id, &spender, &holder_b, &holder_c, &200),
+        Err(Ok(FractionError::TransferNotApproved.into()))
+    );
+    client.fraction_transfer_from(&token_id, &spender, &holder_b, &holder_c, &100);
+    assert_eq!(client.fraction_balance(&token_id, &holder_c), 200);
+    client.transfer_fraction(&token_id, &owner, &holder_c, &100);
+    assert_eq!(client.fraction_balance(&token_id, &holder_c), 300);
+}
+
+#[test]
+fn test_fraction_lockup_locks_each_received_lot() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(Contract, ());
+    let client = ContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let owner = Address::generate(&env);
+    let holder_b = Address::generate(&env);
+    let holder_c = Address::generate(&env);
+    let token_id = BytesN::<32>::random(&env);
+    client.set_admin(&admin);
+    client.issue_token(&token_id, &owner, &(env.ledger().timestamp() + 30 * 86_400));
+    assert_eq!(
+        client.try_fractionalize_token_with_lockup(&token_id, &1000, &100, &0),
+        Err(Ok(FractionError::InvalidLockupPeriod.into()))
+    );
+    client.fractionalize_token_with_lockup(&token_id, &1000, &100, &86_400);
+    assert_eq!(client.get_fraction_lockup(&token_id), 86_400);
+
+    // The fractionalizer's own shares are not locked
+    assert_eq!(client.get_locked_shares(&token_id, &owner), 0);
+    client.transfer_fraction(&token_id, &owner, &holder_b, &300);
+    env.ledger().with_mut(|l| l.timestamp += 43_200);
+    client.transfer_fraction(&token_id, &owner, &holder_b, &200);
+    assert_eq!(client.get_locked_shares(&token_id, &holder_b), 500);
+    assert_eq!(
+        client.try_transfer_fraction(&token_id, &holder_b, &holder_c, &100),
+        Err(Ok(FractionError::SharesLocked.into()))
+    );
+
+    // The first lot unlocks on its own schedule
+    env.ledger().with_mut(|l| l.timestamp += 43_200);
+    assert_eq!(client.get_locked_shares(&token_id, &holder_b), 200);
+    client.transfer_fraction(&token_id, &holder_b, &holder_c, &300);
+    assert_eq!(
+        client.try_transfer_fraction(&token_id, &holder_b, &holder_c, &100),
+        Err(Ok(FractionError::SharesLocked.into()))
+    );
+    assert_eq!(client.get_locked_shares(&token_id, &holder_c), 300);
+
+    env.ledger().with_mut(|l| l.timestamp += 43_200);
+    assert_eq!(client.get_locked_shares(&token_id, &holder_b), 0);
+    client.transfer_fraction(&token_id, &holder_b, &holder_c, &200);
+    assert_eq!(client.fraction_balance(&token_id, &holder_c), 500);
+}
+
+#[test]
+fn test_holders_fund_renewal_of_expired_fractionalized_token() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(Contract, ());
+    let client = ContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let usdc = env.register_stellar_asset_contract_v2(admin.clone());
+    let multisig = Address::generate(&env);
+    client.initialize(
+        &admin,
+        &usdc.address(),
+        &multisig,
+        &default_init_config(&env),
+    );
+    let usdc_client = soroban_sdk::token::Client::new(&env, &usdc.address());
+    let tier_id = String::from_str(&env, "tier_basic");
+    client.create_tier(
+        &admin,
+        &CreateTierParams {
+            id: tier_id.clone(),
+            name: String::from_str(&env, "Basic"),
+            level: common_types::TierLevel::Basic,
+            price: 100_000i128,
+            annual_price: 1_000_000i128,
+            features: soroban_sdk::vec![&env, common_types::TierFeature::BasicAccess],
+            max_users: 100,
+            max_storage: 10_000_000,
+        },
+    );
+
+    let owner = Address::generate(&env);
+    let holder_b = Address::generate(&env);
+    let keeper = Address::generate(&env);
+    let token_id = BytesN::<32>::random(&env);
+    client.issue_token(&token_id, &owner, &(env.ledger().timestamp() + 86_400));
+    client.renew_token(&token_id, &usdc.address(), &tier_id, &BillingCycle::Monthly);
+    client.fractionalize_token(&token_id, &100, &10);
+    client.transfer_fraction(&token_id, &owner, &holder_b, &40);
+
+    let asset = soroban_sdk::token::StellarAssetClient::new(&env, &usdc.address());
+    asset.mint(&owner, &100_000);
+    asset.mint(&holder_b, &100_000);
+    assert_eq!(
+        client.try_contribute_renewal(&token_id, &keeper, &10_000),
+        Err(Ok(Error::Unauthorized.into()))
+    );
+
+    // The membership lapses while split between holders
+    let expiry = client.get_token(&token_id).expiry_date;
+    env.ledger().with_mut(|l| l.timestamp = expiry + 86_400);
+
+    client.contribute_renewal(&token_id, &owner, &80_000);
+    assert_eq!(
+        client.try_renew_fractionalized_token(&token_id, &keeper),
+        Err(Ok(FractionError::RenewalUnderfunded.into()))
+    );
+    let pool = client.contribute_renewal(&token_id, &holder_b, &40_000);
+    assert_eq!(pool.price, 100_000);
+    assert_eq!(pool.funded, 120_000);
+
+    let renewal = client.renew_fractionalized_token(&token_id, &keeper);
+    let now = env.ledger().timestamp();
+    assert_eq!(renewal.new_expiry, now + 30 * 86_400);
+    assert_eq!(client.get_token(&token_id).expiry_date, renewal.new_expiry);
+
+    // The 20_000 excess goes back two-to-one, as it was paid
+    assert_eq!(renewal.contributions.len(), 2);
+    assert_eq!(renewal.refunds.len(), 2);
+    let owner_refund = usdc_client.balance(&owner) - 20_000;
+    let holder_b_refund = usdc_client.balance(&holder_b) - 60_000;
+    assert!((13_333..=13_334).contains(&owner_refund));
+    assert!((6_666..=6_667).contains(&holder_b_refund));
+    assert_eq!(owner_refund + holder_b_refund, 20_000);
+    assert_eq!(usdc_client.balance(&contract_id), 100_000);
+
+    assert_eq!(client.get_renewal_pool(&token_id), None);
+    assert_eq!(client.get_funded_renewals(&token_id).len(), 1);
+    assert_eq!(
+        client
+            .get_renewal_history(&token_id)
+            .last()
+            .unwrap()
+            .trigger,
+        types::RenewalTrigger::HolderFunded
+    );
+
+    // The pooled payment is collected revenue the treasury can withdraw
+    assert_eq!(client.get_withdrawable_revenue(&usdc.address()), 100_000);
+    let destination = Address::generate(&env);
+    client.withdraw_treasury(&admin, &usdc.address(), &100_000, &destination);
+    assert_eq!(usdc_client.balance(&destination), 100_000);
+    assert_eq!(usdc_client.balance(&contract_id), 0);
+}
+
+#[test]
+fn test_renewal_pool_refunds_in_the_token_it_was_funded_in() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(Contract, ());
+    let client = ContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    client.set_admin(&admin);
+    let old_usdc = env.register_stellar_asset_contract_v2(admin.clone());
+    let new_usdc = env.register_stellar_asset_contract_v2(admin.clone());
+    client.set_usdc_contract(&admin, &old_usdc.address());
+    let old_usdc = soroban_sdk::token::Client::new(&env, &old_usdc.address());
+    let new_usdc = soroban_sdk::token::Client::new(&env, &new_usdc.address());
+    let tier_id = String::from_str(&env, "tier_basic");
+    client.create_tier(
+        &admin,
+        &CreateTierParams {
+            id: tier_id.clone(),
+            name: String::from_str(&env, "Basic"),
+            level: common_types::TierLevel::Basic,
+            price: 100_000i128,
+            annual_price: 1_000_000i128,
+            features: soroban_sdk::vec![&env, common_types::TierFeature::BasicAccess],
+            max_users: 100,
+            max_storage: 10_000_000,
+        },
+    );
+
+    let owner = Address::generate(&env);
+    let holder_b = Address::generate(&env);
+    let token_id = BytesN::<32>::random(&env);
+    client.issue_token(&token_id, &owner, &(env.ledger().timestamp() + 86_400));
+    client.renew_token(
+        &token_id,
+        &old_usdc.address,
+        &tier_id,
+        &BillingCycle::Monthly,
+    );
+    client.fractionalize_token(&token_id, &100, &10);
+    client.transfer_fraction(&token_id, &owner, &holder_b, &40);
+
+    let asset = soroban_sdk::token::StellarAssetClient::new(&env, &old_usdc.address);
+    asset.mint(&owner, &100_000);
+    asset.mint(&holder_b, &100_000);
+    client.contribute_renewal(&token_id, &owner, &30_000);
+    let pool = client.contribute_renewal(&token_id, &holder_b, &20_000);
+    assert_eq!(pool.payment_token, old_usdc.address);
+
+    // Rotate the configured USDC contract while the pool is open
+    let pending = client.propose_usdc_contract(&admin, &new_usdc.address);
+    env.ledger()
+        .with_mut(|l| l.timestamp = pending.executable_at);
+    client.confirm_usdc_contract(&admin);
+
+    // Withdrawals and refunds are paid in the asset that was contributed
+    assert_eq!(
+        client.withdraw_renewal_contribution(&token_id, &holder_b),
+        20_000
+    );
+    assert_eq!(old_usdc.balance(&holder_b), 100_000);
+    assert_eq!(new_usdc.balance(&holder_b), 0);
+
+    client.transfer_fraction(&token_id, &holder_b, &owner, &40);
+    client.recombine_fractions(&token_id, &owner);
+    assert_eq!(old_usdc.balance(&owner), 100_000);
+    assert_eq!(new_usdc.balance(&owner), 0);
+    assert_eq!(client.get_renewal_pool(&token_id), None);
+    assert_eq!(old_usdc.balance(&contract_id), 0);
+}
@@ -0,0 +1,151 @@
+use crate::attendance_log::AttendanceLogModule;
+use crate::errors::Error;
+use crate::guests::GuestModule;
+use crate::household::HouseholdModule;
+use crate::subscription::SubscriptionContract;
+use crate::types::{
+    AttendanceAction, BillingCycle, MembershipStatus, SubscriptionTier, TierComparison,
+    TierFeature, TierRecommendation,
+};
+use common_types::DateRange;
+use soroban_sdk::{Address, Env, String, Vec};
+
+// ---------------------------------------------------------------------------
+// Constants
+// ---------------------------------------------------------------------------
+
+/// Window of attendance `recommend_tier` looks at (30 days).
+pub const RECOMMENDATION_WINDOW: u64 = 30 * 24 * 60 * 60;
+
+/// Visits per window from which annual billing is recommended.
+pub const FREQUENT_ATTENDANCE: u32 = 8;
+
+// ---------------------------------------------------------------------------
+// Module
+// ---------------------------------------------------------------------------
+
+/// Read-only tier comparison and recommendation for frontends.
+pub struct TierAdvisorModule;
+
+impl TierAdvisorModule {
+    /// Compares the prices, features and limits of two tiers.
+    pub fn compare_tiers(
+        env: Env,
+        tier_a: String,
+        tier_b: String,
+    ) -> Result<TierComparison, Error> {
+        let a = SubscriptionContract::get_tier(env.clone(), tier_a)?;
+        let b = SubscriptionContract::get_tier(env.clone(), tier_b)?;
+
+        let mut only_in_a = Vec::new(&env);
+        let mut shared_features = Vec::new(&env);
+        for feature in a.features.iter() {
+            if b.features.contains(&feature) {
+                shared_features.push_back(feature);
+            } else {
+                only_in_a.push_back(feature);
+            }
+        }
+        let mut only_in_b: Vec<TierFeature> = Vec::new(&env);
+        for feature in b.features.iter() {
+            if !a.features.contains(&feature) {
+                only_in_b.push_back(feature);
+            }
+        }
+
+        Ok(TierComparison {
+            price_difference: b.price - a.price,
+            annual_price_difference: b.annual_price - a.annual_price,
+            only_in_a,
+            only_in_b,
+            shared_features,
+            max_users_a: a.max_users,
+            max_users_b: b.max_users,
+            max_storage_a: a.max_storage,
+            max_storage_b: b.max_storage,
+            guest_quota_a: GuestModule::get_tier_quota(&env, &a.id),
+            guest_quota_b: GuestModule::get_tier_quota(&env, &b.id),
+            tier_a: a.id,
+            tier_b: b.id,
+        })
+    }
+
+    /// Suggests the cheapest active tier whose user limit fits the user's
+    /// household and whose guest quota covers the guest visits used this
+    /// period. Frequent attendees are pointed at annual billing when it
+    /// costs less than twelve monthly payments. `None` if no active tier
+    /// fits.
+    pub fn recommend_tier(env: Env, user: Address) -> Option<TierRecommendation> {
+        let now = env.ledger().timestamp();
+        let (household_size, guest_visits) = match Self::current_subscription_id(&env, &user) {
+            Some(id) => {
+                let members = HouseholdModule::get_members(&env, &id).len();
+                let guests = GuestModule::get_usage(env.clone(), id).map_or(0, |usage| usage.used);
+                (members + 1, guests)
+            }
+            None => (1, 0),
+        };
+        let window = DateRange {
+            start_time: now.saturating_sub(RECOMMENDATION_WINDOW),
+            end_time: now,
+        };
+        // Subscription events are logged as attendance too; only count visits
+        let subscription_key = String::from_str(&env, "subscription_id");
+        let monthly_attendance = AttendanceLogModule::logs_in_range(&env, &user, &window)
+            .iter()
+            .filter(|log| {
+                log.action == AttendanceAction::ClockIn
+                    && !log.details.contains_key(subscription_key.clone())
+            })
+            .count() as u32;
+
+        let mut best: Option<SubscriptionTier> = None;
+        for tier in SubscriptionContract::get_active_tiers(env.clone()).iter() {
+            let fits_household = tier.max_users == 0 || tier.max_users >= household_size;
+            let fits_guests = GuestModule::get_tier_quota(&env, &tier.id) >= guest_visits;
+            if fits_household
+                && fits_guests
+                && best.as_ref().is_none_or(|best| tier.price < best.price)
+            {
+                best = Some(tier);
+            }
+        }
+        let tier = best?;
+
+        let (billing_cycle, price) =
+            if monthly_attendance >= FREQUENT_ATTENDANCE && tier.annual_price < tier.price * 12 {
+                (BillingCycle::Annual, tier.annual_price)
+            } else {
+                (BillingCycle::Monthly, tier.price)
+            };
+        Some(TierRecommendation {
+            tier_id: tier.id,
+            billing_cycle,
+            price,
+            household_size,
+            guest_visits,
+            monthly_attendance,
+        })
+    }
+
+    /// The user's most recent subscription that is still live.
+    fn current_subscription_id(env: &Env, user: &Address) -> Option<String> {
+        let ids = SubscriptionContract::get_user_subscription_ids(env, user);
+        for i in (0..ids.len()).rev() {
+            let id = ids.get_unchecked(i);
+            let live = SubscriptionContract::get_subscription_view(env.clone(), id.clone())
+                .is_ok_and(|subscription| {
+                    matches!(
+                        subscription.status,
+                        MembershipStatus::Active
+                            | MembershipStatus::Paused
+                            | MembershipStatus::GracePeriod
+                    )
+                });
+            if live {
+                return Some(id);
+            }
+        }
+        None
+    }
+}
@@ -0,0 +1,31 @@
+//! Tier-change error types for the ManageHub contract.
+//!
+//! `TierChangeError` owns the code range 1550–1599 (see [`crate::errors`]).
+
+use soroban_sdk::contracterror;
+
+/// Tier-change errors.
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum TierChangeError {
+    /// Only moves to a lower tier can be scheduled for the period end.
+    NotADowngrade = 1550,
+    /// The subscription already has a downgrade scheduled.
+    DowngradeAlreadyScheduled = 1551,
+    /// The subscription has no scheduled downgrade.
+    NoScheduledDowngrade = 1552,
+    /// The subscription already has a tier change awaiting processing.
+    ChangePending = 1553,
+    /// The tier-change cooldown since the last change has not elapsed.
+    TierChangeTooFrequent = 1554,
+    /// The tier is already deprecated.
+    TierAlreadyDeprecated = 1555,
+    /// The tier has no deprecation plan.
+    TierNotDeprecated = 1556,
+    /// The replacement is the deprecated tier itself.
+    InvalidReplacementTier = 1557,
+    /// The migration time is not in the future.
+    InvalidMigrationTime = 1558,
+    /// The scheduled migration time has not been reached.
+    MigrationNotDue = 1559,
+}
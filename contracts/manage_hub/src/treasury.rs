@@ -0,0 +1,230 @@
+// Allow deprecated events API until migration to #[contractevent] macro
+#![allow(deprecated)]
+
+use crate::errors::{Error, ModuleError};
+use crate::initialization::InitializationModule;
+use crate::membership_token::MembershipTokenContract;
+use crate::treasury_errors::TreasuryError;
+use crate::types::{RevenueBreakdown, RevenueSource};
+use common_types::DateRange;
+use soroban_sdk::{contracttype, symbol_short, token, Address, Env};
+
+// ---------------------------------------------------------------------------
+// Storage keys
+// ---------------------------------------------------------------------------
+
+#[contracttype]
+pub enum TreasuryDataKey {
+    /// Revenue booked on a given day, keyed by `timestamp / SECONDS_PER_DAY`
+    /// (persistent storage).
+    DailyRevenue(u64),
+    /// Revenue booked since deployment (instance storage).
+    LifetimeRevenue,
+    /// Collected revenue per token not yet withdrawn (instance storage).
+    Withdrawable(Address),
+}
+
+// ---------------------------------------------------------------------------
+// Constants
+// ---------------------------------------------------------------------------
+
+const SECONDS_PER_DAY: u64 = 86_400;
+
+/// Longest period `get_revenue_breakdown` will aggregate, in days.
+const MAX_REPORT_DAYS: u64 = 366;
+
+/// Keep daily revenue buckets for ~1 year.
+const REVENUE_TTL_LEDGERS: u32 = 6_307_200;
+
+// ---------------------------------------------------------------------------
+// Module
+// ---------------------------------------------------------------------------
+
+pub struct TreasuryModule;
+
+impl TreasuryModule {
+    /// Credit `amount` to the counters for `source`. Non-positive amounts are ignored.
+    ///
+    /// This only books the revenue for reporting; use [`Self::record_collected`]
+    /// when the tokens are actually held by the contract.
+    pub(crate) fn record_revenue(
+        env: &Env,
+        source: RevenueSource,
+        amount: i128,
+    ) -> Result<(), ModuleError> {
+        if amount <= 0 {
+            return Ok(());
+        }
+
+        let day_key = TreasuryDataKey::DailyRevenue(env.ledger().timestamp() / SECONDS_PER_DAY);
+        let mut daily: RevenueBreakdown =
+            env.storage().persistent().get(&day_key).unwrap_or_default();
+        Self::credit(&mut daily, &source, amount)?;
+        env.storage().persistent().set(&day_key, &daily);
+        env.storage()
+            .persistent()
+            .extend_ttl(&day_key, REVENUE_TTL_LEDGERS, REVENUE_TTL_LEDGERS);
+
+        let mut lifetime = Self::get_lifetime_revenue(env.clone());
+        Self::credit(&mut lifetime, &source, amount)?;
+        env.storage()
+            .instance()
+            .set(&TreasuryDataKey::LifetimeRevenue, &lifetime);
+
+        Ok(())
+    }
+
+    /// Book `amount` of `token` received by the contract as revenue and make
+    /// it available to [`Self::withdraw_treasury`].
+    pub(crate) fn record_collected(
+        env: &Env,
+        source: RevenueSource,
+        token: &Address,
+        amount: i128,
+    ) -> Result<(), ModuleError> {
+        if amount <= 0 {
+            return Ok(());
+        }
+        Self::record_revenue(env, source, amount)?;
+
+        let withdrawable = Self::get_withdrawable(env, token)
+            .checked_add(amount)
+            .ok_or(TreasuryError::Overflow)?;
+        env.storage()
+            .instance()
+            .set(&TreasuryDataKey::Withdrawable(token.clone()), &withdrawable);
+        Ok(())
+    }
+
+    /// Takes `amount` of `token` out of the collected revenue before it is
+    /// paid out of the contract. Errors with `ExceedsWithdrawable` if less
+    /// than that was collected.
+    pub(crate) fn spend_collected(
+        env: &Env,
+        token: &Address,
+        amount: i128,
+    ) -> Result<(), ModuleError> {
+        let withdrawable = Self::get_withdrawable(env, token);
+        if amount > withdrawable {
+            return Err(TreasuryError::ExceedsWithdrawable.into());
+        }
+        env.storage().instance().set(
+            &TreasuryDataKey::Withdrawable(token.clone()),
+            &(withdrawable - amount),
+        );
+        Ok(())
+    }
+
+    /// Collected revenue in `token` that has not been withdrawn yet.
+    pub fn get_withdrawable(env: &Env, token: &Address) -> i128 {
+        env.storage()
+            .instance()
+            .get(&TreasuryDataKey::Withdrawable(token.clone()))
+            .unwrap_or(0)
+    }
+
+    /// Sum revenue per source for every day overlapping `period`.
+    pub fn get_revenue_breakdown(
+        env: Env,
+        period: DateRange,
+    ) -> Result<RevenueBreakdown, ModuleError> {
+        if period.end_time < period.start_time {
+            return Err(TreasuryError::InvalidPeriod.into());
+        }
+        let first_day = period.start_time / SECONDS_PER_DAY;
+        let last_day = period.end_time / SECONDS_PER_DAY;
+        if last_day - first_day >= MAX_REPORT_DAYS {
+            return Err(TreasuryError::InvalidPeriod.into());
+        }
+
+        let add = |sum: i128, amount: i128| sum.checked_add(amount).ok_or(TreasuryError::Overflow);
+        let mut breakdown = RevenueBreakdown::default();
+        for day in first_day..=last_day {
+            if let Some(daily) = env
+                .storage()
+                .persistent()
+                .get::<_, RevenueBreakdown>(&TreasuryDataKey::DailyRevenue(day))
+            {
+                breakdown.subscriptions = add(breakdown.subscriptions, daily.subscriptions)?;
+                breakdown.renewals = add(breakdown.renewals, daily.renewals)?;
+                breakdown.tier_changes = add(breakdown.tier_changes, daily.tier_changes)?;
+                breakdown.marketplace_fees =
+                    add(breakdown.marketplace_fees, daily.marketplace_fees)?;
+                breakdown.penalties = add(breakdown.penalties, daily.penalties)?;
+                breakdown.total = add(breakdown.total, daily.total)?;
+            }
+        }
+
+        Ok(breakdown)
+    }
+
+    /// Revenue per source since deployment.
+    pub fn get_lifetime_revenue(env: Env) -> RevenueBreakdown {
+        env.storage()
+            .instance()
+            .get(&TreasuryDataKey::LifetimeRevenue)
+            .unwrap_or_default()
+    }
+
+    /// Move `amount` of collected `token` revenue to `destination`.
+    ///
+    /// Only revenue booked through [`Self::record_collected`] can leave this
+    /// way; staked principal, reservation deposits and renewal pools held by
+    /// the contract are never withdrawable.
+    ///
+    /// Requires both the admin and the registered access_control contract to
+    /// authorize the call, so withdrawals only go through once a multisig
+    /// proposal has been approved and executed.
+    pub fn withdraw_treasury(
+        env: Env,
+        admin: Address,
+        token: Address,
+        amount: i128,
+        destination: Address,
+    ) -> Result<(), ModuleError> {
+        MembershipTokenContract::require_admin(&env, &admin)?;
+
+        let multisig = InitializationModule::get_access_control(&env)
+            .ok_or(TreasuryError::MultisigNotConfigured)?;
+        multisig.require_auth();
+
+        if amount <= 0 {
+            return Err(Error::InvalidPaymentAmount.into());
+        }
+
+        let token_client = token::Client::new(&env, &token);
+        let treasury = env.current_contract_address();
+        if token_client.balance(&treasury) < amount {
+            return Err(Error::InsufficientBalance.into());
+        }
+        Self::spend_collected(&env, &token, amount)?;
+        token_client.transfer(&treasury, &destination, &amount);
+
+        env.events().publish(
+            (symbol_short!("trs_wdrw"), token, destination),
+            (admin, amount, env.ledger().timestamp()),
+        );
+
+        Ok(())
+    }
+
+    fn credit(
+        breakdown: &mut RevenueBreakdown,
+        source: &RevenueSource,
+        amount: i128,
+    ) -> Result<(), ModuleError> {
+        let bucket = match source {
+            RevenueSource::Subscription => &mut breakdown.subscriptions,
+            RevenueSource::Renewal => &mut breakdown.renewals,
+            RevenueSource::TierChange => &mut breakdown.tier_changes,
+            RevenueSource::MarketplaceFee => &mut breakdown.marketplace_fees,
+            RevenueSource::Penalty => &mut breakdown.penalties,
+        };
+        *bucket = bucket.checked_add(amount).ok_or(TreasuryError::Overflow)?;
+        breakdown.total = breakdown
+            .total
+            .checked_add(amount)
+            .ok_or(TreasuryError::Overflow)?;
+        Ok(())
+    }
+}
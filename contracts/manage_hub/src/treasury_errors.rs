@@ -0,0 +1,20 @@
+//! Treasury-related error types for the ManageHub contract.
+//!
+//! `TreasuryError` owns the code range 1600–1649 (see [`crate::errors`]).
+
+use soroban_sdk::contracterror;
+
+/// Treasury-specific errors.
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum TreasuryError {
+    /// No access_control contract is registered, so withdrawals cannot be
+    /// co-signed by multisig.
+    MultisigNotConfigured = 1600,
+    /// The requested reporting period is inverted or spans too many days.
+    InvalidPeriod = 1601,
+    /// Arithmetic overflow while updating revenue counters.
+    Overflow = 1602,
+    /// The withdrawal exceeds the collected revenue not yet withdrawn.
+    ExceedsWithdrawable = 1603,
+}
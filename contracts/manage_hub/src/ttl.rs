@@ -0,0 +1,176 @@
+use crate::errors::Error;
+use crate::events::TtlPolicyUpdated;
+use crate::installments::InstallmentDataKey;
+use crate::membership_token::{DataKey as MembershipDataKey, MembershipTokenContract};
+use crate::subscription::SubscriptionDataKey;
+use crate::types::{TtlEntityKey, TtlPolicy, TtlSetting};
+use soroban_sdk::{contracttype, Address, Env, IntoVal, Val};
+
+// ---------------------------------------------------------------------------
+// Storage keys
+// ---------------------------------------------------------------------------
+
+#[contracttype]
+pub enum TtlDataKey {
+    /// TTL settings per entity class (instance storage).
+    TtlPolicy,
+}
+
+// ---------------------------------------------------------------------------
+// Defaults (in ledgers; ~1 ledger / 5 s on Stellar)
+// ---------------------------------------------------------------------------
+
+/// Extend an entry once fewer than ~30 days remain.
+const DEFAULT_TTL_THRESHOLD: u32 = 518_400;
+
+/// Extend long-lived entries to ~180 days.
+const DEFAULT_TTL_EXTEND_TO: u32 = 3_110_400;
+
+/// Extend history entries to ~90 days.
+const DEFAULT_HISTORY_TTL_EXTEND_TO: u32 = 1_555_200;
+
+/// Classes of persistent entries that share TTL settings.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum TtlEntityClass {
+    /// Membership tokens.
+    Token,
+    /// Subscriptions and the installment plans and overrides attached to them.
+    Subscription,
+    /// Subscription tiers and their per-tier settings.
+    Tier,
+    /// Append-only histories, e.g. renewal history.
+    History,
+    /// Admin configuration such as accepted payment tokens.
+    Config,
+}
+
+// ---------------------------------------------------------------------------
+// Module
+// ---------------------------------------------------------------------------
+
+/// Rent management for persistent entries.
+///
+/// Entries are extended on every read and write according to the
+/// admin-configurable [`TtlPolicy`] for their class, and the admin can extend
+/// a single entry on demand with `extend_entity_ttl`.
+pub struct TtlModule;
+
+impl TtlModule {
+    pub fn get_policy(env: &Env) -> TtlPolicy {
+        env.storage()
+            .instance()
+            .get(&TtlDataKey::TtlPolicy)
+            .unwrap_or_else(Self::default_policy)
+    }
+
+    fn default_policy() -> TtlPolicy {
+        let long_lived = TtlSetting {
+            threshold: DEFAULT_TTL_THRESHOLD,
+            extend_to: DEFAULT_TTL_EXTEND_TO,
+        };
+        TtlPolicy {
+            token: long_lived.clone(),
+            subscription: long_lived.clone(),
+            tier: long_lived.clone(),
+            history: TtlSetting {
+                threshold: DEFAULT_TTL_THRESHOLD,
+                extend_to: DEFAULT_HISTORY_TTL_EXTEND_TO,
+            },
+            config: long_lived,
+        }
+    }
+
+    pub fn set_policy(env: Env, admin: Address, policy: TtlPolicy) -> Result<(), Error> {
+        MembershipTokenContract::require_admin(&env, &admin)?;
+
+        let max_ttl = env.storage().max_ttl();
+        for setting in [
+            &policy.token,
+            &policy.subscription,
+            &policy.tier,
+            &policy.history,
+            &policy.config,
+        ] {
+            if setting.extend_to == 0
+                || setting.threshold > setting.extend_to
+                || setting.extend_to > max_ttl
+            {
+                return Err(Error::InvalidPaymentAmount);
+            }
+        }
+
+        env.storage()
+            .instance()
+            .set(&TtlDataKey::TtlPolicy, &policy);
+
+        TtlPolicyUpdated {
+            policy,
+            updated_by: admin,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    fn setting(policy: TtlPolicy, class: TtlEntityClass) -> TtlSetting {
+        match class {
+            TtlEntityClass::Token => policy.token,
+            TtlEntityClass::Subscription => policy.subscription,
+            TtlEntityClass::Tier => policy.tier,
+            TtlEntityClass::History => policy.history,
+            TtlEntityClass::Config => policy.config,
+        }
+    }
+
+    /// Extends the persistent entry at `key` according to its class's
+    /// settings. The entry must exist.
+    pub(crate) fn bump<K: IntoVal<Env, Val>>(env: &Env, class: TtlEntityClass, key: &K) {
+        let setting = Self::setting(Self::get_policy(env), class);
+        env.storage()
+            .persistent()
+            .extend_ttl(key, setting.threshold, setting.extend_to);
+    }
+
+    pub fn extend_entity_ttl(env: Env, admin: Address, key: TtlEntityKey) -> Result<(), Error> {
+        MembershipTokenContract::require_admin(&env, &admin)?;
+
+        let storage = env.storage().persistent();
+        match key {
+            TtlEntityKey::Token(id) => {
+                let key = MembershipDataKey::Token(id);
+                if !storage.has(&key) {
+                    return Err(Error::TokenNotFound);
+                }
+                Self::bump(&env, TtlEntityClass::Token, &key);
+            }
+            TtlEntityKey::Subscription(id) => {
+                let key = SubscriptionDataKey::Subscription(id);
+                if !storage.has(&key) {
+                    return Err(Error::SubscriptionNotFound);
+                }
+                Self::bump(&env, TtlEntityClass::Subscription, &key);
+            }
+            TtlEntityKey::InstallmentPlan(id) => {
+                let key = InstallmentDataKey::Plan(id);
+                if !storage.has(&key) {
+                    return Err(Error::SubscriptionNotFound);
+                }
+                Self::bump(&env, TtlEntityClass::Subscription, &key);
+            }
+            TtlEntityKey::Tier(id) => {
+                let key = SubscriptionDataKey::Tier(id);
+                if !storage.has(&key) {
+                    return Err(Error::TierNotFound);
+                }
+                Self::bump(&env, TtlEntityClass::Tier, &key);
+            }
+            TtlEntityKey::RenewalHistory(id) => {
+                let key = MembershipDataKey::RenewalHistory(id);
+                if !storage.has(&key) {
+                    return Err(Error::TokenNotFound);
+                }
+                Self::bump(&env, TtlEntityClass::History, &key);
+            }
+        }
+        Ok(())
+    }
+}
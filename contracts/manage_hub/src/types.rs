@@ -1,4 +1,5 @@
-use soroban_sdk::{contracttype, Address, BytesN, String, Vec};
+use common_types::DateRange;
+use soroban_sdk::{contracttype, Address, BytesN, Map, String, Symbol, Vec};
 
 // Re-export types from common_types for consistency
 pub use common_types::MembershipStatus;
@@ -50,6 +51,52 @@ pub enum BillingCycle {
 #[contracttype]
 #[derive(Clone, Debug, PartialEq)]
 pub struct Subscription {
+    pub id: String,
+    pub user: Address,
+    pub payment_token: Address,
+    pub amount: i128,
+    pub status: MembershipStatus,
+    pub created_at: u64,
+    pub expires_at: u64,
+    pub tier_id: String,
+    pub billing_cycle: BillingCycle,
+    pub paused_at: Option<u64>,
+    pub last_resumed_at: u64,
+    pub pause_count: u32,
+    pub total_paused_duration: u64,
+    /// Tier price (USDC units) locked in at creation; honoured on renewal
+    /// while the tier grandfathers existing subscribers. 0 for legacy subscriptions.
+    pub locked_price: i128,
+}
+
+/// Subscription layout written before schema version 2, with the pause
+/// history stored inline. Only decoded when an old entry is migrated.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct LegacySubscription {
+    pub id: String,
+    pub user: Address,
+    pub payment_token: Address,
+    pub amount: i128,
+    pub status: MembershipStatus,
+    pub created_at: u64,
+    pub expires_at: u64,
+    pub tier_id: String,
+    pub billing_cycle: BillingCycle,
+    pub paused_at: Option<u64>,
+    pub last_resumed_at: u64,
+    pub pause_count: u32,
+    pub total_paused_duration: u64,
+    pub pause_history: Vec<PauseHistoryEntry>,
+    pub locked_price: i128,
+}
+
+/// Subscription layout written before tier prices were locked in. Only
+/// decoded when an old entry is migrated; it comes out with a
+/// `locked_price` of 0.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct SubscriptionV0 {
     pub id: String,
     pub user: Address,
     pub payment_token: Address,
@@ -73,6 +120,19 @@ pub enum PauseAction {
     Resume,
 }
 
+/// How members are compensated for time spent paused.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum PausePolicy {
+    /// Push the expiry back by the paused time
+    ExtendExpiry,
+    /// Bank the paused time as whole loyalty day credits, applied at the
+    /// next renewal
+    CreditDays,
+    /// No compensation
+    NoCompensation,
+}
+
 #[contracttype]
 #[derive(Clone, Debug, PartialEq)]
 pub struct PauseHistoryEntry {
@@ -83,6 +143,24 @@ pub struct PauseHistoryEntry {
     pub reason: Option<String>,
     pub paused_duration: Option<u64>,
     pub applied_extension: Option<u64>,
+    /// Compensation policy in force when the entry was recorded
+    pub policy: PausePolicy,
+    /// Day credits banked on resume under `CreditDays`
+    pub credited_days: u32,
+}
+
+/// Pause history entry layout written before pause policies existed. Only
+/// decoded when an old entry is read.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct LegacyPauseHistoryEntry {
+    pub action: PauseAction,
+    pub timestamp: u64,
+    pub actor: Address,
+    pub is_admin: bool,
+    pub reason: Option<String>,
+    pub paused_duration: Option<u64>,
+    pub applied_extension: Option<u64>,
 }
 
 #[contracttype]
@@ -91,6 +169,30 @@ pub struct PauseConfig {
     pub max_pause_duration: u64,
     pub max_pause_count: u32,
     pub min_active_time: u64,
+    pub policy: PausePolicy,
+}
+
+/// Pause config layout written before pause policies existed. Only decoded
+/// when an old config is read.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct LegacyPauseConfig {
+    pub max_pause_duration: u64,
+    pub max_pause_count: u32,
+    pub min_active_time: u64,
+}
+
+/// A pause a member booked in advance.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ScheduledPause {
+    pub subscription_id: String,
+    pub start_at: u64,
+    /// Seconds the subscription stays paused before it is resumed
+    pub duration: u64,
+    pub scheduled_at: u64,
+    /// When the keeper paused the subscription, once it has
+    pub applied_at: Option<u64>,
 }
 
 #[contracttype]
@@ -138,7 +240,8 @@ pub struct TierAnalytics {
     pub upgrades_count: u32,
     /// Number of downgrades from this tier
     pub downgrades_count: u32,
-    /// Churn rate (cancellations / total * 100)
+    /// Churn rate in percent over the trailing churn window:
+    /// churned / (active + churned) * 100. Computed on read.
     pub churn_rate: u32,
     /// Last updated timestamp
     pub updated_at: u64,
@@ -188,6 +291,8 @@ pub struct UpdateTierParams {
     pub max_storage: Option<u64>,
     /// Whether tier is active (optional)
     pub is_active: Option<bool>,
+    /// Whether existing subscribers keep their locked price on renewal (optional)
+    pub grandfather_existing: Option<bool>,
 }
 
 /// Parameters for creating a promotion.
@@ -263,6 +368,31 @@ pub struct RenewalConfig {
     pub renewals_enabled: bool,
 }
 
+/// Features a lapsed subscription keeps during its grace period.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub enum GraceFeatureAccess {
+    /// No features until the subscription is renewed
+    Suspended,
+    /// Every feature of the tier
+    Full,
+    /// Only the listed features
+    Reduced(Vec<TierFeature>),
+}
+
+/// Grace-period behaviour for one tier, overriding the global
+/// `RenewalConfig`.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct GracePolicy {
+    /// Grace period duration in seconds
+    pub duration: u64,
+    /// Whether tokens in their grace period may not be transferred
+    pub block_transfers: bool,
+    /// Feature access of a lapsed subscription during the grace period
+    pub feature_access: GraceFeatureAccess,
+}
+
 /// Trigger reason for token renewal.
 #[contracttype]
 #[derive(Clone, Debug, PartialEq)]
@@ -273,6 +403,8 @@ pub enum RenewalTrigger {
     AutoRenewal,
     /// Renewal during grace period
     GracePeriod,
+    /// Renewal paid for by a fractionalized token's share holders
+    HolderFunded,
 }
 
 /// Record of a token renewal attempt.
@@ -311,6 +443,61 @@ pub struct AutoRenewalSettings {
     pub payment_token: Address,
     /// Timestamp when settings were last updated
     pub updated_at: u64,
+    /// Most a single auto-renewal may charge, in payment-token units
+    /// (0 = no cap)
+    pub max_amount: i128,
+}
+
+/// Auto-renewal settings layout written before price caps existed. Only
+/// decoded when an old entry is read.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct LegacyAutoRenewalSettings {
+    pub enabled: bool,
+    pub token_id: BytesN<32>,
+    pub payment_token: Address,
+    pub updated_at: u64,
+}
+
+/// A member's explicit consent to auto-renewal terms, recorded each time
+/// they change their settings.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct RenewalConsent {
+    pub enabled: bool,
+    pub payment_token: Address,
+    /// Price cap consented to (0 = no cap)
+    pub max_amount: i128,
+    pub consented_at: u64,
+}
+
+/// Auto-renewal held back because the price exceeds the member's cap,
+/// waiting for them to consent to the new amount.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct PendingRenewalConsent {
+    /// Amount the renewal would charge, in payment-token units
+    pub required_amount: i128,
+    /// Cap in force when the renewal was held back
+    pub max_amount: i128,
+    pub requested_at: u64,
+}
+
+/// Timestamps bounding a token's renewal, for keepers and reminders.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct RenewalWindow {
+    /// When auto-renewal first becomes eligible (expiry minus the notice
+    /// period)
+    pub opens_at: u64,
+    /// Token expiry; auto-renewal is eligible until then
+    pub closes_at: u64,
+    /// End of the grace period: the stored deadline once the token is in
+    /// grace, otherwise the earliest it can fall (expiry plus the tier's
+    /// grace duration)
+    pub grace_deadline: u64,
+    /// Whether the owner has auto-renewal enabled for this token
+    pub auto_renewal_enabled: bool,
 }
 
 // ============================================================================
@@ -366,6 +553,28 @@ pub struct EmergencyPauseState {
     pub pause_count: u32,
 }
 
+/// One global emergency pause, kept for audits.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct GlobalPauseRecord {
+    /// Ledger timestamp when the pause was initiated
+    pub paused_at: u64,
+    /// Admin or guardian that initiated the pause
+    pub paused_by: Address,
+    /// Human-readable reason for the pause
+    pub reason: Option<String>,
+    /// Automatic unpause deadline set at pause time
+    pub auto_unpause_at: Option<u64>,
+    /// Time lock set at pause time
+    pub time_lock_until: Option<u64>,
+    /// When the pause ended; None while it is still active
+    pub unpaused_at: Option<u64>,
+    /// Admin that lifted the pause; None if it auto-expired or was superseded
+    pub unpaused_by: Option<Address>,
+    /// Seconds the pause lasted; 0 while it is still active
+    pub duration: u64,
+}
+
 /// Per-token pause state, allowing fine-grained suspension of individual tokens.
 #[contracttype]
 #[derive(Clone, Debug, PartialEq)]
@@ -378,6 +587,34 @@ pub struct TokenPauseState {
     pub paused_by: Address,
     /// Human-readable reason for the pause
     pub reason: Option<String>,
+    /// Ledger timestamp after which the token auto-resumes without admin action.
+    /// None means the pause has no automatic expiry.
+    pub auto_unpause_at: Option<u64>,
+    /// Minimum ledger timestamp before an admin can manually unpause.
+    /// None means no time lock is applied.
+    pub time_lock_until: Option<u64>,
+}
+
+/// One pause of a single token, kept for audits.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct TokenPauseRecord {
+    /// Ledger timestamp when the pause was initiated
+    pub paused_at: u64,
+    /// Admin or guardian that initiated the pause
+    pub paused_by: Address,
+    /// Human-readable reason for the pause
+    pub reason: Option<String>,
+    /// Automatic unpause deadline set at pause time
+    pub auto_unpause_at: Option<u64>,
+    /// Time lock set at pause time
+    pub time_lock_until: Option<u64>,
+    /// When the pause ended; None while it is still active
+    pub unpaused_at: Option<u64>,
+    /// Address that lifted the pause; None if it auto-expired or was superseded
+    pub unpaused_by: Option<Address>,
+    /// Seconds the pause lasted; 0 while it is still active
+    pub duration: u64,
 }
 
 // ============================================================================
@@ -420,6 +657,36 @@ pub struct StakeInfo {
     pub claimed_rewards: i128,
     /// Whether this stake was emergency-unstaked
     pub emergency_unstaked: bool,
+    /// Seconds spent in cancelled unstake cooldowns, excluded from rewards
+    pub paused_secs: u64,
+}
+
+/// A pending unstake waiting out the configured cooldown.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct UnstakeRequest {
+    /// Staker address
+    pub staker: Address,
+    /// Timestamp of the request; rewards stop accruing here
+    pub requested_at: u64,
+    /// Earliest timestamp at which the stake can be withdrawn
+    pub available_at: u64,
+}
+
+/// A slash applied to a staker for a policy violation.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct SlashRecord {
+    /// Share of the stake slashed, in basis points
+    pub bps: u32,
+    /// Tokens moved from the stake into the treasury
+    pub amount: i128,
+    /// Why the stake was slashed
+    pub reason: String,
+    /// Admin that applied the slash
+    pub slashed_by: Address,
+    /// Timestamp of the slash
+    pub slashed_at: u64,
 }
 
 /// Global staking configuration set by admin.
@@ -430,6 +697,11 @@ pub struct StakingConfig {
     pub staking_enabled: bool,
     /// Penalty in basis points applied on emergency unstake (e.g. 1000 = 10%)
     pub emergency_unstake_penalty_bps: u32,
+    /// Largest share of a stake a single slash may take, in basis points
+    pub max_slash_bps: u32,
+    /// Cooldown in seconds between `request_unstake` and `withdraw_unstaked`;
+    /// 0 keeps instant unstaking at lock expiry
+    pub unstake_cooldown: u64,
     /// Token address used for staking (must be a Soroban token)
     pub staking_token: Address,
     /// Reward pool address that distributes reward tokens
@@ -470,6 +742,16 @@ pub struct TokenVersionSnapshot {
     pub label: Option<String>,
 }
 
+/// How many version snapshots are kept per token.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct SnapshotRetentionConfig {
+    /// Number of most recent snapshots to keep per token (0 = keep all)
+    pub max_snapshots: u32,
+    /// Whether the version-0 snapshot taken at mint survives pruning
+    pub keep_original: bool,
+}
+
 /// A single entry in a token's upgrade history.
 #[contracttype]
 #[derive(Clone, Debug, PartialEq)]
@@ -502,10 +784,82 @@ pub struct BatchUpgradeResult {
     pub new_version: Option<u32>,
 }
 
+/// Field changes applied by a scheduled token upgrade. `None` keeps the
+/// token's current value.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct TokenUpgradeChanges {
+    /// Human-readable label for the new version
+    pub label: Option<String>,
+    /// New expiry date
+    pub new_expiry_date: Option<u64>,
+    /// New tier ID
+    pub new_tier_id: Option<String>,
+}
+
+/// Tokens a scheduled upgrade applies to.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub enum UpgradeTarget {
+    /// An explicit list of token IDs
+    Tokens(Vec<BytesN<32>>),
+    /// Every token held by an address when the upgrade takes effect
+    Owner(Address),
+    /// Every token carrying a metadata attribute value when the upgrade
+    /// takes effect
+    Attribute(String, MetadataValue),
+}
+
+/// A token upgrade waiting for its effective time.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ScheduledTokenUpgrade {
+    /// Unique schedule ID
+    pub id: u64,
+    /// Tokens to upgrade; a filter is replaced by the matching token IDs
+    /// once the upgrade starts applying
+    pub target: UpgradeTarget,
+    /// Field changes to apply
+    pub changes: TokenUpgradeChanges,
+    /// Earliest timestamp at which the upgrade is applied
+    pub effective_at: u64,
+    /// Admin that scheduled the upgrade
+    pub scheduled_by: Address,
+    /// Scheduling timestamp
+    pub scheduled_at: u64,
+    /// Number of target tokens already processed
+    pub processed: u32,
+}
+
+/// Result of pausing or unpausing one token in a bulk operation.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct BatchPauseResult {
+    /// Token ID that was processed
+    pub token_id: BytesN<32>,
+    /// Whether the token was paused or unpaused
+    pub success: bool,
+    /// `Error` code the token failed with (if not success)
+    pub error_code: Option<u32>,
+}
+
 // ============================================================================
 // Token Fractionalization Types
 // ============================================================================
 
+/// Who may receive shares of a fractionalized token.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum FractionTransferPolicy {
+    /// Any address
+    Open,
+    /// Only addresses on the token's holder whitelist
+    Whitelist,
+    /// Any address, for transfers the token's owner has approved; the
+    /// owner's own transfers need no approval
+    OwnerApproved,
+}
+
 /// Fractionalization configuration for a token.
 #[contracttype]
 #[derive(Clone, Debug, PartialEq)]
@@ -522,6 +876,67 @@ pub struct FractionalTokenInfo {
     pub created_by: Address,
 }
 
+/// Shares a holder received in one transfer, locked until `unlocks_at`.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ShareLot {
+    pub amount: i128,
+    pub unlocks_at: u64,
+}
+
+/// One payment toward renewing a fractionalized token.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct RenewalContribution {
+    pub holder: Address,
+    /// USDC paid in, in base units
+    pub amount: i128,
+    pub contributed_at: u64,
+}
+
+/// USDC a fractionalized token's share holders have pooled toward its
+/// next renewal.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct RenewalPool {
+    pub token_id: BytesN<32>,
+    pub tier_id: String,
+    /// Token the pool is paid in, fixed by the first contribution
+    pub payment_token: Address,
+    /// Monthly renewal price in `payment_token` as of the latest contribution
+    pub price: i128,
+    /// Amount contributed so far
+    pub funded: i128,
+    /// Contributions in the order they were made
+    pub contributions: Vec<RenewalContribution>,
+}
+
+/// USDC returned to a contributor once a renewal was paid for.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct RenewalRefund {
+    pub holder: Address,
+    pub amount: i128,
+}
+
+/// A renewal of a fractionalized token paid for by its share holders.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct FundedRenewal {
+    pub token_id: BytesN<32>,
+    pub tier_id: String,
+    /// USDC charged for the renewal
+    pub price: i128,
+    /// USDC the holders had pooled
+    pub funded: i128,
+    pub contributions: Vec<RenewalContribution>,
+    /// Over-contributions returned, in proportion to what each holder paid
+    pub refunds: Vec<RenewalRefund>,
+    pub new_expiry: u64,
+    pub renewed_at: u64,
+    pub renewed_by: Address,
+}
+
 /// Holder-level fractional ownership details.
 #[contracttype]
 #[derive(Clone, Debug, PartialEq)]
@@ -534,6 +949,16 @@ pub struct FractionHolder {
     pub voting_power_bps: u32,
 }
 
+/// Share allowance granted through the SEP-41 style fraction interface.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct FractionAllowance {
+    /// Shares the spender may still move
+    pub amount: i128,
+    /// Last ledger sequence at which the allowance is usable
+    pub expiration_ledger: u32,
+}
+
 /// Dividend distribution summary for fractional shares.
 #[contracttype]
 #[derive(Clone, Debug, PartialEq)]
@@ -574,6 +999,31 @@ pub struct RoyaltyConfig {
     pub enabled: bool,
 }
 
+/// Hub-wide fee on secondary-market transfers made with `transfer_from`.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct TransferFeeConfig {
+    /// Fee in basis points of the allowance amount used for the transfer
+    pub fee_bps: u32,
+    /// Least fee charged per transfer, whatever allowance amount the spender
+    /// declares
+    pub min_fee: i128,
+    /// Address receiving the fee, settled in USDC
+    pub recipient: Address,
+}
+
+/// A membership token listed for peer-to-peer sale.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct TokenSale {
+    pub token_id: BytesN<32>,
+    /// Owner at listing time; receives the proceeds
+    pub seller: Address,
+    /// Asking price in USDC, royalties included
+    pub price: i128,
+    pub listed_at: u64,
+}
+
 /// Information returned when querying royalties
 #[contracttype]
 #[derive(Clone, Debug, PartialEq)]
@@ -583,3 +1033,1514 @@ pub struct RoyaltyInfo {
     /// Total percentage across all recipients (in basis points)
     pub total_percentage: u32,
 }
+
+// ============================================================================
+// Contract Initialization Types
+// ============================================================================
+
+/// Module configurations seeded by `initialize`.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct InitConfig {
+    /// Subscription pause limits
+    pub pause_config: PauseConfig,
+    /// Token renewal and grace-period settings
+    pub renewal_config: RenewalConfig,
+    /// Global staking configuration
+    pub staking_config: StakingConfig,
+    /// Token upgrade configuration
+    pub upgrade_config: UpgradeConfig,
+}
+
+// ============================================================================
+// Payment Configuration Types
+// ============================================================================
+
+/// A proposed change of the USDC payment contract awaiting confirmation.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct PendingUsdcChange {
+    /// Currently configured USDC contract
+    pub current: Address,
+    /// Proposed replacement
+    pub proposed: Address,
+    /// Admin that created the proposal
+    pub proposed_by: Address,
+    /// Proposal creation timestamp
+    pub proposed_at: u64,
+    /// Earliest timestamp at which the change can be confirmed
+    pub executable_at: u64,
+}
+
+// ============================================================================
+// Admin Transfer Types
+// ============================================================================
+
+/// A proposed admin hand-over awaiting acceptance by the new admin.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct PendingAdminTransfer {
+    /// Address that must accept the transfer
+    pub proposed_admin: Address,
+    /// Admin that created the proposal
+    pub proposer: Address,
+    /// Timestamp after which the proposal can no longer be accepted
+    pub expiry: u64,
+}
+
+// ============================================================================
+// Treasury Types
+// ============================================================================
+
+/// Category of income credited to the treasury.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum RevenueSource {
+    /// New subscriptions
+    Subscription,
+    /// Subscription and token renewals
+    Renewal,
+    /// Prorated tier upgrade charges
+    TierChange,
+    /// Fees taken on secondary-market sales
+    MarketplaceFee,
+    /// Penalties such as emergency unstake fees
+    Penalty,
+}
+
+/// Revenue totals per source over a reporting period.
+#[contracttype]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct RevenueBreakdown {
+    /// Revenue from new subscriptions
+    pub subscriptions: i128,
+    /// Revenue from renewals
+    pub renewals: i128,
+    /// Revenue from tier changes
+    pub tier_changes: i128,
+    /// Revenue from marketplace fees
+    pub marketplace_fees: i128,
+    /// Revenue from penalties
+    pub penalties: i128,
+    /// Sum of all sources
+    pub total: i128,
+}
+
+// ============================================================================
+// Payment Token Registry Types
+// ============================================================================
+
+/// A non-USDC asset accepted for payments.
+///
+/// Prices are defined in USDC units and converted with
+/// `charge = usdc_price * rate / RATE_SCALE` (`RATE_SCALE` = 10^7).
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct AcceptedPaymentToken {
+    /// Stellar asset contract address
+    pub token: Address,
+    /// Fixed conversion rate: token units per USDC unit, scaled by 10^7
+    pub rate: i128,
+    /// Optional price feed overriding the global oracle for this asset
+    pub oracle: Option<Address>,
+    /// Timestamp when the asset was whitelisted or last updated
+    pub updated_at: u64,
+}
+
+// ============================================================================
+// Price Oracle Types
+// ============================================================================
+
+/// Settings for converting USD-denominated tier prices via a price oracle.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct OracleConfig {
+    /// Default SEP-40 style price feed (overridable per payment asset)
+    pub oracle: Address,
+    /// Maximum age of an oracle price in seconds
+    pub max_staleness: u64,
+    /// Maximum deviation from the asset's fixed rate, in basis points
+    pub max_deviation_bps: u32,
+    /// Use the asset's fixed rate when the oracle is stale or unavailable
+    pub fallback_enabled: bool,
+}
+
+/// Asset identifier used by SEP-40 price feeds.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub enum OracleAsset {
+    /// A Stellar asset contract
+    Stellar(Address),
+    /// An off-chain asset symbol
+    Other(soroban_sdk::Symbol),
+}
+
+/// Price point returned by a SEP-40 price feed.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct OraclePriceData {
+    /// USD price of one whole asset unit, scaled by the feed's decimals
+    pub price: i128,
+    /// Timestamp of the price point
+    pub timestamp: u64,
+}
+
+// ============================================================================
+// Installment Plan Types
+// ============================================================================
+
+/// Payment schedule for an annual subscription paid in installments.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct InstallmentPlan {
+    /// Subscription this plan pays for
+    pub subscription_id: String,
+    /// Subscriber responsible for the payments
+    pub user: Address,
+    /// Asset the installments are paid in
+    pub payment_token: Address,
+    /// Total annual charge in `payment_token` units
+    pub total_amount: i128,
+    /// Amount due per installment (the last one absorbs any rounding remainder)
+    pub installment_amount: i128,
+    /// Number of installments in the plan
+    pub installment_count: u32,
+    /// Installments paid so far
+    pub paid_count: u32,
+    /// Total paid so far in `payment_token` units
+    pub amount_paid: i128,
+    /// Seconds between installment deadlines
+    pub period: u64,
+    /// Deadline for the next installment (0 once paid off)
+    pub next_due_at: u64,
+    /// Whether the plan was suspended after a missed installment
+    pub suspended: bool,
+    /// Plan creation timestamp
+    pub created_at: u64,
+}
+
+// ============================================================================
+// Operator Types
+// ============================================================================
+
+/// Activity counters for a front-desk operator.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct OperatorActivity {
+    /// Attendance entries logged by the operator
+    pub attendance_logged: u32,
+    /// Grace-period checks run by the operator
+    pub grace_checks: u32,
+    /// When the operator was first added
+    pub added_at: u64,
+    /// Timestamp of the operator's most recent action (0 if none)
+    pub last_active_at: u64,
+}
+
+// ============================================================================
+// Attendance Rate Limit Types
+// ============================================================================
+
+/// Per-user limits on attendance logging. A limit of 0 disables that check.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct AttendanceRateLimit {
+    /// Maximum attendance logs per user per clock hour
+    pub max_logs_per_hour: u32,
+    /// Maximum total bytes of detail keys and values per user per UTC day
+    pub max_detail_bytes_per_day: u32,
+}
+
+/// Admin-configured rules for member-supplied attendance details. A limit
+/// of 0 disables that check and an empty allowlist permits any key.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct AttendanceDetailRules {
+    /// Maximum entries in a details map (at most 50)
+    pub max_entries: u32,
+    /// Maximum length of a detail key, in bytes
+    pub max_key_len: u32,
+    /// Maximum length of a detail value, in bytes
+    pub max_value_len: u32,
+    /// Keys permitted in a details map
+    pub allowed_keys: Vec<String>,
+    /// Keys every log of the given action must include
+    pub required_keys: Map<AttendanceAction, Vec<String>>,
+}
+
+// ============================================================================
+// Attendance Archival Types
+// ============================================================================
+
+/// Compact per-month record that replaces a user's archived attendance logs.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ArchivedAttendanceSummary {
+    /// Month key as `YYYYMM` (UTC)
+    pub year_month: u32,
+    /// Raw log entries rolled into this summary
+    pub log_count: u32,
+    /// Clock-ins (visits) in the month
+    pub visit_count: u32,
+    /// Seconds of completed sessions that started in the month
+    pub total_seconds: u64,
+}
+
+// ============================================================================
+// Loyalty Types
+// ============================================================================
+
+/// Points a member earns per event on a tier.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct LoyaltyEarnRate {
+    /// Points per verified attendance session
+    pub session_points: u32,
+    /// Points per subscription or token renewal
+    pub renewal_points: u32,
+}
+
+/// Rewards catalog entry redeemable for subscription-day credits.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct LoyaltyReward {
+    /// Unique reward identifier
+    pub id: String,
+    /// Human-readable reward name
+    pub name: String,
+    /// Points required to redeem
+    pub points_cost: u64,
+    /// Subscription days credited on redemption
+    pub credit_days: u32,
+    /// Whether the reward can currently be redeemed
+    pub is_active: bool,
+}
+
+/// A member's loyalty balance.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct LoyaltyAccount {
+    /// Spendable points
+    pub points: u64,
+    /// Points earned since joining (never decreases)
+    pub lifetime_points: u64,
+    /// Redeemed subscription days not yet applied to a renewal
+    pub day_credits: u32,
+    /// Attendance sessions that met the minimum verified duration
+    pub verified_sessions: u32,
+}
+
+/// A member's daily check-in streak.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct LoyaltyStreak {
+    /// Consecutive days with a check-in, ending on `last_day`
+    pub current: u32,
+    /// Longest streak ever reached
+    pub longest: u32,
+    /// Day number (Unix days) of the most recent check-in
+    pub last_day: u64,
+}
+
+/// Reward granted when a streak reaches a milestone.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct StreakBonus {
+    /// Bonus loyalty points
+    pub points: u32,
+    /// Subscription days credited as a renewal voucher
+    pub credit_days: u32,
+}
+
+// ============================================================================
+// Achievement Types
+// ============================================================================
+
+/// Condition a member must meet to earn a badge.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub enum BadgeCriterion {
+    /// At least this many verified attendance sessions
+    VerifiedSessions(u32),
+    /// A daily check-in streak of at least this many days
+    AttendanceStreak(u32),
+    /// Membership token held for at least this many days
+    MembershipDays(u64),
+    /// An active stake in the given staking tier
+    StakingTier(String),
+    /// Granted by the admin only
+    Manual,
+}
+
+/// Admin-defined badge.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct BadgeDefinition {
+    /// Unique badge identifier, also used as the token attribute key
+    pub id: String,
+    /// Human-readable badge name
+    pub name: String,
+    /// Condition for earning the badge
+    pub criterion: BadgeCriterion,
+    /// Whether the badge can currently be earned
+    pub is_active: bool,
+}
+
+/// Non-transferable badge held by a member.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Badge {
+    /// Badge definition ID
+    pub badge_id: String,
+    /// Timestamp when the badge was earned
+    pub awarded_at: u64,
+}
+
+// ============================================================================
+// Storage Migration Types
+// ============================================================================
+
+/// Kind of persisted entry tracked by the schema migrators.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub enum MigrationEntity {
+    Token,
+    Subscription,
+}
+
+/// Progress of admin-driven batch migration for one entity kind.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct MigrationProgress {
+    /// Entity kind being migrated
+    pub entity: MigrationEntity,
+    /// Schema version the batches migrate to
+    pub target_version: u32,
+    /// Entries upgraded so far
+    pub migrated: u32,
+    /// Entries skipped because they were missing or already current
+    pub skipped: u32,
+    /// Number of batches run
+    pub batches: u32,
+    /// Timestamp of the most recent batch
+    pub last_batch_at: u64,
+    /// Entries the most recent batch upgraded; 0 once a batch finds
+    /// nothing left to migrate
+    pub last_batch_migrated: u32,
+}
+
+/// Migration progress layout written before the last batch was tracked.
+/// Only decoded when old progress is read.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct LegacyMigrationProgress {
+    pub entity: MigrationEntity,
+    pub target_version: u32,
+    pub migrated: u32,
+    pub skipped: u32,
+    pub batches: u32,
+    pub last_batch_at: u64,
+}
+
+// ============================================================================
+// Contract Upgrade Types
+// ============================================================================
+
+/// A WASM upgrade of this contract waiting for its time lock.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ScheduledContractUpgrade {
+    /// Hash of the uploaded WASM to switch to
+    pub new_wasm_hash: BytesN<32>,
+    /// Access control contract or admin that scheduled the upgrade
+    pub scheduled_by: Address,
+    /// Scheduling timestamp
+    pub scheduled_at: u64,
+    /// Earliest timestamp at which the upgrade can be executed
+    pub executable_at: u64,
+}
+
+// ============================================================================
+// Metadata History Types
+// ============================================================================
+
+/// Audit digest of the metadata history entries pruned for a token.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct PrunedMetadataHistory {
+    /// Hash chain over every pruned entry, oldest first:
+    /// `sha256(previous_checksum || xdr(entry))` from 32 zero bytes.
+    pub checksum: BytesN<32>,
+    /// Entries pruned so far.
+    pub pruned_count: u32,
+    /// Metadata version of the newest pruned entry.
+    pub last_pruned_version: u32,
+    pub last_pruned_at: u64,
+}
+
+// ============================================================================
+// Metadata Query Types
+// ============================================================================
+
+/// How multiple attribute filters combine in a metadata query.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub enum MatchMode {
+    /// Tokens matching every filter.
+    All,
+    /// Tokens matching at least one filter.
+    Any,
+}
+
+// ============================================================================
+// Metadata Schema Types
+// ============================================================================
+
+/// `MetadataValue` variant an attribute must use.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub enum MetadataValueKind {
+    Text,
+    Number,
+    Boolean,
+    Timestamp,
+}
+
+/// Schema rule for one metadata attribute key.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct MetadataFieldRule {
+    pub key: String,
+    pub kind: MetadataValueKind,
+    /// Whether every token's metadata must include the key.
+    pub required: bool,
+    /// Inclusive lower bound: value for numbers and timestamps, length for text.
+    pub min: Option<i128>,
+    /// Inclusive upper bound, measured like `min`.
+    pub max: Option<i128>,
+}
+
+/// A versioned set of metadata attribute rules.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct MetadataSchema {
+    pub version: u32,
+    pub fields: Vec<MetadataFieldRule>,
+    pub created_at: u64,
+}
+
+// ============================================================================
+// Household Types
+// ============================================================================
+
+/// Attendance logged by a household's dependents.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct HouseholdAttendance {
+    /// Attendance logs recorded by dependents.
+    pub total_logs: u32,
+    /// Of those, clock-ins.
+    pub clock_ins: u32,
+    /// Dependent who logged most recently.
+    pub last_member: Option<Address>,
+    /// Timestamp of the most recent dependent log.
+    pub last_logged_at: u64,
+}
+
+// ============================================================================
+// Bundle Types
+// ============================================================================
+
+/// Parameters for creating a subscription bundle.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct CreateBundleParams {
+    /// Unique bundle identifier
+    pub id: String,
+    /// Human-readable bundle name
+    pub name: String,
+    /// Subscription tier included in the bundle
+    pub tier_id: String,
+    /// Monthly bundle price in USDC
+    pub price: i128,
+    /// Annual bundle price in USDC
+    pub annual_price: i128,
+    /// Extra staking reward multiplier in basis points (added to the staking tier's)
+    pub staking_bonus_bps: u32,
+    /// Features granted on top of the tier's own
+    pub extra_features: Vec<TierFeature>,
+}
+
+/// A package of a tier, staking bonus and extra features at a combined price.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct SubscriptionBundle {
+    /// Unique bundle identifier
+    pub id: String,
+    /// Human-readable bundle name
+    pub name: String,
+    /// Subscription tier included in the bundle
+    pub tier_id: String,
+    /// Monthly bundle price in USDC
+    pub price: i128,
+    /// Annual bundle price in USDC
+    pub annual_price: i128,
+    /// Extra staking reward multiplier in basis points
+    pub staking_bonus_bps: u32,
+    /// Features granted on top of the tier's own
+    pub extra_features: Vec<TierFeature>,
+    /// Whether new subscriptions are accepted
+    pub is_active: bool,
+    /// Creation timestamp
+    pub created_at: u64,
+}
+
+/// What a member received from their most recent bundle purchase.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct BundleEntitlements {
+    /// Bundle purchased
+    pub bundle_id: String,
+    /// Subscription provisioned by the purchase; entitlements follow its status
+    pub subscription_id: String,
+    /// Extra staking reward multiplier in basis points
+    pub staking_bonus_bps: u32,
+    /// Features granted on top of the tier's own
+    pub extra_features: Vec<TierFeature>,
+    /// Purchase timestamp
+    pub granted_at: u64,
+}
+
+// ============================================================================
+// Entry Access Types
+// ============================================================================
+
+/// Admin-configured rules applied by `verify_entry`.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct EntryPolicy {
+    /// Require an active, unexpired subscription in addition to a token
+    pub require_subscription: bool,
+    /// Seconds after entering the grace period during which entry is still
+    /// allowed; 0 denies entry during grace
+    pub grace_entry_secs: u64,
+    /// Minimum active stake required for entry; 0 disables the requirement
+    pub min_stake: i128,
+}
+
+/// Outcome of an entry check.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub enum EntryStatus {
+    /// Entry allowed
+    Granted,
+    /// The user holds no membership token
+    NoMembership,
+    /// The token is revoked or otherwise inactive
+    MembershipInactive,
+    /// The token has expired
+    MembershipExpired,
+    /// The token is paused
+    MembershipPaused,
+    /// The token is in its grace period beyond what the policy allows
+    GracePeriodExceeded,
+    /// The policy requires an active subscription and none was found
+    NoActiveSubscription,
+    /// The policy requires a larger active stake
+    InsufficientStake,
+    /// A partner hub vouched for the user, but their visits for the period
+    /// are used up
+    PartnerQuotaExceeded,
+}
+
+/// One-time entry pass issued by a member for a guest check-in.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct EntryPass {
+    /// Pass identifier, encoded in the QR code
+    pub pass_id: BytesN<32>,
+    /// Membership token the pass draws on
+    pub token_id: BytesN<32>,
+    /// Token owner who issued the pass
+    pub issued_by: Address,
+    /// Issue timestamp
+    pub created_at: u64,
+    /// Last timestamp at which the pass can be redeemed
+    pub expires_at: u64,
+    /// Redemption timestamp, once consumed
+    pub redeemed_at: Option<u64>,
+    /// Kiosk operator that redeemed the pass
+    pub redeemed_by: Option<Address>,
+}
+
+/// Entry decision returned to access hardware.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct EntryDecision {
+    /// Whether the door should open
+    pub allowed: bool,
+    /// Why entry was granted or denied
+    pub status: EntryStatus,
+    /// Token the decision was based on, if the user holds one
+    pub token_id: Option<BytesN<32>>,
+    /// Partner hub that vouched for the user, if entry rests on a
+    /// partner-issued token
+    pub partner: Option<Address>,
+    /// Timestamp of the check
+    pub checked_at: u64,
+}
+
+// ============================================================================
+// Guest Pass Types
+// ============================================================================
+
+/// A subscriber's invitation for a guest.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct GuestInvite {
+    /// Host subscription
+    pub subscription_id: String,
+    /// Subscriber the guest's attendance is attributed to
+    pub host: Address,
+    /// Invited guest
+    pub guest: Address,
+    /// Invite timestamp
+    pub invited_at: u64,
+    /// Last timestamp at which the guest can log attendance
+    pub valid_until: u64,
+    /// Clock-ins logged under this invite
+    pub visits: u32,
+}
+
+/// Guest visits used by a subscription in its current quota period.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct GuestUsage {
+    /// Subscription the usage belongs to
+    pub subscription_id: String,
+    /// Start of the current period
+    pub period_start: u64,
+    /// End of the current period, when usage resets
+    pub period_end: u64,
+    /// Guest visits spent this period
+    pub used: u32,
+    /// Guest visits allowed per period by the subscription's tier
+    pub quota: u32,
+}
+
+// ============================================================================
+// Session Booking Types
+// ============================================================================
+
+/// Parameters for scheduling a bookable session.
+/// Used to reduce function argument count.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct CreateSessionParams {
+    /// Unique session identifier
+    pub id: String,
+    /// Human-readable session name
+    pub title: String,
+    /// Session start timestamp
+    pub start_time: u64,
+    /// Session end timestamp
+    pub end_time: u64,
+    /// Maximum number of bookings
+    pub capacity: u32,
+    /// Features a subscription needs to book (empty = open to all members)
+    pub required_features: Vec<TierFeature>,
+}
+
+/// A scheduled class or session members can book.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Session {
+    pub id: String,
+    pub title: String,
+    pub start_time: u64,
+    pub end_time: u64,
+    pub capacity: u32,
+    /// Live bookings (cancelled ones release their seat)
+    pub booked: u32,
+    pub required_features: Vec<TierFeature>,
+    pub created_at: u64,
+    /// Set once unattended bookings have been marked as no-shows
+    pub no_shows_recorded: bool,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub enum BookingStatus {
+    Booked,
+    Cancelled,
+    Attended,
+    NoShow,
+}
+
+/// A subscription's seat in a session.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Booking {
+    pub session_id: String,
+    pub subscription_id: String,
+    pub user: Address,
+    pub status: BookingStatus,
+    pub booked_at: u64,
+    /// Check-in timestamp (0 until the member checks in)
+    pub checked_in_at: u64,
+}
+
+/// Session bookings used and allowed in a subscription's current period.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct BookingUsage {
+    /// Subscription the usage belongs to
+    pub subscription_id: String,
+    /// Start of the current period
+    pub period_start: u64,
+    /// End of the current period, when usage resets
+    pub period_end: u64,
+    /// Bookings made this period
+    pub used: u32,
+    /// Bookings allowed per period by the subscription's tier
+    pub quota: u32,
+}
+
+// ============================================================================
+// Resource Reservation Types
+// ============================================================================
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub enum ResourceKind {
+    MeetingRoom,
+    Locker,
+    Equipment,
+}
+
+/// Parameters for registering a reservable resource.
+/// Used to reduce function argument count.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct RegisterResourceParams {
+    /// Unique resource identifier
+    pub id: String,
+    /// Human-readable resource name
+    pub name: String,
+    /// Kind of resource
+    pub kind: ResourceKind,
+    /// Refundable USDC deposit held per reservation (0 = no deposit)
+    pub deposit: i128,
+    /// Whether the deposit is forfeited when the member does not show up
+    pub forfeit_on_no_show: bool,
+}
+
+/// A meeting room, locker or piece of equipment members can reserve.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Resource {
+    pub id: String,
+    pub name: String,
+    pub kind: ResourceKind,
+    pub deposit: i128,
+    pub forfeit_on_no_show: bool,
+    /// Inactive resources accept no new reservations
+    pub active: bool,
+    pub created_at: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub enum ReservationStatus {
+    /// Upcoming or in progress, not yet checked in
+    Reserved,
+    /// The member checked in
+    CheckedIn,
+    /// Cancelled before the start; the deposit was refunded
+    Cancelled,
+    /// Settled after the member used it; the deposit was refunded
+    Completed,
+    /// Settled as a no-show; the deposit was refunded
+    NoShow,
+    /// Settled as a no-show; the deposit went to the treasury
+    Forfeited,
+}
+
+/// A subscription's hold on a resource for a time range.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Reservation {
+    pub id: u64,
+    pub resource_id: String,
+    pub subscription_id: String,
+    pub user: Address,
+    pub start_time: u64,
+    pub end_time: u64,
+    /// USDC deposit held in escrow for the reservation
+    pub deposit: i128,
+    /// Token the deposit was paid in (`None` when no deposit was taken)
+    pub deposit_token: Option<Address>,
+    pub status: ReservationStatus,
+    pub created_at: u64,
+    /// Check-in timestamp (0 until the member checks in)
+    pub checked_in_at: u64,
+}
+
+// ============================================================================
+// Staff Payroll Types
+// ============================================================================
+
+/// A staff member paid per shift.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct StaffMember {
+    pub address: Address,
+    /// Pay per hour worked, in USDC
+    pub hourly_rate: i128,
+    /// Inactive staff can no longer have shifts recorded
+    pub active: bool,
+    pub registered_at: u64,
+}
+
+/// A worked shift, built from a staff member's clock-in and clock-out logs.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct StaffShift {
+    pub staff: Address,
+    pub clock_in_log: BytesN<32>,
+    pub clock_out_log: BytesN<32>,
+    pub start_time: u64,
+    pub end_time: u64,
+    /// Hourly rate in force when the shift was recorded
+    pub hourly_rate: i128,
+    /// Pay earned for the shift
+    pub amount: i128,
+    /// Set once the shift has been paid out
+    pub paid: bool,
+}
+
+/// A payroll payout to one staff member for one period.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct PayrollPayment {
+    pub id: u64,
+    pub staff: Address,
+    /// Shifts ending in this period were paid
+    pub period: DateRange,
+    pub shifts: u32,
+    /// Total seconds worked across the paid shifts
+    pub worked_secs: u64,
+    pub amount: i128,
+    pub paid_at: u64,
+}
+
+// ============================================================================
+// Federation Types
+// ============================================================================
+
+/// A trusted manage_hub instance run by a partner location.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct PartnerHub {
+    /// Partner manage_hub contract
+    pub contract: Address,
+    pub name: String,
+    /// Visits each partner member may make here per 30-day period
+    pub visit_quota: u32,
+    /// Inactive partners' tokens are no longer recognized
+    pub active: bool,
+    pub added_at: u64,
+}
+
+/// Visits exchanged with a partner hub.
+#[contracttype]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct PartnerUsage {
+    /// Visits by the partner's members at this hub
+    pub inbound_visits: u64,
+    /// Visits by this hub's members at the partner, as reported by it
+    pub outbound_visits: u64,
+    /// Timestamp of the latest inbound visit (0 if none)
+    pub last_inbound_at: u64,
+    /// Timestamp of the latest reported outbound visit (0 if none)
+    pub last_outbound_at: u64,
+}
+
+/// A partner member's visits in the current quota period.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct PartnerVisits {
+    pub period_start: u64,
+    pub used: u32,
+}
+
+// ============================================================================
+// Hub Factory Types
+// ============================================================================
+
+/// Parameters for deploying a new hub from the factory.
+/// Used to reduce function argument count.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct DeployHubParams {
+    /// Salt the new contract address is derived from
+    pub salt: BytesN<32>,
+    /// Human-readable hub name
+    pub name: String,
+    /// Admin of the new hub (must authorize its initialization)
+    pub admin: Address,
+    /// USDC token contract used for payments at the new hub
+    pub usdc: Address,
+    /// Module configuration the new hub is initialized with
+    pub config: InitConfig,
+}
+
+/// A hub deployed by the factory.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct HubRecord {
+    pub address: Address,
+    pub name: String,
+    pub admin: Address,
+    /// access_control contract shared with the factory
+    pub access_control: Address,
+    /// WASM the hub was deployed from
+    pub wasm_hash: BytesN<32>,
+    pub deployed_by: Address,
+    pub deployed_at: u64,
+}
+
+// ============================================================================
+// Tier Cohort Analytics Types
+// ============================================================================
+
+/// Subscribers who joined a tier in one 30-day analytics period.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct TierCohort {
+    /// Start of the period the cohort joined in
+    pub period_start: u64,
+    /// Subscriptions created in the period
+    pub created: u32,
+    /// Of those, still counted as active
+    pub retained: u32,
+}
+
+/// Tier revenue booked in one 30-day analytics period.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct TierRevenuePoint {
+    /// Start of the period
+    pub period_start: u64,
+    /// Revenue booked in the period
+    pub revenue: i128,
+}
+
+/// Contract-wide totals returned by `get_contract_stats`.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ContractStats {
+    /// Membership tokens issued
+    pub tokens_issued: u64,
+    /// Tokens that are currently active
+    pub tokens_active: u64,
+    /// Tokens that have lapsed past their expiry date and not been renewed
+    pub tokens_expired: u64,
+    /// Subscriptions across all tracked statuses
+    pub total_subscriptions: u64,
+    /// Subscriptions currently active
+    pub subscriptions_active: u64,
+    /// Subscriptions currently paused
+    pub subscriptions_paused: u64,
+    /// Subscriptions cancelled or lapsed on missed installments
+    pub subscriptions_inactive: u64,
+    /// Subscriptions marked expired
+    pub subscriptions_expired: u64,
+    /// Principal currently held in stakes
+    pub total_staked: i128,
+    /// Tokens currently split into fractions
+    pub fractionalized_tokens: u64,
+    /// Revenue booked by the treasury since deployment
+    pub lifetime_revenue: i128,
+}
+
+/// Health counters returned by `get_operational_metrics`.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct OperationalMetrics {
+    /// Subscriptions currently paused
+    pub subscriptions_paused: u64,
+    /// Tokens whose grace period has not ended
+    pub tokens_in_grace_period: u64,
+    /// Auto-renewals that failed within the failure window
+    pub auto_renewal_failures: u64,
+    /// Length of the failure window, in days counting today
+    pub failure_window_days: u32,
+    pub measured_at: u64,
+}
+
+/// Preview of the charge or credit produced by a tier change.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct TierChangeQuote {
+    pub subscription_id: String,
+    pub from_tier: String,
+    pub to_tier: String,
+    pub change_type: TierChangeType,
+    /// Seconds of the current billing period being prorated
+    pub remaining_secs: u64,
+    /// Unused value of the current tier, rounded down
+    pub unused_credit: i128,
+    /// Cost of the new tier for the remaining time, rounded up
+    pub new_cost: i128,
+    /// Amount charged when the change is processed
+    pub amount_due: i128,
+    /// Amount credited to the user's credit balance
+    pub credit: i128,
+}
+
+/// Campaign performance of a promotion. Amounts are USDC-denominated.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct PromotionAnalytics {
+    pub promo_id: String,
+    /// Subscriptions created with the promotion
+    pub redemptions: u32,
+    /// Total discount off list price across redemptions
+    pub gross_discount: i128,
+    /// Revenue from subscriptions created with the promotion, at redemption
+    /// and on their renewals
+    pub revenue: i128,
+    /// Promotional subscriptions that went on to renew at least once
+    pub conversions: u32,
+    /// conversions / redemptions in basis points. Computed on read.
+    pub conversion_rate_bps: u32,
+}
+
+/// Retirement plan of a tier: new subscriptions are blocked from
+/// `announced_at`, and remaining subscribers move to the replacement tier
+/// from `migrate_at`.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct TierDeprecation {
+    pub tier_id: String,
+    pub replacement_tier_id: String,
+    pub announced_at: u64,
+    /// Earliest time subscribers can be migrated
+    pub migrate_at: u64,
+    /// Admin who deprecated the tier
+    pub deprecated_by: Address,
+    /// Subscriptions moved to the replacement tier so far
+    pub migrated_count: u32,
+}
+
+/// Side-by-side view of two tiers. Differences are `tier_b` minus `tier_a`.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct TierComparison {
+    pub tier_a: String,
+    pub tier_b: String,
+    pub price_difference: i128,
+    pub annual_price_difference: i128,
+    /// Features only `tier_a` includes
+    pub only_in_a: Vec<TierFeature>,
+    /// Features only `tier_b` includes
+    pub only_in_b: Vec<TierFeature>,
+    /// Features both tiers include
+    pub shared_features: Vec<TierFeature>,
+    /// User limits (0 = unlimited)
+    pub max_users_a: u32,
+    pub max_users_b: u32,
+    /// Storage limits in bytes (0 = unlimited)
+    pub max_storage_a: u64,
+    pub max_storage_b: u64,
+    /// Guest visits per 30 days
+    pub guest_quota_a: u32,
+    pub guest_quota_b: u32,
+}
+
+/// Cheapest active tier that covers a user's observed usage.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct TierRecommendation {
+    pub tier_id: String,
+    /// Annual for frequent attendees when it is cheaper than paying monthly
+    pub billing_cycle: BillingCycle,
+    /// List price for `billing_cycle`
+    pub price: i128,
+    /// Owner plus household members of the user's current subscription
+    pub household_size: u32,
+    /// Guest visits used in the current guest period
+    pub guest_visits: u32,
+    /// Clock-ins over the last 30 days
+    pub monthly_attendance: u32,
+}
+
+/// A feature granted to one subscription outside its tier.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct FeatureOverride {
+    pub feature: TierFeature,
+    /// Admin who granted the override
+    pub granted_by: Address,
+    pub granted_at: u64,
+    /// Timestamp after which the override no longer applies
+    pub expires_at: u64,
+}
+
+/// Entity an outbox record refers to.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub enum OutboxSubject {
+    Token(BytesN<32>),
+    Subscription(String),
+    Account(Address),
+}
+
+/// Compact record of a state change in the on-chain outbox.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct OutboxRecord {
+    /// Sequence number, one above the previous record's
+    pub seq: u64,
+    /// Kind of change, e.g. `sub_new` or `tok_xfer`
+    pub kind: Symbol,
+    pub subject: OutboxSubject,
+    pub timestamp: u64,
+    /// Ledger the change was made in
+    pub ledger: u32,
+}
+
+/// Membership transitions a member is notified about.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub enum NotificationKind {
+    GracePeriodEntered,
+    AutoRenewalFailed,
+    PauseExpiring,
+    TierChangeProcessed,
+}
+
+/// Entry in a member's notification queue.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Notification {
+    /// Per-user sequence number, one above the previous notification's
+    pub seq: u64,
+    pub kind: NotificationKind,
+    pub subject: OutboxSubject,
+    pub created_at: u64,
+    /// When the deadline behind the notification falls, e.g. the end of a
+    /// grace period or pause (0 if none)
+    pub due_at: u64,
+}
+
+/// Operation types the access_control blacklist can be enforced on.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub enum BlacklistOperation {
+    /// Token issuance, checked against the recipient.
+    Issue,
+    /// Token transfers and sales, checked against both parties.
+    Transfer,
+    /// Subscription creation and renewal, checked against the subscriber.
+    Subscription,
+    /// Staking, checked against the staker.
+    Staking,
+}
+
+/// A verified (KYC'd) address.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct VerificationRecord {
+    pub user: Address,
+    /// Admin or designated verifier that recorded the verification
+    pub verified_by: Address,
+    pub verified_at: u64,
+    /// The verification no longer counts from this time
+    pub expires_at: u64,
+}
+
+/// Operations that require the acting address to be verified.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct VerificationPolicy {
+    /// Token owners must be verified to fractionalize
+    pub fractionalization: bool,
+    /// Stakers must be verified once their stake exceeds this amount
+    pub stake_threshold: Option<i128>,
+    /// Household (organisation) owners must be verified to add members
+    pub households: bool,
+}
+
+/// What a dispute contests.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub enum DisputeSubject {
+    /// A membership token, e.g. a wrongful pause.
+    Token(BytesN<32>),
+    /// A subscription, by ID.
+    Subscription(String),
+    /// An attendance log entry, by ID.
+    Attendance(BytesN<32>),
+}
+
+/// Lifecycle of a dispute.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub enum DisputeStatus {
+    Open,
+    /// The contested action stands.
+    Upheld,
+    /// The contested action was reversed.
+    Reversed,
+    /// The member was compensated with loyalty points.
+    Compensated,
+}
+
+/// Resolution chosen for an open dispute.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub enum DisputeOutcome {
+    Uphold,
+    /// Reverse the action; lifts the pause on a disputed token.
+    Reverse,
+    /// Credit the member with this many loyalty points.
+    Compensate(u32),
+}
+
+/// A member's dispute and its resolution.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Dispute {
+    pub id: u64,
+    pub user: Address,
+    pub subject: DisputeSubject,
+    /// Hash of the off-chain evidence
+    pub evidence_hash: BytesN<32>,
+    /// Token the dispute concerns, if any
+    pub linked_token: Option<BytesN<32>>,
+    /// Subscription the dispute concerns, if any
+    pub linked_subscription: Option<String>,
+    pub status: DisputeStatus,
+    pub filed_at: u64,
+    pub resolved_at: Option<u64>,
+    pub resolved_by: Option<Address>,
+    /// Loyalty points paid out as compensation
+    pub credited_points: u32,
+}
+
+/// Ledger TTL settings for one class of persistent entries.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct TtlSetting {
+    /// Extend once fewer than this many ledgers remain
+    pub threshold: u32,
+    /// Number of ledgers to extend the entry's lifetime to
+    pub extend_to: u32,
+}
+
+/// TTL settings for each class of persistent entries.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct TtlPolicy {
+    pub token: TtlSetting,
+    /// Subscriptions, installment plans and feature overrides
+    pub subscription: TtlSetting,
+    pub tier: TtlSetting,
+    /// Append-only histories such as renewal history
+    pub history: TtlSetting,
+    /// Admin configuration such as accepted payment tokens
+    pub config: TtlSetting,
+}
+
+/// A persistent entry whose TTL the admin can extend.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub enum TtlEntityKey {
+    Token(BytesN<32>),
+    Subscription(String),
+    /// Installment plan of a subscription, by subscription ID.
+    InstallmentPlan(String),
+    Tier(String),
+    /// Renewal history of a token.
+    RenewalHistory(BytesN<32>),
+}
+
+// ============================================================================
+// Billing Types
+// ============================================================================
+
+/// Fee components added on top of tier prices. Regional tax rates are
+/// configured separately per region code.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct FeeConfig {
+    /// Platform fee in basis points of the price
+    pub platform_fee_bps: u32,
+    /// Fixed processing fee per charge, USDC-denominated
+    pub processing_fee: i128,
+}
+
+/// A charge split into its price and fee components. All amounts are in
+/// the same units, and `total` is their sum.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct FeeBreakdown {
+    /// Tier price after promotions and credit
+    pub base: i128,
+    /// Platform fee
+    pub platform_fee: i128,
+    /// Fixed processing fee
+    pub processing_fee: i128,
+    /// Regional tax on the price and fees
+    pub tax: i128,
+    /// Amount due
+    pub total: i128,
+}
+
+/// Payment path that produced a billing record.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum BillingKind {
+    /// Initial charge when the subscription was created
+    Create,
+    /// Subscription renewal
+    Renew,
+    /// Prorated charge for a tier upgrade
+    TierChange,
+    /// Installment plan payment, including the first one
+    Installment,
+    /// Charge for bringing back a cancelled or expired subscription
+    Reactivate,
+}
+
+/// A charge made against a subscription.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct BillingRecord {
+    /// Subscription that was charged
+    pub subscription_id: String,
+    /// Address that paid
+    pub payer: Address,
+    /// Payment path
+    pub kind: BillingKind,
+    /// Amount charged, in units of `payment_token`
+    pub amount: i128,
+    /// Asset the charge was paid in
+    pub payment_token: Address,
+    /// Promo code applied to the charge, if any
+    pub promo_code: Option<String>,
+    /// Timestamp of the charge
+    pub timestamp: u64,
+    /// Invoice issued for the charge
+    pub invoice_id: u64,
+}
+
+/// Payment state of an invoice.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum InvoiceStatus {
+    Paid,
+    Refunded,
+}
+
+/// A single charge line on an invoice.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct InvoiceLineItem {
+    /// What was charged for, e.g. "Subscription renewal"
+    pub description: String,
+    /// Line amount, in units of the invoice's payment token
+    pub amount: i128,
+}
+
+/// Invoice issued for a billing event. Invoice IDs increase by one per
+/// invoice across the contract.
+///
+/// Line items add up to `subtotal` when the charge carried a regional tax,
+/// and to `total` when the tax is included in the price.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Invoice {
+    /// Invoice number
+    pub invoice_id: u64,
+    /// Subscription the invoice belongs to
+    pub subscription_id: String,
+    /// Address that paid
+    pub payer: Address,
+    /// Asset the invoice was paid in
+    pub payment_token: Address,
+    /// Charged items
+    pub line_items: Vec<InvoiceLineItem>,
+    /// Total before tax
+    pub subtotal: i128,
+    /// Tax included in `total`
+    pub tax: i128,
+    /// Amount charged
+    pub total: i128,
+    /// Payment state
+    pub status: InvoiceStatus,
+    /// Issue timestamp
+    pub issued_at: u64,
+    /// Timestamp at which the invoice was marked refunded
+    pub refunded_at: Option<u64>,
+}
+
+// ============================================================================
+// Retention Types
+// ============================================================================
+
+/// Structured answer to the cancellation survey.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum CancellationReason {
+    /// The user skipped the survey
+    Unspecified,
+    TooExpensive,
+    NotUsingEnough,
+    MissingFeatures,
+    SwitchedProvider,
+    TechnicalIssues,
+    Other,
+}
+
+/// Why and when a subscription was cancelled, kept for churn analytics.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct CancellationRecord {
+    /// Cancelled subscription
+    pub subscription_id: String,
+    /// Subscription owner
+    pub user: Address,
+    /// Tier the subscription was on, empty for legacy subscriptions
+    pub tier_id: String,
+    /// Survey answer, `Unspecified` if the user gave none
+    pub reason: CancellationReason,
+    /// Cancellation timestamp
+    pub cancelled_at: u64,
+}
+
+/// Win-back offer attached to users when they cancel.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct WinbackConfig {
+    /// Discount on the next tier subscription, in percent (1–100)
+    pub discount_percent: u32,
+    /// Days after cancellation the offer stays valid
+    pub valid_days: u32,
+}
+
+/// Discounted re-subscription offered to a user who cancelled.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct WinbackOffer {
+    /// Subscription whose cancellation produced the offer
+    pub subscription_id: String,
+    /// Discount on the next tier subscription, in percent
+    pub discount_percent: u32,
+    /// Offer timestamp
+    pub issued_at: u64,
+    /// Last timestamp at which the offer can be redeemed
+    pub expires_at: u64,
+}
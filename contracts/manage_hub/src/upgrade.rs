@@ -7,14 +7,29 @@
 //! - `get_token_version`      — query a token's current version number
 //! - `get_upgrade_history`    — retrieve a token's full upgrade history
 //! - `rollback_token_upgrade` — revert a token to a previous version
+//! - `get_version_snapshot`   — read the stored state of a token version
+//! - `set_snapshot_retention` — admin configures snapshot pruning
+//! - `schedule_batch_upgrade` — queue an upgrade for a future effective time
+//! - `apply_scheduled_upgrades` — keeper applies upgrades that have come due
+//! - `cancel_scheduled_token_upgrade` — drop a queued upgrade before it takes effect
+//!
+//! A token's version-0 snapshot is stored at mint, and every upgrade,
+//! rollback, renewal or status change makes sure the current version has a
+//! snapshot before mutating the token. A version's snapshot is written once,
+//! so it holds the state the token had when it entered that version.
 
 #![allow(deprecated)]
 
-use crate::errors::Error;
-use crate::membership_token::{DataKey, MembershipToken};
+use crate::errors::{Error, ModuleError};
+use crate::membership_token::{DataKey, MembershipToken, MembershipTokenContract};
 use crate::migration::MigrationModule;
-use crate::types::{BatchUpgradeResult, MembershipStatus, UpgradeConfig};
+use crate::ttl::{TtlEntityClass, TtlModule};
+use crate::types::{
+    BatchUpgradeResult, MembershipStatus, ScheduledTokenUpgrade, SnapshotRetentionConfig,
+    TokenUpgradeChanges, TokenVersionSnapshot, UpgradeConfig, UpgradeTarget,
+};
 use crate::upgrade_errors::UpgradeError;
+use crate::validation::{BatchValidator, MAX_BATCH_SIZE};
 use soroban_sdk::{Address, BytesN, Env, String, Vec};
 
 // ---------------------------------------------------------------------------
@@ -43,7 +58,7 @@ impl UpgradeModule {
         env: Env,
         admin: Address,
         config: UpgradeConfig,
-    ) -> Result<(), Error> {
+    ) -> Result<(), ModuleError> {
         let stored_admin: Address = env
             .storage()
             .instance()
@@ -51,7 +66,7 @@ impl UpgradeModule {
             .ok_or(Error::AdminNotSet)?;
         stored_admin.require_auth();
         if stored_admin != admin {
-            return Err(Error::Unauthorized);
+            return Err(Error::Unauthorized.into());
         }
 
         env.storage()
@@ -60,6 +75,30 @@ impl UpgradeModule {
         Ok(())
     }
 
+    /// Set how many version snapshots are kept per token. Admin only.
+    ///
+    /// Takes effect the next time a token is snapshotted.
+    pub fn set_snapshot_retention(
+        env: Env,
+        admin: Address,
+        config: SnapshotRetentionConfig,
+    ) -> Result<(), ModuleError> {
+        let stored_admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::AdminNotSet)?;
+        stored_admin.require_auth();
+        if stored_admin != admin {
+            return Err(Error::Unauthorized.into());
+        }
+
+        env.storage()
+            .instance()
+            .set(&DataKey::SnapshotRetention, &config);
+        Ok(())
+    }
+
     // -----------------------------------------------------------------------
     // Core upgrade operations
     // -----------------------------------------------------------------------
@@ -81,7 +120,7 @@ impl UpgradeModule {
         new_expiry_date: Option<u64>,
         new_tier_id: Option<String>,
         new_status: Option<MembershipStatus>,
-    ) -> Result<u32, Error> {
+    ) -> Result<u32, ModuleError> {
         caller.require_auth();
 
         let config = Self::get_config(&env)?;
@@ -117,13 +156,7 @@ impl UpgradeModule {
         let to_version = from_version.checked_add(1).ok_or(UpgradeError::Overflow)?;
 
         // Capture pre-upgrade snapshot (so we can rollback to this version)
-        let snapshot = MigrationModule::capture_snapshot(&env, &token, label.clone());
-        MigrationModule::store_snapshot(&env, &token_id, &snapshot);
-        env.storage().persistent().extend_ttl(
-            &DataKey::VersionSnapshot(token_id.clone(), from_version),
-            VERSION_SNAPSHOT_TTL_LEDGERS,
-            VERSION_SNAPSHOT_TTL_LEDGERS,
-        );
+        Self::snapshot_token(&env, &token_id, &token, label.clone());
 
         // Apply field migrations
         let new_tier_opt: Option<Option<String>> = new_tier_id.map(Some);
@@ -139,6 +172,17 @@ impl UpgradeModule {
         env.storage()
             .persistent()
             .set(&DataKey::Token(token_id.clone()), &updated_token);
+        MembershipTokenContract::reindex_expiry(
+            &env,
+            &token_id,
+            Some(token.expiry_date),
+            updated_token.expiry_date,
+        );
+        TtlModule::bump(
+            &env,
+            TtlEntityClass::Token,
+            &DataKey::Token(token_id.clone()),
+        );
 
         // Record upgrade history
         let record = MigrationModule::build_record(
@@ -181,7 +225,7 @@ impl UpgradeModule {
         token_ids: Vec<BytesN<32>>,
         label: Option<String>,
         new_expiry_date: Option<u64>,
-    ) -> Result<Vec<BatchUpgradeResult>, Error> {
+    ) -> Result<Vec<BatchUpgradeResult>, ModuleError> {
         admin.require_auth();
 
         let stored_admin: Address = env
@@ -190,7 +234,7 @@ impl UpgradeModule {
             .get(&DataKey::Admin)
             .ok_or(Error::AdminNotSet)?;
         if stored_admin != admin {
-            return Err(Error::Unauthorized);
+            return Err(Error::Unauthorized.into());
         }
 
         let config = Self::get_config(&env)?;
@@ -198,16 +242,15 @@ impl UpgradeModule {
             return Err(UpgradeError::UpgradesDisabled.into());
         }
 
+        let changes = TokenUpgradeChanges {
+            label,
+            new_expiry_date,
+            new_tier_id: None,
+        };
         let mut results: Vec<BatchUpgradeResult> = Vec::new(&env);
 
         for token_id in token_ids.iter() {
-            let result = Self::upgrade_single_for_batch(
-                &env,
-                &admin,
-                &token_id,
-                label.clone(),
-                new_expiry_date,
-            );
+            let result = Self::upgrade_single_for_batch(&env, &admin, &token_id, &changes);
             match result {
                 Ok(new_version) => results.push_back(BatchUpgradeResult {
                     token_id: token_id.clone(),
@@ -225,6 +268,188 @@ impl UpgradeModule {
         Ok(results)
     }
 
+    // -----------------------------------------------------------------------
+    // Scheduled upgrades
+    // -----------------------------------------------------------------------
+
+    /// Queue an upgrade of `target` to be applied at `effective_at`. Admin
+    /// only. Owner and attribute filters are resolved when the upgrade starts
+    /// applying, not when it is scheduled.
+    ///
+    /// Returns the schedule ID.
+    pub fn schedule_batch_upgrade(
+        env: Env,
+        admin: Address,
+        target: UpgradeTarget,
+        effective_at: u64,
+        changes: TokenUpgradeChanges,
+    ) -> Result<u64, ModuleError> {
+        Self::require_admin(&env, &admin)?;
+
+        let config = Self::get_config(&env)?;
+        if !config.upgrades_enabled {
+            return Err(UpgradeError::UpgradesDisabled.into());
+        }
+        let now = env.ledger().timestamp();
+        if effective_at <= now {
+            return Err(UpgradeError::InvalidEffectiveTime.into());
+        }
+        if let UpgradeTarget::Tokens(token_ids) = &target {
+            BatchValidator::validate_batch_size(token_ids.len())?;
+        }
+
+        let id: u64 = env
+            .storage()
+            .instance()
+            .get(&DataKey::NextTokenUpgradeId)
+            .unwrap_or(1);
+        env.storage()
+            .instance()
+            .set(&DataKey::NextTokenUpgradeId, &(id + 1));
+
+        let scheduled = ScheduledTokenUpgrade {
+            id,
+            target,
+            changes,
+            effective_at,
+            scheduled_by: admin.clone(),
+            scheduled_at: now,
+            processed: 0,
+        };
+        Self::save_scheduled(&env, &scheduled);
+        let mut pending = Self::pending_ids(&env);
+        pending.push_back(id);
+        Self::save_pending_ids(&env, &pending);
+
+        env.events().publish(
+            (String::from_str(&env, "TokenUpgradeScheduled"), id, admin),
+            effective_at,
+        );
+
+        Ok(id)
+    }
+
+    /// Apply scheduled upgrades whose effective time has passed, oldest
+    /// schedule first. Callable by anyone (e.g. a keeper).
+    ///
+    /// At most `limit` tokens (capped at `MAX_BATCH_SIZE`) are upgraded per
+    /// call; a partly applied schedule resumes where it stopped on the next
+    /// call. Individual token failures are reported in the result and do not
+    /// stop the run.
+    pub fn apply_scheduled_upgrades(
+        env: Env,
+        limit: u32,
+    ) -> Result<Vec<BatchUpgradeResult>, ModuleError> {
+        let config = Self::get_config(&env)?;
+        if !config.upgrades_enabled {
+            return Err(UpgradeError::UpgradesDisabled.into());
+        }
+
+        let now = env.ledger().timestamp();
+        let mut budget = limit.min(MAX_BATCH_SIZE);
+        let mut results: Vec<BatchUpgradeResult> = Vec::new(&env);
+        let pending = Self::pending_ids(&env);
+        let mut remaining: Vec<u64> = Vec::new(&env);
+
+        for id in pending.iter() {
+            let Some(mut scheduled) = Self::load_scheduled(&env, id) else {
+                continue;
+            };
+            if budget == 0 || scheduled.effective_at > now {
+                remaining.push_back(id);
+                continue;
+            }
+
+            let token_ids = Self::resolve_target(&env, &scheduled.target);
+            scheduled.target = UpgradeTarget::Tokens(token_ids.clone());
+            while scheduled.processed < token_ids.len() && budget > 0 {
+                let token_id = token_ids.get(scheduled.processed).unwrap();
+                let result = Self::upgrade_single_for_batch(
+                    &env,
+                    &scheduled.scheduled_by,
+                    &token_id,
+                    &scheduled.changes,
+                );
+                results.push_back(BatchUpgradeResult {
+                    token_id,
+                    success: result.is_ok(),
+                    new_version: result.ok(),
+                });
+                scheduled.processed += 1;
+                budget -= 1;
+            }
+
+            if scheduled.processed < token_ids.len() {
+                Self::save_scheduled(&env, &scheduled);
+                remaining.push_back(id);
+            } else {
+                env.storage()
+                    .persistent()
+                    .remove(&DataKey::ScheduledTokenUpgrade(id));
+                env.events().publish(
+                    (String::from_str(&env, "TokenUpgradeApplied"), id),
+                    scheduled.processed,
+                );
+            }
+        }
+
+        Self::save_pending_ids(&env, &remaining);
+        Ok(results)
+    }
+
+    /// Drop a scheduled upgrade before its effective time. Admin only.
+    pub fn cancel_scheduled_token_upgrade(
+        env: Env,
+        admin: Address,
+        schedule_id: u64,
+    ) -> Result<(), ModuleError> {
+        Self::require_admin(&env, &admin)?;
+
+        let scheduled =
+            Self::load_scheduled(&env, schedule_id).ok_or(UpgradeError::ScheduleNotFound)?;
+        if env.ledger().timestamp() >= scheduled.effective_at {
+            return Err(UpgradeError::UpgradeAlreadyEffective.into());
+        }
+
+        env.storage()
+            .persistent()
+            .remove(&DataKey::ScheduledTokenUpgrade(schedule_id));
+        let mut pending = Self::pending_ids(&env);
+        if let Some(index) = pending.first_index_of(schedule_id) {
+            pending.remove(index);
+        }
+        Self::save_pending_ids(&env, &pending);
+
+        env.events().publish(
+            (
+                String::from_str(&env, "TokenUpgradeCancelled"),
+                schedule_id,
+                admin,
+            ),
+            scheduled.effective_at,
+        );
+        Ok(())
+    }
+
+    /// Return a scheduled upgrade that has not been fully applied or cancelled.
+    pub fn get_scheduled_token_upgrade(
+        env: Env,
+        schedule_id: u64,
+    ) -> Option<ScheduledTokenUpgrade> {
+        Self::load_scheduled(&env, schedule_id)
+    }
+
+    /// Return every scheduled upgrade still pending, oldest first.
+    pub fn get_pending_token_upgrades(env: Env) -> Vec<ScheduledTokenUpgrade> {
+        let mut upgrades = Vec::new(&env);
+        for id in Self::pending_ids(&env).iter() {
+            if let Some(scheduled) = Self::load_scheduled(&env, id) {
+                upgrades.push_back(scheduled);
+            }
+        }
+        upgrades
+    }
+
     // -----------------------------------------------------------------------
     // Rollback
     // -----------------------------------------------------------------------
@@ -240,7 +465,7 @@ impl UpgradeModule {
         admin: Address,
         token_id: BytesN<32>,
         target_version: u32,
-    ) -> Result<u32, Error> {
+    ) -> Result<u32, ModuleError> {
         admin.require_auth();
 
         let stored_admin: Address = env
@@ -249,7 +474,7 @@ impl UpgradeModule {
             .get(&DataKey::Admin)
             .ok_or(Error::AdminNotSet)?;
         if stored_admin != admin {
-            return Err(Error::Unauthorized);
+            return Err(Error::Unauthorized.into());
         }
 
         let config = Self::get_config(&env)?;
@@ -280,9 +505,7 @@ impl UpgradeModule {
         let rollback_label = Some(String::from_str(&env, "rollback"));
 
         // Capture snapshot of current state before overwriting
-        let current_snapshot =
-            MigrationModule::capture_snapshot(&env, &token, rollback_label.clone());
-        MigrationModule::store_snapshot(&env, &token_id, &current_snapshot);
+        Self::snapshot_token(&env, &token_id, &token, rollback_label.clone());
 
         // Apply snapshot fields but keep version incrementing
         let rolled_back_token = MigrationModule::apply_snapshot_to_token(
@@ -296,6 +519,17 @@ impl UpgradeModule {
         env.storage()
             .persistent()
             .set(&DataKey::Token(token_id.clone()), &rolled_back_token);
+        MembershipTokenContract::reindex_expiry(
+            &env,
+            &token_id,
+            Some(token.expiry_date),
+            rolled_back_token.expiry_date,
+        );
+        TtlModule::bump(
+            &env,
+            TtlEntityClass::Token,
+            &DataKey::Token(token_id.clone()),
+        );
 
         // Record rollback in history
         let record = MigrationModule::build_record(
@@ -332,12 +566,12 @@ impl UpgradeModule {
     // -----------------------------------------------------------------------
 
     /// Return the current version number of a token.
-    pub fn get_token_version(env: Env, token_id: BytesN<32>) -> Result<u32, Error> {
+    pub fn get_token_version(env: Env, token_id: BytesN<32>) -> Result<u32, ModuleError> {
         let token: MembershipToken = env
             .storage()
             .persistent()
             .get(&DataKey::Token(token_id))
-            .ok_or(Error::TokenNotFound)?;
+            .ok_or(UpgradeError::TokenNotFound)?;
         Ok(token.current_version)
     }
 
@@ -347,15 +581,145 @@ impl UpgradeModule {
     }
 
     /// Return the global upgrade configuration.
-    pub fn get_upgrade_config(env: Env) -> Result<UpgradeConfig, Error> {
+    pub fn get_upgrade_config(env: Env) -> Result<UpgradeConfig, ModuleError> {
         Self::get_config(&env)
     }
 
+    /// Return the stored state of `version` of a token, if it has not been
+    /// pruned.
+    pub fn get_version_snapshot(
+        env: Env,
+        token_id: BytesN<32>,
+        version: u32,
+    ) -> Option<TokenVersionSnapshot> {
+        MigrationModule::get_snapshot(&env, &token_id, version)
+    }
+
+    /// Return the snapshot retention policy. Defaults to keeping every
+    /// snapshot.
+    pub fn get_snapshot_retention(env: &Env) -> SnapshotRetentionConfig {
+        env.storage()
+            .instance()
+            .get(&DataKey::SnapshotRetention)
+            .unwrap_or(SnapshotRetentionConfig {
+                max_snapshots: 0,
+                keep_original: true,
+            })
+    }
+
+    // -----------------------------------------------------------------------
+    // Snapshots
+    // -----------------------------------------------------------------------
+
+    /// Store `token`'s state as the snapshot of its current version unless
+    /// that version already has one, then prune snapshots that fall outside
+    /// the retention policy.
+    ///
+    /// Must be called **before** the token is mutated.
+    pub(crate) fn snapshot_token(
+        env: &Env,
+        token_id: &BytesN<32>,
+        token: &MembershipToken,
+        label: Option<String>,
+    ) {
+        let snapshot_key = DataKey::VersionSnapshot(token_id.clone(), token.current_version);
+        if env.storage().persistent().has(&snapshot_key) {
+            return;
+        }
+        let snapshot = MigrationModule::capture_snapshot(env, token, label);
+        MigrationModule::store_snapshot(env, token_id, &snapshot);
+        env.storage().persistent().extend_ttl(
+            &snapshot_key,
+            VERSION_SNAPSHOT_TTL_LEDGERS,
+            VERSION_SNAPSHOT_TTL_LEDGERS,
+        );
+
+        let retention = Self::get_snapshot_retention(env);
+        if retention.max_snapshots == 0 || snapshot.version < retention.max_snapshots {
+            return;
+        }
+        // Walk down from the newest out-of-window version; a lowered limit
+        // leaves several stale snapshots behind. Version 0 is handled on its
+        // own because a previously kept original sits behind a gap.
+        let mut version = snapshot.version - retention.max_snapshots;
+        while version > 0 {
+            let key = DataKey::VersionSnapshot(token_id.clone(), version);
+            if !env.storage().persistent().has(&key) {
+                break;
+            }
+            env.storage().persistent().remove(&key);
+            version -= 1;
+        }
+        if !retention.keep_original {
+            env.storage()
+                .persistent()
+                .remove(&DataKey::VersionSnapshot(token_id.clone(), 0));
+        }
+    }
+
     // -----------------------------------------------------------------------
     // Internal helpers
     // -----------------------------------------------------------------------
 
-    fn get_config(env: &Env) -> Result<UpgradeConfig, Error> {
+    fn require_admin(env: &Env, admin: &Address) -> Result<(), ModuleError> {
+        admin.require_auth();
+        let stored_admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::AdminNotSet)?;
+        if &stored_admin != admin {
+            return Err(Error::Unauthorized.into());
+        }
+        Ok(())
+    }
+
+    fn load_scheduled(env: &Env, id: u64) -> Option<ScheduledTokenUpgrade> {
+        let key = DataKey::ScheduledTokenUpgrade(id);
+        let scheduled = env.storage().persistent().get(&key);
+        if scheduled.is_some() {
+            TtlModule::bump(env, TtlEntityClass::Config, &key);
+        }
+        scheduled
+    }
+
+    fn save_scheduled(env: &Env, scheduled: &ScheduledTokenUpgrade) {
+        let key = DataKey::ScheduledTokenUpgrade(scheduled.id);
+        env.storage().persistent().set(&key, scheduled);
+        TtlModule::bump(env, TtlEntityClass::Config, &key);
+    }
+
+    fn pending_ids(env: &Env) -> Vec<u64> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::PendingTokenUpgrades)
+            .unwrap_or_else(|| Vec::new(env))
+    }
+
+    fn save_pending_ids(env: &Env, ids: &Vec<u64>) {
+        env.storage()
+            .persistent()
+            .set(&DataKey::PendingTokenUpgrades, ids);
+        TtlModule::bump(env, TtlEntityClass::Config, &DataKey::PendingTokenUpgrades);
+    }
+
+    /// Token IDs a scheduled upgrade applies to, resolving filters against
+    /// the current owner and metadata indexes.
+    fn resolve_target(env: &Env, target: &UpgradeTarget) -> Vec<BytesN<32>> {
+        match target {
+            UpgradeTarget::Tokens(token_ids) => token_ids.clone(),
+            UpgradeTarget::Owner(owner) => MembershipTokenContract::get_owner_tokens(env, owner),
+            UpgradeTarget::Attribute(key, value) => {
+                MembershipTokenContract::query_tokens_by_attribute(
+                    env.clone(),
+                    key.clone(),
+                    value.clone(),
+                )
+            }
+        }
+    }
+
+    fn get_config(env: &Env) -> Result<UpgradeConfig, ModuleError> {
         env.storage()
             .instance()
             .get(&DataKey::UpgradeConfig)
@@ -370,9 +734,9 @@ impl UpgradeModule {
         env: &Env,
         admin: &Address,
         token_id: &BytesN<32>,
-        label: Option<String>,
-        new_expiry_date: Option<u64>,
-    ) -> Result<u32, Error> {
+        changes: &TokenUpgradeChanges,
+    ) -> Result<u32, ModuleError> {
+        let label = changes.label.clone();
         let token: MembershipToken = env
             .storage()
             .persistent()
@@ -383,21 +747,31 @@ impl UpgradeModule {
         let to_version = from_version.checked_add(1).ok_or(UpgradeError::Overflow)?;
 
         // Snapshot pre-upgrade state
-        let snapshot = MigrationModule::capture_snapshot(env, &token, label.clone());
-        MigrationModule::store_snapshot(env, token_id, &snapshot);
-        env.storage().persistent().extend_ttl(
-            &DataKey::VersionSnapshot(token_id.clone(), from_version),
-            VERSION_SNAPSHOT_TTL_LEDGERS,
-            VERSION_SNAPSHOT_TTL_LEDGERS,
-        );
+        Self::snapshot_token(env, token_id, &token, label.clone());
 
-        // Migrate fields (only expiry for batch; status/tier unchanged)
-        let updated_token =
-            MigrationModule::migrate_token_fields(&token, to_version, new_expiry_date, None, None);
+        let updated_token = MigrationModule::migrate_token_fields(
+            &token,
+            to_version,
+            changes.new_expiry_date,
+            changes.new_tier_id.clone().map(Some),
+            None,
+        );
 
         env.storage()
             .persistent()
             .set(&DataKey::Token(token_id.clone()), &updated_token);
+        MembershipTokenContract::reindex_expiry(
+            env,
+            token_id,
+            Some(token.expiry_date),
+            updated_token.expiry_date,
+        );
+
+        TtlModule::bump(
+            env,
+            TtlEntityClass::Token,
+            &DataKey::Token(token_id.clone()),
+        );
 
         // Record
         let record = MigrationModule::build_record(
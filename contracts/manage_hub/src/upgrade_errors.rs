@@ -1,43 +1,33 @@
 //! Upgrade-related error types for the ManageHub contract.
 //!
-//! A dedicated `UpgradeError` enum is used because the main `Error` enum is
-//! already at the 50-variant XDR limit imposed by `#[contracterror]`.
-//!
-//! The [`From`] impl bridges `UpgradeError` into `Error` (reusing existing
-//! numeric codes) so that `?` propagation works in functions returning
-//! `Result<_, Error>`.
+//! `UpgradeError` owns the code range 300–349 (see [`crate::errors`]).
 
-use crate::errors::Error;
+use soroban_sdk::contracterror;
 
 /// Upgrade-specific errors.
-#[derive(Debug)]
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
 pub enum UpgradeError {
     /// Token upgrades are currently disabled by the admin.
-    UpgradesDisabled,
+    UpgradesDisabled = 300,
     /// The specified token does not exist.
-    TokenNotFound,
+    TokenNotFound = 301,
     /// Caller is not authorized to perform this upgrade.
-    Unauthorized,
+    Unauthorized = 302,
     /// Upgrade configuration has not been initialised.
-    UpgradeNotConfigured,
-    /// No upgrade history found; cannot rollback.
-    NoUpgradeHistory,
+    UpgradeNotConfigured = 303,
+    /// No snapshot exists for the requested version; cannot rollback.
+    NoUpgradeHistory = 304,
     /// Maximum rollback limit has been reached for this token.
-    RollbackLimitExceeded,
+    RollbackLimitExceeded = 305,
     /// Arithmetic overflow during upgrade processing.
-    Overflow,
-}
-
-impl From<UpgradeError> for Error {
-    fn from(e: UpgradeError) -> Self {
-        match e {
-            UpgradeError::UpgradesDisabled => Error::SubscriptionNotActive,
-            UpgradeError::TokenNotFound => Error::TokenNotFound,
-            UpgradeError::Unauthorized => Error::Unauthorized,
-            UpgradeError::UpgradeNotConfigured => Error::AdminNotSet,
-            UpgradeError::NoUpgradeHistory => Error::MetadataNotFound,
-            UpgradeError::RollbackLimitExceeded => Error::PauseCountExceeded,
-            UpgradeError::Overflow => Error::TimestampOverflow,
-        }
-    }
+    Overflow = 306,
+    /// No scheduled token upgrade exists with the given ID.
+    ScheduleNotFound = 307,
+    /// A scheduled upgrade's effective time is not in the future.
+    InvalidEffectiveTime = 308,
+    /// The scheduled upgrade has already taken effect and can no longer be cancelled.
+    UpgradeAlreadyEffective = 309,
+    /// Storage migration batches still have entries left to upgrade.
+    MigrationIncomplete = 310,
 }
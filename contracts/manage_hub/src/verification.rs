@@ -0,0 +1,211 @@
+use crate::errors::{Error, ModuleError};
+use crate::events::{
+    UserVerificationRevoked, UserVerified, VerificationPolicyUpdated, VerifierUpdated,
+};
+use crate::membership_token::MembershipTokenContract;
+use crate::types::{VerificationPolicy, VerificationRecord};
+use crate::verification_errors::VerificationError;
+use soroban_sdk::{contracttype, Address, Env};
+
+// ---------------------------------------------------------------------------
+// Storage keys
+// ---------------------------------------------------------------------------
+
+#[contracttype]
+pub enum VerificationDataKey {
+    /// Marks an address as a designated verifier (persistent storage).
+    Verifier(Address),
+    /// Verification record for an address (persistent storage).
+    Verification(Address),
+    /// Operations that require verification (instance storage).
+    VerificationPolicy,
+}
+
+// ---------------------------------------------------------------------------
+// Module
+// ---------------------------------------------------------------------------
+
+/// KYC / verification registry.
+///
+/// The admin or a designated verifier marks addresses as verified until an
+/// expiry. The admin-set [`VerificationPolicy`] decides which high-value
+/// operations require a current verification; by default none do.
+pub struct VerificationModule;
+
+impl VerificationModule {
+    /// Allows the admin or a designated verifier.
+    fn require_verifier(env: &Env, caller: &Address) -> Result<(), ModuleError> {
+        if Self::is_verifier(env, caller) {
+            caller.require_auth();
+            return Ok(());
+        }
+        MembershipTokenContract::require_admin(env, caller)
+            .map_err(|_| VerificationError::NotVerifier.into())
+    }
+
+    pub fn set_verifier(
+        env: Env,
+        admin: Address,
+        verifier: Address,
+        enabled: bool,
+    ) -> Result<(), Error> {
+        MembershipTokenContract::require_admin(&env, &admin)?;
+
+        let key = VerificationDataKey::Verifier(verifier.clone());
+        if enabled {
+            env.storage().persistent().set(&key, &true);
+        } else {
+            env.storage().persistent().remove(&key);
+        }
+
+        VerifierUpdated {
+            verifier,
+            enabled,
+            updated_by: admin,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    pub fn is_verifier(env: &Env, address: &Address) -> bool {
+        env.storage()
+            .persistent()
+            .has(&VerificationDataKey::Verifier(address.clone()))
+    }
+
+    pub fn verify_user(
+        env: Env,
+        caller: Address,
+        user: Address,
+        expires_at: u64,
+    ) -> Result<(), ModuleError> {
+        Self::require_verifier(&env, &caller)?;
+        let now = env.ledger().timestamp();
+        if expires_at <= now {
+            return Err(VerificationError::InvalidExpiry.into());
+        }
+
+        let record = VerificationRecord {
+            user: user.clone(),
+            verified_by: caller.clone(),
+            verified_at: now,
+            expires_at,
+        };
+        env.storage()
+            .persistent()
+            .set(&VerificationDataKey::Verification(user.clone()), &record);
+
+        UserVerified {
+            user,
+            verified_by: caller,
+            expires_at,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    pub fn revoke_verification(
+        env: Env,
+        caller: Address,
+        user: Address,
+    ) -> Result<(), ModuleError> {
+        Self::require_verifier(&env, &caller)?;
+
+        let key = VerificationDataKey::Verification(user.clone());
+        if !env.storage().persistent().has(&key) {
+            return Err(VerificationError::NotVerified.into());
+        }
+        env.storage().persistent().remove(&key);
+
+        UserVerificationRevoked {
+            user,
+            revoked_by: caller,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    /// Returns the stored record, including expired ones.
+    pub fn get_verification(env: &Env, user: &Address) -> Option<VerificationRecord> {
+        env.storage()
+            .persistent()
+            .get(&VerificationDataKey::Verification(user.clone()))
+    }
+
+    /// Returns `true` if `user` has a verification that has not expired.
+    pub fn is_verified(env: &Env, user: &Address) -> bool {
+        Self::get_verification(env, user)
+            .is_some_and(|record| record.expires_at > env.ledger().timestamp())
+    }
+
+    pub fn set_policy(env: Env, admin: Address, policy: VerificationPolicy) -> Result<(), Error> {
+        MembershipTokenContract::require_admin(&env, &admin)?;
+        if policy
+            .stake_threshold
+            .is_some_and(|threshold| threshold < 0)
+        {
+            return Err(Error::InvalidPaymentAmount);
+        }
+
+        env.storage()
+            .instance()
+            .set(&VerificationDataKey::VerificationPolicy, &policy);
+
+        VerificationPolicyUpdated {
+            policy,
+            updated_by: admin,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    pub fn get_policy(env: &Env) -> VerificationPolicy {
+        env.storage()
+            .instance()
+            .get(&VerificationDataKey::VerificationPolicy)
+            .unwrap_or(VerificationPolicy {
+                fractionalization: false,
+                stake_threshold: None,
+                households: false,
+            })
+    }
+
+    fn require_verified(env: &Env, user: &Address) -> Result<(), ModuleError> {
+        if !Self::is_verified(env, user) {
+            return Err(VerificationError::NotVerified.into());
+        }
+        Ok(())
+    }
+
+    /// Applies the policy to fractionalizing a token owned by `owner`.
+    pub(crate) fn check_fractionalization(env: &Env, owner: &Address) -> Result<(), ModuleError> {
+        if Self::get_policy(env).fractionalization {
+            Self::require_verified(env, owner)?;
+        }
+        Ok(())
+    }
+
+    /// Applies the policy to a stake that would leave `staker` with
+    /// `total_staked`.
+    pub(crate) fn check_stake(
+        env: &Env,
+        staker: &Address,
+        total_staked: i128,
+    ) -> Result<(), ModuleError> {
+        if let Some(threshold) = Self::get_policy(env).stake_threshold {
+            if total_staked > threshold {
+                Self::require_verified(env, staker)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Applies the policy to the owner of a household (organisation)
+    /// account adding members.
+    pub(crate) fn check_household(env: &Env, owner: &Address) -> Result<(), ModuleError> {
+        if Self::get_policy(env).households {
+            Self::require_verified(env, owner)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,17 @@
+//! Verification registry error types for the ManageHub contract.
+//!
+//! `VerificationError` owns the code range 1650–1699 (see [`crate::errors`]).
+
+use soroban_sdk::contracterror;
+
+/// Verification registry errors.
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum VerificationError {
+    /// The operation requires a current verification the address lacks.
+    NotVerified = 1650,
+    /// Caller is neither the admin nor a designated verifier.
+    NotVerifier = 1651,
+    /// The verification expiry is not in the future.
+    InvalidExpiry = 1652,
+}